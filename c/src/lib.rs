@@ -187,6 +187,7 @@ impl Into<MinifyOptions> for TransformOptions {
         Default::default()
       },
       unused_symbols,
+      ..MinifyOptions::default()
     }
   }
 }
@@ -281,6 +282,7 @@ pub extern "C" fn lightningcss_stylesheet_parse(
       Some(lightningcss::css_modules::Config {
         pattern,
         dashed_idents: options.css_modules_dashed_idents,
+        ..Default::default()
       })
     } else {
       None
@@ -288,6 +290,7 @@ pub extern "C" fn lightningcss_stylesheet_parse(
     error_recovery: options.error_recovery,
     source_index: 0,
     warnings: Some(warnings.clone()),
+    ..ParserOptions::default()
   };
 
   let stylesheet = unwrap!(StyleSheet::parse(code, opts), error, std::ptr::null_mut());
@@ -348,6 +351,8 @@ pub extern "C" fn lightningcss_stylesheet_to_css(
     } else {
       None
     },
+    source_map_url: None,
+    precision: None,
   };
 
   let res = unwrap!(wrapper.stylesheet.to_css(opts), error, ToCssResult::default());
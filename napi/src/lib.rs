@@ -11,6 +11,7 @@ use lightningcss::stylesheet::{
   MinifyOptions, ParserFlags, ParserOptions, PrinterOptions, PseudoClasses, StyleAttribute, StyleSheet,
 };
 use lightningcss::targets::{Browsers, Features, Targets};
+use lightningcss::values::color::GamutMapping;
 use napi::bindgen_prelude::{FromNapiValue, ToNapiValue};
 use napi::{CallContext, Env, JsObject, JsUnknown};
 use parcel_sourcemap::SourceMap;
@@ -567,6 +568,8 @@ struct Config {
   pub include: u32,
   #[serde(default)]
   pub exclude: u32,
+  #[serde(default)]
+  pub gamut_mapping: GamutMapping,
   pub minify: Option<bool>,
   pub source_map: Option<bool>,
   pub input_source_map: Option<String>,
@@ -578,6 +581,9 @@ struct Config {
   pub unused_symbols: Option<HashSet<String>>,
   pub error_recovery: Option<bool>,
   pub custom_at_rules: Option<HashMap<String, CustomAtRuleConfig>>,
+  pub precision: Option<u8>,
+  #[cfg(feature = "visitor")]
+  pub px_to_rem: Option<PxToRemOption>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -593,6 +599,39 @@ struct AnalyzeDependenciesConfig {
   preserve_imports: bool,
 }
 
+#[cfg(feature = "visitor")]
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum PxToRemOption {
+  Bool(bool),
+  Config(PxToRemConfig),
+}
+
+#[cfg(feature = "visitor")]
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PxToRemConfig {
+  root_font_size: Option<f32>,
+  min_px: Option<f32>,
+  exclude: Option<Vec<String>>,
+}
+
+#[cfg(feature = "visitor")]
+impl PxToRemOption {
+  fn to_options(&self) -> Option<lightningcss::stylesheet::PxToRemOptions> {
+    let defaults = lightningcss::stylesheet::PxToRemOptions::default();
+    match self {
+      PxToRemOption::Bool(true) => Some(defaults),
+      PxToRemOption::Bool(false) => None,
+      PxToRemOption::Config(c) => Some(lightningcss::stylesheet::PxToRemOptions {
+        root_font_size: c.root_font_size.unwrap_or(defaults.root_font_size),
+        min_px: c.min_px.unwrap_or(defaults.min_px),
+        exclude: c.exclude.clone().unwrap_or_default(),
+      }),
+    }
+  }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 enum CssModulesOption {
@@ -608,6 +647,7 @@ struct CssModulesConfig {
   animation: Option<bool>,
   grid: Option<bool>,
   custom_idents: Option<bool>,
+  paint: Option<bool>,
 }
 
 #[cfg(feature = "bundler")]
@@ -621,6 +661,8 @@ struct BundleConfig {
   pub include: u32,
   #[serde(default)]
   pub exclude: u32,
+  #[serde(default)]
+  pub gamut_mapping: GamutMapping,
   pub minify: Option<bool>,
   pub source_map: Option<bool>,
   pub drafts: Option<Drafts>,
@@ -631,6 +673,9 @@ struct BundleConfig {
   pub unused_symbols: Option<HashSet<String>>,
   pub error_recovery: Option<bool>,
   pub custom_at_rules: Option<HashMap<String, CustomAtRuleConfig>>,
+  pub precision: Option<u8>,
+  #[cfg(feature = "visitor")]
+  pub px_to_rem: Option<PxToRemOption>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -660,6 +705,10 @@ impl<'a> Into<PseudoClasses<'a>> for &'a OwnedPseudoClasses {
 struct Drafts {
   #[serde(default)]
   custom_media: bool,
+  #[serde(default)]
+  when_else: bool,
+  #[serde(default)]
+  mixins: bool,
 }
 
 #[derive(Serialize, Debug, Deserialize, Default)]
@@ -692,6 +741,8 @@ fn compile<'i>(
   let res = {
     let mut flags = ParserFlags::empty();
     flags.set(ParserFlags::CUSTOM_MEDIA, matches!(drafts, Some(d) if d.custom_media));
+    flags.set(ParserFlags::WHEN_ELSE, matches!(drafts, Some(d) if d.when_else));
+    flags.set(ParserFlags::MIXINS, matches!(drafts, Some(d) if d.mixins));
     flags.set(
       ParserFlags::DEEP_SELECTOR_COMBINATOR,
       matches!(non_standard, Some(v) if v.deep_selector_combinator),
@@ -719,6 +770,7 @@ fn compile<'i>(
               animation: c.animation.unwrap_or(true),
               grid: c.grid.unwrap_or(true),
               custom_idents: c.custom_idents.unwrap_or(true),
+              paint: c.paint.unwrap_or_default(),
             }),
           }
         } else {
@@ -727,6 +779,7 @@ fn compile<'i>(
         source_index: 0,
         error_recovery: config.error_recovery.unwrap_or_default(),
         warnings: warnings.clone(),
+        ..ParserOptions::default()
       },
       &mut CustomAtRuleParser {
         configs: config.custom_at_rules.clone().unwrap_or_default(),
@@ -747,6 +800,10 @@ fn compile<'i>(
     stylesheet.minify(MinifyOptions {
       targets,
       unused_symbols: config.unused_symbols.clone().unwrap_or_default(),
+      gamut_mapping: config.gamut_mapping,
+      #[cfg(feature = "visitor")]
+      px_to_rem: config.px_to_rem.as_ref().and_then(|p| p.to_options()),
+      ..MinifyOptions::default()
     })?;
 
     stylesheet.to_css(PrinterOptions {
@@ -766,6 +823,8 @@ fn compile<'i>(
         None
       },
       pseudo_classes: config.pseudo_classes.as_ref().map(|p| p.into()),
+      source_map_url: None,
+      precision: config.precision,
     })?
   };
 
@@ -825,6 +884,8 @@ fn compile_bundle<
     let non_standard = config.non_standard.as_ref();
     let mut flags = ParserFlags::empty();
     flags.set(ParserFlags::CUSTOM_MEDIA, matches!(drafts, Some(d) if d.custom_media));
+    flags.set(ParserFlags::WHEN_ELSE, matches!(drafts, Some(d) if d.when_else));
+    flags.set(ParserFlags::MIXINS, matches!(drafts, Some(d) if d.mixins));
     flags.set(
       ParserFlags::DEEP_SELECTOR_COMBINATOR,
       matches!(non_standard, Some(v) if v.deep_selector_combinator),
@@ -849,6 +910,7 @@ fn compile_bundle<
             animation: c.animation.unwrap_or(true),
             grid: c.grid.unwrap_or(true),
             custom_idents: c.custom_idents.unwrap_or(true),
+            paint: c.paint.unwrap_or_default(),
           }),
         }
       } else {
@@ -858,6 +920,7 @@ fn compile_bundle<
       warnings: warnings.clone(),
       filename: String::new(),
       source_index: 0,
+      ..ParserOptions::default()
     };
 
     let mut at_rule_parser = CustomAtRuleParser {
@@ -881,6 +944,10 @@ fn compile_bundle<
     stylesheet.minify(MinifyOptions {
       targets,
       unused_symbols: config.unused_symbols.clone().unwrap_or_default(),
+      gamut_mapping: config.gamut_mapping,
+      #[cfg(feature = "visitor")]
+      px_to_rem: config.px_to_rem.as_ref().and_then(|p| p.to_options()),
+      ..MinifyOptions::default()
     })?;
 
     stylesheet.to_css(PrinterOptions {
@@ -900,6 +967,8 @@ fn compile_bundle<
         None
       },
       pseudo_classes: config.pseudo_classes.as_ref().map(|p| p.into()),
+      source_map_url: None,
+      precision: config.precision,
     })?
   };
 
@@ -939,11 +1008,16 @@ struct AttrConfig {
   #[serde(default)]
   pub exclude: u32,
   #[serde(default)]
+  pub gamut_mapping: GamutMapping,
+  #[serde(default)]
   pub minify: bool,
   #[serde(default)]
   pub analyze_dependencies: bool,
   #[serde(default)]
   pub error_recovery: bool,
+  pub precision: Option<u8>,
+  #[cfg(feature = "visitor")]
+  pub px_to_rem: Option<PxToRemOption>,
 }
 
 #[derive(Serialize)]
@@ -1003,6 +1077,9 @@ fn compile_attr<'i>(
 
     attr.minify(MinifyOptions {
       targets,
+      gamut_mapping: config.gamut_mapping,
+      #[cfg(feature = "visitor")]
+      px_to_rem: config.px_to_rem.as_ref().and_then(|p| p.to_options()),
       ..MinifyOptions::default()
     });
     attr.to_css(PrinterOptions {
@@ -1016,6 +1093,8 @@ fn compile_attr<'i>(
         None
       },
       pseudo_classes: None,
+      source_map_url: None,
+      precision: config.precision,
     })?
   };
   Ok(AttrResult {
@@ -51,6 +51,7 @@ pub struct JsVisitor {
   visit_function: VisitorsRef,
   function_map: VisitorsRef,
   visit_variable: VisitorsRef,
+  visit_attr: VisitorsRef,
   visit_env: VisitorsRef,
   env_map: VisitorsRef,
   types: VisitTypes,
@@ -160,6 +161,7 @@ impl Drop for JsVisitor {
     drop_tuple!(visit_media_query);
     drop_tuple!(visit_supports_condition);
     drop_tuple!(visit_variable);
+    drop_tuple!(visit_attr);
     drop_tuple!(visit_env);
     drop_tuple!(env_map);
     drop!(visit_custom_ident);
@@ -225,6 +227,7 @@ impl JsVisitor {
         get!("SupportsConditionExit", SUPPORTS_CONDITIONS),
       ),
       visit_variable: VisitorsRef::new(get!("Variable", TOKENS), get!("VariableExit", TOKENS)),
+      visit_attr: VisitorsRef::new(get!("Attr", TOKENS), get!("AttrExit", TOKENS)),
       visit_env: VisitorsRef::new(
         get!("EnvironmentVariable", TOKENS | MEDIA_QUERIES | ENVIRONMENT_VARIABLES),
         get!(
@@ -298,6 +301,7 @@ impl<'i> Visitor<'i, AtRule<'i>> for JsVisitor {
             CssRule::Keyframes(..) => "keyframes",
             CssRule::FontFace(..) => "font-face",
             CssRule::FontPaletteValues(..) => "font-palette-values",
+            CssRule::ColorProfile(..) => "color-profile",
             CssRule::Page(..) => "page",
             CssRule::Supports(..) => "supports",
             CssRule::CounterStyle(..) => "counter-style",
@@ -312,6 +316,12 @@ impl<'i> Visitor<'i, AtRule<'i>> for JsVisitor {
             CssRule::Nesting(..) => "nesting",
             CssRule::Viewport(..) => "viewport",
             CssRule::StartingStyle(..) => "starting-style",
+            CssRule::PositionTry(..) => "position-try",
+            CssRule::ViewTransition(..) => "view-transition",
+            CssRule::When(..) => "when",
+            CssRule::Function(..) => "function",
+            CssRule::Mixin(..) => "mixin",
+            CssRule::Apply(..) => "apply",
             CssRule::Unknown(v) => {
               let name = v.name.as_ref();
               if let Some(visit) = rule_map.custom(stage, "unknown", name) {
@@ -540,6 +550,7 @@ impl<'i> Visitor<'i, AtRule<'i>> for JsVisitor {
       let visit_function = self.visit_function.get::<JsFunction>(&env);
       let function_map = self.function_map.get::<JsObject>(&env);
       let visit_variable = self.visit_variable.get::<JsFunction>(&env);
+      let visit_attr = self.visit_attr.get::<JsFunction>(&env);
       let visit_env = self.visit_env.get::<JsFunction>(&env);
       let env_map = self.env_map.get::<JsObject>(&env);
 
@@ -552,6 +563,7 @@ impl<'i> Visitor<'i, AtRule<'i>> for JsVisitor {
               visit_function.for_stage(stage),
             ),
             TokenOrValue::Var(_) => (None, visit_variable.for_stage(stage)),
+            TokenOrValue::Attr(_) => (None, visit_attr.for_stage(stage)),
             TokenOrValue::Env(e) => (env_map.named(stage, e.name.name()), visit_env.for_stage(stage)),
             TokenOrValue::Token(t) => {
               let name = match t {
@@ -579,6 +591,7 @@ impl<'i> Visitor<'i, AtRule<'i>> for JsVisitor {
             let js_value = match value {
               TokenOrValue::Function(f) => env.to_js_value(f)?,
               TokenOrValue::Var(v) => env.to_js_value(v)?,
+              TokenOrValue::Attr(a) => env.to_js_value(a)?,
               TokenOrValue::Env(v) => env.to_js_value(v)?,
               TokenOrValue::Token(t) => env.to_js_value(t)?,
               _ => unreachable!(),
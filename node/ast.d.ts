@@ -33,6 +33,10 @@ export type Rule<D = Declaration, M = MediaQuery> = | {
     type: "font-palette-values";
     value: FontPaletteValuesRule;
   }
+| {
+    type: "color-profile";
+    value: ColorProfileRule;
+  }
 | {
     type: "page";
     value: PageRule<D>;
@@ -43,7 +47,7 @@ export type Rule<D = Declaration, M = MediaQuery> = | {
   }
 | {
     type: "counter-style";
-    value: CounterStyleRule<D>;
+    value: CounterStyleRule;
   }
 | {
     type: "namespace";
@@ -89,6 +93,30 @@ export type Rule<D = Declaration, M = MediaQuery> = | {
     type: "starting-style";
     value: StartingStyleRule<D, M>;
   }
+| {
+    type: "position-try";
+    value: PositionTryRule<D>;
+  }
+| {
+    type: "view-transition";
+    value: ViewTransitionRule;
+  }
+| {
+    type: "when";
+    value: WhenRule<D, M>;
+  }
+| {
+    type: "function";
+    value: FunctionRule<D>;
+  }
+| {
+    type: "mixin";
+    value: MixinRule<D, M>;
+  }
+| {
+    type: "apply";
+    value: ApplyRule;
+  }
 | {
     type: "ignored";
   }
@@ -363,6 +391,14 @@ export type CalcFor_Length =
   | {
       type: "function";
       value: MathFunctionFor_Length;
+    }
+  | {
+      type: "multiply";
+      /**
+       * @minItems 2
+       * @maxItems 2
+       */
+      value: [CalcFor_Length, CalcFor_Length];
     };
 /**
  * A CSS [math function](https://www.w3.org/TR/css-values-4/#math-function).
@@ -425,11 +461,56 @@ export type MathFunctionFor_Length =
   | {
       type: "hypot";
       value: CalcFor_Length[];
+    }
+  | {
+      type: "anchor";
+      value: AnchorFunctionFor_Length;
+    }
+  | {
+      type: "anchor-size";
+      value: AnchorSizeFunctionFor_Length;
+    }
+  | {
+      type: "sibling-index";
+    }
+  | {
+      type: "sibling-count";
     };
 /**
  * A [rounding strategy](https://www.w3.org/TR/css-values-4/#typedef-rounding-strategy), as used in the `round()` function.
  */
 export type RoundingStrategy = "nearest" | "up" | "down" | "to-zero";
+/**
+ * An [`<anchor-side>`](https://drafts.csswg.org/css-anchor-position-1/#typedef-anchor-side) value.
+ */
+export type AnchorSide =
+  | {
+      type: "keyword";
+      value: AnchorSideKeyword;
+    }
+  | {
+      type: "percentage";
+      value: number;
+    };
+/**
+ * A keyword for the [`<anchor-side>`](https://drafts.csswg.org/css-anchor-position-1/#typedef-anchor-side) argument of the `anchor()` function.
+ */
+export type AnchorSideKeyword =
+  | "inside"
+  | "outside"
+  | "top"
+  | "left"
+  | "right"
+  | "bottom"
+  | "start"
+  | "end"
+  | "self-start"
+  | "self-end"
+  | "center";
+/**
+ * An [`<anchor-size>`](https://drafts.csswg.org/css-anchor-position-1/#typedef-anchor-size) keyword.
+ */
+export type AnchorSizeKeyword = "width" | "height" | "block" | "inline" | "self-block" | "self-inline";
 /**
  * A CSS [`<resolution>`](https://www.w3.org/TR/css-values-4/#resolution) value.
  */
@@ -481,6 +562,14 @@ export type TokenOrValue =
       type: "env";
       value: EnvironmentVariable;
     }
+  | {
+      type: "attr";
+      value: AttrFunction;
+    }
+  | {
+      type: "toggle";
+      value: ToggleFunction;
+    }
   | {
       type: "function";
       value: Function;
@@ -641,7 +730,17 @@ export type Token =
  *
  * Each color space is represented as a struct that implements the `From` and `Into` traits for all other color spaces, so it is possible to convert between color spaces easily. In addition, colors support [interpolation](#method.interpolate) as in the `color-mix()` function.
  */
-export type CssColor = CurrentColor | RGBColor | LABColor | PredefinedColor | FloatColor | LightDark | SystemColor;
+export type CssColor =
+  | CurrentColor
+  | RGBColor
+  | LABColor
+  | PredefinedColor
+  | FloatColor
+  | Profile
+  | DeviceCmyk
+  | LightDark
+  | ContrastColor
+  | SystemColor;
 export type CurrentColor = {
   type: "currentcolor";
 };
@@ -966,6 +1065,10 @@ export type LightDark = {
   light: CssColor;
   type: "light-dark";
 };
+export type ContrastColor = {
+  color: CssColor;
+  type: "contrast-color";
+};
 /**
  * A CSS [system color](https://drafts.csswg.org/css-color/#css-system-colors) keyword.
  */
@@ -1082,6 +1185,101 @@ export type Specifier =
       type: "source-index";
       value: number;
     };
+/**
+ * The type of an [attr()](AttrFunction) reference.
+ */
+export type AttrType =
+  | {
+      type: "type";
+      value: SyntaxString;
+    }
+  | {
+      type: "raw-string";
+    }
+  | {
+      type: "number";
+    }
+  | {
+      type: "percentage";
+    }
+  | {
+      type: "unit";
+      value: String;
+    };
+/**
+ * A CSS [syntax string](https://drafts.css-houdini.org/css-properties-values-api/#syntax-strings) used to define the grammar for a registered custom property.
+ */
+export type SyntaxString =
+  | {
+      type: "components";
+      value: SyntaxComponent[];
+    }
+  | {
+      type: "universal";
+    };
+/**
+ * A [syntax component component name](https://drafts.css-houdini.org/css-properties-values-api/#supported-names).
+ */
+export type SyntaxComponentKind =
+  | {
+      type: "length";
+    }
+  | {
+      type: "number";
+    }
+  | {
+      type: "percentage";
+    }
+  | {
+      type: "length-percentage";
+    }
+  | {
+      type: "color";
+    }
+  | {
+      type: "image";
+    }
+  | {
+      type: "url";
+    }
+  | {
+      type: "integer";
+    }
+  | {
+      type: "angle";
+    }
+  | {
+      type: "time";
+    }
+  | {
+      type: "resolution";
+    }
+  | {
+      type: "transform-function";
+    }
+  | {
+      type: "transform-list";
+    }
+  | {
+      type: "custom-ident";
+    }
+  | {
+      type: "literal";
+      value: string;
+    };
+/**
+ * A [multiplier](https://drafts.css-houdini.org/css-properties-values-api/#multipliers) for a [SyntaxComponent](SyntaxComponent). Indicates whether and how the component may be repeated.
+ */
+export type Multiplier =
+  | {
+      type: "none";
+    }
+  | {
+      type: "space";
+    }
+  | {
+      type: "comma";
+    };
 /**
  * A CSS [`<angle>`](https://www.w3.org/TR/css-values-4/#angles) value.
  *
@@ -1214,6 +1412,14 @@ export type SupportsCondition =
       type: "selector";
       value: String;
     }
+  | {
+      type: "font-format";
+      value: FontFormat;
+    }
+  | {
+      type: "font-technology";
+      value: FontTechnology;
+    }
   | {
       type: "unknown";
       value: String;
@@ -1269,6 +1475,12 @@ export type PropertyId =
   | {
       property: "visibility";
     }
+  | {
+      property: "reading-flow";
+    }
+  | {
+      property: "reading-order";
+    }
   | {
       property: "width";
     }
@@ -1312,6 +1524,21 @@ export type PropertyId =
   | {
       property: "aspect-ratio";
     }
+  | {
+      property: "contain-intrinsic-width";
+    }
+  | {
+      property: "contain-intrinsic-height";
+    }
+  | {
+      property: "contain-intrinsic-block-size";
+    }
+  | {
+      property: "contain-intrinsic-inline-size";
+    }
+  | {
+      property: "contain-intrinsic-size";
+    }
   | {
       property: "overflow";
     }
@@ -1321,10 +1548,29 @@ export type PropertyId =
   | {
       property: "overflow-y";
     }
+  | {
+      property: "overscroll-behavior";
+    }
+  | {
+      property: "overscroll-behavior-x";
+    }
+  | {
+      property: "overscroll-behavior-y";
+    }
   | {
       property: "text-overflow";
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "line-clamp";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "continue";
+    }
+  | {
+      property: "overflow-clip-margin";
+    }
   | {
       property: "position";
     }
@@ -1361,6 +1607,27 @@ export type PropertyId =
   | {
       property: "inset";
     }
+  | {
+      property: "anchor-name";
+    }
+  | {
+      property: "position-anchor";
+    }
+  | {
+      property: "position-area";
+    }
+  | {
+      property: "inset-area";
+    }
+  | {
+      property: "position-try-order";
+    }
+  | {
+      property: "position-try-fallbacks";
+    }
+  | {
+      property: "position-try";
+    }
   | {
       property: "border-spacing";
     }
@@ -1468,6 +1735,33 @@ export type PropertyId =
       property: "border-radius";
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "corner-top-left-shape";
+    }
+  | {
+      property: "corner-top-right-shape";
+    }
+  | {
+      property: "corner-bottom-right-shape";
+    }
+  | {
+      property: "corner-bottom-left-shape";
+    }
+  | {
+      property: "corner-start-start-shape";
+    }
+  | {
+      property: "corner-start-end-shape";
+    }
+  | {
+      property: "corner-end-end-shape";
+    }
+  | {
+      property: "corner-end-start-shape";
+    }
+  | {
+      property: "corner-shape";
+    }
   | {
       property: "border-image-source";
     }
@@ -1872,6 +2166,39 @@ export type PropertyId =
   | {
       property: "scroll-padding";
     }
+  | {
+      property: "scroll-start-block";
+    }
+  | {
+      property: "scroll-start-inline";
+    }
+  | {
+      property: "scroll-start-x";
+    }
+  | {
+      property: "scroll-start-y";
+    }
+  | {
+      property: "scroll-start";
+    }
+  | {
+      property: "scroll-start-target-block";
+    }
+  | {
+      property: "scroll-start-target-inline";
+    }
+  | {
+      property: "scroll-start-target-x";
+    }
+  | {
+      property: "scroll-start-target-y";
+    }
+  | {
+      property: "scroll-start-target";
+    }
+  | {
+      property: "scroll-initial-target";
+    }
   | {
       property: "font-weight";
     }
@@ -1890,6 +2217,27 @@ export type PropertyId =
   | {
       property: "font-variant-caps";
     }
+  | {
+      property: "font-variant-ligatures";
+    }
+  | {
+      property: "font-variant-alternates";
+    }
+  | {
+      property: "font-variant-numeric";
+    }
+  | {
+      property: "font-variant-east-asian";
+    }
+  | {
+      property: "font-variant-position";
+    }
+  | {
+      property: "font-variant-emoji";
+    }
+  | {
+      property: "font-variant";
+    }
   | {
       property: "line-height";
     }
@@ -1902,6 +2250,24 @@ export type PropertyId =
   | {
       property: "font-palette";
     }
+  | {
+      property: "font-size-adjust";
+    }
+  | {
+      property: "font-synthesis-weight";
+    }
+  | {
+      property: "font-synthesis-style";
+    }
+  | {
+      property: "font-synthesis-small-caps";
+    }
+  | {
+      property: "font-synthesis-position";
+    }
+  | {
+      property: "font-synthesis";
+    }
   | {
       property: "transition-property";
       vendorPrefix: VendorPrefix;
@@ -1918,6 +2284,9 @@ export type PropertyId =
       property: "transition-timing-function";
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "transition-behavior";
+    }
   | {
       property: "transition";
       vendorPrefix: VendorPrefix;
@@ -1965,7 +2334,28 @@ export type PropertyId =
       vendorPrefix: VendorPrefix;
     }
   | {
-      property: "transform";
+      property: "scroll-timeline-name";
+    }
+  | {
+      property: "scroll-timeline-axis";
+    }
+  | {
+      property: "scroll-timeline";
+    }
+  | {
+      property: "view-timeline-name";
+    }
+  | {
+      property: "view-timeline-axis";
+    }
+  | {
+      property: "view-timeline-inset";
+    }
+  | {
+      property: "view-timeline";
+    }
+  | {
+      property: "transform";
       vendorPrefix: VendorPrefix;
     }
   | {
@@ -2000,9 +2390,33 @@ export type PropertyId =
   | {
       property: "scale";
     }
+  | {
+      property: "offset-path";
+    }
+  | {
+      property: "offset-distance";
+    }
+  | {
+      property: "offset-rotate";
+    }
+  | {
+      property: "offset-anchor";
+    }
+  | {
+      property: "offset-position";
+    }
+  | {
+      property: "offset";
+    }
   | {
       property: "text-transform";
     }
+  | {
+      property: "white-space-collapse";
+    }
+  | {
+      property: "text-wrap-mode";
+    }
   | {
       property: "white-space";
     }
@@ -2045,6 +2459,15 @@ export type PropertyId =
   | {
       property: "text-indent";
     }
+  | {
+      property: "text-spacing-trim";
+    }
+  | {
+      property: "text-autospace";
+    }
+  | {
+      property: "text-spacing";
+    }
   | {
       property: "text-decoration-line";
       vendorPrefix: VendorPrefix;
@@ -2087,6 +2510,22 @@ export type PropertyId =
   | {
       property: "text-shadow";
     }
+  | {
+      property: "text-box-trim";
+    }
+  | {
+      property: "text-box-edge";
+    }
+  | {
+      property: "text-box";
+    }
+  | {
+      property: "initial-letter";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "initial-letter-align";
+    }
   | {
       property: "text-size-adjust";
       vendorPrefix: VendorPrefix;
@@ -2127,6 +2566,27 @@ export type PropertyId =
       property: "appearance";
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "field-sizing";
+    }
+  | {
+      property: "scrollbar-width";
+    }
+  | {
+      property: "scrollbar-color";
+    }
+  | {
+      property: "tap-highlight-color";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "touch-callout";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "overflow-scrolling";
+      vendorPrefix: VendorPrefix;
+    }
   | {
       property: "list-style-type";
     }
@@ -2208,6 +2668,36 @@ export type PropertyId =
   | {
       property: "image-rendering";
     }
+  | {
+      property: "vector-effect";
+    }
+  | {
+      property: "dominant-baseline";
+    }
+  | {
+      property: "d";
+    }
+  | {
+      property: "cx";
+    }
+  | {
+      property: "cy";
+    }
+  | {
+      property: "r";
+    }
+  | {
+      property: "rx";
+    }
+  | {
+      property: "ry";
+    }
+  | {
+      property: "x";
+    }
+  | {
+      property: "y";
+    }
   | {
       property: "clip-path";
       vendorPrefix: VendorPrefix;
@@ -2318,9 +2808,15 @@ export type PropertyId =
       property: "backdrop-filter";
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "zoom";
+    }
   | {
       property: "z-index";
     }
+  | {
+      property: "overlay";
+    }
   | {
       property: "container-type";
     }
@@ -2333,9 +2829,21 @@ export type PropertyId =
   | {
       property: "view-transition-name";
     }
+  | {
+      property: "view-transition-class";
+    }
   | {
       property: "color-scheme";
     }
+  | {
+      property: "math-depth";
+    }
+  | {
+      property: "math-style";
+    }
+  | {
+      property: "math-shift";
+    }
   | {
       property: "all";
     }
@@ -2344,6 +2852,50 @@ export type PropertyId =
     };
 export type Prefix = "none" | "webkit" | "moz" | "ms" | "o";
 export type VendorPrefix = Prefix[];
+/**
+ * A font format keyword in the `format()` function of the the [src](https://drafts.csswg.org/css-fonts/#src-desc) property of an `@font-face` rule.
+ */
+export type FontFormat =
+  | {
+      type: "woff";
+    }
+  | {
+      type: "woff2";
+    }
+  | {
+      type: "truetype";
+    }
+  | {
+      type: "opentype";
+    }
+  | {
+      type: "embedded-opentype";
+    }
+  | {
+      type: "collection";
+    }
+  | {
+      type: "svg";
+    }
+  | {
+      type: "string";
+      value: String;
+    };
+/**
+ * A font format keyword in the `format()` function of the the [src](https://drafts.csswg.org/css-fonts/#src-desc) property of an `@font-face` rule.
+ */
+export type FontTechnology =
+  | "features-opentype"
+  | "features-aat"
+  | "features-graphite"
+  | "color-colrv0"
+  | "color-colrv1"
+  | "color-svg"
+  | "color-sbix"
+  | "color-cbdt"
+  | "variations"
+  | "palettes"
+  | "incremental";
 export type Declaration =
   | {
       property: "background-color";
@@ -2411,6 +2963,14 @@ export type Declaration =
       property: "visibility";
       value: Visibility;
     }
+  | {
+      property: "reading-flow";
+      value: ReadingFlow;
+    }
+  | {
+      property: "reading-order";
+      value: number;
+    }
   | {
       property: "width";
       value: Size;
@@ -2468,6 +3028,26 @@ export type Declaration =
       property: "aspect-ratio";
       value: AspectRatio;
     }
+  | {
+      property: "contain-intrinsic-width";
+      value: ContainIntrinsicLength;
+    }
+  | {
+      property: "contain-intrinsic-height";
+      value: ContainIntrinsicLength;
+    }
+  | {
+      property: "contain-intrinsic-block-size";
+      value: ContainIntrinsicLength;
+    }
+  | {
+      property: "contain-intrinsic-inline-size";
+      value: ContainIntrinsicLength;
+    }
+  | {
+      property: "contain-intrinsic-size";
+      value: ContainIntrinsicSize;
+    }
   | {
       property: "overflow";
       value: Overflow;
@@ -2480,11 +3060,36 @@ export type Declaration =
       property: "overflow-y";
       value: OverflowKeyword;
     }
+  | {
+      property: "overscroll-behavior";
+      value: OverscrollBehavior;
+    }
+  | {
+      property: "overscroll-behavior-x";
+      value: OverscrollBehaviorKeyword;
+    }
+  | {
+      property: "overscroll-behavior-y";
+      value: OverscrollBehaviorKeyword;
+    }
   | {
       property: "text-overflow";
       value: TextOverflow;
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "line-clamp";
+      value: LineClamp;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "continue";
+      value: Continue;
+    }
+  | {
+      property: "overflow-clip-margin";
+      value: OverflowClipMargin;
+    }
   | {
       property: "position";
       value: Position2;
@@ -2533,6 +3138,34 @@ export type Declaration =
       property: "inset";
       value: Inset;
     }
+  | {
+      property: "anchor-name";
+      value: AnchorName;
+    }
+  | {
+      property: "position-anchor";
+      value: PositionAnchor;
+    }
+  | {
+      property: "position-area";
+      value: PositionArea;
+    }
+  | {
+      property: "inset-area";
+      value: PositionArea;
+    }
+  | {
+      property: "position-try-order";
+      value: PositionTryOrder;
+    }
+  | {
+      property: "position-try-fallbacks";
+      value: PositionTryFallbacks;
+    }
+  | {
+      property: "position-try";
+      value: PositionTry;
+    }
   | {
       property: "border-spacing";
       value: Size2DFor_Length;
@@ -2674,6 +3307,42 @@ export type Declaration =
       value: BorderRadius;
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "corner-top-left-shape";
+      value: CornerShapeValue;
+    }
+  | {
+      property: "corner-top-right-shape";
+      value: CornerShapeValue;
+    }
+  | {
+      property: "corner-bottom-right-shape";
+      value: CornerShapeValue;
+    }
+  | {
+      property: "corner-bottom-left-shape";
+      value: CornerShapeValue;
+    }
+  | {
+      property: "corner-start-start-shape";
+      value: CornerShapeValue;
+    }
+  | {
+      property: "corner-start-end-shape";
+      value: CornerShapeValue;
+    }
+  | {
+      property: "corner-end-end-shape";
+      value: CornerShapeValue;
+    }
+  | {
+      property: "corner-end-start-shape";
+      value: CornerShapeValue;
+    }
+  | {
+      property: "corner-shape";
+      value: CornerShape;
+    }
   | {
       property: "border-image-source";
       value: Image;
@@ -3203,6 +3872,50 @@ export type Declaration =
       property: "scroll-padding";
       value: ScrollPadding;
     }
+  | {
+      property: "scroll-start-block";
+      value: ScrollStartValue;
+    }
+  | {
+      property: "scroll-start-inline";
+      value: ScrollStartValue;
+    }
+  | {
+      property: "scroll-start-x";
+      value: ScrollStartValue;
+    }
+  | {
+      property: "scroll-start-y";
+      value: ScrollStartValue;
+    }
+  | {
+      property: "scroll-start";
+      value: ScrollStart;
+    }
+  | {
+      property: "scroll-start-target-block";
+      value: ScrollStartTargetValue;
+    }
+  | {
+      property: "scroll-start-target-inline";
+      value: ScrollStartTargetValue;
+    }
+  | {
+      property: "scroll-start-target-x";
+      value: ScrollStartTargetValue;
+    }
+  | {
+      property: "scroll-start-target-y";
+      value: ScrollStartTargetValue;
+    }
+  | {
+      property: "scroll-start-target";
+      value: ScrollStartTarget;
+    }
+  | {
+      property: "scroll-initial-target";
+      value: ScrollInitialTarget;
+    }
   | {
       property: "font-weight";
       value: FontWeight;
@@ -3227,6 +3940,34 @@ export type Declaration =
       property: "font-variant-caps";
       value: FontVariantCaps;
     }
+  | {
+      property: "font-variant-ligatures";
+      value: FontVariantLigatures;
+    }
+  | {
+      property: "font-variant-alternates";
+      value: FontVariantAlternates;
+    }
+  | {
+      property: "font-variant-numeric";
+      value: FontVariantNumeric;
+    }
+  | {
+      property: "font-variant-east-asian";
+      value: FontVariantEastAsian;
+    }
+  | {
+      property: "font-variant-position";
+      value: FontVariantPosition;
+    }
+  | {
+      property: "font-variant-emoji";
+      value: FontVariantEmoji;
+    }
+  | {
+      property: "font-variant";
+      value: FontVariant;
+    }
   | {
       property: "line-height";
       value: LineHeight;
@@ -3241,7 +3982,31 @@ export type Declaration =
     }
   | {
       property: "font-palette";
-      value: DashedIdentReference;
+      value: FontPalette;
+    }
+  | {
+      property: "font-size-adjust";
+      value: FontSizeAdjust;
+    }
+  | {
+      property: "font-synthesis-weight";
+      value: FontSynthesisKeyword;
+    }
+  | {
+      property: "font-synthesis-style";
+      value: FontSynthesisKeyword;
+    }
+  | {
+      property: "font-synthesis-small-caps";
+      value: FontSynthesisKeyword;
+    }
+  | {
+      property: "font-synthesis-position";
+      value: FontSynthesisKeyword;
+    }
+  | {
+      property: "font-synthesis";
+      value: FontSynthesis;
     }
   | {
       property: "transition-property";
@@ -3263,6 +4028,10 @@ export type Declaration =
       value: EasingFunction[];
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "transition-behavior";
+      value: TransitionBehavior[];
+    }
   | {
       property: "transition";
       value: Transition[];
@@ -3322,14 +4091,42 @@ export type Declaration =
       vendorPrefix: VendorPrefix;
     }
   | {
-      property: "transform";
-      value: Transform[];
-      vendorPrefix: VendorPrefix;
+      property: "scroll-timeline-name";
+      value: TimelineName[];
     }
   | {
-      property: "transform-origin";
-      value: Position;
-      vendorPrefix: VendorPrefix;
+      property: "scroll-timeline-axis";
+      value: ScrollAxis[];
+    }
+  | {
+      property: "scroll-timeline";
+      value: ScrollTimelineDefinition[];
+    }
+  | {
+      property: "view-timeline-name";
+      value: TimelineName[];
+    }
+  | {
+      property: "view-timeline-axis";
+      value: ScrollAxis[];
+    }
+  | {
+      property: "view-timeline-inset";
+      value: Size2DFor_LengthPercentageOrAuto[];
+    }
+  | {
+      property: "view-timeline";
+      value: ViewTimelineDefinition[];
+    }
+  | {
+      property: "transform";
+      value: Transform[];
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "transform-origin";
+      value: Position;
+      vendorPrefix: VendorPrefix;
     }
   | {
       property: "transform-style";
@@ -3367,10 +4164,42 @@ export type Declaration =
       property: "scale";
       value: Scale;
     }
+  | {
+      property: "offset-path";
+      value: OffsetPath;
+    }
+  | {
+      property: "offset-distance";
+      value: DimensionPercentageFor_LengthValue;
+    }
+  | {
+      property: "offset-rotate";
+      value: OffsetRotate;
+    }
+  | {
+      property: "offset-anchor";
+      value: OffsetAnchor;
+    }
+  | {
+      property: "offset-position";
+      value: OffsetPosition;
+    }
+  | {
+      property: "offset";
+      value: Offset;
+    }
   | {
       property: "text-transform";
       value: TextTransform;
     }
+  | {
+      property: "white-space-collapse";
+      value: WhiteSpaceCollapse;
+    }
+  | {
+      property: "text-wrap-mode";
+      value: TextWrapMode;
+    }
   | {
       property: "white-space";
       value: WhiteSpace;
@@ -3426,6 +4255,18 @@ export type Declaration =
       property: "text-indent";
       value: TextIndent;
     }
+  | {
+      property: "text-spacing-trim";
+      value: TextSpacingTrim;
+    }
+  | {
+      property: "text-autospace";
+      value: TextAutospace;
+    }
+  | {
+      property: "text-spacing";
+      value: TextSpacing;
+    }
   | {
       property: "text-decoration-line";
       value: TextDecorationLine;
@@ -3479,6 +4320,27 @@ export type Declaration =
       property: "text-shadow";
       value: TextShadow[];
     }
+  | {
+      property: "text-box-trim";
+      value: TextBoxTrim;
+    }
+  | {
+      property: "text-box-edge";
+      value: TextBoxEdge;
+    }
+  | {
+      property: "text-box";
+      value: TextBox;
+    }
+  | {
+      property: "initial-letter";
+      value: InitialLetter;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "initial-letter-align";
+      value: InitialLetterAlign;
+    }
   | {
       property: "text-size-adjust";
       value: TextSizeAdjust;
@@ -3531,6 +4393,33 @@ export type Declaration =
       value: Appearance;
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "field-sizing";
+      value: FieldSizing;
+    }
+  | {
+      property: "scrollbar-width";
+      value: ScrollbarWidth;
+    }
+  | {
+      property: "scrollbar-color";
+      value: ScrollbarColor;
+    }
+  | {
+      property: "tap-highlight-color";
+      value: CssColor;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "touch-callout";
+      value: WebKitTouchCallout;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "overflow-scrolling";
+      value: WebKitOverflowScrolling;
+      vendorPrefix: VendorPrefix;
+    }
   | {
       property: "list-style-type";
       value: ListStyleType;
@@ -3639,6 +4528,46 @@ export type Declaration =
       property: "image-rendering";
       value: ImageRendering;
     }
+  | {
+      property: "vector-effect";
+      value: VectorEffect;
+    }
+  | {
+      property: "dominant-baseline";
+      value: DominantBaseline;
+    }
+  | {
+      property: "d";
+      value: PathData;
+    }
+  | {
+      property: "cx";
+      value: DimensionPercentageFor_LengthValue;
+    }
+  | {
+      property: "cy";
+      value: DimensionPercentageFor_LengthValue;
+    }
+  | {
+      property: "r";
+      value: DimensionPercentageFor_LengthValue;
+    }
+  | {
+      property: "rx";
+      value: LengthPercentageOrAuto;
+    }
+  | {
+      property: "ry";
+      value: LengthPercentageOrAuto;
+    }
+  | {
+      property: "x";
+      value: DimensionPercentageFor_LengthValue;
+    }
+  | {
+      property: "y";
+      value: DimensionPercentageFor_LengthValue;
+    }
   | {
       property: "clip-path";
       value: ClipPath;
@@ -3780,10 +4709,18 @@ export type Declaration =
       value: FilterList;
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "zoom";
+      value: Zoom;
+    }
   | {
       property: "z-index";
       value: ZIndex;
     }
+  | {
+      property: "overlay";
+      value: Overlay;
+    }
   | {
       property: "container-type";
       value: ContainerType;
@@ -3798,12 +4735,28 @@ export type Declaration =
     }
   | {
       property: "view-transition-name";
-      value: String;
+      value: ViewTransitionName;
+    }
+  | {
+      property: "view-transition-class";
+      value: ViewTransitionClass;
     }
   | {
       property: "color-scheme";
       value: ColorScheme;
     }
+  | {
+      property: "math-depth";
+      value: MathDepth;
+    }
+  | {
+      property: "math-style";
+      value: MathStyle;
+    }
+  | {
+      property: "math-shift";
+      value: MathShift;
+    }
   | {
       property: "all";
       value: CSSWideKeyword;
@@ -3834,6 +4787,10 @@ export type Image =
   | {
       type: "image-set";
       value: ImageSet;
+    }
+  | {
+      type: "paint";
+      value: PaintFunction;
     };
 /**
  * A CSS [`<gradient>`](https://www.w3.org/TR/css-images-3/#gradients) value.
@@ -4088,6 +5045,14 @@ export type CalcFor_DimensionPercentageFor_LengthValue =
   | {
       type: "function";
       value: MathFunctionFor_DimensionPercentageFor_LengthValue;
+    }
+  | {
+      type: "multiply";
+      /**
+       * @minItems 2
+       * @maxItems 2
+       */
+      value: [CalcFor_DimensionPercentageFor_LengthValue, CalcFor_DimensionPercentageFor_LengthValue];
     };
 /**
  * A CSS [math function](https://www.w3.org/TR/css-values-4/#math-function).
@@ -4154,6 +5119,20 @@ export type MathFunctionFor_DimensionPercentageFor_LengthValue =
   | {
       type: "hypot";
       value: CalcFor_DimensionPercentageFor_LengthValue[];
+    }
+  | {
+      type: "anchor";
+      value: AnchorFunctionFor_DimensionPercentageFor_LengthValue;
+    }
+  | {
+      type: "anchor-size";
+      value: AnchorSizeFunctionFor_DimensionPercentageFor_LengthValue;
+    }
+  | {
+      type: "sibling-index";
+    }
+  | {
+      type: "sibling-count";
     };
 /**
  * A component within a [Position](Position) value, representing a position along either the horizontal or vertical axis of a box.
@@ -4330,6 +5309,14 @@ export type CalcFor_DimensionPercentageFor_Angle =
   | {
       type: "function";
       value: MathFunctionFor_DimensionPercentageFor_Angle;
+    }
+  | {
+      type: "multiply";
+      /**
+       * @minItems 2
+       * @maxItems 2
+       */
+      value: [CalcFor_DimensionPercentageFor_Angle, CalcFor_DimensionPercentageFor_Angle];
     };
 /**
  * A CSS [math function](https://www.w3.org/TR/css-values-4/#math-function).
@@ -4396,6 +5383,20 @@ export type MathFunctionFor_DimensionPercentageFor_Angle =
   | {
       type: "hypot";
       value: CalcFor_DimensionPercentageFor_Angle[];
+    }
+  | {
+      type: "anchor";
+      value: AnchorFunctionFor_DimensionPercentageFor_Angle;
+    }
+  | {
+      type: "anchor-size";
+      value: AnchorSizeFunctionFor_DimensionPercentageFor_Angle;
+    }
+  | {
+      type: "sibling-index";
+    }
+  | {
+      type: "sibling-count";
     };
 /**
  * A keyword or number within a [WebKitGradientPoint](WebKitGradientPoint).
@@ -4567,6 +5568,17 @@ export type DisplayOutside = "block" | "inline" | "run-in";
  * A value for the [visibility](https://drafts.csswg.org/css-display-3/#visibility) property.
  */
 export type Visibility = "visible" | "hidden" | "collapse";
+/**
+ * A value for the [reading-flow](https://drafts.csswg.org/css-display-4/#reading-flow) property.
+ */
+export type ReadingFlow =
+  | "normal"
+  | "source-order"
+  | "flex-visual"
+  | "flex-flow"
+  | "grid-rows"
+  | "grid-columns"
+  | "grid-order";
 /**
  * A value for the [preferred size properties](https://drafts.csswg.org/css-sizing-3/#preferred-size-properties), i.e. `width` and `height.
  */
@@ -4643,10 +5655,54 @@ export type BoxSizing = "content-box" | "border-box";
  * An [overflow](https://www.w3.org/TR/css-overflow-3/#overflow-properties) keyword as used in the `overflow-x`, `overflow-y`, and `overflow` properties.
  */
 export type OverflowKeyword = "visible" | "hidden" | "clip" | "scroll" | "auto";
+/**
+ * An [overscroll-behavior](https://drafts.csswg.org/css-overscroll-1/#overscroll-behavior-properties) keyword as used in the `overscroll-behavior-x`, `overscroll-behavior-y`, and `overscroll-behavior` properties.
+ */
+export type OverscrollBehaviorKeyword = "auto" | "contain" | "none";
 /**
  * A value for the [text-overflow](https://www.w3.org/TR/css-overflow-3/#text-overflow) property.
  */
 export type TextOverflow = "clip" | "ellipsis";
+/**
+ * A value for the [line-clamp](https://drafts.csswg.org/css-overflow-4/#line-clamp) property.
+ */
+export type LineClamp =
+  | {
+      type: "none";
+    }
+  | {
+      /**
+       * The ellipsis inserted at the end of the last line.
+       */
+      blockEllipsis: BlockEllipsis;
+      /**
+       * The maximum number of lines.
+       */
+      lines: number;
+      type: "lines";
+    };
+/**
+ * A value for the [block-ellipsis](https://drafts.csswg.org/css-overflow-4/#block-ellipsis) component of the `line-clamp` property.
+ */
+export type BlockEllipsis =
+  | {
+      type: "auto";
+    }
+  | {
+      type: "no-ellipsis";
+    }
+  | {
+      type: "string";
+      value: String;
+    };
+/**
+ * A value for the [continue](https://drafts.csswg.org/css-overflow-4/#continue) property.
+ */
+export type Continue = "auto" | "discard" | "collapse";
+/**
+ * A [`<visual-box>`](https://drafts.csswg.org/css-box-4/#typedef-visual-box) value, as used in the `overflow-clip-margin` property.
+ */
+export type VisualBox = "content-box" | "padding-box" | "border-box";
 /**
  * A value for the [position](https://www.w3.org/TR/css-position-3/#position-property) property.
  */
@@ -4667,6 +5723,137 @@ export type Position2 =
   | {
       type: "fixed";
     };
+/**
+ * A value for the [anchor-name](https://drafts.csswg.org/css-anchor-position-1/#name) property.
+ */
+export type AnchorName =
+  | {
+      type: "none";
+    }
+  | {
+      type: "names";
+      value: String[];
+    };
+/**
+ * A value for the [position-anchor](https://drafts.csswg.org/css-anchor-position-1/#position-anchor) property.
+ */
+export type PositionAnchor =
+  | {
+      type: "auto";
+    }
+  | {
+      type: "name";
+      value: DashedIdentReference;
+    };
+/**
+ * A value for the [position-area](https://drafts.csswg.org/css-anchor-position-1/#position-area) property.
+ *
+ * Values are normalized when parsed: keywords for the x or block axis are listed first, and a second keyword is omitted when it is implied by the first.
+ */
+export type PositionArea =
+  | {
+      type: "none";
+    }
+  | {
+      type: "area";
+      /**
+       * @minItems 2
+       * @maxItems 2
+       */
+      value: [PositionAreaKeyword, PositionAreaKeyword | null];
+    };
+/**
+ * A keyword in a [`<position-area>`](https://drafts.csswg.org/css-anchor-position-1/#typedef-position-area) value.
+ */
+export type PositionAreaKeyword =
+  | "center"
+  | "span-all"
+  | "left"
+  | "right"
+  | "span-left"
+  | "span-right"
+  | "x-start"
+  | "x-end"
+  | "span-x-start"
+  | "span-x-end"
+  | "x-self-start"
+  | "x-self-end"
+  | "span-x-self-start"
+  | "span-x-self-end"
+  | "top"
+  | "bottom"
+  | "span-top"
+  | "span-bottom"
+  | "y-start"
+  | "y-end"
+  | "span-y-start"
+  | "span-y-end"
+  | "y-self-start"
+  | "y-self-end"
+  | "span-y-self-start"
+  | "span-y-self-end"
+  | "block-start"
+  | "block-end"
+  | "span-block-start"
+  | "span-block-end"
+  | "inline-start"
+  | "inline-end"
+  | "span-inline-start"
+  | "span-inline-end"
+  | "self-block-start"
+  | "self-block-end"
+  | "span-self-block-start"
+  | "span-self-block-end"
+  | "self-inline-start"
+  | "self-inline-end"
+  | "span-self-inline-start"
+  | "span-self-inline-end"
+  | "start"
+  | "end"
+  | "span-start"
+  | "span-end"
+  | "self-start"
+  | "self-end"
+  | "span-self-start"
+  | "span-self-end";
+/**
+ * A value for the [position-try-order](https://drafts.csswg.org/css-anchor-position-1/#position-try-order-property) property.
+ */
+export type PositionTryOrder = "normal" | "most-width" | "most-height" | "most-block-size" | "most-inline-size";
+/**
+ * A value for the [position-try-fallbacks](https://drafts.csswg.org/css-anchor-position-1/#position-try-fallbacks) property.
+ */
+export type PositionTryFallbacks =
+  | {
+      type: "none";
+    }
+  | {
+      type: "fallbacks";
+      value: PositionTryFallback[];
+    };
+/**
+ * A fallback position in the [position-try-fallbacks](https://drafts.csswg.org/css-anchor-position-1/#position-try-fallbacks) property.
+ */
+export type PositionTryFallback =
+  | {
+      /**
+       * The name of the `@position-try` rule.
+       */
+      name: String;
+      /**
+       * The try tactic applied to the rule.
+       */
+      tactic: TryTactic;
+      type: "name";
+    }
+  | {
+      type: "tactic";
+      value: TryTactic;
+    }
+  | {
+      type: "position-area";
+      value: PositionArea;
+    };
 /**
  * A generic value that represents a value with two components, e.g. a border radius.
  *
@@ -4719,6 +5906,34 @@ export type Size2DFor_DimensionPercentageFor_LengthValue = [
   DimensionPercentageFor_LengthValue,
   DimensionPercentageFor_LengthValue
 ];
+/**
+ * A [`<corner-shape-value>`](https://drafts.csswg.org/css-borders-4/#typedef-corner-shape-value), as used in the `corner-shape` property and its longhands.
+ *
+ * `superellipse()` values that are equivalent to a keyword are parsed as that keyword.
+ */
+export type CornerShapeValue =
+  | {
+      type: "round";
+    }
+  | {
+      type: "scoop";
+    }
+  | {
+      type: "bevel";
+    }
+  | {
+      type: "notch";
+    }
+  | {
+      type: "square";
+    }
+  | {
+      type: "squircle";
+    }
+  | {
+      type: "superellipse";
+      value: number;
+    };
 /**
  * A generic value that represents a value for four sides of a box, e.g. border-width, margin, padding, etc.
  *
@@ -5225,12 +6440,36 @@ export type GridLine =
       type: "span";
     };
 /**
- * A value for the [font-weight](https://www.w3.org/TR/css-fonts-4/#font-weight-prop) property.
+ * A value for the [scroll-start](https://drafts.csswg.org/css-scroll-snap-2/#scroll-start) longhand properties, i.e. `scroll-start-block`, `scroll-start-inline`, `scroll-start-x`, and `scroll-start-y`.
  */
-export type FontWeight =
+export type ScrollStartValue =
   | {
-      type: "absolute";
-      value: AbsoluteFontWeight;
+      type: "keyword";
+      value: ScrollStartKeyword;
+    }
+  | {
+      type: "length-percentage";
+      value: DimensionPercentageFor_LengthValue;
+    };
+/**
+ * A keyword for the [scroll-start](https://drafts.csswg.org/css-scroll-snap-2/#scroll-start) properties.
+ */
+export type ScrollStartKeyword = "auto" | "start" | "end" | "center" | "left" | "right" | "top" | "bottom";
+/**
+ * A value for the [scroll-start-target](https://drafts.csswg.org/css-scroll-snap-2/#scroll-start-target) longhand properties, i.e. `scroll-start-target-block`, `scroll-start-target-inline`, `scroll-start-target-x`, and `scroll-start-target-y`.
+ */
+export type ScrollStartTargetValue = "none" | "auto";
+/**
+ * A value for the [scroll-initial-target](https://drafts.csswg.org/css-scroll-snap-2/#scroll-initial-target) property.
+ */
+export type ScrollInitialTarget = "none" | "nearest";
+/**
+ * A value for the [font-weight](https://www.w3.org/TR/css-fonts-4/#font-weight-prop) property.
+ */
+export type FontWeight =
+  | {
+      type: "absolute";
+      value: AbsoluteFontWeight;
     }
   | {
       type: "bolder";
@@ -5371,6 +6610,50 @@ export type FontVariantCaps =
   | "all-petite-caps"
   | "unicase"
   | "titling-caps";
+/**
+ * A [`<common-lig-values>`](https://drafts.csswg.org/css-fonts-4/#common-lig-values) value, as used in the `font-variant-ligatures` property.
+ */
+export type CommonLigValues = "common-ligatures" | "no-common-ligatures";
+/**
+ * A [`<contextual-alt-values>`](https://drafts.csswg.org/css-fonts-4/#contextual-alt-values) value, as used in the `font-variant-ligatures` property.
+ */
+export type ContextualAltValues = "contextual" | "no-contextual";
+/**
+ * A [`<discretionary-lig-values>`](https://drafts.csswg.org/css-fonts-4/#discretionary-lig-values) value, as used in the `font-variant-ligatures` property.
+ */
+export type DiscretionaryLigValues = "discretionary-ligatures" | "no-discretionary-ligatures";
+/**
+ * A [`<historical-lig-values>`](https://drafts.csswg.org/css-fonts-4/#historical-lig-values) value, as used in the `font-variant-ligatures` property.
+ */
+export type HistoricalLigValues = "historical-ligatures" | "no-historical-ligatures";
+/**
+ * A [`<numeric-figure-values>`](https://drafts.csswg.org/css-fonts-4/#numeric-figure-values) value, as used in the `font-variant-numeric` property.
+ */
+export type NumericFigureValues = "lining-nums" | "oldstyle-nums";
+/**
+ * A [`<numeric-fraction-values>`](https://drafts.csswg.org/css-fonts-4/#numeric-fraction-values) value, as used in the `font-variant-numeric` property.
+ */
+export type NumericFractionValues = "diagonal-fractions" | "stacked-fractions";
+/**
+ * A [`<numeric-spacing-values>`](https://drafts.csswg.org/css-fonts-4/#numeric-spacing-values) value, as used in the `font-variant-numeric` property.
+ */
+export type NumericSpacingValues = "proportional-nums" | "tabular-nums";
+/**
+ * A [`<east-asian-variant-values>`](https://drafts.csswg.org/css-fonts-4/#east-asian-variant-values) value, as used in the `font-variant-east-asian` property.
+ */
+export type EastAsianVariantValues = "jis78" | "jis83" | "jis90" | "jis04" | "simplified" | "traditional";
+/**
+ * A [`<east-asian-width-values>`](https://drafts.csswg.org/css-fonts-4/#east-asian-width-values) value, as used in the `font-variant-east-asian` property.
+ */
+export type EastAsianWidthValues = "full-width" | "proportional-width";
+/**
+ * A value for the [font-variant-position](https://drafts.csswg.org/css-fonts-4/#font-variant-position-prop) property.
+ */
+export type FontVariantPosition = "normal" | "sub" | "super";
+/**
+ * A value for the [font-variant-emoji](https://drafts.csswg.org/css-fonts-4/#font-variant-emoji-prop) property.
+ */
+export type FontVariantEmoji = "normal" | "text" | "emoji" | "unicode";
 /**
  * A value for the [line-height](https://www.w3.org/TR/2020/WD-css-inline-3-20200827/#propdef-line-height) property.
  */
@@ -5410,6 +6693,60 @@ export type VerticalAlignKeyword =
   | "middle"
   | "bottom"
   | "text-bottom";
+/**
+ * A value for the [font-palette](https://drafts.csswg.org/css-fonts-4/#font-palette-prop) property.
+ */
+export type FontPalette =
+  | {
+      type: "normal";
+    }
+  | {
+      type: "light";
+    }
+  | {
+      type: "dark";
+    }
+  | {
+      type: "custom";
+      value: DashedIdentReference;
+    };
+/**
+ * A value for the [font-size-adjust](https://drafts.csswg.org/css-fonts-5/#font-size-adjust-prop) property.
+ */
+export type FontSizeAdjust =
+  | {
+      type: "none";
+    }
+  | {
+      /**
+       * The font metric to normalize.
+       */
+      metric: FontMetric;
+      type: "metric";
+      /**
+       * The aspect value.
+       */
+      value: FontSizeAdjustValue;
+    };
+/**
+ * A font metric used by the [font-size-adjust](https://drafts.csswg.org/css-fonts-5/#font-size-adjust-prop) property.
+ */
+export type FontMetric = "ex-height" | "cap-height" | "ch-width" | "ic-width" | "ic-height";
+/**
+ * The aspect value of a [font-size-adjust](https://drafts.csswg.org/css-fonts-5/#font-size-adjust-prop) property.
+ */
+export type FontSizeAdjustValue =
+  | {
+      type: "from-font";
+    }
+  | {
+      type: "number";
+      value: number;
+    };
+/**
+ * A value for the [font-synthesis](https://drafts.csswg.org/css-fonts-4/#font-synthesis) longhand properties, e.g. `font-synthesis-weight`.
+ */
+export type FontSynthesisKeyword = "auto" | "none";
 /**
  * A CSS [easing function](https://www.w3.org/TR/css-easing-1/#easing-functions).
  */
@@ -5475,6 +6812,10 @@ export type StepPosition =
   | {
       type: "jump-both";
     };
+/**
+ * A value for the [transition-behavior](https://drafts.csswg.org/css-transitions-2/#transition-behavior-property) property.
+ */
+export type TransitionBehavior = "normal" | "allow-discrete";
 /**
  * A value for the [animation-iteration-count](https://drafts.csswg.org/css-animations/#animation-iteration-count) property.
  */
@@ -5541,6 +6882,17 @@ export type Scroller = "root" | "nearest" | "self";
  * @maxItems 2
  */
 export type Size2DFor_LengthPercentageOrAuto = [LengthPercentageOrAuto, LengthPercentageOrAuto];
+/**
+ * A value for the [scroll-timeline-name](https://drafts.csswg.org/scroll-animations-1/#scroll-timeline-name) and [view-timeline-name](https://drafts.csswg.org/scroll-animations-1/#view-timeline-name) properties.
+ */
+export type TimelineName =
+  | {
+      type: "none";
+    }
+  | {
+      type: "dashed-ident";
+      value: String;
+    };
 /**
  * An individual [transform function](https://www.w3.org/TR/2019/CR-css-transforms-1-20190214/#two-d-transform-functions).
  */
@@ -5714,14 +7066,174 @@ export type Scale =
      */
     z: NumberOrPercentage;
   };
+/**
+ * A value for the [offset-path](https://drafts.fxtf.org/motion-1/#offset-path-property) property.
+ */
+export type OffsetPath =
+  | {
+      type: "none";
+    }
+  | {
+      type: "url";
+      value: Url;
+    }
+  | {
+      /**
+       * A reference box that the shape is positioned according to.
+       */
+      referenceBox: GeometryBox;
+      /**
+       * The shape of the path.
+       */
+      shape: OffsetPathShape;
+      type: "shape";
+    }
+  | {
+      type: "box";
+      value: GeometryBox;
+    };
+/**
+ * A [`<geometry-box>`](https://www.w3.org/TR/css-masking-1/#typedef-geometry-box) value as used in the `mask-clip` and `clip-path` properties.
+ */
+export type GeometryBox =
+  | "border-box"
+  | "padding-box"
+  | "content-box"
+  | "margin-box"
+  | "fill-box"
+  | "stroke-box"
+  | "view-box";
+/**
+ * A shape used as an [offset path](https://drafts.fxtf.org/motion-1/#typedef-offset-path).
+ */
+export type OffsetPathShape =
+  | {
+      type: "ray";
+      value: Ray;
+    }
+  | {
+      type: "path";
+      value: SVGPath;
+    }
+  | {
+      type: "basic-shape";
+      value: BasicShape;
+    };
+/**
+ * A [`<ray-size>`](https://drafts.fxtf.org/motion-1/#typedef-ray-size) value, which determines the length of a `ray()` path.
+ */
+export type RaySize = "closest-side" | "closest-corner" | "farthest-side" | "farthest-corner" | "sides";
+/**
+ * An [SVG path command](https://svgwg.org/svg2-draft/paths.html#PathData).
+ */
+export type PathCommand =
+  | "move-to"
+  | "close-path"
+  | "line-to"
+  | "horizontal-line-to"
+  | "vertical-line-to"
+  | "curve-to"
+  | "smooth-curve-to"
+  | "quadratic-curve-to"
+  | "smooth-quadratic-curve-to"
+  | "arc";
+/**
+ * A CSS [`<basic-shape>`](https://www.w3.org/TR/css-shapes-1/#basic-shape-functions) value.
+ */
+export type BasicShape =
+  | {
+      type: "inset";
+      value: InsetRect;
+    }
+  | {
+      type: "circle";
+      value: Circle2;
+    }
+  | {
+      type: "ellipse";
+      value: Ellipse2;
+    }
+  | {
+      type: "polygon";
+      value: Polygon;
+    };
+/**
+ * A generic value that represents a value for four sides of a box, e.g. border-width, margin, padding, etc.
+ *
+ * When serialized, as few components as possible are written when there are duplicate values.
+ *
+ * @minItems 4
+ * @maxItems 4
+ */
+export type RectFor_DimensionPercentageFor_LengthValue = [
+  DimensionPercentageFor_LengthValue,
+  DimensionPercentageFor_LengthValue,
+  DimensionPercentageFor_LengthValue,
+  DimensionPercentageFor_LengthValue
+];
+/**
+ * A [`<shape-radius>`](https://www.w3.org/TR/css-shapes-1/#typedef-shape-radius) value that defines the radius of a `circle()` or `ellipse()` shape.
+ */
+export type ShapeRadius =
+  | {
+      type: "length-percentage";
+      value: DimensionPercentageFor_LengthValue;
+    }
+  | {
+      type: "closest-side";
+    }
+  | {
+      type: "farthest-side";
+    };
+/**
+ * A [`<fill-rule>`](https://www.w3.org/TR/css-shapes-1/#typedef-fill-rule) used to determine the interior of a `polygon()` shape.
+ *
+ * See [Polygon](Polygon).
+ */
+export type FillRule = "nonzero" | "evenodd";
+/**
+ * A value for the [offset-anchor](https://drafts.fxtf.org/motion-1/#offset-anchor-property) property.
+ */
+export type OffsetAnchor =
+  | {
+      type: "auto";
+    }
+  | {
+      type: "position";
+      value: Position;
+    };
+/**
+ * A value for the [offset-position](https://drafts.fxtf.org/motion-1/#offset-position-property) property.
+ */
+export type OffsetPosition =
+  | {
+      type: "normal";
+    }
+  | {
+      type: "auto";
+    }
+  | {
+      type: "position";
+      value: Position;
+    };
 /**
  * Defines how text case should be transformed in the [text-transform](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#text-transform-property) property.
  */
 export type TextTransformCase = "none" | "uppercase" | "lowercase" | "capitalize";
 /**
- * A value for the [white-space](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#white-space-property) property.
+ * A value for the [white-space-collapse](https://drafts.csswg.org/css-text-4/#white-space-collapsing) property.
  */
-export type WhiteSpace = "normal" | "pre" | "nowrap" | "pre-wrap" | "break-spaces" | "pre-line";
+export type WhiteSpaceCollapse =
+  | "collapse"
+  | "discard"
+  | "preserve"
+  | "preserve-breaks"
+  | "preserve-spaces"
+  | "break-spaces";
+/**
+ * A value for the [text-wrap-mode](https://drafts.csswg.org/css-text-4/#text-wrap-mode) property.
+ */
+export type TextWrapMode = "wrap" | "nowrap";
 /**
  * A value for the [word-break](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#word-break-property) property.
  */
@@ -5761,6 +7273,38 @@ export type Spacing =
       type: "length";
       value: Length;
     };
+/**
+ * A value for the [text-spacing-trim](https://drafts.csswg.org/css-text-4/#text-spacing-trim-property) property.
+ */
+export type TextSpacingTrim = "normal" | "space-all" | "space-first" | "trim-start" | "trim-both" | "trim-all" | "auto";
+/**
+ * A value for the [text-autospace](https://drafts.csswg.org/css-text-4/#text-autospace-property) property.
+ */
+export type TextAutospace =
+  | {
+      type: "normal";
+    }
+  | {
+      type: "auto";
+    }
+  | {
+      type: "no-autospace";
+    }
+  | {
+      /**
+       * The boundaries at which spacing is inserted.
+       */
+      boundaries: AutospaceBoundaries;
+      /**
+       * How spacing interacts with existing spaces.
+       */
+      mode: AutospaceMode;
+      type: "autospace";
+    };
+/**
+ * Defines how autospacing interacts with existing spaces in the [text-autospace](https://drafts.csswg.org/css-text-4/#text-autospace-property) property.
+ */
+export type AutospaceMode = "insert" | "replace";
 export type TextDecorationLine = ExclusiveTextDecorationLine | OtherTextDecorationLine[];
 export type ExclusiveTextDecorationLine = "none" | "spelling-error" | "grammar-error";
 export type OtherTextDecorationLine = "underline" | "overline" | "line-through" | "blink";
@@ -5833,35 +7377,94 @@ export type TextEmphasisPositionHorizontal = "left" | "right";
  */
 export type TextEmphasisPositionVertical = "over" | "under";
 /**
- * A value for the [text-size-adjust](https://w3c.github.io/csswg-drafts/css-size-adjust/#adjustment-control) property.
+ * A value for the [text-box-trim](https://drafts.csswg.org/css-inline-3/#text-box-trim) property.
  */
-export type TextSizeAdjust =
+export type TextBoxTrim = "none" | "trim-start" | "trim-end" | "trim-both";
+/**
+ * A value for the [text-box-edge](https://drafts.csswg.org/css-inline-3/#text-box-edge) property.
+ */
+export type TextBoxEdge =
   | {
       type: "auto";
     }
   | {
-      type: "none";
-    }
-  | {
-      type: "percentage";
-      value: number;
+      type: "edge";
+      value: TextEdge;
     };
 /**
- * A value for the [direction](https://drafts.csswg.org/css-writing-modes-3/#direction) property.
+ * A metric used for the over (block-start) edge in a [`<text-edge>`](https://drafts.csswg.org/css-inline-3/#typedef-text-edge) value.
  */
-export type Direction2 = "ltr" | "rtl";
+export type TextEdgeOver = "text" | "ideographic" | "ideographic-ink" | "cap" | "ex";
 /**
- * A value for the [unicode-bidi](https://drafts.csswg.org/css-writing-modes-3/#unicode-bidi) property.
+ * A metric used for the under (block-end) edge in a [`<text-edge>`](https://drafts.csswg.org/css-inline-3/#typedef-text-edge) value.
  */
-export type UnicodeBidi = "normal" | "embed" | "isolate" | "bidi-override" | "isolate-override" | "plaintext";
+export type TextEdgeUnder = "text" | "ideographic" | "ideographic-ink" | "alphabetic";
 /**
- * A value for the [box-decoration-break](https://www.w3.org/TR/css-break-3/#break-decoration) property.
+ * A value for the [initial-letter](https://drafts.csswg.org/css-inline-3/#sizing-drop-initials) property.
  */
-export type BoxDecorationBreak = "slice" | "clone";
+export type InitialLetter =
+  | {
+      type: "normal";
+    }
+  | {
+      /**
+       * How far the initial letter sinks.
+       */
+      sink: InitialLetterSink;
+      /**
+       * The size of the initial letter, in lines.
+       */
+      size: number;
+      type: "letter";
+    };
 /**
- * A value for the [resize](https://www.w3.org/TR/2021/WD-css-ui-4-20210316/#resize) property.
+ * The sink of an [initial-letter](https://drafts.csswg.org/css-inline-3/#sizing-drop-initials) value.
  */
-export type Resize = "none" | "both" | "horizontal" | "vertical" | "block" | "inline";
+export type InitialLetterSink =
+  | {
+      type: "drop";
+    }
+  | {
+      type: "raise";
+    }
+  | {
+      type: "integer";
+      value: number;
+    };
+/**
+ * An alignment keyword for the [initial-letter-align](https://drafts.csswg.org/css-inline-3/#aligning-initial-letter) property.
+ */
+export type InitialLetterAlignKeyword = "alphabetic" | "ideographic" | "hanging" | "leading";
+/**
+ * A value for the [text-size-adjust](https://w3c.github.io/csswg-drafts/css-size-adjust/#adjustment-control) property.
+ */
+export type TextSizeAdjust =
+  | {
+      type: "auto";
+    }
+  | {
+      type: "none";
+    }
+  | {
+      type: "percentage";
+      value: number;
+    };
+/**
+ * A value for the [direction](https://drafts.csswg.org/css-writing-modes-3/#direction) property.
+ */
+export type Direction2 = "ltr" | "rtl";
+/**
+ * A value for the [unicode-bidi](https://drafts.csswg.org/css-writing-modes-3/#unicode-bidi) property.
+ */
+export type UnicodeBidi = "normal" | "embed" | "isolate" | "bidi-override" | "isolate-override" | "plaintext";
+/**
+ * A value for the [box-decoration-break](https://www.w3.org/TR/css-break-3/#break-decoration) property.
+ */
+export type BoxDecorationBreak = "slice" | "clone";
+/**
+ * A value for the [resize](https://www.w3.org/TR/2021/WD-css-ui-4-20210316/#resize) property.
+ */
+export type Resize = "none" | "both" | "horizontal" | "vertical" | "block" | "inline";
 /**
  * A pre-defined [cursor](https://www.w3.org/TR/2021/WD-css-ui-4-20210316/#cursor) value, used in the `cursor` property.
  *
@@ -5924,6 +7527,40 @@ export type CaretShape = "auto" | "bar" | "block" | "underscore";
  */
 export type UserSelect = "auto" | "text" | "none" | "contain" | "all";
 export type Appearance = string;
+/**
+ * A value for the [field-sizing](https://drafts.csswg.org/css-ui-4/#field-sizing) property.
+ */
+export type FieldSizing = "content" | "fixed";
+/**
+ * A value for the [scrollbar-width](https://drafts.csswg.org/css-scrollbars-1/#scrollbar-width) property.
+ */
+export type ScrollbarWidth = "auto" | "thin" | "none";
+/**
+ * A value for the [scrollbar-color](https://drafts.csswg.org/css-scrollbars-1/#scrollbar-color) property.
+ */
+export type ScrollbarColor =
+  | {
+      type: "auto";
+    }
+  | {
+      /**
+       * The color of the scrollbar thumb.
+       */
+      thumb: CssColor;
+      /**
+       * The color of the scrollbar track.
+       */
+      track: CssColor;
+      type: "colors";
+    };
+/**
+ * A value for the nonstandard [-webkit-touch-callout](https://developer.mozilla.org/en-US/docs/Web/CSS/-webkit-touch-callout) property.
+ */
+export type WebKitTouchCallout = "default" | "none";
+/**
+ * A value for the nonstandard [-webkit-overflow-scrolling](https://developer.mozilla.org/en-US/docs/Web/CSS/-webkit-overflow-scrolling) property.
+ */
+export type WebKitOverflowScrolling = "auto" | "touch";
 /**
  * A value for the [list-style-type](https://www.w3.org/TR/2020/WD-css-lists-3-20201117/#text-markers) property.
  */
@@ -6089,12 +7726,6 @@ export type SVGPaintFallback =
       type: "color";
       value: CssColor;
     };
-/**
- * A [`<fill-rule>`](https://www.w3.org/TR/css-shapes-1/#typedef-fill-rule) used to determine the interior of a `polygon()` shape.
- *
- * See [Polygon](Polygon).
- */
-export type FillRule = "nonzero" | "evenodd";
 /**
  * A value for the [stroke-linecap](https://www.w3.org/TR/SVG2/painting.html#LineCaps) property.
  */
@@ -6145,6 +7776,34 @@ export type TextRendering = "auto" | "optimizespeed" | "optimizelegibility" | "g
  * A value for the [image-rendering](https://www.w3.org/TR/SVG2/painting.html#ImageRendering) property.
  */
 export type ImageRendering = "auto" | "optimizespeed" | "optimizequality";
+/**
+ * A value for the [vector-effect](https://www.w3.org/TR/SVG2/coords.html#VectorEffects) property.
+ */
+export type VectorEffect = "none" | "non-scaling-stroke" | "non-scaling-size" | "non-rotation" | "fixed-position";
+/**
+ * A value for the [dominant-baseline](https://drafts.csswg.org/css-inline-3/#dominant-baseline-property) property.
+ */
+export type DominantBaseline =
+  | "auto"
+  | "text-bottom"
+  | "alphabetic"
+  | "ideographic"
+  | "middle"
+  | "central"
+  | "mathematical"
+  | "hanging"
+  | "text-top";
+/**
+ * A value for the [d](https://svgwg.org/svg2-draft/paths.html#TheDProperty) property.
+ */
+export type PathData =
+  | {
+      type: "none";
+    }
+  | {
+      type: "path";
+      value: SVGPath;
+    };
 /**
  * A value for the [clip-path](https://www.w3.org/TR/css-masking-1/#the-clip-path) property.
  */
@@ -6171,65 +7830,6 @@ export type ClipPath =
       type: "box";
       value: GeometryBox;
     };
-/**
- * A [`<geometry-box>`](https://www.w3.org/TR/css-masking-1/#typedef-geometry-box) value as used in the `mask-clip` and `clip-path` properties.
- */
-export type GeometryBox =
-  | "border-box"
-  | "padding-box"
-  | "content-box"
-  | "margin-box"
-  | "fill-box"
-  | "stroke-box"
-  | "view-box";
-/**
- * A CSS [`<basic-shape>`](https://www.w3.org/TR/css-shapes-1/#basic-shape-functions) value.
- */
-export type BasicShape =
-  | {
-      type: "inset";
-      value: InsetRect;
-    }
-  | {
-      type: "circle";
-      value: Circle2;
-    }
-  | {
-      type: "ellipse";
-      value: Ellipse2;
-    }
-  | {
-      type: "polygon";
-      value: Polygon;
-    };
-/**
- * A generic value that represents a value for four sides of a box, e.g. border-width, margin, padding, etc.
- *
- * When serialized, as few components as possible are written when there are duplicate values.
- *
- * @minItems 4
- * @maxItems 4
- */
-export type RectFor_DimensionPercentageFor_LengthValue = [
-  DimensionPercentageFor_LengthValue,
-  DimensionPercentageFor_LengthValue,
-  DimensionPercentageFor_LengthValue,
-  DimensionPercentageFor_LengthValue
-];
-/**
- * A [`<shape-radius>`](https://www.w3.org/TR/css-shapes-1/#typedef-shape-radius) value that defines the radius of a `circle()` or `ellipse()` shape.
- */
-export type ShapeRadius =
-  | {
-      type: "length-percentage";
-      value: DimensionPercentageFor_LengthValue;
-    }
-  | {
-      type: "closest-side";
-    }
-  | {
-      type: "farthest-side";
-    };
 /**
  * A value for the [mask-mode](https://www.w3.org/TR/css-masking-1/#the-mask-mode) property.
  */
@@ -6333,6 +7933,17 @@ export type Filter =
       type: "url";
       value: Url;
     };
+/**
+ * A value for the [zoom](https://drafts.csswg.org/css-viewport/#zoom-property) property.
+ */
+export type Zoom =
+  | {
+      type: "normal";
+    }
+  | {
+      type: "factor";
+      value: NumberOrPercentage;
+    };
 /**
  * A value for the [z-index](https://drafts.csswg.org/css2/#z-index) property.
  */
@@ -6344,6 +7955,12 @@ export type ZIndex =
       type: "integer";
       value: number;
     };
+/**
+ * A value for the [overlay](https://drafts.csswg.org/css-position-4/#overlay) property.
+ *
+ * This is set by the user agent for elements in the top layer, and can only be transitioned by authors.
+ */
+export type Overlay = "none" | "auto";
 /**
  * A value for the [container-type](https://drafts.csswg.org/css-contain-3/#container-type) property. Establishes the element as a query container for the purpose of container queries.
  */
@@ -6359,6 +7976,57 @@ export type ContainerNameList =
       type: "names";
       value: String[];
     };
+/**
+ * A value for the [view-transition-name](https://drafts.csswg.org/css-view-transitions-1/#view-transition-name-prop) property.
+ */
+export type ViewTransitionName =
+  | {
+      type: "none";
+    }
+  | {
+      type: "auto";
+    }
+  | {
+      type: "match-element";
+    }
+  | {
+      type: "custom";
+      value: String;
+    };
+/**
+ * A value for the [view-transition-class](https://drafts.csswg.org/css-view-transitions-2/#view-transition-class-prop) property.
+ */
+export type ViewTransitionClass =
+  | {
+      type: "none";
+    }
+  | {
+      type: "classes";
+      value: String[];
+    };
+/**
+ * A value for the [math-depth](https://w3c.github.io/mathml-core/#the-math-script-level-property) property.
+ */
+export type MathDepth =
+  | {
+      type: "auto-add";
+    }
+  | {
+      type: "add";
+      value: number;
+    }
+  | {
+      type: "integer";
+      value: number;
+    };
+/**
+ * A value for the [math-style](https://w3c.github.io/mathml-core/#the-math-style-property) property.
+ */
+export type MathStyle = "normal" | "compact";
+/**
+ * A value for the [math-shift](https://w3c.github.io/mathml-core/#the-math-shift) property.
+ */
+export type MathShift = "normal" | "compact";
 /**
  * A [CSS-wide keyword](https://drafts.csswg.org/css-cascade-5/#defaulting-keywords).
  */
@@ -6721,6 +8389,17 @@ export type PseudoClass =
        * The pseudo class name.
        */
       name: String;
+    }
+  | {
+      kind: "custom-selector-function";
+      /**
+       * The pseudo class name.
+       */
+      name: String;
+      /**
+       * The selector arguments of the pseudo class function.
+       */
+      selectors: SelectorList;
     };
 /**
  * The [:dir()](https://drafts.csswg.org/selectors-4/#the-dir-pseudo) pseudo class.
@@ -6817,28 +8496,28 @@ export type PseudoElement =
       /**
        * A part name selector.
        */
-      partName: ViewTransitionPartName;
+      part: ViewTransitionPartSelector;
     }
   | {
       kind: "view-transition-image-pair";
       /**
        * A part name selector.
        */
-      partName: ViewTransitionPartName;
+      part: ViewTransitionPartSelector;
     }
   | {
       kind: "view-transition-old";
       /**
        * A part name selector.
        */
-      partName: ViewTransitionPartName;
+      part: ViewTransitionPartSelector;
     }
   | {
       kind: "view-transition-new";
       /**
        * A part name selector.
        */
-      partName: ViewTransitionPartName;
+      part: ViewTransitionPartSelector;
     }
   | {
       kind: "custom";
@@ -6857,6 +8536,17 @@ export type PseudoElement =
        * The name of the pseudo element.
        */
       name: String;
+    }
+  | {
+      kind: "custom-selector-function";
+      /**
+       * The name of the pseudo element.
+       */
+      name: String;
+      /**
+       * The selector arguments of the pseudo element function.
+       */
+      selectors: SelectorList;
     };
 /**
  * A [webkit scrollbar](https://webkit.org/blog/363/styling-scrollbars/) pseudo element.
@@ -6885,7 +8575,15 @@ export type KeyframeSelector =
     }
   | {
       type: "to";
+    }
+  | {
+      type: "timeline-range-percentage";
+      value: TimelineRangePercentage;
     };
+/**
+ * A [view progress timeline range](https://drafts.csswg.org/scroll-animations-1/#view-timelines-ranges) name.
+ */
+export type TimelineRangeName = "cover" | "contain" | "entry" | "exit" | "entry-crossing" | "exit-crossing";
 /**
  * KeyframesName
  */
@@ -6944,50 +8642,6 @@ export type Source =
       type: "local";
       value: FontFamily;
     };
-/**
- * A font format keyword in the `format()` function of the the [src](https://drafts.csswg.org/css-fonts/#src-desc) property of an `@font-face` rule.
- */
-export type FontFormat =
-  | {
-      type: "woff";
-    }
-  | {
-      type: "woff2";
-    }
-  | {
-      type: "truetype";
-    }
-  | {
-      type: "opentype";
-    }
-  | {
-      type: "embedded-opentype";
-    }
-  | {
-      type: "collection";
-    }
-  | {
-      type: "svg";
-    }
-  | {
-      type: "string";
-      value: String;
-    };
-/**
- * A font format keyword in the `format()` function of the the [src](https://drafts.csswg.org/css-fonts/#src-desc) property of an `@font-face` rule.
- */
-export type FontTechnology =
-  | "features-opentype"
-  | "features-aat"
-  | "features-graphite"
-  | "color-colrv0"
-  | "color-colrv1"
-  | "color-svg"
-  | "color-sbix"
-  | "color-cbdt"
-  | "variations"
-  | "palettes"
-  | "incremental";
 /**
  * A value for the [font-style](https://w3c.github.io/csswg-drafts/css-fonts/#descdef-font-face-font-style) descriptor in an `@font-face` rule.
  */
@@ -7066,14 +8720,51 @@ export type BasePalette =
       value: number;
     };
 /**
- * A [page margin box](https://www.w3.org/TR/css-page-3/#margin-boxes).
+ * The name of an `@color-profile` rule.
  */
-export type PageMarginBox =
-  | "top-left-corner"
-  | "top-left"
-  | "top-center"
-  | "top-right"
-  | "top-right-corner"
+export type ColorProfileName =
+  | {
+      type: "dashed";
+      value: String;
+    }
+  | {
+      type: "device-cmyk";
+    };
+/**
+ * A property within a `@color-profile` rule.
+ *
+ * See [ColorProfileRule](ColorProfileRule).
+ */
+export type ColorProfileProperty =
+  | {
+      type: "src";
+      value: Url;
+    }
+  | {
+      type: "rendering-intent";
+      value: RenderingIntent;
+    }
+  | {
+      type: "components";
+      value: String[];
+    }
+  | {
+      type: "custom";
+      value: CustomProperty;
+    };
+/**
+ * A value for the [rendering-intent](https://drafts.csswg.org/css-color-5/#descdef-color-profile-rendering-intent) property in a `@color-profile` rule.
+ */
+export type RenderingIntent = "relativecolorimetric" | "absolutecolorimetric" | "perceptual" | "saturation";
+/**
+ * A [page margin box](https://www.w3.org/TR/css-page-3/#margin-boxes).
+ */
+export type PageMarginBox =
+  | "top-left-corner"
+  | "top-left"
+  | "top-center"
+  | "top-right"
+  | "top-right-corner"
   | "left-top"
   | "left-middle"
   | "left-bottom"
@@ -7092,159 +8783,223 @@ export type PageMarginBox =
  */
 export type PagePseudoClass = "left" | "right" | "first" | "last" | "blank";
 /**
- * A parsed value for a [SyntaxComponent](SyntaxComponent).
+ * A property within a `@counter-style` rule.
+ *
+ * See [CounterStyleRule](CounterStyleRule).
  */
-export type ParsedComponent =
+export type CounterStyleProperty =
   | {
-      type: "length";
-      value: Length;
+      type: "system";
+      value: CounterSystem;
     }
   | {
-      type: "number";
-      value: number;
+      type: "negative";
+      value: CounterNegative;
     }
   | {
-      type: "percentage";
-      value: number;
+      type: "prefix";
+      value: CounterSymbol;
     }
   | {
-      type: "length-percentage";
-      value: DimensionPercentageFor_LengthValue;
+      type: "suffix";
+      value: CounterSymbol;
     }
   | {
-      type: "color";
-      value: CssColor;
+      type: "range";
+      value: CounterRange;
     }
   | {
-      type: "image";
-      value: Image;
+      type: "pad";
+      value: CounterPad;
     }
   | {
-      type: "url";
-      value: Url;
+      type: "fallback";
+      value: CounterStyle;
     }
   | {
-      type: "integer";
-      value: number;
+      type: "symbols";
+      value: CounterSymbol[];
     }
   | {
-      type: "angle";
-      value: Angle;
+      type: "additive-symbols";
+      value: AdditiveSymbol[];
     }
   | {
-      type: "time";
-      value: Time;
+      type: "speak-as";
+      value: SpeakAs;
     }
   | {
-      type: "resolution";
-      value: Resolution;
+      type: "custom";
+      value: CustomProperty;
+    };
+/**
+ * A value for the [system](https://drafts.csswg.org/css-counter-styles/#counter-style-system) property in a `@counter-style` rule.
+ */
+export type CounterSystem =
+  | {
+      type: "cyclic";
     }
   | {
-      type: "transform-function";
-      value: Transform;
+      type: "numeric";
     }
   | {
-      type: "transform-list";
-      value: Transform[];
+      type: "alphabetic";
     }
   | {
-      type: "custom-ident";
-      value: String;
+      type: "symbolic";
     }
   | {
-      type: "literal";
+      type: "additive";
+    }
+  | {
+      type: "fixed";
+      value: number | null;
+    }
+  | {
+      type: "extends";
+      value: CounterStyle;
+    };
+/**
+ * A [`<symbol>`](https://drafts.csswg.org/css-counter-styles/#typedef-symbol) value, as used in a `@counter-style` rule.
+ */
+export type CounterSymbol =
+  | {
+      type: "string";
       value: String;
     }
   | {
-      type: "repeated";
-      value: {
-        /**
-         * The components to repeat.
-         */
-        components: ParsedComponent[];
-        /**
-         * A multiplier describing how the components repeat.
-         */
-        multiplier: Multiplier;
-      };
+      type: "ident";
+      value: String;
     }
   | {
-      type: "token-list";
-      value: TokenOrValue[];
+      type: "image";
+      value: Image;
     };
 /**
- * A [multiplier](https://drafts.css-houdini.org/css-properties-values-api/#multipliers) for a [SyntaxComponent](SyntaxComponent). Indicates whether and how the component may be repeated.
+ * A value for the [range](https://drafts.csswg.org/css-counter-styles/#counter-style-range) property in a `@counter-style` rule.
  */
-export type Multiplier =
+export type CounterRange =
   | {
-      type: "none";
+      type: "auto";
     }
   | {
-      type: "space";
+      type: "ranges";
+      value: CounterRangeItem[];
+    };
+/**
+ * A bound within a [CounterRangeItem](CounterRangeItem).
+ */
+export type CounterRangeBound =
+  | {
+      type: "infinite";
     }
   | {
-      type: "comma";
+      type: "integer";
+      value: number;
     };
 /**
- * A CSS [syntax string](https://drafts.css-houdini.org/css-properties-values-api/#syntax-strings) used to define the grammar for a registered custom property.
+ * A value for the [speak-as](https://drafts.csswg.org/css-counter-styles/#counter-style-speak-as) property in a `@counter-style` rule.
  */
-export type SyntaxString =
+export type SpeakAs =
   | {
-      type: "components";
-      value: SyntaxComponent[];
+      type: "auto";
     }
   | {
-      type: "universal";
+      type: "bullets";
+    }
+  | {
+      type: "numbers";
+    }
+  | {
+      type: "words";
+    }
+  | {
+      type: "spell-out";
+    }
+  | {
+      type: "counter-style";
+      value: CounterStyle;
     };
 /**
- * A [syntax component component name](https://drafts.css-houdini.org/css-properties-values-api/#supported-names).
+ * A parsed value for a [SyntaxComponent](SyntaxComponent).
  */
-export type SyntaxComponentKind =
+export type ParsedComponent =
   | {
       type: "length";
+      value: Length;
     }
   | {
       type: "number";
+      value: number;
     }
   | {
       type: "percentage";
+      value: number;
     }
   | {
       type: "length-percentage";
+      value: DimensionPercentageFor_LengthValue;
     }
   | {
       type: "color";
+      value: CssColor;
     }
   | {
       type: "image";
+      value: Image;
     }
   | {
       type: "url";
+      value: Url;
     }
   | {
       type: "integer";
+      value: number;
     }
   | {
       type: "angle";
+      value: Angle;
     }
   | {
       type: "time";
+      value: Time;
     }
   | {
       type: "resolution";
+      value: Resolution;
     }
   | {
       type: "transform-function";
+      value: Transform;
     }
   | {
       type: "transform-list";
+      value: Transform[];
     }
   | {
       type: "custom-ident";
+      value: String;
     }
   | {
       type: "literal";
-      value: string;
+      value: String;
+    }
+  | {
+      type: "repeated";
+      value: {
+        /**
+         * The components to repeat.
+         */
+        components: ParsedComponent[];
+        /**
+         * A multiplier describing how the components repeat.
+         */
+        multiplier: Multiplier;
+      };
+    }
+  | {
+      type: "token-list";
+      value: TokenOrValue[];
     };
 /**
  * Represents a container condition.
@@ -7362,6 +9117,62 @@ export type StyleQuery<D = Declaration> = | {
     operator: Operator;
     type: "operation";
   };
+/**
+ * A property within a `@view-transition` rule.
+ *
+ * See [ViewTransitionRule](ViewTransitionRule).
+ */
+export type ViewTransitionProperty =
+  | {
+      type: "navigation";
+      value: Navigation;
+    }
+  | {
+      type: "types";
+      value: ViewTransitionTypes;
+    }
+  | {
+      type: "custom";
+      value: CustomProperty;
+    };
+/**
+ * A value for the [navigation](https://drafts.csswg.org/css-view-transitions-2/#view-transition-navigation-descriptor) property in a `@view-transition` rule.
+ */
+export type Navigation = "none" | "auto";
+/**
+ * A value for the [types](https://drafts.csswg.org/css-view-transitions-2/#types-cross-doc) property in a `@view-transition` rule.
+ */
+export type ViewTransitionTypes =
+  | {
+      type: "none";
+    }
+  | {
+      type: "types";
+      value: String[];
+    };
+/**
+ * A [`<boolean-condition>`](https://drafts.csswg.org/css-conditional-5/#typedef-boolean-condition), as used in the `@when` and `@else` rules.
+ */
+export type WhenCondition<M = MediaQuery> = | {
+    type: "media";
+    value: M;
+  }
+| {
+    type: "supports";
+    value: SupportsCondition;
+  }
+| {
+    type: "not";
+    value: WhenCondition<M>;
+  }
+| {
+    type: "and";
+    value: WhenCondition<M>[];
+  }
+| {
+    type: "or";
+    value: WhenCondition<M>[];
+  };
 export type DefaultAtRule = null;
 
 /**
@@ -7469,6 +9280,40 @@ export interface LengthValue {
    */
   value: number;
 }
+/**
+ * An [`anchor()`](https://drafts.csswg.org/css-anchor-position-1/#anchor-pos) function, which resolves to the position of an edge of an anchor element.
+ */
+export interface AnchorFunctionFor_Length {
+  /**
+   * A fallback value, used when the anchor element cannot be resolved.
+   */
+  fallback?: CalcFor_Length | null;
+  /**
+   * The name of the anchor element. If omitted, the default anchor element is used.
+   */
+  name?: string | null;
+  /**
+   * The side of the anchor element to position against.
+   */
+  side: AnchorSide;
+}
+/**
+ * An [`anchor-size()`](https://drafts.csswg.org/css-anchor-position-1/#anchor-size-fn) function, which resolves to a dimension of an anchor element.
+ */
+export interface AnchorSizeFunctionFor_Length {
+  /**
+   * A fallback value, used when the anchor element cannot be resolved.
+   */
+  fallback?: CalcFor_Length | null;
+  /**
+   * The name of the anchor element. If omitted, the default anchor element is used.
+   */
+  name?: string | null;
+  /**
+   * The dimension of the anchor element. If omitted, the axis of the property is used.
+   */
+  size?: AnchorSizeKeyword | null;
+}
 /**
  * A CSS environment variable reference.
  */
@@ -7486,6 +9331,52 @@ export interface EnvironmentVariable {
    */
   name: EnvironmentVariableName;
 }
+/**
+ * A color in a custom color profile, defined by an [`@color-profile`](https://drafts.csswg.org/css-color-5/#at-profile) rule and referenced by name within the `color()` function, e.g. `color(--swop5c 0% 70% 20% 0%)`.
+ */
+export interface Profile {
+  /**
+   * The alpha component.
+   */
+  alpha: number;
+  /**
+   * The color components. Percentages are stored as numbers between 0 and 1, and missing (i.e. `none`) components are stored as NaN.
+   */
+  components: number[];
+  /**
+   * The name of the color profile, including the leading `--`.
+   */
+  profile: string;
+}
+/**
+ * A color in the uncalibrated [device-cmyk](https://drafts.csswg.org/css-color-5/#device-cmyk) color space.
+ */
+export interface DeviceCmyk {
+  /**
+   * The alpha component.
+   */
+  alpha: number;
+  /**
+   * The cyan component, between 0 and 1.
+   */
+  c: number;
+  /**
+   * An optional fallback color.
+   */
+  fallback?: CssColor | null;
+  /**
+   * The black component, between 0 and 1.
+   */
+  k: number;
+  /**
+   * The magenta component, between 0 and 1.
+   */
+  m: number;
+  /**
+   * The yellow component, between 0 and 1.
+   */
+  y: number;
+}
 /**
  * A CSS [url()](https://www.w3.org/TR/css-values-4/#urls) value and its source location.
  */
@@ -7543,17 +9434,58 @@ export interface DashedIdentReference {
   ident: String;
 }
 /**
- * A custom CSS function.
+ * A CSS [attr()](https://drafts.csswg.org/css-values-5/#attr-notation) reference.
  */
-export interface Function {
+export interface AttrFunction {
   /**
-   * The function arguments.
+   * The type that the attribute value is parsed as.
    */
-  arguments: TokenOrValue[];
+  attrType?: AttrType | null;
   /**
-   * The function name.
+   * A fallback value in case the attribute is missing or invalid.
    */
-  name: String;
+  fallback?: TokenOrValue[] | null;
+  /**
+   * The attribute name.
+   */
+  name: String;
+}
+/**
+ * A [syntax component](https://drafts.css-houdini.org/css-properties-values-api/#syntax-component) within a [SyntaxString](SyntaxString).
+ *
+ * A syntax component consists of a component kind an a multiplier, which indicates how the component may repeat during parsing.
+ */
+export interface SyntaxComponent {
+  /**
+   * The kind of component.
+   */
+  kind: SyntaxComponentKind;
+  /**
+   * A multiplier for the component.
+   */
+  multiplier: Multiplier;
+}
+/**
+ * A CSS [toggle()](https://drafts.csswg.org/css-values-5/#toggle-notation) value.
+ */
+export interface ToggleFunction {
+  /**
+   * The values to cycle between.
+   */
+  values: TokenOrValue[][];
+}
+/**
+ * A custom CSS function.
+ */
+export interface Function {
+  /**
+   * The function arguments.
+   */
+  arguments: TokenOrValue[];
+  /**
+   * The function name.
+   */
+  name: String;
 }
 /**
  * A [@import](https://drafts.csswg.org/css-cascade/#at-import) rule.
@@ -7616,6 +9548,40 @@ export interface DeclarationBlock<D = Declaration> {
    */
   importantDeclarations?: D[];
 }
+/**
+ * An [`anchor()`](https://drafts.csswg.org/css-anchor-position-1/#anchor-pos) function, which resolves to the position of an edge of an anchor element.
+ */
+export interface AnchorFunctionFor_DimensionPercentageFor_LengthValue {
+  /**
+   * A fallback value, used when the anchor element cannot be resolved.
+   */
+  fallback?: CalcFor_DimensionPercentageFor_LengthValue | null;
+  /**
+   * The name of the anchor element. If omitted, the default anchor element is used.
+   */
+  name?: string | null;
+  /**
+   * The side of the anchor element to position against.
+   */
+  side: AnchorSide;
+}
+/**
+ * An [`anchor-size()`](https://drafts.csswg.org/css-anchor-position-1/#anchor-size-fn) function, which resolves to a dimension of an anchor element.
+ */
+export interface AnchorSizeFunctionFor_DimensionPercentageFor_LengthValue {
+  /**
+   * A fallback value, used when the anchor element cannot be resolved.
+   */
+  fallback?: CalcFor_DimensionPercentageFor_LengthValue | null;
+  /**
+   * The name of the anchor element. If omitted, the default anchor element is used.
+   */
+  name?: string | null;
+  /**
+   * The dimension of the anchor element. If omitted, the axis of the property is used.
+   */
+  size?: AnchorSizeKeyword | null;
+}
 /**
  * A CSS [`<position>`](https://www.w3.org/TR/css3-values/#position) value, as used in the `background-position` property, gradients, masks, etc.
  */
@@ -7629,6 +9595,40 @@ export interface Position {
    */
   y: PositionComponentFor_VerticalPositionKeyword;
 }
+/**
+ * An [`anchor()`](https://drafts.csswg.org/css-anchor-position-1/#anchor-pos) function, which resolves to the position of an edge of an anchor element.
+ */
+export interface AnchorFunctionFor_DimensionPercentageFor_Angle {
+  /**
+   * A fallback value, used when the anchor element cannot be resolved.
+   */
+  fallback?: CalcFor_DimensionPercentageFor_Angle | null;
+  /**
+   * The name of the anchor element. If omitted, the default anchor element is used.
+   */
+  name?: string | null;
+  /**
+   * The side of the anchor element to position against.
+   */
+  side: AnchorSide;
+}
+/**
+ * An [`anchor-size()`](https://drafts.csswg.org/css-anchor-position-1/#anchor-size-fn) function, which resolves to a dimension of an anchor element.
+ */
+export interface AnchorSizeFunctionFor_DimensionPercentageFor_Angle {
+  /**
+   * A fallback value, used when the anchor element cannot be resolved.
+   */
+  fallback?: CalcFor_DimensionPercentageFor_Angle | null;
+  /**
+   * The name of the anchor element. If omitted, the default anchor element is used.
+   */
+  name?: string | null;
+  /**
+   * The dimension of the anchor element. If omitted, the axis of the property is used.
+   */
+  size?: AnchorSizeKeyword | null;
+}
 /**
  * An x/y position within a legacy `-webkit-gradient()`.
  */
@@ -7687,6 +9687,19 @@ export interface ImageSetOption {
    */
   resolution: Resolution;
 }
+/**
+ * A CSS [`paint()`](https://drafts.css-houdini.org/css-paint-api/#paint-notation) function, which renders an image using a registered paint worklet.
+ */
+export interface PaintFunction {
+  /**
+   * The arguments passed to the paint worklet.
+   */
+  arguments: TokenOrValue[];
+  /**
+   * The name of the paint worklet.
+   */
+  name: String;
+}
 /**
  * A value for the [background-position](https://drafts.csswg.org/css-backgrounds/#background-position) shorthand property.
  */
@@ -7792,6 +9805,32 @@ export interface AspectRatio {
    */
   ratio?: Ratio | null;
 }
+/**
+ * A value for the [contain-intrinsic-*](https://drafts.csswg.org/css-sizing-4/#intrinsic-size-override) longhand properties, i.e. `contain-intrinsic-width` and `contain-intrinsic-height`.
+ */
+export interface ContainIntrinsicLength {
+  /**
+   * The `auto` keyword, which uses the last remembered size of the element if any.
+   */
+  auto: boolean;
+  /**
+   * The explicit intrinsic size, or `None` for the `none` keyword.
+   */
+  length?: Length | null;
+}
+/**
+ * A value for the [contain-intrinsic-size](https://drafts.csswg.org/css-sizing-4/#propdef-contain-intrinsic-size) shorthand property.
+ */
+export interface ContainIntrinsicSize {
+  /**
+   * The intrinsic height.
+   */
+  height: ContainIntrinsicLength;
+  /**
+   * The intrinsic width.
+   */
+  width: ContainIntrinsicLength;
+}
 /**
  * A value for the [overflow](https://www.w3.org/TR/css-overflow-3/#overflow-properties) shorthand property.
  */
@@ -7805,6 +9844,32 @@ export interface Overflow {
    */
   y: OverflowKeyword;
 }
+/**
+ * A value for the [overscroll-behavior](https://drafts.csswg.org/css-overscroll-1/#overscroll-behavior-properties) shorthand property.
+ */
+export interface OverscrollBehavior {
+  /**
+   * The overscroll behavior for the x direction.
+   */
+  x: OverscrollBehaviorKeyword;
+  /**
+   * The overscroll behavior for the y direction.
+   */
+  y: OverscrollBehaviorKeyword;
+}
+/**
+ * A value for the [overflow-clip-margin](https://drafts.csswg.org/css-overflow-4/#overflow-clip-margin) property.
+ */
+export interface OverflowClipMargin {
+  /**
+   * How far the overflow clip edge is extended outward from the box edge.
+   */
+  length: Length;
+  /**
+   * The box edge from which the overflow clip edge is drawn.
+   */
+  visualBox: VisualBox;
+}
 /**
  * A value for the [inset-block](https://drafts.csswg.org/css-logical/#propdef-inset-block) shorthand property.
  */
@@ -7852,6 +9917,24 @@ export interface Inset {
    */
   top: LengthPercentageOrAuto;
 }
+export interface TryTactic {
+  flipBlock: boolean;
+  flipInline: boolean;
+  flipStart: boolean;
+}
+/**
+ * A value for the [position-try](https://drafts.csswg.org/css-anchor-position-1/#position-try-prop) shorthand property.
+ */
+export interface PositionTry {
+  /**
+   * The fallback positions.
+   */
+  fallbacks: PositionTryFallbacks;
+  /**
+   * The order in which fallback positions are tried.
+   */
+  order: PositionTryOrder;
+}
 /**
  * A value for the [border-radius](https://www.w3.org/TR/css-backgrounds-3/#border-radius) property.
  */
@@ -7873,6 +9956,27 @@ export interface BorderRadius {
    */
   topRight: Size2DFor_DimensionPercentageFor_LengthValue;
 }
+/**
+ * A value for the [corner-shape](https://drafts.csswg.org/css-borders-4/#propdef-corner-shape) shorthand property.
+ */
+export interface CornerShape {
+  /**
+   * The shape of the bottom left corner.
+   */
+  bottomLeft: CornerShapeValue;
+  /**
+   * The shape of the bottom right corner.
+   */
+  bottomRight: CornerShapeValue;
+  /**
+   * The shape of the top left corner.
+   */
+  topLeft: CornerShapeValue;
+  /**
+   * The shape of the top right corner.
+   */
+  topRight: CornerShapeValue;
+}
 /**
  * A value for the [border-image-repeat](https://www.w3.org/TR/css-backgrounds-3/#border-image-repeat) property.
  */
@@ -8495,6 +10599,169 @@ export interface ScrollPadding {
    */
   top: LengthPercentageOrAuto;
 }
+/**
+ * A value for the [scroll-start](https://drafts.csswg.org/css-scroll-snap-2/#scroll-start) shorthand property.
+ */
+export interface ScrollStart {
+  /**
+   * The initial scroll position in the block axis.
+   */
+  block: ScrollStartValue;
+  /**
+   * The initial scroll position in the inline axis.
+   */
+  inline: ScrollStartValue;
+}
+/**
+ * A value for the [scroll-start-target](https://drafts.csswg.org/css-scroll-snap-2/#scroll-start-target) shorthand property.
+ */
+export interface ScrollStartTarget {
+  /**
+   * Whether the element is a scroll start target in the block axis.
+   */
+  block: ScrollStartTargetValue;
+  /**
+   * Whether the element is a scroll start target in the inline axis.
+   */
+  inline: ScrollStartTargetValue;
+}
+/**
+ * A value for the [font-variant-ligatures](https://drafts.csswg.org/css-fonts-4/#font-variant-ligatures-prop) property.
+ *
+ * The `normal` keyword is represented by all fields being `None`, and `none` by all of them being disabled.
+ */
+export interface FontVariantLigatures {
+  /**
+   * Common ligatures, e.g. "fi".
+   */
+  common?: CommonLigValues | null;
+  /**
+   * Contextual alternates.
+   */
+  contextual?: ContextualAltValues | null;
+  /**
+   * Discretionary ligatures, which are specific to the font.
+   */
+  discretionary?: DiscretionaryLigValues | null;
+  /**
+   * Historical ligatures.
+   */
+  historical?: HistoricalLigValues | null;
+}
+/**
+ * A value for the [font-variant-alternates](https://drafts.csswg.org/css-fonts-4/#font-variant-alternates-prop) property.
+ *
+ * The `normal` keyword is represented by the default value. The names refer to feature values defined in `@font-feature-values` rules.
+ */
+export interface FontVariantAlternates {
+  /**
+   * The `annotation()` function.
+   */
+  annotation?: String | null;
+  /**
+   * The `character-variant()` function. Empty if not specified.
+   */
+  characterVariant: String[];
+  /**
+   * Whether historical forms are used.
+   */
+  historicalForms: boolean;
+  /**
+   * The `ornaments()` function.
+   */
+  ornaments?: String | null;
+  /**
+   * The `styleset()` function. Empty if not specified.
+   */
+  styleset: String[];
+  /**
+   * The `stylistic()` function.
+   */
+  stylistic?: String | null;
+  /**
+   * The `swash()` function.
+   */
+  swash?: String | null;
+}
+/**
+ * A value for the [font-variant-numeric](https://drafts.csswg.org/css-fonts-4/#font-variant-numeric-prop) property.
+ *
+ * The `normal` keyword is represented by the default value.
+ */
+export interface FontVariantNumeric {
+  /**
+   * The style of numerals.
+   */
+  figure?: NumericFigureValues | null;
+  /**
+   * The style of fractions.
+   */
+  fraction?: NumericFractionValues | null;
+  /**
+   * Whether ordinal forms are used.
+   */
+  ordinal: boolean;
+  /**
+   * Whether a slashed zero is used.
+   */
+  slashedZero: boolean;
+  /**
+   * The spacing of numerals.
+   */
+  spacing?: NumericSpacingValues | null;
+}
+/**
+ * A value for the [font-variant-east-asian](https://drafts.csswg.org/css-fonts-4/#font-variant-east-asian-prop) property.
+ *
+ * The `normal` keyword is represented by the default value.
+ */
+export interface FontVariantEastAsian {
+  /**
+   * Whether ruby variant glyphs are used.
+   */
+  ruby: boolean;
+  /**
+   * The glyph variant standard.
+   */
+  variant?: EastAsianVariantValues | null;
+  /**
+   * The glyph width.
+   */
+  width?: EastAsianWidthValues | null;
+}
+/**
+ * A value for the [font-variant](https://drafts.csswg.org/css-fonts-4/#font-variant-prop) shorthand property.
+ */
+export interface FontVariant {
+  /**
+   * The alternate glyphs.
+   */
+  alternates: FontVariantAlternates;
+  /**
+   * How the text should be capitalized.
+   */
+  caps: FontVariantCaps;
+  /**
+   * The East Asian glyphs.
+   */
+  eastAsian: FontVariantEastAsian;
+  /**
+   * The emoji presentation.
+   */
+  emoji: FontVariantEmoji;
+  /**
+   * The ligatures and contextual forms.
+   */
+  ligatures: FontVariantLigatures;
+  /**
+   * The numeric glyphs.
+   */
+  numeric: FontVariantNumeric;
+  /**
+   * Superscript and subscript glyphs.
+   */
+  position: FontVariantPosition;
+}
 /**
  * A value for the [font](https://www.w3.org/TR/css-fonts-4/#font-prop) shorthand property.
  */
@@ -8528,10 +10795,35 @@ export interface Font {
    */
   weight: FontWeight;
 }
+/**
+ * A value for the [font-synthesis](https://drafts.csswg.org/css-fonts-4/#font-synthesis) shorthand property.
+ */
+export interface FontSynthesis {
+  /**
+   * Whether superscript and subscript faces may be synthesized.
+   */
+  position: FontSynthesisKeyword;
+  /**
+   * Whether small caps faces may be synthesized.
+   */
+  smallCaps: FontSynthesisKeyword;
+  /**
+   * Whether oblique faces may be synthesized.
+   */
+  style: FontSynthesisKeyword;
+  /**
+   * Whether bold faces may be synthesized.
+   */
+  weight: FontSynthesisKeyword;
+}
 /**
  * A value for the [transition](https://www.w3.org/TR/2018/WD-css-transitions-1-20181011/#transition-shorthand-property) property.
  */
 export interface Transition {
+  /**
+   * Whether the transition applies to discretely animated properties.
+   */
+  behavior: TransitionBehavior;
   /**
    * The delay before the transition starts.
    */
@@ -8616,6 +10908,36 @@ export interface Animation {
    */
   timingFunction: EasingFunction;
 }
+/**
+ * A value for the [scroll-timeline](https://drafts.csswg.org/scroll-animations-1/#scroll-timeline-shorthand) shorthand property.
+ */
+export interface ScrollTimelineDefinition {
+  /**
+   * The scroll axis used for progress of the timeline.
+   */
+  axis: ScrollAxis;
+  /**
+   * The name of the scroll timeline.
+   */
+  name: TimelineName;
+}
+/**
+ * A value for the [view-timeline](https://drafts.csswg.org/scroll-animations-1/#view-timeline-shorthand) shorthand property.
+ */
+export interface ViewTimelineDefinition {
+  /**
+   * The scroll axis used for progress of the timeline.
+   */
+  axis: ScrollAxis;
+  /**
+   * An adjustment of the view progress visibility range.
+   */
+  inset: Size2DFor_LengthPercentageOrAuto;
+  /**
+   * The name of the view timeline.
+   */
+  name: TimelineName;
+}
 /**
  * A 2D matrix.
  */
@@ -8669,6 +10991,166 @@ export interface Rotate {
    */
   z: number;
 }
+/**
+ * A [`ray()`](https://drafts.fxtf.org/motion-1/#ray-function) function, which defines an offset path as a line segment starting at the offset position.
+ */
+export interface Ray {
+  /**
+   * The angle of the ray, measured clockwise from the positive y-axis.
+   */
+  angle: Angle;
+  /**
+   * Whether the ray is shortened so that the element stays within the containing block.
+   */
+  contain: boolean;
+  /**
+   * The starting point of the ray. If not specified, the `offset-position` is used.
+   */
+  position?: Position | null;
+  /**
+   * The length of the ray.
+   */
+  size: RaySize;
+}
+/**
+ * A [path()](https://drafts.csswg.org/css-shapes-1/#funcdef-basic-shape-path) function containing [SVG path data](https://svgwg.org/svg2-draft/paths.html#PathData).
+ *
+ * Consecutive segments with the same command are merged, and the path data is serialized in its most compact form.
+ */
+export interface SVGPath {
+  /**
+   * The segments of the path.
+   */
+  segments: PathSegment[];
+}
+/**
+ * A segment of an [SVGPath](SVGPath), consisting of a command and its arguments.
+ */
+export interface PathSegment {
+  /**
+   * The path command.
+   */
+  command: PathCommand;
+  /**
+   * Whether the coordinates are relative to the current point.
+   */
+  relative: boolean;
+  /**
+   * The arguments of the command. This may contain multiple sets of arguments if the command is repeated.
+   */
+  values: number[];
+}
+/**
+ * An [`inset()`](https://www.w3.org/TR/css-shapes-1/#funcdef-inset) rectangle shape.
+ */
+export interface InsetRect {
+  /**
+   * A corner radius for the rectangle.
+   */
+  radius: BorderRadius;
+  /**
+   * The rectangle.
+   */
+  rect: RectFor_DimensionPercentageFor_LengthValue;
+}
+/**
+ * A [`circle()`](https://www.w3.org/TR/css-shapes-1/#funcdef-circle) shape.
+ */
+export interface Circle2 {
+  /**
+   * The position of the center of the circle.
+   */
+  position: Position;
+  /**
+   * The radius of the circle.
+   */
+  radius: ShapeRadius;
+}
+/**
+ * An [`ellipse()`](https://www.w3.org/TR/css-shapes-1/#funcdef-ellipse) shape.
+ */
+export interface Ellipse2 {
+  /**
+   * The position of the center of the ellipse.
+   */
+  position: Position;
+  /**
+   * The x-radius of the ellipse.
+   */
+  radiusX: ShapeRadius;
+  /**
+   * The y-radius of the ellipse.
+   */
+  radiusY: ShapeRadius;
+}
+/**
+ * A [`polygon()`](https://www.w3.org/TR/css-shapes-1/#funcdef-polygon) shape.
+ */
+export interface Polygon {
+  /**
+   * The fill rule used to determine the interior of the polygon.
+   */
+  fillRule: FillRule;
+  /**
+   * The points of each vertex of the polygon.
+   */
+  points: Point[];
+}
+/**
+ * A point within a `polygon()` shape.
+ *
+ * See [Polygon](Polygon).
+ */
+export interface Point {
+  /**
+   * The x position of the point.
+   */
+  x: DimensionPercentageFor_LengthValue;
+  /**
+   * the y position of the point.
+   */
+  y: DimensionPercentageFor_LengthValue;
+}
+/**
+ * A value for the [offset-rotate](https://drafts.fxtf.org/motion-1/#offset-rotate-property) property.
+ *
+ * The `reverse` keyword is represented as `auto` with an additional 180 degree rotation.
+ */
+export interface OffsetRotate {
+  /**
+   * A fixed rotation, added to the direction of the path if `auto` is set.
+   */
+  angle: Angle;
+  /**
+   * Whether the element is rotated by the direction of the offset path.
+   */
+  auto: boolean;
+}
+/**
+ * A value for the [offset](https://drafts.fxtf.org/motion-1/#offset-shorthand) shorthand property.
+ */
+export interface Offset {
+  /**
+   * The anchor point of the element.
+   */
+  anchor: OffsetAnchor;
+  /**
+   * The distance along the offset path.
+   */
+  distance: DimensionPercentageFor_LengthValue;
+  /**
+   * The offset path.
+   */
+  path: OffsetPath;
+  /**
+   * The offset starting position.
+   */
+  position: OffsetPosition;
+  /**
+   * The rotation of the element along the offset path.
+   */
+  rotate: OffsetRotate;
+}
 /**
  * A value for the [text-transform](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#text-transform-property) property.
  */
@@ -8686,6 +11168,19 @@ export interface TextTransform {
    */
   fullWidth: boolean;
 }
+/**
+ * A value for the [white-space](https://drafts.csswg.org/css-text-4/#white-space-property) shorthand property.
+ */
+export interface WhiteSpace {
+  /**
+   * How white space is collapsed.
+   */
+  collapse: WhiteSpaceCollapse;
+  /**
+   * Whether lines may wrap.
+   */
+  wrapMode: TextWrapMode;
+}
 /**
  * A value for the [text-indent](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#text-indent-property) property.
  */
@@ -8703,6 +11198,24 @@ export interface TextIndent {
    */
   value: DimensionPercentageFor_LengthValue;
 }
+export interface AutospaceBoundaries {
+  ideographAlpha: boolean;
+  ideographNumeric: boolean;
+  punctuation: boolean;
+}
+/**
+ * A value for the [text-spacing](https://drafts.csswg.org/css-text-4/#text-spacing-property) shorthand property.
+ */
+export interface TextSpacing {
+  /**
+   * Where extra space is inserted between characters.
+   */
+  autospace: TextAutospace;
+  /**
+   * How fullwidth punctuation is trimmed.
+   */
+  spacingTrim: TextSpacingTrim;
+}
 /**
  * A value for the [text-decoration](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#text-decoration-property) shorthand property.
  */
@@ -8767,13 +11280,52 @@ export interface TextShadow {
    */
   spread: Length;
   /**
-   * The x offset of the text shadow.
+   * The x offset of the text shadow.
+   */
+  xOffset: Length;
+  /**
+   * The y offset of the text shadow.
+   */
+  yOffset: Length;
+}
+/**
+ * A [`<text-edge>`](https://drafts.csswg.org/css-inline-3/#typedef-text-edge) value, as used in the `text-box-edge` property.
+ */
+export interface TextEdge {
+  /**
+   * The metric used for the over edge.
+   */
+  over: TextEdgeOver;
+  /**
+   * The metric used for the under edge.
+   */
+  under: TextEdgeUnder;
+}
+/**
+ * A value for the [text-box](https://drafts.csswg.org/css-inline-3/#text-box-shorthand) shorthand property.
+ */
+export interface TextBox {
+  /**
+   * The text metrics used to trim.
+   */
+  edge: TextBoxEdge;
+  /**
+   * The sides to trim.
+   */
+  trim: TextBoxTrim;
+}
+/**
+ * A value for the [initial-letter-align](https://drafts.csswg.org/css-inline-3/#aligning-initial-letter) property.
+ */
+export interface InitialLetterAlign {
+  /**
+   * The alignment points to use.
    */
-  xOffset: Length;
+  alignment?: InitialLetterAlignKeyword | null;
   /**
-   * The y offset of the text shadow.
+   * Whether the border box of the initial letter is used for alignment rather than its glyph outline.
    */
-  yOffset: Length;
+  borderBox: boolean;
 }
 /**
  * A value for the [cursor](https://www.w3.org/TR/2021/WD-css-ui-4-20210316/#cursor) property.
@@ -8853,77 +11405,6 @@ export interface Composes {
    */
   names: String[];
 }
-/**
- * An [`inset()`](https://www.w3.org/TR/css-shapes-1/#funcdef-inset) rectangle shape.
- */
-export interface InsetRect {
-  /**
-   * A corner radius for the rectangle.
-   */
-  radius: BorderRadius;
-  /**
-   * The rectangle.
-   */
-  rect: RectFor_DimensionPercentageFor_LengthValue;
-}
-/**
- * A [`circle()`](https://www.w3.org/TR/css-shapes-1/#funcdef-circle) shape.
- */
-export interface Circle2 {
-  /**
-   * The position of the center of the circle.
-   */
-  position: Position;
-  /**
-   * The radius of the circle.
-   */
-  radius: ShapeRadius;
-}
-/**
- * An [`ellipse()`](https://www.w3.org/TR/css-shapes-1/#funcdef-ellipse) shape.
- */
-export interface Ellipse2 {
-  /**
-   * The position of the center of the ellipse.
-   */
-  position: Position;
-  /**
-   * The x-radius of the ellipse.
-   */
-  radiusX: ShapeRadius;
-  /**
-   * The y-radius of the ellipse.
-   */
-  radiusY: ShapeRadius;
-}
-/**
- * A [`polygon()`](https://www.w3.org/TR/css-shapes-1/#funcdef-polygon) shape.
- */
-export interface Polygon {
-  /**
-   * The fill rule used to determine the interior of the polygon.
-   */
-  fillRule: FillRule;
-  /**
-   * The points of each vertex of the polygon.
-   */
-  points: Point[];
-}
-/**
- * A point within a `polygon()` shape.
- *
- * See [Polygon](Polygon).
- */
-export interface Point {
-  /**
-   * The x position of the point.
-   */
-  x: DimensionPercentageFor_LengthValue;
-  /**
-   * the y position of the point.
-   */
-  y: DimensionPercentageFor_LengthValue;
-}
 /**
  * A value for the [mask](https://www.w3.org/TR/css-masking-1/#the-mask) shorthand property.
  */
@@ -9062,6 +11543,19 @@ export interface AttrOperation {
   operator: AttrSelectorOperator;
   value: string;
 }
+/**
+ * A [view transition part selector](https://drafts.csswg.org/css-view-transitions-2/#typedef-pt-name-and-class-selector).
+ */
+export interface ViewTransitionPartSelector {
+  /**
+   * A list of view transition classes.
+   */
+  classes: String[];
+  /**
+   * The view transition part name.
+   */
+  name?: ViewTransitionPartName | null;
+}
 /**
  * A [@keyframes](https://drafts.csswg.org/css-animations/#keyframes) rule.
  */
@@ -9098,6 +11592,19 @@ export interface Keyframe<D = Declaration> {
    */
   selectors: KeyframeSelector[];
 }
+/**
+ * A [named timeline range selector](https://drafts.csswg.org/scroll-animations-1/#named-range-keyframes) within an `@keyframes` rule, e.g. `entry 25%`.
+ */
+export interface TimelineRangePercentage {
+  /**
+   * The name of the timeline range.
+   */
+  name: TimelineRangeName;
+  /**
+   * The percentage progress within the timeline range.
+   */
+  percentage: number;
+}
 /**
  * A [@font-face](https://drafts.csswg.org/css-fonts/#font-face-rule) rule.
  */
@@ -9173,6 +11680,23 @@ export interface OverrideColors {
    */
   index: number;
 }
+/**
+ * A [@color-profile](https://drafts.csswg.org/css-color-5/#at-profile) rule.
+ */
+export interface ColorProfileRule {
+  /**
+   * The location of the rule in the source file.
+   */
+  loc: Location2;
+  /**
+   * The name of the color profile.
+   */
+  name: ColorProfileName;
+  /**
+   * Declarations in the `@color-profile` rule.
+   */
+  properties: ColorProfileProperty[];
+}
 /**
  * A [@page](https://www.w3.org/TR/css-page-3/#at-page-rule) rule.
  */
@@ -9246,11 +11770,7 @@ export interface SupportsRule<D = Declaration, M = MediaQuery> {
 /**
  * A [@counter-style](https://drafts.csswg.org/css-counter-styles/#the-counter-style-rule) rule.
  */
-export interface CounterStyleRule<D = Declaration> {
-  /**
-   * Declarations in the `@counter-style` rule.
-   */
-  declarations: DeclarationBlock<D>;
+export interface CounterStyleRule {
   /**
    * The location of the rule in the source file.
    */
@@ -9259,6 +11779,62 @@ export interface CounterStyleRule<D = Declaration> {
    * The name of the counter style to declare.
    */
   name: String;
+  /**
+   * Declarations in the `@counter-style` rule.
+   */
+  properties: CounterStyleProperty[];
+}
+/**
+ * A value for the [negative](https://drafts.csswg.org/css-counter-styles/#counter-style-negative) property in a `@counter-style` rule.
+ */
+export interface CounterNegative {
+  /**
+   * The symbol prepended to the representation of a negative counter value.
+   */
+  prefix: CounterSymbol;
+  /**
+   * The symbol appended to the representation of a negative counter value.
+   */
+  suffix?: CounterSymbol | null;
+}
+/**
+ * A single range within the [range](https://drafts.csswg.org/css-counter-styles/#counter-style-range) property.
+ */
+export interface CounterRangeItem {
+  /**
+   * The upper bound of the range. `infinite` represents positive infinity.
+   */
+  end: CounterRangeBound;
+  /**
+   * The lower bound of the range. `infinite` represents negative infinity.
+   */
+  start: CounterRangeBound;
+}
+/**
+ * A value for the [pad](https://drafts.csswg.org/css-counter-styles/#counter-style-pad) property in a `@counter-style` rule.
+ */
+export interface CounterPad {
+  /**
+   * The minimum length of the marker representation.
+   */
+  length: number;
+  /**
+   * The symbol used for padding.
+   */
+  symbol: CounterSymbol;
+}
+/**
+ * A weighted symbol within the [additive-symbols](https://drafts.csswg.org/css-counter-styles/#counter-style-symbols) property in a `@counter-style` rule.
+ */
+export interface AdditiveSymbol {
+  /**
+   * The symbol.
+   */
+  symbol: CounterSymbol;
+  /**
+   * The weight of the symbol.
+   */
+  weight: number;
 }
 /**
  * A [@namespace](https://drafts.csswg.org/css-namespaces/#declaration) rule.
@@ -9396,21 +11972,6 @@ export interface PropertyRule {
    */
   syntax: SyntaxString;
 }
-/**
- * A [syntax component](https://drafts.css-houdini.org/css-properties-values-api/#syntax-component) within a [SyntaxString](SyntaxString).
- *
- * A syntax component consists of a component kind an a multiplier, which indicates how the component may repeat during parsing.
- */
-export interface SyntaxComponent {
-  /**
-   * The kind of component.
-   */
-  kind: SyntaxComponentKind;
-  /**
-   * A multiplier for the component.
-   */
-  multiplier: Multiplier;
-}
 /**
  * A [@container](https://drafts.csswg.org/css-contain-3/#container-rule) rule.
  */
@@ -9468,6 +12029,160 @@ export interface StartingStyleRule<D = Declaration, M = MediaQuery> {
    */
   rules: Rule<D, M>[];
 }
+/**
+ * A [@position-try](https://drafts.csswg.org/css-anchor-position-1/#fallback-rule) rule.
+ */
+export interface PositionTryRule<D = Declaration> {
+  /**
+   * The declarations within the `@position-try` rule.
+   */
+  declarations: DeclarationBlock<D>;
+  /**
+   * The location of the rule in the source file.
+   */
+  loc: Location2;
+  /**
+   * The name of the position try option.
+   */
+  name: String;
+}
+/**
+ * A [@view-transition](https://drafts.csswg.org/css-view-transitions-2/#view-transition-rule) rule.
+ */
+export interface ViewTransitionRule {
+  /**
+   * The location of the rule in the source file.
+   */
+  loc: Location2;
+  /**
+   * Declarations in the `@view-transition` rule.
+   */
+  properties: ViewTransitionProperty[];
+}
+/**
+ * A [@when](https://drafts.csswg.org/css-conditional-5/#when-rule) rule, along with the chain of `@else` rules that follow it.
+ */
+export interface WhenRule<D = Declaration, M = MediaQuery> {
+  /**
+   * The condition of the `@when` rule.
+   */
+  condition: WhenCondition<M>;
+  /**
+   * The `@else` rules chained to the `@when` rule.
+   */
+  else_rules: ElseRule<D, M>[];
+  /**
+   * The location of the rule in the source file.
+   */
+  loc: Location2;
+  /**
+   * The rules within the `@when` rule.
+   */
+  rules: Rule<D, M>[];
+}
+/**
+ * An [@else](https://drafts.csswg.org/css-conditional-5/#else-rule) rule.
+ *
+ * See [WhenRule](WhenRule).
+ */
+export interface ElseRule<D = Declaration, M = MediaQuery> {
+  /**
+   * The condition of the `@else` rule, if any.
+   */
+  condition?: WhenCondition<M> | null;
+  /**
+   * The location of the rule in the source file.
+   */
+  loc: Location2;
+  /**
+   * The rules within the `@else` rule.
+   */
+  rules: Rule<D, M>[];
+}
+/**
+ * A [@function](https://drafts.csswg.org/css-mixins-1/#function-rule) rule.
+ */
+export interface FunctionRule<D = Declaration> {
+  /**
+   * The declarations within the `@function` rule, such as `result` and local variables. These are preserved as raw tokens, since they may reference the function's parameters.
+   */
+  declarations: DeclarationBlock<D>;
+  /**
+   * The location of the rule in the source file.
+   */
+  loc: Location2;
+  /**
+   * The name of the custom function.
+   */
+  name: String;
+  /**
+   * The parameters accepted by the function.
+   */
+  parameters: FunctionParameter[];
+  /**
+   * The type returned by the function, if specified.
+   */
+  returns?: SyntaxString | null;
+}
+/**
+ * A [function parameter](https://drafts.csswg.org/css-mixins-1/#typedef-function-parameter), as used in the `@function` and `@mixin` rules.
+ */
+export interface FunctionParameter {
+  /**
+   * The default value of the parameter, if specified.
+   */
+  defaultValue?: TokenOrValue[] | null;
+  /**
+   * The name of the parameter.
+   */
+  name: String;
+  /**
+   * The type of the parameter, if specified.
+   */
+  syntax?: SyntaxString | null;
+}
+/**
+ * A [@mixin](https://drafts.csswg.org/css-mixins-1/#mixin-rule) rule.
+ */
+export interface MixinRule<D = Declaration, M = MediaQuery> {
+  /**
+   * The declarations within the `@mixin` rule.
+   */
+  declarations: DeclarationBlock<D>;
+  /**
+   * The location of the rule in the source file.
+   */
+  loc: Location2;
+  /**
+   * The name of the mixin.
+   */
+  name: String;
+  /**
+   * The parameters accepted by the mixin, if it was declared with parentheses.
+   */
+  parameters?: FunctionParameter[] | null;
+  /**
+   * Nested rules within the `@mixin` rule.
+   */
+  rules: Rule<D, M>[];
+}
+/**
+ * An [@apply](https://drafts.csswg.org/css-mixins-1/#apply-rule) rule.
+ */
+export interface ApplyRule {
+  /**
+   * The arguments passed to the mixin, if it was applied with parentheses.
+   */
+  arguments?: TokenOrValue[][] | null;
+  /**
+   * The location of the rule in the source file.
+   */
+  loc: Location2;
+  /**
+   * The name of the mixin to apply.
+   */
+  name: String;
+}
 /**
  * An unknown at-rule, stored as raw tokens.
  */
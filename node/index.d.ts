@@ -1,4 +1,4 @@
-import type { Angle, CssColor, Rule, CustomProperty, EnvironmentVariable, Function, Image, LengthValue, MediaQuery, Declaration, Ratio, Resolution, Selector, SupportsCondition, Time, Token, TokenOrValue, UnknownAtRule, Url, Variable, StyleRule, DeclarationBlock, ParsedComponent, Multiplier, StyleSheet } from './ast';
+import type { Angle, AttrFunction, CssColor, Rule, CustomProperty, EnvironmentVariable, Function, Image, LengthValue, MediaQuery, Declaration, Ratio, Resolution, Selector, SupportsCondition, Time, Token, TokenOrValue, UnknownAtRule, Url, Variable, StyleRule, DeclarationBlock, ParsedComponent, Multiplier, StyleSheet } from './ast';
 import { Targets, Features } from './targets';
 
 export * from './ast';
@@ -27,6 +27,11 @@ export interface TransformOptions<C extends CustomAtRules> {
   include?: number,
   /** Features that should never be compiled, even when unsupported by targets. */
   exclude?: number,
+  /**
+   * The algorithm used to map out of gamut colors when generating sRGB fallbacks.
+   * `chroma-reduction` (the default) preserves lightness and hue, while `clip` clamps each channel.
+   */
+  gamutMapping?: 'chroma-reduction' | 'clip',
   /** Whether to enable parsing various draft syntax. */
   drafts?: Drafts,
   /** Whether to enable various non-standard syntax. */
@@ -57,6 +62,16 @@ export interface TransformOptions<C extends CustomAtRules> {
    * omitted from the output code.
    */
   errorRecovery?: boolean,
+  /**
+   * The maximum number of decimal places to serialize numbers with, e.g. the results of `calc()`.
+   * By default, numbers are serialized with all of their significant digits.
+   */
+  precision?: number,
+  /**
+   * Converts `px` lengths in declarations to `rem`. Pass `true` to use the default options,
+   * i.e. a root font size of 16px.
+   */
+  pxToRem?: boolean | PxToRemOptions,
   /**
    * An AST visitor object. This allows custom transforms or analysis to be implemented in JavaScript.
    * Multiple visitors can be composed into one using the `composeVisitors` function.
@@ -209,6 +224,8 @@ export interface Visitor<C extends CustomAtRules> {
   FunctionExit?: FunctionVisitor | { [name: string]: FunctionVisitor };
   Variable?(variable: Variable): TokenReturnValue;
   VariableExit?(variable: Variable): TokenReturnValue;
+  Attr?(attr: AttrFunction): TokenReturnValue;
+  AttrExit?(attr: AttrFunction): TokenReturnValue;
   EnvironmentVariable?: EnvironmentVariableVisitor | EnvironmentVariableVisitors;
   EnvironmentVariableExit?: EnvironmentVariableVisitor | EnvironmentVariableVisitors;
 }
@@ -262,7 +279,11 @@ export interface Resolver {
 
 export interface Drafts {
   /** Whether to enable @custom-media rules. */
-  customMedia?: boolean
+  customMedia?: boolean,
+  /** Whether to enable @when and @else rules. */
+  whenElse?: boolean,
+  /** Whether to enable @function, @mixin, and @apply rules. */
+  mixins?: boolean
 }
 
 export interface NonStandard {
@@ -300,11 +321,29 @@ export interface Warning {
   loc: ErrorLocation
 }
 
+/** Custom properties are left as is, since their values may be used in places other than lengths. */
+export interface PxToRemOptions {
+  /** The root font size, i.e. the number of `px` in `1rem`. Default is `16`. */
+  rootFontSize?: number,
+  /**
+   * Lengths smaller than this number of `px` are not converted. Default is `0`, which converts
+   * all non-zero lengths. Set it to e.g. `2` to keep hairline borders in `px`.
+   */
+  minPx?: number,
+  /**
+   * Names of properties to leave in `px`. A name also matches the properties
+   * that start with it followed by a `-`, e.g. `border` matches `border-top-width`.
+   */
+  exclude?: string[]
+}
+
 export interface CSSModulesConfig {
   /** The pattern to use when renaming class names and other identifiers. Default is `[hash]_[local]`. */
   pattern?: string,
   /** Whether to rename dashed identifiers, e.g. custom properties. */
-  dashedIdents?: boolean
+  dashedIdents?: boolean,
+  /** Whether to scope paint worklet names used in `paint()`. */
+  paint?: boolean
 }
 
 export type CSSModuleExports = {
@@ -409,6 +448,8 @@ export interface TransformAttributeOptions {
   minify?: boolean,
   /** The browser targets for the generated code. */
   targets?: Targets,
+  /** The algorithm used to map out of gamut colors when generating sRGB fallbacks. */
+  gamutMapping?: 'chroma-reduction' | 'clip',
   /**
    * Whether to analyze `url()` dependencies.
    * When enabled, `url()` dependencies are replaced with hashed placeholders
@@ -422,6 +463,16 @@ export interface TransformAttributeOptions {
    * omitted from the output code.
    */
   errorRecovery?: boolean,
+  /**
+   * The maximum number of decimal places to serialize numbers with, e.g. the results of `calc()`.
+   * By default, numbers are serialized with all of their significant digits.
+   */
+  precision?: number,
+  /**
+   * Converts `px` lengths in declarations to `rem`. Pass `true` to use the default options,
+   * i.e. a root font size of 16px.
+   */
+  pxToRem?: boolean | PxToRemOptions,
   /**
    * An AST visitor object. This allows custom transforms or analysis to be implemented in JavaScript.
    * Multiple visitors can be composed into one using the `composeVisitors` function.
@@ -249,6 +249,28 @@ test('specific environment variables', () => {
   assert.equal(res.code.toString(), '@media (width<=600px){body{padding:20px}}');
 });
 
+test('attr function', () => {
+  let res = transform({
+    filename: 'test.css',
+    minify: true,
+    code: Buffer.from(`
+      .foo {
+        width: attr(data-size px, 10px);
+        height: attr(data-height type(<length>), 20px);
+      }
+    `),
+    visitor: {
+      Attr(attr) {
+        if (attr.name === 'data-size' && attr.attrType?.type === 'unit' && attr.fallback) {
+          return attr.fallback;
+        }
+      }
+    }
+  });
+
+  assert.equal(res.code.toString(), '.foo{width:10px;height:attr(data-height type(<length>),20px)}');
+});
+
 test('url', () => {
   // https://www.npmjs.com/package/postcss-url
   let res = transform({
@@ -70,6 +70,26 @@ prefixes['any-pseudo'] = {
     })
 }
 
+// -webkit-line-clamp only works alongside other legacy declarations,
+// so it is added by LineClampHandler rather than as a normal prefix.
+prefixes['line-clamp'] = {
+  browsers: []
+};
+
+// Autoprefixer has no data for initial-letter, so derive it from the prefixed MDN entries.
+prefixes['initial-letter'] = {
+  browsers: Object.entries(mdn.css.properties['initial-letter'].__compat.support)
+    .flatMap(([key, value]) => {
+      let prefixed = (Array.isArray(value) ? value : [value]).find(v => v.prefix === '-webkit-' && !v.version_removed);
+      key = MDN_BROWSER_MAPPING[key] || key;
+      if (!prefixed || !latestBrowserVersions[key]) {
+        return [];
+      }
+
+      return [`${key} ${prefixed.version_added}`, `${key} ${latestBrowserVersions[key]}`];
+    })
+};
+
 let flexSpec = {};
 let oldGradient = {};
 let p = new Map();
@@ -259,6 +279,37 @@ let mdnFeatures = {
   placeContent: mdn.css.properties['place-content'].__compat.support,
   placeItems: mdn.css.properties['place-items'].__compat.support,
   overflowShorthand: mdn.css.properties['overflow'].multiple_keywords.__compat.support,
+  overscrollBehaviorShorthand: mdn.css.properties['overscroll-behavior'].__compat.support,
+  individualTransforms: mdn.css.properties.translate.__compat.support,
+  transitionBehavior: mdn.css.properties['transition-behavior'].__compat.support,
+  readingFlow: mdn.css.properties['reading-flow'].__compat.support,
+  readingOrder: mdn.css.properties['reading-order'].__compat.support,
+  caretShorthand: mdn.css.properties.caret.__compat.support,
+  textSpacingTrim: mdn.css.properties['text-spacing-trim'].__compat.support,
+  textAutospace: mdn.css.properties['text-autospace'].__compat.support,
+  scrollbarWidth: mdn.css.properties['scrollbar-width'].__compat.support,
+  scrollbarColor: mdn.css.properties['scrollbar-color'].__compat.support,
+  fontSynthesisWeight: mdn.css.properties['font-synthesis-weight'].__compat.support,
+  fontSynthesisStyle: mdn.css.properties['font-synthesis-style'].__compat.support,
+  fontSynthesisSmallCaps: mdn.css.properties['font-synthesis-small-caps'].__compat.support,
+  fontSynthesisPosition: mdn.css.properties['font-synthesis-position'].__compat.support,
+  zoom: mdn.css.properties.zoom.__compat.support,
+  supportsSelector: mdn.css['at-rules'].supports.selector.__compat.support,
+  supportsFontFormat: mdn.css['at-rules'].supports['font-format'].__compat.support,
+  supportsFontTech: mdn.css['at-rules'].supports['font-tech'].__compat.support,
+  lineClamp: Object.fromEntries(
+    Object.entries(mdn.css.properties['line-clamp'].__compat.support)
+      .map(([browser, value]) => {
+        // Only the prefixed -webkit-line-clamp property is widely supported.
+        if (Array.isArray(value)) {
+          value = value.filter(v => !v.prefix);
+        } else if (value.prefix) {
+          value = undefined;
+        }
+
+        return [browser, value && value.length !== 0 ? value : { version_added: false }];
+      })
+  ),
   mediaRangeSyntax: mdn.css['at-rules'].media.range_syntax.__compat.support,
   mediaIntervalSyntax: Object.fromEntries(
     Object.entries(mdn.css['at-rules'].media.range_syntax.__compat.support)
@@ -309,6 +360,7 @@ let mdnFeatures = {
   ),
   partPseudo: mdn.css.selectors.part.__compat.support,
   imageSet: mdn.css.types.image['image-set'].__compat.support,
+  paintFunction: mdn.css.types.image.paint.__compat.support,
   xResolutionUnit: mdn.css.types.resolution.x.__compat.support,
   nthChildOf: mdn.css.selectors['nth-child'].of_syntax.__compat.support,
   minFunction: mdn.css.types.min.__compat.support,
@@ -319,6 +371,8 @@ let mdnFeatures = {
   absFunction: mdn.css.types.abs.__compat.support,
   signFunction: mdn.css.types.sign.__compat.support,
   hypotFunction: mdn.css.types.hypot.__compat.support,
+  siblingIndexFunction: mdn.css.types['sibling-index'].__compat.support,
+  siblingCountFunction: mdn.css.types['sibling-count'].__compat.support,
   gradientInterpolationHints: mdn.css.types.image.gradient['linear-gradient'].interpolation_hints.__compat.support,
   borderImageRepeatRound: mdn.css.properties['border-image-repeat'].round.__compat.support,
   borderImageRepeatSpace: mdn.css.properties['border-image-repeat'].space.__compat.support,
@@ -328,8 +382,15 @@ let mdnFeatures = {
   fontWeightNumber: mdn.css.properties['font-weight'].number.__compat.support,
   fontStretchPercentage: mdn.css.properties['font-stretch'].percentage.__compat.support,
   lightDark: mdn.css.types.color['light-dark'].__compat.support,
+  contrastColor: mdn.css.types.color['contrast-color'].__compat.support,
+  deviceCmyk: mdn.css.types.color['device-cmyk'].__compat.support,
   accentSystemColor: mdn.css.types.color['system-color'].accentcolor_accentcolortext.__compat.support,
   animationTimelineShorthand: mdn.css.properties.animation['animation-timeline_included'].__compat.support,
+  fontFormatWoff: mdn.css['at-rules']['font-face'].WOFF.__compat.support,
+  fontFormatWoff2: mdn.css['at-rules']['font-face'].WOFF_2.__compat.support,
+  webkitTapHighlightColor: mdn.css.properties['-webkit-tap-highlight-color'].__compat.support,
+  webkitTouchCallout: mdn.css.properties['-webkit-touch-callout'].__compat.support,
+  webkitOverflowScrolling: mdn.css.properties['-webkit-overflow-scrolling'].__compat.support,
 };
 
 for (let key in mdn.css.types.length) {
@@ -430,6 +491,17 @@ addValue(compat, {
   ios_saf: parseVersion('10.3')
 }, 'p3Colors');
 
+// EOT fonts are only supported by Internet Explorer.
+addValue(compat, {
+  ie: parseVersion('6')
+}, 'fontFormatEmbeddedOpenType');
+
+// SVG fonts were removed from Chrome and Opera, and are only supported by Safari.
+addValue(compat, {
+  safari: parseVersion('3.1'),
+  ios_saf: parseVersion('3.2')
+}, 'fontFormatSvg');
+
 addValue(compat, {
   // https://github.com/WebKit/WebKit/commit/baed0d8b0abf366e1d9a6105dc378c59a5f21575
   safari: parseVersion('10.1'),
@@ -464,6 +536,8 @@ let flags = [
   'DoublePositionGradients',
   'VendorPrefixes',
   'LogicalProperties',
+  'LineClamp',
+  'WebKitScrollbar',
   ['Selectors', ['Nesting', 'NotSelectorList', 'DirSelector', 'LangSelectorList', 'IsSelector']],
   ['MediaQueries', ['MediaIntervalSyntax', 'MediaRangeSyntax', 'CustomMediaQueries']],
   ['Colors', ['ColorFunction', 'OklabColors', 'LabColors', 'P3Colors', 'HexAlphaColors', 'SpaceSeparatedColorNotation']],
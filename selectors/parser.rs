@@ -755,6 +755,18 @@ impl<'i, Impl: SelectorImpl<'i>> Selector<'i, Impl> {
     self.1.insert(index, component);
   }
 
+  /// Appends a pseudo element to the end of the selector.
+  /// The selector must not already contain a pseudo element.
+  #[inline]
+  pub fn append_pseudo_element(&mut self, pseudo: Impl::PseudoElement) {
+    debug_assert!(!self.has_pseudo_element());
+    // Components are stored in matching order, so the pseudo element goes first.
+    self.1.insert(0, Component::Combinator(Combinator::PseudoElement));
+    self.1.insert(0, Component::PseudoElement(pseudo));
+    self.0.specificity += 1;
+    self.0.flags |= SelectorFlags::HAS_PSEUDO;
+  }
+
   #[inline]
   pub fn parts(&self) -> Option<&[Impl::Identifier]> {
     if !self.is_part() {
@@ -993,14 +1005,23 @@ impl<'i, Impl: SelectorImpl<'i>> From<Component<'i, Impl>> for Selector<'i, Impl
 impl<'i, Impl: SelectorImpl<'i>> From<Vec<Component<'i, Impl>>> for Selector<'i, Impl> {
   fn from(vec: Vec<Component<'i, Impl>>) -> Self {
     let mut builder = SelectorBuilder::default();
+    let mut parsed_pseudo = false;
+    let mut parsed_slotted = false;
+    let mut parsed_part = false;
     for component in vec.into_iter() {
+      match component {
+        Component::PseudoElement(..) => parsed_pseudo = true,
+        Component::Slotted(..) => parsed_slotted = true,
+        Component::Part(..) => parsed_part = true,
+        _ => {}
+      }
       if let Some(combinator) = component.as_combinator() {
         builder.push_combinator(combinator);
       } else {
         builder.push_simple_selector(component);
       }
     }
-    let (spec, components) = builder.build(false, false, false);
+    let (spec, components) = builder.build(parsed_pseudo, parsed_slotted, parsed_part);
     Selector(spec, components)
   }
 }
@@ -1561,6 +1582,71 @@ impl<'i, Impl: SelectorImpl<'i>> Component<'i, Impl> {
     }
   }
 
+  /// Creates a type selector, e.g. `div`.
+  pub fn local_name(name: CowRcStr<'i>) -> Self {
+    Component::LocalName(LocalName {
+      lower_name: to_ascii_lowercase(name.clone()).into(),
+      name: name.into(),
+    })
+  }
+
+  /// Creates an ID selector, e.g. `#foo`.
+  pub fn id(id: CowRcStr<'i>) -> Self {
+    Component::ID(id.into())
+  }
+
+  /// Creates a class selector, e.g. `.foo`.
+  pub fn class(class: CowRcStr<'i>) -> Self {
+    Component::Class(class.into())
+  }
+
+  /// Creates an attribute selector without a namespace that matches elements
+  /// that have the attribute, e.g. `[foo]`.
+  pub fn attribute_exists(local_name: CowRcStr<'i>) -> Self {
+    Component::AttributeInNoNamespaceExists {
+      local_name_lower: to_ascii_lowercase(local_name.clone()).into(),
+      local_name: local_name.into(),
+    }
+  }
+
+  /// Creates an attribute selector without a namespace that compares the value
+  /// of the attribute, e.g. `[foo^=bar]`. Case sensitivity depends on the name of
+  /// the attribute, as if neither the `i` nor the `s` flag was specified.
+  pub fn attribute(local_name: CowRcStr<'i>, operator: AttrSelectorOperator, value: CowRcStr<'i>) -> Self {
+    let never_matches = match operator {
+      AttrSelectorOperator::Equal | AttrSelectorOperator::DashMatch => false,
+      AttrSelectorOperator::Includes => value.is_empty() || value.contains(SELECTOR_WHITESPACE),
+      AttrSelectorOperator::Prefix | AttrSelectorOperator::Substring | AttrSelectorOperator::Suffix => {
+        value.is_empty()
+      }
+    };
+
+    let local_name_lower = to_ascii_lowercase(local_name.clone());
+    let case_sensitivity =
+      AttributeFlags::CaseSensitivityDependsOnName.to_case_sensitivity(local_name_lower.as_ref(), false);
+    if local_name_lower == local_name {
+      Component::AttributeInNoNamespace {
+        local_name: local_name.into(),
+        operator,
+        value: value.into(),
+        case_sensitivity,
+        never_matches,
+      }
+    } else {
+      Component::AttributeOther(Box::new(AttrSelectorWithOptionalNamespace {
+        namespace: None,
+        local_name: local_name.into(),
+        local_name_lower: local_name_lower.into(),
+        never_matches,
+        operation: ParsedAttrSelectorOperation::WithValue {
+          operator,
+          case_sensitivity,
+          expected_value: value.into(),
+        },
+      }))
+    }
+  }
+
   /// Whether this component is valid after a pseudo-element. Only intended
   /// for sanity-checking.
   pub fn maybe_allowed_after_pseudo_element(&self) -> bool {
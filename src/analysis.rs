@@ -0,0 +1,459 @@
+//! Style sheet analysis.
+//!
+//! These functions inspect a parsed [StyleSheet](crate::stylesheet::StyleSheet) and report
+//! patterns that may be worth refactoring, without modifying it. For example,
+//! [find_duplicate_declarations](find_duplicate_declarations) finds groups of declarations
+//! that are repeated verbatim across many style rules, which could be extracted into
+//! a utility class or custom properties, and [font_usage](font_usage) reports which
+//! `@font-face` rules are used, e.g. for font subsetting tools.
+
+use crate::declaration::DeclarationBlock;
+use crate::printer::PrinterOptions;
+use crate::properties::custom::{Token, TokenOrValue};
+use crate::properties::font::FontFamily;
+use crate::properties::Property;
+use crate::rules::font_face::{FontFaceProperty, FontFaceRule, UnicodeRange};
+use crate::rules::{style::StyleRule, CssRule, CssRuleList, Location};
+use crate::stylesheet::StyleSheet;
+use crate::traits::ToCss;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+
+/// Options for [find_duplicate_declarations](find_duplicate_declarations).
+#[derive(Debug, Clone)]
+pub struct DuplicateDeclarationOptions {
+  /// The minimum number of declarations in a reported group.
+  pub min_declarations: usize,
+  /// The minimum number of style rules a group must appear in to be reported.
+  pub min_occurrences: usize,
+}
+
+impl Default for DuplicateDeclarationOptions {
+  fn default() -> Self {
+    DuplicateDeclarationOptions {
+      min_declarations: 2,
+      min_occurrences: 2,
+    }
+  }
+}
+
+/// A group of declarations that is repeated across multiple style rules.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicateDeclarations<'i> {
+  /// The repeated declarations, in the order they first appeared.
+  pub declarations: DeclarationBlock<'i>,
+  /// The number of style rules that contain all of the declarations.
+  pub count: usize,
+  /// The locations of the style rules that contain all of the declarations.
+  pub locations: Vec<Location>,
+}
+
+struct RuleDeclarations {
+  loc: Location,
+  /// Sorted, unique declaration ids.
+  ids: Vec<usize>,
+}
+
+/// Finds groups of declarations that are repeated verbatim across multiple style rules.
+///
+/// Declarations are compared by their serialized value, ignoring order within a rule.
+/// Only maximal groups are reported: a group is omitted if a larger group appears in the
+/// same set of rules. Results are sorted so that groups with the most repeated declarations
+/// (declarations × occurrences) come first.
+pub fn find_duplicate_declarations<'i, T>(
+  stylesheet: &StyleSheet<'i, '_, T>,
+  options: &DuplicateDeclarationOptions,
+) -> Vec<DuplicateDeclarations<'i>> {
+  let mut keys = HashMap::new();
+  let mut declarations = Vec::new();
+  let mut rules = Vec::new();
+  collect_rules(&stylesheet.rules, &mut keys, &mut declarations, &mut rules);
+
+  let min_declarations = options.min_declarations.max(1);
+  let min_occurrences = options.min_occurrences.max(2);
+
+  // Build an inverted index from each declaration to the sorted list of rules containing it,
+  // so only rules that actually share declarations are compared.
+  let mut index = vec![Vec::new(); declarations.len()];
+  for (i, rule) in rules.iter().enumerate() {
+    for id in &rule.ids {
+      index[*id].push(i);
+    }
+  }
+
+  // Candidate groups are the full declaration sets of each rule,
+  // and the declarations shared between each pair of rules.
+  let mut candidates = HashSet::new();
+  let mut shared: HashMap<usize, Vec<usize>> = HashMap::new();
+  for (i, a) in rules.iter().enumerate() {
+    if a.ids.len() >= min_declarations {
+      candidates.insert(a.ids.clone());
+    }
+
+    for id in &a.ids {
+      for b in &index[*id] {
+        if *b > i {
+          shared.entry(*b).or_default().push(*id);
+        }
+      }
+    }
+
+    for (_, ids) in shared.drain() {
+      if ids.len() >= min_declarations {
+        candidates.insert(ids);
+      }
+    }
+  }
+
+  // The rules matching a group are the intersection of the rules containing each of its declarations.
+  // Only the largest group is kept for each set of matching rules, since the others are contained in it.
+  let mut groups: HashMap<Vec<usize>, Vec<usize>> = HashMap::new();
+  for ids in candidates {
+    let mut matches = index[ids[0]].clone();
+    for id in &ids[1..] {
+      if matches.len() < min_occurrences {
+        break;
+      }
+      matches = intersect(&matches, &index[*id]);
+    }
+
+    if matches.len() >= min_occurrences {
+      match groups.entry(matches) {
+        Entry::Occupied(mut entry) => {
+          if ids.len() > entry.get().len() {
+            entry.insert(ids);
+          }
+        }
+        Entry::Vacant(entry) => {
+          entry.insert(ids);
+        }
+      }
+    }
+  }
+
+  let mut groups: Vec<(Vec<usize>, Vec<usize>)> =
+    groups.into_iter().map(|(matches, ids)| (ids, matches)).collect();
+  groups.sort_by(|(a, a_matches), (b, b_matches)| {
+    (b.len() * b_matches.len())
+      .cmp(&(a.len() * a_matches.len()))
+      .then(b_matches.len().cmp(&a_matches.len()))
+      .then(a_matches.cmp(b_matches))
+      .then(a.cmp(b))
+  });
+
+  groups
+    .into_iter()
+    .map(|(ids, matches)| {
+      let mut block = DeclarationBlock::new();
+      for id in ids {
+        let (property, important) = &declarations[id];
+        if *important {
+          block.important_declarations.push(property.clone());
+        } else {
+          block.declarations.push(property.clone());
+        }
+      }
+
+      DuplicateDeclarations {
+        declarations: block,
+        count: matches.len(),
+        locations: matches.into_iter().map(|i| rules[i].loc).collect(),
+      }
+    })
+    .collect()
+}
+
+fn collect_rules<'i, T>(
+  rules: &CssRuleList<'i, T>,
+  keys: &mut HashMap<String, usize>,
+  declarations: &mut Vec<(Property<'i>, bool)>,
+  dest: &mut Vec<RuleDeclarations>,
+) {
+  for rule in &rules.0 {
+    match rule {
+      CssRule::Style(style) => collect_style_rule(style, keys, declarations, dest),
+      CssRule::Media(media) => collect_rules(&media.rules, keys, declarations, dest),
+      CssRule::Supports(supports) => collect_rules(&supports.rules, keys, declarations, dest),
+      CssRule::Container(container) => collect_rules(&container.rules, keys, declarations, dest),
+      CssRule::LayerBlock(layer) => collect_rules(&layer.rules, keys, declarations, dest),
+      CssRule::MozDocument(document) => collect_rules(&document.rules, keys, declarations, dest),
+      CssRule::Scope(scope) => collect_rules(&scope.rules, keys, declarations, dest),
+      CssRule::StartingStyle(starting_style) => collect_rules(&starting_style.rules, keys, declarations, dest),
+      CssRule::Nesting(nesting) => collect_style_rule(&nesting.style, keys, declarations, dest),
+      _ => {}
+    }
+  }
+}
+
+fn collect_style_rule<'i, T>(
+  style: &StyleRule<'i, T>,
+  keys: &mut HashMap<String, usize>,
+  declarations: &mut Vec<(Property<'i>, bool)>,
+  dest: &mut Vec<RuleDeclarations>,
+) {
+  let mut ids = Vec::new();
+  for (property, important) in style.declarations.iter() {
+    let Ok(key) = property.to_css_string(
+      important,
+      PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      },
+    ) else {
+      continue;
+    };
+    let id = *keys.entry(key).or_insert_with(|| {
+      declarations.push((property.clone(), important));
+      declarations.len() - 1
+    });
+    ids.push(id);
+  }
+
+  ids.sort_unstable();
+  ids.dedup();
+  if !ids.is_empty() {
+    dest.push(RuleDeclarations { loc: style.loc, ids });
+  }
+
+  collect_rules(&style.rules, keys, declarations, dest);
+}
+
+fn intersect(a: &[usize], b: &[usize]) -> Vec<usize> {
+  let mut res = Vec::new();
+  let (mut i, mut j) = (0, 0);
+  while i < a.len() && j < b.len() {
+    match a[i].cmp(&b[j]) {
+      std::cmp::Ordering::Equal => {
+        res.push(a[i]);
+        i += 1;
+        j += 1;
+      }
+      std::cmp::Ordering::Less => i += 1,
+      std::cmp::Ordering::Greater => j += 1,
+    }
+  }
+  res
+}
+
+/// A report of how the fonts declared by `@font-face` rules are used in a style sheet.
+///
+/// With the `serde` feature enabled, this can be serialized to JSON and passed to
+/// font subsetting tools to determine which fonts, weights, and glyphs are needed.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "camelCase"))]
+pub struct FontUsageReport {
+  /// The `@font-face` rules in the style sheet, in source order.
+  pub font_faces: Vec<FontFaceReport>,
+  /// The named font families referenced by `font-family` or `font` declarations.
+  pub families: Vec<FontFamilyUsage>,
+}
+
+/// A `@font-face` rule within a [FontUsageReport](FontUsageReport).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "camelCase"))]
+pub struct FontFaceReport {
+  /// The value of the `font-family` descriptor.
+  pub family: Option<String>,
+  /// The value of the `font-weight` descriptor.
+  pub weight: Option<String>,
+  /// The value of the `font-style` descriptor.
+  pub style: Option<String>,
+  /// The value of the `font-stretch` descriptor.
+  pub stretch: Option<String>,
+  /// The ranges in the `unicode-range` descriptor.
+  pub unicode_range: Vec<UnicodeRange>,
+  /// The sources in the `src` descriptor.
+  pub sources: Vec<String>,
+  /// Whether the font family is referenced by any style rule.
+  pub used: bool,
+  /// The location of the rule.
+  pub loc: Location,
+}
+
+/// The usage of a named font family within a [FontUsageReport](FontUsageReport).
+///
+/// Values are collected from the style rules that reference the family. Since inheritance
+/// depends on the document, declarations in other rules that may apply to the same
+/// elements are not included.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "camelCase"))]
+pub struct FontFamilyUsage {
+  /// The font family name.
+  pub family: String,
+  /// Whether the family is declared by a `@font-face` rule.
+  pub declared: bool,
+  /// The font weights used alongside the family.
+  pub weights: Vec<String>,
+  /// The font styles used alongside the family.
+  pub styles: Vec<String>,
+  /// Declarations that affect which glyphs are rendered, e.g. `text-transform` or `font-feature-settings`.
+  pub features: Vec<String>,
+  /// Strings inserted via the `content` property.
+  pub content: Vec<String>,
+  /// The locations of the style rules that reference the family.
+  pub locations: Vec<Location>,
+}
+
+/// Properties that affect which glyphs of a font are rendered.
+const GLYPH_PROPERTIES: &[&str] = &[
+  "text-transform",
+  "font-variant",
+  "font-variant-caps",
+  "font-variant-ligatures",
+  "font-variant-numeric",
+  "font-variant-east-asian",
+  "font-variant-alternates",
+  "font-variant-position",
+  "font-feature-settings",
+  "font-kerning",
+];
+
+/// Correlates the `@font-face` rules in a style sheet with the `font-family` and `font`
+/// declarations that reference them.
+pub fn font_usage<T>(stylesheet: &StyleSheet<'_, '_, T>) -> FontUsageReport {
+  let mut report = FontUsageReport::default();
+  collect_font_usage(&stylesheet.rules, &mut report);
+
+  for family in &mut report.families {
+    family.declared = report
+      .font_faces
+      .iter()
+      .any(|font_face| matches!(&font_face.family, Some(name) if name.eq_ignore_ascii_case(&family.family)));
+  }
+
+  for font_face in &mut report.font_faces {
+    font_face.used = matches!(&font_face.family, Some(name) if report.families.iter().any(|family| family.family.eq_ignore_ascii_case(name)));
+  }
+
+  report
+}
+
+fn collect_font_usage<T>(rules: &CssRuleList<'_, T>, report: &mut FontUsageReport) {
+  for rule in &rules.0 {
+    match rule {
+      CssRule::FontFace(font_face) => report.font_faces.push(font_face_report(font_face)),
+      CssRule::Style(style) => collect_style_font_usage(style, report),
+      CssRule::Nesting(nesting) => collect_style_font_usage(&nesting.style, report),
+      CssRule::Media(media) => collect_font_usage(&media.rules, report),
+      CssRule::Supports(supports) => collect_font_usage(&supports.rules, report),
+      CssRule::Container(container) => collect_font_usage(&container.rules, report),
+      CssRule::LayerBlock(layer) => collect_font_usage(&layer.rules, report),
+      CssRule::MozDocument(document) => collect_font_usage(&document.rules, report),
+      CssRule::Scope(scope) => collect_font_usage(&scope.rules, report),
+      CssRule::StartingStyle(starting_style) => collect_font_usage(&starting_style.rules, report),
+      _ => {}
+    }
+  }
+}
+
+fn font_face_report(rule: &FontFaceRule) -> FontFaceReport {
+  let mut report = FontFaceReport {
+    family: None,
+    weight: None,
+    style: None,
+    stretch: None,
+    unicode_range: Vec::new(),
+    sources: Vec::new(),
+    used: false,
+    loc: rule.loc,
+  };
+
+  for property in &rule.properties {
+    match property {
+      FontFaceProperty::FontFamily(family) => report.family = Some(family_name(family)),
+      FontFaceProperty::FontWeight(weight) => report.weight = weight.to_css_string(PrinterOptions::default()).ok(),
+      FontFaceProperty::FontStyle(style) => report.style = style.to_css_string(PrinterOptions::default()).ok(),
+      FontFaceProperty::FontStretch(stretch) => {
+        report.stretch = stretch.to_css_string(PrinterOptions::default()).ok()
+      }
+      FontFaceProperty::UnicodeRange(ranges) => report.unicode_range = ranges.clone(),
+      FontFaceProperty::Source(sources) => {
+        report.sources = sources
+          .iter()
+          .filter_map(|source| source.to_css_string(PrinterOptions::default()).ok())
+          .collect()
+      }
+      FontFaceProperty::Custom(_) => {}
+    }
+  }
+
+  report
+}
+
+fn collect_style_font_usage<T>(style: &StyleRule<'_, T>, report: &mut FontUsageReport) {
+  let mut families = Vec::new();
+  let mut weights = Vec::new();
+  let mut styles = Vec::new();
+  let mut features = Vec::new();
+  let mut content = Vec::new();
+
+  for (property, important) in style.declarations.iter() {
+    match property {
+      Property::FontFamily(f) => families.extend(f.iter().filter(|f| matches!(f, FontFamily::FamilyName(_)))),
+      Property::FontWeight(weight) => weights.extend(weight.to_css_string(PrinterOptions::default())),
+      Property::FontStyle(s) => styles.extend(s.to_css_string(PrinterOptions::default())),
+      Property::Font(font) => {
+        families.extend(font.family.iter().filter(|f| matches!(f, FontFamily::FamilyName(_))));
+        weights.extend(font.weight.to_css_string(PrinterOptions::default()));
+        styles.extend(font.style.to_css_string(PrinterOptions::default()));
+      }
+      Property::Custom(custom) if property.property_id().name() == "content" => {
+        content.extend(custom.value.0.iter().filter_map(|token| match token {
+          TokenOrValue::Token(Token::String(s)) => Some(s.to_string()),
+          _ => None,
+        }))
+      }
+      _ if GLYPH_PROPERTIES.contains(&property.property_id().name()) => {
+        features.extend(property.to_css_string(important, PrinterOptions::default()))
+      }
+      _ => {}
+    }
+  }
+
+  for family in families {
+    let name = family_name(family);
+    let index = match report
+      .families
+      .iter()
+      .position(|usage| usage.family.eq_ignore_ascii_case(&name))
+    {
+      Some(index) => index,
+      None => {
+        report.families.push(FontFamilyUsage {
+          family: name,
+          declared: false,
+          weights: Vec::new(),
+          styles: Vec::new(),
+          features: Vec::new(),
+          content: Vec::new(),
+          locations: Vec::new(),
+        });
+        report.families.len() - 1
+      }
+    };
+
+    let usage = &mut report.families[index];
+    extend_unique(&mut usage.weights, &weights);
+    extend_unique(&mut usage.styles, &styles);
+    extend_unique(&mut usage.features, &features);
+    extend_unique(&mut usage.content, &content);
+    usage.locations.push(style.loc);
+  }
+
+  collect_font_usage(&style.rules, report);
+}
+
+fn family_name(family: &FontFamily) -> String {
+  match family {
+    FontFamily::FamilyName(name) => name.to_string(),
+    FontFamily::Generic(_) => family.to_css_string(PrinterOptions::default()).unwrap_or_default(),
+  }
+}
+
+fn extend_unique(dest: &mut Vec<String>, values: &[String]) {
+  for value in values {
+    if !dest.contains(value) {
+      dest.push(value.clone());
+    }
+  }
+}
@@ -9,6 +9,9 @@
 //! Rules are bundled following `@import` order, and wrapped in the necessary `@media`, `@supports`,
 //! and `@layer` rules as appropriate to preserve the authored behavior.
 //!
+//! Multiple entry files can be bundled at once using [bundle_chunks](Bundler::bundle_chunks),
+//! which extracts the rules shared between all of them into a common style sheet.
+//!
 //! # Example
 //!
 //! ```no_run
@@ -25,6 +28,7 @@
 
 use crate::{
   error::ErrorLocation,
+  macros::phase,
   parser::DefaultAtRuleParser,
   properties::{
     css_modules::Specifier,
@@ -89,6 +93,24 @@ struct BundleStyleSheet<'i, 'o, T> {
   loc: Location,
 }
 
+/// Identifies a file included in a bundle, along with the conditions it is wrapped in.
+#[derive(Clone, PartialEq)]
+struct FileKey<'i> {
+  path: PathBuf,
+  layer: Option<Option<LayerName<'i>>>,
+  media: MediaList<'i>,
+  supports: Option<SupportsCondition<'i>>,
+}
+
+/// The result of [bundle_chunks](Bundler::bundle_chunks).
+#[derive(Debug)]
+pub struct BundleChunks<'i, 'o, T> {
+  /// A style sheet containing the rules shared between all entries.
+  pub common: StyleSheet<'i, 'o, T>,
+  /// A style sheet for each entry, in the order they were given.
+  pub entries: Vec<StyleSheet<'i, 'o, T>>,
+}
+
 /// A trait to provide the contents of files to a Bundler.
 ///
 /// See [FileProvider](FileProvider) for an implementation that uses the
@@ -244,6 +266,96 @@ where
     &mut self,
     entry: &'e Path,
   ) -> Result<StyleSheet<'a, 'o, T::AtRule>, Error<BundleErrorKind<'a, P::Error>>> {
+    phase!("bundle");
+    self.load_entry(entry)?;
+
+    // Phase 3: concatenate.
+    let mut files = Vec::new();
+    self.inline(&mut files);
+    let rules = files.into_iter().flat_map(|(_, rules)| rules).collect();
+
+    Ok(self.build_stylesheet(rules))
+  }
+
+  /// Bundles multiple entry files, extracting rules shared between all of them into a common chunk.
+  ///
+  /// Files are shared only if they appear at the start of every entry in the same order and with
+  /// the same `@media`, `@supports`, and `@layer` conditions, which ensures that loading the common
+  /// chunk before each entry chunk preserves the cascade order. Each entry chunk starts with an
+  /// `@import` rule for the given `common_url` if the common chunk is not empty. Files that are shared
+  /// in other positions are included in each entry chunk as needed.
+  ///
+  /// Source maps are not supported when bundling multiple entries.
+  pub fn bundle_chunks<'e>(
+    &mut self,
+    entries: &[&'e Path],
+    common_url: &str,
+  ) -> Result<BundleChunks<'a, 'o, T::AtRule>, Error<BundleErrorKind<'a, P::Error>>> {
+    let mut chunks = Vec::new();
+    for entry in entries {
+      self.stylesheets.get_mut().unwrap().clear();
+      self.source_indexes.clear();
+      self.load_entry(entry)?;
+
+      let keys = self.file_keys();
+      let mut files = Vec::new();
+      self.inline(&mut files);
+      let files: Vec<_> = files
+        .into_iter()
+        .map(|(source_index, rules)| (keys[source_index as usize].clone(), rules))
+        .collect();
+      chunks.push((files, self.build_stylesheet(Vec::new())));
+    }
+
+    let shared = if chunks.len() < 2 {
+      0
+    } else {
+      let (first, _) = &chunks[0];
+      (0..first.len())
+        .take_while(|i| {
+          chunks
+            .iter()
+            .all(|(files, _)| files.get(*i).map(|f| &f.0) == Some(&first[*i].0))
+        })
+        .count()
+    };
+
+    let mut common = Vec::new();
+    let mut stylesheets = Vec::new();
+    for (files, mut stylesheet) in chunks {
+      let mut files = files.into_iter();
+      let prefix: Vec<_> = files.by_ref().take(shared).flat_map(|(_, rules)| rules).collect();
+      if stylesheets.is_empty() {
+        common = prefix;
+      }
+
+      let mut rules = Vec::new();
+      if shared > 0 {
+        rules.push(CssRule::Import(ImportRule {
+          url: common_url.to_owned().into(),
+          layer: None,
+          supports: None,
+          media: MediaList::new(),
+          loc: Location {
+            source_index: 0,
+            line: 0,
+            column: 0,
+          },
+        }));
+      }
+      rules.extend(files.flat_map(|(_, rules)| rules));
+      stylesheet.rules = CssRuleList(rules);
+      stylesheets.push(stylesheet);
+    }
+
+    let sources = stylesheets.first().map_or_else(Vec::new, |s| s.sources.clone());
+    Ok(BundleChunks {
+      common: StyleSheet::new(sources, CssRuleList(common), self.options.clone()),
+      entries: stylesheets,
+    })
+  }
+
+  fn load_entry(&mut self, entry: &Path) -> Result<(), Error<BundleErrorKind<'a, P::Error>>> {
     // Phase 1: load and parse all files. This is done in parallel.
     self.load_file(
       &entry,
@@ -262,11 +374,10 @@ where
 
     // Phase 2: determine the order that the files should be concatenated.
     self.order();
+    Ok(())
+  }
 
-    // Phase 3: concatenate.
-    let mut rules: Vec<CssRule<'a, T::AtRule>> = Vec::new();
-    self.inline(&mut rules);
-
+  fn build_stylesheet(&mut self, rules: Vec<CssRule<'a, T::AtRule>>) -> StyleSheet<'a, 'o, T::AtRule> {
     let sources = self
       .stylesheets
       .get_mut()
@@ -293,7 +404,29 @@ where
       .flat_map(|s| s.stylesheet.as_ref().unwrap().license_comments.iter().cloned())
       .collect();
 
-    Ok(stylesheet)
+    stylesheet
+  }
+
+  /// Returns a key identifying each loaded file along with the conditions it will be wrapped in.
+  fn file_keys(&mut self) -> Vec<FileKey<'a>> {
+    let mut paths = vec![PathBuf::new(); self.source_indexes.len()];
+    for entry in self.source_indexes.iter() {
+      paths[*entry.value() as usize] = entry.key().clone();
+    }
+
+    self
+      .stylesheets
+      .get_mut()
+      .unwrap()
+      .iter()
+      .zip(paths)
+      .map(|(s, path)| FileKey {
+        path,
+        layer: s.layer.clone(),
+        media: s.media.clone(),
+        supports: s.supports.clone(),
+      })
+      .collect()
   }
 
   fn find_filename(&self, source_index: u32) -> String {
@@ -311,30 +444,20 @@ where
         // from this import rule with the existing ones using a logical or operator.
         let entry = &mut stylesheets[*source_index as usize];
 
-        // We cannot combine a media query and a supports query from different @import rules.
-        // e.g. @import "a.css" print; @import "a.css" supports(color: red);
+        // We cannot combine a media query and a supports query from different @import rules
+        // unless one of them is shared. e.g. @import "a.css" print; @import "a.css" supports(color: red);
         // This would require duplicating the actual rules in the file.
-        if (!rule.media.media_queries.is_empty() && !entry.supports.is_none())
-          || (!entry.media.media_queries.is_empty() && !rule.supports.is_none())
-        {
-          return Err(Error {
-            kind: BundleErrorKind::UnsupportedImportCondition,
-            loc: Some(ErrorLocation::new(rule.loc, self.find_filename(rule.loc.source_index))),
-          });
-        }
-
-        if rule.media.media_queries.is_empty() {
-          entry.media.media_queries.clear();
-        } else if !entry.media.media_queries.is_empty() {
-          entry.media.or(&rule.media);
-        }
-
-        if let Some(supports) = rule.supports {
-          if let Some(existing_supports) = &mut entry.supports {
-            existing_supports.or(&supports)
+        match union_conditions((&entry.media, &entry.supports), (&rule.media, &rule.supports)) {
+          Some((media, supports)) => {
+            entry.media = media;
+            entry.supports = supports;
+          }
+          None => {
+            return Err(Error {
+              kind: BundleErrorKind::UnsupportedImportCondition,
+              loc: Some(ErrorLocation::new(rule.loc, self.find_filename(rule.loc.source_index))),
+            });
           }
-        } else {
-          entry.supports = None;
         }
 
         if let Some(layer) = &rule.layer {
@@ -410,6 +533,8 @@ where
       }
     }
 
+    merge_adjacent_imports(&mut stylesheet.rules.0);
+
     // Collect and load dependencies for this stylesheet in parallel.
     let dependencies: Result<Vec<u32>, _> = stylesheet
       .rules
@@ -600,12 +725,23 @@ where
   }
 
   fn order(&mut self) {
-    process(self.stylesheets.get_mut().unwrap(), 0, &mut HashSet::new());
-
+    process(
+      self.stylesheets.get_mut().unwrap(),
+      0,
+      &mut HashSet::new(),
+      &mut HashSet::new(),
+    );
+
+    // In browsers, every instance of an @import is evaluated, so the last instance of a file
+    // determines its position in the cascade. The import graph is traversed in reverse order,
+    // so that the first time a file is seen corresponds to its last instance. At that point,
+    // all of its dependencies are seen as well, so later (i.e. earlier in the cascade) instances
+    // of a file can be skipped entirely.
     fn process<'i, T>(
       stylesheets: &mut Vec<BundleStyleSheet<'i, '_, T>>,
       source_index: u32,
       visited: &mut HashSet<u32>,
+      composed: &mut HashSet<u32>,
     ) {
       if visited.contains(&source_index) {
         return;
@@ -613,42 +749,45 @@ where
 
       visited.insert(source_index);
 
-      let mut dep_index = 0;
-      for i in 0..stylesheets[source_index as usize].css_modules_deps.len() {
-        let dep_source_index = stylesheets[source_index as usize].css_modules_deps[i];
-        let resolved = &mut stylesheets[dep_source_index as usize];
-
-        // CSS modules preserve the first instance of composed stylesheets.
-        if !visited.contains(&dep_source_index) {
-          resolved.parent_dep_index = dep_index;
-          resolved.parent_source_index = source_index;
-          process(stylesheets, dep_source_index, visited);
+      let css_modules_deps_len = stylesheets[source_index as usize].css_modules_deps.len();
+      for i in (0..stylesheets[source_index as usize].dependencies.len()).rev() {
+        let dep_source_index = stylesheets[source_index as usize].dependencies[i];
+        if visited.contains(&dep_source_index) {
+          continue;
         }
 
-        dep_index += 1;
+        let resolved = &mut stylesheets[dep_source_index as usize];
+        resolved.parent_dep_index = (css_modules_deps_len + i) as u32;
+        resolved.parent_source_index = source_index;
+        process(stylesheets, dep_source_index, visited, composed);
       }
 
-      for i in 0..stylesheets[source_index as usize].dependencies.len() {
-        let dep_source_index = stylesheets[source_index as usize].dependencies[i];
-        let resolved = &mut stylesheets[dep_source_index as usize];
+      for i in (0..css_modules_deps_len).rev() {
+        let dep_source_index = stylesheets[source_index as usize].css_modules_deps[i];
 
-        // In browsers, every instance of an @import is evaluated, so we preserve the last.
-        resolved.parent_dep_index = dep_index;
-        resolved.parent_source_index = source_index;
+        // CSS modules preserve the first instance of composed stylesheets,
+        // which is the last one seen in reverse order.
+        if visited.contains(&dep_source_index) && !composed.contains(&dep_source_index) {
+          continue;
+        }
 
-        process(stylesheets, dep_source_index, visited);
-        dep_index += 1;
+        let resolved = &mut stylesheets[dep_source_index as usize];
+        resolved.parent_dep_index = i as u32;
+        resolved.parent_source_index = source_index;
+        composed.insert(dep_source_index);
+        process(stylesheets, dep_source_index, visited, composed);
       }
     }
   }
 
-  fn inline(&mut self, dest: &mut Vec<CssRule<'a, T::AtRule>>) {
+  /// Concatenates the rules of each file in order, grouped by source index.
+  fn inline(&mut self, dest: &mut Vec<(u32, Vec<CssRule<'a, T::AtRule>>)>) {
     process(self.stylesheets.get_mut().unwrap(), 0, dest);
 
     fn process<'a, T>(
       stylesheets: &mut Vec<BundleStyleSheet<'a, '_, T>>,
       source_index: u32,
-      dest: &mut Vec<CssRule<'a, T>>,
+      dest: &mut Vec<(u32, Vec<CssRule<'a, T>>)>,
     ) {
       let stylesheet = &mut stylesheets[source_index as usize];
       let mut rules = std::mem::take(&mut stylesheet.stylesheet.as_mut().unwrap().rules.0);
@@ -687,7 +826,7 @@ where
             // @layer rules are the only rules that may appear before an @import.
             // We must preserve this order to ensure correctness.
             let layer = std::mem::replace(rule, CssRule::Ignored);
-            dest.push(layer);
+            dest.push((source_index, vec![layer]));
           }
           CssRule::Ignored => {}
           _ => break,
@@ -721,11 +860,80 @@ where
         })]
       }
 
-      dest.extend(rules);
+      dest.push((source_index, rules));
     }
   }
 }
 
+/// Merges consecutive @import rules of the same file into a single rule whose conditions
+/// are the union of the originals, e.g. `@import "a.css" print; @import "a.css" screen;`
+/// becomes `@import "a.css" print, screen;`. Since no other rules can appear between them,
+/// including the file once at the position of the last rule is equivalent.
+fn merge_adjacent_imports<T>(rules: &mut [CssRule<'_, T>]) {
+  for i in 1..rules.len() {
+    let (before, after) = rules.split_at_mut(i);
+    let (CssRule::Import(prev), CssRule::Import(import)) = (&before[i - 1], &mut after[0]) else {
+      continue;
+    };
+
+    // Anonymous layers are distinct each time they are imported, so they cannot be merged.
+    if prev.url != import.url || prev.layer != import.layer || import.layer == Some(None) {
+      continue;
+    }
+
+    if let Some((media, supports)) =
+      union_conditions((&prev.media, &prev.supports), (&import.media, &import.supports))
+    {
+      import.media = media;
+      import.supports = supports;
+      before[i - 1] = CssRule::Ignored;
+    }
+  }
+}
+
+/// Combines the media queries and supports conditions of two imports into a single
+/// condition that matches when either of them would. Returns `None` if this cannot
+/// be expressed without duplicating the rules, i.e. if the imports differ in both
+/// their media queries and supports conditions.
+fn union_conditions<'a>(
+  a: (&MediaList<'a>, &Option<SupportsCondition<'a>>),
+  b: (&MediaList<'a>, &Option<SupportsCondition<'a>>),
+) -> Option<(MediaList<'a>, Option<SupportsCondition<'a>>)> {
+  let (a_media, a_supports) = a;
+  let (b_media, b_supports) = b;
+  let is_unconditional =
+    |media: &MediaList, supports: &Option<SupportsCondition>| media.media_queries.is_empty() && supports.is_none();
+
+  if is_unconditional(a_media, a_supports) || is_unconditional(b_media, b_supports) {
+    return Some((MediaList::new(), None));
+  }
+
+  if a_supports == b_supports {
+    let media = if a_media.media_queries.is_empty() || b_media.media_queries.is_empty() {
+      MediaList::new()
+    } else {
+      let mut media = a_media.clone();
+      media.or(b_media);
+      media
+    };
+    return Some((media, a_supports.clone()));
+  }
+
+  if a_media == b_media {
+    let supports = match (a_supports, b_supports) {
+      (Some(a), Some(b)) => {
+        let mut supports = a.clone();
+        supports.or(b);
+        Some(supports)
+      }
+      _ => None,
+    };
+    return Some((a_media.clone(), supports));
+  }
+
+  None
+}
+
 fn combine_supports<'a>(
   a: Option<SupportsCondition<'a>>,
   b: &Option<SupportsCondition<'a>>,
@@ -762,6 +970,16 @@ fn visit_vars<'a, 'b>(
             return Some(name);
           }
         }
+        Some(TokenOrValue::Attr(attr)) => {
+          if let Some(fallback) = &mut attr.fallback {
+            stack.push(fallback.0.iter_mut());
+          }
+        }
+        Some(TokenOrValue::Toggle(toggle)) => {
+          for value in &mut toggle.values {
+            stack.push(value.0.iter_mut());
+          }
+        }
         Some(TokenOrValue::UnresolvedColor(color)) => match color {
           UnresolvedColor::RGB { alpha, .. } | UnresolvedColor::HSL { alpha, .. } => {
             stack.push(alpha.0.iter_mut());
@@ -1675,6 +1893,62 @@ mod tests {
     "#}
     );
 
+    let res = bundle(
+      TestProvider {
+        map: fs! {
+          "/index.css": r#"
+          @import "a.css";
+          @import "y.css";
+          @import "z.css";
+          @import "b.css";
+        "#,
+          "/a.css": r#"
+          @import "x.css";
+          .a { color: red }
+        "#,
+          "/b.css": r#"
+          @import "x.css";
+          .b { color: red }
+        "#,
+          "/x.css": r#"
+          @import "y.css";
+          .x { color: red }
+        "#,
+          "/y.css": r#"
+          .y { color: green }
+        "#,
+          "/z.css": r#"
+          .z { color: green }
+        "#
+        },
+      },
+      "/index.css",
+    );
+    assert_eq!(
+      res,
+      indoc! { r#"
+      .a {
+        color: red;
+      }
+
+      .z {
+        color: green;
+      }
+
+      .y {
+        color: green;
+      }
+
+      .x {
+        color: red;
+      }
+
+      .b {
+        color: red;
+      }
+    "#}
+    );
+
     let res = bundle(
       CustomProvider {
         map: fs! {
@@ -1724,6 +1998,85 @@ mod tests {
       })),
     );
 
+    let res = bundle(
+      TestProvider {
+        map: fs! {
+          "/a.css": r#"
+          @import "b.css" supports(display: grid) print;
+          @import "b.css" supports(display: grid) screen;
+          .a { color: red }
+        "#,
+          "/b.css": r#"
+          .b { color: green }
+        "#
+        },
+      },
+      "/a.css",
+    );
+    assert_eq!(
+      res,
+      indoc! { r#"
+      @supports (display: grid) {
+        @media print, screen {
+          .b {
+            color: green;
+          }
+        }
+      }
+
+      .a {
+        color: red;
+      }
+    "#}
+    );
+
+    let res = bundle(
+      TestProvider {
+        map: fs! {
+          "/a.css": r#"
+          @import "b.css" supports(display: grid) print;
+          @import "b.css";
+          .a { color: red }
+        "#,
+          "/b.css": r#"
+          .b { color: green }
+        "#
+        },
+      },
+      "/a.css",
+    );
+    assert_eq!(
+      res,
+      indoc! { r#"
+      .b {
+        color: green;
+      }
+
+      .a {
+        color: red;
+      }
+    "#}
+    );
+
+    error_test(
+      TestProvider {
+        map: fs! {
+          "/a.css": r#"
+          @import "b.css" supports(color: red) (color);
+          @import "b.css" supports(foo: bar) (orientation: landscape);
+          .a { color: red }
+        "#,
+          "/b.css": r#"
+          .b { color: green }
+        "#
+        },
+      },
+      "/a.css",
+      Some(Box::new(|err| {
+        assert!(matches!(err, BundleErrorKind::UnsupportedImportCondition));
+      })),
+    );
+
     // let res = bundle(fs! {
     //   "/a.css": r#"
     //     @import "b.css" supports(color: red) (color);
@@ -2058,4 +2411,82 @@ mod tests {
     "#}
     );
   }
+
+  #[test]
+  fn test_bundle_chunks() {
+    fn bundle_chunks<P: SourceProvider>(fs: P, entries: &[&str]) -> (String, Vec<String>) {
+      let mut bundler = Bundler::new(&fs, None, ParserOptions::default());
+      let entries: Vec<&Path> = entries.iter().map(|entry| Path::new(*entry)).collect();
+      let chunks = bundler.bundle_chunks(&entries, "common.css").unwrap();
+      let options = || PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      };
+      (
+        chunks.common.to_css(options()).unwrap().code,
+        chunks
+          .entries
+          .iter()
+          .map(|entry| entry.to_css(options()).unwrap().code)
+          .collect(),
+      )
+    }
+
+    let fs = TestProvider {
+      map: fs! {
+        "/a.css": r#"
+        @import "reset.css";
+        @import "base.css";
+        .a { color: red }
+      "#,
+        "/b.css": r#"
+        @import "reset.css";
+        @import "base.css" layer(base);
+        .b { color: red }
+      "#,
+        "/c.css": r#"
+        @import "reset.css";
+        @import "base.css";
+        @import "util.css";
+        .c { color: red }
+      "#,
+        "/base.css": r#"
+        @import "util.css";
+        .base { color: green }
+      "#,
+        "/reset.css": ".reset { margin: 0 }",
+        "/util.css": ".util { color: blue }"
+      },
+    };
+
+    let (common, entries) = bundle_chunks(fs.clone(), &["/a.css", "/c.css"]);
+    assert_eq!(common, ".reset{margin:0}");
+    assert_eq!(
+      entries,
+      vec![
+        "@import \"common.css\";.util{color:#00f}.base{color:green}.a{color:red}",
+        "@import \"common.css\";.base{color:green}.util{color:#00f}.c{color:red}",
+      ]
+    );
+
+    let (common, entries) = bundle_chunks(fs.clone(), &["/a.css", "/b.css"]);
+    assert_eq!(common, ".reset{margin:0}");
+    assert_eq!(
+      entries,
+      vec![
+        "@import \"common.css\";.util{color:#00f}.base{color:green}.a{color:red}",
+        "@import \"common.css\";@layer base{.util{color:#00f}}@layer base{.base{color:green}}.b{color:red}",
+      ]
+    );
+
+    let (common, entries) = bundle_chunks(fs, &["/base.css", "/a.css"]);
+    assert_eq!(common, "");
+    assert_eq!(
+      entries,
+      vec![
+        ".util{color:#00f}.base{color:green}",
+        ".reset{margin:0}.util{color:#00f}.base{color:green}.a{color:red}",
+      ]
+    );
+  }
 }
@@ -26,6 +26,7 @@ pub enum Feature {
   CalcFunction,
   CambodianListStyleType,
   CapUnit,
+  CaretShorthand,
   CaseInsensitive,
   ChUnit,
   CircleListStyleType,
@@ -36,10 +37,12 @@ pub enum Feature {
   ColorFunction,
   ConicGradient,
   ContainerQueryLengthUnits,
+  ContrastColor,
   Cue,
   CueFunction,
   CustomMediaQueries,
   DecimalLeadingZeroListStyleType,
+  DeviceCmyk,
   DecimalListStyleType,
   DefaultPseudo,
   DevanagariListStyleType,
@@ -74,10 +77,18 @@ pub enum Feature {
   FocusVisible,
   FocusWithin,
   FontFamilySystemUi,
+  FontFormatEmbeddedOpenType,
+  FontFormatSvg,
+  FontFormatWoff,
+  FontFormatWoff2,
   FontSizeRem,
   FontSizeXXXLarge,
   FontStretchPercentage,
   FontStyleObliqueAngle,
+  FontSynthesisPosition,
+  FontSynthesisSmallCaps,
+  FontSynthesisStyle,
+  FontSynthesisWeight,
   FontWeightNumber,
   FootnotesListStyleType,
   FormValidation,
@@ -97,6 +108,7 @@ pub enum Feature {
   ImageSet,
   InOutOfRange,
   IndeterminatePseudo,
+  IndividualTransforms,
   IsAnimatableSize,
   IsSelector,
   JapaneseFormalListStyleType,
@@ -113,6 +125,7 @@ pub enum Feature {
   LaoListStyleType,
   LhUnit,
   LightDark,
+  LineClamp,
   LinearGradient,
   LogicalBorderRadius,
   LogicalBorderShorthand,
@@ -154,7 +167,9 @@ pub enum Feature {
   OriyaListStyleType,
   OromoListStyleType,
   OverflowShorthand,
+  OverscrollBehaviorShorthand,
   P3Colors,
+  PaintFunction,
   PartPseudo,
   PersianListStyleType,
   PlaceContent,
@@ -167,6 +182,8 @@ pub enum Feature {
   RcapUnit,
   RchUnit,
   ReadOnlyWrite,
+  ReadingFlow,
+  ReadingOrder,
   RemFunction,
   RemUnit,
   RepeatingConicGradient,
@@ -176,10 +193,14 @@ pub enum Feature {
   RicUnit,
   RlhUnit,
   RoundFunction,
+  ScrollbarColor,
+  ScrollbarWidth,
   Selection,
   Selectors2,
   Selectors3,
   Shadowdomv1,
+  SiblingCountFunction,
+  SiblingIndexFunction,
   SidamaListStyleType,
   SignFunction,
   SimpChineseFormalListStyleType,
@@ -189,11 +210,16 @@ pub enum Feature {
   SquareListStyleType,
   StretchSize,
   StringListStyleType,
+  SupportsFontFormat,
+  SupportsFontTech,
+  SupportsSelector,
   SymbolsListStyleType,
   TamilListStyleType,
   TeluguListStyleType,
+  TextAutospace,
   TextDecorationThicknessPercent,
   TextDecorationThicknessShorthand,
+  TextSpacingTrim,
   ThaiListStyleType,
   TibetanListStyleType,
   TigreListStyleType,
@@ -203,6 +229,7 @@ pub enum Feature {
   TigrinyaEtListStyleType,
   TradChineseFormalListStyleType,
   TradChineseInformalListStyleType,
+  TransitionBehavior,
   UpperAlphaListStyleType,
   UpperArmenianListStyleType,
   UpperHexadecimalListStyleType,
@@ -219,7 +246,11 @@ pub enum Feature {
   VminUnit,
   VwUnit,
   WebkitFillAvailableSize,
+  WebkitOverflowScrolling,
+  WebkitTapHighlightColor,
+  WebkitTouchCallout,
   XResolutionUnit,
+  Zoom,
 }
 
 impl Feature {
@@ -1862,7 +1893,7 @@ impl Feature {
           return false;
         }
       }
-      Feature::MediaRangeSyntax => {
+      Feature::IndividualTransforms => {
         if let Some(version) = browsers.chrome {
           if version < 6815744 {
             return false;
@@ -1874,22 +1905,22 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 4128768 {
+          if version < 4718592 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 4653056 {
+          if version < 5898240 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 1049600 {
+          if version < 917760 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 1049600 {
+          if version < 918784 {
             return false;
           }
         }
@@ -1907,44 +1938,44 @@ impl Feature {
           return false;
         }
       }
-      Feature::MediaIntervalSyntax => {
+      Feature::TransitionBehavior => {
         if let Some(version) = browsers.chrome {
-          if version < 6815744 {
+          if version < 7667712 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 6815744 {
+          if version < 7667712 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 6684672 {
+          if version < 8454144 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 4653056 {
+          if version < 6750208 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 1049600 {
+          if version < 1115136 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 1049600 {
+          if version < 1115136 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 1310720 {
+          if version < 1572864 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 6815744 {
+          if version < 7667712 {
             return false;
           }
         }
@@ -1952,179 +1983,173 @@ impl Feature {
           return false;
         }
       }
-      Feature::LogicalBorders => {
+      Feature::ReadingFlow | Feature::ReadingOrder => {
         if let Some(version) = browsers.chrome {
-          if version < 4521984 {
+          if version < 8978432 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 8978432 {
             return false;
           }
         }
-        if let Some(version) = browsers.firefox {
-          if version < 2686976 {
+        if let Some(version) = browsers.opera {
+          if version < 7929856 {
             return false;
           }
         }
-        if let Some(version) = browsers.opera {
-          if version < 3145728 {
+        if let Some(version) = browsers.android {
+          if version < 8978432 {
             return false;
           }
         }
-        if let Some(version) = browsers.safari {
-          if version < 786688 {
+        if browsers.firefox.is_some()
+          || browsers.ie.is_some()
+          || browsers.ios_saf.is_some()
+          || browsers.safari.is_some()
+          || browsers.samsung.is_some()
+        {
+          return false;
+        }
+      }
+      Feature::CaretShorthand => {
+        if let Some(version) = browsers.chrome {
+          if version < 8781824 {
             return false;
           }
         }
-        if let Some(version) = browsers.ios_saf {
-          if version < 786944 {
+        if let Some(version) = browsers.edge {
+          if version < 8781824 {
             return false;
           }
         }
-        if let Some(version) = browsers.samsung {
-          if version < 655360 {
+        if let Some(version) = browsers.opera {
+          if version < 7798784 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 4521984 {
+          if version < 8781824 {
             return false;
           }
         }
-        if browsers.ie.is_some() {
+        if browsers.firefox.is_some()
+          || browsers.ie.is_some()
+          || browsers.ios_saf.is_some()
+          || browsers.safari.is_some()
+          || browsers.samsung.is_some()
+        {
           return false;
         }
       }
-      Feature::LogicalBorderShorthand | Feature::LogicalMarginShorthand | Feature::LogicalPaddingShorthand => {
+      Feature::TextSpacingTrim => {
         if let Some(version) = browsers.chrome {
-          if version < 5701632 {
+          if version < 8060928 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5701632 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.firefox {
-          if version < 4325376 {
+          if version < 8060928 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 4063232 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.safari {
-          if version < 917760 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.ios_saf {
-          if version < 918784 {
+          if version < 7143424 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 917504 {
+          if version < 1769472 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 5701632 {
+          if version < 8060928 {
             return false;
           }
         }
-        if browsers.ie.is_some() {
+        if browsers.firefox.is_some()
+          || browsers.ie.is_some()
+          || browsers.ios_saf.is_some()
+          || browsers.safari.is_some()
+        {
           return false;
         }
       }
-      Feature::LogicalBorderRadius => {
+      Feature::TextAutospace => {
         if let Some(version) = browsers.chrome {
-          if version < 5832704 {
+          if version < 9175040 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5832704 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.firefox {
-          if version < 4325376 {
+          if version < 9175040 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 4128768 {
+          if version < 8126464 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 983040 {
+          if version < 1180672 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 983040 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.samsung {
-          if version < 983040 {
+          if version < 1180672 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 5832704 {
+          if version < 9175040 {
             return false;
           }
         }
-        if browsers.ie.is_some() {
+        if browsers.firefox.is_some() || browsers.ie.is_some() || browsers.samsung.is_some() {
           return false;
         }
       }
-      Feature::LogicalMargin | Feature::LogicalPadding => {
+      Feature::ScrollbarWidth => {
         if let Some(version) = browsers.chrome {
-          if version < 4521984 {
+          if version < 7929856 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 7929856 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 2686976 {
+          if version < 4194304 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 3145728 {
+          if version < 7012352 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 786688 {
+          if version < 1180160 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 786944 {
+          if version < 1180160 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 655360 {
+          if version < 1638400 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 5701632 {
+          if version < 7929856 {
             return false;
           }
         }
@@ -2132,89 +2157,79 @@ impl Feature {
           return false;
         }
       }
-      Feature::LogicalInset => {
+      Feature::ScrollbarColor => {
         if let Some(version) = browsers.chrome {
-          if version < 5701632 {
+          if version < 7929856 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5701632 {
+          if version < 7929856 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 4128768 {
+          if version < 4194304 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 4063232 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.safari {
-          if version < 917760 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.ios_saf {
-          if version < 918784 {
+          if version < 7012352 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 917504 {
+          if version < 1638400 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 5701632 {
+          if version < 7929856 {
             return false;
           }
         }
-        if browsers.ie.is_some() {
+        if browsers.ie.is_some() || browsers.ios_saf.is_some() || browsers.safari.is_some() {
           return false;
         }
       }
-      Feature::LogicalSize => {
+      Feature::FontSynthesisWeight | Feature::FontSynthesisStyle | Feature::FontSynthesisSmallCaps => {
         if let Some(version) = browsers.chrome {
-          if version < 3735552 {
+          if version < 6356992 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 6356992 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 2686976 {
+          if version < 7274496 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 2818048 {
+          if version < 5439488 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 786688 {
+          if version < 1049600 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 786944 {
+          if version < 1049600 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 327680 {
+          if version < 1179648 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 3735552 {
+          if version < 6356992 {
             return false;
           }
         }
@@ -2222,44 +2237,62 @@ impl Feature {
           return false;
         }
       }
-      Feature::LogicalTextAlign => {
+      Feature::FontSynthesisPosition => {
+        if let Some(version) = browsers.firefox {
+          if version < 7733248 {
+            return false;
+          }
+        }
+        if browsers.android.is_some()
+          || browsers.chrome.is_some()
+          || browsers.edge.is_some()
+          || browsers.ie.is_some()
+          || browsers.ios_saf.is_some()
+          || browsers.opera.is_some()
+          || browsers.safari.is_some()
+          || browsers.samsung.is_some()
+        {
+          return false;
+        }
+      }
+      Feature::OverscrollBehaviorShorthand => {
         if let Some(version) = browsers.chrome {
-          if version < 1179648 {
+          if version < 4128768 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 1179648 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 262144 {
+          if version < 3866624 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 917504 {
+          if version < 3276800 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 196864 {
+          if version < 1048576 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 131072 {
+          if version < 1048576 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 65536 {
+          if version < 524288 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 2424832 {
+          if version < 4128768 {
             return false;
           }
         }
@@ -2267,44 +2300,44 @@ impl Feature {
           return false;
         }
       }
-      Feature::LabColors => {
+      Feature::MediaRangeSyntax => {
         if let Some(version) = browsers.chrome {
-          if version < 7274496 {
+          if version < 6815744 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 7274496 {
+          if version < 6815744 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 7405568 {
+          if version < 4128768 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 4915200 {
+          if version < 4653056 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 983040 {
+          if version < 1049600 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 983040 {
+          if version < 1049600 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 1441792 {
+          if version < 1310720 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 7274496 {
+          if version < 6815744 {
             return false;
           }
         }
@@ -2312,44 +2345,44 @@ impl Feature {
           return false;
         }
       }
-      Feature::OklabColors => {
+      Feature::MediaIntervalSyntax => {
         if let Some(version) = browsers.chrome {
-          if version < 7274496 {
+          if version < 6815744 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 7274496 {
+          if version < 6815744 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 7405568 {
+          if version < 6684672 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 4915200 {
+          if version < 4653056 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 984064 {
+          if version < 1049600 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 984064 {
+          if version < 1049600 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 1441792 {
+          if version < 1310720 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 7274496 {
+          if version < 6815744 {
             return false;
           }
         }
@@ -2357,44 +2390,44 @@ impl Feature {
           return false;
         }
       }
-      Feature::ColorFunction => {
+      Feature::LogicalBorders => {
         if let Some(version) = browsers.chrome {
-          if version < 7274496 {
+          if version < 4521984 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 7274496 {
+          if version < 5177344 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 7405568 {
+          if version < 2686976 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 4915200 {
+          if version < 3145728 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 655616 {
+          if version < 786688 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 656128 {
+          if version < 786944 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 1441792 {
+          if version < 655360 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 7274496 {
+          if version < 4521984 {
             return false;
           }
         }
@@ -2402,44 +2435,44 @@ impl Feature {
           return false;
         }
       }
-      Feature::SpaceSeparatedColorNotation => {
+      Feature::LogicalBorderShorthand | Feature::LogicalMarginShorthand | Feature::LogicalPaddingShorthand => {
         if let Some(version) = browsers.chrome {
-          if version < 4259840 {
+          if version < 5701632 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 5701632 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 3407872 {
+          if version < 4325376 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 3080192 {
+          if version < 4063232 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 786688 {
+          if version < 917760 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 786944 {
+          if version < 918784 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 589824 {
+          if version < 917504 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 4259840 {
+          if version < 5701632 {
             return false;
           }
         }
@@ -2447,44 +2480,44 @@ impl Feature {
           return false;
         }
       }
-      Feature::TextDecorationThicknessPercent => {
+      Feature::LogicalBorderRadius => {
         if let Some(version) = browsers.chrome {
-          if version < 5701632 {
+          if version < 5832704 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5701632 {
+          if version < 5832704 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 5177344 {
+          if version < 4325376 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 4063232 {
+          if version < 4128768 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 1115136 {
+          if version < 983040 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 1115136 {
+          if version < 983040 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 917504 {
+          if version < 983040 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 5701632 {
+          if version < 5832704 {
             return false;
           }
         }
@@ -2492,29 +2525,39 @@ impl Feature {
           return false;
         }
       }
-      Feature::TextDecorationThicknessShorthand => {
+      Feature::LogicalMargin | Feature::LogicalPadding => {
         if let Some(version) = browsers.chrome {
-          if version < 5701632 {
+          if version < 4521984 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5701632 {
+          if version < 5177344 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 5177344 {
+          if version < 2686976 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 4063232 {
+          if version < 3145728 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 786688 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 786944 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 917504 {
+          if version < 655360 {
             return false;
           }
         }
@@ -2523,48 +2566,48 @@ impl Feature {
             return false;
           }
         }
-        if browsers.ie.is_some() || browsers.ios_saf.is_some() || browsers.safari.is_some() {
+        if browsers.ie.is_some() {
           return false;
         }
       }
-      Feature::Cue => {
+      Feature::LogicalInset => {
         if let Some(version) = browsers.chrome {
-          if version < 1703936 {
+          if version < 5701632 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 5701632 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 3604480 {
+          if version < 4128768 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 917504 {
+          if version < 4063232 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 458752 {
+          if version < 917760 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 458752 {
+          if version < 918784 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 66816 {
+          if version < 917504 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 263168 {
+          if version < 5701632 {
             return false;
           }
         }
@@ -2572,9 +2615,9 @@ impl Feature {
           return false;
         }
       }
-      Feature::CueFunction => {
+      Feature::LogicalSize => {
         if let Some(version) = browsers.chrome {
-          if version < 1703936 {
+          if version < 3735552 {
             return false;
           }
         }
@@ -2583,36 +2626,41 @@ impl Feature {
             return false;
           }
         }
+        if let Some(version) = browsers.firefox {
+          if version < 2686976 {
+            return false;
+          }
+        }
         if let Some(version) = browsers.opera {
-          if version < 917504 {
+          if version < 2818048 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 458752 {
+          if version < 786688 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 458752 {
+          if version < 786944 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 66816 {
+          if version < 327680 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 263168 {
+          if version < 3735552 {
             return false;
           }
         }
-        if browsers.firefox.is_some() || browsers.ie.is_some() {
+        if browsers.ie.is_some() {
           return false;
         }
       }
-      Feature::AnyPseudo => {
+      Feature::LogicalTextAlign => {
         if let Some(version) = browsers.chrome {
           if version < 1179648 {
             return false;
@@ -2634,12 +2682,12 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 327680 {
+          if version < 196864 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 327680 {
+          if version < 131072 {
             return false;
           }
         }
@@ -2657,44 +2705,44 @@ impl Feature {
           return false;
         }
       }
-      Feature::PartPseudo => {
+      Feature::LabColors => {
         if let Some(version) = browsers.chrome {
-          if version < 4784128 {
+          if version < 7274496 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 7274496 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 5177344 {
+          if version < 7405568 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 3407872 {
+          if version < 4915200 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 852224 {
+          if version < 983040 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 852992 {
+          if version < 983040 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 720896 {
+          if version < 1441792 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 4784128 {
+          if version < 7274496 {
             return false;
           }
         }
@@ -2702,44 +2750,44 @@ impl Feature {
           return false;
         }
       }
-      Feature::ImageSet => {
+      Feature::OklabColors => {
         if let Some(version) = browsers.chrome {
-          if version < 1638400 {
+          if version < 7274496 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 7274496 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 5767168 {
+          if version < 7405568 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 917504 {
+          if version < 4915200 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 393216 {
+          if version < 984064 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 393216 {
+          if version < 984064 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 66816 {
+          if version < 1441792 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 263168 {
+          if version < 7274496 {
             return false;
           }
         }
@@ -2747,79 +2795,89 @@ impl Feature {
           return false;
         }
       }
-      Feature::XResolutionUnit => {
+      Feature::ColorFunction => {
         if let Some(version) = browsers.chrome {
-          if version < 4456448 {
+          if version < 7274496 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 7274496 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 4063232 {
+          if version < 7405568 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 3145728 {
+          if version < 4915200 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 655616 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 656128 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 655360 {
+          if version < 1441792 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 4456448 {
+          if version < 7274496 {
             return false;
           }
         }
-        if browsers.ie.is_some() || browsers.ios_saf.is_some() || browsers.safari.is_some() {
+        if browsers.ie.is_some() {
           return false;
         }
       }
-      Feature::NthChildOf => {
+      Feature::SpaceSeparatedColorNotation => {
         if let Some(version) = browsers.chrome {
-          if version < 7274496 {
+          if version < 4259840 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 7274496 {
+          if version < 5177344 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 7405568 {
+          if version < 3407872 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 4915200 {
+          if version < 3080192 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 589824 {
+          if version < 786688 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 589824 {
+          if version < 786944 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 1441792 {
+          if version < 589824 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 7274496 {
+          if version < 4259840 {
             return false;
           }
         }
@@ -2827,14 +2885,14 @@ impl Feature {
           return false;
         }
       }
-      Feature::MinFunction | Feature::MaxFunction => {
+      Feature::TextDecorationThicknessPercent => {
         if let Some(version) = browsers.chrome {
-          if version < 5177344 {
+          if version < 5701632 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 5701632 {
             return false;
           }
         }
@@ -2844,27 +2902,27 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 3735552 {
+          if version < 4063232 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 721152 {
+          if version < 1115136 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 721664 {
+          if version < 1115136 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 786432 {
+          if version < 917504 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 5177344 {
+          if version < 5701632 {
             return false;
           }
         }
@@ -2872,120 +2930,129 @@ impl Feature {
           return false;
         }
       }
-      Feature::RoundFunction | Feature::RemFunction | Feature::ModFunction => {
+      Feature::TextDecorationThicknessShorthand => {
         if let Some(version) = browsers.chrome {
-          if version < 8192000 {
+          if version < 5701632 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 8192000 {
+          if version < 5701632 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 7733248 {
+          if version < 5177344 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 7274496 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.safari {
-          if version < 984064 {
+          if version < 4063232 {
             return false;
           }
         }
-        if let Some(version) = browsers.ios_saf {
-          if version < 984064 {
+        if let Some(version) = browsers.samsung {
+          if version < 917504 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 8192000 {
+          if version < 5701632 {
             return false;
           }
         }
-        if browsers.ie.is_some() || browsers.samsung.is_some() {
+        if browsers.ie.is_some() || browsers.ios_saf.is_some() || browsers.safari.is_some() {
           return false;
         }
       }
-      Feature::AbsFunction | Feature::SignFunction => {
-        if let Some(version) = browsers.firefox {
-          if version < 7733248 {
+      Feature::Cue => {
+        if let Some(version) = browsers.chrome {
+          if version < 1703936 {
             return false;
           }
         }
-        if let Some(version) = browsers.safari {
-          if version < 984064 {
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
             return false;
           }
         }
-        if let Some(version) = browsers.ios_saf {
-          if version < 984064 {
+        if let Some(version) = browsers.firefox {
+          if version < 3604480 {
             return false;
           }
         }
-        if browsers.android.is_some()
-          || browsers.chrome.is_some()
-          || browsers.edge.is_some()
-          || browsers.ie.is_some()
-          || browsers.opera.is_some()
-          || browsers.samsung.is_some()
-        {
-          return false;
-        }
-      }
-      Feature::HypotFunction => {
-        if let Some(version) = browsers.chrome {
-          if version < 7864320 {
+        if let Some(version) = browsers.opera {
+          if version < 917504 {
             return false;
           }
         }
-        if let Some(version) = browsers.edge {
-          if version < 7864320 {
+        if let Some(version) = browsers.safari {
+          if version < 458752 {
             return false;
           }
         }
-        if let Some(version) = browsers.firefox {
-          if version < 7733248 {
+        if let Some(version) = browsers.ios_saf {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 66816 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 263168 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::CueFunction => {
+        if let Some(version) = browsers.chrome {
+          if version < 1703936 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 5242880 {
+          if version < 917504 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 984064 {
+          if version < 458752 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 984064 {
+          if version < 458752 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 1638400 {
+          if version < 66816 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 7864320 {
+          if version < 263168 {
             return false;
           }
         }
-        if browsers.ie.is_some() {
+        if browsers.firefox.is_some() || browsers.ie.is_some() {
           return false;
         }
       }
-      Feature::GradientInterpolationHints => {
+      Feature::AnyPseudo => {
         if let Some(version) = browsers.chrome {
-          if version < 2621440 {
+          if version < 1179648 {
             return false;
           }
         }
@@ -2995,32 +3062,32 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 2359296 {
+          if version < 262144 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 1769472 {
+          if version < 917504 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 458752 {
+          if version < 327680 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 458752 {
+          if version < 327680 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 262144 {
+          if version < 65536 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 2621440 {
+          if version < 2424832 {
             return false;
           }
         }
@@ -3028,185 +3095,169 @@ impl Feature {
           return false;
         }
       }
-      Feature::BorderImageRepeatRound => {
+      Feature::PartPseudo => {
         if let Some(version) = browsers.chrome {
-          if version < 1966080 {
+          if version < 4784128 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 786432 {
+          if version < 5177344 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 983040 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.ie {
-          if version < 720896 {
+          if version < 5177344 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 1179648 {
+          if version < 3407872 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 590080 {
+          if version < 852224 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 590592 {
+          if version < 852992 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 131072 {
+          if version < 720896 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 263168 {
+          if version < 4784128 {
             return false;
           }
         }
+        if browsers.ie.is_some() {
+          return false;
+        }
       }
-      Feature::BorderImageRepeatSpace => {
+      Feature::ImageSet => {
         if let Some(version) = browsers.chrome {
-          if version < 3670016 {
+          if version < 1638400 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 786432 {
+          if version < 5177344 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 3276800 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.ie {
-          if version < 720896 {
+          if version < 5767168 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 2818048 {
+          if version < 917504 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 590080 {
+          if version < 393216 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 590592 {
+          if version < 393216 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 393216 {
+          if version < 66816 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 3670016 {
+          if version < 263168 {
             return false;
           }
         }
+        if browsers.ie.is_some() {
+          return false;
+        }
       }
-      Feature::FontSizeRem => {
+      Feature::XResolutionUnit => {
         if let Some(version) = browsers.chrome {
-          if version < 2752512 {
+          if version < 4456448 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 786432 {
+          if version < 5177344 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 2031616 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.ie {
-          if version < 589824 {
+          if version < 4063232 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 1835008 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.safari {
-          if version < 458752 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.ios_saf {
-          if version < 458752 {
+          if version < 3145728 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 262144 {
+          if version < 655360 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 2752512 {
+          if version < 4456448 {
             return false;
           }
         }
+        if browsers.ie.is_some() || browsers.ios_saf.is_some() || browsers.safari.is_some() {
+          return false;
+        }
       }
-      Feature::FontSizeXXXLarge => {
+      Feature::NthChildOf => {
         if let Some(version) = browsers.chrome {
-          if version < 5177344 {
+          if version < 7274496 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 7274496 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 5177344 {
+          if version < 7405568 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 3735552 {
+          if version < 4915200 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 1049600 {
+          if version < 589824 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 1049600 {
+          if version < 589824 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 786432 {
+          if version < 1441792 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 5177344 {
+          if version < 7274496 {
             return false;
           }
         }
@@ -3214,9 +3265,9 @@ impl Feature {
           return false;
         }
       }
-      Feature::FontStyleObliqueAngle => {
+      Feature::MinFunction | Feature::MaxFunction => {
         if let Some(version) = browsers.chrome {
-          if version < 4063232 {
+          if version < 5177344 {
             return false;
           }
         }
@@ -3226,12 +3277,12 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 3997696 {
+          if version < 5177344 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 3014656 {
+          if version < 3735552 {
             return false;
           }
         }
@@ -3246,12 +3297,12 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 524288 {
+          if version < 786432 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 4063232 {
+          if version < 5177344 {
             return false;
           }
         }
@@ -3259,89 +3310,110 @@ impl Feature {
           return false;
         }
       }
-      Feature::FontWeightNumber => {
+      Feature::RoundFunction | Feature::RemFunction | Feature::ModFunction => {
         if let Some(version) = browsers.chrome {
-          if version < 4063232 {
+          if version < 8192000 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 1114112 {
+          if version < 8192000 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 3997696 {
+          if version < 7733248 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 3014656 {
+          if version < 7274496 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 720896 {
+          if version < 984064 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 720896 {
+          if version < 984064 {
             return false;
           }
         }
-        if let Some(version) = browsers.samsung {
-          if version < 524288 {
+        if let Some(version) = browsers.android {
+          if version < 8192000 {
             return false;
           }
         }
-        if let Some(version) = browsers.android {
-          if version < 4063232 {
+        if browsers.ie.is_some() || browsers.samsung.is_some() {
+          return false;
+        }
+      }
+      Feature::AbsFunction | Feature::SignFunction => {
+        if let Some(version) = browsers.firefox {
+          if version < 7733248 {
             return false;
           }
         }
-        if browsers.ie.is_some() {
+        if let Some(version) = browsers.safari {
+          if version < 984064 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 984064 {
+            return false;
+          }
+        }
+        if browsers.android.is_some()
+          || browsers.chrome.is_some()
+          || browsers.edge.is_some()
+          || browsers.ie.is_some()
+          || browsers.opera.is_some()
+          || browsers.samsung.is_some()
+        {
           return false;
         }
       }
-      Feature::FontStretchPercentage => {
+      Feature::HypotFunction => {
         if let Some(version) = browsers.chrome {
-          if version < 4063232 {
+          if version < 7864320 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 1179648 {
+          if version < 7864320 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 3997696 {
+          if version < 7733248 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 3014656 {
+          if version < 5242880 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 721152 {
+          if version < 984064 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 721664 {
+          if version < 984064 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 524288 {
+          if version < 1638400 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 4063232 {
+          if version < 7864320 {
             return false;
           }
         }
@@ -3349,199 +3421,148 @@ impl Feature {
           return false;
         }
       }
-      Feature::LightDark => {
+      Feature::GradientInterpolationHints => {
         if let Some(version) = browsers.chrome {
-          if version < 8060928 {
+          if version < 2621440 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 8060928 {
+          if version < 5177344 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 7864320 {
+          if version < 2359296 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 5373952 {
+          if version < 1769472 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 1115392 {
+          if version < 458752 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 1115392 {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 262144 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 8060928 {
+          if version < 2621440 {
             return false;
           }
         }
-        if browsers.ie.is_some() || browsers.samsung.is_some() {
+        if browsers.ie.is_some() {
           return false;
         }
       }
-      Feature::AccentSystemColor => {
-        if let Some(version) = browsers.firefox {
-          if version < 6750208 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.safari {
-          if version < 1049856 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.ios_saf {
-          if version < 1049856 {
-            return false;
-          }
-        }
-        if browsers.android.is_some()
-          || browsers.chrome.is_some()
-          || browsers.edge.is_some()
-          || browsers.ie.is_some()
-          || browsers.opera.is_some()
-          || browsers.samsung.is_some()
-        {
-          return false;
-        }
-      }
-      Feature::AnimationTimelineShorthand => {
+      Feature::BorderImageRepeatRound => {
         if let Some(version) = browsers.chrome {
-          if version < 7536640 {
+          if version < 1966080 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 7536640 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.opera {
-          if version < 5046272 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.samsung {
-          if version < 1507328 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.android {
-          if version < 7536640 {
-            return false;
-          }
-        }
-        if browsers.firefox.is_some()
-          || browsers.ie.is_some()
-          || browsers.ios_saf.is_some()
-          || browsers.safari.is_some()
-        {
-          return false;
-        }
-      }
-      Feature::QUnit => {
-        if let Some(version) = browsers.chrome {
-          if version < 4128768 {
+          if version < 786432 {
             return false;
           }
         }
-        if let Some(version) = browsers.edge {
-          if version < 5177344 {
+        if let Some(version) = browsers.firefox {
+          if version < 983040 {
             return false;
           }
         }
-        if let Some(version) = browsers.firefox {
-          if version < 3211264 {
+        if let Some(version) = browsers.ie {
+          if version < 720896 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 3014656 {
+          if version < 1179648 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 852224 {
+          if version < 590080 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 852992 {
+          if version < 590592 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 524288 {
+          if version < 131072 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 4128768 {
+          if version < 263168 {
             return false;
           }
         }
-        if browsers.ie.is_some() {
-          return false;
-        }
       }
-      Feature::CapUnit => {
+      Feature::BorderImageRepeatSpace => {
         if let Some(version) = browsers.chrome {
-          if version < 7667712 {
+          if version < 3670016 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 7667712 {
+          if version < 786432 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 6356992 {
+          if version < 3276800 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ie {
+          if version < 720896 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 5111808 {
+          if version < 2818048 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 1114624 {
+          if version < 590080 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 1114624 {
+          if version < 590592 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 1572864 {
+          if version < 393216 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 7667712 {
+          if version < 3670016 {
             return false;
           }
         }
-        if browsers.ie.is_some() {
-          return false;
-        }
       }
-      Feature::ChUnit => {
+      Feature::FontSizeRem => {
         if let Some(version) = browsers.chrome {
-          if version < 1769472 {
+          if version < 2752512 {
             return false;
           }
         }
@@ -3551,7 +3572,7 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 262144 {
+          if version < 2031616 {
             return false;
           }
         }
@@ -3561,7 +3582,7 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 983040 {
+          if version < 1835008 {
             return false;
           }
         }
@@ -3576,54 +3597,54 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 66816 {
+          if version < 262144 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 263168 {
+          if version < 2752512 {
             return false;
           }
         }
       }
-      Feature::ContainerQueryLengthUnits => {
+      Feature::FontSizeXXXLarge => {
         if let Some(version) = browsers.chrome {
-          if version < 6881280 {
+          if version < 5177344 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 6881280 {
+          if version < 5177344 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 7208960 {
+          if version < 5177344 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 4718592 {
+          if version < 3735552 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 1048576 {
+          if version < 1049600 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 1048576 {
+          if version < 1049600 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 1310720 {
+          if version < 786432 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 6881280 {
+          if version < 5177344 {
             return false;
           }
         }
@@ -3631,142 +3652,134 @@ impl Feature {
           return false;
         }
       }
-      Feature::EmUnit => {
+      Feature::FontStyleObliqueAngle => {
         if let Some(version) = browsers.chrome {
-          if version < 1179648 {
+          if version < 4063232 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 786432 {
+          if version < 5177344 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 262144 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.ie {
-          if version < 196608 {
+          if version < 3997696 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 655616 {
+          if version < 3014656 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 65536 {
+          if version < 721152 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 65536 {
+          if version < 721664 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 65536 {
+          if version < 524288 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 65536 {
+          if version < 4063232 {
             return false;
           }
         }
+        if browsers.ie.is_some() {
+          return false;
+        }
       }
-      Feature::ExUnit
-      | Feature::CircleListStyleType
-      | Feature::DecimalListStyleType
-      | Feature::DiscListStyleType
-      | Feature::SquareListStyleType => {
+      Feature::FontWeightNumber => {
         if let Some(version) = browsers.chrome {
-          if version < 1179648 {
+          if version < 4063232 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 786432 {
+          if version < 1114112 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 262144 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.ie {
-          if version < 262144 {
+          if version < 3997696 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 655616 {
+          if version < 3014656 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 65536 {
+          if version < 720896 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 65536 {
+          if version < 720896 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 65536 {
+          if version < 524288 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 263168 {
+          if version < 4063232 {
             return false;
           }
         }
+        if browsers.ie.is_some() {
+          return false;
+        }
       }
-      Feature::IcUnit => {
+      Feature::FontStretchPercentage => {
         if let Some(version) = browsers.chrome {
-          if version < 6946816 {
+          if version < 4063232 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 6946816 {
+          if version < 1179648 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 6356992 {
+          if version < 3997696 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 4718592 {
+          if version < 3014656 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 984064 {
+          if version < 721152 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 984064 {
+          if version < 721664 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 1310720 {
+          if version < 524288 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 6946816 {
+          if version < 4063232 {
             return false;
           }
         }
@@ -3774,88 +3787,659 @@ impl Feature {
           return false;
         }
       }
-      Feature::LhUnit => {
+      Feature::FontFormatWoff => {
         if let Some(version) = browsers.chrome {
-          if version < 7143424 {
+          if version < 327680 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 7143424 {
+          if version < 786432 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 7864320 {
+          if version < 198144 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 4849664 {
+          if version < 721152 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 1049600 {
+          if version < 327936 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 1049600 {
+          if version < 327680 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 1376256 {
+          if version < 65536 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 7143424 {
+          if version < 263168 {
             return false;
           }
         }
-        if browsers.ie.is_some() {
-          return false;
+        if let Some(version) = browsers.ie {
+          if version < 589824 {
+            return false;
+          }
         }
       }
-      Feature::RcapUnit => {
+      Feature::FontFormatWoff2 => {
         if let Some(version) = browsers.chrome {
-          if version < 7667712 {
+          if version < 2359296 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 7667712 {
+          if version < 917504 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 2555904 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 5111808 {
+          if version < 1507328 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 1114624 {
+          if version < 786432 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 1114624 {
+          if version < 655360 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 1572864 {
+          if version < 196608 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 7667712 {
+          if version < 2424832 {
             return false;
           }
         }
-        if browsers.firefox.is_some() || browsers.ie.is_some() {
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::FontFormatEmbeddedOpenType => {
+        if let Some(version) = browsers.ie {
+          if version < 393216 {
+            return false;
+          }
+        }
+        if browsers.android.is_some()
+          || browsers.chrome.is_some()
+          || browsers.edge.is_some()
+          || browsers.firefox.is_some()
+          || browsers.ios_saf.is_some()
+          || browsers.opera.is_some()
+          || browsers.safari.is_some()
+          || browsers.samsung.is_some()
+        {
+          return false;
+        }
+      }
+      Feature::FontFormatSvg => {
+        if let Some(version) = browsers.safari {
+          if version < 196864 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 197120 {
+            return false;
+          }
+        }
+        if browsers.android.is_some()
+          || browsers.chrome.is_some()
+          || browsers.edge.is_some()
+          || browsers.firefox.is_some()
+          || browsers.ie.is_some()
+          || browsers.opera.is_some()
+          || browsers.samsung.is_some()
+        {
+          return false;
+        }
+      }
+      Feature::LineClamp => {
+        if browsers.android.is_some()
+          || browsers.chrome.is_some()
+          || browsers.edge.is_some()
+          || browsers.firefox.is_some()
+          || browsers.ie.is_some()
+          || browsers.ios_saf.is_some()
+          || browsers.opera.is_some()
+          || browsers.safari.is_some()
+          || browsers.samsung.is_some()
+        {
+          return false;
+        }
+      }
+      Feature::LightDark => {
+        if let Some(version) = browsers.chrome {
+          if version < 8060928 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 8060928 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 7864320 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 5373952 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1115392 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1115392 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 8060928 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() || browsers.samsung.is_some() {
+          return false;
+        }
+      }
+      Feature::AccentSystemColor => {
+        if let Some(version) = browsers.firefox {
+          if version < 6750208 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1049856 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1049856 {
+            return false;
+          }
+        }
+        if browsers.android.is_some()
+          || browsers.chrome.is_some()
+          || browsers.edge.is_some()
+          || browsers.ie.is_some()
+          || browsers.opera.is_some()
+          || browsers.samsung.is_some()
+        {
+          return false;
+        }
+      }
+      Feature::AnimationTimelineShorthand => {
+        if let Some(version) = browsers.chrome {
+          if version < 7536640 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 7536640 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 5046272 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1507328 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 7536640 {
+            return false;
+          }
+        }
+        if browsers.firefox.is_some()
+          || browsers.ie.is_some()
+          || browsers.ios_saf.is_some()
+          || browsers.safari.is_some()
+        {
+          return false;
+        }
+      }
+      Feature::QUnit => {
+        if let Some(version) = browsers.chrome {
+          if version < 4128768 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 3211264 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 3014656 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 852224 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 852992 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 524288 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 4128768 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::CapUnit => {
+        if let Some(version) = browsers.chrome {
+          if version < 7667712 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 7667712 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 6356992 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 5111808 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1114624 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1114624 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1572864 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 7667712 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::ChUnit => {
+        if let Some(version) = browsers.chrome {
+          if version < 1769472 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 262144 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ie {
+          if version < 589824 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 983040 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 66816 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 263168 {
+            return false;
+          }
+        }
+      }
+      Feature::ContainerQueryLengthUnits => {
+        if let Some(version) = browsers.chrome {
+          if version < 6881280 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 6881280 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 7208960 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 4718592 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1048576 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1048576 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1310720 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 6881280 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::EmUnit => {
+        if let Some(version) = browsers.chrome {
+          if version < 1179648 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 262144 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ie {
+          if version < 196608 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 655616 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 65536 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 65536 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 65536 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 65536 {
+            return false;
+          }
+        }
+      }
+      Feature::ExUnit
+      | Feature::CircleListStyleType
+      | Feature::DecimalListStyleType
+      | Feature::DiscListStyleType
+      | Feature::SquareListStyleType => {
+        if let Some(version) = browsers.chrome {
+          if version < 1179648 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 262144 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ie {
+          if version < 262144 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 655616 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 65536 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 65536 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 65536 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 263168 {
+            return false;
+          }
+        }
+      }
+      Feature::IcUnit => {
+        if let Some(version) = browsers.chrome {
+          if version < 6946816 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 6946816 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 6356992 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 4718592 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 984064 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 984064 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1310720 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 6946816 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::LhUnit => {
+        if let Some(version) = browsers.chrome {
+          if version < 7143424 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 7143424 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 7864320 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 4849664 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1049600 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1049600 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1376256 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 7143424 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::RcapUnit => {
+        if let Some(version) = browsers.chrome {
+          if version < 7667712 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 7667712 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 5111808 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1114624 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1114624 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1572864 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 7667712 {
+            return false;
+          }
+        }
+        if browsers.firefox.is_some() || browsers.ie.is_some() {
           return false;
         }
       }
@@ -4576,22 +5160,200 @@ impl Feature {
             return false;
           }
         }
-        if browsers.firefox.is_some() || browsers.ie.is_some() {
+        if browsers.firefox.is_some() || browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::BinaryListStyleType
+      | Feature::OctalListStyleType
+      | Feature::OromoListStyleType
+      | Feature::SidamaListStyleType
+      | Feature::SomaliListStyleType
+      | Feature::TigreListStyleType
+      | Feature::TigrinyaErListStyleType
+      | Feature::TigrinyaErAbegedeListStyleType
+      | Feature::TigrinyaEtListStyleType
+      | Feature::TigrinyaEtAbegedeListStyleType => {
+        if let Some(version) = browsers.chrome {
+          if version < 1179648 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5963776 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 917504 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 327680 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 262656 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 65536 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 263168 {
+            return false;
+          }
+        }
+        if browsers.firefox.is_some() || browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::CambodianListStyleType | Feature::MongolianListStyleType | Feature::TibetanListStyleType => {
+        if let Some(version) = browsers.chrome {
+          if version < 1179648 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 2162688 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 917504 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 327680 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 262656 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 65536 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 263168 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::CjkDecimalListStyleType => {
+        if let Some(version) = browsers.chrome {
+          if version < 5963776 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5963776 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 1835008 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 4194304 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 983040 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 983040 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1048576 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 5963776 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::DisclosureClosedListStyleType | Feature::DisclosureOpenListStyleType => {
+        if let Some(version) = browsers.chrome {
+          if version < 5832704 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5832704 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 2162688 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 4128768 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 983040 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 983040 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 983040 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 5832704 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
           return false;
         }
       }
-      Feature::BinaryListStyleType
-      | Feature::OctalListStyleType
-      | Feature::OromoListStyleType
-      | Feature::SidamaListStyleType
-      | Feature::SomaliListStyleType
-      | Feature::TigreListStyleType
-      | Feature::TigrinyaErListStyleType
-      | Feature::TigrinyaErAbegedeListStyleType
-      | Feature::TigrinyaEtListStyleType
-      | Feature::TigrinyaEtAbegedeListStyleType => {
+      Feature::EthiopicNumericListStyleType
+      | Feature::JapaneseFormalListStyleType
+      | Feature::JapaneseInformalListStyleType
+      | Feature::TamilListStyleType => {
         if let Some(version) = browsers.chrome {
-          if version < 1179648 {
+          if version < 5963776 {
             return false;
           }
         }
@@ -4600,48 +5362,63 @@ impl Feature {
             return false;
           }
         }
+        if let Some(version) = browsers.firefox {
+          if version < 262144 {
+            return false;
+          }
+        }
         if let Some(version) = browsers.opera {
-          if version < 917504 {
+          if version < 4194304 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 327680 {
+          if version < 983040 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 262656 {
+          if version < 983040 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 65536 {
+          if version < 1048576 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 263168 {
+          if version < 5963776 {
             return false;
           }
         }
-        if browsers.firefox.is_some() || browsers.ie.is_some() {
+        if browsers.ie.is_some() {
           return false;
         }
       }
-      Feature::CambodianListStyleType | Feature::MongolianListStyleType | Feature::TibetanListStyleType => {
+      Feature::HebrewListStyleType
+      | Feature::HiraganaListStyleType
+      | Feature::HiraganaIrohaListStyleType
+      | Feature::KatakanaListStyleType
+      | Feature::KatakanaIrohaListStyleType
+      | Feature::AutoSize => {
         if let Some(version) = browsers.chrome {
           if version < 1179648 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 786432 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 2162688 {
+          if version < 262144 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ie {
+          if version < 720896 {
             return false;
           }
         }
@@ -4651,12 +5428,12 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 327680 {
+          if version < 65536 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 262656 {
+          if version < 65536 {
             return false;
           }
         }
@@ -4670,18 +5447,17 @@ impl Feature {
             return false;
           }
         }
-        if browsers.ie.is_some() {
-          return false;
-        }
       }
-      Feature::CjkDecimalListStyleType => {
+      Feature::KoreanHangulFormalListStyleType
+      | Feature::KoreanHanjaFormalListStyleType
+      | Feature::KoreanHanjaInformalListStyleType => {
         if let Some(version) = browsers.chrome {
-          if version < 5963776 {
+          if version < 2949120 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5963776 {
+          if version < 5177344 {
             return false;
           }
         }
@@ -4691,7 +5467,7 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 4194304 {
+          if version < 2097152 {
             return false;
           }
         }
@@ -4706,12 +5482,12 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 1048576 {
+          if version < 327680 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 5963776 {
+          if version < 2949120 {
             return false;
           }
         }
@@ -4719,14 +5495,14 @@ impl Feature {
           return false;
         }
       }
-      Feature::DisclosureClosedListStyleType | Feature::DisclosureOpenListStyleType => {
+      Feature::LowerArmenianListStyleType | Feature::UpperArmenianListStyleType => {
         if let Some(version) = browsers.chrome {
-          if version < 5832704 {
+          if version < 1179648 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5832704 {
+          if version < 5177344 {
             return false;
           }
         }
@@ -4736,27 +5512,27 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 4128768 {
+          if version < 917504 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 983040 {
+          if version < 327936 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 983040 {
+          if version < 327680 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 983040 {
+          if version < 65536 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 5832704 {
+          if version < 263168 {
             return false;
           }
         }
@@ -4764,17 +5540,14 @@ impl Feature {
           return false;
         }
       }
-      Feature::EthiopicNumericListStyleType
-      | Feature::JapaneseFormalListStyleType
-      | Feature::JapaneseInformalListStyleType
-      | Feature::TamilListStyleType => {
+      Feature::LowerLatinListStyleType => {
         if let Some(version) = browsers.chrome {
-          if version < 5963776 {
+          if version < 1179648 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5963776 {
+          if version < 786432 {
             return false;
           }
         }
@@ -4783,41 +5556,38 @@ impl Feature {
             return false;
           }
         }
+        if let Some(version) = browsers.ie {
+          if version < 524288 {
+            return false;
+          }
+        }
         if let Some(version) = browsers.opera {
-          if version < 4194304 {
+          if version < 655616 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 983040 {
+          if version < 65536 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 983040 {
+          if version < 65536 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 1048576 {
+          if version < 327680 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 5963776 {
+          if version < 263168 {
             return false;
           }
         }
-        if browsers.ie.is_some() {
-          return false;
-        }
       }
-      Feature::HebrewListStyleType
-      | Feature::HiraganaListStyleType
-      | Feature::HiraganaIrohaListStyleType
-      | Feature::KatakanaListStyleType
-      | Feature::KatakanaIrohaListStyleType
-      | Feature::AutoSize => {
+      Feature::NoneListStyleType => {
         if let Some(version) = browsers.chrome {
           if version < 1179648 {
             return false;
@@ -4829,7 +5599,7 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 262144 {
+          if version < 5177344 {
             return false;
           }
         }
@@ -4864,9 +5634,10 @@ impl Feature {
           }
         }
       }
-      Feature::KoreanHangulFormalListStyleType
-      | Feature::KoreanHanjaFormalListStyleType
-      | Feature::KoreanHanjaInformalListStyleType => {
+      Feature::SimpChineseFormalListStyleType
+      | Feature::SimpChineseInformalListStyleType
+      | Feature::TradChineseFormalListStyleType
+      | Feature::TradChineseInformalListStyleType => {
         if let Some(version) = browsers.chrome {
           if version < 2949120 {
             return false;
@@ -4878,7 +5649,7 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 1835008 {
+          if version < 262144 {
             return false;
           }
         }
@@ -4911,9 +5682,9 @@ impl Feature {
           return false;
         }
       }
-      Feature::LowerArmenianListStyleType | Feature::UpperArmenianListStyleType => {
+      Feature::StringListStyleType => {
         if let Some(version) = browsers.chrome {
-          if version < 1179648 {
+          if version < 5177344 {
             return false;
           }
         }
@@ -4923,7 +5694,100 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 2162688 {
+          if version < 2555904 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 3735552 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 917760 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 918784 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::SymbolsListStyleType => {
+        if let Some(version) = browsers.firefox {
+          if version < 2293760 {
+            return false;
+          }
+        }
+        if browsers.android.is_some()
+          || browsers.chrome.is_some()
+          || browsers.edge.is_some()
+          || browsers.ie.is_some()
+          || browsers.ios_saf.is_some()
+          || browsers.opera.is_some()
+          || browsers.safari.is_some()
+          || browsers.samsung.is_some()
+        {
+          return false;
+        }
+      }
+      Feature::AnchorSizeSize => {
+        if let Some(version) = browsers.chrome {
+          if version < 8192000 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 8192000 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 7274496 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 8192000 {
+            return false;
+          }
+        }
+        if browsers.firefox.is_some()
+          || browsers.ie.is_some()
+          || browsers.ios_saf.is_some()
+          || browsers.safari.is_some()
+          || browsers.samsung.is_some()
+        {
+          return false;
+        }
+      }
+      Feature::FitContentSize => {
+        if let Some(version) = browsers.chrome {
+          if version < 1638400 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 262144 {
             return false;
           }
         }
@@ -4933,17 +5797,17 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 327936 {
+          if version < 458752 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 327680 {
+          if version < 458752 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 65536 {
+          if version < 66816 {
             return false;
           }
         }
@@ -4956,9 +5820,9 @@ impl Feature {
           return false;
         }
       }
-      Feature::LowerLatinListStyleType => {
+      Feature::IsAnimatableSize => {
         if let Some(version) = browsers.chrome {
-          if version < 1179648 {
+          if version < 1703936 {
             return false;
           }
         }
@@ -4968,32 +5832,32 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 262144 {
+          if version < 1048576 {
             return false;
           }
         }
         if let Some(version) = browsers.ie {
-          if version < 524288 {
+          if version < 720896 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 655616 {
+          if version < 917504 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 65536 {
+          if version < 458752 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 65536 {
+          if version < 458752 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 327680 {
+          if version < 66816 {
             return false;
           }
         }
@@ -5003,44 +5867,39 @@ impl Feature {
           }
         }
       }
-      Feature::NoneListStyleType => {
+      Feature::MaxContentSize => {
         if let Some(version) = browsers.chrome {
-          if version < 1179648 {
+          if version < 1638400 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 786432 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.firefox {
           if version < 5177344 {
             return false;
           }
         }
-        if let Some(version) = browsers.ie {
-          if version < 720896 {
+        if let Some(version) = browsers.firefox {
+          if version < 262144 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 917504 {
+          if version < 2818048 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 65536 {
+          if version < 720896 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 65536 {
+          if version < 720896 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 65536 {
+          if version < 66816 {
             return false;
           }
         }
@@ -5049,13 +5908,13 @@ impl Feature {
             return false;
           }
         }
+        if browsers.ie.is_some() {
+          return false;
+        }
       }
-      Feature::SimpChineseFormalListStyleType
-      | Feature::SimpChineseInformalListStyleType
-      | Feature::TradChineseFormalListStyleType
-      | Feature::TradChineseInformalListStyleType => {
+      Feature::MinContentSize => {
         if let Some(version) = browsers.chrome {
-          if version < 2949120 {
+          if version < 3014656 {
             return false;
           }
         }
@@ -5070,17 +5929,17 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 2097152 {
+          if version < 2162688 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 983040 {
+          if version < 720896 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 983040 {
+          if version < 720896 {
             return false;
           }
         }
@@ -5090,7 +5949,7 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.android {
-          if version < 2949120 {
+          if version < 3014656 {
             return false;
           }
         }
@@ -5098,9 +5957,9 @@ impl Feature {
           return false;
         }
       }
-      Feature::StringListStyleType => {
+      Feature::WebkitFillAvailableSize => {
         if let Some(version) = browsers.chrome {
-          if version < 5177344 {
+          if version < 1638400 {
             return false;
           }
         }
@@ -5109,51 +5968,46 @@ impl Feature {
             return false;
           }
         }
-        if let Some(version) = browsers.firefox {
-          if version < 2555904 {
-            return false;
-          }
-        }
         if let Some(version) = browsers.opera {
-          if version < 3735552 {
+          if version < 917504 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 917760 {
+          if version < 458752 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 918784 {
+          if version < 458752 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 786432 {
+          if version < 327680 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 5177344 {
+          if version < 263168 {
             return false;
           }
         }
-        if browsers.ie.is_some() {
+        if browsers.firefox.is_some() || browsers.ie.is_some() {
           return false;
         }
       }
-      Feature::SymbolsListStyleType => {
-        if let Some(version) = browsers.firefox {
-          if version < 2293760 {
+      Feature::WebkitOverflowScrolling => {
+        if let Some(version) = browsers.ios_saf {
+          if version < 327680 {
             return false;
           }
         }
         if browsers.android.is_some()
           || browsers.chrome.is_some()
           || browsers.edge.is_some()
+          || browsers.firefox.is_some()
           || browsers.ie.is_some()
-          || browsers.ios_saf.is_some()
           || browsers.opera.is_some()
           || browsers.safari.is_some()
           || browsers.samsung.is_some()
@@ -5161,166 +6015,189 @@ impl Feature {
           return false;
         }
       }
-      Feature::AnchorSizeSize => {
+      Feature::WebkitTapHighlightColor => {
         if let Some(version) = browsers.chrome {
-          if version < 8192000 {
+          if version < 1048576 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 8192000 {
+          if version < 786432 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 7274496 {
+          if version < 983040 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 262144 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 65536 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 8192000 {
+          if version < 263168 {
             return false;
           }
         }
-        if browsers.firefox.is_some()
+        if browsers.firefox.is_some() || browsers.ie.is_some() || browsers.safari.is_some() {
+          return false;
+        }
+      }
+      Feature::WebkitTouchCallout => {
+        if let Some(version) = browsers.ios_saf {
+          if version < 131072 {
+            return false;
+          }
+        }
+        if browsers.android.is_some()
+          || browsers.chrome.is_some()
+          || browsers.edge.is_some()
+          || browsers.firefox.is_some()
           || browsers.ie.is_some()
-          || browsers.ios_saf.is_some()
+          || browsers.opera.is_some()
           || browsers.safari.is_some()
           || browsers.samsung.is_some()
         {
           return false;
         }
       }
-      Feature::FitContentSize => {
+      Feature::Zoom => {
         if let Some(version) = browsers.chrome {
-          if version < 1638400 {
+          if version < 65536 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 786432 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 262144 {
+          if version < 8257536 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ie {
+          if version < 328960 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 917504 {
+          if version < 983040 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 458752 {
+          if version < 196864 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 458752 {
+          if version < 196608 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 66816 {
+          if version < 65536 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 263168 {
+          if version < 131328 {
             return false;
           }
         }
-        if browsers.ie.is_some() {
-          return false;
-        }
       }
-      Feature::IsAnimatableSize => {
+      Feature::SupportsSelector => {
         if let Some(version) = browsers.chrome {
-          if version < 1703936 {
+          if version < 5439488 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 786432 {
+          if version < 5439488 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 1048576 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.ie {
-          if version < 720896 {
+          if version < 4521984 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 917504 {
+          if version < 4521984 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 458752 {
+          if version < 917760 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 458752 {
+          if version < 918784 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 66816 {
+          if version < 851968 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 263168 {
+          if version < 5439488 {
             return false;
           }
         }
+        if browsers.ie.is_some() {
+          return false;
+        }
       }
-      Feature::MaxContentSize => {
+      Feature::SupportsFontFormat => {
         if let Some(version) = browsers.chrome {
-          if version < 1638400 {
+          if version < 7077888 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 7077888 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 262144 {
+          if version < 6946816 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 2818048 {
+          if version < 6160384 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 720896 {
+          if version < 1114112 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 720896 {
+          if version < 1114112 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 66816 {
+          if version < 1376256 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 263168 {
+          if version < 7077888 {
             return false;
           }
         }
@@ -5328,44 +6205,44 @@ impl Feature {
           return false;
         }
       }
-      Feature::MinContentSize => {
+      Feature::SupportsFontTech => {
         if let Some(version) = browsers.chrome {
-          if version < 3014656 {
+          if version < 7077888 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 7077888 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 262144 {
+          if version < 6946816 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 2162688 {
+          if version < 6160384 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 720896 {
+          if version < 1114112 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 720896 {
+          if version < 1114112 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 327680 {
+          if version < 1376256 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 3014656 {
+          if version < 7077888 {
             return false;
           }
         }
@@ -5373,43 +6250,107 @@ impl Feature {
           return false;
         }
       }
-      Feature::WebkitFillAvailableSize => {
+      Feature::ContrastColor => {
+        if let Some(version) = browsers.firefox {
+          if version < 9568256 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1703936 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1703936 {
+            return false;
+          }
+        }
+        if browsers.android.is_some()
+          || browsers.chrome.is_some()
+          || browsers.edge.is_some()
+          || browsers.ie.is_some()
+          || browsers.opera.is_some()
+          || browsers.samsung.is_some()
+        {
+          return false;
+        }
+      }
+      Feature::DeviceCmyk => {
+        if browsers.android.is_some()
+          || browsers.chrome.is_some()
+          || browsers.edge.is_some()
+          || browsers.firefox.is_some()
+          || browsers.ie.is_some()
+          || browsers.ios_saf.is_some()
+          || browsers.opera.is_some()
+          || browsers.safari.is_some()
+          || browsers.samsung.is_some()
+        {
+          return false;
+        }
+      }
+      Feature::SiblingCountFunction | Feature::SiblingIndexFunction => {
         if let Some(version) = browsers.chrome {
-          if version < 1638400 {
+          if version < 9043968 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 9043968 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 917504 {
+          if version < 7995392 {
             return false;
           }
         }
-        if let Some(version) = browsers.safari {
-          if version < 458752 {
+        if let Some(version) = browsers.android {
+          if version < 9043968 {
             return false;
           }
         }
-        if let Some(version) = browsers.ios_saf {
-          if version < 458752 {
+        if browsers.firefox.is_some()
+          || browsers.ie.is_some()
+          || browsers.ios_saf.is_some()
+          || browsers.safari.is_some()
+          || browsers.samsung.is_some()
+        {
+          return false;
+        }
+      }
+      Feature::PaintFunction => {
+        if let Some(version) = browsers.chrome {
+          if version < 4259840 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 3407872 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 327680 {
+          if version < 589824 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 263168 {
+          if version < 4259840 {
             return false;
           }
         }
-        if browsers.firefox.is_some() || browsers.ie.is_some() {
+        if browsers.firefox.is_some()
+          || browsers.ie.is_some()
+          || browsers.ios_saf.is_some()
+          || browsers.safari.is_some()
+        {
           return false;
         }
       }
@@ -1,21 +1,40 @@
+#[cfg(feature = "substitute_variables")]
+use std::collections::HashMap;
 use std::collections::HashSet;
 
 use crate::compat::Feature;
 use crate::declaration::DeclarationBlock;
+#[cfg(feature = "visitor")]
+use crate::declaration::DeclarationList;
 use crate::media_query::{
   MediaCondition, MediaFeatureId, MediaFeatureName, MediaFeatureValue, MediaList, MediaQuery, MediaType,
   QueryFeature,
 };
+#[cfg(any(feature = "substitute_variables", feature = "visitor"))]
+use crate::properties::custom::TokenList;
 use crate::properties::custom::UnparsedProperty;
+#[cfg(feature = "visitor")]
+use crate::properties::custom::{CustomProperty, CustomPropertyName, TokenOrValue, UnresolvedColor};
 use crate::properties::Property;
 use crate::rules::media::MediaRule;
 use crate::rules::supports::{SupportsCondition, SupportsRule};
 use crate::rules::{style::StyleRule, CssRule, CssRuleList};
-use crate::selector::{Direction, PseudoClass};
+use crate::selector::{Direction, PseudoClass, PseudoElement, WebKitScrollbarPseudoElement};
+use crate::stylesheet::{FallbackControl, FallbackKind};
+#[cfg(feature = "visitor")]
+use crate::stylesheet::{LightDarkStrategy, PxToRemOptions};
 use crate::targets::Targets;
+#[cfg(feature = "visitor")]
+use crate::values::color::CssColor;
+use crate::values::color::GamutMapping;
 use crate::values::ident::Ident;
+#[cfg(feature = "visitor")]
+use crate::values::length::LengthValue;
 use crate::vendor_prefix::VendorPrefix;
+#[cfg(feature = "visitor")]
+use crate::visitor::{Visit, VisitTypes, Visitor};
 use parcel_selectors::parser::Component;
+use parcel_selectors::SelectorList;
 
 #[derive(Debug)]
 pub(crate) struct SupportsEntry<'i> {
@@ -30,6 +49,7 @@ pub(crate) enum DeclarationContext {
   StyleRule,
   Keyframes,
   StyleAttribute,
+  PositionTry,
 }
 
 #[derive(Debug)]
@@ -40,8 +60,17 @@ pub(crate) struct PropertyHandlerContext<'i, 'o> {
   ltr: Vec<Property<'i>>,
   rtl: Vec<Property<'i>>,
   dark: Vec<Property<'i>>,
+  webkit_scrollbar: Vec<(WebKitScrollbarPseudoElement, Vec<Property<'i>>)>,
   pub context: DeclarationContext,
   pub unused_symbols: &'o HashSet<String>,
+  pub fallback_control: Option<&'o dyn FallbackControl>,
+  pub gamut_mapping: GamutMapping,
+  #[cfg(feature = "substitute_variables")]
+  pub environment_variables: Option<&'o HashMap<String, TokenList<'static>>>,
+  #[cfg(feature = "visitor")]
+  pub light_dark: LightDarkStrategy,
+  #[cfg(feature = "visitor")]
+  pub px_to_rem: Option<&'o PxToRemOptions>,
 }
 
 impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
@@ -53,8 +82,17 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
       ltr: Vec::new(),
       rtl: Vec::new(),
       dark: Vec::new(),
+      webkit_scrollbar: Vec::new(),
       context: DeclarationContext::None,
       unused_symbols,
+      fallback_control: None,
+      gamut_mapping: GamutMapping::default(),
+      #[cfg(feature = "substitute_variables")]
+      environment_variables: None,
+      #[cfg(feature = "visitor")]
+      light_dark: LightDarkStrategy::default(),
+      #[cfg(feature = "visitor")]
+      px_to_rem: None,
     }
   }
 
@@ -66,15 +104,80 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
       ltr: Vec::new(),
       rtl: Vec::new(),
       dark: Vec::new(),
+      webkit_scrollbar: Vec::new(),
       context,
       unused_symbols: self.unused_symbols,
+      fallback_control: self.fallback_control,
+      gamut_mapping: self.gamut_mapping,
+      #[cfg(feature = "substitute_variables")]
+      environment_variables: self.environment_variables,
+      #[cfg(feature = "visitor")]
+      light_dark: self.light_dark,
+      #[cfg(feature = "visitor")]
+      px_to_rem: self.px_to_rem,
+    }
+  }
+
+  /// Substitutes the provided environment variables into an unparsed or custom property,
+  /// returning `None` if nothing was replaced.
+  #[cfg(feature = "substitute_variables")]
+  pub fn substitute_environment_variables(&self, property: &Property<'i>) -> Option<Property<'i>> {
+    let env = self.environment_variables?;
+    match property {
+      Property::Unparsed(unparsed) => {
+        let mut unparsed = unparsed.clone();
+        if !unparsed.value.substitute_env(env) {
+          return None;
+        }
+        match unparsed.reparse() {
+          Ok(property) => Some(property),
+          Err(()) => Some(Property::Unparsed(unparsed)),
+        }
+      }
+      Property::Custom(custom) => {
+        let mut custom = custom.clone();
+        if !custom.value.substitute_env(env) {
+          return None;
+        }
+        Some(Property::Custom(custom))
+      }
+      _ => None,
+    }
+  }
+
+  /// Converts `px` lengths in a property to `rem`, returning `None` if nothing was converted.
+  #[cfg(feature = "visitor")]
+  pub fn convert_px_to_rem(&self, property: &Property<'i>) -> Option<Property<'i>> {
+    let options = self.px_to_rem?;
+    match property {
+      Property::Custom(CustomProperty {
+        name: CustomPropertyName::Custom(_),
+        ..
+      }) => return None,
+      _ if options.is_excluded(property.property_id().name()) => return None,
+      _ => {}
+    }
+
+    let mut property = property.clone();
+    let mut visitor = PxToRemVisitor {
+      options,
+      converted: false,
+    };
+    property.visit(&mut visitor).unwrap();
+    if visitor.converted {
+      Some(property)
+    } else {
+      None
     }
   }
 
   pub fn should_compile_logical(&self, feature: Feature) -> bool {
-    // Don't convert logical properties in style attributes because
-    // our fallbacks rely on extra rules to define --ltr and --rtl.
-    if self.context == DeclarationContext::StyleAttribute {
+    // Don't convert logical properties in style attributes or @position-try rules
+    // because our fallbacks rely on extra rules to define --ltr and --rtl.
+    if matches!(
+      self.context,
+      DeclarationContext::StyleAttribute | DeclarationContext::PositionTry
+    ) {
       return false;
     }
 
@@ -82,14 +185,154 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
   }
 
   pub fn add_logical_rule(&mut self, ltr: Property<'i>, rtl: Property<'i>) {
+    if let Some(control) = self.fallback_control {
+      let mut fallbacks = control
+        .fallbacks(&ltr, FallbackKind::Logical, &self.targets, vec![ltr.clone(), rtl])
+        .into_iter();
+      if let (Some(ltr), Some(rtl)) = (fallbacks.next(), fallbacks.next()) {
+        self.ltr.push(ltr);
+        self.rtl.push(rtl);
+      }
+      return;
+    }
+
     self.ltr.push(ltr);
     self.rtl.push(rtl);
   }
 
+  /// Passes generated fallback values for a property through the fallback control hook, if any.
+  pub fn fallbacks(
+    &self,
+    property: impl FnOnce() -> Property<'i>,
+    fallbacks: Vec<Property<'i>>,
+  ) -> Vec<Property<'i>> {
+    self.control_fallbacks(property, FallbackKind::Value, fallbacks)
+  }
+
+  /// Passes generated vendor prefixed declarations for a property through the fallback control hook, if any.
+  /// This is used for prefixes that cannot be expressed by the vendor prefix of the property itself,
+  /// e.g. prefixed values such as `display: -webkit-flex`, or legacy properties such as `-webkit-box-flex`.
+  pub fn prefixed(
+    &self,
+    property: impl FnOnce() -> Property<'i>,
+    prefixed: Vec<Property<'i>>,
+  ) -> Vec<Property<'i>> {
+    self.control_fallbacks(property, FallbackKind::Prefix, prefixed)
+  }
+
+  fn control_fallbacks(
+    &self,
+    property: impl FnOnce() -> Property<'i>,
+    kind: FallbackKind,
+    fallbacks: Vec<Property<'i>>,
+  ) -> Vec<Property<'i>> {
+    match self.fallback_control {
+      Some(control) if !fallbacks.is_empty() => control.fallbacks(&property(), kind, &self.targets, fallbacks),
+      _ => fallbacks,
+    }
+  }
+
+  /// Splits the vendor prefixes added by the targets into separate declarations when
+  /// a fallback control hook is present, so they can be suppressed or replaced.
+  /// Returns the prefixes to use for the original declaration, and the extra declarations to emit before it.
+  pub fn split_prefixes(
+    &self,
+    prefix: VendorPrefix,
+    expanded: VendorPrefix,
+    property: impl Fn(VendorPrefix) -> Property<'i>,
+  ) -> (VendorPrefix, Vec<Property<'i>>) {
+    self.split_prefixes_with(prefix, expanded, || property(prefix), |p| vec![property(p)])
+  }
+
+  /// Like [split_prefixes](Self::split_prefixes), but for declarations where each vendor prefix
+  /// may expand to multiple properties, e.g. `-webkit-mask` with `-webkit-mask-composite`.
+  /// The returned prefixes may be empty if all of them were added by the targets.
+  pub fn split_prefixes_with(
+    &self,
+    prefix: VendorPrefix,
+    expanded: VendorPrefix,
+    property: impl Fn() -> Property<'i>,
+    prefixed: impl Fn(VendorPrefix) -> Vec<Property<'i>>,
+  ) -> (VendorPrefix, Vec<Property<'i>>) {
+    let added = expanded - prefix;
+    match self.fallback_control {
+      Some(control) if !added.is_empty() => {
+        // Emit the prefixed declarations in the same order they would be printed in.
+        let mut decls = Vec::new();
+        for p in (expanded - VendorPrefix::None).iter() {
+          if added.contains(p) {
+            decls.extend(control.fallbacks(&property(), FallbackKind::Prefix, &self.targets, prefixed(p)));
+          } else {
+            decls.extend(prefixed(p));
+          }
+        }
+        (expanded & VendorPrefix::None, decls)
+      }
+      _ => (expanded, Vec::new()),
+    }
+  }
+
+  /// Adds the vendor prefixes required by the targets to an unparsed property, splitting them into
+  /// separate declarations when a fallback control hook is present.
+  /// Returns the prefixed property, and the extra declarations to emit before it.
+  pub fn prefix_unparsed(
+    &self,
+    unparsed: &UnparsedProperty<'i>,
+    feature: crate::prefixes::Feature,
+  ) -> (UnparsedProperty<'i>, Vec<Property<'i>>) {
+    let prefix = unparsed.property_id.prefix().or_none();
+    let expanded = self.targets.prefixes(prefix, feature);
+    let (prefix, prefixed) = self.split_prefixes(prefix, expanded, |vp| {
+      Property::Unparsed(unparsed.with_property_id(unparsed.property_id.with_prefix(vp)))
+    });
+    (
+      unparsed.with_property_id(unparsed.property_id.with_prefix(prefix)),
+      prefixed,
+    )
+  }
+
   pub fn add_dark_rule(&mut self, property: Property<'i>) {
     self.dark.push(property);
   }
 
+  /// Replaces `light-dark()` colors in the given declarations with the light color,
+  /// and adds copies using the dark color to a `prefers-color-scheme: dark` rule.
+  #[cfg(feature = "visitor")]
+  pub fn add_light_dark_rules(&mut self, decls: &mut DeclarationList<'i>) {
+    if self.light_dark != LightDarkStrategy::MediaQuery
+      || self.context != DeclarationContext::StyleRule
+      || self.targets.is_compatible(Feature::LightDark)
+    {
+      return;
+    }
+
+    for decl in decls.iter_mut() {
+      let mut dark = decl.clone();
+      let mut visitor = LightDarkVisitor {
+        dark: true,
+        found: false,
+      };
+      dark.visit(&mut visitor).unwrap();
+      if visitor.found {
+        decl
+          .visit(&mut LightDarkVisitor {
+            dark: false,
+            found: false,
+          })
+          .unwrap();
+        self.add_dark_rule(dark);
+      }
+    }
+  }
+
+  pub fn add_webkit_scrollbar_rule(&mut self, part: WebKitScrollbarPseudoElement, property: Property<'i>) {
+    if let Some((_, decls)) = self.webkit_scrollbar.iter_mut().find(|(p, _)| *p == part) {
+      decls.push(property);
+    } else {
+      self.webkit_scrollbar.push((part, vec![property]));
+    }
+  }
+
   pub fn get_additional_rules<T>(&self, style_rule: &StyleRule<'i, T>) -> Vec<CssRule<'i, T>> {
     // TODO: :dir/:lang raises the specificity of the selector. Use :where to lower it?
     let mut dest = Vec::new();
@@ -152,6 +395,36 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
       }))
     }
 
+    for (part, decls) in &self.webkit_scrollbar {
+      // Pseudo elements cannot be nested, so skip selectors that already have one.
+      let selectors: Vec<_> = style_rule
+        .selectors
+        .0
+        .iter()
+        .filter(|selector| !selector.has_pseudo_element())
+        .map(|selector| {
+          let mut selector = selector.clone();
+          selector.append_pseudo_element(PseudoElement::WebKitScrollbar(part.clone()));
+          selector
+        })
+        .collect();
+
+      if selectors.is_empty() {
+        continue;
+      }
+
+      dest.push(CssRule::Style(StyleRule {
+        selectors: SelectorList::from_vec(selectors),
+        vendor_prefix: VendorPrefix::None,
+        declarations: DeclarationBlock {
+          declarations: decls.clone(),
+          important_declarations: vec![],
+        },
+        rules: CssRuleList(vec![]),
+        loc: style_rule.loc,
+      }));
+    }
+
     dest
   }
 
@@ -187,7 +460,7 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
       return;
     }
 
-    let fallbacks = unparsed.value.get_fallbacks(self.targets);
+    let fallbacks = unparsed.value.get_fallbacks(self.targets, self.gamut_mapping);
     for (condition, fallback) in fallbacks {
       self.add_conditional_property(
         condition,
@@ -230,5 +503,73 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
     self.ltr.clear();
     self.rtl.clear();
     self.dark.clear();
+    self.webkit_scrollbar.clear();
+  }
+}
+
+#[cfg(feature = "visitor")]
+struct LightDarkVisitor {
+  dark: bool,
+  found: bool,
+}
+
+#[cfg(feature = "visitor")]
+impl<'i> Visitor<'i> for LightDarkVisitor {
+  type Error = std::convert::Infallible;
+
+  fn visit_types(&self) -> VisitTypes {
+    crate::visit_types!(PROPERTIES | COLORS | TOKENS)
+  }
+
+  fn visit_token_list(&mut self, tokens: &mut TokenList<'i>) -> Result<(), Self::Error> {
+    let mut i = 0;
+    while i < tokens.0.len() {
+      let token = &mut tokens.0[i];
+      token.visit(self)?;
+      if let TokenOrValue::UnresolvedColor(UnresolvedColor::LightDark { light, dark }) = token {
+        let value = if self.dark { dark.0.clone() } else { light.0.clone() };
+        let len = value.len();
+        tokens.0.splice(i..i + 1, value);
+        self.found = true;
+        i += len;
+        continue;
+      }
+      i += 1;
+    }
+    Ok(())
+  }
+
+  fn visit_color(&mut self, color: &mut CssColor) -> Result<(), Self::Error> {
+    if let CssColor::LightDark(light, dark) = color {
+      *color = if self.dark { (**dark).clone() } else { (**light).clone() };
+      self.found = true;
+    }
+    Ok(())
+  }
+}
+
+#[cfg(feature = "visitor")]
+struct PxToRemVisitor<'o> {
+  options: &'o PxToRemOptions,
+  converted: bool,
+}
+
+#[cfg(feature = "visitor")]
+impl<'i, 'o> Visitor<'i> for PxToRemVisitor<'o> {
+  type Error = std::convert::Infallible;
+
+  fn visit_types(&self) -> VisitTypes {
+    crate::visit_types!(PROPERTIES | LENGTHS | TOKENS)
+  }
+
+  fn visit_length(&mut self, length: &mut LengthValue) -> Result<(), Self::Error> {
+    // Zero lengths are left alone, since they are equivalent in any unit.
+    if let LengthValue::Px(px) = *length {
+      if px != 0.0 && px.abs() >= self.options.min_px {
+        *length = LengthValue::Rem(px / self.options.root_font_size);
+        self.converted = true;
+      }
+    }
+    Ok(())
   }
 }
@@ -41,6 +41,9 @@ pub struct Config<'i> {
   /// Whether to scope custom identifiers
   /// Default is `true`.
   pub custom_idents: bool,
+  /// Whether to scope paint worklet names used in `paint()`.
+  /// Default is `false`.
+  pub paint: bool,
 }
 
 impl<'i> Default for Config<'i> {
@@ -51,6 +54,7 @@ impl<'i> Default for Config<'i> {
       animation: true,
       grid: true,
       custom_idents: true,
+      paint: false,
     }
   }
 }
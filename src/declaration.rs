@@ -6,6 +6,7 @@ use std::ops::Range;
 
 use crate::context::{DeclarationContext, PropertyHandlerContext};
 use crate::error::{ParserError, PrinterError};
+use crate::macros::phase;
 use crate::parser::ParserOptions;
 use crate::printer::Printer;
 use crate::properties::box_shadow::BoxShadowHandler;
@@ -14,25 +15,28 @@ use crate::properties::masking::MaskHandler;
 use crate::properties::text::{Direction, UnicodeBidi};
 use crate::properties::{
   align::AlignHandler,
-  animation::AnimationHandler,
+  anchor::PositionTryHandler,
+  animation::{AnimationHandler, ScrollTimelineHandler, ViewTimelineHandler},
   background::BackgroundHandler,
   border::BorderHandler,
   contain::ContainerHandler,
   display::DisplayHandler,
   flex::FlexHandler,
-  font::FontHandler,
+  font::{FontHandler, FontSynthesisHandler},
   grid::GridHandler,
   list::ListStyleHandler,
   margin_padding::*,
+  motion::OffsetHandler,
   outline::OutlineHandler,
-  overflow::OverflowHandler,
+  overflow::{LineClampHandler, OverflowHandler, OverscrollBehaviorHandler},
   position::PositionHandler,
-  prefix_handler::{FallbackHandler, PrefixHandler},
-  size::SizeHandler,
-  text::TextDecorationHandler,
+  prefix_handler::{split_prefixed, FallbackHandler, PrefixHandler},
+  size::{ContainIntrinsicSizeHandler, SizeHandler},
+  svg::{MarkerHandler, SVGHandler},
+  text::{TextBoxHandler, TextDecorationHandler, TextSpacingHandler, WhiteSpaceHandler},
   transform::TransformHandler,
   transition::TransitionHandler,
-  ui::ColorSchemeHandler,
+  ui::{CaretHandler, ColorSchemeHandler, ScrollbarHandler, WebKitMobileHandler},
 };
 use crate::properties::{Property, PropertyId};
 use crate::traits::{PropertyHandler, ToCss};
@@ -194,6 +198,14 @@ impl<'i> DeclarationBlock<'i> {
       ($decls: expr, $handler: expr, $important: literal) => {
         for decl in $decls.iter() {
           context.is_important = $important;
+          #[cfg(feature = "substitute_variables")]
+          let substituted = context.substitute_environment_variables(decl);
+          #[cfg(feature = "substitute_variables")]
+          let decl = substituted.as_ref().unwrap_or(decl);
+          #[cfg(feature = "visitor")]
+          let converted = context.convert_px_to_rem(decl);
+          #[cfg(feature = "visitor")]
+          let decl = converted.as_ref().unwrap_or(decl);
           let handled = $handler.handle_property(decl, context);
 
           if !handled {
@@ -203,11 +215,19 @@ impl<'i> DeclarationBlock<'i> {
       };
     }
 
-    handle!(self.important_declarations, important_handler, true);
-    handle!(self.declarations, handler, false);
+    {
+      phase!("minify: handle properties");
+      handle!(self.important_declarations, important_handler, true);
+      handle!(self.declarations, handler, false);
+    }
 
-    handler.finalize(context);
-    important_handler.finalize(context);
+    {
+      phase!("minify: finalize properties");
+      handler.finalize(context);
+      important_handler.finalize(context);
+    }
+    #[cfg(feature = "visitor")]
+    context.add_light_dark_rules(&mut handler.decls);
     self.important_declarations = std::mem::take(&mut important_handler.decls);
     self.declarations = std::mem::take(&mut handler.decls);
   }
@@ -496,24 +516,40 @@ pub(crate) struct DeclarationHandler<'i> {
   grid: GridHandler<'i>,
   align: AlignHandler,
   size: SizeHandler,
+  contain_intrinsic_size: ContainIntrinsicSizeHandler,
   margin: MarginHandler<'i>,
   padding: PaddingHandler<'i>,
   scroll_margin: ScrollMarginHandler<'i>,
   scroll_padding: ScrollPaddingHandler<'i>,
   font: FontHandler<'i>,
+  font_synthesis: FontSynthesisHandler,
   text: TextDecorationHandler<'i>,
   list: ListStyleHandler<'i>,
   transition: TransitionHandler<'i>,
   animation: AnimationHandler<'i>,
+  scroll_timeline: ScrollTimelineHandler<'i>,
+  view_timeline: ViewTimelineHandler<'i>,
   display: DisplayHandler<'i>,
   position: PositionHandler,
   inset: InsetHandler<'i>,
+  position_try: PositionTryHandler<'i>,
   overflow: OverflowHandler,
+  line_clamp: LineClampHandler<'i>,
+  overscroll_behavior: OverscrollBehaviorHandler,
   transform: TransformHandler,
+  offset: OffsetHandler<'i>,
   box_shadow: BoxShadowHandler,
   mask: MaskHandler<'i>,
   container: ContainerHandler<'i>,
+  text_box: TextBoxHandler,
+  white_space: WhiteSpaceHandler,
+  text_spacing: TextSpacingHandler,
   color_scheme: ColorSchemeHandler,
+  scrollbar: ScrollbarHandler,
+  caret: CaretHandler,
+  marker: MarkerHandler<'i>,
+  svg: SVGHandler,
+  webkit_mobile: WebKitMobileHandler,
   fallback: FallbackHandler,
   prefix: PrefixHandler,
   direction: Option<Direction>,
@@ -528,33 +564,56 @@ impl<'i> DeclarationHandler<'i> {
     property: &Property<'i>,
     context: &mut PropertyHandlerContext<'i, '_>,
   ) -> bool {
-    self.background.handle_property(property, &mut self.decls, context)
-      || self.border.handle_property(property, &mut self.decls, context)
-      || self.outline.handle_property(property, &mut self.decls, context)
-      || self.flex.handle_property(property, &mut self.decls, context)
-      || self.grid.handle_property(property, &mut self.decls, context)
-      || self.align.handle_property(property, &mut self.decls, context)
-      || self.size.handle_property(property, &mut self.decls, context)
-      || self.margin.handle_property(property, &mut self.decls, context)
-      || self.padding.handle_property(property, &mut self.decls, context)
-      || self.scroll_margin.handle_property(property, &mut self.decls, context)
-      || self.scroll_padding.handle_property(property, &mut self.decls, context)
-      || self.font.handle_property(property, &mut self.decls, context)
-      || self.text.handle_property(property, &mut self.decls, context)
-      || self.list.handle_property(property, &mut self.decls, context)
-      || self.transition.handle_property(property, &mut self.decls, context)
-      || self.animation.handle_property(property, &mut self.decls, context)
-      || self.display.handle_property(property, &mut self.decls, context)
-      || self.position.handle_property(property, &mut self.decls, context)
-      || self.inset.handle_property(property, &mut self.decls, context)
-      || self.overflow.handle_property(property, &mut self.decls, context)
-      || self.transform.handle_property(property, &mut self.decls, context)
-      || self.box_shadow.handle_property(property, &mut self.decls, context)
-      || self.mask.handle_property(property, &mut self.decls, context)
-      || self.container.handle_property(property, &mut self.decls, context)
-      || self.color_scheme.handle_property(property, &mut self.decls, context)
-      || self.fallback.handle_property(property, &mut self.decls, context)
-      || self.prefix.handle_property(property, &mut self.decls, context)
+    macro_rules! handle {
+      ($handler: ident) => {{
+        phase!(concat!("minify: handle ", stringify!($handler)));
+        self.$handler.handle_property(property, &mut self.decls, context)
+      }};
+    }
+
+    handle!(background)
+      || handle!(border)
+      || handle!(outline)
+      || handle!(flex)
+      || handle!(grid)
+      || handle!(align)
+      || handle!(size)
+      || handle!(contain_intrinsic_size)
+      || handle!(margin)
+      || handle!(padding)
+      || handle!(scroll_margin)
+      || handle!(scroll_padding)
+      || handle!(font)
+      || handle!(font_synthesis)
+      || handle!(text)
+      || handle!(list)
+      || handle!(transition)
+      || handle!(animation)
+      || handle!(scroll_timeline)
+      || handle!(view_timeline)
+      || handle!(display)
+      || handle!(position)
+      || handle!(inset)
+      || handle!(position_try)
+      || handle!(overflow)
+      || handle!(line_clamp)
+      || handle!(overscroll_behavior)
+      || handle!(transform)
+      || handle!(offset)
+      || handle!(box_shadow)
+      || handle!(mask)
+      || handle!(container)
+      || handle!(text_box)
+      || handle!(white_space)
+      || handle!(text_spacing)
+      || handle!(color_scheme)
+      || handle!(scrollbar)
+      || handle!(caret)
+      || handle!(marker)
+      || handle!(svg)
+      || handle!(webkit_mobile)
+      || handle!(fallback)
+      || handle!(prefix)
       || self.handle_all(property)
       || self.handle_custom_property(property, context)
   }
@@ -627,7 +686,7 @@ impl<'i> DeclarationHandler<'i> {
     context: &mut PropertyHandlerContext<'i, '_>,
   ) {
     if context.context != DeclarationContext::Keyframes {
-      let fallbacks = custom.value.get_fallbacks(context.targets);
+      let fallbacks = custom.value.get_fallbacks(context.targets, context.gamut_mapping);
       for (condition, fallback) in fallbacks {
         context.add_conditional_property(
           condition,
@@ -648,33 +707,59 @@ impl<'i> DeclarationHandler<'i> {
       self.decls.push(Property::UnicodeBidi(unicode_bidi));
     }
 
-    self.background.finalize(&mut self.decls, context);
-    self.border.finalize(&mut self.decls, context);
-    self.outline.finalize(&mut self.decls, context);
-    self.flex.finalize(&mut self.decls, context);
-    self.grid.finalize(&mut self.decls, context);
-    self.align.finalize(&mut self.decls, context);
-    self.size.finalize(&mut self.decls, context);
-    self.margin.finalize(&mut self.decls, context);
-    self.padding.finalize(&mut self.decls, context);
-    self.scroll_margin.finalize(&mut self.decls, context);
-    self.scroll_padding.finalize(&mut self.decls, context);
-    self.font.finalize(&mut self.decls, context);
-    self.text.finalize(&mut self.decls, context);
-    self.list.finalize(&mut self.decls, context);
-    self.transition.finalize(&mut self.decls, context);
-    self.animation.finalize(&mut self.decls, context);
-    self.display.finalize(&mut self.decls, context);
-    self.position.finalize(&mut self.decls, context);
-    self.inset.finalize(&mut self.decls, context);
-    self.overflow.finalize(&mut self.decls, context);
-    self.transform.finalize(&mut self.decls, context);
-    self.box_shadow.finalize(&mut self.decls, context);
-    self.mask.finalize(&mut self.decls, context);
-    self.container.finalize(&mut self.decls, context);
-    self.color_scheme.finalize(&mut self.decls, context);
-    self.fallback.finalize(&mut self.decls, context);
-    self.prefix.finalize(&mut self.decls, context);
+    macro_rules! finalize {
+      ($handler: ident) => {{
+        phase!(concat!("minify: finalize ", stringify!($handler)));
+        self.$handler.finalize(&mut self.decls, context);
+      }};
+    }
+
+    finalize!(background);
+    finalize!(border);
+    finalize!(outline);
+    finalize!(flex);
+    finalize!(grid);
+    finalize!(align);
+    finalize!(size);
+    finalize!(contain_intrinsic_size);
+    finalize!(margin);
+    finalize!(padding);
+    finalize!(scroll_margin);
+    finalize!(scroll_padding);
+    finalize!(font);
+    finalize!(font_synthesis);
+    finalize!(text);
+    finalize!(list);
+    finalize!(transition);
+    finalize!(animation);
+    finalize!(scroll_timeline);
+    finalize!(view_timeline);
+    finalize!(display);
+    finalize!(position);
+    finalize!(inset);
+    finalize!(position_try);
+    finalize!(overflow);
+    finalize!(line_clamp);
+    finalize!(overscroll_behavior);
+    finalize!(transform);
+    finalize!(offset);
+    finalize!(box_shadow);
+    finalize!(mask);
+    finalize!(container);
+    finalize!(text_box);
+    finalize!(white_space);
+    finalize!(text_spacing);
+    finalize!(color_scheme);
+    finalize!(scrollbar);
+    finalize!(caret);
+    finalize!(marker);
+    finalize!(svg);
+    finalize!(webkit_mobile);
+    finalize!(fallback);
+    finalize!(prefix);
+    let mut prefixed = self.fallback.take_prefixed();
+    prefixed.extend(self.prefix.take_prefixed());
+    split_prefixed(&mut self.decls, prefixed, context);
     self.custom_properties.clear();
   }
 }
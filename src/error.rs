@@ -104,6 +104,10 @@ pub enum ParserError<'i> {
   UnexpectedToken(#[cfg_attr(any(feature = "serde", feature = "nodejs"), serde(skip))] Token<'i>),
   /// Maximum nesting depth was reached.
   MaximumNestingDepth,
+  /// A property is not allowed within an at rule.
+  DisallowedProperty(CowArcStr<'i>),
+  /// An `:is()` or `:where()` selector could not be expanded for the configured targets.
+  UnexpandableSelector(CowArcStr<'i>),
 }
 
 impl<'i> fmt::Display for ParserError<'i> {
@@ -132,6 +136,8 @@ impl<'i> fmt::Display for ParserError<'i> {
       ),
       UnexpectedToken(token) => write!(f, "Unexpected token {:?}", token),
       MaximumNestingDepth => write!(f, "Overflowed the maximum nesting depth"),
+      DisallowedProperty(name) => write!(f, "The {} property is not allowed here", name),
+      UnexpandableSelector(selector) => write!(f, "Could not expand :is() or :where() in selector {}", selector),
     }
   }
 }
@@ -17,6 +17,7 @@
 #![deny(missing_docs)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+pub mod analysis;
 #[cfg(feature = "bundler")]
 #[cfg_attr(docsrs, doc(cfg(feature = "bundler")))]
 pub mod bundler;
@@ -26,6 +27,7 @@ pub mod css_modules;
 pub mod declaration;
 pub mod dependencies;
 pub mod error;
+pub mod lint;
 mod logical;
 mod macros;
 pub mod media_query;
@@ -37,6 +39,9 @@ pub mod rules;
 pub mod selector;
 pub mod stylesheet;
 pub mod targets;
+#[cfg(feature = "timings")]
+#[cfg_attr(docsrs, doc(cfg(feature = "timings")))]
+pub mod timings;
 pub mod traits;
 pub mod values;
 pub mod vendor_prefix;
@@ -53,7 +58,7 @@ mod tests {
   use crate::dependencies::Dependency;
   use crate::error::{Error, ErrorLocation, MinifyErrorKind, ParserError, PrinterErrorKind, SelectorError};
   use crate::parser::ParserFlags;
-  use crate::properties::custom::Token;
+  use crate::properties::custom::{AttrType, Token, TokenOrValue};
   use crate::properties::Property;
   use crate::rules::CssRule;
   use crate::rules::Location;
@@ -61,6 +66,7 @@ mod tests {
   use crate::targets::{Browsers, Features, Targets};
   use crate::traits::{Parse, ToCss};
   use crate::values::color::CssColor;
+  use crate::values::image::Image;
   use crate::vendor_prefix::VendorPrefix;
   use cssparser::SourceLocation;
   use indoc::indoc;
@@ -83,12 +89,37 @@ mod tests {
   }
 
   fn minify_test_with_options<'i, 'o>(source: &'i str, expected: &'i str, options: ParserOptions<'o, 'i>) {
-    let mut stylesheet = StyleSheet::parse(&source, options.clone()).unwrap();
-    stylesheet.minify(MinifyOptions::default()).unwrap();
+    minify_test_with_parser_and_minify_options(source, expected, options, MinifyOptions::default())
+  }
+
+  fn minify_test_with_minify_options(source: &str, expected: &str, options: MinifyOptions) {
+    minify_test_with_parser_and_minify_options(source, expected, ParserOptions::default(), options)
+  }
+
+  fn minify_test_with_parser_and_minify_options<'i, 'o>(
+    source: &'i str,
+    expected: &'i str,
+    parser_options: ParserOptions<'o, 'i>,
+    options: MinifyOptions,
+  ) {
+    minify_test_with_all_options(source, expected, parser_options, options, PrinterOptions::default())
+  }
+
+  fn minify_test_with_all_options<'i, 'o>(
+    source: &'i str,
+    expected: &'i str,
+    parser_options: ParserOptions<'o, 'i>,
+    options: MinifyOptions,
+    printer_options: PrinterOptions,
+  ) {
+    let targets = options.targets;
+    let mut stylesheet = StyleSheet::parse(&source, parser_options).unwrap();
+    stylesheet.minify(options).unwrap();
     let res = stylesheet
       .to_css(PrinterOptions {
         minify: true,
-        ..PrinterOptions::default()
+        targets,
+        ..printer_options
       })
       .unwrap();
     assert_eq!(res.code, expected);
@@ -2830,6 +2861,75 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_corner_shape() {
+    minify_test(".foo { corner-shape: bevel }", ".foo{corner-shape:bevel}");
+    minify_test(".foo { corner-shape: round scoop }", ".foo{corner-shape:round scoop}");
+    minify_test(
+      ".foo { corner-shape: round scoop round scoop }",
+      ".foo{corner-shape:round scoop}",
+    );
+    minify_test(
+      ".foo { corner-shape: notch square squircle bevel }",
+      ".foo{corner-shape:notch square squircle bevel}",
+    );
+    minify_test(
+      ".foo { corner-shape: superellipse(1) superellipse(-1) superellipse(0) superellipse(2) }",
+      ".foo{corner-shape:round scoop bevel squircle}",
+    );
+    minify_test(
+      ".foo { corner-shape: superellipse(infinity) superellipse(-infinity) }",
+      ".foo{corner-shape:square notch}",
+    );
+    minify_test(
+      ".foo { corner-shape: superellipse(0.50) }",
+      ".foo{corner-shape:superellipse(.5)}",
+    );
+    minify_test(
+      ".foo { corner-shape: superellipse(calc(1 + 2)) }",
+      ".foo{corner-shape:superellipse(3)}",
+    );
+    minify_test(
+      ".foo { corner-top-left-shape: superellipse(-1.5) }",
+      ".foo{corner-top-left-shape:superellipse(-1.5)}",
+    );
+    minify_test(
+      ".foo { corner-top-left-shape: bevel; corner-top-right-shape: bevel; corner-bottom-right-shape: bevel; corner-bottom-left-shape: bevel }",
+      ".foo{corner-shape:bevel}",
+    );
+    minify_test(
+      ".foo { corner-shape: bevel; corner-top-left-shape: scoop }",
+      ".foo{corner-shape:scoop bevel bevel}",
+    );
+    minify_test(
+      ".foo { corner-shape: bevel; corner-start-start-shape: scoop }",
+      ".foo{corner-shape:bevel;corner-start-start-shape:scoop}",
+    );
+    minify_test(
+      ".foo { corner-start-start-shape: scoop; corner-shape: bevel }",
+      ".foo{corner-shape:bevel}",
+    );
+    minify_test(
+      ".foo { border-radius: 10px; corner-shape: bevel; border-top-left-radius: 5px; corner-top-left-shape: scoop }",
+      ".foo{border-radius:5px 10px 10px;corner-shape:scoop bevel bevel}",
+    );
+    minify_test(
+      ".foo { corner-shape: bevel; corner-top-left-shape: var(--shape) }",
+      ".foo{corner-shape:bevel;corner-top-left-shape:var(--shape)}",
+    );
+
+    for value in [
+      "superellipse()",
+      "superellipse(foo)",
+      "superellipse(1px)",
+      "circle",
+      "round round round round round",
+    ] {
+      let property = Property::parse_string("corner-shape".into(), value, ParserOptions::default()).unwrap();
+      assert!(matches!(property, Property::Unparsed(..)), "{}", value);
+    }
+  }
+
   #[test]
   pub fn test_outline() {
     test(
@@ -3955,6 +4055,86 @@ mod tests {
     minify_test(".foo { aspect-ratio: 2 / 3 auto }", ".foo{aspect-ratio:auto 2/3}");
   }
 
+  #[test]
+  fn test_contain_intrinsic_size() {
+    minify_test(
+      ".foo { contain-intrinsic-size: none }",
+      ".foo{contain-intrinsic-size:none}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: 100px }",
+      ".foo{contain-intrinsic-size:100px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: 100px 100px }",
+      ".foo{contain-intrinsic-size:100px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: 100px 50px }",
+      ".foo{contain-intrinsic-size:100px 50px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: auto 100px }",
+      ".foo{contain-intrinsic-size:auto 100px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: auto 100px auto 100px }",
+      ".foo{contain-intrinsic-size:auto 100px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: auto none 50px }",
+      ".foo{contain-intrinsic-size:auto none 50px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: auto none }",
+      ".foo{contain-intrinsic-size:auto none}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: -10px }",
+      ".foo{contain-intrinsic-size:-10px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-width: auto 0px }",
+      ".foo{contain-intrinsic-width:auto 0}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-block-size: auto 2em }",
+      ".foo{contain-intrinsic-block-size:auto 2em}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-inline-size: none }",
+      ".foo{contain-intrinsic-inline-size:none}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-width: 100px; contain-intrinsic-height: auto 50px }",
+      ".foo{contain-intrinsic-size:100px auto 50px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: 100px; contain-intrinsic-height: 50px }",
+      ".foo{contain-intrinsic-size:100px 50px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: 100px; contain-intrinsic-width: auto 100px; contain-intrinsic-height: auto 100px }",
+      ".foo{contain-intrinsic-size:auto 100px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: 100px; contain-intrinsic-block-size: 50px }",
+      ".foo{contain-intrinsic-size:100px;contain-intrinsic-block-size:50px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-block-size: 50px; contain-intrinsic-size: 100px }",
+      ".foo{contain-intrinsic-block-size:50px;contain-intrinsic-size:100px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: 100px; contain-intrinsic-height: var(--h) }",
+      ".foo{contain-intrinsic-size:100px;contain-intrinsic-height:var(--h)}",
+    );
+    minify_test(
+      ".foo { content-visibility: auto; contain-intrinsic-size: auto 500px auto 500px }",
+      ".foo{content-visibility:auto;contain-intrinsic-size:auto 500px}",
+    );
+  }
+
   #[test]
   pub fn test_background() {
     test(
@@ -6318,4352 +6498,5129 @@ mod tests {
   }
 
   #[test]
-  fn test_vertical_align() {
-    minify_test(".foo { vertical-align: middle }", ".foo{vertical-align:middle}");
-    minify_test(".foo { vertical-align: 0.3em }", ".foo{vertical-align:.3em}");
-  }
-
-  #[test]
-  fn test_selectors() {
-    minify_test(":nth-col(2n) {width: 20px}", ":nth-col(2n){width:20px}");
-    minify_test(":nth-col(10n-1) {width: 20px}", ":nth-col(10n-1){width:20px}");
-    minify_test(":nth-col(-n+2) {width: 20px}", ":nth-col(-n+2){width:20px}");
-    minify_test(":nth-col(even) {width: 20px}", ":nth-col(2n){width:20px}");
-    minify_test(":nth-col(odd) {width: 20px}", ":nth-col(odd){width:20px}");
-    minify_test(":nth-last-col(2n) {width: 20px}", ":nth-last-col(2n){width:20px}");
-    minify_test(":nth-last-col(10n-1) {width: 20px}", ":nth-last-col(10n-1){width:20px}");
-    minify_test(":nth-last-col(-n+2) {width: 20px}", ":nth-last-col(-n+2){width:20px}");
-    minify_test(":nth-last-col(even) {width: 20px}", ":nth-last-col(2n){width:20px}");
-    minify_test(":nth-last-col(odd) {width: 20px}", ":nth-last-col(odd){width:20px}");
-    minify_test(":nth-child(odd) {width: 20px}", ":nth-child(odd){width:20px}");
-    minify_test(":nth-child(2n) {width: 20px}", ":nth-child(2n){width:20px}");
-    minify_test(":nth-child(2n+1) {width: 20px}", ":nth-child(odd){width:20px}");
-    minify_test(":first-child {width: 20px}", ":first-child{width:20px}");
-    minify_test(":nth-child(1) {width: 20px}", ":first-child{width:20px}");
-    minify_test(":nth-last-child(1) {width: 20px}", ":last-child{width:20px}");
-    minify_test(":nth-of-type(1) {width: 20px}", ":first-of-type{width:20px}");
-    minify_test(":nth-last-of-type(1) {width: 20px}", ":last-of-type{width:20px}");
+  fn test_font_size_adjust() {
+    minify_test(".foo { font-size-adjust: none }", ".foo{font-size-adjust:none}");
+    minify_test(".foo { font-size-adjust: 0.5 }", ".foo{font-size-adjust:.5}");
+    minify_test(".foo { font-size-adjust: ex-height 0.5 }", ".foo{font-size-adjust:.5}");
     minify_test(
-      ":nth-child(even of li.important) {width: 20px}",
-      ":nth-child(2n of li.important){width:20px}",
+      ".foo { font-size-adjust: from-font }",
+      ".foo{font-size-adjust:from-font}",
     );
     minify_test(
-      ":nth-child(1 of li.important) {width: 20px}",
-      ":nth-child(1 of li.important){width:20px}",
+      ".foo { font-size-adjust: ex-height from-font }",
+      ".foo{font-size-adjust:from-font}",
     );
     minify_test(
-      ":nth-last-child(even of li.important) {width: 20px}",
-      ":nth-last-child(2n of li.important){width:20px}",
+      ".foo { font-size-adjust: cap-height 0.7 }",
+      ".foo{font-size-adjust:cap-height .7}",
     );
     minify_test(
-      ":nth-last-child(1 of li.important) {width: 20px}",
-      ":nth-last-child(1 of li.important){width:20px}",
+      ".foo { font-size-adjust: IC-WIDTH from-font }",
+      ".foo{font-size-adjust:ic-width from-font}",
     );
     minify_test(
-      ":nth-last-child(1 of.important) {width: 20px}",
-      ":nth-last-child(1 of .important){width:20px}",
+      ".foo { font-size-adjust: ch-width 0 }",
+      ".foo{font-size-adjust:ch-width 0}",
+    );
+    minify_test(".foo { font-size-adjust: -1 }", ".foo{font-size-adjust:-1}");
+    minify_test(
+      ".foo { font-size-adjust: 0.5 cap-height }",
+      ".foo{font-size-adjust:.5 cap-height}",
     );
+  }
 
-    minify_test("[foo=\"baz\"] {color:red}", "[foo=baz]{color:red}");
-    minify_test("[foo=\"foo bar\"] {color:red}", "[foo=foo\\ bar]{color:red}");
-    minify_test("[foo=\"foo bar baz\"] {color:red}", "[foo=\"foo bar baz\"]{color:red}");
-    minify_test("[foo=\"\"] {color:red}", "[foo=\"\"]{color:red}");
+  #[test]
+  fn test_font_synthesis() {
+    minify_test(".foo { font-synthesis: none }", ".foo{font-synthesis:none}");
+    minify_test(".foo { font-synthesis: weight }", ".foo{font-synthesis:weight}");
     minify_test(
-      ".test:not([foo=\"bar\"]) {color:red}",
-      ".test:not([foo=bar]){color:red}",
+      ".foo { font-synthesis: small-caps STYLE weight }",
+      ".foo{font-synthesis:weight style small-caps}",
     );
-    minify_test(".test + .foo {color:red}", ".test+.foo{color:red}");
-    minify_test(".test ~ .foo {color:red}", ".test~.foo{color:red}");
-    minify_test(".test .foo {color:red}", ".test .foo{color:red}");
     minify_test(
-      ".custom-range::-webkit-slider-thumb:active {color:red}",
-      ".custom-range::-webkit-slider-thumb:active{color:red}",
+      ".foo { font-synthesis: position weight style small-caps }",
+      ".foo{font-synthesis:weight style small-caps position}",
     );
-    minify_test(".test:not(.foo, .bar) {color:red}", ".test:not(.foo,.bar){color:red}");
-    minify_test(".test:is(.foo, .bar) {color:red}", ".test:is(.foo,.bar){color:red}");
     minify_test(
-      ".test:where(.foo, .bar) {color:red}",
-      ".test:where(.foo,.bar){color:red}",
+      ".foo { font-synthesis: weight weight }",
+      ".foo{font-synthesis:weight weight}",
     );
     minify_test(
-      ".test:where(.foo, .bar) {color:red}",
-      ".test:where(.foo,.bar){color:red}",
+      ".foo { font-synthesis-weight: none }",
+      ".foo{font-synthesis-weight:none}",
     );
-    minify_test(":host {color:red}", ":host{color:red}");
-    minify_test(":host(.foo) {color:red}", ":host(.foo){color:red}");
-    minify_test("::slotted(span) {color:red", "::slotted(span){color:red}");
     minify_test(
-      "custom-element::part(foo) {color:red}",
-      "custom-element::part(foo){color:red}",
+      ".foo { font-synthesis-position: auto }",
+      ".foo{font-synthesis-position:auto}",
     );
-    minify_test(".sm\\:text-5xl { font-size: 3rem }", ".sm\\:text-5xl{font-size:3rem}");
-    minify_test("a:has(> img) {color:red}", "a:has(>img){color:red}");
-    minify_test("dt:has(+ dt) {color:red}", "dt:has(+dt){color:red}");
     minify_test(
-      "section:not(:has(h1, h2, h3, h4, h5, h6)) {color:red}",
-      "section:not(:has(h1,h2,h3,h4,h5,h6)){color:red}",
+      ".foo { font-synthesis-weight: none; font-synthesis-style: auto; font-synthesis-small-caps: none; font-synthesis-position: none }",
+      ".foo{font-synthesis:style}",
     );
     minify_test(
-      ":has(.sibling ~ .target) {color:red}",
-      ":has(.sibling~.target){color:red}",
+      ".foo { font-synthesis-weight: none; font-synthesis-style: none; font-synthesis-small-caps: none; font-synthesis-position: none }",
+      ".foo{font-synthesis:none}",
     );
-    minify_test(".x:has(> .a > .b) {color:red}", ".x:has(>.a>.b){color:red}");
-    minify_test(".x:has(.bar, #foo) {color:red}", ".x:has(.bar,#foo){color:red}");
-    minify_test(".x:has(span + span) {color:red}", ".x:has(span+span){color:red}");
-    minify_test("a:has(:visited) {color:red}", "a:has(:visited){color:red}");
-    for element in [
-      "-webkit-scrollbar",
-      "-webkit-scrollbar-button",
-      "-webkit-scrollbar-track",
-      "-webkit-scrollbar-track-piece",
-      "-webkit-scrollbar-thumb",
-      "-webkit-scrollbar-corner",
-      "-webkit-resizer",
-    ] {
-      for class in [
-        "enabled",
-        "disabled",
-        "hover",
-        "active",
-        "horizontal",
-        "vertical",
-        "decrement",
-        "increment",
-        "start",
-        "end",
-        "double-button",
-        "single-button",
-        "no-button",
-        "corner-present",
-        "window-inactive",
-      ] {
-        minify_test(
-          &format!("::{}:{} {{color:red}}", element, class),
-          &format!("::{}:{}{{color:red}}", element, class),
-        );
-      }
-    }
-    for class in [
-      "horizontal",
-      "vertical",
-      "decrement",
-      "increment",
-      "start",
-      "end",
-      "double-button",
-      "single-button",
-      "no-button",
-      "corner-present",
-      "window-inactive",
-    ] {
-      error_test(
-        &format!(":{} {{color:red}}", class),
-        ParserError::SelectorError(SelectorError::InvalidPseudoClassBeforeWebKitScrollbar),
-      );
-    }
-    for element in [
-      "-webkit-scrollbar",
-      "-webkit-scrollbar-button",
-      "-webkit-scrollbar-track",
-      "-webkit-scrollbar-track-piece",
-      "-webkit-scrollbar-thumb",
-      "-webkit-scrollbar-corner",
-      "-webkit-resizer",
-    ] {
-      error_test(
-        &format!("::{}:focus {{color:red}}", element),
-        ParserError::SelectorError(SelectorError::InvalidPseudoClassAfterWebKitScrollbar),
-      );
-    }
-
-    error_test(
-      "a::first-letter:last-child {color:red}",
-      ParserError::SelectorError(SelectorError::InvalidPseudoClassAfterPseudoElement),
+    minify_test(
+      ".foo { font-synthesis: none; font-synthesis-weight: auto }",
+      ".foo{font-synthesis:weight}",
     );
     minify_test(
-      "a:last-child::first-letter {color:red}",
-      "a:last-child:first-letter{color:red}",
+      ".foo { font-synthesis-weight: none; font-synthesis-style: none }",
+      ".foo{font-synthesis-weight:none;font-synthesis-style:none}",
+    );
+    minify_test(
+      ".foo { font-synthesis: none; font-synthesis-style: var(--style) }",
+      ".foo{font-synthesis:none;font-synthesis-style:var(--style)}",
     );
 
     prefix_test(
-      ".test:not(.foo, .bar) {color:red}",
-      indoc! {r#"
-      .test:not(:-webkit-any(.foo, .bar)) {
-        color: red;
+      r#"
+      .foo {
+        font-synthesis-weight: none;
+        font-synthesis-style: auto;
+        font-synthesis-small-caps: none;
       }
-
-      .test:not(:is(.foo, .bar)) {
-        color: red;
+    "#,
+      indoc! {r#"
+      .foo {
+        font-synthesis: style;
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(8 << 16),
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
-      ".test:not(.foo, .bar) {color:red}",
+      r#"
+      .foo {
+        font-synthesis-weight: none;
+        font-synthesis-style: auto;
+        font-synthesis-small-caps: none;
+      }
+    "#,
       indoc! {r#"
-      .test:not(.foo, .bar) {
-        color: red;
+      .foo {
+        font-synthesis-weight: none;
+        font-synthesis-style: auto;
+        font-synthesis-small-caps: none;
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(11 << 16),
+        chrome: Some(110 << 16),
         ..Browsers::default()
       },
     );
-
-    minify_test("a:lang(en) {color:red}", "a:lang(en){color:red}");
-    minify_test("a:lang(en, fr) {color:red}", "a:lang(en,fr){color:red}");
-    minify_test("a:lang('en') {color:red}", "a:lang(en){color:red}");
-    minify_test(
-      "a:-webkit-any(.foo, .bar) {color:red}",
-      "a:-webkit-any(.foo,.bar){color:red}",
-    );
-    minify_test("a:-moz-any(.foo, .bar) {color:red}", "a:-moz-any(.foo,.bar){color:red}");
-
     prefix_test(
-      "a:is(.foo, .bar) {color:red}",
-      indoc! {r#"
-      a:-webkit-any(.foo, .bar) {
-        color: red;
-      }
-
-      a:-moz-any(.foo, .bar) {
-        color: red;
-      }
-
-      a:is(.foo, .bar) {
-        color: red;
+      r#"
+      .foo {
+        font-synthesis-weight: none;
+        font-synthesis-style: none;
       }
-      "#},
-      Browsers {
-        safari: Some(11 << 16),
-        firefox: Some(50 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    prefix_test(
-      "a:is(.foo > .bar) {color:red}",
+    "#,
       indoc! {r#"
-      a:is(.foo > .bar) {
-        color: red;
+      .foo {
+        font-synthesis-weight: none;
+        font-synthesis-style: none;
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(11 << 16),
-        firefox: Some(50 << 16),
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
-      "a:lang(en, fr) {color:red}",
-      indoc! {r#"
-      a:-webkit-any(:lang(en), :lang(fr)) {
-        color: red;
-      }
-
-      a:-moz-any(:lang(en), :lang(fr)) {
-        color: red;
-      }
-
-      a:is(:lang(en), :lang(fr)) {
-        color: red;
+      r#"
+      .foo {
+        font-synthesis: weight style small-caps position;
       }
-      "#},
-      Browsers {
-        safari: Some(11 << 16),
-        firefox: Some(50 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    prefix_test(
-      "a:lang(en, fr) {color:red}",
+    "#,
       indoc! {r#"
-      a:is(:lang(en), :lang(fr)) {
-        color: red;
+      .foo {
+        font-synthesis: weight style small-caps;
+        font-synthesis-position: auto;
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(14 << 16),
-        firefox: Some(88 << 16),
+        chrome: Some(110 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
-      "a:lang(en, fr) {color:red}",
-      indoc! {r#"
-      a:lang(en, fr) {
-        color: red;
+      r#"
+      .foo {
+        font-synthesis: weight style small-caps position;
       }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    prefix_test(
-      "a:dir(rtl) {color:red}",
+    "#,
       indoc! {r#"
-      a:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        color: red;
-      }
-
-      a:-moz-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        color: red;
-      }
-
-      a:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        color: red;
+      .foo {
+        font-synthesis: weight style small-caps position;
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(11 << 16),
-        firefox: Some(50 << 16),
+        firefox: Some(120 << 16),
         ..Browsers::default()
       },
     );
+  }
 
-    prefix_test(
-      "a:dir(ltr) {color:red}",
-      indoc! {r#"
-      a:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        color: red;
-      }
-
-      a:not(:-moz-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        color: red;
-      }
-
-      a:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        color: red;
-      }
-      "#},
-      Browsers {
-        safari: Some(11 << 16),
-        firefox: Some(50 << 16),
-        ..Browsers::default()
-      },
+  #[test]
+  fn test_font_variant() {
+    minify_test(
+      ".foo { font-variant-ligatures: normal }",
+      ".foo{font-variant-ligatures:normal}",
     );
-
-    prefix_test(
-      "a:dir(rtl) {color:red}",
-      indoc! {r#"
-      a:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        color: red;
-      }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        firefox: Some(88 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { font-variant-ligatures: none }",
+      ".foo{font-variant-ligatures:none}",
     );
-
-    prefix_test(
-      "a:dir(ltr) {color:red}",
-      indoc! {r#"
-      a:not(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        color: red;
-      }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        firefox: Some(88 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { font-variant-ligatures: no-contextual common-ligatures }",
+      ".foo{font-variant-ligatures:common-ligatures no-contextual}",
     );
-
-    prefix_test(
-      "a:dir(rtl) {color:red}",
-      indoc! {r#"
-      a:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
-        color: red;
-      }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { font-variant-ligatures: no-common-ligatures no-discretionary-ligatures no-historical-ligatures no-contextual }",
+      ".foo{font-variant-ligatures:none}",
     );
-
-    prefix_test(
-      "a:dir(ltr) {color:red}",
-      indoc! {r#"
-      a:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
-        color: red;
-      }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { font-variant-ligatures: common-ligatures no-common-ligatures }",
+      ".foo{font-variant-ligatures:common-ligatures no-common-ligatures}",
     );
-
-    prefix_test(
-      "a:is(:dir(rtl)) {color:red}",
-      indoc! {r#"
-      a:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
-        color: red;
-      }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { font-variant-numeric: slashed-zero tabular-nums oldstyle-nums }",
+      ".foo{font-variant-numeric:oldstyle-nums tabular-nums slashed-zero}",
     );
-
-    prefix_test(
-      "a:where(:dir(rtl)) {color:red}",
-      indoc! {r#"
-      a:where(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
-        color: red;
-      }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { font-variant-numeric: ordinal diagonal-fractions }",
+      ".foo{font-variant-numeric:diagonal-fractions ordinal}",
     );
-
-    prefix_test(
-      "a:has(:dir(rtl)) {color:red}",
-      indoc! {r#"
-      a:has(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
-        color: red;
-      }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { font-variant-east-asian: ruby full-width jis04 }",
+      ".foo{font-variant-east-asian:jis04 full-width ruby}",
     );
-
-    prefix_test(
-      "a:not(:dir(rtl)) {color:red}",
-      indoc! {r#"
-      a:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
-        color: red;
-      }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { font-variant-alternates: historical-forms stylistic(foo) }",
+      ".foo{font-variant-alternates:stylistic(foo) historical-forms}",
     );
-
-    prefix_test(
-      "a:dir(rtl)::after {color:red}",
-      indoc! {r#"
-      a:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi):after {
-        color: red;
-      }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { font-variant-alternates: styleset(a, b) character-variant(c) swash(d) ornaments(e) annotation(f) }",
+      ".foo{font-variant-alternates:styleset(a,b) character-variant(c) swash(d) ornaments(e) annotation(f)}",
     );
-
-    prefix_test(
-      "a:dir(rtl) div {color:red}",
-      indoc! {r#"
-      a:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) div {
-        color: red;
-      }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    minify_test(".foo::cue {color: red}", ".foo::cue{color:red}");
-    minify_test(".foo::cue-region {color: red}", ".foo::cue-region{color:red}");
-    minify_test(".foo::cue(b) {color: red}", ".foo::cue(b){color:red}");
-    minify_test(".foo::cue-region(b) {color: red}", ".foo::cue-region(b){color:red}");
     minify_test(
-      "::cue(v[voice='active']) {color: yellow;}",
-      "::cue(v[voice=active]){color:#ff0}",
+      ".foo { font-variant-position: super }",
+      ".foo{font-variant-position:super}",
     );
-    minify_test(":foo(bar) { color: yellow }", ":foo(bar){color:#ff0}");
-    minify_test("::foo(bar) { color: yellow }", "::foo(bar){color:#ff0}");
-    minify_test("::foo(*) { color: yellow }", "::foo(*){color:#ff0}");
+    minify_test(".foo { font-variant-emoji: emoji }", ".foo{font-variant-emoji:emoji}");
 
-    minify_test(":is(.foo) { color: yellow }", ".foo{color:#ff0}");
-    minify_test(":is(#foo) { color: yellow }", "#foo{color:#ff0}");
-    minify_test("a:is(.foo) { color: yellow }", "a.foo{color:#ff0}");
-    minify_test("a:is([data-test]) { color: yellow }", "a[data-test]{color:#ff0}");
-    minify_test(".foo:is(a) { color: yellow }", ".foo:is(a){color:#ff0}");
-    minify_test(".foo:is(*|a) { color: yellow }", ".foo:is(*|a){color:#ff0}");
-    minify_test(".foo:is(*) { color: yellow }", ".foo:is(*){color:#ff0}");
+    minify_test(".foo { font-variant: normal }", ".foo{font-variant:normal}");
+    minify_test(".foo { font-variant: none }", ".foo{font-variant:none}");
+    minify_test(".foo { font-variant: small-caps }", ".foo{font-variant:small-caps}");
     minify_test(
-      "@namespace svg url(http://www.w3.org/2000/svg); .foo:is(svg|a) { color: yellow }",
-      "@namespace svg \"http://www.w3.org/2000/svg\";.foo:is(svg|a){color:#ff0}",
+      ".foo { font-variant: tabular-nums small-caps no-contextual super emoji }",
+      ".foo{font-variant:no-contextual small-caps tabular-nums super emoji}",
     );
-    minify_test("a:is(.foo .bar) { color: yellow }", "a:is(.foo .bar){color:#ff0}");
-    minify_test(":is(.foo, .bar) { color: yellow }", ":is(.foo,.bar){color:#ff0}");
-    minify_test("a:is(:not(.foo)) { color: yellow }", "a:not(.foo){color:#ff0}");
-    minify_test("a:is(:first-child) { color: yellow }", "a:first-child{color:#ff0}");
-    minify_test("a:is(:has(.foo)) { color: yellow }", "a:has(.foo){color:#ff0}");
-    minify_test("a:is(:is(.foo)) { color: yellow }", "a.foo{color:#ff0}");
-    minify_test(":host(:hover) {color: red}", ":host(:hover){color:red}");
-    minify_test("::slotted(:hover) {color: red}", "::slotted(:hover){color:red}");
-
     minify_test(
-      ":root::view-transition {position: fixed}",
-      ":root::view-transition{position:fixed}",
+      ".foo { font-variant: none small-caps }",
+      ".foo{font-variant:none small-caps}",
     );
-    for name in &[
-      "view-transition-group",
-      "view-transition-image-pair",
-      "view-transition-new",
-      "view-transition-old",
-    ] {
-      minify_test(
-        &format!(":root::{}(*) {{position: fixed}}", name),
-        &format!(":root::{}(*){{position:fixed}}", name),
-      );
-      minify_test(
-        &format!(":root::{}(foo) {{position: fixed}}", name),
-        &format!(":root::{}(foo){{position:fixed}}", name),
-      );
-      minify_test(
-        &format!(":root::{}(foo):only-child {{position: fixed}}", name),
-        &format!(":root::{}(foo):only-child{{position:fixed}}", name),
-      );
-      error_test(
-        &format!(":root::{}(foo):first-child {{position: fixed}}", name),
-        ParserError::SelectorError(SelectorError::InvalidPseudoClassAfterPseudoElement),
-      );
-      error_test(
-        &format!(":root::{}(foo)::before {{position: fixed}}", name),
-        ParserError::SelectorError(SelectorError::InvalidState),
-      );
-    }
-
-    minify_test(".foo ::deep .bar {width: 20px}", ".foo ::deep .bar{width:20px}");
-    minify_test(".foo::deep .bar {width: 20px}", ".foo::deep .bar{width:20px}");
-    minify_test(".foo ::deep.bar {width: 20px}", ".foo ::deep.bar{width:20px}");
-    minify_test(".foo ::unknown .bar {width: 20px}", ".foo ::unknown .bar{width:20px}");
     minify_test(
-      ".foo ::unknown(foo) .bar {width: 20px}",
-      ".foo ::unknown(foo) .bar{width:20px}",
+      ".foo { font-variant: normal small-caps }",
+      ".foo{font-variant:normal small-caps}",
     );
     minify_test(
-      ".foo ::unknown:only-child {width: 20px}",
-      ".foo ::unknown:only-child{width:20px}",
+      ".foo { font-variant: small-caps; font-variant-ligatures: none }",
+      ".foo{font-variant:no-common-ligatures no-discretionary-ligatures no-historical-ligatures no-contextual small-caps}",
     );
     minify_test(
-      ".foo ::unknown(.foo) .bar {width: 20px}",
-      ".foo ::unknown(.foo) .bar{width:20px}",
+      ".foo { font-variant: small-caps; font-variant-caps: normal }",
+      ".foo{font-variant:normal}",
     );
     minify_test(
-      ".foo ::unknown(.foo .bar / .baz) .bar {width: 20px}",
-      ".foo ::unknown(.foo .bar / .baz) .bar{width:20px}",
+      ".foo { font-variant-caps: small-caps; font-variant-numeric: tabular-nums }",
+      ".foo{font-variant-caps:small-caps;font-variant-numeric:tabular-nums}",
     );
     minify_test(
-      ".foo ::unknown(something(foo)) .bar {width: 20px}",
-      ".foo ::unknown(something(foo)) .bar{width:20px}",
+      ".foo { font-variant-numeric: tabular-nums; font-variant: small-caps }",
+      ".foo{font-variant:small-caps}",
     );
     minify_test(
-      ".foo ::unknown([abc]) .bar {width: 20px}",
-      ".foo ::unknown([abc]) .bar{width:20px}",
+      ".foo { font-variant: tabular-nums; font-variant-emoji: var(--emoji) }",
+      ".foo{font-variant:tabular-nums;font-variant-emoji:var(--emoji)}",
     );
-
-    let deep_options = ParserOptions {
-      flags: ParserFlags::DEEP_SELECTOR_COMBINATOR,
-      ..ParserOptions::default()
-    };
-
-    error_test(
-      ".foo >>> .bar {width: 20px}",
-      ParserError::SelectorError(SelectorError::DanglingCombinator),
+    minify_test(
+      ".foo { font: 12px serif; font-variant: tabular-nums }",
+      ".foo{font:12px serif;font-variant-numeric:tabular-nums}",
     );
-    error_test(
-      ".foo /deep/ .bar {width: 20px}",
-      ParserError::SelectorError(SelectorError::DanglingCombinator),
+    minify_test(
+      ".foo { font-variant: tabular-nums; font: 12px serif }",
+      ".foo{font:12px serif}",
     );
-    minify_test_with_options(
-      ".foo >>> .bar {width: 20px}",
-      ".foo>>>.bar{width:20px}",
-      deep_options.clone(),
+    minify_test(
+      ".foo { font-variant: tabular-nums all-small-caps; font: 12px serif }",
+      ".foo{font:12px serif}",
     );
-    minify_test_with_options(
-      ".foo /deep/ .bar {width: 20px}",
-      ".foo /deep/ .bar{width:20px}",
-      deep_options.clone(),
+    minify_test(
+      ".foo { font: small-caps 12px serif; font-variant-position: sub }",
+      ".foo{font:small-caps 12px serif;font-variant-position:sub}",
     );
   }
 
   #[test]
-  fn test_keyframes() {
+  fn test_font_shorthand_reset() {
     minify_test(
-      r#"
-      @keyframes "test" {
-        100% {
-          background: blue
-        }
-      }
-    "#,
-      "@keyframes test{to{background:#00f}}",
+      ".foo { font-size-adjust: 0.5; font: 12px serif }",
+      ".foo{font:12px serif}",
     );
     minify_test(
-      r#"
-      @keyframes test {
-        100% {
-          background: blue
-        }
-      }
-    "#,
-      "@keyframes test{to{background:#00f}}",
+      ".foo { font: 12px serif; font-size-adjust: 0.5 }",
+      ".foo{font:12px serif;font-size-adjust:.5}",
     );
-
-    // CSS-wide keywords and `none` cannot remove quotes.
     minify_test(
-      r#"
-      @keyframes "revert" {
-        from {
-          background: green;
-        }
-      }
-    "#,
-      "@keyframes \"revert\"{0%{background:green}}",
+      ".foo { font: 12px serif; font-size-adjust: none }",
+      ".foo{font:12px serif}",
     );
-
     minify_test(
-      r#"
-      @keyframes "none" {
-        from {
-          background: green;
-        }
-      }
-    "#,
-      "@keyframes \"none\"{0%{background:green}}",
+      ".foo { font-family: serif; font-size-adjust: cap-height 0.5 }",
+      ".foo{font-family:serif;font-size-adjust:cap-height .5}",
     );
-
-    // CSS-wide keywords without quotes throws an error.
-    error_test(
-      r#"
-      @keyframes revert {}
-    "#,
-      ParserError::UnexpectedToken(Token::Ident("revert".into())),
+    minify_test(
+      ".foo { font: 12px serif; font-size-adjust: var(--adjust) }",
+      ".foo{font:12px serif;font-size-adjust:var(--adjust)}",
     );
-
-    error_test(
-      r#"
-      @keyframes revert-layer {}
-    "#,
-      ParserError::UnexpectedToken(Token::Ident("revert-layer".into())),
+    minify_test(
+      ".foo { font: 12px serif; font-kerning: none }",
+      ".foo{font:12px serif;font-kerning:none}",
     );
-
-    error_test(
-      r#"
-      @keyframes none {}
-    "#,
-      ParserError::UnexpectedToken(Token::Ident("none".into())),
+    minify_test(
+      ".foo { font-kerning: none; font: 12px serif }",
+      ".foo{font-kerning:none;font:12px serif}",
     );
-
-    error_test(
-      r#"
-      @keyframes NONE {}
-    "#,
-      ParserError::UnexpectedToken(Token::Ident("NONE".into())),
+    minify_test(
+      ".foo { font: 12px serif; font-variation-settings: \"wght\" 400; font-weight: bold }",
+      ".foo{font:12px serif;font-variation-settings:\"wght\" 400;font-weight:700}",
     );
-
     minify_test(
-      r#"
-      @-webkit-keyframes test {
-        from {
-          background: green;
-          background-color: red;
-        }
+      ".foo { font: 12px serif; font-feature-settings: \"liga\" 0 }",
+      ".foo{font:12px serif;font-feature-settings:\"liga\" 0}",
+    );
+  }
 
-        100% {
-          background: blue
-        }
-      }
-    "#,
-      "@-webkit-keyframes test{0%{background:red}to{background:#00f}}",
+  #[test]
+  fn test_vertical_align() {
+    minify_test(".foo { vertical-align: middle }", ".foo{vertical-align:middle}");
+    minify_test(".foo { vertical-align: 0.3em }", ".foo{vertical-align:.3em}");
+  }
+
+  #[test]
+  fn test_selectors() {
+    minify_test(":nth-col(2n) {width: 20px}", ":nth-col(2n){width:20px}");
+    minify_test(":nth-col(10n-1) {width: 20px}", ":nth-col(10n-1){width:20px}");
+    minify_test(":nth-col(-n+2) {width: 20px}", ":nth-col(-n+2){width:20px}");
+    minify_test(":nth-col(even) {width: 20px}", ":nth-col(2n){width:20px}");
+    minify_test(":nth-col(odd) {width: 20px}", ":nth-col(odd){width:20px}");
+    minify_test(":nth-last-col(2n) {width: 20px}", ":nth-last-col(2n){width:20px}");
+    minify_test(":nth-last-col(10n-1) {width: 20px}", ":nth-last-col(10n-1){width:20px}");
+    minify_test(":nth-last-col(-n+2) {width: 20px}", ":nth-last-col(-n+2){width:20px}");
+    minify_test(":nth-last-col(even) {width: 20px}", ":nth-last-col(2n){width:20px}");
+    minify_test(":nth-last-col(odd) {width: 20px}", ":nth-last-col(odd){width:20px}");
+    minify_test(":nth-child(odd) {width: 20px}", ":nth-child(odd){width:20px}");
+    minify_test(":nth-child(2n) {width: 20px}", ":nth-child(2n){width:20px}");
+    minify_test(":nth-child(2n+1) {width: 20px}", ":nth-child(odd){width:20px}");
+    minify_test(":first-child {width: 20px}", ":first-child{width:20px}");
+    minify_test(":nth-child(1) {width: 20px}", ":first-child{width:20px}");
+    minify_test(":nth-last-child(1) {width: 20px}", ":last-child{width:20px}");
+    minify_test(":nth-of-type(1) {width: 20px}", ":first-of-type{width:20px}");
+    minify_test(":nth-last-of-type(1) {width: 20px}", ":last-of-type{width:20px}");
+    minify_test(
+      ":nth-child(even of li.important) {width: 20px}",
+      ":nth-child(2n of li.important){width:20px}",
     );
     minify_test(
-      r#"
-      @-moz-keyframes test {
-        from {
-          background: green;
-          background-color: red;
-        }
-
-        100% {
-          background: blue
-        }
-      }
-    "#,
-      "@-moz-keyframes test{0%{background:red}to{background:#00f}}",
+      ":nth-child(1 of li.important) {width: 20px}",
+      ":nth-child(1 of li.important){width:20px}",
+    );
+    minify_test(
+      ":nth-last-child(even of li.important) {width: 20px}",
+      ":nth-last-child(2n of li.important){width:20px}",
+    );
+    minify_test(
+      ":nth-last-child(1 of li.important) {width: 20px}",
+      ":nth-last-child(1 of li.important){width:20px}",
+    );
+    minify_test(
+      ":nth-last-child(1 of.important) {width: 20px}",
+      ":nth-last-child(1 of .important){width:20px}",
     );
-    minify_test(r#"
-      @-webkit-keyframes test {
-        from {
-          background: green;
-          background-color: red;
-        }
 
-        100% {
-          background: blue
-        }
-      }
-      @-moz-keyframes test {
-        from {
-          background: green;
-          background-color: red;
-        }
-
-        100% {
-          background: blue
-        }
-      }
-    "#, "@-webkit-keyframes test{0%{background:red}to{background:#00f}}@-moz-keyframes test{0%{background:red}to{background:#00f}}");
-
-    prefix_test(
-      r#"
-      @keyframes test {
-        from {
-          background: green;
-        }
-        to {
-          background: blue
-        }
-      }
-    "#,
-      indoc! { r#"
-      @-webkit-keyframes test {
-        from {
-          background: green;
-        }
-
-        to {
-          background: #00f;
-        }
+    minify_test("[foo=\"baz\"] {color:red}", "[foo=baz]{color:red}");
+    minify_test("[foo=\"foo bar\"] {color:red}", "[foo=foo\\ bar]{color:red}");
+    minify_test("[foo=\"foo bar baz\"] {color:red}", "[foo=\"foo bar baz\"]{color:red}");
+    minify_test("[foo=\"\"] {color:red}", "[foo=\"\"]{color:red}");
+    minify_test(
+      ".test:not([foo=\"bar\"]) {color:red}",
+      ".test:not([foo=bar]){color:red}",
+    );
+    minify_test(".test + .foo {color:red}", ".test+.foo{color:red}");
+    minify_test(".test ~ .foo {color:red}", ".test~.foo{color:red}");
+    minify_test(".test .foo {color:red}", ".test .foo{color:red}");
+    minify_test(
+      ".custom-range::-webkit-slider-thumb:active {color:red}",
+      ".custom-range::-webkit-slider-thumb:active{color:red}",
+    );
+    minify_test(".test:not(.foo, .bar) {color:red}", ".test:not(.foo,.bar){color:red}");
+    minify_test(".test:is(.foo, .bar) {color:red}", ".test:is(.foo,.bar){color:red}");
+    minify_test(
+      ".test:where(.foo, .bar) {color:red}",
+      ".test:where(.foo,.bar){color:red}",
+    );
+    minify_test(
+      ".test:where(.foo, .bar) {color:red}",
+      ".test:where(.foo,.bar){color:red}",
+    );
+    minify_test(":host {color:red}", ":host{color:red}");
+    minify_test(":host(.foo) {color:red}", ":host(.foo){color:red}");
+    minify_test("::slotted(span) {color:red", "::slotted(span){color:red}");
+    minify_test(
+      "custom-element::part(foo) {color:red}",
+      "custom-element::part(foo){color:red}",
+    );
+    minify_test(".sm\\:text-5xl { font-size: 3rem }", ".sm\\:text-5xl{font-size:3rem}");
+    minify_test("a:has(> img) {color:red}", "a:has(>img){color:red}");
+    minify_test("dt:has(+ dt) {color:red}", "dt:has(+dt){color:red}");
+    minify_test(
+      "section:not(:has(h1, h2, h3, h4, h5, h6)) {color:red}",
+      "section:not(:has(h1,h2,h3,h4,h5,h6)){color:red}",
+    );
+    minify_test(
+      ":has(.sibling ~ .target) {color:red}",
+      ":has(.sibling~.target){color:red}",
+    );
+    minify_test(".x:has(> .a > .b) {color:red}", ".x:has(>.a>.b){color:red}");
+    minify_test(".x:has(.bar, #foo) {color:red}", ".x:has(.bar,#foo){color:red}");
+    minify_test(".x:has(span + span) {color:red}", ".x:has(span+span){color:red}");
+    minify_test("a:has(:visited) {color:red}", "a:has(:visited){color:red}");
+    for element in [
+      "-webkit-scrollbar",
+      "-webkit-scrollbar-button",
+      "-webkit-scrollbar-track",
+      "-webkit-scrollbar-track-piece",
+      "-webkit-scrollbar-thumb",
+      "-webkit-scrollbar-corner",
+      "-webkit-resizer",
+    ] {
+      for class in [
+        "enabled",
+        "disabled",
+        "hover",
+        "active",
+        "horizontal",
+        "vertical",
+        "decrement",
+        "increment",
+        "start",
+        "end",
+        "double-button",
+        "single-button",
+        "no-button",
+        "corner-present",
+        "window-inactive",
+      ] {
+        minify_test(
+          &format!("::{}:{} {{color:red}}", element, class),
+          &format!("::{}:{}{{color:red}}", element, class),
+        );
       }
+    }
+    for class in [
+      "horizontal",
+      "vertical",
+      "decrement",
+      "increment",
+      "start",
+      "end",
+      "double-button",
+      "single-button",
+      "no-button",
+      "corner-present",
+      "window-inactive",
+    ] {
+      error_test(
+        &format!(":{} {{color:red}}", class),
+        ParserError::SelectorError(SelectorError::InvalidPseudoClassBeforeWebKitScrollbar),
+      );
+    }
+    for element in [
+      "-webkit-scrollbar",
+      "-webkit-scrollbar-button",
+      "-webkit-scrollbar-track",
+      "-webkit-scrollbar-track-piece",
+      "-webkit-scrollbar-thumb",
+      "-webkit-scrollbar-corner",
+      "-webkit-resizer",
+    ] {
+      error_test(
+        &format!("::{}:focus {{color:red}}", element),
+        ParserError::SelectorError(SelectorError::InvalidPseudoClassAfterWebKitScrollbar),
+      );
+    }
 
-      @-moz-keyframes test {
-        from {
-          background: green;
-        }
+    error_test(
+      "a::first-letter:last-child {color:red}",
+      ParserError::SelectorError(SelectorError::InvalidPseudoClassAfterPseudoElement),
+    );
+    minify_test(
+      "a:last-child::first-letter {color:red}",
+      "a:last-child:first-letter{color:red}",
+    );
 
-        to {
-          background: #00f;
-        }
+    prefix_test(
+      ".test:not(.foo, .bar) {color:red}",
+      indoc! {r#"
+      .test:not(:-webkit-any(.foo, .bar)) {
+        color: red;
       }
 
-      @keyframes test {
-        from {
-          background: green;
-        }
-
-        to {
-          background: #00f;
-        }
+      .test:not(:is(.foo, .bar)) {
+        color: red;
       }
-    "#},
+      "#},
       Browsers {
-        safari: Some(5 << 16),
-        firefox: Some(6 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
-      r#"
-      @-webkit-keyframes test {
-        from {
-          background: green;
-        }
-
-        to {
-          background: blue;
-        }
+      ".test:not(.foo, .bar) {color:red}",
+      indoc! {r#"
+      .test:not(.foo, .bar) {
+        color: red;
       }
-      @-moz-keyframes test {
-        from {
-          background: green;
-        }
+      "#},
+      Browsers {
+        safari: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
 
-        to {
-          background: blue;
-        }
+    minify_test("a:lang(en) {color:red}", "a:lang(en){color:red}");
+    minify_test("a:lang(en, fr) {color:red}", "a:lang(en,fr){color:red}");
+    minify_test("a:lang('en') {color:red}", "a:lang(en){color:red}");
+    minify_test(
+      "a:-webkit-any(.foo, .bar) {color:red}",
+      "a:-webkit-any(.foo,.bar){color:red}",
+    );
+    minify_test("a:-moz-any(.foo, .bar) {color:red}", "a:-moz-any(.foo,.bar){color:red}");
+
+    prefix_test(
+      "a:is(.foo, .bar) {color:red}",
+      indoc! {r#"
+      a:-webkit-any(.foo, .bar) {
+        color: red;
       }
-      @keyframes test {
-        from {
-          background: green;
-        }
-        to {
-          background: blue
-        }
+
+      a:-moz-any(.foo, .bar) {
+        color: red;
       }
-    "#,
-      indoc! { r#"
-      @keyframes test {
-        from {
-          background: green;
-        }
 
-        to {
-          background: #00f;
-        }
+      a:is(.foo, .bar) {
+        color: red;
       }
-    "#},
+      "#},
       Browsers {
-        safari: Some(10 << 16),
-        firefox: Some(17 << 16),
+        safari: Some(11 << 16),
+        firefox: Some(50 << 16),
         ..Browsers::default()
       },
     );
-    prefix_test(
-      r#"
-      @-webkit-keyframes test1 {
-        from {
-          background: green;
-        }
-
-        to {
-          background: blue;
-        }
-      }
 
-      @-moz-keyframes test2 {
-        from {
-          background: green;
-        }
-
-        to {
-          background: blue;
-        }
-      }
-
-      @keyframes test3 {
-        from {
-          background: green;
-        }
-        to {
-          background: blue
-        }
+    prefix_test(
+      "a:is(.foo > .bar) {color:red}",
+      indoc! {r#"
+      a:is(.foo > .bar) {
+        color: red;
       }
-    "#,
-      indoc! { r#"
-      @-webkit-keyframes test1 {
-        from {
-          background: green;
-        }
+      "#},
+      Browsers {
+        safari: Some(11 << 16),
+        firefox: Some(50 << 16),
+        ..Browsers::default()
+      },
+    );
 
-        to {
-          background: #00f;
-        }
+    prefix_test(
+      "a:lang(en, fr) {color:red}",
+      indoc! {r#"
+      a:-webkit-any(:lang(en), :lang(fr)) {
+        color: red;
       }
 
-      @-moz-keyframes test2 {
-        from {
-          background: green;
-        }
-
-        to {
-          background: #00f;
-        }
+      a:-moz-any(:lang(en), :lang(fr)) {
+        color: red;
       }
 
-      @keyframes test3 {
-        from {
-          background: green;
-        }
-
-        to {
-          background: #00f;
-        }
+      a:is(:lang(en), :lang(fr)) {
+        color: red;
       }
-    "#},
+      "#},
       Browsers {
-        safari: Some(10 << 16),
-        firefox: Some(17 << 16),
+        safari: Some(11 << 16),
+        firefox: Some(50 << 16),
         ..Browsers::default()
       },
     );
-    prefix_test(
-      r#"
-      @-webkit-keyframes test {
-        from {
-          background: green;
-        }
 
-        to {
-          background: red;
-        }
+    prefix_test(
+      "a:lang(en, fr) {color:red}",
+      indoc! {r#"
+      a:is(:lang(en), :lang(fr)) {
+        color: red;
       }
-      @-moz-keyframes test {
-        from {
-          background: green;
-        }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        firefox: Some(88 << 16),
+        ..Browsers::default()
+      },
+    );
 
-        to {
-          background: pink;
-        }
-      }
-      @keyframes test {
-        from {
-          background: green;
-        }
-        to {
-          background: blue
-        }
+    prefix_test(
+      "a:lang(en, fr) {color:red}",
+      indoc! {r#"
+      a:lang(en, fr) {
+        color: red;
       }
-    "#,
-      indoc! { r#"
-      @-webkit-keyframes test {
-        from {
-          background: green;
-        }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
 
-        to {
-          background: red;
-        }
+    prefix_test(
+      "a:dir(rtl) {color:red}",
+      indoc! {r#"
+      a:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        color: red;
       }
 
-      @-moz-keyframes test {
-        from {
-          background: green;
-        }
-
-        to {
-          background: pink;
-        }
+      a:-moz-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        color: red;
       }
 
-      @keyframes test {
-        from {
-          background: green;
-        }
-
-        to {
-          background: #00f;
-        }
+      a:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        color: red;
       }
-    "#},
+      "#},
       Browsers {
-        safari: Some(10 << 16),
-        firefox: Some(17 << 16),
+        safari: Some(11 << 16),
+        firefox: Some(50 << 16),
         ..Browsers::default()
       },
     );
 
-    minify_test(
-      r#"
-      @keyframes test {
-        100% {
-          background: blue
-        }
+    prefix_test(
+      "a:dir(ltr) {color:red}",
+      indoc! {r#"
+      a:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        color: red;
       }
 
-      @keyframes test {
-        100% {
-          background: red
-        }
-      }
-    "#,
-      "@keyframes test{to{background:red}}",
-    );
-    minify_test(
-      r#"
-      @keyframes test {
-        100% {
-          background: blue
-        }
+      a:not(:-moz-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        color: red;
       }
 
-      @-webkit-keyframes test {
-        100% {
-          background: red
-        }
+      a:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        color: red;
       }
-    "#,
-      "@keyframes test{to{background:#00f}}@-webkit-keyframes test{to{background:red}}",
+      "#},
+      Browsers {
+        safari: Some(11 << 16),
+        firefox: Some(50 << 16),
+        ..Browsers::default()
+      },
     );
-  }
 
-  #[test]
-  fn test_important() {
-    test(
-      r#"
-      .foo {
-        align-items: center;
-        justify-items: center !important;
-      }
-    "#,
+    prefix_test(
+      "a:dir(rtl) {color:red}",
       indoc! {r#"
-      .foo {
-        align-items: center;
-        justify-items: center !important;
+      a:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        color: red;
       }
-    "#},
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        firefox: Some(88 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
-      r#"
-      .foo {
-        justify-items: center !important;
-        align-items: center;
-      }
-    "#,
+    prefix_test(
+      "a:dir(ltr) {color:red}",
       indoc! {r#"
-      .foo {
-        align-items: center;
-        justify-items: center !important;
+      a:not(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        color: red;
       }
-    "#},
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        firefox: Some(88 << 16),
+        ..Browsers::default()
+      },
     );
 
-    minify_test(
-      r#"
-      .foo {
-        font-family: SFMono-Regular, Menlo, Monaco, Consolas, "Liberation Mono", "Courier New", monospace !important;
+    prefix_test(
+      "a:dir(rtl) {color:red}",
+      indoc! {r#"
+      a:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
+        color: red;
       }
-    "#,
-      ".foo{font-family:SFMono-Regular,Menlo,Monaco,Consolas,Liberation Mono,Courier New,monospace!important}",
-    );
-  }
-
-  #[test]
-  fn test_calc() {
-    minify_test(".foo { width: calc(20px * 2) }", ".foo{width:40px}");
-    minify_test(".foo { font-size: calc(100vw / 35) }", ".foo{font-size:2.85714vw}");
-    minify_test(".foo { width: calc(20px * 2 * 3) }", ".foo{width:120px}");
-    minify_test(".foo { width: calc(20px + 30px) }", ".foo{width:50px}");
-    minify_test(".foo { width: calc(20px + 30px + 40px) }", ".foo{width:90px}");
-    minify_test(".foo { width: calc(100% - 30px) }", ".foo{width:calc(100% - 30px)}");
-    minify_test(
-      ".foo { width: calc(100% - 30px + 20px) }",
-      ".foo{width:calc(100% - 10px)}",
-    );
-    minify_test(
-      ".foo { width: calc(20px + 100% - 30px) }",
-      ".foo{width:calc(100% - 10px)}",
-    );
-    minify_test(
-      ".foo { width: calc(20px + 100% + 10vw - 30px) }",
-      ".foo{width:calc(100% - 10px + 10vw)}",
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { width: calc(20px + 100% - 30px) }",
-      ".foo{width:calc(100% - 10px)}",
+
+    prefix_test(
+      "a:dir(ltr) {color:red}",
+      indoc! {r#"
+      a:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { width: calc(2 * (100% - 20px)) }",
-      ".foo{width:calc(200% - 40px)}",
+
+    prefix_test(
+      "a:is(:dir(rtl)) {color:red}",
+      indoc! {r#"
+      a:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { width: calc((100% - 20px) * 2) }",
-      ".foo{width:calc(200% - 40px)}",
+
+    prefix_test(
+      "a:where(:dir(rtl)) {color:red}",
+      indoc! {r#"
+      a:where(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(".foo { width: calc(100% - 20px * 2) }", ".foo{width:calc(100% - 40px)}");
-    minify_test(".foo { width: calc(1px + 1px) }", ".foo{width:2px}");
-    minify_test(".foo { width: calc(100vw / 2) }", ".foo{width:50vw}");
-    minify_test(".foo { width: calc(50px - (20px - 30px)) }", ".foo{width:60px}");
-    minify_test(".foo { width: calc(100px - (100px - 100%)) }", ".foo{width:100%}");
-    minify_test(
-      ".foo { width: calc(100px + (100px - 100%)) }",
-      ".foo{width:calc(200px - 100%)}",
+
+    prefix_test(
+      "a:has(:dir(rtl)) {color:red}",
+      indoc! {r#"
+      a:has(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { width: calc(1px - (2em + 3%)) }",
-      ".foo{width:calc(1px + -2em - 3%)}",
-    ); // TODO: fix sign
-    minify_test(
-      ".foo { width: calc((100vw - 50em) / 2) }",
-      ".foo{width:calc(50vw - 25em)}",
+
+    prefix_test(
+      "a:not(:dir(rtl)) {color:red}",
+      indoc! {r#"
+      a:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { width: calc(1px - (2em + 4vh + 3%)) }",
-      ".foo{width:calc(1px + -2em - 4vh - 3%)}",
-    ); // TODO
-    minify_test(
-      ".foo { width: calc(1px + (2em + (3vh + 4px))) }",
-      ".foo{width:calc(2em + 3vh + 5px)}",
+
+    prefix_test(
+      "a:dir(rtl)::after {color:red}",
+      indoc! {r#"
+      a:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi):after {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { width: calc(1px - (2em + 4px - 6vh) / 2) }",
-      ".foo{width:calc(-1em - 1px + 3vh)}",
+
+    prefix_test(
+      "a:dir(rtl) div {color:red}",
+      indoc! {r#"
+      a:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) div {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
+
+    minify_test(".foo::cue {color: red}", ".foo::cue{color:red}");
+    minify_test(".foo::cue-region {color: red}", ".foo::cue-region{color:red}");
+    minify_test(".foo::cue(b) {color: red}", ".foo::cue(b){color:red}");
+    minify_test(".foo::cue-region(b) {color: red}", ".foo::cue-region(b){color:red}");
     minify_test(
-      ".foo { width: calc(100% - calc(50% + 25px)) }",
-      ".foo{width:calc(50% - 25px)}",
+      "::cue(v[voice='active']) {color: yellow;}",
+      "::cue(v[voice=active]){color:#ff0}",
     );
-    minify_test(".foo { width: calc(1px/100) }", ".foo{width:.01px}");
+    minify_test(":foo(bar) { color: yellow }", ":foo(bar){color:#ff0}");
+    minify_test("::foo(bar) { color: yellow }", "::foo(bar){color:#ff0}");
+    minify_test("::foo(*) { color: yellow }", "::foo(*){color:#ff0}");
+
+    minify_test(":is(.foo) { color: yellow }", ".foo{color:#ff0}");
+    minify_test(":is(#foo) { color: yellow }", "#foo{color:#ff0}");
+    minify_test("a:is(.foo) { color: yellow }", "a.foo{color:#ff0}");
+    minify_test("a:is([data-test]) { color: yellow }", "a[data-test]{color:#ff0}");
+    minify_test(".foo:is(a) { color: yellow }", ".foo:is(a){color:#ff0}");
+    minify_test(".foo:is(*|a) { color: yellow }", ".foo:is(*|a){color:#ff0}");
+    minify_test(".foo:is(*) { color: yellow }", ".foo:is(*){color:#ff0}");
     minify_test(
-      ".foo { width: calc(100vw / 2 - 6px + 0px) }",
-      ".foo{width:calc(50vw - 6px)}",
+      "@namespace svg url(http://www.w3.org/2000/svg); .foo:is(svg|a) { color: yellow }",
+      "@namespace svg \"http://www.w3.org/2000/svg\";.foo:is(svg|a){color:#ff0}",
     );
-    minify_test(".foo { width: calc(1px + 1) }", ".foo{width:calc(1px + 1)}");
+    minify_test("a:is(.foo .bar) { color: yellow }", "a:is(.foo .bar){color:#ff0}");
+    minify_test(":is(.foo, .bar) { color: yellow }", ":is(.foo,.bar){color:#ff0}");
+    minify_test("a:is(:not(.foo)) { color: yellow }", "a:not(.foo){color:#ff0}");
+    minify_test("a:is(:first-child) { color: yellow }", "a:first-child{color:#ff0}");
+    minify_test("a:is(:has(.foo)) { color: yellow }", "a:has(.foo){color:#ff0}");
+    minify_test("a:is(:is(.foo)) { color: yellow }", "a.foo{color:#ff0}");
+    minify_test(":host(:hover) {color: red}", ":host(:hover){color:red}");
+    minify_test("::slotted(:hover) {color: red}", "::slotted(:hover){color:red}");
+
     minify_test(
-      ".foo { width: calc( (1em - calc( 10px + 1em)) / 2) }",
-      ".foo{width:-5px}",
+      ":root::view-transition {position: fixed}",
+      ":root::view-transition{position:fixed}",
     );
+    for name in &[
+      "view-transition-group",
+      "view-transition-image-pair",
+      "view-transition-new",
+      "view-transition-old",
+    ] {
+      minify_test(
+        &format!(":root::{}(*) {{position: fixed}}", name),
+        &format!(":root::{}(*){{position:fixed}}", name),
+      );
+      minify_test(
+        &format!(":root::{}(foo) {{position: fixed}}", name),
+        &format!(":root::{}(foo){{position:fixed}}", name),
+      );
+      minify_test(
+        &format!(":root::{}(*.foo) {{position: fixed}}", name),
+        &format!(":root::{}(*.foo){{position:fixed}}", name),
+      );
+      minify_test(
+        &format!(":root::{}(foo.bar.baz) {{position: fixed}}", name),
+        &format!(":root::{}(foo.bar.baz){{position:fixed}}", name),
+      );
+      minify_test(
+        &format!(":root::{}(.foo) {{position: fixed}}", name),
+        &format!(":root::{}(.foo){{position:fixed}}", name),
+      );
+      minify_test(
+        &format!(":root::{}( foo ) {{position: fixed}}", name),
+        &format!(":root::{}(foo){{position:fixed}}", name),
+      );
+      minify_test(
+        &format!(":root::{}(foo):only-child {{position: fixed}}", name),
+        &format!(":root::{}(foo):only-child{{position:fixed}}", name),
+      );
+      error_test(
+        &format!(":root::{}(foo):first-child {{position: fixed}}", name),
+        ParserError::SelectorError(SelectorError::InvalidPseudoClassAfterPseudoElement),
+      );
+      error_test(
+        &format!(":root::{}(foo)::before {{position: fixed}}", name),
+        ParserError::SelectorError(SelectorError::InvalidState),
+      );
+    }
+
+    minify_test(".foo ::deep .bar {width: 20px}", ".foo ::deep .bar{width:20px}");
+    minify_test(".foo::deep .bar {width: 20px}", ".foo::deep .bar{width:20px}");
+    minify_test(".foo ::deep.bar {width: 20px}", ".foo ::deep.bar{width:20px}");
+    minify_test(".foo ::unknown .bar {width: 20px}", ".foo ::unknown .bar{width:20px}");
     minify_test(
-      ".foo { width: calc((100px - 1em) + (-50px + 1em)) }",
-      ".foo{width:50px}",
+      ".foo ::unknown(foo) .bar {width: 20px}",
+      ".foo ::unknown(foo) .bar{width:20px}",
     );
     minify_test(
-      ".foo { width: calc(100% + (2 * 100px) - ((75.37% - 63.5px) - 900px)) }",
-      ".foo{width:calc(24.63% + 1163.5px)}",
+      ".foo ::unknown:only-child {width: 20px}",
+      ".foo ::unknown:only-child{width:20px}",
     );
     minify_test(
-      ".foo { width: calc(((((100% + (2 * 30px) + 63.5px) / 0.7537) - (100vw - 60px)) / 2) + 30px) }",
-      ".foo{width:calc(66.3394% + 141.929px - 50vw)}",
+      ".foo ::unknown(.foo) .bar {width: 20px}",
+      ".foo ::unknown(.foo) .bar{width:20px}",
     );
     minify_test(
-      ".foo { width: calc(((75.37% - 63.5px) - 900px) + (2 * 100px)) }",
-      ".foo{width:calc(75.37% - 763.5px)}",
+      ".foo ::unknown(.foo .bar / .baz) .bar {width: 20px}",
+      ".foo ::unknown(.foo .bar / .baz) .bar{width:20px}",
     );
     minify_test(
-      ".foo { width: calc((900px - (10% - 63.5px)) + (2 * 100px)) }",
-      ".foo{width:calc(1163.5px - 10%)}",
+      ".foo ::unknown(something(foo)) .bar {width: 20px}",
+      ".foo ::unknown(something(foo)) .bar{width:20px}",
     );
-    minify_test(".foo { width: calc(500px/0) }", ".foo{width:calc(500px/0)}");
-    minify_test(".foo { width: calc(500px/2px) }", ".foo{width:calc(500px/2px)}");
-    minify_test(".foo { width: calc(100% / 3 * 3) }", ".foo{width:100%}");
-    minify_test(".foo { width: calc(+100px + +100px) }", ".foo{width:200px}");
-    minify_test(".foo { width: calc(+100px - +100px) }", ".foo{width:0}");
-    minify_test(".foo { width: calc(200px * +1) }", ".foo{width:200px}");
-    minify_test(".foo { width: calc(200px / +1) }", ".foo{width:200px}");
-    minify_test(".foo { width: calc(1.1e+1px + 1.1e+1px) }", ".foo{width:22px}");
-    minify_test(".foo { border-width: calc(1px + 2px) }", ".foo{border-width:3px}");
     minify_test(
-      ".foo { border-width: calc(1em + 2px + 2em + 3px) }",
-      ".foo{border-width:calc(3em + 5px)}",
+      ".foo ::unknown([abc]) .bar {width: 20px}",
+      ".foo ::unknown([abc]) .bar{width:20px}",
     );
 
-    minify_test(
-      ".foo { border-width: min(1em, 2px) }",
-      ".foo{border-width:min(1em,2px)}",
-    );
-    minify_test(
-      ".foo { border-width: min(1em + 2em, 2px + 2px) }",
-      ".foo{border-width:min(3em,4px)}",
-    );
-    minify_test(
-      ".foo { border-width: min(1em + 2px, 2px + 1em) }",
-      ".foo{border-width:min(1em + 2px,2px + 1em)}",
+    let deep_options = ParserOptions {
+      flags: ParserFlags::DEEP_SELECTOR_COMBINATOR,
+      ..ParserOptions::default()
+    };
+
+    error_test(
+      ".foo >>> .bar {width: 20px}",
+      ParserError::SelectorError(SelectorError::DanglingCombinator),
     );
-    minify_test(
-      ".foo { border-width: min(1em + 2px + 2px, 2px + 1em + 1px) }",
-      ".foo{border-width:min(1em + 4px,3px + 1em)}",
+    error_test(
+      ".foo /deep/ .bar {width: 20px}",
+      ParserError::SelectorError(SelectorError::DanglingCombinator),
     );
-    minify_test(
-      ".foo { border-width: min(2px + 1px, 3px + 4px) }",
-      ".foo{border-width:3px}",
+    minify_test_with_options(
+      ".foo >>> .bar {width: 20px}",
+      ".foo>>>.bar{width:20px}",
+      deep_options.clone(),
     );
-    minify_test(
-      ".foo { border-width: min(1px, 1em, 2px, 3in) }",
-      ".foo{border-width:min(1px,1em)}",
+    minify_test_with_options(
+      ".foo /deep/ .bar {width: 20px}",
+      ".foo /deep/ .bar{width:20px}",
+      deep_options.clone(),
     );
+  }
 
-    minify_test(
-      ".foo { border-width: max(1em, 2px) }",
-      ".foo{border-width:max(1em,2px)}",
-    );
-    minify_test(
-      ".foo { border-width: max(1em + 2em, 2px + 2px) }",
-      ".foo{border-width:max(3em,4px)}",
+  #[test]
+  fn test_custom_pseudo() {
+    use crate::selector::CustomPseudoSyntax;
+    use std::sync::{Arc, RwLock};
+
+    let options = ParserOptions {
+      custom_pseudo_classes: HashMap::from([
+        ("deep".into(), CustomPseudoSyntax::Selector),
+        ("state".into(), CustomPseudoSyntax::Tokens),
+        ("scoped".into(), CustomPseudoSyntax::None),
+      ]),
+      custom_pseudo_elements: HashMap::from([("v-deep".into(), CustomPseudoSyntax::SelectorList)]),
+      ..ParserOptions::default()
+    };
+
+    minify_test_with_options(
+      ".foo :deep(.bar  >  .baz) {width: 20px}",
+      ".foo :deep(.bar>.baz){width:20px}",
+      options.clone(),
     );
-    minify_test(
-      ".foo { border-width: max(1em + 2px, 2px + 1em) }",
-      ".foo{border-width:max(1em + 2px,2px + 1em)}",
+    minify_test_with_options(
+      ".foo :DEEP(.bar) {width: 20px}",
+      ".foo :DEEP(.bar){width:20px}",
+      options.clone(),
     );
-    minify_test(
-      ".foo { border-width: max(1em + 2px + 2px, 2px + 1em + 1px) }",
-      ".foo{border-width:max(1em + 4px,3px + 1em)}",
+    minify_test_with_options(
+      ".foo ::v-deep(.a, .b) {width: 20px}",
+      ".foo ::v-deep(.a,.b){width:20px}",
+      options.clone(),
     );
-    minify_test(
-      ".foo { border-width: max(2px + 1px, 3px + 4px) }",
-      ".foo{border-width:7px}",
+    minify_test_with_options(
+      ".foo ::v-deep .bar {width: 20px}",
+      ".foo ::v-deep .bar{width:20px}",
+      options.clone(),
     );
-    minify_test(
-      ".foo { border-width: max(1px, 1em, 2px, 3in) }",
-      ".foo{border-width:max(3in,1em)}",
+    minify_test_with_options(
+      ".foo:state(checked) {width: 20px}",
+      ".foo:state(checked){width:20px}",
+      options.clone(),
     );
+    minify_test_with_options(".foo:scoped {width: 20px}", ".foo:scoped{width:20px}", options.clone());
 
-    minify_test(".foo { border-width: clamp(1px, 2px, 3px) }", ".foo{border-width:2px}");
-    minify_test(".foo { border-width: clamp(1px, 10px, 3px) }", ".foo{border-width:3px}");
-    minify_test(".foo { border-width: clamp(5px, 2px, 10px) }", ".foo{border-width:5px}");
-    minify_test(
-      ".foo { border-width: clamp(100px, 2px, 10px) }",
-      ".foo{border-width:100px}",
-    );
-    minify_test(
-      ".foo { border-width: clamp(5px + 5px, 5px + 7px, 10px + 20px) }",
-      ".foo{border-width:12px}",
-    );
+    assert!(StyleSheet::parse(".foo :deep(20px) {width: 20px}", options.clone()).is_err());
+    assert!(StyleSheet::parse(".foo :scoped(.bar) {width: 20px}", options.clone()).is_err());
 
-    minify_test(
-      ".foo { border-width: clamp(1em, 2px, 4vh) }",
-      ".foo{border-width:clamp(1em,2px,4vh)}",
-    );
-    minify_test(
-      ".foo { border-width: clamp(1em, 2em, 4vh) }",
-      ".foo{border-width:min(2em,4vh)}",
-    );
-    minify_test(
-      ".foo { border-width: clamp(1em, 2vh, 4vh) }",
-      ".foo{border-width:max(1em,2vh)}",
-    );
-    minify_test(
-      ".foo { border-width: clamp(1px, 1px + 2em, 4px) }",
-      ".foo{border-width:clamp(1px,1px + 2em,4px)}",
+    let warnings = Arc::new(RwLock::new(Vec::new()));
+    StyleSheet::parse(
+      ".foo :deep(.bar), .foo::v-deep, .foo:state(open), .foo:unknown {width: 20px}",
+      ParserOptions {
+        warnings: Some(warnings.clone()),
+        ..options.clone()
+      },
+    )
+    .unwrap();
+    let warnings = warnings.read().unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+      warnings[0].kind,
+      ParserError::SelectorError(SelectorError::UnsupportedPseudoClassOrElement("unknown".into()))
     );
-    minify_test(".foo { border-width: clamp(1px, 2pt, 1in) }", ".foo{border-width:2pt}");
+  }
 
+  #[test]
+  fn test_keyframes() {
     minify_test(
-      ".foo { top: calc(-1 * clamp(1.75rem, 8vw, 4rem)) }",
-      ".foo{top:calc(-1*clamp(1.75rem,8vw,4rem))}",
-    );
-    minify_test(
-      ".foo { top: calc(-1 * min(1.75rem, 8vw, 4rem)) }",
-      ".foo{top:calc(-1*min(1.75rem,8vw))}",
-    );
-    minify_test(
-      ".foo { top: calc(-1 * max(1.75rem, 8vw, 4rem)) }",
-      ".foo{top:calc(-1*max(4rem,8vw))}",
+      r#"
+      @keyframes "test" {
+        100% {
+          background: blue
+        }
+      }
+    "#,
+      "@keyframes test{to{background:#00f}}",
     );
     minify_test(
-      ".foo { top: calc(clamp(1.75rem, 8vw, 4rem) * -1) }",
-      ".foo{top:calc(-1*clamp(1.75rem,8vw,4rem))}",
+      r#"
+      @keyframes test {
+        100% {
+          background: blue
+        }
+      }
+    "#,
+      "@keyframes test{to{background:#00f}}",
     );
+
+    // CSS-wide keywords and `none` cannot remove quotes.
     minify_test(
-      ".foo { top: calc(min(1.75rem, 8vw, 4rem) * -1) }",
-      ".foo{top:calc(-1*min(1.75rem,8vw))}",
+      r#"
+      @keyframes "revert" {
+        from {
+          background: green;
+        }
+      }
+    "#,
+      "@keyframes \"revert\"{0%{background:green}}",
     );
+
     minify_test(
-      ".foo { top: calc(max(1.75rem, 8vw, 4rem) * -1) }",
-      ".foo{top:calc(-1*max(4rem,8vw))}",
+      r#"
+      @keyframes "none" {
+        from {
+          background: green;
+        }
+      }
+    "#,
+      "@keyframes \"none\"{0%{background:green}}",
     );
-    minify_test(
-      ".foo { top: calc(clamp(1.75rem, 8vw, 4rem) / 2) }",
-      ".foo{top:calc(clamp(1.75rem,8vw,4rem)/2)}",
+
+    // CSS-wide keywords without quotes throws an error.
+    error_test(
+      r#"
+      @keyframes revert {}
+    "#,
+      ParserError::UnexpectedToken(Token::Ident("revert".into())),
     );
-    minify_test(
-      ".foo { top: calc(min(1.75rem, 8vw, 4rem) / 2) }",
-      ".foo{top:calc(min(1.75rem,8vw)/2)}",
+
+    error_test(
+      r#"
+      @keyframes revert-layer {}
+    "#,
+      ParserError::UnexpectedToken(Token::Ident("revert-layer".into())),
     );
-    minify_test(
-      ".foo { top: calc(max(1.75rem, 8vw, 4rem) / 2) }",
-      ".foo{top:calc(max(4rem,8vw)/2)}",
+
+    error_test(
+      r#"
+      @keyframes none {}
+    "#,
+      ParserError::UnexpectedToken(Token::Ident("none".into())),
     );
-    minify_test(
-      ".foo { top: calc(0.5 * clamp(1.75rem, 8vw, 4rem)) }",
-      ".foo{top:calc(clamp(1.75rem,8vw,4rem)/2)}",
+
+    error_test(
+      r#"
+      @keyframes NONE {}
+    "#,
+      ParserError::UnexpectedToken(Token::Ident("NONE".into())),
     );
+
     minify_test(
-      ".foo { top: calc(1 * clamp(1.75rem, 8vw, 4rem)) }",
-      ".foo{top:calc(clamp(1.75rem,8vw,4rem))}",
+      r#"
+      @-webkit-keyframes test {
+        from {
+          background: green;
+          background-color: red;
+        }
+
+        100% {
+          background: blue
+        }
+      }
+    "#,
+      "@-webkit-keyframes test{0%{background:red}to{background:#00f}}",
     );
     minify_test(
-      ".foo { top: calc(2 * clamp(1.75rem, 8vw, 4rem) / 2) }",
-      ".foo{top:calc(clamp(1.75rem,8vw,4rem))}",
+      r#"
+      @-moz-keyframes test {
+        from {
+          background: green;
+          background-color: red;
+        }
+
+        100% {
+          background: blue
+        }
+      }
+    "#,
+      "@-moz-keyframes test{0%{background:red}to{background:#00f}}",
     );
+    minify_test(r#"
+      @-webkit-keyframes test {
+        from {
+          background: green;
+          background-color: red;
+        }
 
-    minify_test(".foo { width: max(0px, 1vw) }", ".foo{width:max(0px,1vw)}");
+        100% {
+          background: blue
+        }
+      }
+      @-moz-keyframes test {
+        from {
+          background: green;
+          background-color: red;
+        }
 
-    prefix_test(
-      ".foo { border-width: clamp(1em, 2px, 4vh) }",
-      indoc! { r#"
-        .foo {
-          border-width: max(1em, min(2px, 4vh));
+        100% {
+          background: blue
         }
-      "#},
-      Browsers {
-        safari: Some(12 << 16),
-        ..Browsers::default()
-      },
-    );
+      }
+    "#, "@-webkit-keyframes test{0%{background:red}to{background:#00f}}@-moz-keyframes test{0%{background:red}to{background:#00f}}");
 
     prefix_test(
-      ".foo { border-width: clamp(1em, 2px, 4vh) }",
-      indoc! { r#"
-        .foo {
-          border-width: clamp(1em, 2px, 4vh);
+      r#"
+      @keyframes test {
+        from {
+          background: green;
+        }
+        to {
+          background: blue
+        }
+      }
+    "#,
+      indoc! { r#"
+      @-webkit-keyframes test {
+        from {
+          background: green;
         }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    minify_test(".foo { width: calc(1vh + 2vh) }", ".foo{width:3vh}");
-    minify_test(".foo { width: calc(1dvh + 2dvh) }", ".foo{width:3dvh}");
-    minify_test(".foo { width: calc(1lvh + 2lvh) }", ".foo{width:3lvh}");
-    minify_test(".foo { width: calc(1svh + 2svh) }", ".foo{width:3svh}");
-    minify_test(".foo { width: calc(1sVmin + 2Svmin) }", ".foo{width:3svmin}");
-    minify_test(".foo { width: calc(1ic + 2ic) }", ".foo{width:3ic}");
-    minify_test(".foo { width: calc(1ric + 2ric) }", ".foo{width:3ric}");
-    minify_test(".foo { width: calc(1cap + 2cap) }", ".foo{width:3cap}");
-    minify_test(".foo { width: calc(1lh + 2lh) }", ".foo{width:3lh}");
-    minify_test(".foo { width: calc(1x + 2x) }", ".foo{width:calc(1x + 2x)}");
-    minify_test(
-      ".foo { left: calc(50% - 100px + clamp(0px, calc(50vw - 50px), 100px)) }",
-      ".foo{left:calc(50% - 100px + clamp(0px,50vw - 50px,100px))}",
-    );
-    minify_test(
-      ".foo { left: calc(10px + min(10px, 1rem) + max(2px, 1vw)) }",
-      ".foo{left:calc(10px + min(10px,1rem) + max(2px,1vw))}",
-    );
-    minify_test(".foo { width: round(22px, 5px) }", ".foo{width:20px}");
-    minify_test(".foo { width: round(nearest, 22px, 5px) }", ".foo{width:20px}");
-    minify_test(".foo { width: round(down, 22px, 5px) }", ".foo{width:20px}");
-    minify_test(".foo { width: round(to-zero, 22px, 5px) }", ".foo{width:20px}");
-    minify_test(".foo { width: round(up, 22px, 5px) }", ".foo{width:25px}");
-    minify_test(".foo { width: round(23px, 5px) }", ".foo{width:25px}");
-    minify_test(".foo { width: round(nearest, 23px, 5px) }", ".foo{width:25px}");
-    minify_test(".foo { width: round(down, 23px, 5px) }", ".foo{width:20px}");
-    minify_test(".foo { width: round(to-zero, 23px, 5px) }", ".foo{width:20px}");
-    minify_test(".foo { width: round(up, 23px, 5px) }", ".foo{width:25px}");
-    minify_test(".foo { width: round(22px, 5vw) }", ".foo{width:round(22px,5vw)}");
-    minify_test(".foo { rotate: round(22deg, 5deg) }", ".foo{rotate:20deg}");
-    minify_test(".foo { rotate: round(22deg, 5deg) }", ".foo{rotate:20deg}");
-    minify_test(
-      ".foo { transition-duration: round(22ms, 5ms) }",
-      ".foo{transition-duration:20ms}",
-    );
-    minify_test(".foo { margin: round(to-zero, -23px, 5px) }", ".foo{margin:-20px}");
-    minify_test(".foo { margin: round(nearest, -23px, 5px) }", ".foo{margin:-25px}");
-    minify_test(".foo { margin: calc(10px * round(22, 5)) }", ".foo{margin:200px}");
-    minify_test(".foo { width: rem(18px, 5px) }", ".foo{width:3px}");
-    minify_test(".foo { width: rem(-18px, 5px) }", ".foo{width:-3px}");
-    minify_test(".foo { width: rem(18px, 5vw) }", ".foo{width:rem(18px,5vw)}");
-    minify_test(".foo { rotate: rem(-140deg, -90deg) }", ".foo{rotate:-50deg}");
-    minify_test(".foo { rotate: rem(140deg, -90deg) }", ".foo{rotate:50deg}");
-    minify_test(".foo { width: calc(10px * rem(18, 5)) }", ".foo{width:30px}");
-    minify_test(".foo { width: mod(18px, 5px) }", ".foo{width:3px}");
-    minify_test(".foo { width: mod(-18px, 5px) }", ".foo{width:2px}");
-    minify_test(".foo { rotate: mod(-140deg, -90deg) }", ".foo{rotate:-50deg}");
-    minify_test(".foo { rotate: mod(140deg, -90deg) }", ".foo{rotate:-40deg}");
-    minify_test(".foo { width: mod(18px, 5vw) }", ".foo{width:mod(18px,5vw)}");
-    minify_test(
-      ".foo { transform: rotateX(mod(140deg, -90deg)) rotateY(rem(140deg, -90deg)) }",
-      ".foo{transform:rotateX(-40deg)rotateY(50deg)}",
-    );
-    minify_test(".foo { width: calc(10px * mod(18, 5)) }", ".foo{width:30px}");
-  }
-
-  #[test]
-  fn test_trig() {
-    minify_test(".foo { width: calc(2px * pi); }", ".foo{width:6.28319px}");
-    minify_test(".foo { width: calc(2px / pi); }", ".foo{width:.63662px}");
-    // minify_test(
-    //   ".foo { width: calc(2px * infinity); }",
-    //   ".foo{width:calc(2px*infinity)}",
-    // );
-    // minify_test(
-    //   ".foo { width: calc(2px * -infinity); }",
-    //   ".foo{width:calc(2px*-infinity)}",
-    // );
-    minify_test(".foo { width: calc(100px * sin(45deg))", ".foo{width:70.7107px}");
-    minify_test(".foo { width: calc(100px * sin(.125turn))", ".foo{width:70.7107px}");
-    minify_test(
-      ".foo { width: calc(100px * sin(3.14159265 / 4))",
-      ".foo{width:70.7107px}",
-    );
-    minify_test(".foo { width: calc(100px * sin(pi / 4))", ".foo{width:70.7107px}");
-    minify_test(
-      ".foo { width: calc(100px * sin(22deg + 23deg))",
-      ".foo{width:70.7107px}",
-    );
-
-    minify_test(".foo { width: calc(2px * cos(45deg))", ".foo{width:1.41421px}");
-    minify_test(".foo { width: calc(2px * tan(45deg))", ".foo{width:2px}");
-
-    minify_test(".foo { rotate: asin(sin(45deg))", ".foo{rotate:45deg}");
-    minify_test(".foo { rotate: asin(1)", ".foo{rotate:90deg}");
-    minify_test(".foo { rotate: asin(-1)", ".foo{rotate:-90deg}");
-    minify_test(".foo { rotate: asin(0.5)", ".foo{rotate:30deg}");
-    minify_test(".foo { rotate: asin(45deg)", ".foo{rotate:asin(45deg)}"); // invalid
-    minify_test(".foo { rotate: asin(-20)", ".foo{rotate:asin(-20)}"); // evaluates to NaN
-    minify_test(".foo { width: asin(sin(45deg))", ".foo{width:asin(sin(45deg))}"); // invalid
-
-    minify_test(".foo { rotate: acos(cos(45deg))", ".foo{rotate:45deg}");
-    minify_test(".foo { rotate: acos(-1)", ".foo{rotate:180deg}");
-    minify_test(".foo { rotate: acos(0)", ".foo{rotate:90deg}");
-    minify_test(".foo { rotate: acos(1)", ".foo{rotate:none}");
-    minify_test(".foo { rotate: acos(45deg)", ".foo{rotate:acos(45deg)}"); // invalid
-    minify_test(".foo { rotate: acos(-20)", ".foo{rotate:acos(-20)}"); // evaluates to NaN
-
-    minify_test(".foo { rotate: atan(tan(45deg))", ".foo{rotate:45deg}");
-    minify_test(".foo { rotate: atan(1)", ".foo{rotate:45deg}");
-    minify_test(".foo { rotate: atan(0)", ".foo{rotate:none}");
-    minify_test(".foo { rotate: atan(45deg)", ".foo{rotate:atan(45deg)}"); // invalid
-
-    minify_test(".foo { rotate: atan2(1px, -1px)", ".foo{rotate:135deg}");
-    minify_test(".foo { rotate: atan2(1vw, -1vw)", ".foo{rotate:135deg}");
-    minify_test(".foo { rotate: atan2(1, -1)", ".foo{rotate:135deg}");
-    minify_test(".foo { rotate: atan2(1ms, -1ms)", ".foo{rotate:135deg}");
-    minify_test(".foo { rotate: atan2(1%, -1%)", ".foo{rotate:135deg}");
-    minify_test(".foo { rotate: atan2(1deg, -1deg)", ".foo{rotate:135deg}");
-    minify_test(".foo { rotate: atan2(1cm, 1mm)", ".foo{rotate:84.2894deg}");
-    minify_test(".foo { rotate: atan2(0, -1)", ".foo{rotate:180deg}");
-    minify_test(".foo { rotate: atan2(-1, 1)", ".foo{rotate:-45deg}");
-    // incompatible units
-    minify_test(".foo { rotate: atan2(1px, -1vw)", ".foo{rotate:atan2(1px,-1vw)}");
-  }
-
-  #[test]
-  fn test_exp() {
-    minify_test(".foo { width: hypot()", ".foo{width:hypot()}");
-    minify_test(".foo { width: hypot(1px)", ".foo{width:1px}");
-    minify_test(".foo { width: hypot(1px, 2px)", ".foo{width:2.23607px}");
-    minify_test(".foo { width: hypot(1px, 2px, 3px)", ".foo{width:3.74166px}");
-    minify_test(".foo { width: hypot(1px, 2vw)", ".foo{width:hypot(1px,2vw)}");
-    minify_test(".foo { width: hypot(1px, 2px, 3vw)", ".foo{width:hypot(1px,2px,3vw)}");
-    minify_test(".foo { width: calc(100px * hypot(3, 4))", ".foo{width:500px}");
-    minify_test(".foo { width: calc(1px * pow(2, sqrt(100))", ".foo{width:1024px}");
-    minify_test(".foo { width: calc(100px * pow(2, pow(2, 2)", ".foo{width:1600px}");
-    minify_test(".foo { width: calc(1px * log(1))", ".foo{width:0}");
-    minify_test(".foo { width: calc(1px * log(10, 10))", ".foo{width:1px}");
-    minify_test(".foo { width: calc(1px * exp(0))", ".foo{width:1px}");
-    minify_test(".foo { width: calc(1px * log(e))", ".foo{width:1px}");
-    minify_test(".foo { width: calc(1px * (e - exp(1)))", ".foo{width:0}");
-    minify_test(
-      ".foo { width: calc(1px * (exp(log(1) + exp(0)*2))",
-      ".foo{width:7.38906px}",
-    );
-  }
 
-  #[test]
-  fn test_sign() {
-    minify_test(".foo { width: abs(1px)", ".foo{width:1px}");
-    minify_test(".foo { width: abs(-1px)", ".foo{width:1px}");
-    minify_test(".foo { width: abs(1%)", ".foo{width:abs(1%)}"); // spec says percentages must be against resolved value
+        to {
+          background: #00f;
+        }
+      }
 
-    minify_test(".foo { width: calc(10px * sign(-1vw)", ".foo{width:-10px}");
-    minify_test(".foo { width: calc(10px * sign(1%)", ".foo{width:calc(10px*sign(1%))}");
-  }
+      @-moz-keyframes test {
+        from {
+          background: green;
+        }
 
-  #[test]
-  fn test_box_shadow() {
-    minify_test(
-      ".foo { box-shadow: 64px 64px 12px 40px rgba(0,0,0,0.4) }",
-      ".foo{box-shadow:64px 64px 12px 40px #0006}",
-    );
-    minify_test(
-      ".foo { box-shadow: 12px 12px 0px 8px rgba(0,0,0,0.4) inset }",
-      ".foo{box-shadow:inset 12px 12px 0 8px #0006}",
-    );
-    minify_test(
-      ".foo { box-shadow: inset 12px 12px 0px 8px rgba(0,0,0,0.4) }",
-      ".foo{box-shadow:inset 12px 12px 0 8px #0006}",
-    );
-    minify_test(
-      ".foo { box-shadow: 12px 12px 8px 0px rgba(0,0,0,0.4) }",
-      ".foo{box-shadow:12px 12px 8px #0006}",
-    );
-    minify_test(
-      ".foo { box-shadow: 12px 12px 0px 0px rgba(0,0,0,0.4) }",
-      ".foo{box-shadow:12px 12px #0006}",
-    );
-    minify_test(
-      ".foo { box-shadow: 64px 64px 12px 40px rgba(0,0,0,0.4), 12px 12px 0px 8px rgba(0,0,0,0.4) inset }",
-      ".foo{box-shadow:64px 64px 12px 40px #0006,inset 12px 12px 0 8px #0006}",
-    );
+        to {
+          background: #00f;
+        }
+      }
 
-    prefix_test(
-      ".foo { box-shadow: 12px 12px lab(40% 56.6 39) }",
-      indoc! { r#"
-        .foo {
-          box-shadow: 12px 12px #b32323;
-          box-shadow: 12px 12px lab(40% 56.6 39);
+      @keyframes test {
+        from {
+          background: green;
         }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
-    );
 
-    prefix_test(
-      ".foo { box-shadow: 12px 12px lab(40% 56.6 39) }",
-      indoc! { r#"
-        .foo {
-          -webkit-box-shadow: 12px 12px #b32323;
-          box-shadow: 12px 12px #b32323;
-          box-shadow: 12px 12px lab(40% 56.6 39);
+        to {
+          background: #00f;
         }
-      "#},
+      }
+    "#},
       Browsers {
-        chrome: Some(4 << 16),
+        safari: Some(5 << 16),
+        firefox: Some(6 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
-      ".foo { box-shadow: 12px 12px lab(40% 56.6 39), 12px 12px yellow }",
-      indoc! { r#"
-        .foo {
-          -webkit-box-shadow: 12px 12px #b32323, 12px 12px #ff0;
-          box-shadow: 12px 12px #b32323, 12px 12px #ff0;
-          box-shadow: 12px 12px lab(40% 56.6 39), 12px 12px #ff0;
+      r#"
+      @-webkit-keyframes test {
+        from {
+          background: green;
         }
-      "#},
-      Browsers {
-        chrome: Some(4 << 16),
-        ..Browsers::default()
-      },
-    );
 
-    prefix_test(
-      ".foo { -webkit-box-shadow: 12px 12px #0006 }",
-      indoc! { r#"
-        .foo {
-          -webkit-box-shadow: 12px 12px rgba(0, 0, 0, .4);
+        to {
+          background: blue;
+        }
+      }
+      @-moz-keyframes test {
+        from {
+          background: green;
         }
-      "#},
-      Browsers {
-        chrome: Some(4 << 16),
-        ..Browsers::default()
-      },
-    );
 
-    prefix_test(
-      ".foo {
-        -webkit-box-shadow: 12px 12px #0006;
-        -moz-box-shadow: 12px 12px #0009;
-      }",
+        to {
+          background: blue;
+        }
+      }
+      @keyframes test {
+        from {
+          background: green;
+        }
+        to {
+          background: blue
+        }
+      }
+    "#,
       indoc! { r#"
-        .foo {
-          -webkit-box-shadow: 12px 12px rgba(0, 0, 0, .4);
-          -moz-box-shadow: 12px 12px rgba(0, 0, 0, .6);
+      @keyframes test {
+        from {
+          background: green;
         }
-      "#},
+
+        to {
+          background: #00f;
+        }
+      }
+    "#},
       Browsers {
-        chrome: Some(4 << 16),
+        safari: Some(10 << 16),
+        firefox: Some(17 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
-      ".foo {
-        -webkit-box-shadow: 12px 12px #0006;
-        -moz-box-shadow: 12px 12px #0006;
-        box-shadow: 12px 12px #0006;
-      }",
+      r#"
+      @-webkit-keyframes test1 {
+        from {
+          background: green;
+        }
+
+        to {
+          background: blue;
+        }
+      }
+
+      @-moz-keyframes test2 {
+        from {
+          background: green;
+        }
+
+        to {
+          background: blue;
+        }
+      }
+
+      @keyframes test3 {
+        from {
+          background: green;
+        }
+        to {
+          background: blue
+        }
+      }
+    "#,
       indoc! { r#"
-        .foo {
-          box-shadow: 12px 12px #0006;
+      @-webkit-keyframes test1 {
+        from {
+          background: green;
         }
-      "#},
+
+        to {
+          background: #00f;
+        }
+      }
+
+      @-moz-keyframes test2 {
+        from {
+          background: green;
+        }
+
+        to {
+          background: #00f;
+        }
+      }
+
+      @keyframes test3 {
+        from {
+          background: green;
+        }
+
+        to {
+          background: #00f;
+        }
+      }
+    "#},
       Browsers {
-        chrome: Some(95 << 16),
+        safari: Some(10 << 16),
+        firefox: Some(17 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
-      ".foo { box-shadow: var(--foo) 12px lab(40% 56.6 39) }",
+      r#"
+      @-webkit-keyframes test {
+        from {
+          background: green;
+        }
+
+        to {
+          background: red;
+        }
+      }
+      @-moz-keyframes test {
+        from {
+          background: green;
+        }
+
+        to {
+          background: pink;
+        }
+      }
+      @keyframes test {
+        from {
+          background: green;
+        }
+        to {
+          background: blue
+        }
+      }
+    "#,
       indoc! { r#"
-        .foo {
-          box-shadow: var(--foo) 12px #b32323;
+      @-webkit-keyframes test {
+        from {
+          background: green;
         }
 
-        @supports (color: lab(0% 0 0)) {
-          .foo {
-            box-shadow: var(--foo) 12px lab(40% 56.6 39);
-          }
+        to {
+          background: red;
         }
-      "#},
+      }
+
+      @-moz-keyframes test {
+        from {
+          background: green;
+        }
+
+        to {
+          background: pink;
+        }
+      }
+
+      @keyframes test {
+        from {
+          background: green;
+        }
+
+        to {
+          background: #00f;
+        }
+      }
+    "#},
       Browsers {
-        chrome: Some(90 << 16),
+        safari: Some(10 << 16),
+        firefox: Some(17 << 16),
         ..Browsers::default()
       },
     );
 
-    prefix_test(
+    minify_test(
       r#"
-      .foo {
-        box-shadow: 0px 0px 22px red;
-        box-shadow: 0px 0px max(2cqw, 22px) red;
+      @keyframes test {
+        100% {
+          background: blue
+        }
+      }
+
+      @keyframes test {
+        100% {
+          background: red
+        }
       }
     "#,
-      indoc! {r#"
-      .foo {
-        box-shadow: 0 0 22px red;
-        box-shadow: 0 0 max(2cqw, 22px) red;
+      "@keyframes test{to{background:red}}",
+    );
+    minify_test(
+      r#"
+      @keyframes test {
+        100% {
+          background: blue
+        }
       }
-    "#
-      },
-      Browsers {
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
+
+      @-webkit-keyframes test {
+        100% {
+          background: red
+        }
+      }
+    "#,
+      "@keyframes test{to{background:#00f}}@-webkit-keyframes test{to{background:red}}",
     );
-    prefix_test(
+  }
+
+  #[test]
+  fn test_important() {
+    test(
       r#"
       .foo {
-        box-shadow: 0px 0px 22px red;
-        box-shadow: 0px 0px max(2cqw, 22px) red;
+        align-items: center;
+        justify-items: center !important;
       }
     "#,
       indoc! {r#"
       .foo {
-        box-shadow: 0 0 max(2cqw, 22px) red;
+        align-items: center;
+        justify-items: center !important;
       }
-    "#
-      },
-      Browsers {
-        safari: Some(16 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
 
-    prefix_test(
+    test(
       r#"
       .foo {
-        box-shadow: 0px 0px 22px red;
-        box-shadow: 0px 0px 22px lab(40% 56.6 39);
+        justify-items: center !important;
+        align-items: center;
       }
     "#,
       indoc! {r#"
       .foo {
-        box-shadow: 0 0 22px red;
-        box-shadow: 0 0 22px lab(40% 56.6 39);
-      }
-    "#
-      },
-      Browsers {
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
-    );
-    prefix_test(
-      r#"
-      .foo {
-        box-shadow: 0px 0px 22px red;
-        box-shadow: 0px 0px 22px lab(40% 56.6 39);
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        box-shadow: 0 0 22px lab(40% 56.6 39);
+        align-items: center;
+        justify-items: center !important;
       }
-    "#
-      },
-      Browsers {
-        safari: Some(16 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
 
-    prefix_test(
+    minify_test(
       r#"
       .foo {
-        box-shadow: var(--fallback);
-        box-shadow: 0px 0px 22px lab(40% 56.6 39);
+        font-family: SFMono-Regular, Menlo, Monaco, Consolas, "Liberation Mono", "Courier New", monospace !important;
       }
     "#,
-      indoc! {r#"
-      .foo {
-        box-shadow: var(--fallback);
-        box-shadow: 0 0 22px lab(40% 56.6 39);
-      }
-    "#
-      },
-      Browsers {
-        safari: Some(16 << 16),
-        ..Browsers::default()
-      },
+      ".foo{font-family:SFMono-Regular,Menlo,Monaco,Consolas,Liberation Mono,Courier New,monospace!important}",
     );
   }
 
   #[test]
-  fn test_media() {
+  fn test_calc() {
+    minify_test(".foo { width: calc(20px * 2) }", ".foo{width:40px}");
+    minify_test(".foo { font-size: calc(100vw / 35) }", ".foo{font-size:2.85714vw}");
+    minify_test(".foo { width: calc(20px * 2 * 3) }", ".foo{width:120px}");
+    minify_test(".foo { width: calc(20px + 30px) }", ".foo{width:50px}");
+    minify_test(".foo { width: calc(20px + 30px + 40px) }", ".foo{width:90px}");
+    minify_test(".foo { width: calc(100% - 30px) }", ".foo{width:calc(100% - 30px)}");
+    minify_test(".foo { width: calc(10px + 1) }", ".foo{width:calc(10px + 1)}");
     minify_test(
-      "@media (min-width: 240px) { .foo { color: chartreuse }}",
-      "@media (width>=240px){.foo{color:#7fff00}}",
+      ".foo { font-stretch: calc(50% + 1) }",
+      ".foo{font-stretch:calc(50% + 1)}",
     );
     minify_test(
-      "@media (width < 240px) { .foo { color: chartreuse }}",
-      "@media (width<240px){.foo{color:#7fff00}}",
+      ".foo { width: calc(100% - 30px + 20px) }",
+      ".foo{width:calc(100% - 10px)}",
     );
     minify_test(
-      "@media (width <= 240px) { .foo { color: chartreuse }}",
-      "@media (width<=240px){.foo{color:#7fff00}}",
+      ".foo { width: calc(20px + 100% - 30px) }",
+      ".foo{width:calc(100% - 10px)}",
     );
     minify_test(
-      "@media (width > 240px) { .foo { color: chartreuse }}",
-      "@media (width>240px){.foo{color:#7fff00}}",
+      ".foo { width: calc(20px + 100% + 10vw - 30px) }",
+      ".foo{width:calc(100% - 10px + 10vw)}",
     );
     minify_test(
-      "@media (width >= 240px) { .foo { color: chartreuse }}",
-      "@media (width>=240px){.foo{color:#7fff00}}",
+      ".foo { width: calc(20px + 100% - 30px) }",
+      ".foo{width:calc(100% - 10px)}",
     );
     minify_test(
-      "@media (240px < width) { .foo { color: chartreuse }}",
-      "@media (width>240px){.foo{color:#7fff00}}",
+      ".foo { width: calc(2 * (100% - 20px)) }",
+      ".foo{width:calc(200% - 40px)}",
     );
     minify_test(
-      "@media (240px <= width) { .foo { color: chartreuse }}",
-      "@media (width>=240px){.foo{color:#7fff00}}",
+      ".foo { width: calc((100% - 20px) * 2) }",
+      ".foo{width:calc(200% - 40px)}",
     );
+    minify_test(".foo { width: calc(100% - 20px * 2) }", ".foo{width:calc(100% - 40px)}");
+    minify_test(".foo { width: calc(1px + 1px) }", ".foo{width:2px}");
+    minify_test(".foo { width: calc(100vw / 2) }", ".foo{width:50vw}");
+    minify_test(".foo { width: calc(50px - (20px - 30px)) }", ".foo{width:60px}");
+    minify_test(".foo { width: calc(100px - (100px - 100%)) }", ".foo{width:100%}");
     minify_test(
-      "@media (240px > width) { .foo { color: chartreuse }}",
-      "@media (width<240px){.foo{color:#7fff00}}",
+      ".foo { width: calc(100px + (100px - 100%)) }",
+      ".foo{width:calc(200px - 100%)}",
     );
     minify_test(
-      "@media (240px >= width) { .foo { color: chartreuse }}",
-      "@media (width<=240px){.foo{color:#7fff00}}",
+      ".foo { width: calc(1px - (2em + 3%)) }",
+      ".foo{width:calc(1px + -2em - 3%)}",
+    ); // TODO: fix sign
+    minify_test(
+      ".foo { width: calc((100vw - 50em) / 2) }",
+      ".foo{width:calc(50vw - 25em)}",
     );
     minify_test(
-      "@media (100px < width < 200px) { .foo { color: chartreuse }}",
-      "@media (100px<width<200px){.foo{color:#7fff00}}",
+      ".foo { width: calc(1px - (2em + 4vh + 3%)) }",
+      ".foo{width:calc(1px + -2em - 4vh - 3%)}",
+    ); // TODO
+    minify_test(
+      ".foo { width: calc(1px + (2em + (3vh + 4px))) }",
+      ".foo{width:calc(2em + 3vh + 5px)}",
     );
     minify_test(
-      "@media (100px <= width <= 200px) { .foo { color: chartreuse }}",
-      "@media (100px<=width<=200px){.foo{color:#7fff00}}",
+      ".foo { width: calc(1px - (2em + 4px - 6vh) / 2) }",
+      ".foo{width:calc(-1em - 1px + 3vh)}",
     );
     minify_test(
-      "@media (min-width: 30em) and (max-width: 50em) { .foo { color: chartreuse }}",
-      "@media (width>=30em) and (width<=50em){.foo{color:#7fff00}}",
+      ".foo { width: calc(100% - calc(50% + 25px)) }",
+      ".foo{width:calc(50% - 25px)}",
     );
+    minify_test(".foo { width: calc(1px/100) }", ".foo{width:.01px}");
     minify_test(
-      "@media screen, print { .foo { color: chartreuse }}",
-      "@media screen,print{.foo{color:#7fff00}}",
+      ".foo { width: calc(100vw / 2 - 6px + 0px) }",
+      ".foo{width:calc(50vw - 6px)}",
     );
+    minify_test(".foo { width: calc(1px + 1) }", ".foo{width:calc(1px + 1)}");
     minify_test(
-      "@media (hover: hover) { .foo { color: chartreuse }}",
-      "@media (hover:hover){.foo{color:#7fff00}}",
+      ".foo { width: calc( (1em - calc( 10px + 1em)) / 2) }",
+      ".foo{width:-5px}",
     );
     minify_test(
-      "@media (hover) { .foo { color: chartreuse }}",
-      "@media (hover){.foo{color:#7fff00}}",
+      ".foo { width: calc((100px - 1em) + (-50px + 1em)) }",
+      ".foo{width:50px}",
     );
     minify_test(
-      "@media (aspect-ratio: 11/5) { .foo { color: chartreuse }}",
-      "@media (aspect-ratio:11/5){.foo{color:#7fff00}}",
+      ".foo { width: calc(100% + (2 * 100px) - ((75.37% - 63.5px) - 900px)) }",
+      ".foo{width:calc(24.63% + 1163.5px)}",
     );
     minify_test(
-      "@media (aspect-ratio: 2/1) { .foo { color: chartreuse }}",
-      "@media (aspect-ratio:2){.foo{color:#7fff00}}",
+      ".foo { width: calc(((((100% + (2 * 30px) + 63.5px) / 0.7537) - (100vw - 60px)) / 2) + 30px) }",
+      ".foo{width:calc(66.3394% + 141.929px - 50vw)}",
     );
     minify_test(
-      "@media (aspect-ratio: 2) { .foo { color: chartreuse }}",
-      "@media (aspect-ratio:2){.foo{color:#7fff00}}",
+      ".foo { width: calc(((75.37% - 63.5px) - 900px) + (2 * 100px)) }",
+      ".foo{width:calc(75.37% - 763.5px)}",
     );
     minify_test(
-      "@media not screen and (color) { .foo { color: chartreuse }}",
-      "@media not screen and (color){.foo{color:#7fff00}}",
+      ".foo { width: calc((900px - (10% - 63.5px)) + (2 * 100px)) }",
+      ".foo{width:calc(1163.5px - 10%)}",
     );
+    minify_test(".foo { width: calc(500px/0) }", ".foo{width:calc(500px/0)}");
+    minify_test(".foo { width: calc(500px/2px) }", ".foo{width:calc(500px/2px)}");
+    minify_test(".foo { width: calc(100% / 3 * 3) }", ".foo{width:100%}");
+    minify_test(".foo { width: calc(+100px + +100px) }", ".foo{width:200px}");
+    minify_test(".foo { width: calc(+100px - +100px) }", ".foo{width:0}");
+    minify_test(".foo { width: calc(200px * +1) }", ".foo{width:200px}");
+    minify_test(".foo { width: calc(200px / +1) }", ".foo{width:200px}");
+    minify_test(".foo { width: calc(1.1e+1px + 1.1e+1px) }", ".foo{width:22px}");
+    minify_test(".foo { border-width: calc(1px + 2px) }", ".foo{border-width:3px}");
     minify_test(
-      "@media only screen and (color) { .foo { color: chartreuse }}",
-      "@media only screen and (color){.foo{color:#7fff00}}",
+      ".foo { border-width: calc(1em + 2px + 2em + 3px) }",
+      ".foo{border-width:calc(3em + 5px)}",
     );
+
     minify_test(
-      "@media (update: slow) or (hover: none) { .foo { color: chartreuse }}",
-      "@media (update:slow) or (hover:none){.foo{color:#7fff00}}",
+      ".foo { border-width: min(1em, 2px) }",
+      ".foo{border-width:min(1em,2px)}",
     );
     minify_test(
-      "@media (width < 600px) and (height < 600px) { .foo { color: chartreuse }}",
-      "@media (width<600px) and (height<600px){.foo{color:#7fff00}}",
+      ".foo { border-width: min(1em + 2em, 2px + 2px) }",
+      ".foo{border-width:min(3em,4px)}",
     );
     minify_test(
-      "@media (not (color)) or (hover) { .foo { color: chartreuse }}",
-      "@media (not (color)) or (hover){.foo{color:#7fff00}}",
+      ".foo { border-width: min(1em + 2px, 2px + 1em) }",
+      ".foo{border-width:min(1em + 2px,2px + 1em)}",
     );
-    error_test(
-      "@media (example, all,), speech { .foo { color: chartreuse }}",
-      ParserError::UnexpectedToken(Token::Comma),
+    minify_test(
+      ".foo { border-width: min(1em + 2px + 2px, 2px + 1em + 1px) }",
+      ".foo{border-width:min(1em + 4px,3px + 1em)}",
     );
-    error_test(
-      "@media &test, speech { .foo { color: chartreuse }}",
-      ParserError::UnexpectedToken(Token::Delim('&')),
+    minify_test(
+      ".foo { border-width: min(2px + 1px, 3px + 4px) }",
+      ".foo{border-width:3px}",
     );
-    error_test(
-      "@media &test { .foo { color: chartreuse }}",
-      ParserError::UnexpectedToken(Token::Delim('&')),
+    minify_test(
+      ".foo { border-width: min(1px, 1em, 2px, 3in) }",
+      ".foo{border-width:min(1px,1em)}",
     );
+
     minify_test(
-      "@media (min-width: calc(200px + 40px)) { .foo { color: chartreuse }}",
-      "@media (width>=240px){.foo{color:#7fff00}}",
+      ".foo { border-width: max(1em, 2px) }",
+      ".foo{border-width:max(1em,2px)}",
     );
     minify_test(
-      "@media (min-width: calc(1em + 5px)) { .foo { color: chartreuse }}",
-      "@media (width>=calc(1em + 5px)){.foo{color:#7fff00}}",
+      ".foo { border-width: max(1em + 2em, 2px + 2px) }",
+      ".foo{border-width:max(3em,4px)}",
     );
-    minify_test("@media { .foo { color: chartreuse }}", ".foo{color:#7fff00}");
-    minify_test("@media all { .foo { color: chartreuse }}", ".foo{color:#7fff00}");
     minify_test(
-      "@media not (((color) or (hover))) { .foo { color: chartreuse }}",
-      "@media not ((color) or (hover)){.foo{color:#7fff00}}",
+      ".foo { border-width: max(1em + 2px, 2px + 1em) }",
+      ".foo{border-width:max(1em + 2px,2px + 1em)}",
     );
     minify_test(
-      "@media (hover) and ((color) and (test)) { .foo { color: chartreuse }}",
-      "@media (hover) and (color) and (test){.foo{color:#7fff00}}",
+      ".foo { border-width: max(1em + 2px + 2px, 2px + 1em + 1px) }",
+      ".foo{border-width:max(1em + 4px,3px + 1em)}",
     );
     minify_test(
-      "@media (grid: 1) { .foo { color: chartreuse }}",
-      "@media (grid:1){.foo{color:#7fff00}}",
+      ".foo { border-width: max(2px + 1px, 3px + 4px) }",
+      ".foo{border-width:7px}",
     );
     minify_test(
-      "@media (width >= calc(2px + 4px)) { .foo { color: chartreuse }}",
-      "@media (width>=6px){.foo{color:#7fff00}}",
+      ".foo { border-width: max(1px, 1em, 2px, 3in) }",
+      ".foo{border-width:max(3in,1em)}",
     );
 
-    prefix_test(
-      r#"
-        @media (width >= 240px) {
-          .foo {
-            color: chartreuse;
-          }
-        }
-      "#,
-      indoc! { r#"
-        @media (min-width: 240px) {
-          .foo {
-            color: #7fff00;
-          }
-        }
-      "#},
-      Browsers {
-        firefox: Some(60 << 16),
-        ..Browsers::default()
-      },
+    minify_test(".foo { border-width: clamp(1px, 2px, 3px) }", ".foo{border-width:2px}");
+    minify_test(".foo { border-width: clamp(1px, 10px, 3px) }", ".foo{border-width:3px}");
+    minify_test(".foo { border-width: clamp(5px, 2px, 10px) }", ".foo{border-width:5px}");
+    minify_test(
+      ".foo { border-width: clamp(100px, 2px, 10px) }",
+      ".foo{border-width:100px}",
+    );
+    minify_test(
+      ".foo { border-width: clamp(5px + 5px, 5px + 7px, 10px + 20px) }",
+      ".foo{border-width:12px}",
     );
 
-    prefix_test(
-      r#"
-        @media (width >= 240px) {
-          .foo {
-            color: chartreuse;
-          }
-        }
-      "#,
-      indoc! { r#"
-        @media (width >= 240px) {
-          .foo {
-            color: #7fff00;
-          }
-        }
-      "#},
-      Browsers {
-        firefox: Some(64 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { border-width: clamp(1em, 2px, 4vh) }",
+      ".foo{border-width:clamp(1em,2px,4vh)}",
     );
-
-    prefix_test(
-      r#"
-        @media (color > 2) {
-          .foo {
-            color: chartreuse;
-          }
-        }
-      "#,
-      indoc! { r#"
-        @media (min-color: 3) {
-          .foo {
-            color: #7fff00;
-          }
-        }
-      "#},
-      Browsers {
-        firefox: Some(60 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { border-width: clamp(1em, 2em, 4vh) }",
+      ".foo{border-width:min(2em,4vh)}",
     );
-
-    prefix_test(
-      r#"
-        @media (color < 2) {
-          .foo {
-            color: chartreuse;
-          }
-        }
-      "#,
-      indoc! { r#"
-        @media (max-color: 1) {
-          .foo {
-            color: #7fff00;
-          }
-        }
-      "#},
-      Browsers {
-        firefox: Some(60 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { border-width: clamp(1em, 2vh, 4vh) }",
+      ".foo{border-width:max(1em,2vh)}",
     );
-
-    prefix_test(
-      r#"
-        @media (width > 240px) {
-          .foo {
-            color: chartreuse;
-          }
-        }
-      "#,
-      indoc! { r#"
-        @media (min-width: 240.001px) {
-          .foo {
-            color: #7fff00;
-          }
-        }
-      "#},
-      Browsers {
-        firefox: Some(60 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { border-width: clamp(1px, 1px + 2em, 4px) }",
+      ".foo{border-width:clamp(1px,1px + 2em,4px)}",
     );
-
-    prefix_test(
-      r#"
-        @media (width <= 240px) {
-          .foo {
-            color: chartreuse;
-          }
-        }
-      "#,
-      indoc! { r#"
-        @media (max-width: 240px) {
-          .foo {
-            color: #7fff00;
-          }
-        }
-      "#},
-      Browsers {
-        firefox: Some(60 << 16),
-        ..Browsers::default()
-      },
+    minify_test(".foo { border-width: clamp(1px, 2pt, 1in) }", ".foo{border-width:2pt}");
+    minify_test(
+      ".foo { border-width: clamp(40px, 5vw, 30px) }",
+      ".foo{border-width:40px}",
     );
-
+    minify_test(
+      ".foo { border-width: min(10px, min(20px, 5vw)) }",
+      ".foo{border-width:min(10px,5vw)}",
+    );
+    minify_test(
+      ".foo { border-width: max(1px, max(2px, 5vw), 3vw) }",
+      ".foo{border-width:max(2px,5vw)}",
+    );
+    minify_test(
+      ".foo { border-width: min(10px, 20px, var(--x)) }",
+      ".foo{border-width:min(10px,var(--x))}",
+    );
+    minify_test(
+      ".foo { border-width: max(10px, 20px, var(--x), 1cm) }",
+      ".foo{border-width:max(1cm,var(--x))}",
+    );
+    minify_test(
+      ".foo { border-width: min(10px + var(--x), 5px, 8px) }",
+      ".foo{border-width:min(10px + var(--x),5px)}",
+    );
+    minify_test(".foo { --y: min(1, 2, var(--x)) }", ".foo{--y:min(1,var(--x))}");
     prefix_test(
-      r#"
-        @media (width <= 240px) {
-          .foo {
-            color: chartreuse;
-          }
-        }
-      "#,
-      indoc! { r#"
-        @media (width <= 240px) {
-          .foo {
-            color: #7fff00;
-          }
+      ".foo { width: min(10px, 20px, var(--x)) }",
+      indoc! {r#"
+        .foo {
+          width: min(10px, var(--x));
         }
       "#},
-      Browsers {
-        firefox: Some(64 << 16),
-        ..Browsers::default()
-      },
+      Browsers::default(),
+    );
+    minify_test(
+      ".foo { width: calc(var(--x) + 10px + 20px) }",
+      ".foo{width:calc(var(--x) + 30px)}",
     );
+    minify_test(
+      ".foo { width: calc(10px + var(--x) - 30px) }",
+      ".foo{width:calc(-20px + var(--x))}",
+    );
+    minify_test(
+      ".foo { width: calc(var(--x) - 10px + 1in + 5%) }",
+      ".foo{width:calc(var(--x) + 86px + 5%)}",
+    );
+    minify_test(
+      ".foo { width: calc(var(--x) + 10px - 30px) }",
+      ".foo{width:calc(var(--x) - 20px)}",
+    );
+    minify_test(
+      ".foo { width: calc(var(--x) * 2 + 1em + 10px + 2em) }",
+      ".foo{width:calc(var(--x)*2 + 3em + 10px)}",
+    );
+    minify_test(
+      ".foo { width: calc((var(--x) + 10px) + 20px) }",
+      ".foo{width:calc((var(--x) + 10px) + 20px)}",
+    );
+    minify_test(".foo { --y: calc(var(--x) + 1 + 2) }", ".foo{--y:calc(var(--x) + 3)}");
 
-    prefix_test(
-      r#"
-        @media (width < 240px) {
-          .foo {
-            color: chartreuse;
-          }
-        }
-      "#,
-      indoc! { r#"
-        @media (max-width: 239.999px) {
-          .foo {
-            color: #7fff00;
-          }
-        }
-      "#},
-      Browsers {
-        firefox: Some(60 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { top: calc(-1 * clamp(1.75rem, 8vw, 4rem)) }",
+      ".foo{top:calc(-1*clamp(1.75rem,8vw,4rem))}",
+    );
+    minify_test(
+      ".foo { top: calc(-1 * min(1.75rem, 8vw, 4rem)) }",
+      ".foo{top:calc(-1*min(1.75rem,8vw))}",
+    );
+    minify_test(
+      ".foo { top: calc(-1 * max(1.75rem, 8vw, 4rem)) }",
+      ".foo{top:calc(-1*max(4rem,8vw))}",
+    );
+    minify_test(
+      ".foo { top: calc(clamp(1.75rem, 8vw, 4rem) * -1) }",
+      ".foo{top:calc(-1*clamp(1.75rem,8vw,4rem))}",
+    );
+    minify_test(
+      ".foo { top: calc(min(1.75rem, 8vw, 4rem) * -1) }",
+      ".foo{top:calc(-1*min(1.75rem,8vw))}",
+    );
+    minify_test(
+      ".foo { top: calc(max(1.75rem, 8vw, 4rem) * -1) }",
+      ".foo{top:calc(-1*max(4rem,8vw))}",
+    );
+    minify_test(
+      ".foo { top: calc(clamp(1.75rem, 8vw, 4rem) / 2) }",
+      ".foo{top:calc(clamp(1.75rem,8vw,4rem)/2)}",
+    );
+    minify_test(
+      ".foo { top: calc(min(1.75rem, 8vw, 4rem) / 2) }",
+      ".foo{top:calc(min(1.75rem,8vw)/2)}",
+    );
+    minify_test(
+      ".foo { top: calc(max(1.75rem, 8vw, 4rem) / 2) }",
+      ".foo{top:calc(max(4rem,8vw)/2)}",
+    );
+    minify_test(
+      ".foo { top: calc(0.5 * clamp(1.75rem, 8vw, 4rem)) }",
+      ".foo{top:calc(clamp(1.75rem,8vw,4rem)/2)}",
+    );
+    minify_test(
+      ".foo { top: calc(1 * clamp(1.75rem, 8vw, 4rem)) }",
+      ".foo{top:calc(clamp(1.75rem,8vw,4rem))}",
+    );
+    minify_test(
+      ".foo { top: calc(2 * clamp(1.75rem, 8vw, 4rem) / 2) }",
+      ".foo{top:calc(clamp(1.75rem,8vw,4rem))}",
     );
 
+    minify_test(".foo { width: max(0px, 1vw) }", ".foo{width:max(0px,1vw)}");
+
     prefix_test(
-      r#"
-        @media (100px <= width <= 200px) {
-          .foo {
-            color: chartreuse;
-          }
-        }
-      "#,
+      ".foo { border-width: clamp(1em, 2px, 4vh) }",
       indoc! { r#"
-        @media (min-width: 100px) and (max-width: 200px) {
-          .foo {
-            color: #7fff00;
-          }
+        .foo {
+          border-width: max(1em, min(2px, 4vh));
         }
       "#},
       Browsers {
-        firefox: Some(85 << 16),
+        safari: Some(12 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      r#"
-        @media not (100px <= width <= 200px) {
-          .foo {
-            color: chartreuse;
-          }
-        }
-      "#,
+      ".foo { border-width: clamp(1em, 2px, 4vh) }",
       indoc! { r#"
-        @media not ((min-width: 100px) and (max-width: 200px)) {
-          .foo {
-            color: #7fff00;
-          }
+        .foo {
+          border-width: clamp(1em, 2px, 4vh);
         }
       "#},
       Browsers {
-        firefox: Some(85 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
 
-    prefix_test(
-      r#"
-        @media (hover) and (100px <= width <= 200px) {
-          .foo {
-            color: chartreuse;
-          }
-        }
-      "#,
-      indoc! { r#"
-        @media (hover) and (min-width: 100px) and (max-width: 200px) {
-          .foo {
-            color: #7fff00;
-          }
-        }
-      "#},
-      Browsers {
-        firefox: Some(85 << 16),
-        ..Browsers::default()
-      },
+    minify_test(".foo { width: calc(1vh + 2vh) }", ".foo{width:3vh}");
+    minify_test(".foo { width: calc(1dvh + 2dvh) }", ".foo{width:3dvh}");
+    minify_test(".foo { width: calc(1lvh + 2lvh) }", ".foo{width:3lvh}");
+    minify_test(".foo { width: calc(1svh + 2svh) }", ".foo{width:3svh}");
+    minify_test(".foo { width: calc(1sVmin + 2Svmin) }", ".foo{width:3svmin}");
+    minify_test(".foo { width: calc(1ic + 2ic) }", ".foo{width:3ic}");
+    minify_test(".foo { width: calc(1ric + 2ric) }", ".foo{width:3ric}");
+    minify_test(".foo { width: calc(1cap + 2cap) }", ".foo{width:3cap}");
+    minify_test(".foo { width: calc(1lh + 2lh) }", ".foo{width:3lh}");
+    minify_test(".foo { width: calc(1x + 2x) }", ".foo{width:calc(1x + 2x)}");
+    minify_test(
+      ".foo { left: calc(50% - 100px + clamp(0px, calc(50vw - 50px), 100px)) }",
+      ".foo{left:calc(50% - 100px + clamp(0px,50vw - 50px,100px))}",
+    );
+    minify_test(
+      ".foo { left: calc(10px + min(10px, 1rem) + max(2px, 1vw)) }",
+      ".foo{left:calc(10px + min(10px,1rem) + max(2px,1vw))}",
+    );
+    minify_test(".foo { width: calc(1in + 4px) }", ".foo{width:100px}");
+    minify_test(".foo { width: calc(1cm + 1mm) }", ".foo{width:11mm}");
+    minify_test(".foo { width: calc(1in + 1pt) }", ".foo{width:73pt}");
+    minify_test(".foo { width: calc(0.5in + 48px) }", ".foo{width:1in}");
+    minify_test(".foo { width: calc(10px + 1cm) }", ".foo{width:47.7953px}");
+    minify_test(".foo { width: calc(1pc + 2pt + 1em) }", ".foo{width:calc(14pt + 1em)}");
+    minify_test(".foo { width: round(22px, 5px) }", ".foo{width:20px}");
+    minify_test(".foo { width: round(nearest, 22px, 5px) }", ".foo{width:20px}");
+    minify_test(".foo { width: round(down, 22px, 5px) }", ".foo{width:20px}");
+    minify_test(".foo { width: round(to-zero, 22px, 5px) }", ".foo{width:20px}");
+    minify_test(".foo { width: round(up, 22px, 5px) }", ".foo{width:25px}");
+    minify_test(".foo { width: round(23px, 5px) }", ".foo{width:25px}");
+    minify_test(".foo { width: round(nearest, 23px, 5px) }", ".foo{width:25px}");
+    minify_test(".foo { width: round(down, 23px, 5px) }", ".foo{width:20px}");
+    minify_test(".foo { width: round(to-zero, 23px, 5px) }", ".foo{width:20px}");
+    minify_test(".foo { width: round(up, 23px, 5px) }", ".foo{width:25px}");
+    minify_test(".foo { width: round(22px, 5vw) }", ".foo{width:round(22px,5vw)}");
+    minify_test(".foo { rotate: round(22deg, 5deg) }", ".foo{rotate:20deg}");
+    minify_test(".foo { rotate: round(22deg, 5deg) }", ".foo{rotate:20deg}");
+    minify_test(
+      ".foo { transition-duration: round(22ms, 5ms) }",
+      ".foo{transition-duration:20ms}",
+    );
+    minify_test(".foo { margin: round(to-zero, -23px, 5px) }", ".foo{margin:-20px}");
+    minify_test(".foo { margin: round(nearest, -23px, 5px) }", ".foo{margin:-25px}");
+    minify_test(".foo { margin: calc(10px * round(22, 5)) }", ".foo{margin:200px}");
+    minify_test(".foo { width: calc(round(10.4px, 1px)) }", ".foo{width:10px}");
+    minify_test(".foo { margin: calc(10px * round(2.5)) }", ".foo{margin:30px}");
+    minify_test(".foo { margin: calc(10px * round(down, 2.7)) }", ".foo{margin:20px}");
+    minify_test(".foo { opacity: round(0.46) }", ".foo{opacity:0}");
+    minify_test(".foo { z-index: calc(2 * 3) }", ".foo{z-index:6}");
+    minify_test(".foo { z-index: round(2.5) }", ".foo{z-index:3}");
+    minify_test(".foo { z-index: sign(-4) }", ".foo{z-index:-1}");
+    minify_test(".foo { order: calc(-2.5) }", ".foo{order:-2}");
+    minify_test(".foo { width: rem(18px, 5px) }", ".foo{width:3px}");
+    minify_test(".foo { width: rem(-18px, 5px) }", ".foo{width:-3px}");
+    minify_test(".foo { width: rem(18px, 5vw) }", ".foo{width:rem(18px,5vw)}");
+    minify_test(".foo { rotate: rem(-140deg, -90deg) }", ".foo{rotate:-50deg}");
+    minify_test(".foo { rotate: rem(140deg, -90deg) }", ".foo{rotate:50deg}");
+    minify_test(".foo { width: calc(10px * rem(18, 5)) }", ".foo{width:30px}");
+    minify_test(".foo { width: mod(18px, 5px) }", ".foo{width:3px}");
+    minify_test(".foo { width: mod(-18px, 5px) }", ".foo{width:2px}");
+    minify_test(".foo { rotate: mod(-140deg, -90deg) }", ".foo{rotate:-50deg}");
+    minify_test(".foo { rotate: mod(140deg, -90deg) }", ".foo{rotate:-40deg}");
+    minify_test(".foo { width: mod(18px, 5vw) }", ".foo{width:mod(18px,5vw)}");
+    minify_test(
+      ".foo { transform: rotateX(mod(140deg, -90deg)) rotateY(rem(140deg, -90deg)) }",
+      ".foo{transform:rotateX(-40deg)rotateY(50deg)}",
+    );
+    minify_test(".foo { width: calc(10px * mod(18, 5)) }", ".foo{width:30px}");
+  }
+
+  #[test]
+  fn test_precision() {
+    fn precision_test(source: &str, expected: &str, precision: u8) {
+      minify_test_with_all_options(
+        source,
+        expected,
+        ParserOptions::default(),
+        MinifyOptions::default(),
+        PrinterOptions {
+          precision: Some(precision),
+          ..PrinterOptions::default()
+        },
+      );
+    }
+
+    precision_test(".foo { width: calc(100px / 3) }", ".foo{width:33.33px}", 2);
+    precision_test(".foo { width: calc(100px / 3) }", ".foo{width:33px}", 0);
+    precision_test(".foo { opacity: .33333 }", ".foo{opacity:.333}", 3);
+    precision_test(".foo { height: calc(100% / 3) }", ".foo{height:33.3%}", 1);
+    precision_test(".foo { margin: -0.001px }", ".foo{margin:0}", 2);
+    precision_test(
+      ".foo { width: calc(1px / 3 + 1em) }",
+      ".foo{width:calc(.33px + 1em)}",
+      2,
+    );
+    precision_test(
+      ".foo { transform: rotate(33.33333deg) translate(1.23456px) }",
+      ".foo{transform:rotate(33.33deg)translate(1.23px)}",
+      2,
+    );
+    precision_test(
+      ".foo { color: lab(50.12345% 20.4567 30.9876) }",
+      ".foo{color:lab(50.12% 20.46 30.99)}",
+      2,
+    );
+    // Values with fewer decimal places are unaffected.
+    precision_test(".foo { width: 1.5px; opacity: .5 }", ".foo{opacity:.5;width:1.5px}", 2);
+  }
+
+  #[test]
+  fn test_trig() {
+    minify_test(".foo { width: calc(2px * pi); }", ".foo{width:6.28319px}");
+    minify_test(".foo { width: calc(2px / pi); }", ".foo{width:.63662px}");
+    // minify_test(
+    //   ".foo { width: calc(2px * infinity); }",
+    //   ".foo{width:calc(2px*infinity)}",
+    // );
+    // minify_test(
+    //   ".foo { width: calc(2px * -infinity); }",
+    //   ".foo{width:calc(2px*-infinity)}",
+    // );
+    minify_test(".foo { width: calc(100px * sin(45deg))", ".foo{width:70.7107px}");
+    minify_test(".foo { width: calc(100px * sin(.125turn))", ".foo{width:70.7107px}");
+    minify_test(
+      ".foo { width: calc(100px * sin(3.14159265 / 4))",
+      ".foo{width:70.7107px}",
+    );
+    minify_test(".foo { width: calc(100px * sin(pi / 4))", ".foo{width:70.7107px}");
+    minify_test(
+      ".foo { width: calc(100px * sin(22deg + 23deg))",
+      ".foo{width:70.7107px}",
+    );
+
+    minify_test(".foo { width: calc(2px * cos(45deg))", ".foo{width:1.41421px}");
+    minify_test(".foo { width: calc(2px * tan(45deg))", ".foo{width:2px}");
+    minify_test(".foo { width: calc(100px * cos(90deg))", ".foo{width:0}");
+    minify_test(".foo { width: calc(100px * sin(pi))", ".foo{width:0}");
+    minify_test(".foo { width: calc(100px * sin(1))", ".foo{width:84.1471px}");
+    minify_test(".foo { opacity: sin(30deg)", ".foo{opacity:.5}");
+
+    minify_test(".foo { rotate: asin(sin(45deg))", ".foo{rotate:45deg}");
+    minify_test(".foo { rotate: asin(1)", ".foo{rotate:90deg}");
+    minify_test(".foo { rotate: asin(-1)", ".foo{rotate:-90deg}");
+    minify_test(".foo { rotate: asin(0.5)", ".foo{rotate:30deg}");
+    minify_test(".foo { rotate: asin(45deg)", ".foo{rotate:asin(45deg)}"); // invalid
+    minify_test(".foo { rotate: asin(-20)", ".foo{rotate:asin(-20)}"); // evaluates to NaN
+    minify_test(".foo { width: asin(sin(45deg))", ".foo{width:asin(sin(45deg))}"); // invalid
+
+    minify_test(".foo { rotate: acos(cos(45deg))", ".foo{rotate:45deg}");
+    minify_test(".foo { rotate: acos(-1)", ".foo{rotate:180deg}");
+    minify_test(".foo { rotate: acos(0)", ".foo{rotate:90deg}");
+    minify_test(".foo { rotate: acos(1)", ".foo{rotate:none}");
+    minify_test(".foo { rotate: acos(45deg)", ".foo{rotate:acos(45deg)}"); // invalid
+    minify_test(".foo { rotate: acos(-20)", ".foo{rotate:acos(-20)}"); // evaluates to NaN
+
+    minify_test(".foo { rotate: atan(tan(45deg))", ".foo{rotate:45deg}");
+    minify_test(".foo { rotate: atan(1)", ".foo{rotate:45deg}");
+    minify_test(".foo { rotate: atan(0)", ".foo{rotate:none}");
+    minify_test(".foo { rotate: atan(45deg)", ".foo{rotate:atan(45deg)}"); // invalid
+
+    minify_test(".foo { rotate: atan2(1px, -1px)", ".foo{rotate:135deg}");
+    minify_test(".foo { rotate: atan2(1vw, -1vw)", ".foo{rotate:135deg}");
+    minify_test(".foo { rotate: atan2(1, -1)", ".foo{rotate:135deg}");
+    minify_test(".foo { rotate: atan2(1ms, -1ms)", ".foo{rotate:135deg}");
+    minify_test(".foo { rotate: atan2(1%, -1%)", ".foo{rotate:135deg}");
+    minify_test(".foo { rotate: atan2(1deg, -1deg)", ".foo{rotate:135deg}");
+    minify_test(".foo { rotate: atan2(1cm, 1mm)", ".foo{rotate:84.2894deg}");
+    minify_test(".foo { rotate: atan2(0, -1)", ".foo{rotate:180deg}");
+    minify_test(".foo { rotate: atan2(-1, 1)", ".foo{rotate:-45deg}");
+    // incompatible units
+    minify_test(".foo { rotate: atan2(1px, -1vw)", ".foo{rotate:atan2(1px,-1vw)}");
+  }
+
+  #[test]
+  fn test_exp() {
+    minify_test(".foo { width: hypot()", ".foo{width:hypot()}");
+    minify_test(".foo { width: hypot(1px)", ".foo{width:1px}");
+    minify_test(".foo { width: hypot(1px, 2px)", ".foo{width:2.23607px}");
+    minify_test(".foo { width: hypot(1px, 2px, 3px)", ".foo{width:3.74166px}");
+    minify_test(".foo { width: hypot(1px, 2vw)", ".foo{width:hypot(1px,2vw)}");
+    minify_test(".foo { width: hypot(1px, 2px, 3vw)", ".foo{width:hypot(1px,2px,3vw)}");
+    minify_test(".foo { width: calc(100px * hypot(3, 4))", ".foo{width:500px}");
+    minify_test(".foo { width: calc(1px * pow(2, sqrt(100))", ".foo{width:1024px}");
+    minify_test(".foo { width: calc(100px * pow(2, pow(2, 2)", ".foo{width:1600px}");
+    minify_test(".foo { width: calc(1px * log(1))", ".foo{width:0}");
+    minify_test(".foo { width: calc(1px * log(10, 10))", ".foo{width:1px}");
+    minify_test(".foo { width: calc(1px * exp(0))", ".foo{width:1px}");
+    minify_test(".foo { width: calc(1px * log(e))", ".foo{width:1px}");
+    minify_test(".foo { width: calc(1px * (e - exp(1)))", ".foo{width:0}");
+    minify_test(
+      ".foo { width: calc(1px * (exp(log(1) + exp(0)*2))",
+      ".foo{width:7.38906px}",
+    );
+  }
+
+  #[test]
+  fn test_sign() {
+    minify_test(".foo { width: abs(1px)", ".foo{width:1px}");
+    minify_test(".foo { width: abs(-1px)", ".foo{width:1px}");
+    minify_test(".foo { width: abs(1%)", ".foo{width:abs(1%)}"); // spec says percentages must be against resolved value
+
+    minify_test(".foo { width: calc(10px * sign(-1vw)", ".foo{width:-10px}");
+    minify_test(".foo { width: calc(10px * sign(1%)", ".foo{width:calc(10px*sign(1%))}");
+  }
+
+  #[test]
+  fn test_sibling_functions() {
+    minify_test(
+      ".foo { width: calc(sibling-index() * 10px) }",
+      ".foo{width:calc(sibling-index()*10px)}",
+    );
+    minify_test(
+      ".foo { width: calc(10px * sibling-count()) }",
+      ".foo{width:calc(sibling-count()*10px)}",
+    );
+    minify_test(
+      ".foo { width: calc((sibling-index() - 1) * 10px + 5%) }",
+      ".foo{width:calc((sibling-index() - 1)*10px + 5%)}",
+    );
+    minify_test(
+      ".foo { width: calc(2 * sibling-index() * 10px) }",
+      ".foo{width:calc(2*sibling-index()*10px)}",
+    );
+    minify_test(
+      ".foo { rotate: calc(sibling-index() * 30deg) }",
+      ".foo{rotate:calc(sibling-index()*30deg)}",
+    );
+    minify_test(
+      ".foo { width: calc(sibling-index() * 10px + sibling-count() * 5px) }",
+      ".foo{width:calc(sibling-index()*10px + sibling-count()*5px)}",
+    );
+    minify_test(".foo { z-index: sibling-index() }", ".foo{z-index:sibling-index()}");
+    minify_test(
+      ".foo { width: calc(sibling-index(1) * 10px) }",
+      ".foo{width:calc(sibling-index(1)*10px)}",
+    );
+    // Typed values can be merged into shorthands.
+    minify_test(
+      ".foo { margin-top: calc(sibling-index() * 10px); margin-right: 0; margin-bottom: 0; margin-left: 0 }",
+      ".foo{margin:calc(sibling-index()*10px) 0 0}",
+    );
+  }
+
+  #[test]
+  fn test_box_shadow() {
+    minify_test(
+      ".foo { box-shadow: 64px 64px 12px 40px rgba(0,0,0,0.4) }",
+      ".foo{box-shadow:64px 64px 12px 40px #0006}",
+    );
+    minify_test(
+      ".foo { box-shadow: 12px 12px 0px 8px rgba(0,0,0,0.4) inset }",
+      ".foo{box-shadow:inset 12px 12px 0 8px #0006}",
+    );
+    minify_test(
+      ".foo { box-shadow: inset 12px 12px 0px 8px rgba(0,0,0,0.4) }",
+      ".foo{box-shadow:inset 12px 12px 0 8px #0006}",
+    );
+    minify_test(
+      ".foo { box-shadow: 12px 12px 8px 0px rgba(0,0,0,0.4) }",
+      ".foo{box-shadow:12px 12px 8px #0006}",
+    );
+    minify_test(
+      ".foo { box-shadow: 12px 12px 0px 0px rgba(0,0,0,0.4) }",
+      ".foo{box-shadow:12px 12px #0006}",
+    );
+    minify_test(
+      ".foo { box-shadow: 64px 64px 12px 40px rgba(0,0,0,0.4), 12px 12px 0px 8px rgba(0,0,0,0.4) inset }",
+      ".foo{box-shadow:64px 64px 12px 40px #0006,inset 12px 12px 0 8px #0006}",
     );
 
     prefix_test(
-      r#"
-        @media (hover) or (100px <= width <= 200px) {
-          .foo {
-            color: chartreuse;
-          }
-        }
-      "#,
+      ".foo { box-shadow: 12px 12px lab(40% 56.6 39) }",
       indoc! { r#"
-        @media (hover) or ((min-width: 100px) and (max-width: 200px)) {
-          .foo {
-            color: #7fff00;
-          }
+        .foo {
+          box-shadow: 12px 12px #b32323;
+          box-shadow: 12px 12px lab(40% 56.6 39);
         }
       "#},
       Browsers {
-        firefox: Some(85 << 16),
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      r#"
-        @media (100px < width < 200px) {
-          .foo {
-            color: chartreuse;
-          }
-        }
-      "#,
+      ".foo { box-shadow: 12px 12px lab(40% 56.6 39) }",
       indoc! { r#"
-        @media (min-width: 100.001px) and (max-width: 199.999px) {
-          .foo {
-            color: #7fff00;
-          }
+        .foo {
+          -webkit-box-shadow: 12px 12px #b32323;
+          box-shadow: 12px 12px #b32323;
+          box-shadow: 12px 12px lab(40% 56.6 39);
         }
       "#},
       Browsers {
-        firefox: Some(85 << 16),
+        chrome: Some(4 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      r#"
-        @media (200px >= width >= 100px) {
-          .foo {
-            color: chartreuse;
-          }
-        }
-      "#,
+      ".foo { box-shadow: 12px 12px lab(40% 56.6 39), 12px 12px yellow }",
       indoc! { r#"
-        @media (max-width: 200px) and (min-width: 100px) {
-          .foo {
-            color: #7fff00;
-          }
+        .foo {
+          -webkit-box-shadow: 12px 12px #b32323, 12px 12px #ff0;
+          box-shadow: 12px 12px #b32323, 12px 12px #ff0;
+          box-shadow: 12px 12px lab(40% 56.6 39), 12px 12px #ff0;
         }
       "#},
       Browsers {
-        firefox: Some(85 << 16),
+        chrome: Some(4 << 16),
         ..Browsers::default()
       },
     );
 
-    test(
-      r#"
-      @media not all {
-        .a {
-          color: green;
-        }
-      }
-      "#,
-      "\n",
-    );
-
     prefix_test(
-      r#"
-      @media (width > calc(1px + 1rem)) {
-        .foo { color: yellow; }
-      }
-      "#,
+      ".foo { -webkit-box-shadow: 12px 12px #0006 }",
       indoc! { r#"
-        @media (min-width: calc(1.001px + 1rem)) {
-          .foo {
-            color: #ff0;
-          }
+        .foo {
+          -webkit-box-shadow: 12px 12px rgba(0, 0, 0, .4);
         }
       "#},
       Browsers {
-        chrome: Some(85 << 16),
+        chrome: Some(4 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
-      r#"
-      @media (width > max(10px, 1rem)) {
-        .foo { color: yellow; }
-      }
-      "#,
+      ".foo {
+        -webkit-box-shadow: 12px 12px #0006;
+        -moz-box-shadow: 12px 12px #0009;
+      }",
       indoc! { r#"
-        @media (min-width: calc(max(10px, 1rem) + .001px)) {
-          .foo {
-            color: #ff0;
-          }
+        .foo {
+          -webkit-box-shadow: 12px 12px rgba(0, 0, 0, .4);
+          -moz-box-shadow: 12px 12px rgba(0, 0, 0, .6);
         }
       "#},
       Browsers {
-        chrome: Some(85 << 16),
+        chrome: Some(4 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
-      r#"
-      @media (width > 0) {
-        .foo { color: yellow; }
-      }
-      "#,
+      ".foo {
+        -webkit-box-shadow: 12px 12px #0006;
+        -moz-box-shadow: 12px 12px #0006;
+        box-shadow: 12px 12px #0006;
+      }",
       indoc! { r#"
-        @media (min-width: .001px) {
-          .foo {
-            color: #ff0;
-          }
+        .foo {
+          box-shadow: 12px 12px #0006;
         }
       "#},
       Browsers {
-        chrome: Some(85 << 16),
+        chrome: Some(95 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
-      r#"
-      @media (min-resolution: 2dppx) {
-        .foo { color: yellow; }
-      }
-      "#,
+      ".foo { box-shadow: var(--foo) 12px lab(40% 56.6 39) }",
       indoc! { r#"
-        @media (-webkit-min-device-pixel-ratio: 2), (min-resolution: 2dppx) {
-          .foo {
-            color: #ff0;
-          }
+        .foo {
+          box-shadow: var(--foo) 12px #b32323;
         }
-      "#},
-      Browsers {
-        safari: Some(15 << 16),
-        ..Browsers::default()
-      },
-    );
-    prefix_test(
-      r#"
-      @media (min-resolution: 2dppx) {
-        .foo { color: yellow; }
-      }
-      "#,
-      indoc! { r#"
-        @media (min--moz-device-pixel-ratio: 2), (min-resolution: 2dppx) {
+
+        @supports (color: lab(0% 0 0)) {
           .foo {
-            color: #ff0;
+            box-shadow: var(--foo) 12px lab(40% 56.6 39);
           }
         }
       "#},
       Browsers {
-        firefox: Some(10 << 16),
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
       r#"
-      @media (resolution > 2dppx) {
-        .foo { color: yellow; }
+      .foo {
+        box-shadow: 0px 0px 22px red;
+        box-shadow: 0px 0px max(2cqw, 22px) red;
       }
-      "#,
-      indoc! { r#"
-        @media (-webkit-min-device-pixel-ratio: 2.001), (min-resolution: 2.001dppx) {
-          .foo {
-            color: #ff0;
-          }
-        }
-      "#},
-      Browsers {
-        safari: Some(15 << 16),
-        ..Browsers::default()
-      },
-    );
-    prefix_test(
-      r#"
-      @media (resolution >= 300dpi) {
-        .foo { color: yellow; }
+    "#,
+      indoc! {r#"
+      .foo {
+        box-shadow: 0 0 22px red;
+        box-shadow: 0 0 max(2cqw, 22px) red;
       }
-      "#,
-      indoc! { r#"
-        @media (-webkit-min-device-pixel-ratio: 3.125), (min-resolution: 300dpi) {
-          .foo {
-            color: #ff0;
-          }
-        }
-      "#},
+    "#
+      },
       Browsers {
-        safari: Some(15 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
       r#"
-      @media (min-resolution: 113.38dpcm) {
-        .foo { color: yellow; }
+      .foo {
+        box-shadow: 0px 0px 22px red;
+        box-shadow: 0px 0px max(2cqw, 22px) red;
       }
-      "#,
-      indoc! { r#"
-        @media (-webkit-min-device-pixel-ratio: 2.99985), (min--moz-device-pixel-ratio: 2.99985), (min-resolution: 113.38dpcm) {
-          .foo {
-            color: #ff0;
-          }
-        }
-      "#},
+    "#,
+      indoc! {r#"
+      .foo {
+        box-shadow: 0 0 max(2cqw, 22px) red;
+      }
+    "#
+      },
       Browsers {
-        safari: Some(15 << 16),
-        firefox: Some(10 << 16),
+        safari: Some(16 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
       r#"
-      @media (color) and (min-resolution: 2dppx) {
-        .foo { color: yellow; }
+      .foo {
+        box-shadow: 0px 0px 22px red;
+        box-shadow: 0px 0px 22px lab(40% 56.6 39);
       }
-      "#,
-      indoc! { r#"
-        @media (color) and (-webkit-min-device-pixel-ratio: 2), (color) and (min-resolution: 2dppx) {
-          .foo {
-            color: #ff0;
-          }
-        }
-      "#},
+    "#,
+      indoc! {r#"
+      .foo {
+        box-shadow: 0 0 22px red;
+        box-shadow: 0 0 22px lab(40% 56.6 39);
+      }
+    "#
+      },
       Browsers {
-        safari: Some(15 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
       r#"
-      @media (min-resolution: 2dppx),
-             (min-resolution: 192dpi) {
-        .foo { color: yellow; }
+      .foo {
+        box-shadow: 0px 0px 22px red;
+        box-shadow: 0px 0px 22px lab(40% 56.6 39);
       }
-      "#,
-      indoc! { r#"
-        @media (-webkit-min-device-pixel-ratio: 2), (min--moz-device-pixel-ratio: 2), (min-resolution: 2dppx), (min-resolution: 192dpi) {
-          .foo {
-            color: #ff0;
-          }
-        }
-      "#},
+    "#,
+      indoc! {r#"
+      .foo {
+        box-shadow: 0 0 22px lab(40% 56.6 39);
+      }
+    "#
+      },
       Browsers {
-        safari: Some(15 << 16),
-        firefox: Some(10 << 16),
+        safari: Some(16 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
       r#"
-      @media only screen and (min-resolution: 124.8dpi) {
-        .foo { color: yellow; }
+      .foo {
+        box-shadow: var(--fallback);
+        box-shadow: 0px 0px 22px lab(40% 56.6 39);
       }
-      "#,
-      indoc! { r#"
-        @media only screen and (-webkit-min-device-pixel-ratio: 1.3), only screen and (min--moz-device-pixel-ratio: 1.3), only screen and (min-resolution: 124.8dpi) {
-          .foo {
-            color: #ff0;
-          }
-        }
-      "#},
+    "#,
+      indoc! {r#"
+      .foo {
+        box-shadow: var(--fallback);
+        box-shadow: 0 0 22px lab(40% 56.6 39);
+      }
+    "#
+      },
       Browsers {
-        safari: Some(15 << 16),
-        firefox: Some(10 << 16),
+        safari: Some(16 << 16),
         ..Browsers::default()
       },
     );
+  }
 
-    error_test(
-      "@media (min-width: hi) { .foo { color: chartreuse }}",
-      ParserError::InvalidMediaQuery,
-    );
-    error_test(
-      "@media (width >= hi) { .foo { color: chartreuse }}",
-      ParserError::InvalidMediaQuery,
+  #[test]
+  fn test_media() {
+    minify_test(
+      "@media (min-width: 240px) { .foo { color: chartreuse }}",
+      "@media (width>=240px){.foo{color:#7fff00}}",
     );
-    error_test(
-      "@media (width >= 2/1) { .foo { color: chartreuse }}",
-      ParserError::UnexpectedToken(Token::Delim('/')),
+    minify_test(
+      "@media (width < 240px) { .foo { color: chartreuse }}",
+      "@media (width<240px){.foo{color:#7fff00}}",
     );
-    error_test(
-      "@media (600px <= min-height) { .foo { color: chartreuse }}",
-      ParserError::InvalidMediaQuery,
+    minify_test(
+      "@media (width <= 240px) { .foo { color: chartreuse }}",
+      "@media (width<=240px){.foo{color:#7fff00}}",
     );
-    error_test(
-      "@media (scan >= 1) { .foo { color: chartreuse }}",
-      ParserError::InvalidMediaQuery,
+    minify_test(
+      "@media (width > 240px) { .foo { color: chartreuse }}",
+      "@media (width>240px){.foo{color:#7fff00}}",
     );
-    error_test(
-      "@media (min-scan: interlace) { .foo { color: chartreuse }}",
-      ParserError::InvalidMediaQuery,
+    minify_test(
+      "@media (width >= 240px) { .foo { color: chartreuse }}",
+      "@media (width>=240px){.foo{color:#7fff00}}",
     );
-    error_test(
-      "@media (1px <= width <= bar) { .foo { color: chartreuse }}",
-      ParserError::InvalidMediaQuery,
+    minify_test(
+      "@media (240px < width) { .foo { color: chartreuse }}",
+      "@media (width>240px){.foo{color:#7fff00}}",
     );
-    error_test(
-      "@media (1px <= min-width <= 2px) { .foo { color: chartreuse }}",
-      ParserError::InvalidMediaQuery,
+    minify_test(
+      "@media (240px <= width) { .foo { color: chartreuse }}",
+      "@media (width>=240px){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (240px > width) { .foo { color: chartreuse }}",
+      "@media (width<240px){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (240px >= width) { .foo { color: chartreuse }}",
+      "@media (width<=240px){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (100px < width < 200px) { .foo { color: chartreuse }}",
+      "@media (100px<width<200px){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (100px <= width <= 200px) { .foo { color: chartreuse }}",
+      "@media (100px<=width<=200px){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (min-width: 30em) and (max-width: 50em) { .foo { color: chartreuse }}",
+      "@media (width>=30em) and (width<=50em){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media screen, print { .foo { color: chartreuse }}",
+      "@media screen,print{.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (hover: hover) { .foo { color: chartreuse }}",
+      "@media (hover:hover){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (hover) { .foo { color: chartreuse }}",
+      "@media (hover){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (aspect-ratio: 11/5) { .foo { color: chartreuse }}",
+      "@media (aspect-ratio:11/5){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (aspect-ratio: 2/1) { .foo { color: chartreuse }}",
+      "@media (aspect-ratio:2){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (aspect-ratio: 2) { .foo { color: chartreuse }}",
+      "@media (aspect-ratio:2){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media not screen and (color) { .foo { color: chartreuse }}",
+      "@media not screen and (color){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media only screen and (color) { .foo { color: chartreuse }}",
+      "@media only screen and (color){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (update: slow) or (hover: none) { .foo { color: chartreuse }}",
+      "@media (update:slow) or (hover:none){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (width < 600px) and (height < 600px) { .foo { color: chartreuse }}",
+      "@media (width<600px) and (height<600px){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (not (color)) or (hover) { .foo { color: chartreuse }}",
+      "@media (not (color)) or (hover){.foo{color:#7fff00}}",
     );
     error_test(
-      "@media (1px <= scan <= 2px) { .foo { color: chartreuse }}",
-      ParserError::InvalidMediaQuery,
+      "@media (example, all,), speech { .foo { color: chartreuse }}",
+      ParserError::UnexpectedToken(Token::Comma),
     );
     error_test(
-      "@media (grid: 10) { .foo { color: chartreuse }}",
-      ParserError::InvalidMediaQuery,
+      "@media &test, speech { .foo { color: chartreuse }}",
+      ParserError::UnexpectedToken(Token::Delim('&')),
     );
     error_test(
-      "@media (prefers-color-scheme = dark) { .foo { color: chartreuse }}",
-      ParserError::InvalidMediaQuery,
+      "@media &test { .foo { color: chartreuse }}",
+      ParserError::UnexpectedToken(Token::Delim('&')),
+    );
+    minify_test(
+      "@media (min-width: calc(200px + 40px)) { .foo { color: chartreuse }}",
+      "@media (width>=240px){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (min-width: calc(1em + 5px)) { .foo { color: chartreuse }}",
+      "@media (width>=calc(1em + 5px)){.foo{color:#7fff00}}",
+    );
+    minify_test("@media { .foo { color: chartreuse }}", ".foo{color:#7fff00}");
+    minify_test("@media all { .foo { color: chartreuse }}", ".foo{color:#7fff00}");
+    minify_test(
+      "@media not (((color) or (hover))) { .foo { color: chartreuse }}",
+      "@media not ((color) or (hover)){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (hover) and ((color) and (test)) { .foo { color: chartreuse }}",
+      "@media (hover) and (color) and (test){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (grid: 1) { .foo { color: chartreuse }}",
+      "@media (grid:1){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (width >= calc(2px + 4px)) { .foo { color: chartreuse }}",
+      "@media (width>=6px){.foo{color:#7fff00}}",
     );
-  }
 
-  #[test]
-  fn test_merge_layers() {
-    test(
+    prefix_test(
       r#"
-      @layer foo {
-        .foo {
-          color: red;
+        @media (width >= 240px) {
+          .foo {
+            color: chartreuse;
+          }
         }
-      }
-      @layer foo {
-        .foo {
-          background: #fff;
+      "#,
+      indoc! { r#"
+        @media (min-width: 240px) {
+          .foo {
+            color: #7fff00;
+          }
         }
+      "#},
+      Browsers {
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
+    );
 
-        .baz {
-          color: #fff;
+    prefix_test(
+      r#"
+        @media (width >= 240px) {
+          .foo {
+            color: chartreuse;
+          }
         }
-      }
       "#,
-      indoc! {r#"
-      @layer foo {
-        .foo {
-          color: red;
-          background: #fff;
-        }
-
-        .baz {
-          color: #fff;
+      indoc! { r#"
+        @media (width >= 240px) {
+          .foo {
+            color: #7fff00;
+          }
         }
-      }
-    "#},
+      "#},
+      Browsers {
+        firefox: Some(64 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
-      r#"
-      @layer a {}
-      @layer b {}
-
-      @layer b {
-        foo {
-          color: red;
-        }
-      }
 
-      @layer a {
-        bar {
-          color: yellow;
+    prefix_test(
+      r#"
+        @media (color > 2) {
+          .foo {
+            color: chartreuse;
+          }
         }
-      }
       "#,
-      indoc! {r#"
-      @layer a {
-        bar {
-          color: #ff0;
-        }
-      }
-
-      @layer b {
-        foo {
-          color: red;
+      indoc! { r#"
+        @media (min-color: 3) {
+          .foo {
+            color: #7fff00;
+          }
         }
-      }
-    "#},
+      "#},
+      Browsers {
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
-      @layer a {}
-      @layer b {}
-
-      @layer b {
-        foo {
-          color: red;
+        @media (color < 2) {
+          .foo {
+            color: chartreuse;
+          }
         }
-      }
       "#,
-      indoc! {r#"
-      @layer a;
-
-      @layer b {
-        foo {
-          color: red;
+      indoc! { r#"
+        @media (max-color: 1) {
+          .foo {
+            color: #7fff00;
+          }
         }
-      }
-    "#},
+      "#},
+      Browsers {
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
-      @layer a;
-      @layer b;
-      @layer c;
+        @media (width > 240px) {
+          .foo {
+            color: chartreuse;
+          }
+        }
       "#,
-      indoc! {r#"
-      @layer a, b, c;
-    "#},
+      indoc! { r#"
+        @media (min-width: 240.001px) {
+          .foo {
+            color: #7fff00;
+          }
+        }
+      "#},
+      Browsers {
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
-      @layer a {}
-      @layer b {}
-      @layer c {}
+        @media (width <= 240px) {
+          .foo {
+            color: chartreuse;
+          }
+        }
       "#,
-      indoc! {r#"
-      @layer a, b, c;
-    "#},
+      indoc! { r#"
+        @media (max-width: 240px) {
+          .foo {
+            color: #7fff00;
+          }
+        }
+      "#},
+      Browsers {
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
-      @layer a;
-      @layer b {
-        .foo {
-          color: red;
+        @media (width <= 240px) {
+          .foo {
+            color: chartreuse;
+          }
         }
-      }
-      @layer c {}
       "#,
-      indoc! {r#"
-      @layer a;
-
-      @layer b {
-        .foo {
-          color: red;
+      indoc! { r#"
+        @media (width <= 240px) {
+          .foo {
+            color: #7fff00;
+          }
         }
-      }
-
-      @layer c;
-    "#},
+      "#},
+      Browsers {
+        firefox: Some(64 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
-      @layer a, b;
-      @layer c {}
-
-      @layer d {
-        foo {
-          color: red;
+        @media (width < 240px) {
+          .foo {
+            color: chartreuse;
+          }
         }
-      }
       "#,
-      indoc! {r#"
-      @layer a, b, c;
-
-      @layer d {
-        foo {
-          color: red;
+      indoc! { r#"
+        @media (max-width: 239.999px) {
+          .foo {
+            color: #7fff00;
+          }
         }
-      }
-    "#},
+      "#},
+      Browsers {
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
-      @layer a;
-      @layer b;
-      @import "a.css" layer(x);
-      @layer c;
+        @media (100px <= width <= 200px) {
+          .foo {
+            color: chartreuse;
+          }
+        }
+      "#,
+      indoc! { r#"
+        @media (min-width: 100px) and (max-width: 200px) {
+          .foo {
+            color: #7fff00;
+          }
+        }
+      "#},
+      Browsers {
+        firefox: Some(85 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      @layer d {
-        foo {
-          color: red;
+    prefix_test(
+      r#"
+        @media not (100px <= width <= 200px) {
+          .foo {
+            color: chartreuse;
+          }
         }
-      }
       "#,
-      indoc! {r#"
-      @layer a, b;
-      @import "a.css" layer(x);
-      @layer c;
+      indoc! { r#"
+        @media not ((min-width: 100px) and (max-width: 200px)) {
+          .foo {
+            color: #7fff00;
+          }
+        }
+      "#},
+      Browsers {
+        firefox: Some(85 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      @layer d {
-        foo {
-          color: red;
+    prefix_test(
+      r#"
+        @media (hover) and (100px <= width <= 200px) {
+          .foo {
+            color: chartreuse;
+          }
         }
-      }
-    "#},
+      "#,
+      indoc! { r#"
+        @media (hover) and (min-width: 100px) and (max-width: 200px) {
+          .foo {
+            color: #7fff00;
+          }
+        }
+      "#},
+      Browsers {
+        firefox: Some(85 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
-      @layer a, b, c;
+        @media (hover) or (100px <= width <= 200px) {
+          .foo {
+            color: chartreuse;
+          }
+        }
+      "#,
+      indoc! { r#"
+        @media (hover) or ((min-width: 100px) and (max-width: 200px)) {
+          .foo {
+            color: #7fff00;
+          }
+        }
+      "#},
+      Browsers {
+        firefox: Some(85 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      @layer a {
-        foo {
-          color: red;
+    prefix_test(
+      r#"
+        @media (100px < width < 200px) {
+          .foo {
+            color: chartreuse;
+          }
         }
-      }
       "#,
-      indoc! {r#"
-      @layer a {
-        foo {
-          color: red;
+      indoc! { r#"
+        @media (min-width: 100.001px) and (max-width: 199.999px) {
+          .foo {
+            color: #7fff00;
+          }
         }
-      }
+      "#},
+      Browsers {
+        firefox: Some(85 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      @layer b, c;
-    "#},
+    prefix_test(
+      r#"
+        @media (200px >= width >= 100px) {
+          .foo {
+            color: chartreuse;
+          }
+        }
+      "#,
+      indoc! { r#"
+        @media (max-width: 200px) and (min-width: 100px) {
+          .foo {
+            color: #7fff00;
+          }
+        }
+      "#},
+      Browsers {
+        firefox: Some(85 << 16),
+        ..Browsers::default()
+      },
     );
-  }
 
-  #[test]
-  fn test_merge_rules() {
     test(
       r#"
-      .foo {
-        color: red;
-      }
-      .bar {
-        color: red;
-      }
-    "#,
-      indoc! {r#"
-      .foo, .bar {
-        color: red;
+      @media not all {
+        .a {
+          color: green;
+        }
       }
-    "#},
+      "#,
+      "\n",
     );
-    test(
+
+    prefix_test(
       r#"
-      .foo {
-        color: red;
-      }
-      .foo {
-        background: green;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        color: red;
-        background: green;
+      @media (width > calc(1px + 1rem)) {
+        .foo { color: yellow; }
       }
-    "#},
+      "#,
+      indoc! { r#"
+        @media (min-width: calc(1.001px + 1rem)) {
+          .foo {
+            color: #ff0;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(85 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
+    prefix_test(
       r#"
-      .foo {
-        color: red;
-      }
-      .foo {
-        background: green !important;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        color: red;
-        background: green !important;
+      @media (width > max(10px, 1rem)) {
+        .foo { color: yellow; }
       }
-    "#},
+      "#,
+      indoc! { r#"
+        @media (min-width: calc(max(10px, 1rem) + .001px)) {
+          .foo {
+            color: #ff0;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(85 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
+    prefix_test(
       r#"
-      .foo {
-        background: red;
-      }
-      .foo {
-        background: green;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        background: green;
+      @media (width > 0) {
+        .foo { color: yellow; }
       }
-    "#},
+      "#,
+      indoc! { r#"
+        @media (min-width: .001px) {
+          .foo {
+            color: #ff0;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(85 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
+    prefix_test(
       r#"
-      .foo {
-        --foo: red;
-        --foo: purple;
-      }
-      .foo {
-        --foo: green;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        --foo: green;
+      @media (min-resolution: 2dppx) {
+        .foo { color: yellow; }
       }
-    "#},
+      "#,
+      indoc! { r#"
+        @media (-webkit-min-device-pixel-ratio: 2), (min-resolution: 2dppx) {
+          .foo {
+            color: #ff0;
+          }
+        }
+      "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
+    prefix_test(
       r#"
-      .foo {
-        color: red;
-      }
-
-      .bar {
-        background: green;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        color: red;
-      }
-
-      .bar {
-        background: green;
+      @media (min-resolution: 2dppx) {
+        .foo { color: yellow; }
       }
-    "#},
+      "#,
+      indoc! { r#"
+        @media (min--moz-device-pixel-ratio: 2), (min-resolution: 2dppx) {
+          .foo {
+            color: #ff0;
+          }
+        }
+      "#},
+      Browsers {
+        firefox: Some(10 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
+    prefix_test(
       r#"
-      .foo {
-        color: red;
-      }
-
-      .baz {
-        color: blue;
-      }
-
-      .bar {
-        color: red;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        color: red;
-      }
-
-      .baz {
-        color: #00f;
-      }
-
-      .bar {
-        color: red;
+      @media (resolution > 2dppx) {
+        .foo { color: yellow; }
       }
-    "#},
+      "#,
+      indoc! { r#"
+        @media (-webkit-min-device-pixel-ratio: 2.001), (min-resolution: 2.001dppx) {
+          .foo {
+            color: #ff0;
+          }
+        }
+      "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
+    prefix_test(
       r#"
-      .foo {
-        background: red;
-      }
-      .bar {
-        background: red;
-      }
-      .foo {
-        color: green;
-      }
-      .bar {
-        color: green;
-      }
-    "#,
-      indoc! {r#"
-      .foo, .bar {
-        color: green;
-        background: red;
+      @media (resolution >= 300dpi) {
+        .foo { color: yellow; }
       }
-    "#},
+      "#,
+      indoc! { r#"
+        @media (-webkit-min-device-pixel-ratio: 3.125), (min-resolution: 300dpi) {
+          .foo {
+            color: #ff0;
+          }
+        }
+      "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
+    prefix_test(
       r#"
-      .foo, .bar {
-        background: red;
-      }
-      .foo {
-        color: green;
-      }
-      .bar {
-        color: green;
-      }
-    "#,
-      indoc! {r#"
-      .foo, .bar {
-        color: green;
-        background: red;
+      @media (min-resolution: 113.38dpcm) {
+        .foo { color: yellow; }
       }
-    "#},
-    );
-    test(
-      r#"
-      .foo {
-        background: red;
-      }
-      .foo {
-        color: green;
-      }
-      .bar {
-        background: red;
+      "#,
+      indoc! { r#"
+        @media (-webkit-min-device-pixel-ratio: 2.99985), (min--moz-device-pixel-ratio: 2.99985), (min-resolution: 113.38dpcm) {
+          .foo {
+            color: #ff0;
+          }
+        }
+      "#},
+      Browsers {
+        safari: Some(15 << 16),
+        firefox: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      @media (color) and (min-resolution: 2dppx) {
+        .foo { color: yellow; }
       }
-      .bar {
-        color: green;
+      "#,
+      indoc! { r#"
+        @media (color) and (-webkit-min-device-pixel-ratio: 2), (color) and (min-resolution: 2dppx) {
+          .foo {
+            color: #ff0;
+          }
+        }
+      "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      @media (min-resolution: 2dppx),
+             (min-resolution: 192dpi) {
+        .foo { color: yellow; }
       }
-    "#,
-      indoc! {r#"
-      .foo, .bar {
-        color: green;
-        background: red;
+      "#,
+      indoc! { r#"
+        @media (-webkit-min-device-pixel-ratio: 2), (min--moz-device-pixel-ratio: 2), (min-resolution: 2dppx), (min-resolution: 192dpi) {
+          .foo {
+            color: #ff0;
+          }
+        }
+      "#},
+      Browsers {
+        safari: Some(15 << 16),
+        firefox: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      @media only screen and (min-resolution: 124.8dpi) {
+        .foo { color: yellow; }
       }
-    "#},
+      "#,
+      indoc! { r#"
+        @media only screen and (-webkit-min-device-pixel-ratio: 1.3), only screen and (min--moz-device-pixel-ratio: 1.3), only screen and (min-resolution: 124.8dpi) {
+          .foo {
+            color: #ff0;
+          }
+        }
+      "#},
+      Browsers {
+        safari: Some(15 << 16),
+        firefox: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    error_test(
+      "@media (min-width: hi) { .foo { color: chartreuse }}",
+      ParserError::InvalidMediaQuery,
+    );
+    error_test(
+      "@media (width >= hi) { .foo { color: chartreuse }}",
+      ParserError::InvalidMediaQuery,
+    );
+    error_test(
+      "@media (width >= 2/1) { .foo { color: chartreuse }}",
+      ParserError::UnexpectedToken(Token::Delim('/')),
+    );
+    error_test(
+      "@media (600px <= min-height) { .foo { color: chartreuse }}",
+      ParserError::InvalidMediaQuery,
+    );
+    error_test(
+      "@media (scan >= 1) { .foo { color: chartreuse }}",
+      ParserError::InvalidMediaQuery,
+    );
+    error_test(
+      "@media (min-scan: interlace) { .foo { color: chartreuse }}",
+      ParserError::InvalidMediaQuery,
+    );
+    error_test(
+      "@media (1px <= width <= bar) { .foo { color: chartreuse }}",
+      ParserError::InvalidMediaQuery,
+    );
+    error_test(
+      "@media (1px <= min-width <= 2px) { .foo { color: chartreuse }}",
+      ParserError::InvalidMediaQuery,
+    );
+    error_test(
+      "@media (1px <= scan <= 2px) { .foo { color: chartreuse }}",
+      ParserError::InvalidMediaQuery,
+    );
+    error_test(
+      "@media (grid: 10) { .foo { color: chartreuse }}",
+      ParserError::InvalidMediaQuery,
+    );
+    error_test(
+      "@media (prefers-color-scheme = dark) { .foo { color: chartreuse }}",
+      ParserError::InvalidMediaQuery,
     );
+  }
+
+  #[test]
+  fn test_merge_layers() {
     test(
       r#"
-      [foo="bar"] {
-        color: red;
+      @layer foo {
+        .foo {
+          color: red;
+        }
       }
-      .bar {
-        color: red;
+      @layer foo {
+        .foo {
+          background: #fff;
+        }
+
+        .baz {
+          color: #fff;
+        }
       }
-    "#,
+      "#,
       indoc! {r#"
-      [foo="bar"], .bar {
-        color: red;
+      @layer foo {
+        .foo {
+          color: red;
+          background: #fff;
+        }
+
+        .baz {
+          color: #fff;
+        }
       }
     "#},
     );
     test(
       r#"
-      .a {
-        color: red;
-      }
-      .b {
-        color: green;
+      @layer a {}
+      @layer b {}
+
+      @layer b {
+        foo {
+          color: red;
+        }
       }
-      .a {
-        color: red;
+
+      @layer a {
+        bar {
+          color: yellow;
+        }
       }
-    "#,
+      "#,
       indoc! {r#"
-      .b {
-        color: green;
+      @layer a {
+        bar {
+          color: #ff0;
+        }
       }
 
-      .a {
-        color: red;
+      @layer b {
+        foo {
+          color: red;
+        }
       }
     "#},
     );
+
     test(
       r#"
-      .a {
-        color: red;
-      }
-      .b {
-        color: green;
-      }
-      .a {
-        color: pink;
+      @layer a {}
+      @layer b {}
+
+      @layer b {
+        foo {
+          color: red;
+        }
       }
-    "#,
+      "#,
       indoc! {r#"
-      .b {
-        color: green;
-      }
+      @layer a;
 
-      .a {
-        color: pink;
+      @layer b {
+        foo {
+          color: red;
+        }
       }
     "#},
     );
+
     test(
       r#"
-      .a:foo(#000) {
-        color: red;
-      }
-      .b {
-        color: green;
-      }
-      .a:foo(#ff0) {
-        color: pink;
-      }
-    "#,
+      @layer a;
+      @layer b;
+      @layer c;
+      "#,
       indoc! {r#"
-      .a:foo(#000) {
-        color: red;
-      }
-
-      .b {
-        color: green;
-      }
-
-      .a:foo(#ff0) {
-        color: pink;
-      }
+      @layer a, b, c;
     "#},
     );
+
     test(
       r#"
-      .a {
-        border-radius: 10px;
-      }
-      .b {
-        color: green;
-      }
-      .a {
-        border-radius: 10px;
-      }
-    "#,
+      @layer a {}
+      @layer b {}
+      @layer c {}
+      "#,
       indoc! {r#"
-      .b {
-        color: green;
-      }
-
-      .a {
-        border-radius: 10px;
-      }
+      @layer a, b, c;
     "#},
     );
+
     test(
       r#"
-      .a {
-        border-radius: 10px;
-      }
-      .b {
-        color: green;
-      }
-      .a {
-        -webkit-border-radius: 10px;
+      @layer a;
+      @layer b {
+        .foo {
+          color: red;
+        }
       }
-    "#,
+      @layer c {}
+      "#,
       indoc! {r#"
-      .a {
-        border-radius: 10px;
-      }
+      @layer a;
 
-      .b {
-        color: green;
+      @layer b {
+        .foo {
+          color: red;
+        }
       }
 
-      .a {
-        -webkit-border-radius: 10px;
-      }
+      @layer c;
     "#},
     );
+
     test(
       r#"
-      .a {
-        border-radius: 10px;
-      }
-      .b {
-        color: green;
-      }
-      .a {
-        border-radius: var(--foo);
+      @layer a, b;
+      @layer c {}
+
+      @layer d {
+        foo {
+          color: red;
+        }
       }
-    "#,
+      "#,
       indoc! {r#"
-      .b {
-        color: green;
-      }
+      @layer a, b, c;
 
-      .a {
-        border-radius: var(--foo);
+      @layer d {
+        foo {
+          color: red;
+        }
       }
     "#},
     );
+
     test(
       r#"
-      .a {
-        border-radius: 10px;
-      }
-      .b {
-        color: green;
-      }
-      .c {
-        border-radius: 20px;
-      }
-    "#,
-      indoc! {r#"
-      .a {
-        border-radius: 10px;
-      }
+      @layer a;
+      @layer b;
+      @import "a.css" layer(x);
+      @layer c;
 
-      .b {
-        color: green;
+      @layer d {
+        foo {
+          color: red;
+        }
       }
+      "#,
+      indoc! {r#"
+      @layer a, b;
+      @import "a.css" layer(x);
+      @layer c;
 
-      .c {
-        border-radius: 20px;
+      @layer d {
+        foo {
+          color: red;
+        }
       }
     "#},
     );
+
     test(
       r#"
-      @media print {
-        .a {
-          color: red;
-        }
-        .b {
-          color: green;
-        }
-        .a {
+      @layer a, b, c;
+
+      @layer a {
+        foo {
           color: red;
         }
       }
-    "#,
+      "#,
       indoc! {r#"
-      @media print {
-        .b {
-          color: green;
-        }
-
-        .a {
+      @layer a {
+        foo {
           color: red;
         }
       }
+
+      @layer b, c;
     "#},
     );
+  }
+
+  #[test]
+  fn test_merge_rules() {
     test(
       r#"
-      .a {
-        border-radius: 10px;
-      }
-      .b {
-        color: green;
+      .foo {
+        color: red;
       }
-      .a {
-        border-radius: 20px;
-        color: pink;
+      .bar {
+        color: red;
       }
     "#,
       indoc! {r#"
-      .a {
-        border-radius: 10px;
-      }
-
-      .b {
-        color: green;
-      }
-
-      .a {
-        color: pink;
-        border-radius: 20px;
+      .foo, .bar {
+        color: red;
       }
     "#},
     );
     test(
       r#"
-      .a {
-        color: red;
-      }
-      .b {
-        color: green;
-      }
-      .a {
+      .foo {
         color: red;
       }
-      .b {
-        color: green;
+      .foo {
+        background: green;
       }
     "#,
       indoc! {r#"
-      .a {
+      .foo {
         color: red;
-      }
-
-      .b {
-        color: green;
+        background: green;
       }
     "#},
     );
-
-    prefix_test(
+    test(
       r#"
-      [foo="bar"] {
+      .foo {
         color: red;
       }
-      .bar {
-        color: red;
+      .foo {
+        background: green !important;
       }
     "#,
       indoc! {r#"
-      [foo="bar"] {
-        color: red;
-      }
-
-      .bar {
+      .foo {
         color: red;
+        background: green !important;
       }
     "#},
-      Browsers {
-        ie: Some(6 << 16),
-        ..Browsers::default()
-      },
     );
-
-    prefix_test(
+    test(
       r#"
-      [foo="bar"] {
-        color: red;
+      .foo {
+        background: red;
       }
-      .bar {
-        color: red;
+      .foo {
+        background: green;
       }
     "#,
       indoc! {r#"
-      [foo="bar"], .bar {
-        color: red;
+      .foo {
+        background: green;
       }
     "#},
-      Browsers {
-        ie: Some(10 << 16),
-        ..Browsers::default()
-      },
     );
-
     test(
       r#"
-      .foo:-moz-read-only {
-        color: red;
+      .foo {
+        --foo: red;
+        --foo: purple;
+      }
+      .foo {
+        --foo: green;
       }
     "#,
       indoc! {r#"
-      .foo:-moz-read-only {
-        color: red;
+      .foo {
+        --foo: green;
       }
     "#},
     );
-
     test(
       r#"
-      .foo:-moz-read-only {
+      .foo {
         color: red;
       }
 
-      .foo:read-only {
-        color: red;
+      .bar {
+        background: green;
       }
     "#,
       indoc! {r#"
-      .foo:-moz-read-only {
+      .foo {
         color: red;
       }
 
-      .foo:read-only {
-        color: red;
+      .bar {
+        background: green;
       }
     "#},
     );
-
-    prefix_test(
+    test(
       r#"
-      .foo:-moz-read-only {
+      .foo {
         color: red;
       }
 
-      .foo:read-only {
-        color: red;
-      }
-    "#,
-      indoc! {r#"
-      .foo:read-only {
-        color: red;
-      }
-    "#},
-      Browsers {
-        firefox: Some(85 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    prefix_test(
-      r#"
-      .foo:-moz-read-only {
-        color: red;
+      .baz {
+        color: blue;
       }
 
       .bar {
-        color: yellow;
-      }
-
-      .foo:read-only {
         color: red;
       }
     "#,
       indoc! {r#"
-      .foo:-moz-read-only {
+      .foo {
         color: red;
       }
 
-      .bar {
-        color: #ff0;
+      .baz {
+        color: #00f;
       }
 
-      .foo:read-only {
+      .bar {
         color: red;
       }
     "#},
-      Browsers {
-        firefox: Some(85 << 16),
-        ..Browsers::default()
-      },
     );
-
-    prefix_test(
+    test(
       r#"
-      .foo:-moz-read-only {
-        color: red;
+      .foo {
+        background: red;
       }
-
-      .foo:read-only {
-        color: red;
+      .bar {
+        background: red;
+      }
+      .foo {
+        color: green;
+      }
+      .bar {
+        color: green;
       }
     "#,
       indoc! {r#"
-      .foo:-moz-read-only {
-        color: red;
-      }
-
-      .foo:read-only {
-        color: red;
+      .foo, .bar {
+        color: green;
+        background: red;
       }
     "#},
-      Browsers {
-        firefox: Some(36 << 16),
-        ..Browsers::default()
-      },
     );
-
-    prefix_test(
+    test(
       r#"
-      .foo:read-only {
-        color: red;
+      .foo, .bar {
+        background: red;
+      }
+      .foo {
+        color: green;
+      }
+      .bar {
+        color: green;
       }
     "#,
       indoc! {r#"
-      .foo:-moz-read-only {
-        color: red;
-      }
-
-      .foo:read-only {
-        color: red;
+      .foo, .bar {
+        color: green;
+        background: red;
       }
     "#},
-      Browsers {
-        firefox: Some(36 << 16),
-        ..Browsers::default()
-      },
     );
-
-    prefix_test(
+    test(
       r#"
-      .foo:-webkit-full-screen {
-        color: red;
+      .foo {
+        background: red;
       }
-      .foo:-moz-full-screen {
-        color: red;
+      .foo {
+        color: green;
       }
-      .foo:-ms-fullscreen {
-        color: red;
+      .bar {
+        background: red;
       }
-      .foo:fullscreen {
-        color: red;
+      .bar {
+        color: green;
       }
     "#,
       indoc! {r#"
-      .foo:fullscreen {
-        color: red;
+      .foo, .bar {
+        color: green;
+        background: red;
       }
     "#},
-      Browsers {
-        chrome: Some(96 << 16),
-        ..Browsers::default()
-      },
     );
-
-    prefix_test(
+    test(
       r#"
-      .foo:fullscreen {
+      [foo="bar"] {
+        color: red;
+      }
+      .bar {
         color: red;
       }
     "#,
       indoc! {r#"
-      .foo:-webkit-full-screen {
+      [foo="bar"], .bar {
         color: red;
       }
-
-      .foo:-moz-full-screen {
+    "#},
+    );
+    test(
+      r#"
+      .a {
         color: red;
       }
-
-      .foo:-ms-fullscreen {
+      .b {
+        color: green;
+      }
+      .a {
         color: red;
       }
+    "#,
+      indoc! {r#"
+      .b {
+        color: green;
+      }
 
-      .foo:fullscreen {
+      .a {
         color: red;
       }
     "#},
-      Browsers {
-        chrome: Some(45 << 16),
-        firefox: Some(45 << 16),
-        ie: Some(11 << 16),
-        ..Browsers::default()
-      },
     );
-
-    prefix_test(
+    test(
       r#"
-      .foo::placeholder {
+      .a {
         color: red;
       }
-    "#,
-      indoc! {r#"
-      .foo::-webkit-input-placeholder {
-        color: red;
+      .b {
+        color: green;
       }
-
-      .foo::-moz-placeholder {
-        color: red;
+      .a {
+        color: pink;
       }
-
-      .foo::-ms-input-placeholder {
-        color: red;
+    "#,
+      indoc! {r#"
+      .b {
+        color: green;
       }
 
-      .foo::placeholder {
-        color: red;
+      .a {
+        color: pink;
       }
     "#},
-      Browsers {
-        chrome: Some(45 << 16),
-        firefox: Some(45 << 16),
-        ie: Some(11 << 16),
-        ..Browsers::default()
-      },
     );
-
-    prefix_test(
+    test(
       r#"
-      .foo::file-selector-button {
+      .a:foo(#000) {
         color: red;
       }
+      .b {
+        color: green;
+      }
+      .a:foo(#ff0) {
+        color: pink;
+      }
     "#,
       indoc! {r#"
-      .foo::-webkit-file-upload-button {
+      .a:foo(#000) {
         color: red;
       }
 
-      .foo::-ms-browse {
-        color: red;
+      .b {
+        color: green;
       }
 
-      .foo::file-selector-button {
-        color: red;
+      .a:foo(#ff0) {
+        color: pink;
       }
     "#},
-      Browsers {
-        chrome: Some(84 << 16),
-        ie: Some(10 << 16),
-        ..Browsers::default()
-      },
     );
-
-    prefix_test(
+    test(
       r#"
-      .foo::file-selector-button {
-        margin-inline-start: 2px;
+      .a {
+        border-radius: 10px;
+      }
+      .b {
+        color: green;
+      }
+      .a {
+        border-radius: 10px;
       }
     "#,
       indoc! {r#"
-      .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)))::-webkit-file-upload-button {
-        margin-left: 2px;
+      .b {
+        color: green;
       }
 
-      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)))::-ms-browse {
-        margin-left: 2px;
+      .a {
+        border-radius: 10px;
       }
-
-      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)))::file-selector-button {
-        margin-left: 2px;
+    "#},
+    );
+    test(
+      r#"
+      .a {
+        border-radius: 10px;
       }
-
-      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))::-webkit-file-upload-button {
-        margin-right: 2px;
+      .b {
+        color: green;
       }
-
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))::-ms-browse {
-        margin-right: 2px;
+      .a {
+        -webkit-border-radius: 10px;
       }
-
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))::file-selector-button {
-        margin-right: 2px;
+    "#,
+      indoc! {r#"
+      .a {
+        border-radius: 10px;
+      }
+
+      .b {
+        color: green;
+      }
+
+      .a {
+        -webkit-border-radius: 10px;
       }
     "#},
-      Browsers {
-        chrome: Some(84 << 16),
-        ie: Some(10 << 16),
-        ..Browsers::default()
-      },
     );
+    test(
+      r#"
+      .a {
+        border-radius: 10px;
+      }
+      .b {
+        color: green;
+      }
+      .a {
+        border-radius: var(--foo);
+      }
+    "#,
+      indoc! {r#"
+      .b {
+        color: green;
+      }
 
-    prefix_test(
+      .a {
+        border-radius: var(--foo);
+      }
+    "#},
+    );
+    test(
       r#"
-      .foo:placeholder-shown .bar { color: red; }
-      .foo:autofill .baz { color: red; }
-      "#,
+      .a {
+        border-radius: 10px;
+      }
+      .b {
+        color: green;
+      }
+      .c {
+        border-radius: 20px;
+      }
+    "#,
       indoc! {r#"
-      .foo:placeholder-shown .bar {
-        color: red;
+      .a {
+        border-radius: 10px;
       }
 
-      .foo:-webkit-autofill .baz {
-        color: red;
+      .b {
+        color: green;
       }
 
-      .foo:autofill .baz {
-        color: red;
+      .c {
+        border-radius: 20px;
       }
-      "#},
-      Browsers {
-        chrome: Some(103 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
-
-    prefix_test(
+    test(
       r#"
-      .foo:placeholder-shown .bar,.foo:autofill .baz{color:red}
-      "#,
-      indoc! {r#"
-      :-webkit-any(.foo:placeholder-shown .bar, .foo:-webkit-autofill .baz) {
-        color: red;
+      @media print {
+        .a {
+          color: red;
+        }
+        .b {
+          color: green;
+        }
+        .a {
+          color: red;
+        }
       }
+    "#,
+      indoc! {r#"
+      @media print {
+        .b {
+          color: green;
+        }
 
-      :is(.foo:placeholder-shown .bar, .foo:autofill .baz) {
-        color: red;
+        .a {
+          color: red;
+        }
       }
-      "#},
-      Browsers {
-        chrome: Some(103 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
-
-    prefix_test(
+    test(
       r#"
-      .foo:placeholder-shown .bar, .foo:-webkit-autofill .baz {
-        color: red;
+      .a {
+        border-radius: 10px;
       }
-
-      .foo:placeholder-shown .bar, .foo:autofill .baz {
-        color: red;
+      .b {
+        color: green;
       }
-      "#,
+      .a {
+        border-radius: 20px;
+        color: pink;
+      }
+    "#,
       indoc! {r#"
-      :-webkit-any(.foo:placeholder-shown .bar, .foo:-webkit-autofill .baz) {
-        color: red;
+      .a {
+        border-radius: 10px;
       }
 
-      :is(.foo:placeholder-shown .bar, .foo:autofill .baz) {
-        color: red;
+      .b {
+        color: green;
       }
-      "#},
-      Browsers {
-        chrome: Some(103 << 16),
-        ..Browsers::default()
-      },
-    );
 
+      .a {
+        color: pink;
+        border-radius: 20px;
+      }
+    "#},
+    );
     test(
       r#"
-      .foo:placeholder-shown .bar, .foo:-webkit-autofill .baz {
+      .a {
         color: red;
       }
-
-      .foo:placeholder-shown .bar, .foo:autofill .baz {
+      .b {
+        color: green;
+      }
+      .a {
         color: red;
       }
-      "#,
+      .b {
+        color: green;
+      }
+    "#,
       indoc! {r#"
-      .foo:placeholder-shown .bar, .foo:-webkit-autofill .baz {
+      .a {
         color: red;
       }
 
-      .foo:placeholder-shown .bar, .foo:autofill .baz {
-        color: red;
+      .b {
+        color: green;
       }
-      "#},
+    "#},
     );
 
     prefix_test(
       r#"
-      :hover, :focus-visible {
+      [foo="bar"] {
         color: red;
       }
-      "#,
+      .bar {
+        color: red;
+      }
+    "#,
       indoc! {r#"
-      :hover {
+      [foo="bar"] {
         color: red;
       }
 
-      :focus-visible {
+      .bar {
         color: red;
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(13 << 16),
+        ie: Some(6 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo {
+      [foo="bar"] {
         color: red;
       }
-
-      :hover, :focus-visible {
+      .bar {
         color: red;
       }
-      "#,
+    "#,
       indoc! {r#"
-      .foo, :hover {
-        color: red;
-      }
-
-      :focus-visible {
+      [foo="bar"], .bar {
         color: red;
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(13 << 16),
+        ie: Some(10 << 16),
         ..Browsers::default()
       },
     );
 
-    prefix_test(
+    test(
       r#"
-      :hover, :focus-visible {
-        margin-inline-start: 24px;
+      .foo:-moz-read-only {
+        color: red;
       }
-      "#,
+    "#,
       indoc! {r#"
-      :hover:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
-        margin-left: 24px;
+      .foo:-moz-read-only {
+        color: red;
       }
+    "#},
+    );
 
-      :hover:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
-        margin-right: 24px;
+    test(
+      r#"
+      .foo:-moz-read-only {
+        color: red;
       }
 
-      :focus-visible:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
-        margin-left: 24px;
+      .foo:read-only {
+        color: red;
+      }
+    "#,
+      indoc! {r#"
+      .foo:-moz-read-only {
+        color: red;
       }
 
-      :focus-visible:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
-        margin-right: 24px;
+      .foo:read-only {
+        color: red;
       }
-      "#},
-      Browsers {
-        safari: Some(11 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
 
     prefix_test(
       r#"
-      :focus-within, :focus-visible {
+      .foo:-moz-read-only {
         color: red;
       }
-      "#,
-      indoc! {r#"
-      :focus-within {
+
+      .foo:read-only {
         color: red;
       }
-
-      :focus-visible {
+    "#,
+      indoc! {r#"
+      .foo:read-only {
         color: red;
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(9 << 16),
+        firefox: Some(85 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      :hover, :focus-visible {
+      .foo:-moz-read-only {
         color: red;
       }
-      "#,
+
+      .bar {
+        color: yellow;
+      }
+
+      .foo:read-only {
+        color: red;
+      }
+    "#,
       indoc! {r#"
-      :is(:hover, :focus-visible) {
+      .foo:-moz-read-only {
         color: red;
       }
-      "#},
+
+      .bar {
+        color: #ff0;
+      }
+
+      .foo:read-only {
+        color: red;
+      }
+    "#},
       Browsers {
-        safari: Some(14 << 16),
+        firefox: Some(85 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      a::after:hover, a::after:focus-visible {
+      .foo:-moz-read-only {
         color: red;
       }
-      "#,
+
+      .foo:read-only {
+        color: red;
+      }
+    "#,
       indoc! {r#"
-      a:after:hover {
+      .foo:-moz-read-only {
         color: red;
       }
 
-      a:after:focus-visible {
+      .foo:read-only {
         color: red;
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(14 << 16),
+        firefox: Some(36 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      a:not(:hover), a:not(:focus-visible) {
+      .foo:read-only {
         color: red;
       }
-      "#,
+    "#,
       indoc! {r#"
-      :is(a:not(:hover), a:not(:focus-visible)) {
+      .foo:-moz-read-only {
         color: red;
       }
-      "#},
+
+      .foo:read-only {
+        color: red;
+      }
+    "#},
       Browsers {
-        safari: Some(14 << 16),
+        firefox: Some(36 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      a:has(:hover), a:has(:focus-visible) {
+      .foo:-webkit-full-screen {
         color: red;
       }
-      "#,
+      .foo:-moz-full-screen {
+        color: red;
+      }
+      .foo:-ms-fullscreen {
+        color: red;
+      }
+      .foo:fullscreen {
+        color: red;
+      }
+    "#,
       indoc! {r#"
-      :is(a:has(:hover), a:has(:focus-visible)) {
+      .foo:fullscreen {
         color: red;
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(14 << 16),
+        chrome: Some(96 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo.foo:hover, .bar:focus-visible {
+      .foo:fullscreen {
         color: red;
       }
-      "#,
+    "#,
       indoc! {r#"
-      .foo.foo:hover {
+      .foo:-webkit-full-screen {
         color: red;
       }
 
-      .bar:focus-visible {
+      .foo:-moz-full-screen {
         color: red;
       }
-      "#},
+
+      .foo:-ms-fullscreen {
+        color: red;
+      }
+
+      .foo:fullscreen {
+        color: red;
+      }
+    "#},
       Browsers {
-        safari: Some(14 << 16),
+        chrome: Some(45 << 16),
+        firefox: Some(45 << 16),
+        ie: Some(11 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      a::unknown-a, a::unknown-b {
+      .foo::placeholder {
         color: red;
       }
-      "#,
+    "#,
       indoc! {r#"
-      a::unknown-a {
+      .foo::-webkit-input-placeholder {
         color: red;
       }
 
-      a::unknown-b {
+      .foo::-moz-placeholder {
         color: red;
       }
-      "#},
+
+      .foo::-ms-input-placeholder {
+        color: red;
+      }
+
+      .foo::placeholder {
+        color: red;
+      }
+    "#},
       Browsers {
-        safari: Some(14 << 16),
+        chrome: Some(45 << 16),
+        firefox: Some(45 << 16),
+        ie: Some(11 << 16),
         ..Browsers::default()
       },
     );
 
-    nesting_test_with_targets(
+    prefix_test(
       r#"
-      .foo {
-        padding-inline-start: 3px;
-
-        .bar {
-          padding-inline-start: 5px;
-        }
+      .foo::file-selector-button {
+        color: red;
       }
-      "#,
+    "#,
       indoc! {r#"
-      .foo:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
-        padding-left: 3px;
-      }
-
-      .foo:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
-        padding-right: 3px;
+      .foo::-webkit-file-upload-button {
+        color: red;
       }
 
-      .foo .bar:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
-        padding-left: 5px;
+      .foo::-ms-browse {
+        color: red;
       }
 
-      .foo .bar:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
-        padding-right: 5px;
+      .foo::file-selector-button {
+        color: red;
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(12 << 16),
+        chrome: Some(84 << 16),
+        ie: Some(10 << 16),
         ..Browsers::default()
-      }
-      .into(),
+      },
     );
 
     prefix_test(
       r#"
-      .foo::part(header), .foo::part(body) {
-        display: none
+      .foo::file-selector-button {
+        margin-inline-start: 2px;
       }
-      "#,
+    "#,
       indoc! {r#"
-      .foo::part(header), .foo::part(body) {
-        display: none;
+      .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)))::-webkit-file-upload-button {
+        margin-left: 2px;
       }
-      "#},
+
+      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)))::-ms-browse {
+        margin-left: 2px;
+      }
+
+      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)))::file-selector-button {
+        margin-left: 2px;
+      }
+
+      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))::-webkit-file-upload-button {
+        margin-right: 2px;
+      }
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))::-ms-browse {
+        margin-right: 2px;
+      }
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))::file-selector-button {
+        margin-right: 2px;
+      }
+    "#},
       Browsers {
-        safari: Some(14 << 16),
+        chrome: Some(84 << 16),
+        ie: Some(10 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo :is(.bar) {
-        color: red;
-      }
+      .foo:placeholder-shown .bar { color: red; }
+      .foo:autofill .baz { color: red; }
       "#,
       indoc! {r#"
-        .foo .bar {
-          color: red;
-        }
+      .foo:placeholder-shown .bar {
+        color: red;
+      }
+
+      .foo:-webkit-autofill .baz {
+        color: red;
+      }
+
+      .foo:autofill .baz {
+        color: red;
+      }
       "#},
       Browsers {
-        chrome: Some(87 << 16),
+        chrome: Some(103 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo :is(.bar), .bar :is(.baz) {
-        color: red;
-      }
+      .foo:placeholder-shown .bar,.foo:autofill .baz{color:red}
       "#,
       indoc! {r#"
-        .foo .bar, .bar .baz {
-          color: red;
-        }
+      :-webkit-any(.foo:placeholder-shown .bar, .foo:-webkit-autofill .baz) {
+        color: red;
+      }
+
+      :is(.foo:placeholder-shown .bar, .foo:autofill .baz) {
+        color: red;
+      }
       "#},
       Browsers {
-        chrome: Some(87 << 16),
+        chrome: Some(103 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo :is(.bar:focus-visible), .bar :is(.baz:hover) {
+      .foo:placeholder-shown .bar, .foo:-webkit-autofill .baz {
+        color: red;
+      }
+
+      .foo:placeholder-shown .bar, .foo:autofill .baz {
         color: red;
       }
       "#,
       indoc! {r#"
-        .bar .baz:hover {
-          color: red;
-        }
+      :-webkit-any(.foo:placeholder-shown .bar, .foo:-webkit-autofill .baz) {
+        color: red;
+      }
 
-        .foo .bar:focus-visible {
-          color: red;
-        }
+      :is(.foo:placeholder-shown .bar, .foo:autofill .baz) {
+        color: red;
+      }
       "#},
       Browsers {
-        chrome: Some(85 << 16),
+        chrome: Some(103 << 16),
         ..Browsers::default()
       },
     );
 
-    prefix_test(
+    test(
       r#"
-      *,
-      ::before,
-      ::after,
-      ::backdrop {
-        padding: 5px;
+      .foo:placeholder-shown .bar, .foo:-webkit-autofill .baz {
+        color: red;
       }
-      "#,
-      indoc! {r#"
-        *, :before, :after {
-          padding: 5px;
-        }
 
-        ::-webkit-backdrop {
-          padding: 5px;
-        }
+      .foo:placeholder-shown .bar, .foo:autofill .baz {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+      .foo:placeholder-shown .bar, .foo:-webkit-autofill .baz {
+        color: red;
+      }
 
-        ::backdrop {
-          padding: 5px;
-        }
+      .foo:placeholder-shown .bar, .foo:autofill .baz {
+        color: red;
+      }
       "#},
-      Browsers {
-        chrome: Some(33 << 16),
-        ..Browsers::default()
-      },
     );
 
-    test(
+    prefix_test(
       r#"
-      .foo:-webkit-any(.bar, .baz):after {
-        color: red;
-      }
-
-      .foo:is(.bar, .baz):after {
+      :hover, :focus-visible {
         color: red;
       }
       "#,
       indoc! {r#"
-        .foo:-webkit-any(.bar, .baz):after {
-          color: red;
-        }
+      :hover {
+        color: red;
+      }
 
-        .foo:is(.bar, .baz):after {
-          color: red;
-        }
+      :focus-visible {
+        color: red;
+      }
       "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
     );
 
     prefix_test(
       r#"
-      .foo:-webkit-any(.bar, .baz):after {
+      .foo {
         color: red;
       }
 
-      .foo:is(.bar, .baz):after {
+      :hover, :focus-visible {
         color: red;
       }
       "#,
       indoc! {r#"
-        .foo:is(.bar, .baz):after {
-          color: red;
-        }
+      .foo, :hover {
+        color: red;
+      }
+
+      :focus-visible {
+        color: red;
+      }
       "#},
       Browsers {
-        safari: Some(16 << 16),
+        safari: Some(13 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo:-webkit-any(.bar):after {
-        color: red;
+      :hover, :focus-visible {
+        margin-inline-start: 24px;
+      }
+      "#,
+      indoc! {r#"
+      :hover:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        margin-left: 24px;
       }
 
-      .foo:is(.bar, .baz):after {
+      :hover:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
+        margin-right: 24px;
+      }
+
+      :focus-visible:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        margin-left: 24px;
+      }
+
+      :focus-visible:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
+        margin-right: 24px;
+      }
+      "#},
+      Browsers {
+        safari: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      :focus-within, :focus-visible {
         color: red;
       }
       "#,
       indoc! {r#"
-        .foo:-webkit-any(.bar):after {
-          color: red;
-        }
+      :focus-within {
+        color: red;
+      }
 
-        .foo:is(.bar, .baz):after {
-          color: red;
-        }
+      :focus-visible {
+        color: red;
+      }
       "#},
       Browsers {
-        safari: Some(16 << 16),
+        safari: Some(9 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo:-webkit-any(.bar, .baz):after {
+      :hover, :focus-visible {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+      :is(:hover, :focus-visible) {
         color: red;
       }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      .foo:is(.bar, .baz):after {
+    prefix_test(
+      r#"
+      a::after:hover, a::after:focus-visible {
         color: red;
       }
       "#,
       indoc! {r#"
-        .foo:-webkit-any(.bar, .baz):after {
-          color: red;
-        }
+      a:after:hover {
+        color: red;
+      }
 
-        .foo:is(.bar, .baz):after {
-          color: red;
-        }
+      a:after:focus-visible {
+        color: red;
+      }
       "#},
       Browsers {
-        safari: Some(12 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo:-webkit-any(.bar, .baz):after {
+      a:not(:hover), a:not(:focus-visible) {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+      :is(a:not(:hover), a:not(:focus-visible)) {
         color: red;
       }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      .foo:-moz-any(.bar, .baz):after {
+    prefix_test(
+      r#"
+      a:has(:hover), a:has(:focus-visible) {
         color: red;
       }
       "#,
       indoc! {r#"
-        .foo:-webkit-any(.bar, .baz):after {
-          color: red;
-        }
-
-        .foo:-moz-any(.bar, .baz):after {
-          color: red;
-        }
+      :is(a:has(:hover), a:has(:focus-visible)) {
+        color: red;
+      }
       "#},
       Browsers {
-        safari: Some(12 << 16),
-        firefox: Some(67 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .a {
-        padding-inline: var(--foo);
-      }
-
-      .a:-webkit-any(.b, .c) {
-        padding-inline: var(--foo);
+      .foo.foo:hover, .bar:focus-visible {
+        color: red;
       }
       "#,
       indoc! {r#"
-        .a {
-          padding-inline: var(--foo);
-        }
+      .foo.foo:hover {
+        color: red;
+      }
 
-        .a:-webkit-any(.b, .c) {
-          padding-inline: var(--foo);
-        }
+      .bar:focus-visible {
+        color: red;
+      }
       "#},
       Browsers {
-        safari: Some(12 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
-  }
 
-  #[test]
-  fn test_merge_media_rules() {
-    test(
+    prefix_test(
       r#"
-      @media (hover) {
-        .foo {
-          color: red;
-        }
-      }
-      @media (hover) {
-        .foo {
-          background: #fff;
-        }
-
-        .baz {
-          color: #fff;
-        }
+      a::unknown-a, a::unknown-b {
+        color: red;
       }
       "#,
       indoc! {r#"
-      @media (hover) {
-        .foo {
-          color: red;
-          background: #fff;
-        }
+      a::unknown-a {
+        color: red;
+      }
 
-        .baz {
-          color: #fff;
-        }
+      a::unknown-b {
+        color: red;
       }
-    "#},
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    nesting_test_with_targets(
       r#"
-      @media (hover) {
-        .foo {
-          color: red;
-        }
-      }
-      @media (min-width: 250px) {
-        .foo {
-          background: #fff;
-        }
+      .foo {
+        padding-inline-start: 3px;
 
-        .baz {
-          color: #fff;
+        .bar {
+          padding-inline-start: 5px;
         }
       }
       "#,
       indoc! {r#"
-      @media (hover) {
-        .foo {
-          color: red;
-        }
+      .foo:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        padding-left: 3px;
       }
 
-      @media (width >= 250px) {
-        .foo {
-          background: #fff;
-        }
+      .foo:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
+        padding-right: 3px;
+      }
 
-        .baz {
-          color: #fff;
-        }
+      .foo .bar:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        padding-left: 5px;
       }
-    "#},
+
+      .foo .bar:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
+        padding-right: 5px;
+      }
+      "#},
+      Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      }
+      .into(),
     );
-  }
 
-  #[test]
-  fn test_merge_supports() {
-    test(
+    prefix_test(
       r#"
-      @supports (flex: 1) {
-        .foo {
+      .foo::part(header), .foo::part(body) {
+        display: none
+      }
+      "#,
+      indoc! {r#"
+      .foo::part(header), .foo::part(body) {
+        display: none;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo :is(.bar) {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+        .foo .bar {
           color: red;
         }
+      "#},
+      Browsers {
+        chrome: Some(87 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo :is(.bar), .bar :is(.baz) {
+        color: red;
       }
-      @supports (flex: 1) {
-        .foo {
-          background: #fff;
+      "#,
+      indoc! {r#"
+        .foo .bar, .bar .baz {
+          color: red;
         }
+      "#},
+      Browsers {
+        chrome: Some(87 << 16),
+        ..Browsers::default()
+      },
+    );
 
-        .baz {
-          color: #fff;
-        }
+    prefix_test(
+      r#"
+      .foo :is(.bar:focus-visible), .bar :is(.baz:hover) {
+        color: red;
       }
       "#,
       indoc! {r#"
-      @supports (flex: 1) {
-        .foo {
+        .bar .baz:hover {
           color: red;
-          background: #fff;
         }
 
-        .baz {
-          color: #fff;
+        .foo .bar:focus-visible {
+          color: red;
         }
+      "#},
+      Browsers {
+        chrome: Some(85 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      *,
+      ::before,
+      ::after,
+      ::backdrop {
+        padding: 5px;
       }
-    "#},
+      "#,
+      indoc! {r#"
+        *, :before, :after {
+          padding: 5px;
+        }
+
+        ::-webkit-backdrop {
+          padding: 5px;
+        }
+
+        ::backdrop {
+          padding: 5px;
+        }
+      "#},
+      Browsers {
+        chrome: Some(33 << 16),
+        ..Browsers::default()
+      },
     );
 
     test(
       r#"
-      @supports (flex: 1) {
-        .foo {
+      .foo:-webkit-any(.bar, .baz):after {
+        color: red;
+      }
+
+      .foo:is(.bar, .baz):after {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+        .foo:-webkit-any(.bar, .baz):after {
           color: red;
         }
-      }
-      @supports (display: grid) {
-        .foo {
-          background: #fff;
+
+        .foo:is(.bar, .baz):after {
+          color: red;
         }
+      "#},
+    );
 
-        .baz {
-          color: #fff;
+    prefix_test(
+      r#"
+      .foo:-webkit-any(.bar, .baz):after {
+        color: red;
+      }
+
+      .foo:is(.bar, .baz):after {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+        .foo:is(.bar, .baz):after {
+          color: red;
         }
+      "#},
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo:-webkit-any(.bar):after {
+        color: red;
+      }
+
+      .foo:is(.bar, .baz):after {
+        color: red;
       }
       "#,
       indoc! {r#"
-      @supports (flex: 1) {
-        .foo {
+        .foo:-webkit-any(.bar):after {
+          color: red;
+        }
+
+        .foo:is(.bar, .baz):after {
           color: red;
         }
+      "#},
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo:-webkit-any(.bar, .baz):after {
+        color: red;
       }
 
-      @supports (display: grid) {
-        .foo {
-          background: #fff;
+      .foo:is(.bar, .baz):after {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+        .foo:-webkit-any(.bar, .baz):after {
+          color: red;
         }
 
-        .baz {
-          color: #fff;
+        .foo:is(.bar, .baz):after {
+          color: red;
         }
+      "#},
+      Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo:-webkit-any(.bar, .baz):after {
+        color: red;
       }
-    "#},
+
+      .foo:-moz-any(.bar, .baz):after {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+        .foo:-webkit-any(.bar, .baz):after {
+          color: red;
+        }
+
+        .foo:-moz-any(.bar, .baz):after {
+          color: red;
+        }
+      "#},
+      Browsers {
+        safari: Some(12 << 16),
+        firefox: Some(67 << 16),
+        ..Browsers::default()
+      },
     );
-  }
 
-  #[test]
-  fn test_opacity() {
-    minify_test(".foo { opacity: 0 }", ".foo{opacity:0}");
-    minify_test(".foo { opacity: 0% }", ".foo{opacity:0}");
-    minify_test(".foo { opacity: 0.5 }", ".foo{opacity:.5}");
-    minify_test(".foo { opacity: 50% }", ".foo{opacity:.5}");
-    minify_test(".foo { opacity: 1 }", ".foo{opacity:1}");
-    minify_test(".foo { opacity: 100% }", ".foo{opacity:1}");
-  }
+    prefix_test(
+      r#"
+      .a {
+        padding-inline: var(--foo);
+      }
 
-  #[test]
-  fn test_transitions() {
-    minify_test(".foo { transition-duration: 500ms }", ".foo{transition-duration:.5s}");
-    minify_test(".foo { transition-duration: .5s }", ".foo{transition-duration:.5s}");
-    minify_test(".foo { transition-duration: 99ms }", ".foo{transition-duration:99ms}");
-    minify_test(".foo { transition-duration: .099s }", ".foo{transition-duration:99ms}");
-    minify_test(".foo { transition-duration: 2000ms }", ".foo{transition-duration:2s}");
-    minify_test(".foo { transition-duration: 2s }", ".foo{transition-duration:2s}");
-    minify_test(
-      ".foo { transition-duration: calc(1s - 50ms) }",
-      ".foo{transition-duration:.95s}",
+      .a:-webkit-any(.b, .c) {
+        padding-inline: var(--foo);
+      }
+      "#,
+      indoc! {r#"
+        .a {
+          padding-inline: var(--foo);
+        }
+
+        .a:-webkit-any(.b, .c) {
+          padding-inline: var(--foo);
+        }
+      "#},
+      Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      },
     );
+
     minify_test(
-      ".foo { transition-duration: calc(1s - 50ms + 2s) }",
-      ".foo{transition-duration:2.95s}",
+      ".a.b { color: red } .b.a { background: blue }",
+      ".a.b{color:red;background:#00f}",
     );
     minify_test(
-      ".foo { transition-duration: calc((1s - 50ms) * 2) }",
-      ".foo{transition-duration:1.9s}",
+      ".a, .b[foo] { color: red } .b[foo], .a { background: blue }",
+      ".a,.b[foo]{color:red;background:#00f}",
     );
     minify_test(
-      ".foo { transition-duration: calc(2 * (1s - 50ms)) }",
-      ".foo{transition-duration:1.9s}",
+      ".a.b#c { color: red } .foo { color: green } #c.b.a { color: blue }",
+      ".foo{color:green}#c.b.a{color:#00f}",
     );
     minify_test(
-      ".foo { transition-duration: calc((2s + 50ms) - (1s - 50ms)) }",
-      ".foo{transition-duration:1.1s}",
+      ".a .b { color: red } .b .a { background: blue }",
+      ".a .b{color:red}.b .a{background:#00f}",
     );
     minify_test(
-      ".foo { transition-duration: 500ms, 50ms }",
-      ".foo{transition-duration:.5s,50ms}",
+      ".a:hover.b { color: red } .b:focus.a { background: blue }",
+      ".a:hover.b{color:red}.b:focus.a{background:#00f}",
     );
-    minify_test(".foo { transition-delay: 500ms }", ".foo{transition-delay:.5s}");
-    minify_test(
-      ".foo { transition-property: background }",
-      ".foo{transition-property:background}",
+  }
+
+  #[test]
+  fn test_restructure_rules() {
+    fn restructure_test(source: &str, expected: &str) {
+      minify_test_with_minify_options(
+        source,
+        expected,
+        MinifyOptions {
+          merge_rules: MergeRules::Restructure,
+          ..MinifyOptions::default()
+        },
+      );
+    }
+
+    restructure_test(
+      ".a { color: red } .b { margin: 0 } .a { background: green }",
+      ".a{color:red;background:green}.b{margin:0}",
     );
-    minify_test(
-      ".foo { transition-property: background, opacity }",
-      ".foo{transition-property:background,opacity}",
+    restructure_test(
+      ".a { color: red } .b { margin: 0 } .c { color: red }",
+      ".a,.c{color:red}.b{margin:0}",
     );
-    minify_test(
-      ".foo { transition-timing-function: linear }",
-      ".foo{transition-timing-function:linear}",
+    restructure_test(
+      ".a { color: red } .b { color: blue !important } .a { background: green }",
+      ".a{color:red;background:green}.b{color:#00f!important}",
     );
-    minify_test(
-      ".foo { transition-timing-function: ease }",
-      ".foo{transition-timing-function:ease}",
+    restructure_test(
+      "@media print { .a { color: red } .b { margin: 0 } .a { background: green } }",
+      "@media print{.a{color:red;background:green}.b{margin:0}}",
     );
-    minify_test(
-      ".foo { transition-timing-function: ease-in }",
-      ".foo{transition-timing-function:ease-in}",
+    restructure_test(
+      ".a { color: red; background: green } .b { color: blue } .a { color: green }",
+      ".a{color:red;background:green}.b{color:#00f}.a{color:green}",
     );
-    minify_test(
-      ".foo { transition-timing-function: ease-out }",
-      ".foo{transition-timing-function:ease-out}",
+    restructure_test(
+      ".a { color: red } .b { margin: 1px } .a { margin-top: 0 }",
+      ".a{color:red}.b{margin:1px}.a{margin-top:0}",
     );
-    minify_test(
-      ".foo { transition-timing-function: ease-in-out }",
-      ".foo{transition-timing-function:ease-in-out}",
+    restructure_test(
+      ".a { color: red } .b { margin-left: 1px } .a { margin-inline-start: 0 }",
+      ".a{color:red}.b{margin-left:1px}.a{margin-inline-start:0}",
     );
-    minify_test(
-      ".foo { transition-timing-function: cubic-bezier(0.25, 0.1, 0.25, 1) }",
-      ".foo{transition-timing-function:ease}",
+    restructure_test(
+      ".a { color: red } .b { foo: bar } .a { background: green }",
+      ".a{color:red}.b{foo:bar}.a{background:green}",
     );
-    minify_test(
-      ".foo { transition-timing-function: cubic-bezier(0.42, 0, 1, 1) }",
-      ".foo{transition-timing-function:ease-in}",
+    restructure_test(
+      ".a { color: red } @media print { .b { margin: 0 } } .a { background: green }",
+      ".a{color:red}@media print{.b{margin:0}}.a{background:green}",
     );
-    minify_test(
-      ".foo { transition-timing-function: cubic-bezier(0, 0, 0.58, 1) }",
-      ".foo{transition-timing-function:ease-out}",
+    restructure_test(
+      ".a { color: red } .b { margin: 0; .c { background: red } } .a { background: green }",
+      ".a{color:red}.b{margin:0;& .c{background:red}}.a{background:green}",
     );
-    minify_test(
-      ".foo { transition-timing-function: cubic-bezier(0.42, 0, 0.58, 1) }",
-      ".foo{transition-timing-function:ease-in-out}",
+
+    restructure_test(
+      "@media print { .a { color: red } } .b { margin: 0 } @media print { .c { color: red } }",
+      "@media print{.a,.c{color:red}}.b{margin:0}",
     );
-    minify_test(
-      ".foo { transition-timing-function: cubic-bezier(0.58, 0.2, 0.11, 1.2) }",
-      ".foo{transition-timing-function:cubic-bezier(.58,.2,.11,1.2)}",
+    restructure_test(
+      "@media (min-width: 100px) { .a { color: red } } .b { margin: 0 } @media (width >= 100px) { .c { background: red } }",
+      "@media (width>=100px){.a{color:red}.c{background:red}}.b{margin:0}",
     );
-    minify_test(
-      ".foo { transition-timing-function: step-start }",
-      ".foo{transition-timing-function:step-start}",
+    restructure_test(
+      "@media print { .a { color: red } } .b { color: blue } @media print { .c { color: green } }",
+      "@media print{.a{color:red}}.b{color:#00f}@media print{.c{color:green}}",
     );
-    minify_test(
-      ".foo { transition-timing-function: step-end }",
-      ".foo{transition-timing-function:step-end}",
+    restructure_test(
+      "@media print { .a { color: red } } @font-face { font-family: foo } @media print { .c { margin: 0 } }",
+      "@media print{.a{color:red}}@font-face{font-family:foo}@media print{.c{margin:0}}",
     );
+
     minify_test(
-      ".foo { transition-timing-function: steps(1, start) }",
-      ".foo{transition-timing-function:step-start}",
+      ".a { color: red } .b { margin: 0 } .a { background: green }",
+      ".a{color:red}.b{margin:0}.a{background:green}",
     );
     minify_test(
-      ".foo { transition-timing-function: steps(1, jump-start) }",
-      ".foo{transition-timing-function:step-start}",
+      "@media print { .a { color: red } } .b { margin: 0 } @media print { .c { color: red } }",
+      "@media print{.a{color:red}}.b{margin:0}@media print{.c{color:red}}",
     );
-    minify_test(
-      ".foo { transition-timing-function: steps(1, end) }",
-      ".foo{transition-timing-function:step-end}",
+  }
+
+  #[test]
+  fn test_hoist_shared_declarations() {
+    fn hoist_test(source: &str, expected: &str) {
+      minify_test_with_minify_options(
+        source,
+        expected,
+        MinifyOptions {
+          merge_rules: MergeRules::Aggressive,
+          ..MinifyOptions::default()
+        },
+      );
+    }
+
+    hoist_test(
+      ".a { color: red; margin: 0 } .b { color: red; padding: 0 } .c { color: red; width: 0 }",
+      ".a,.b,.c{color:red}.a{margin:0}.b{padding:0}.c{width:0}",
     );
-    minify_test(
-      ".foo { transition-timing-function: steps(1, jump-end) }",
-      ".foo{transition-timing-function:step-end}",
+    hoist_test(
+      ".a { color: red; margin: 0 } .x { width: 0 } .b { color: red; padding: 0 }",
+      ".a,.b{color:red}.a{margin:0}.x{width:0}.b{padding:0}",
     );
-    minify_test(
-      ".foo { transition-timing-function: steps(5, jump-start) }",
-      ".foo{transition-timing-function:steps(5,start)}",
+    hoist_test(
+      ".a { color: red } .x { width: 0 } .b { color: red; padding: 0 }",
+      ".a,.b{color:red}.x{width:0}.b{padding:0}",
     );
-    minify_test(
-      ".foo { transition-timing-function: steps(5, jump-end) }",
-      ".foo{transition-timing-function:steps(5,end)}",
+    hoist_test(
+      ".a { top: 0; margin: 0 } .b { top: 0; padding: 0 }",
+      ".a{margin:0;top:0}.b{padding:0;top:0}",
     );
-    minify_test(
-      ".foo { transition-timing-function: steps(5, jump-both) }",
-      ".foo{transition-timing-function:steps(5,jump-both)}",
+    hoist_test(
+      ".a { color: red; margin: 0 } .x { color: blue } .b { color: red; padding: 0 }",
+      ".a{color:red;margin:0}.x{color:#00f}.b{color:red;padding:0}",
     );
-    minify_test(
-      ".foo { transition-timing-function: ease-in-out, cubic-bezier(0.42, 0, 1, 1) }",
-      ".foo{transition-timing-function:ease-in-out,ease-in}",
+    hoist_test(
+      ".a { color: red; margin: 0 } @media print { .x { width: 0 } } .b { color: red; padding: 0 }",
+      ".a{color:red;margin:0}@media print{.x{width:0}}.b{color:red;padding:0}",
     );
+
     minify_test(
-      ".foo { transition-timing-function: cubic-bezier(0.42, 0, 1, 1), cubic-bezier(0.58, 0.2, 0.11, 1.2) }",
-      ".foo{transition-timing-function:ease-in,cubic-bezier(.58,.2,.11,1.2)}",
+      ".a { color: red; margin: 0 } .b { color: red; padding: 0 } .c { color: red; width: 0 }",
+      ".a{color:red;margin:0}.b{color:red;padding:0}.c{color:red;width:0}",
     );
-    minify_test(
-      ".foo { transition-timing-function: step-start, steps(5, jump-start) }",
-      ".foo{transition-timing-function:step-start,steps(5,start)}",
+  }
+
+  #[test]
+  fn test_remove_overridden_declarations() {
+    fn overridden_test(source: &str, expected: &str) {
+      minify_test_with_minify_options(
+        source,
+        expected,
+        MinifyOptions {
+          remove_overridden_declarations: true,
+          ..MinifyOptions::default()
+        },
+      );
+    }
+
+    overridden_test(
+      ".a { color: red; margin: 0 } .b { color: blue } .a { color: green }",
+      ".a{margin:0}.b{color:#00f}.a{color:green}",
     );
-    minify_test(".foo { transition: width 2s ease }", ".foo{transition:width 2s}");
-    minify_test(
-      ".foo { transition: width 2s ease, height 1000ms cubic-bezier(0.25, 0.1, 0.25, 1) }",
-      ".foo{transition:width 2s,height 1s}",
+    overridden_test(
+      ".a { color: red; margin-top: 1px } .b { color: blue } .a { color: green; margin: 0 }",
+      ".b{color:#00f}.a{color:green;margin:0}",
     );
-    minify_test(".foo { transition: width 2s 1s }", ".foo{transition:width 2s 1s}");
-    minify_test(".foo { transition: width 2s ease 1s }", ".foo{transition:width 2s 1s}");
+    overridden_test(
+      ".a.b { color: red } .c { color: blue } .b.a { color: green }",
+      ".c{color:#00f}.b.a{color:green}",
+    );
+    overridden_test(
+      ".a { color: red } .b { color: blue } .a, .c { color: green }",
+      ".b{color:#00f}.a,.c{color:green}",
+    );
+    overridden_test(
+      ".a, .c { color: red } .b { color: blue } .a { color: green }",
+      ".a,.c{color:red}.b{color:#00f}.a{color:green}",
+    );
+    overridden_test(
+      ".a { color: red !important } .b { color: blue } .a { color: green }",
+      ".a{color:red!important}.b{color:#00f}.a{color:green}",
+    );
+    overridden_test(
+      ".a { color: red } .b { color: blue } .a { color: green !important }",
+      ".b{color:#00f}.a{color:green!important}",
+    );
+    overridden_test(
+      ".a { margin-top: 1px; color: red } .b { color: blue } .a { margin: 0 }",
+      ".a{color:red}.b{color:#00f}.a{margin:0}",
+    );
+    overridden_test(
+      ".a { margin: 1px } .b { color: blue } .a { margin-top: 0 }",
+      ".a{margin:1px}.b{color:#00f}.a{margin-top:0}",
+    );
+    overridden_test(
+      ".a { color: red; margin: 0 } .b { color: blue } .a { color: var(--foo) }",
+      ".a{color:red;margin:0}.b{color:#00f}.a{color:var(--foo)}",
+    );
+    overridden_test(
+      ".a { foo: bar; margin: 0 } .b { color: blue } .a { foo: baz }",
+      ".a{foo:bar;margin:0}.b{color:#00f}.a{foo:baz}",
+    );
+    overridden_test(
+      ".a { color: red } @media print { .a { color: green } }",
+      ".a{color:red}@media print{.a{color:green}}",
+    );
+    overridden_test(
+      "@media print { .a { color: red } .b { color: blue } .a { color: green } }",
+      "@media print{.b{color:#00f}.a{color:green}}",
+    );
+
     minify_test(
-      ".foo { transition: ease-in 1s width 4s }",
-      ".foo{transition:width 1s ease-in 4s}",
+      ".a { color: red; margin: 0 } .b { color: blue } .a { color: green }",
+      ".a{color:red;margin:0}.b{color:#00f}.a{color:green}",
     );
-    minify_test(".foo { transition: opacity 0s .6s }", ".foo{transition:opacity 0s .6s}");
+  }
+
+  #[test]
+  fn test_merge_media_rules() {
     test(
       r#"
-      .foo {
-        transition-property: opacity;
-        transition-duration: 0.09s;
-        transition-timing-function: ease-in-out;
-        transition-delay: 500ms;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        transition: opacity 90ms ease-in-out .5s;
+      @media (hover) {
+        .foo {
+          color: red;
+        }
       }
-    "#},
-    );
-    test(
-      r#"
-      .foo {
-        transition: opacity 2s;
-        transition-timing-function: ease;
-        transition-delay: 500ms;
+      @media (hover) {
+        .foo {
+          background: #fff;
+        }
+
+        .baz {
+          color: #fff;
+        }
       }
-    "#,
+      "#,
       indoc! {r#"
-      .foo {
-        transition: opacity 2s .5s;
+      @media (hover) {
+        .foo {
+          color: red;
+          background: #fff;
+        }
+
+        .baz {
+          color: #fff;
+        }
       }
     "#},
     );
+
     test(
       r#"
-      .foo {
-        transition: opacity 500ms;
-        transition-timing-function: var(--ease);
+      @media (hover) {
+        .foo {
+          color: red;
+        }
       }
-    "#,
+      @media (min-width: 250px) {
+        .foo {
+          background: #fff;
+        }
+
+        .baz {
+          color: #fff;
+        }
+      }
+      "#,
       indoc! {r#"
-      .foo {
-        transition: opacity .5s;
-        transition-timing-function: var(--ease);
+      @media (hover) {
+        .foo {
+          color: red;
+        }
+      }
+
+      @media (width >= 250px) {
+        .foo {
+          background: #fff;
+        }
+
+        .baz {
+          color: #fff;
+        }
       }
     "#},
     );
+
+    minify_test(
+      "@media print { .a { color: red } @media print { .b { color: green } } }",
+      "@media print{.a{color:red}.b{color:green}}",
+    );
+    minify_test(
+      "@media print { @media print { .a { color: red } } }",
+      "@media print{.a{color:red}}",
+    );
+    minify_test(
+      "@media print { @media (min-width: 100px) { .a { color: red } } }",
+      "@media print{@media (width>=100px){.a{color:red}}}",
+    );
+  }
+
+  #[test]
+  fn test_merge_supports() {
     test(
       r#"
-      .foo {
-        transition-property: opacity;
-        transition-duration: 0.09s;
-        transition-timing-function: ease-in-out;
-        transition-delay: 500ms;
-        transition: color 2s;
+      @supports (flex: 1) {
+        .foo {
+          color: red;
+        }
       }
-    "#,
-      indoc! {r#"
-      .foo {
-        transition: color 2s;
+      @supports (flex: 1) {
+        .foo {
+          background: #fff;
+        }
+
+        .baz {
+          color: #fff;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @supports (flex: 1) {
+        .foo {
+          color: red;
+          background: #fff;
+        }
+
+        .baz {
+          color: #fff;
+        }
       }
     "#},
     );
+
     test(
       r#"
-      .foo {
-        transition-property: opacity, color;
-        transition-duration: 2s, 4s;
-        transition-timing-function: ease-in-out, ease-in;
-        transition-delay: 500ms, 0s;
+      @supports (flex: 1) {
+        .foo {
+          color: red;
+        }
       }
-    "#,
+      @supports (display: grid) {
+        .foo {
+          background: #fff;
+        }
+
+        .baz {
+          color: #fff;
+        }
+      }
+      "#,
       indoc! {r#"
-      .foo {
-        transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+      @supports (flex: 1) {
+        .foo {
+          color: red;
+        }
+      }
+
+      @supports (display: grid) {
+        .foo {
+          background: #fff;
+        }
+
+        .baz {
+          color: #fff;
+        }
       }
     "#},
     );
+  }
+
+  #[test]
+  fn test_opacity() {
+    minify_test(".foo { opacity: 0 }", ".foo{opacity:0}");
+    minify_test(".foo { opacity: 0% }", ".foo{opacity:0}");
+    minify_test(".foo { opacity: 0.5 }", ".foo{opacity:.5}");
+    minify_test(".foo { opacity: 50% }", ".foo{opacity:.5}");
+    minify_test(".foo { opacity: 1 }", ".foo{opacity:1}");
+    minify_test(".foo { opacity: 100% }", ".foo{opacity:1}");
+  }
+
+  #[test]
+  fn test_transitions() {
+    minify_test(".foo { transition-duration: 500ms }", ".foo{transition-duration:.5s}");
+    minify_test(".foo { transition-duration: .5s }", ".foo{transition-duration:.5s}");
+    minify_test(".foo { transition-duration: 99ms }", ".foo{transition-duration:99ms}");
+    minify_test(".foo { transition-duration: .099s }", ".foo{transition-duration:99ms}");
+    minify_test(".foo { transition-duration: 2000ms }", ".foo{transition-duration:2s}");
+    minify_test(".foo { transition-duration: 2s }", ".foo{transition-duration:2s}");
+    minify_test(
+      ".foo { transition-duration: calc(1s - 50ms) }",
+      ".foo{transition-duration:.95s}",
+    );
+    minify_test(
+      ".foo { transition-duration: calc(1s - 50ms + 2s) }",
+      ".foo{transition-duration:2.95s}",
+    );
+    minify_test(
+      ".foo { transition-duration: calc((1s - 50ms) * 2) }",
+      ".foo{transition-duration:1.9s}",
+    );
+    minify_test(
+      ".foo { transition-duration: calc(2 * (1s - 50ms)) }",
+      ".foo{transition-duration:1.9s}",
+    );
+    minify_test(
+      ".foo { transition-duration: calc((2s + 50ms) - (1s - 50ms)) }",
+      ".foo{transition-duration:1.1s}",
+    );
+    minify_test(
+      ".foo { transition-duration: 500ms, 50ms }",
+      ".foo{transition-duration:.5s,50ms}",
+    );
+    minify_test(".foo { transition-delay: 500ms }", ".foo{transition-delay:.5s}");
+    minify_test(
+      ".foo { transition-property: background }",
+      ".foo{transition-property:background}",
+    );
+    minify_test(
+      ".foo { transition-property: background, opacity }",
+      ".foo{transition-property:background,opacity}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: linear }",
+      ".foo{transition-timing-function:linear}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: ease }",
+      ".foo{transition-timing-function:ease}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: ease-in }",
+      ".foo{transition-timing-function:ease-in}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: ease-out }",
+      ".foo{transition-timing-function:ease-out}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: ease-in-out }",
+      ".foo{transition-timing-function:ease-in-out}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: cubic-bezier(0.25, 0.1, 0.25, 1) }",
+      ".foo{transition-timing-function:ease}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: cubic-bezier(0.42, 0, 1, 1) }",
+      ".foo{transition-timing-function:ease-in}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: cubic-bezier(0, 0, 0.58, 1) }",
+      ".foo{transition-timing-function:ease-out}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: cubic-bezier(0.42, 0, 0.58, 1) }",
+      ".foo{transition-timing-function:ease-in-out}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: cubic-bezier(0.58, 0.2, 0.11, 1.2) }",
+      ".foo{transition-timing-function:cubic-bezier(.58,.2,.11,1.2)}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: step-start }",
+      ".foo{transition-timing-function:step-start}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: step-end }",
+      ".foo{transition-timing-function:step-end}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: steps(1, start) }",
+      ".foo{transition-timing-function:step-start}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: steps(1, jump-start) }",
+      ".foo{transition-timing-function:step-start}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: steps(1, end) }",
+      ".foo{transition-timing-function:step-end}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: steps(1, jump-end) }",
+      ".foo{transition-timing-function:step-end}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: steps(5, jump-start) }",
+      ".foo{transition-timing-function:steps(5,start)}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: steps(5, jump-end) }",
+      ".foo{transition-timing-function:steps(5,end)}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: steps(5, jump-both) }",
+      ".foo{transition-timing-function:steps(5,jump-both)}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: ease-in-out, cubic-bezier(0.42, 0, 1, 1) }",
+      ".foo{transition-timing-function:ease-in-out,ease-in}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: cubic-bezier(0.42, 0, 1, 1), cubic-bezier(0.58, 0.2, 0.11, 1.2) }",
+      ".foo{transition-timing-function:ease-in,cubic-bezier(.58,.2,.11,1.2)}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: step-start, steps(5, jump-start) }",
+      ".foo{transition-timing-function:step-start,steps(5,start)}",
+    );
+    minify_test(".foo { transition: width 2s ease }", ".foo{transition:width 2s}");
+    minify_test(
+      ".foo { transition: width 2s ease, height 1000ms cubic-bezier(0.25, 0.1, 0.25, 1) }",
+      ".foo{transition:width 2s,height 1s}",
+    );
+    minify_test(".foo { transition: width 2s 1s }", ".foo{transition:width 2s 1s}");
+    minify_test(".foo { transition: width 2s ease 1s }", ".foo{transition:width 2s 1s}");
+    minify_test(
+      ".foo { transition: ease-in 1s width 4s }",
+      ".foo{transition:width 1s ease-in 4s}",
+    );
+    minify_test(".foo { transition: opacity 0s .6s }", ".foo{transition:opacity 0s .6s}");
     test(
       r#"
       .foo {
-        transition-property: opacity, color;
-        transition-duration: 2s;
+        transition-property: opacity;
+        transition-duration: 0.09s;
         transition-timing-function: ease-in-out;
         transition-delay: 500ms;
       }
     "#,
       indoc! {r#"
       .foo {
-        transition: opacity 2s ease-in-out .5s, color 2s ease-in-out .5s;
+        transition: opacity 90ms ease-in-out .5s;
       }
     "#},
     );
     test(
       r#"
       .foo {
-        transition-property: opacity, color, width, height;
-        transition-duration: 2s, 4s;
+        transition: opacity 2s;
         transition-timing-function: ease;
-        transition-delay: 0s;
+        transition-delay: 500ms;
       }
     "#,
       indoc! {r#"
       .foo {
-        transition: opacity 2s, color 4s, width 2s, height 4s;
+        transition: opacity 2s .5s;
       }
     "#},
     );
-
     test(
       r#"
       .foo {
-        -webkit-transition-property: opacity, color;
-        -webkit-transition-duration: 2s, 4s;
-        -webkit-transition-timing-function: ease-in-out, ease-in;
-        -webkit-transition-delay: 500ms, 0s;
+        transition: opacity 500ms;
+        transition-timing-function: var(--ease);
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+        transition: opacity .5s;
+        transition-timing-function: var(--ease);
       }
     "#},
     );
-
     test(
       r#"
       .foo {
-        -webkit-transition-property: opacity, color;
-        -webkit-transition-duration: 2s, 4s;
-        -webkit-transition-timing-function: ease-in-out, ease-in;
-        -webkit-transition-delay: 500ms, 0s;
-        -moz-transition-property: opacity, color;
-        -moz-transition-duration: 2s, 4s;
-        -moz-transition-timing-function: ease-in-out, ease-in;
-        -moz-transition-delay: 500ms, 0s;
-        transition-property: opacity, color;
-        transition-duration: 2s, 4s;
-        transition-timing-function: ease-in-out, ease-in;
-        transition-delay: 500ms, 0s;
+        transition-property: opacity;
+        transition-duration: 0.09s;
+        transition-timing-function: ease-in-out;
+        transition-delay: 500ms;
+        transition: color 2s;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-transition: opacity 2s ease-in-out .5s, color 4s ease-in;
-        -moz-transition: opacity 2s ease-in-out .5s, color 4s ease-in;
-        transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+        transition: color 2s;
+      }
+    "#},
+    );
+    test(
+      r#"
+      .foo {
+        transition-property: opacity, color;
+        transition-duration: 2s, 4s;
+        transition-timing-function: ease-in-out, ease-in;
+        transition-delay: 500ms, 0s;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+      }
+    "#},
+    );
+    test(
+      r#"
+      .foo {
+        transition-property: opacity, color;
+        transition-duration: 2s;
+        transition-timing-function: ease-in-out;
+        transition-delay: 500ms;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transition: opacity 2s ease-in-out .5s, color 2s ease-in-out .5s;
+      }
+    "#},
+    );
+    test(
+      r#"
+      .foo {
+        transition-property: opacity, color, width, height;
+        transition-duration: 2s, 4s;
+        transition-timing-function: ease;
+        transition-delay: 0s;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transition: opacity 2s, color 4s, width 2s, height 4s;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        -webkit-transition-property: opacity, color;
+        -webkit-transition-duration: 2s, 4s;
+        -webkit-transition-timing-function: ease-in-out, ease-in;
+        -webkit-transition-delay: 500ms, 0s;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        -webkit-transition-property: opacity, color;
+        -webkit-transition-duration: 2s, 4s;
+        -webkit-transition-timing-function: ease-in-out, ease-in;
+        -webkit-transition-delay: 500ms, 0s;
+        -moz-transition-property: opacity, color;
+        -moz-transition-duration: 2s, 4s;
+        -moz-transition-timing-function: ease-in-out, ease-in;
+        -moz-transition-delay: 500ms, 0s;
+        transition-property: opacity, color;
+        transition-duration: 2s, 4s;
+        transition-timing-function: ease-in-out, ease-in;
+        transition-delay: 500ms, 0s;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+        -moz-transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+        transition: opacity 2s ease-in-out .5s, color 4s ease-in;
       }
     "#},
     );
@@ -11283,6 +12240,26 @@ mod tests {
     );
     minify_test(".foo { animation: foo 3s auto }", ".foo{animation:3s foo}");
     minify_test(".foo { animation-composition: add }", ".foo{animation-composition:add}");
+    minify_test(
+      ".foo { animation-composition: add, replace }",
+      ".foo{animation-composition:add,replace}",
+    );
+    minify_test(
+      ".foo { animation-composition: add; animation: foo 3s }",
+      ".foo{animation:3s foo;animation-composition:add}",
+    );
+    minify_test(
+      ".foo { animation: foo 3s; animation-composition: add; animation-duration: 2s }",
+      ".foo{animation:2s foo;animation-composition:add}",
+    );
+    minify_test(
+      ".foo { animation-composition: add; animation-composition: accumulate }",
+      ".foo{animation-composition:accumulate}",
+    );
+    minify_test(
+      ".foo { animation-composition: add; animation: var(--anim) }",
+      ".foo{animation-composition:add;animation:var(--anim)}",
+    );
     test(
       r#"
       .foo {
@@ -11809,10 +12786,132 @@ mod tests {
     minify_test(".foo { scale: 1 0 1 }", ".foo{scale:1 0}");
     minify_test(".foo { scale: 1 0 0 }", ".foo{scale:1 0 0}");
 
-    // TODO: Re-enable with a better solution
-    //       See: https://github.com/parcel-bundler/lightningcss/issues/288
-    // minify_test(".foo { transform: scale(3); scale: 0.5 }", ".foo{transform:scale(1.5)}");
-    minify_test(".foo { scale: 0.5; transform: scale(3); }", ".foo{transform:scale(3)}");
+    // The individual transform properties are independent of transform.
+    // See: https://github.com/parcel-bundler/lightningcss/issues/288
+    minify_test(
+      ".foo { transform: scale(3); scale: 0.5 }",
+      ".foo{transform:scale(3);scale:.5}",
+    );
+    minify_test(
+      ".foo { scale: 0.5; transform: scale(3); }",
+      ".foo{transform:scale(3);scale:.5}",
+    );
+    minify_test(
+      ".foo { translate: 10px; translate: 20px; rotate: 45deg }",
+      ".foo{translate:20px;rotate:45deg}",
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        transform: skewX(10deg);
+        scale: 2;
+        rotate: 45deg;
+        translate: 10px 20px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transform: translate(10px, 20px)rotate(45deg)scale(2)skewX(10deg);
+      }
+    "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        translate: 10px;
+        rotate: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transform: translate(10px);
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        transform: rotate(10deg);
+        scale: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transform: rotate(10deg);
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        translate: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        translate: none;
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        transform: skewX(10deg);
+        translate: 10px 20px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transform: skewX(10deg);
+        translate: 10px 20px;
+      }
+    "#},
+      Browsers {
+        chrome: Some(110 << 16),
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        transform: skewX(10deg);
+        rotate: 45deg;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-transform: rotate(45deg)skewX(10deg);
+        transform: rotate(45deg)skewX(10deg);
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
 
     prefix_test(
       r#"
@@ -12946,6 +14045,89 @@ mod tests {
       },
     );
     minify_test(".foo { font-palette: --Custom; }", ".foo{font-palette:--Custom}");
+    minify_test(".foo { font-palette: normal; }", ".foo{font-palette:normal}");
+    minify_test(".foo { font-palette: LIGHT; }", ".foo{font-palette:light}");
+    minify_test(".foo { font-palette: dark; }", ".foo{font-palette:dark}");
+    minify_test(".foo { font-palette: foo; }", ".foo{font-palette:foo}");
+    minify_test(
+      "@font-palette-values --Cooler { font-family: Bixa; override-colors: 0 rgb(255, 0, 0), 1 hsl(240, 100%, 50%); }",
+      "@font-palette-values --Cooler{font-family:Bixa;override-colors:0 red,1 #00f}",
+    );
+  }
+
+  #[test]
+  fn test_color_profile() {
+    minify_test(
+      r#"@color-profile --swop5c {
+      src: url("https://example.org/SWOP2006_Coated5v2.icc");
+      rendering-intent: perceptual;
+      components: cyan, magenta, yellow, black;
+    }"#,
+      "@color-profile --swop5c{src:url(https://example.org/SWOP2006_Coated5v2.icc);rendering-intent:perceptual;components:cyan,magenta,yellow,black}",
+    );
+    minify_test(
+      "@color-profile device-cmyk { src: url(cmyk.icc) }",
+      "@color-profile device-cmyk{src:url(cmyk.icc)}",
+    );
+    minify_test(
+      "@color-profile --foo { rendering-intent: perceptual; rendering-intent: saturation }",
+      "@color-profile --foo{rendering-intent:saturation}",
+    );
+    minify_test(
+      "@color-profile --foo { src: url(foo.icc); foo: bar }",
+      "@color-profile --foo{src:url(foo.icc)}",
+    );
+    minify_test(
+      "@color-profile --foo { rendering-intent: var(--intent) }",
+      "@color-profile --foo{rendering-intent:var(--intent)}",
+    );
+    test(
+      "@color-profile --foo { src: url(foo.icc); rendering-intent: relative-colorimetric }",
+      indoc! {r#"
+      @color-profile --foo {
+        src: url("foo.icc");
+        rendering-intent: relative-colorimetric;
+      }
+      "#},
+    );
+    error_test(
+      "@color-profile foo { src: url(foo.icc) }",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("foo".into())),
+    );
+
+    minify_test(
+      ".foo { color: color(--swop5c 0% 70.0% 20.00% .0); }",
+      ".foo{color:color(--swop5c 0 .7 .2 0)}",
+    );
+    minify_test(
+      ".foo { color: color(--swop5c 0 0.7 0.2 0 / 50%); }",
+      ".foo{color:color(--swop5c 0 .7 .2 0/.5)}",
+    );
+    minify_test(
+      ".foo { color: color(--swop5c 0 none 0.2 0 / 1); }",
+      ".foo{color:color(--swop5c 0 none .2 0)}",
+    );
+    minify_test(
+      ".foo { color: color-mix(in srgb, color(--swop5c 0 0.7 0.2 0), red); }",
+      ".foo{color:color-mix(in srgb,color(--swop5c 0 .7 .2 0),red)}",
+    );
+    prefix_test(
+      ".foo { color: color(--swop5c 0 0.7 0.2 0); }",
+      indoc! {r#"
+      .foo {
+        color: color(--swop5c 0 .7 .2 0);
+      }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+    minify_test(".foo { color: color(--swop5c); }", ".foo{color:color(--swop5c)}");
+    minify_test(
+      ".foo { color: color(from red --swop5c r g b); }",
+      ".foo{color:color(from red --swop5c r g b)}",
+    );
   }
 
   #[test]
@@ -13199,6 +14381,26 @@ mod tests {
       }
     "#},
     );
+    minify_test(
+      "@supports font-format(WOFF2) { .test { foo: bar } }",
+      "@supports font-format(woff2){.test{foo:bar}}",
+    );
+    minify_test(
+      "@supports font-tech(color-COLRv1) { .test { foo: bar } }",
+      "@supports font-tech(color-colrv1){.test{foo:bar}}",
+    );
+    minify_test(
+      "@supports font-format(\"woff2\") { .test { foo: bar } }",
+      "@supports font-format(woff2){.test{foo:bar}}",
+    );
+    minify_test(
+      "@supports font-format(\"foo\") { .test { foo: bar } }",
+      "@supports font-format(\"foo\"){.test{foo:bar}}",
+    );
+    minify_test(
+      "@supports font-tech(foo) { .test { foo: bar } }",
+      "@supports font-tech(foo){.test{foo:bar}}",
+    );
     test(
       r#"
       @supports unknown(test) {
@@ -13370,10 +14572,55 @@ mod tests {
       }
     "#},
     );
-  }
-
-  #[test]
-  fn test_namespace() {
+    minify_test(
+      r#"@counter-style foo { system: fixed 1; symbols: "a" "b" "c"; suffix: ") "; }"#,
+      r#"@counter-style foo{system:fixed;symbols:a b c;suffix:") "}"#,
+    );
+    minify_test(
+      r#"@counter-style foo { system: fixed 3; symbols: "1" "2" url(a.png) "inherit"; }"#,
+      r#"@counter-style foo{system:fixed 3;symbols:"1""2"url(a.png)"inherit"}"#,
+    );
+    minify_test(
+      r#"@counter-style foo { system: cyclic; symbols: a; system: numeric; symbols: "0" "1"; }"#,
+      r#"@counter-style foo{system:numeric;symbols:"0""1"}"#,
+    );
+    minify_test(
+      r#"@counter-style foo { system: additive; symbols: a b; additive-symbols: 10 X, "V" 5, 1 I; negative: "(" ")"; pad: "0" 3; }"#,
+      r#"@counter-style foo{system:additive;additive-symbols:10 X,5 V,1 I;negative:"("")";pad:3 "0"}"#,
+    );
+    minify_test(
+      "@counter-style foo { system: extends decimal; range: 1 10, infinite -1; fallback: lower-alpha; speak-as: spell-out; }",
+      "@counter-style foo{system:extends decimal;range:1 10,infinite -1;fallback:lower-alpha;speak-as:spell-out}",
+    );
+    minify_test(
+      "@counter-style foo { system: extends bar; speak-as: baz; prefix: 'x'; }",
+      "@counter-style foo{system:extends bar;speak-as:baz;prefix:x}",
+    );
+    minify_test(
+      "@counter-style foo { symbols: a; range: 10 1; additive-symbols: 1 a, 2 b; fallback: none; }",
+      "@counter-style foo{symbols:a;range:10 1;additive-symbols:1 a,2 b;fallback:none}",
+    );
+    minify_test(
+      "@counter-style foo { symbols: a; unknown: foo; }",
+      "@counter-style foo{symbols:a}",
+    );
+    error_test(
+      "@counter-style foo { system: alphabetic; symbols: a; }",
+      ParserError::AtRuleBodyInvalid,
+    );
+    error_test(
+      "@counter-style foo { system: additive; }",
+      ParserError::AtRuleBodyInvalid,
+    );
+    error_test(
+      "@counter-style foo { system: extends decimal; symbols: a; }",
+      ParserError::AtRuleBodyInvalid,
+    );
+    error_test("@counter-style foo { system: cyclic; }", ParserError::AtRuleBodyInvalid);
+  }
+
+  #[test]
+  fn test_namespace() {
     minify_test(
       "@namespace url(http://toto.example.org);",
       "@namespace \"http://toto.example.org\";",
@@ -13763,6 +15010,55 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_reading_flow() {
+    minify_test(".foo { reading-flow: normal }", ".foo{reading-flow:normal}");
+    minify_test(".foo { reading-flow: Grid-Rows }", ".foo{reading-flow:grid-rows}");
+    minify_test(".foo { reading-flow: flex-visual }", ".foo{reading-flow:flex-visual}");
+    minify_test(".foo { reading-flow: source-order }", ".foo{reading-flow:source-order}");
+    minify_test(".foo { reading-flow: grid-order }", ".foo{reading-flow:grid-order}");
+    minify_test(".foo { reading-flow: rows }", ".foo{reading-flow:rows}");
+    minify_test(".foo { reading-order: 2 }", ".foo{reading-order:2}");
+    minify_test(".foo { reading-order: -1 }", ".foo{reading-order:-1}");
+    minify_test(".foo { reading-order: 1.5 }", ".foo{reading-order:1.5}");
+
+    let property = Property::parse_string("reading-flow".into(), "grid-rows", ParserOptions::default()).unwrap();
+    assert!(matches!(
+      property,
+      Property::ReadingFlow(crate::properties::display::ReadingFlow::GridRows)
+    ));
+    assert_eq!(
+      property.is_compatible(Browsers {
+        chrome: Some(137 << 16),
+        ..Browsers::default()
+      }),
+      Some(true)
+    );
+    assert_eq!(
+      property.is_compatible(Browsers {
+        chrome: Some(130 << 16),
+        ..Browsers::default()
+      }),
+      Some(false)
+    );
+    assert_eq!(
+      property.is_compatible(Browsers {
+        safari: Some(18 << 16),
+        ..Browsers::default()
+      }),
+      Some(false)
+    );
+
+    let property = Property::parse_string("reading-order".into(), "1", ParserOptions::default()).unwrap();
+    assert_eq!(
+      property.is_compatible(Browsers {
+        firefox: Some(130 << 16),
+        ..Browsers::default()
+      }),
+      Some(false)
+    );
+  }
+
   #[test]
   fn test_visibility() {
     minify_test(".foo { visibility: visible }", ".foo{visibility:visible}");
@@ -13809,6 +15105,52 @@ mod tests {
     minify_test(".foo { white-space: break-spaces }", ".foo{white-space:break-spaces}");
     minify_test(".foo { white-space: pre-line }", ".foo{white-space:pre-line}");
     minify_test(".foo { white-space: NoWrAp }", ".foo{white-space:nowrap}");
+    minify_test(".foo { white-space: collapse }", ".foo{white-space:normal}");
+    minify_test(".foo { white-space: wrap }", ".foo{white-space:normal}");
+    minify_test(".foo { white-space: collapse nowrap }", ".foo{white-space:nowrap}");
+    minify_test(".foo { white-space: nowrap preserve }", ".foo{white-space:pre}");
+    minify_test(".foo { white-space: preserve wrap }", ".foo{white-space:pre-wrap}");
+    minify_test(".foo { white-space: preserve-breaks }", ".foo{white-space:pre-line}");
+    minify_test(
+      ".foo { white-space: break-spaces wrap }",
+      ".foo{white-space:break-spaces}",
+    );
+    minify_test(".foo { white-space: discard }", ".foo{white-space:discard}");
+    minify_test(
+      ".foo { white-space: preserve-spaces nowrap }",
+      ".foo{white-space:preserve-spaces nowrap}",
+    );
+    minify_test(".foo { white-space: pre nowrap }", ".foo{white-space:pre nowrap}");
+
+    minify_test(
+      ".foo { white-space-collapse: preserve }",
+      ".foo{white-space-collapse:preserve}",
+    );
+    minify_test(".foo { text-wrap-mode: nowrap }", ".foo{text-wrap-mode:nowrap}");
+    minify_test(
+      ".foo { white-space-collapse: preserve; text-wrap-mode: nowrap }",
+      ".foo{white-space:pre}",
+    );
+    minify_test(
+      ".foo { white-space: pre; text-wrap-mode: wrap }",
+      ".foo{white-space:pre-wrap}",
+    );
+    minify_test(
+      ".foo { white-space: nowrap; white-space-collapse: preserve-spaces }",
+      ".foo{white-space:preserve-spaces nowrap}",
+    );
+    minify_test(
+      ".foo { white-space: nowrap; text-wrap: balance }",
+      ".foo{white-space:nowrap;text-wrap:balance}",
+    );
+    minify_test(
+      ".foo { text-wrap: balance; white-space: nowrap }",
+      ".foo{text-wrap:balance;white-space:nowrap}",
+    );
+    minify_test(
+      ".foo { white-space: pre; white-space-collapse: var(--x) }",
+      ".foo{white-space:pre;white-space-collapse:var(--x)}",
+    );
   }
 
   #[test]
@@ -15577,6 +16919,153 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_overflow_clip_margin() {
+    minify_test(".foo { overflow-clip-margin: 20px }", ".foo{overflow-clip-margin:20px}");
+    minify_test(".foo { overflow-clip-margin: 0px }", ".foo{overflow-clip-margin:0}");
+    minify_test(
+      ".foo { overflow-clip-margin: padding-box }",
+      ".foo{overflow-clip-margin:0}",
+    );
+    minify_test(
+      ".foo { overflow-clip-margin: padding-box 20px }",
+      ".foo{overflow-clip-margin:20px}",
+    );
+    minify_test(
+      ".foo { overflow-clip-margin: content-box }",
+      ".foo{overflow-clip-margin:content-box}",
+    );
+    minify_test(
+      ".foo { overflow-clip-margin: content-box 0px }",
+      ".foo{overflow-clip-margin:content-box}",
+    );
+    minify_test(
+      ".foo { overflow-clip-margin: 1em border-box }",
+      ".foo{overflow-clip-margin:border-box 1em}",
+    );
+    minify_test(
+      ".foo { overflow-clip-margin: calc(1px + 2px) content-box }",
+      ".foo{overflow-clip-margin:content-box 3px}",
+    );
+    minify_test(
+      ".foo { overflow-clip-margin: -10px }",
+      ".foo{overflow-clip-margin:-10px}",
+    );
+    minify_test(
+      ".foo { overflow-clip-margin: content-box border-box }",
+      ".foo{overflow-clip-margin:content-box border-box}",
+    );
+  }
+
+  #[test]
+  fn test_line_clamp() {
+    minify_test(".foo { line-clamp: none }", ".foo{line-clamp:none}");
+    minify_test(".foo { line-clamp: 3 }", ".foo{line-clamp:3}");
+    minify_test(".foo { line-clamp: 3 auto }", ".foo{line-clamp:3}");
+    minify_test(".foo { line-clamp: no-ellipsis 3 }", ".foo{line-clamp:3 no-ellipsis}");
+    minify_test(".foo { line-clamp: 2 \"…\" }", ".foo{line-clamp:2 \"…\"}");
+    minify_test(".foo { line-clamp: 0 }", ".foo{line-clamp:0}");
+    minify_test(".foo { -webkit-line-clamp: 3 }", ".foo{-webkit-line-clamp:3}");
+    minify_test(
+      ".foo { -webkit-line-clamp: 3; line-clamp: 3 }",
+      ".foo{-webkit-line-clamp:3;line-clamp:3}",
+    );
+    minify_test(
+      ".foo { -webkit-line-clamp: 2; line-clamp: 3 }",
+      ".foo{-webkit-line-clamp:2;line-clamp:3}",
+    );
+    minify_test(".foo { line-clamp: 2; line-clamp: 3 }", ".foo{line-clamp:3}");
+    minify_test(".foo { continue: discard }", ".foo{continue:discard}");
+    minify_test(".foo { continue: COLLAPSE }", ".foo{continue:collapse}");
+
+    prefix_test(
+      ".foo { overflow: hidden; line-clamp: 3 }",
+      indoc! {r#"
+      .foo {
+        overflow: hidden;
+        display: -webkit-box;
+        -webkit-box-orient: vertical;
+        -webkit-line-clamp: 3;
+        line-clamp: 3;
+      }
+      "#},
+      Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { line-clamp: none }",
+      indoc! {r#"
+      .foo {
+        -webkit-line-clamp: none;
+        line-clamp: none;
+      }
+      "#},
+      Browsers {
+        safari: Some(17 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { line-clamp: 3 no-ellipsis }",
+      indoc! {r#"
+      .foo {
+        line-clamp: 3 no-ellipsis;
+      }
+      "#},
+      Browsers {
+        firefox: Some(120 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { display: -webkit-box; -webkit-box-orient: vertical; -webkit-line-clamp: 3 }",
+      indoc! {r#"
+      .foo {
+        -webkit-box-orient: vertical;
+        display: -webkit-box;
+        -webkit-line-clamp: 3;
+      }
+      "#},
+      Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    let targets = Targets {
+      browsers: Some(Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
+      }),
+      exclude: Features::LineClamp,
+      ..Targets::default()
+    };
+    minify_test_with_minify_options(
+      ".foo { line-clamp: 3 }",
+      ".foo{line-clamp:3}",
+      MinifyOptions {
+        targets,
+        ..MinifyOptions::default()
+      },
+    );
+
+    // Legacy blocks are normalized to the standard property when all targets support it.
+    let targets = Targets {
+      browsers: Some(Browsers::default()),
+      ..Targets::default()
+    };
+    minify_test_with_minify_options(
+      ".foo { display: -webkit-box; -webkit-box-orient: vertical; -webkit-line-clamp: 3; overflow: hidden }",
+      ".foo{overflow:hidden;line-clamp:3}",
+      MinifyOptions {
+        targets,
+        ..MinifyOptions::default()
+      },
+    );
+  }
+
   #[test]
   fn test_ui() {
     minify_test(".foo { resize: both }", ".foo{resize:both}");
@@ -15601,6 +17090,21 @@ mod tests {
     minify_test(".foo { caret: auto }", ".foo{caret:auto}");
     minify_test(".foo { caret: yellow auto }", ".foo{caret:#ff0}");
     minify_test(".foo { caret: auto block }", ".foo{caret:block}");
+    minify_test(".foo { caret-color: yellow; caret-shape: bar }", ".foo{caret:#ff0 bar}");
+    minify_test(".foo { caret-shape: bar; caret-color: auto }", ".foo{caret:bar}");
+    minify_test(
+      ".foo { caret: yellow bar; caret-shape: block }",
+      ".foo{caret:#ff0 block}",
+    );
+    minify_test(".foo { caret: yellow; caret-color: red }", ".foo{caret:red}");
+    minify_test(
+      ".foo { caret-shape: bar; caret-shape: block }",
+      ".foo{caret-shape:block}",
+    );
+    minify_test(
+      ".foo { caret: yellow bar; caret-color: var(--foo) }",
+      ".foo{caret:#ff0 bar;caret-color:var(--foo)}",
+    );
     minify_test(".foo { user-select: none }", ".foo{user-select:none}");
     minify_test(".foo { -webkit-user-select: none }", ".foo{-webkit-user-select:none}");
     minify_test(".foo { accent-color: auto }", ".foo{accent-color:auto}");
@@ -15610,6 +17114,13 @@ mod tests {
       ".foo { -webkit-appearance: textfield }",
       ".foo{-webkit-appearance:textfield}",
     );
+    minify_test(".foo { field-sizing: content }", ".foo{field-sizing:content}");
+    minify_test(".foo { field-sizing: FIXED }", ".foo{field-sizing:fixed}");
+    minify_test(".foo { field-sizing: auto }", ".foo{field-sizing:auto}");
+    minify_test(
+      ".foo { field-sizing: var(--sizing) }",
+      ".foo{field-sizing:var(--sizing)}",
+    );
 
     prefix_test(
       r#"
@@ -15769,9 +17280,10 @@ mod tests {
       ".foo { caret: lch(50.998% 135.363 338) block }",
       indoc! { r#"
         .foo {
-          caret: #ee00be block;
-          caret: color(display-p3 .972962 -.362078 .804206) block;
-          caret: lch(50.998% 135.363 338) block;
+          caret-color: #ee00be;
+          caret-color: color(display-p3 .972962 -.362078 .804206);
+          caret-color: lch(50.998% 135.363 338);
+          caret-shape: block;
         }
       "#},
       Browsers {
@@ -15781,6 +17293,48 @@ mod tests {
       },
     );
 
+    prefix_test(
+      ".foo { caret-color: yellow; caret-shape: bar }",
+      indoc! { r#"
+        .foo {
+          caret: #ff0 bar;
+        }
+      "#},
+      Browsers {
+        chrome: Some(134 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { caret-color: yellow; caret-shape: bar }",
+      indoc! { r#"
+        .foo {
+          caret-color: #ff0;
+          caret-shape: bar;
+        }
+      "#},
+      Browsers {
+        chrome: Some(134 << 16),
+        safari: Some(17 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { caret: yellow bar }",
+      indoc! { r#"
+        .foo {
+          caret-color: #ff0;
+          caret-shape: bar;
+        }
+      "#},
+      Browsers {
+        firefox: Some(120 << 16),
+        ..Browsers::default()
+      },
+    );
+
     prefix_test(
       ".foo { caret: lch(50.998% 135.363 338) var(--foo) }",
       indoc! { r#"
@@ -15801,6 +17355,84 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_scrollbar() {
+    minify_test(".foo { scrollbar-width: thin }", ".foo{scrollbar-width:thin}");
+    minify_test(".foo { scrollbar-width: NONE }", ".foo{scrollbar-width:none}");
+    minify_test(".foo { scrollbar-color: auto }", ".foo{scrollbar-color:auto}");
+    minify_test(
+      ".foo { scrollbar-color: #ff0000 rgb(0, 0, 255) }",
+      ".foo{scrollbar-color:red #00f}",
+    );
+    minify_test(".foo { scrollbar-color: red }", ".foo{scrollbar-color:red}");
+
+    // Without the feature flag, no ::-webkit-scrollbar rules are generated.
+    prefix_test(
+      ".foo { scrollbar-width: thin; scrollbar-color: red blue }",
+      indoc! {r#"
+      .foo {
+        scrollbar-width: thin;
+        scrollbar-color: red #00f;
+      }
+      "#},
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    fn scrollbar_test(source: &str, expected: &str, browsers: Option<Browsers>) {
+      minify_test_with_minify_options(
+        source,
+        expected,
+        MinifyOptions {
+          targets: Targets {
+            browsers,
+            include: Features::WebKitScrollbar,
+            ..Targets::default()
+          },
+          ..MinifyOptions::default()
+        },
+      );
+    }
+
+    scrollbar_test(
+      ".foo { scrollbar-width: thin; scrollbar-color: red blue }",
+      ".foo{scrollbar-width:thin;scrollbar-color:red #00f}.foo::-webkit-scrollbar{width:8px;height:8px}.foo::-webkit-scrollbar-thumb{background-color:red}.foo::-webkit-scrollbar-track{background-color:#00f}",
+      Some(Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      }),
+    );
+    scrollbar_test(
+      ".foo, .bar::before { scrollbar-width: none }",
+      ".foo,.bar:before{scrollbar-width:none}.foo::-webkit-scrollbar{display:none}",
+      None,
+    );
+    scrollbar_test(
+      ".foo { scrollbar-width: thin; scrollbar-color: red blue }",
+      ".foo{scrollbar-width:thin;scrollbar-color:red #00f}.foo::-webkit-scrollbar-thumb{background-color:red}.foo::-webkit-scrollbar-track{background-color:#00f}",
+      Some(Browsers {
+        safari: Some(18 << 16 | 2 << 8),
+        ..Browsers::default()
+      }),
+    );
+    scrollbar_test(
+      ".foo { scrollbar-width: auto; scrollbar-color: auto }",
+      ".foo{scrollbar-width:auto;scrollbar-color:auto}",
+      None,
+    );
+    scrollbar_test(
+      ".foo { scrollbar-width: thin; scrollbar-color: red blue }",
+      ".foo{scrollbar-width:thin;scrollbar-color:red #00f}",
+      Some(Browsers {
+        chrome: Some(121 << 16),
+        firefox: Some(64 << 16),
+        ..Browsers::default()
+      }),
+    );
+  }
+
   #[test]
   fn test_list() {
     minify_test(".foo { list-style-type: disc; }", ".foo{list-style-type:disc}");
@@ -15935,7 +17567,7 @@ mod tests {
     // test image-set(<string>)
     minify_test(
       ".foo { background: image-set(\"foo.png\" 2x, url(bar.png) 1x) }",
-      ".foo{background:image-set(\"foo.png\" 2x,\"bar.png\" 1x)}",
+      ".foo{background:image-set(\"bar.png\" 1x,\"foo.png\" 2x)}",
     );
 
     // test image-set(type(<string>))
@@ -15945,21 +17577,43 @@ mod tests {
     );
     minify_test(
       ".foo { background: image-set('foo.avif' 2x type('image/avif'), url(foo.png)) }",
-      ".foo{background:image-set(\"foo.avif\" 2x type(\"image/avif\"),\"foo.png\" 1x)}",
+      ".foo{background:image-set(\"foo.png\" 1x,\"foo.avif\" 2x type(\"image/avif\"))}",
     );
     minify_test(
       ".foo { background: image-set(url('example.png') 3x type('image/png')) }",
-      ".foo{background:image-set(\"example.png\" 3x type(\"image/png\"))}",
+      ".foo{background:image-set(\"example.png\" 3x)}",
     );
 
     minify_test(
       ".foo { background: image-set(url(example.png) type('image/png') 1x) }",
-      ".foo{background:image-set(\"example.png\" 1x type(\"image/png\"))}",
+      ".foo{background:image-set(\"example.png\" 1x)}",
+    );
+
+    // Options are sorted by resolution, and options that can never be chosen are removed.
+    minify_test(
+      ".foo { background: image-set('a.png' 3x, 'b.png' 1x, 'c.png' 2x) }",
+      ".foo{background:image-set(\"b.png\" 1x,\"c.png\" 2x,\"a.png\" 3x)}",
+    );
+    minify_test(
+      ".foo { background: image-set('a.png' 1x, 'b.png' 1x, 'c.png' 96dpi) }",
+      ".foo{background:image-set(\"a.png\" 1x)}",
+    );
+    minify_test(
+      ".foo { background: image-set('a.avif' type('image/avif'), 'a.webp' type('image/webp'), 'a.png', 'b.png') }",
+      ".foo{background:image-set(\"a.avif\" 1x type(\"image/avif\"),\"a.webp\" 1x type(\"image/webp\"),\"a.png\" 1x)}",
+    );
+    minify_test(
+      ".foo { background: image-set('a.avif' type('image/avif'), 'b.avif' type('image/avif')) }",
+      ".foo{background:image-set(\"a.avif\" 1x type(\"image/avif\"))}",
+    );
+    minify_test(
+      ".foo { background: image-set('a.jpg' 2x type('image/JPEG'), 'a.gif' type('image/gif')) }",
+      ".foo{background:image-set(\"a.gif\" 1x,\"a.jpg\" 2x)}",
     );
 
     minify_test(
       ".foo { background: -webkit-image-set(url(\"foo.png\") 2x, url(bar.png) 1x) }",
-      ".foo{background:-webkit-image-set(url(foo.png) 2x,url(bar.png) 1x)}",
+      ".foo{background:-webkit-image-set(url(bar.png) 1x,url(foo.png) 2x)}",
     );
 
     test(
@@ -15971,27 +17625,46 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        background: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
-        background: image-set("foo.png" 2x, "bar.png" 1x);
+        background: -webkit-image-set(url("bar.png") 1x, url("foo.png") 2x);
+        background: image-set("bar.png" 1x, "foo.png" 2x);
       }
     "#},
     );
 
-    // test image-set(<gradient>)
-    test(
+    // -webkit-image-set() does not support type().
+    prefix_test(
       r#"
       .foo {
-        background: image-set(linear-gradient(cornflowerblue, white) 1x, url("detailed-gradient.png") 3x);
+        background: image-set(url("foo.avif") 2x type("image/avif"), url(foo.png) 2x, url(bar.png) 1x);
       }
     "#,
       indoc! {r#"
       .foo {
-        background: image-set(linear-gradient(#6495ed, #fff) 1x, "detailed-gradient.png" 3x);
+        background: -webkit-image-set(url("bar.png") 1x, url("foo.png") 2x);
+        background: image-set("bar.png" 1x, "foo.avif" 2x type("image/avif"), "foo.png" 2x);
       }
     "#},
+      Browsers {
+        chrome: Some(85 << 16),
+        ..Browsers::default()
+      },
     );
 
-    prefix_test(
+    // test image-set(<gradient>)
+    test(
+      r#"
+      .foo {
+        background: image-set(linear-gradient(cornflowerblue, white) 1x, url("detailed-gradient.png") 3x);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        background: image-set(linear-gradient(#6495ed, #fff) 1x, "detailed-gradient.png" 3x);
+      }
+    "#},
+    );
+
+    prefix_test(
       r#"
       .foo {
         background: image-set(url("foo.png") 2x, url(bar.png) 1x);
@@ -15999,8 +17672,8 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        background: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
-        background: image-set("foo.png" 2x, "bar.png" 1x);
+        background: -webkit-image-set(url("bar.png") 1x, url("foo.png") 2x);
+        background: image-set("bar.png" 1x, "foo.png" 2x);
       }
     "#},
       Browsers {
@@ -16019,8 +17692,8 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        background: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
-        background: image-set("foo.png" 2x, "bar.png" 1x);
+        background: -webkit-image-set(url("bar.png") 1x, url("foo.png") 2x);
+        background: image-set("bar.png" 1x, "foo.png" 2x);
       }
     "#},
       Browsers {
@@ -16037,7 +17710,7 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        background: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
+        background: -webkit-image-set(url("bar.png") 1x, url("foo.png") 2x);
       }
     "#},
       Browsers {
@@ -16071,7 +17744,7 @@ mod tests {
           indoc! {r#"
         .foo {{
           {}: url("foo.png");
-          {}: image-set("foo.png" 2x, "bar.png" 1x);
+          {}: image-set("bar.png" 1x, "foo.png" 2x);
         }}
       "#},
           property, property
@@ -16096,8 +17769,8 @@ mod tests {
         &format!(
           indoc! {r#"
         .foo {{
-          {}: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
-          {}: image-set("foo.png" 2x, "bar.png" 1x);
+          {}: -webkit-image-set(url("bar.png") 1x, url("foo.png") 2x);
+          {}: image-set("bar.png" 1x, "foo.png" 2x);
         }}
       "#},
           property, property
@@ -16110,6 +17783,81 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_paint() {
+    minify_test(
+      ".foo { background-image: paint(checker) }",
+      ".foo{background-image:paint(checker)}",
+    );
+    minify_test(
+      ".foo { background-image: paint( checker , 10px , #ff0000 ) }",
+      ".foo{background-image:paint(checker,10px,red)}",
+    );
+    minify_test(
+      ".foo { background: paint(checker) no-repeat, url(foo.png) }",
+      ".foo{background:paint(checker) no-repeat,url(foo.png)}",
+    );
+    minify_test(
+      ".foo { border-image: paint(ring, var(--width)) 30 }",
+      ".foo{border-image:paint(ring,var(--width)) 30}",
+    );
+    minify_test(".foo { mask-image: paint(shape) }", ".foo{mask-image:paint(shape)}");
+
+    prefix_test(
+      r#"
+      .foo {
+        background-image: url(foo.png);
+        background-image: paint(checker);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        background-image: url("foo.png");
+        background-image: paint(checker);
+      }
+    "#},
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        background-image: url(foo.png);
+        background-image: paint(checker);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        background-image: paint(checker);
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    let stylesheet = StyleSheet::parse(
+      ".foo { background-image: paint(checker, 10px) }",
+      ParserOptions::default(),
+    )
+    .unwrap();
+    let rule = match &stylesheet.rules.0[0] {
+      CssRule::Style(rule) => rule,
+      _ => unreachable!(),
+    };
+    match &rule.declarations.declarations[0] {
+      Property::BackgroundImage(images) => match &images[0] {
+        Image::Paint(paint) => assert_eq!(paint.name.0.as_ref(), "checker"),
+        image => panic!("expected paint(), got {:?}", image),
+      },
+      property => panic!("expected background-image, got {:?}", property),
+    }
+  }
+
   #[test]
   fn test_color() {
     minify_test(".foo { color: yellow }", ".foo{color:#ff0}");
@@ -17009,6 +18757,22 @@ mod tests {
     test("lch(from orchid l 30 h)", "lch(62.7526% 30 326.969)");
     test("lch(from orchid l 30 h)", "lch(62.7526% 30 326.969)");
     test("lch(from peru calc(l * 0.8) c h)", "lch(49.8022% 54.0117 63.6804)");
+    test("lab(from indianred calc(l + 10) a b)", "lab(63.9252% 45.7516 23.1557)");
+    test("lab(from indianred 50 a b)", "lab(50% 45.7516 23.1557)");
+    test("oklch(from red calc(l - 0.1) c h)", "oklch(52.7955% .257683 29.2339)");
+    test("oklch(from red calc(l - 10%) c h)", "oklch(52.7955% .257683 29.2339)");
+    test("oklab(from red 0.5 a b)", "oklab(50% .224863 .125846)");
+    test("hsl(from red h 20 l)", "#966");
+    test("hsl(from red h calc(s - 20) l)", "#e61a1a");
+    test("hwb(from red h 10 b)", "#ff1a1a");
+    minify_test(
+      ".foo { color: oklch(from red calc(l - 10% + 0.1) c h) }",
+      ".foo{color:oklch(from red calc(l - 10% + .1)c h)}",
+    );
+    minify_test(
+      ".foo { color: rgb(from Canvas r g b / .5) }",
+      ".foo{color:rgb(from Canvas r g b/.5)}",
+    );
     test("rgb(from indianred 255 g b)", "rgb(255, 92, 92)");
     test("rgb(from indianred r g b / .5)", "rgba(205, 92, 92, .5)");
     test(
@@ -18885,6 +20649,18 @@ mod tests {
       ".foo { color: color-mix(in srgb, blue, currentColor); }",
       ".foo{color:color-mix(in srgb,blue,currentColor)}",
     );
+    minify_test(
+      ".foo { color: color-mix(in srgb, Canvas, blue); }",
+      ".foo{color:color-mix(in srgb,Canvas,blue)}",
+    );
+    minify_test(
+      ".foo { color: color-mix(in oklch, blue 20%, ButtonText); }",
+      ".foo{color:color-mix(in oklch,blue 20%,ButtonText)}",
+    );
+    minify_test(
+      ".foo { color: color-mix(in srgb, light-dark(Canvas, red), blue); }",
+      ".foo{color:color-mix(in srgb,light-dark(canvas,red),blue)}",
+    );
 
     // regex for converting web platform tests:
     // test_computed_value\(.*?, `(.*?)`, `(.*?)`\);
@@ -22185,5485 +23961,9815 @@ mod tests {
         }
       "#},
     );
-
-    nesting_test(
-      r#"
-        .foo .bar {
-          &h1 .baz {
-            background: green;
-          }
-        }
-      "#,
-      indoc! {r#"
-        h1:is(.foo .bar) .baz {
-          background: green;
-        }
-      "#},
+
+    nesting_test(
+      r#"
+        .foo .bar {
+          &h1 .baz {
+            background: green;
+          }
+        }
+      "#,
+      indoc! {r#"
+        h1:is(.foo .bar) .baz {
+          background: green;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo .bar {
+          &.baz {
+            background: green;
+          }
+        }
+      "#,
+      indoc! {r#"
+        .foo .bar.baz {
+          background: green;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo {
+          color: red;
+          @nest .parent & {
+            color: blue;
+          }
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          color: red;
+        }
+
+        .parent .foo {
+          color: #00f;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo {
+          color: red;
+          @nest :not(&) {
+            color: blue;
+          }
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          color: red;
+        }
+
+        :not(.foo) {
+          color: #00f;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo {
+          color: blue;
+          @nest .bar & {
+            color: red;
+            &.baz {
+              color: green;
+            }
+          }
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          color: #00f;
+        }
+
+        .bar .foo {
+          color: red;
+        }
+
+        .bar .foo.baz {
+          color: green;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo {
+          @nest :not(&) {
+            color: red;
+          }
+
+          & h1 {
+            background: green;
+          }
+        }
+      "#,
+      indoc! {r#"
+        :not(.foo) {
+          color: red;
+        }
+
+        .foo h1 {
+          background: green;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo {
+          & h1 {
+            background: green;
+          }
+
+          @nest :not(&) {
+            color: red;
+          }
+        }
+      "#,
+      indoc! {r#"
+        .foo h1 {
+          background: green;
+        }
+
+        :not(.foo) {
+          color: red;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo .bar {
+          @nest h1& {
+            background: green;
+          }
+        }
+      "#,
+      indoc! {r#"
+        h1:is(.foo .bar) {
+          background: green;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        @namespace "http://example.com/foo";
+        @namespace toto "http://toto.example.org";
+
+        div {
+          @nest .foo& {
+            color: red;
+          }
+        }
+
+        * {
+          @nest .foo& {
+            color: red;
+          }
+        }
+
+        |x {
+          @nest .foo& {
+            color: red;
+          }
+        }
+
+        *|x {
+          @nest .foo& {
+            color: red;
+          }
+        }
+
+        toto|x {
+          @nest .foo& {
+            color: red;
+          }
+        }
+      "#,
+      indoc! {r#"
+        @namespace "http://example.com/foo";
+        @namespace toto "http://toto.example.org";
+
+        .foo:is(div) {
+          color: red;
+        }
+
+        .foo:is(*) {
+          color: red;
+        }
+
+        .foo:is(|x) {
+          color: red;
+        }
+
+        .foo:is(*|x) {
+          color: red;
+        }
+
+        .foo:is(toto|x) {
+          color: red;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo .bar {
+          @nest h1 .baz& {
+            background: green;
+          }
+        }
+      "#,
+      indoc! {r#"
+        h1 .baz:is(.foo .bar) {
+          background: green;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo .bar {
+          @nest .baz& {
+            background: green;
+          }
+        }
+      "#,
+      indoc! {r#"
+        .baz:is(.foo .bar) {
+          background: green;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo .bar {
+          @nest .baz & {
+            background: green;
+          }
+        }
+      "#,
+      indoc! {r#"
+        .baz :is(.foo .bar) {
+          background: green;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo {
+          color: red;
+          @nest & > .bar {
+            color: blue;
+          }
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          color: red;
+        }
+
+        .foo > .bar {
+          color: #00f;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+      .foo {
+        color: red;
+        .bar {
+          color: blue;
+        }
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        color: red;
+      }
+
+      .foo .bar {
+        color: #00f;
+      }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+      .foo {
+        color: red;
+        .bar & {
+          color: blue;
+        }
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        color: red;
+      }
+
+      .bar .foo {
+        color: #00f;
+      }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+      .foo {
+        color: red;
+        + .bar + & { color: blue; }
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        color: red;
+      }
+
+      .foo + .bar + .foo {
+        color: #00f;
+      }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+      .foo {
+        color: red;
+        .bar & {
+          color: blue;
+        }
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        color: red;
+      }
+
+      .bar .foo {
+        color: #00f;
+      }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo {
+          color: red;
+          .parent & {
+            color: blue;
+          }
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          color: red;
+        }
+
+        .parent .foo {
+          color: #00f;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo {
+          color: red;
+          :not(&) {
+            color: blue;
+          }
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          color: red;
+        }
+
+        :not(.foo) {
+          color: #00f;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo {
+          color: blue;
+          .bar & {
+            color: red;
+            &.baz {
+              color: green;
+            }
+          }
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          color: #00f;
+        }
+
+        .bar .foo {
+          color: red;
+        }
+
+        .bar .foo.baz {
+          color: green;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo {
+          :not(&) {
+            color: red;
+          }
+
+          & h1 {
+            background: green;
+          }
+        }
+      "#,
+      indoc! {r#"
+        :not(.foo) {
+          color: red;
+        }
+
+        .foo h1 {
+          background: green;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo {
+          & h1 {
+            background: green;
+          }
+
+          :not(&) {
+            color: red;
+          }
+        }
+      "#,
+      indoc! {r#"
+        .foo h1 {
+          background: green;
+        }
+
+        :not(.foo) {
+          color: red;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo .bar {
+          :is(h1)& {
+            background: green;
+          }
+        }
+      "#,
+      indoc! {r#"
+        :is(h1):is(.foo .bar) {
+          background: green;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        @namespace "http://example.com/foo";
+        @namespace toto "http://toto.example.org";
+
+        div {
+          .foo& {
+            color: red;
+          }
+        }
+
+        * {
+          .foo& {
+            color: red;
+          }
+        }
+
+        |x {
+          .foo& {
+            color: red;
+          }
+        }
+
+        *|x {
+          .foo& {
+            color: red;
+          }
+        }
+
+        toto|x {
+          .foo& {
+            color: red;
+          }
+        }
+      "#,
+      indoc! {r#"
+        @namespace "http://example.com/foo";
+        @namespace toto "http://toto.example.org";
+
+        .foo:is(div) {
+          color: red;
+        }
+
+        .foo:is(*) {
+          color: red;
+        }
+
+        .foo:is(|x) {
+          color: red;
+        }
+
+        .foo:is(*|x) {
+          color: red;
+        }
+
+        .foo:is(toto|x) {
+          color: red;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo .bar {
+          :is(h1) .baz& {
+            background: green;
+          }
+        }
+      "#,
+      indoc! {r#"
+        :is(h1) .baz:is(.foo .bar) {
+          background: green;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo .bar {
+          .baz& {
+            background: green;
+          }
+        }
+      "#,
+      indoc! {r#"
+        .baz:is(.foo .bar) {
+          background: green;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo .bar {
+          .baz & {
+            background: green;
+          }
+        }
+      "#,
+      indoc! {r#"
+        .baz :is(.foo .bar) {
+          background: green;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo {
+          .bar {
+            color: blue;
+          }
+          color: red;
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          color: red;
+        }
+
+        .foo .bar {
+          color: #00f;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        article {
+          color: green;
+          & { color: blue; }
+          color: red;
+        }
+      "#,
+      indoc! {r#"
+        article {
+          color: red;
+        }
+
+        article {
+          color: #00f;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        & .foo {
+          color: red;
+        }
+      "#,
+      indoc! {r#"
+        :scope .foo {
+          color: red;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        &.foo {
+          color: red;
+        }
+      "#,
+      indoc! {r#"
+        :scope.foo {
+          color: red;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo& {
+          color: red;
+        }
+      "#,
+      indoc! {r#"
+        .foo:scope {
+          color: red;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        &html {
+          color: red;
+        }
+      "#,
+      indoc! {r#"
+        html:scope {
+          color: red;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo {
+          color: blue;
+          div {
+            color: red;
+          }
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          color: #00f;
+        }
+
+        .foo div {
+          color: red;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        div {
+          color: blue;
+
+          button:focus {
+            color: red;
+          }
+        }
+      "#,
+      indoc! {r#"
+        div {
+          color: #00f;
+        }
+
+        div button:focus {
+          color: red;
+        }
+      "#},
+    );
+    nesting_test(
+      r#"
+        div {
+          color: blue;
+
+          --button:focus {
+            color: red;
+          }
+        }
+      "#,
+      indoc! {r#"
+        div {
+          color: #00f;
+          --button: focus { color: red; };
+        }
+      "#},
+    );
+
+    nesting_test_no_targets(
+      r#"
+        .foo {
+          color: blue;
+          @nest .bar & {
+            color: red;
+            &.baz {
+              color: green;
+            }
+          }
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          color: #00f;
+
+          @nest .bar & {
+            color: red;
+
+            &.baz {
+              color: green;
+            }
+          }
+        }
+      "#},
+    );
+
+    nesting_test_no_targets(
+      r#"
+        .foo {
+          color: blue;
+          &div {
+            color: red;
+          }
+
+          &span {
+            color: purple;
+          }
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          color: #00f;
+
+          &div {
+            color: red;
+          }
+
+          &span {
+            color: purple;
+          }
+        }
+      "#},
+    );
+
+    nesting_test_no_targets(
+      r#"
+        .error, .invalid {
+          &:hover > .baz { color: red; }
+        }
+      "#,
+      indoc! {r#"
+        .error, .invalid {
+          &:hover > .baz {
+            color: red;
+          }
+        }
+      "#},
+    );
+
+    nesting_test_with_targets(
+      r#"
+        .foo {
+          color: blue;
+          & > .bar { color: red; }
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          color: #00f;
+        }
+
+        .foo > .bar {
+          color: red;
+        }
+      "#},
+      Targets {
+        browsers: Some(Browsers {
+          chrome: Some(112 << 16),
+          ..Browsers::default()
+        }),
+        include: Features::Nesting,
+        exclude: Features::empty(),
+      },
+    );
+    nesting_test_with_targets(
+      r#"
+        .foo {
+          color: blue;
+          & > .bar { color: red; }
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          color: #00f;
+
+          & > .bar {
+            color: red;
+          }
+        }
+      "#},
+      Targets {
+        browsers: Some(Browsers {
+          chrome: Some(50 << 16),
+          ..Browsers::default()
+        }),
+        include: Features::empty(),
+        exclude: Features::Nesting,
+      },
+    );
+
+    let mut stylesheet = StyleSheet::parse(
+      r#"
+      .foo {
+        color: blue;
+        .bar {
+          color: red;
+        }
+      }
+      "#,
+      ParserOptions::default(),
+    )
+    .unwrap();
+    stylesheet.minify(MinifyOptions::default()).unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(res.code, ".foo{color:#00f;& .bar{color:red}}");
+
+    nesting_test_with_targets(
+      r#"
+        .a {
+          &.b,
+          &.c {
+            &.d {
+              color: red;
+            }
+          }
+        }
+      "#,
+      indoc! {r#"
+        .a.b.d {
+          color: red;
+        }
+
+        .a.c.d {
+          color: red;
+        }
+      "#},
+      Targets {
+        browsers: Some(Browsers {
+          safari: Some(13 << 16),
+          ..Browsers::default()
+        }),
+        include: Features::Nesting,
+        exclude: Features::empty(),
+      },
+    );
+  }
+
+  #[test]
+  fn test_css_modules() {
+    css_modules_test(
+      r#"
+      .foo {
+        color: red;
+      }
+
+      #id {
+        animation: 2s test;
+      }
+
+      @keyframes test {
+        from { color: red }
+        to { color: yellow }
+      }
+
+      @counter-style circles {
+        symbols: Ⓐ Ⓑ Ⓒ;
+      }
+
+      ul {
+        list-style: circles;
+      }
+
+      ol {
+        list-style-type: none;
+      }
+
+      li {
+        list-style-type: disc;
+      }
+
+      @keyframes fade {
+        from { opacity: 0 }
+        to { opacity: 1 }
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_foo {
+        color: red;
+      }
+
+      #EgL3uq_id {
+        animation: 2s EgL3uq_test;
+      }
+
+      @keyframes EgL3uq_test {
+        from {
+          color: red;
+        }
+
+        to {
+          color: #ff0;
+        }
+      }
+
+      @counter-style EgL3uq_circles {
+        symbols: Ⓐ Ⓑ Ⓒ;
+      }
+
+      ul {
+        list-style: EgL3uq_circles;
+      }
+
+      ol {
+        list-style-type: none;
+      }
+
+      li {
+        list-style-type: disc;
+      }
+
+      @keyframes EgL3uq_fade {
+        from {
+          opacity: 0;
+        }
+
+        to {
+          opacity: 1;
+        }
+      }
+    "#},
+      map! {
+        "foo" => "EgL3uq_foo",
+        "id" => "EgL3uq_id",
+        "test" => "EgL3uq_test" referenced: true,
+        "circles" => "EgL3uq_circles" referenced: true,
+        "fade" => "EgL3uq_fade"
+      },
+      HashMap::new(),
+      Default::default(),
+    );
+
+    css_modules_test(
+      r#"
+      .foo {
+        color: red;
+      }
+
+      #id {
+        animation: 2s test;
+      }
+
+      @keyframes test {
+        from { color: red }
+        to { color: yellow }
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_foo {
+        color: red;
+      }
+
+      #EgL3uq_id {
+        animation: 2s test;
+      }
+
+      @keyframes test {
+        from {
+          color: red;
+        }
+
+        to {
+          color: #ff0;
+        }
+      }
+    "#},
+      map! {
+        "foo" => "EgL3uq_foo",
+        "id" => "EgL3uq_id"
+      },
+      HashMap::new(),
+      crate::css_modules::Config {
+        animation: false,
+        // custom_idents: false,
+        ..Default::default()
+      },
+    );
+
+    css_modules_test(
+      r#"
+      @counter-style circles {
+        symbols: Ⓐ Ⓑ Ⓒ;
+      }
+
+      ul {
+        list-style: circles;
+      }
+
+      ol {
+        list-style-type: none;
+      }
+
+      li {
+        list-style-type: disc;
+      }
+    "#,
+      indoc! {r#"
+      @counter-style circles {
+        symbols: Ⓐ Ⓑ Ⓒ;
+      }
+
+      ul {
+        list-style: circles;
+      }
+
+      ol {
+        list-style-type: none;
+      }
+
+      li {
+        list-style-type: disc;
+      }
+    "#},
+      map! {
+        "circles" => "EgL3uq_circles" referenced: true
+      },
+      HashMap::new(),
+      crate::css_modules::Config {
+        custom_idents: false,
+        ..Default::default()
+      },
+    );
+
+    css_modules_test(
+      r#"
+      @counter-style circles {
+        symbols: Ⓐ Ⓑ Ⓒ;
+        fallback: squares;
+      }
+
+      @counter-style squares {
+        system: extends decimal;
+        speak-as: circles;
+      }
+    "#,
+      indoc! {r#"
+      @counter-style EgL3uq_circles {
+        symbols: Ⓐ Ⓑ Ⓒ;
+        fallback: EgL3uq_squares;
+      }
+
+      @counter-style EgL3uq_squares {
+        system: extends decimal;
+        speak-as: EgL3uq_circles;
+      }
+    "#},
+      map! {
+        "circles" => "EgL3uq_circles" referenced: true,
+        "squares" => "EgL3uq_squares" referenced: true
+      },
+      HashMap::new(),
+      Default::default(),
+    );
+
+    #[cfg(feature = "grid")]
+    css_modules_test(
+      r#"
+      body {
+        grid: [header-top] "a a a" [header-bottom]
+              [main-top] "b b b" 1fr [main-bottom]
+              / auto 1fr auto;
+      }
+
+      header {
+        grid-area: a;
+      }
+
+      main {
+        grid-row: main-top / main-bottom;
+      }
+    "#,
+      indoc! {r#"
+      body {
+        grid: [EgL3uq_header-top] "EgL3uq_a EgL3uq_a EgL3uq_a" [EgL3uq_header-bottom]
+              [EgL3uq_main-top] "EgL3uq_b EgL3uq_b EgL3uq_b" 1fr [EgL3uq_main-bottom]
+              / auto 1fr auto;
+      }
+
+      header {
+        grid-area: EgL3uq_a;
+      }
+
+      main {
+        grid-row: EgL3uq_main-top / EgL3uq_main-bottom;
+      }
+    "#},
+      map! {
+        "header-top" => "EgL3uq_header-top",
+        "header-bottom" => "EgL3uq_header-bottom",
+        "main-top" => "EgL3uq_main-top",
+        "main-bottom" => "EgL3uq_main-bottom",
+        "a" => "EgL3uq_a",
+        "b" => "EgL3uq_b"
+      },
+      HashMap::new(),
+      Default::default(),
+    );
+
+    #[cfg(feature = "grid")]
+    css_modules_test(
+      r#"
+        .grid {
+          grid-template-areas: "foo";
+        }
+
+        .foo {
+          grid-area: foo;
+        }
+
+        .bar {
+          grid-column-start: foo-start;
+        }
+      "#,
+      indoc! {r#"
+        .EgL3uq_grid {
+          grid-template-areas: "EgL3uq_foo";
+        }
+
+        .EgL3uq_foo {
+          grid-area: EgL3uq_foo;
+        }
+
+        .EgL3uq_bar {
+          grid-column-start: EgL3uq_foo-start;
+        }
+      "#},
+      map! {
+        "foo" => "EgL3uq_foo",
+        "foo-start" => "EgL3uq_foo-start",
+        "grid" => "EgL3uq_grid",
+        "bar" => "EgL3uq_bar"
+      },
+      HashMap::new(),
+      Default::default(),
+    );
+
+    #[cfg(feature = "grid")]
+    css_modules_test(
+      r#"
+        .grid {
+          grid-template-areas: "foo";
+        }
+
+        .foo {
+          grid-area: foo;
+        }
+
+        .bar {
+          grid-column-start: foo-start;
+        }
+      "#,
+      indoc! {r#"
+        .EgL3uq_grid {
+          grid-template-areas: "foo";
+        }
+
+        .EgL3uq_foo {
+          grid-area: foo;
+        }
+
+        .EgL3uq_bar {
+          grid-column-start: foo-start;
+        }
+      "#},
+      map! {
+        "foo" => "EgL3uq_foo",
+        "grid" => "EgL3uq_grid",
+        "bar" => "EgL3uq_bar"
+      },
+      HashMap::new(),
+      crate::css_modules::Config {
+        grid: false,
+        ..Default::default()
+      },
+    );
+
+    css_modules_test(
+      r#"
+      test {
+        transition-property: opacity;
+      }
+    "#,
+      indoc! {r#"
+      test {
+        transition-property: opacity;
+      }
+    "#},
+      map! {},
+      HashMap::new(),
+      Default::default(),
+    );
+
+    css_modules_test(
+      r#"
+      :global(.foo) {
+        color: red;
+      }
+
+      :local(.bar) {
+        color: yellow;
+      }
+
+      .bar :global(.baz) {
+        color: purple;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        color: red;
+      }
+
+      .EgL3uq_bar {
+        color: #ff0;
+      }
+
+      .EgL3uq_bar .baz {
+        color: purple;
+      }
+    "#},
+      map! {
+        "bar" => "EgL3uq_bar"
+      },
+      HashMap::new(),
+      Default::default(),
+    );
+
+    // :global(:local(.hi)) {
+    //   color: green;
+    // }
+
+    css_modules_test(
+      r#"
+      .test {
+        composes: foo;
+        background: white;
+      }
+
+      .foo {
+        color: red;
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_test {
+        background: #fff;
+      }
+
+      .EgL3uq_foo {
+        color: red;
+      }
+    "#},
+      map! {
+        "test" => "EgL3uq_test" "EgL3uq_foo",
+        "foo" => "EgL3uq_foo"
+      },
+      HashMap::new(),
+      Default::default(),
+    );
+
+    css_modules_test(
+      r#"
+      .a, .b {
+        composes: foo;
+        background: white;
+      }
+
+      .foo {
+        color: red;
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_a, .EgL3uq_b {
+        background: #fff;
+      }
+
+      .EgL3uq_foo {
+        color: red;
+      }
+    "#},
+      map! {
+        "a" => "EgL3uq_a" "EgL3uq_foo",
+        "b" => "EgL3uq_b" "EgL3uq_foo",
+        "foo" => "EgL3uq_foo"
+      },
+      HashMap::new(),
+      Default::default(),
+    );
+
+    css_modules_test(
+      r#"
+      .test {
+        composes: foo bar;
+        background: white;
+      }
+
+      .foo {
+        color: red;
+      }
+
+      .bar {
+        color: yellow;
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_test {
+        background: #fff;
+      }
+
+      .EgL3uq_foo {
+        color: red;
+      }
+
+      .EgL3uq_bar {
+        color: #ff0;
+      }
+    "#},
+      map! {
+        "test" => "EgL3uq_test" "EgL3uq_foo" "EgL3uq_bar",
+        "foo" => "EgL3uq_foo",
+        "bar" => "EgL3uq_bar"
+      },
+      HashMap::new(),
+      Default::default(),
+    );
+
+    css_modules_test(
+      r#"
+      .test {
+        composes: foo from global;
+        background: white;
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_test {
+        background: #fff;
+      }
+    "#},
+      map! {
+        "test" => "EgL3uq_test" "foo" global: true
+      },
+      HashMap::new(),
+      Default::default(),
+    );
+
+    css_modules_test(
+      r#"
+      .test {
+        composes: foo bar from global;
+        background: white;
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_test {
+        background: #fff;
+      }
+    "#},
+      map! {
+        "test" => "EgL3uq_test" "foo" global: true "bar" global: true
+      },
+      HashMap::new(),
+      Default::default(),
+    );
+
+    css_modules_test(
+      r#"
+      .test {
+        composes: foo from "foo.css";
+        background: white;
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_test {
+        background: #fff;
+      }
+    "#},
+      map! {
+        "test" => "EgL3uq_test" "foo" from "foo.css"
+      },
+      HashMap::new(),
+      Default::default(),
+    );
+
+    css_modules_test(
+      r#"
+      .test {
+        composes: foo bar from "foo.css";
+        background: white;
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_test {
+        background: #fff;
+      }
+    "#},
+      map! {
+        "test" => "EgL3uq_test" "foo" from "foo.css" "bar" from "foo.css"
+      },
+      HashMap::new(),
+      Default::default(),
+    );
+
+    css_modules_test(
+      r#"
+      .test {
+        composes: foo;
+        composes: foo from "foo.css";
+        composes: bar from "bar.css";
+        background: white;
+      }
+
+      .foo {
+        color: red;
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_test {
+        background: #fff;
+      }
+
+      .EgL3uq_foo {
+        color: red;
+      }
+    "#},
+      map! {
+        "test" => "EgL3uq_test" "EgL3uq_foo" "foo" from "foo.css" "bar" from "bar.css",
+        "foo" => "EgL3uq_foo"
+      },
+      HashMap::new(),
+      Default::default(),
+    );
+
+    css_modules_test(
+      r#"
+      .foo {
+        color: red;
+      }
+    "#,
+      indoc! {r#"
+      .test-EgL3uq-foo {
+        color: red;
+      }
+    "#},
+      map! {
+        "foo" => "test-EgL3uq-foo"
+      },
+      HashMap::new(),
+      crate::css_modules::Config {
+        pattern: crate::css_modules::Pattern::parse("test-[hash]-[local]").unwrap(),
+        ..Default::default()
+      },
+    );
+
+    let stylesheet = StyleSheet::parse(
+      r#"
+        .grid {
+          grid-template-areas: "foo";
+        }
+
+        .foo {
+          grid-area: foo;
+        }
+
+        .bar {
+          grid-column-start: foo-start;
+        }
+      "#,
+      ParserOptions {
+        css_modules: Some(crate::css_modules::Config {
+          pattern: crate::css_modules::Pattern::parse("test-[local]-[hash]").unwrap(),
+          ..Default::default()
+        }),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    if let Err(err) = stylesheet.to_css(PrinterOptions::default()) {
+      assert_eq!(err.kind, PrinterErrorKind::InvalidCssModulesPatternInGrid);
+    } else {
+      unreachable!()
+    }
+
+    css_modules_test(
+      r#"
+      @property --foo {
+        syntax: '<color>';
+        inherits: false;
+        initial-value: yellow;
+      }
+
+      .foo {
+        --foo: red;
+        color: var(--foo);
+      }
+    "#,
+      indoc! {r#"
+      @property --foo {
+        syntax: "<color>";
+        inherits: false;
+        initial-value: #ff0;
+      }
+
+      .EgL3uq_foo {
+        --foo: red;
+        color: var(--foo);
+      }
+    "#},
+      map! {
+        "foo" => "EgL3uq_foo"
+      },
+      HashMap::new(),
+      Default::default(),
+    );
+
+    css_modules_test(
+      r#"
+      @property --foo {
+        syntax: '<color>';
+        inherits: false;
+        initial-value: yellow;
+      }
+
+      @font-palette-values --Cooler {
+        font-family: Bixa;
+        base-palette: 1;
+        override-colors: 1 #7EB7E4;
+      }
+
+      .foo {
+        --foo: red;
+        --bar: green;
+        color: var(--foo);
+        font-palette: --Cooler;
+      }
+
+      .bar {
+        color: var(--color from "./b.css");
+      }
+    "#,
+      indoc! {r#"
+      @property --EgL3uq_foo {
+        syntax: "<color>";
+        inherits: false;
+        initial-value: #ff0;
+      }
+
+      @font-palette-values --EgL3uq_Cooler {
+        font-family: Bixa;
+        base-palette: 1;
+        override-colors: 1 #7eb7e4;
+      }
+
+      .EgL3uq_foo {
+        --EgL3uq_foo: red;
+        --EgL3uq_bar: green;
+        color: var(--EgL3uq_foo);
+        font-palette: --EgL3uq_Cooler;
+      }
+
+      .EgL3uq_bar {
+        color: var(--ma1CsG);
+      }
+    "#},
+      map! {
+        "foo" => "EgL3uq_foo",
+        "--foo" => "--EgL3uq_foo" referenced: true,
+        "--bar" => "--EgL3uq_bar",
+        "bar" => "EgL3uq_bar",
+        "--Cooler" => "--EgL3uq_Cooler" referenced: true
+      },
+      HashMap::from([(
+        "--ma1CsG".into(),
+        CssModuleReference::Dependency {
+          name: "--color".into(),
+          specifier: "./b.css".into(),
+        },
+      )]),
+      crate::css_modules::Config {
+        dashed_idents: true,
+        ..Default::default()
+      },
+    );
+
+    css_modules_test(
+      r#"
+      .test {
+        animation: rotate var(--duration) linear infinite;
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_test {
+        animation: EgL3uq_rotate var(--duration) linear infinite;
+      }
+    "#},
+      map! {
+        "test" => "EgL3uq_test",
+        "rotate" => "EgL3uq_rotate" referenced: true
+      },
+      HashMap::new(),
+      Default::default(),
+    );
+    css_modules_test(
+      r#"
+      .test {
+        animation: none var(--duration);
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_test {
+        animation: none var(--duration);
+      }
+    "#},
+      map! {
+        "test" => "EgL3uq_test"
+      },
+      HashMap::new(),
+      Default::default(),
+    );
+    css_modules_test(
+      r#"
+      .test {
+        animation: var(--animation);
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_test {
+        animation: var(--animation);
+      }
+    "#},
+      map! {
+        "test" => "EgL3uq_test"
+      },
+      HashMap::new(),
+      Default::default(),
+    );
+    css_modules_test(
+      r#"
+      .test {
+        animation: rotate var(--duration);
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_test {
+        animation: rotate var(--duration);
+      }
+    "#},
+      map! {
+        "test" => "EgL3uq_test"
+      },
+      HashMap::new(),
+      crate::css_modules::Config {
+        animation: false,
+        ..Default::default()
+      },
+    );
+    css_modules_test(
+      r#"
+      .test {
+        animation: "rotate" var(--duration);
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_test {
+        animation: EgL3uq_rotate var(--duration);
+      }
+    "#},
+      map! {
+        "test" => "EgL3uq_test",
+        "rotate" => "EgL3uq_rotate" referenced: true
+      },
+      HashMap::new(),
+      crate::css_modules::Config { ..Default::default() },
+    );
+
+    // Stable hashes between project roots.
+    fn test_project_root(project_root: &str, filename: &str, hash: &str) {
+      let stylesheet = StyleSheet::parse(
+        r#"
+        .foo {
+          background: red;
+        }
+        "#,
+        ParserOptions {
+          filename: filename.into(),
+          css_modules: Some(Default::default()),
+          ..ParserOptions::default()
+        },
+      )
+      .unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          project_root: Some(project_root),
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(
+        res.code,
+        format!(
+          indoc! {r#"
+      .{}_foo {{
+        background: red;
+      }}
+      "#},
+          hash
+        )
+      );
+    }
+
+    test_project_root("/foo/bar", "/foo/bar/test.css", "EgL3uq");
+    test_project_root("/foo", "/foo/test.css", "EgL3uq");
+    test_project_root("/foo/bar", "/foo/bar/baz/test.css", "xLEkNW");
+    test_project_root("/foo", "/foo/baz/test.css", "xLEkNW");
+  }
+
+  #[test]
+  fn test_pseudo_replacement() {
+    let source = r#"
+      .foo:hover {
+        color: red;
+      }
+
+      .foo:active {
+        color: yellow;
+      }
+
+      .foo:focus-visible {
+        color: purple;
+      }
+    "#;
+
+    let expected = indoc! { r#"
+      .foo.is-hovered {
+        color: red;
+      }
+
+      .foo.is-active {
+        color: #ff0;
+      }
+
+      .foo.focus-visible {
+        color: purple;
+      }
+    "#};
+
+    let stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        pseudo_classes: Some(PseudoClasses {
+          hover: Some("is-hovered"),
+          active: Some("is-active"),
+          focus_visible: Some("focus-visible"),
+          ..PseudoClasses::default()
+        }),
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(res.code, expected);
+
+    let source = r#"
+      .foo:hover {
+        color: red;
+      }
+    "#;
+
+    let expected = indoc! { r#"
+      .EgL3uq_foo.EgL3uq_is-hovered {
+        color: red;
+      }
+    "#};
+
+    let stylesheet = StyleSheet::parse(
+      &source,
+      ParserOptions {
+        filename: "test.css".into(),
+        css_modules: Some(Default::default()),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        pseudo_classes: Some(PseudoClasses {
+          hover: Some("is-hovered"),
+          ..PseudoClasses::default()
+        }),
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(res.code, expected);
+  }
+
+  #[test]
+  fn test_unused_symbols() {
+    let source = r#"
+      .foo {
+        color: red;
+      }
+
+      .bar {
+        color: green;
+      }
+
+      .bar:hover {
+        color: purple;
+      }
+
+      .bar .baz {
+        background: red;
+      }
+
+      .baz:is(.bar) {
+        background: green;
+      }
+
+      #id {
+        animation: 2s test;
+      }
+
+      #other_id {
+        color: red;
+      }
+
+      @keyframes test {
+        from { color: red }
+        to { color: yellow }
+      }
+
+      @counter-style circles {
+        symbols: Ⓐ Ⓑ Ⓒ;
+      }
+
+      @keyframes fade {
+        from { opacity: 0 }
+        to { opacity: 1 }
+      }
+    "#;
+
+    let expected = indoc! {r#"
+      .foo {
+        color: red;
+      }
+
+      #id {
+        animation: 2s test;
+      }
+
+      @keyframes test {
+        from {
+          color: red;
+        }
+
+        to {
+          color: #ff0;
+        }
+      }
+    "#};
+
+    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        unused_symbols: vec!["bar", "other_id", "fade", "circles"]
+          .iter()
+          .map(|s| String::from(*s))
+          .collect(),
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let res = stylesheet.to_css(PrinterOptions::default()).unwrap();
+    assert_eq!(res.code, expected);
+
+    let source = r#"
+      .foo {
+        color: red;
+
+        &.bar {
+          color: green;
+        }
+      }
+    "#;
+
+    let expected = indoc! {r#"
+      .foo {
+        color: red;
+      }
+    "#};
+
+    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        unused_symbols: vec!["bar"].iter().map(|s| String::from(*s)).collect(),
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let res = stylesheet.to_css(PrinterOptions::default()).unwrap();
+    assert_eq!(res.code, expected);
+
+    let source = r#"
+      .foo {
+        color: red;
+
+        &.bar {
+          color: purple;
+        }
+
+        @nest &.bar {
+          color: orange;
+        }
+
+        @nest :not(&) {
+          color: green;
+        }
+
+        @media (orientation: portrait) {
+          color: brown;
+        }
+      }
+
+      .x {
+        color: purple;
+
+        &.y {
+          color: green;
+        }
+      }
+    "#;
+
+    let expected = indoc! {r#"
+      :not(.foo) {
+        color: green;
+      }
+    "#};
+
+    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        unused_symbols: vec!["foo", "x"].iter().map(|s| String::from(*s)).collect(),
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        targets: Browsers {
+          chrome: Some(95 << 16),
+          ..Browsers::default()
+        }
+        .into(),
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(res.code, expected);
+
+    let source = r#"
+      @property --EgL3uq_foo {
+        syntax: "<color>";
+        inherits: false;
+        initial-value: #ff0;
+      }
+
+      @font-palette-values --EgL3uq_Cooler {
+        font-family: Bixa;
+        base-palette: 1;
+        override-colors: 1 #7EB7E4;
+      }
+
+      .EgL3uq_foo {
+        --EgL3uq_foo: red;
+      }
+
+      .EgL3uq_bar {
+        color: green;
+      }
+    "#;
+
+    let expected = indoc! {r#"
+      .EgL3uq_bar {
+        color: green;
+      }
+    "#};
+
+    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        unused_symbols: vec!["--EgL3uq_foo", "--EgL3uq_Cooler"]
+          .iter()
+          .map(|s| String::from(*s))
+          .collect(),
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let res = stylesheet.to_css(PrinterOptions::default()).unwrap();
+    assert_eq!(res.code, expected);
+  }
+
+  #[test]
+  fn test_svg() {
+    minify_test(".foo { fill: yellow; }", ".foo{fill:#ff0}");
+    minify_test(".foo { fill: url(#foo); }", ".foo{fill:url(#foo)}");
+    minify_test(".foo { fill: url(#foo) none; }", ".foo{fill:url(#foo) none}");
+    minify_test(".foo { fill: url(#foo) yellow; }", ".foo{fill:url(#foo) #ff0}");
+    minify_test(".foo { fill: none; }", ".foo{fill:none}");
+    minify_test(".foo { fill: context-fill; }", ".foo{fill:context-fill}");
+    minify_test(".foo { fill: context-stroke; }", ".foo{fill:context-stroke}");
+
+    minify_test(".foo { stroke: yellow; }", ".foo{stroke:#ff0}");
+    minify_test(".foo { stroke: url(#foo); }", ".foo{stroke:url(#foo)}");
+    minify_test(".foo { stroke: url(#foo) none; }", ".foo{stroke:url(#foo) none}");
+    minify_test(".foo { stroke: url(#foo) yellow; }", ".foo{stroke:url(#foo) #ff0}");
+    minify_test(".foo { stroke: none; }", ".foo{stroke:none}");
+    minify_test(".foo { stroke: context-fill; }", ".foo{stroke:context-fill}");
+    minify_test(".foo { stroke: context-stroke; }", ".foo{stroke:context-stroke}");
+
+    minify_test(".foo { marker-start: url(#foo); }", ".foo{marker-start:url(#foo)}");
+
+    minify_test(".foo { stroke-dasharray: 4 1 2; }", ".foo{stroke-dasharray:4 1 2}");
+    minify_test(".foo { stroke-dasharray: 4,1,2; }", ".foo{stroke-dasharray:4 1 2}");
+    minify_test(".foo { stroke-dasharray: 4, 1, 2; }", ".foo{stroke-dasharray:4 1 2}");
+    minify_test(
+      ".foo { stroke-dasharray: 4px, 1px, 2px; }",
+      ".foo{stroke-dasharray:4 1 2}",
+    );
+
+    minify_test(".foo { mask: url('foo.svg'); }", ".foo{mask:url(foo.svg)}");
+    minify_test(
+      ".foo { mask: url(masks.svg#star) luminance }",
+      ".foo{mask:url(masks.svg#star) luminance}",
+    );
+    minify_test(
+      ".foo { mask: url(masks.svg#star) 40px 20px }",
+      ".foo{mask:url(masks.svg#star) 40px 20px}",
+    );
+    minify_test(
+      ".foo { mask: url(masks.svg#star) 0 0 / 50px 50px }",
+      ".foo{mask:url(masks.svg#star) 0 0/50px 50px}",
+    );
+    minify_test(
+      ".foo { mask: url(masks.svg#star) repeat-x }",
+      ".foo{mask:url(masks.svg#star) repeat-x}",
+    );
+    minify_test(
+      ".foo { mask: url(masks.svg#star) stroke-box }",
+      ".foo{mask:url(masks.svg#star) stroke-box}",
+    );
+    minify_test(
+      ".foo { mask: url(masks.svg#star) stroke-box stroke-box }",
+      ".foo{mask:url(masks.svg#star) stroke-box}",
+    );
+    minify_test(
+      ".foo { mask: url(masks.svg#star) border-box }",
+      ".foo{mask:url(masks.svg#star)}",
+    );
+    minify_test(
+      ".foo { mask: url(masks.svg#star) left / 16px repeat-y, url(masks.svg#circle) right / 16px repeat-y }",
+      ".foo{mask:url(masks.svg#star) 0/16px repeat-y,url(masks.svg#circle) 100%/16px repeat-y}",
+    );
+
+    minify_test(
+      ".foo { mask-border: url('border-mask.png') 25; }",
+      ".foo{mask-border:url(border-mask.png) 25}",
+    );
+    minify_test(
+      ".foo { mask-border: url('border-mask.png') 25 / 35px / 12px space alpha; }",
+      ".foo{mask-border:url(border-mask.png) 25/35px/12px space}",
+    );
+    minify_test(
+      ".foo { mask-border: url('border-mask.png') 25 / 35px / 12px space luminance; }",
+      ".foo{mask-border:url(border-mask.png) 25/35px/12px space luminance}",
+    );
+    minify_test(
+      ".foo { mask-border: url('border-mask.png') luminance 25 / 35px / 12px space; }",
+      ".foo{mask-border:url(border-mask.png) 25/35px/12px space luminance}",
+    );
+
+    minify_test(
+      ".foo { clip-path: url('clip.svg#star'); }",
+      ".foo{clip-path:url(clip.svg#star)}",
+    );
+    minify_test(".foo { clip-path: margin-box; }", ".foo{clip-path:margin-box}");
+    minify_test(
+      ".foo { clip-path: inset(100px 50px); }",
+      ".foo{clip-path:inset(100px 50px)}",
+    );
+    minify_test(
+      ".foo { clip-path: inset(100px 50px round 5px); }",
+      ".foo{clip-path:inset(100px 50px round 5px)}",
+    );
+    minify_test(
+      ".foo { clip-path: inset(100px 50px round 5px 5px 5px 5px); }",
+      ".foo{clip-path:inset(100px 50px round 5px)}",
+    );
+    minify_test(".foo { clip-path: circle(50px); }", ".foo{clip-path:circle(50px)}");
+    minify_test(
+      ".foo { clip-path: circle(50px at center center); }",
+      ".foo{clip-path:circle(50px)}",
+    );
+    minify_test(
+      ".foo { clip-path: circle(50px at 50% 50%); }",
+      ".foo{clip-path:circle(50px)}",
+    );
+    minify_test(
+      ".foo { clip-path: circle(50px at 0 100px); }",
+      ".foo{clip-path:circle(50px at 0 100px)}",
+    );
+    minify_test(
+      ".foo { clip-path: circle(closest-side at 0 100px); }",
+      ".foo{clip-path:circle(at 0 100px)}",
+    );
+    minify_test(
+      ".foo { clip-path: circle(farthest-side at 0 100px); }",
+      ".foo{clip-path:circle(farthest-side at 0 100px)}",
+    );
+    minify_test(
+      ".foo { clip-path: circle(closest-side at 50% 50%); }",
+      ".foo{clip-path:circle()}",
+    );
+    minify_test(
+      ".foo { clip-path: ellipse(50px 60px at 0 10% 20%); }",
+      ".foo{clip-path:ellipse(50px 60px at 0 10% 20%)}",
+    );
+    minify_test(
+      ".foo { clip-path: ellipse(50px 60px at center center); }",
+      ".foo{clip-path:ellipse(50px 60px)}",
+    );
+    minify_test(
+      ".foo { clip-path: ellipse(closest-side closest-side at 50% 50%); }",
+      ".foo{clip-path:ellipse()}",
+    );
+    minify_test(
+      ".foo { clip-path: ellipse(closest-side closest-side at 10% 20%); }",
+      ".foo{clip-path:ellipse(at 10% 20%)}",
+    );
+    minify_test(
+      ".foo { clip-path: ellipse(farthest-side closest-side at 10% 20%); }",
+      ".foo{clip-path:ellipse(farthest-side closest-side at 10% 20%)}",
+    );
+    minify_test(
+      ".foo { clip-path: polygon(50% 0%, 100% 50%, 50% 100%, 0% 50%); }",
+      ".foo{clip-path:polygon(50% 0%,100% 50%,50% 100%,0% 50%)}",
+    );
+    minify_test(
+      ".foo { clip-path: polygon(nonzero, 50% 0%, 100% 50%, 50% 100%, 0% 50%); }",
+      ".foo{clip-path:polygon(50% 0%,100% 50%,50% 100%,0% 50%)}",
+    );
+    minify_test(
+      ".foo { clip-path: polygon(evenodd, 50% 0%, 100% 50%, 50% 100%, 0% 50%); }",
+      ".foo{clip-path:polygon(evenodd,50% 0%,100% 50%,50% 100%,0% 50%)}",
+    );
+    minify_test(
+      ".foo { clip-path: padding-box circle(50px at 0 100px); }",
+      ".foo{clip-path:circle(50px at 0 100px) padding-box}",
     );
-
-    nesting_test(
-      r#"
-        .foo .bar {
-          &.baz {
-            background: green;
-          }
-        }
-      "#,
-      indoc! {r#"
-        .foo .bar.baz {
-          background: green;
-        }
-      "#},
+    minify_test(
+      ".foo { clip-path: circle(50px at 0 100px) padding-box; }",
+      ".foo{clip-path:circle(50px at 0 100px) padding-box}",
+    );
+    minify_test(
+      ".foo { clip-path: circle(50px at 0 100px) border-box; }",
+      ".foo{clip-path:circle(50px at 0 100px)}",
     );
 
-    nesting_test(
-      r#"
-        .foo {
-          color: red;
-          @nest .parent & {
-            color: blue;
-          }
-        }
-      "#,
-      indoc! {r#"
+    prefix_test(
+      ".foo { clip-path: circle(50px); }",
+      indoc! { r#"
         .foo {
-          color: red;
-        }
-
-        .parent .foo {
-          color: #00f;
+          -webkit-clip-path: circle(50px);
+          clip-path: circle(50px);
         }
       "#},
+      Browsers {
+        chrome: Some(30 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
-      r#"
-        .foo {
-          color: red;
-          @nest :not(&) {
-            color: blue;
-          }
-        }
-      "#,
-      indoc! {r#"
+    prefix_test(
+      ".foo { clip-path: circle(50px); }",
+      indoc! { r#"
         .foo {
-          color: red;
-        }
-
-        :not(.foo) {
-          color: #00f;
+          clip-path: circle(50px);
         }
       "#},
+      Browsers {
+        chrome: Some(80 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
-      r#"
-        .foo {
-          color: blue;
-          @nest .bar & {
-            color: red;
-            &.baz {
-              color: green;
-            }
-          }
-        }
-      "#,
-      indoc! {r#"
+    prefix_test(
+      ".foo { clip-path: circle(50px); }",
+      indoc! { r#"
         .foo {
-          color: #00f;
-        }
-
-        .bar .foo {
-          color: red;
-        }
-
-        .bar .foo.baz {
-          color: green;
+          -webkit-clip-path: circle(50px);
+          clip-path: circle(50px);
         }
       "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
-      r#"
+    prefix_test(
+      ".foo { clip-path: circle(50px); }",
+      indoc! { r#"
         .foo {
-          @nest :not(&) {
-            color: red;
-          }
-
-          & h1 {
-            background: green;
-          }
-        }
-      "#,
-      indoc! {r#"
-        :not(.foo) {
-          color: red;
-        }
-
-        .foo h1 {
-          background: green;
+          clip-path: circle(50px);
         }
       "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
-      r#"
+    prefix_test(
+      ".foo { fill: lch(50.998% 135.363 338) }",
+      indoc! { r#"
         .foo {
-          & h1 {
-            background: green;
-          }
-
-          @nest :not(&) {
-            color: red;
-          }
-        }
-      "#,
-      indoc! {r#"
-        .foo h1 {
-          background: green;
-        }
-
-        :not(.foo) {
-          color: red;
+          fill: #ee00be;
+          fill: color(display-p3 .972962 -.362078 .804206);
+          fill: lch(50.998% 135.363 338);
         }
       "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
-      r#"
-        .foo .bar {
-          @nest h1& {
-            background: green;
-          }
-        }
-      "#,
-      indoc! {r#"
-        h1:is(.foo .bar) {
-          background: green;
+    prefix_test(
+      ".foo { stroke: lch(50.998% 135.363 338) }",
+      indoc! { r#"
+        .foo {
+          stroke: #ee00be;
+          stroke: color(display-p3 .972962 -.362078 .804206);
+          stroke: lch(50.998% 135.363 338);
         }
       "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
-      r#"
-        @namespace "http://example.com/foo";
-        @namespace toto "http://toto.example.org";
-
-        div {
-          @nest .foo& {
-            color: red;
-          }
-        }
-
-        * {
-          @nest .foo& {
-            color: red;
-          }
-        }
-
-        |x {
-          @nest .foo& {
-            color: red;
-          }
-        }
-
-        *|x {
-          @nest .foo& {
-            color: red;
-          }
-        }
-
-        toto|x {
-          @nest .foo& {
-            color: red;
-          }
-        }
-      "#,
-      indoc! {r#"
-        @namespace "http://example.com/foo";
-        @namespace toto "http://toto.example.org";
-
-        .foo:is(div) {
-          color: red;
-        }
-
-        .foo:is(*) {
-          color: red;
-        }
-
-        .foo:is(|x) {
-          color: red;
+    prefix_test(
+      ".foo { fill: url(#foo) lch(50.998% 135.363 338) }",
+      indoc! { r##"
+        .foo {
+          fill: url("#foo") #ee00be;
+          fill: url("#foo") color(display-p3 .972962 -.362078 .804206);
+          fill: url("#foo") lch(50.998% 135.363 338);
         }
+      "##},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
 
-        .foo:is(*|x) {
-          color: red;
+    prefix_test(
+      ".foo { fill: var(--url) lch(50.998% 135.363 338) }",
+      indoc! { r#"
+        .foo {
+          fill: var(--url) #ee00be;
         }
 
-        .foo:is(toto|x) {
-          color: red;
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            fill: var(--url) lab(50.998% 125.506 -50.7078);
+          }
         }
       "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
-      r#"
-        .foo .bar {
-          @nest h1 .baz& {
-            background: green;
-          }
-        }
-      "#,
-      indoc! {r#"
-        h1 .baz:is(.foo .bar) {
-          background: green;
+    prefix_test(
+      ".foo { mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask-image: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff));
+          -webkit-mask-image: -webkit-linear-gradient(#ff0f0e, #7773ff);
+          -webkit-mask-image: linear-gradient(#ff0f0e, #7773ff);
+          mask-image: linear-gradient(#ff0f0e, #7773ff);
+          -webkit-mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+          mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
         }
       "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
-      r#"
-        .foo .bar {
-          @nest .baz& {
-            background: green;
-          }
-        }
-      "#,
-      indoc! {r#"
-        .baz:is(.foo .bar) {
-          background: green;
+    prefix_test(
+      ".foo { mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask-image: linear-gradient(#ff0f0e, #7773ff);
+          mask-image: linear-gradient(#ff0f0e, #7773ff);
+          -webkit-mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+          mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
         }
       "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
-      r#"
-        .foo .bar {
-          @nest .baz & {
-            background: green;
-          }
-        }
-      "#,
-      indoc! {r#"
-        .baz :is(.foo .bar) {
-          background: green;
+    prefix_test(
+      ".foo { mask-image: linear-gradient(red, green) }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask-image: linear-gradient(red, green);
+          mask-image: linear-gradient(red, green);
         }
       "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
-      r#"
-        .foo {
-          color: red;
-          @nest & > .bar {
-            color: blue;
-          }
-        }
-      "#,
-      indoc! {r#"
+    prefix_test(
+      ".foo { -webkit-mask-image: url(x.svg); mask-image: url(x.svg); }",
+      indoc! { r#"
         .foo {
-          color: red;
+          -webkit-mask-image: url("x.svg");
+          mask-image: url("x.svg");
         }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
 
-        .foo > .bar {
-          color: #00f;
+    prefix_test(
+      ".foo { mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff)) 40px 20px;
+          -webkit-mask: -webkit-linear-gradient(#ff0f0e, #7773ff) 40px 20px;
+          -webkit-mask: linear-gradient(#ff0f0e, #7773ff) 40px 20px;
+          mask: linear-gradient(#ff0f0e, #7773ff) 40px 20px;
+          -webkit-mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px;
+          mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px;
         }
       "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
-      r#"
-      .foo {
-        color: red;
-        .bar {
-          color: blue;
+    prefix_test(
+      ".foo { mask: -webkit-linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff)) 40px 20px;
+          -webkit-mask: -webkit-linear-gradient(#ff0f0e, #7773ff) 40px 20px;
         }
-      }
-      "#,
-      indoc! {r#"
-      .foo {
-        color: red;
-      }
-
-      .foo .bar {
-        color: #00f;
-      }
       "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
-      r#"
-      .foo {
-        color: red;
-        .bar & {
-          color: blue;
+    prefix_test(
+      ".foo { mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px var(--foo) }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask: linear-gradient(#ff0f0e, #7773ff) 40px var(--foo);
+          mask: linear-gradient(#ff0f0e, #7773ff) 40px var(--foo);
         }
-      }
-      "#,
-      indoc! {r#"
-      .foo {
-        color: red;
-      }
 
-      .bar .foo {
-        color: #00f;
-      }
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            -webkit-mask: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) 40px var(--foo);
+            mask: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) 40px var(--foo);
+          }
+        }
       "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
-      r#"
-      .foo {
-        color: red;
-        + .bar + & { color: blue; }
-      }
-      "#,
-      indoc! {r#"
-      .foo {
-        color: red;
-      }
+    prefix_test(
+      ".foo { mask: url(masks.svg#star) luminance }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask: url("masks.svg#star");
+          -webkit-mask-source-type: luminance;
+          mask: url("masks.svg#star") luminance;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      .foo + .bar + .foo {
-        color: #00f;
-      }
-      "#},
+    prefix_test(
+      ".foo { mask-image: url(masks.svg#star) }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask-image: url("masks.svg#star");
+          mask-image: url("masks.svg#star");
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
+    prefix_test(
       r#"
-      .foo {
-        color: red;
-        .bar & {
-          color: blue;
+        .foo {
+          mask-image: url(masks.svg#star);
+          mask-position: 25% 75%;
+          mask-size: cover;
+          mask-repeat: no-repeat;
+          mask-clip: padding-box;
+          mask-origin: content-box;
+          mask-composite: subtract;
+          mask-mode: luminance;
         }
-      }
       "#,
-      indoc! {r#"
-      .foo {
-        color: red;
-      }
-
-      .bar .foo {
-        color: #00f;
-      }
-      "#},
+      indoc! { r#"
+        .foo {
+          -webkit-mask: url("masks.svg#star") 25% 75% / cover no-repeat content-box padding-box;
+          -webkit-mask-composite: source-out;
+          -webkit-mask-source-type: luminance;
+          mask: url("masks.svg#star") 25% 75% / cover no-repeat content-box padding-box subtract luminance;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
+    prefix_test(
       r#"
         .foo {
-          color: red;
-          .parent & {
-            color: blue;
-          }
+          mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+          mask-position: 25% 75%;
+          mask-size: cover;
+          mask-repeat: no-repeat;
+          mask-clip: padding-box;
+          mask-origin: content-box;
+          mask-composite: subtract;
+          mask-mode: luminance;
         }
       "#,
-      indoc! {r#"
+      indoc! { r#"
         .foo {
-          color: red;
-        }
-
-        .parent .foo {
-          color: #00f;
+          -webkit-mask: linear-gradient(#ff0f0e, #7773ff) 25% 75% / cover no-repeat content-box padding-box;
+          -webkit-mask-composite: source-out;
+          -webkit-mask-source-type: luminance;
+          mask: linear-gradient(#ff0f0e, #7773ff) 25% 75% / cover no-repeat content-box padding-box subtract luminance;
+          -webkit-mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25% 75% / cover no-repeat content-box padding-box;
+          -webkit-mask-composite: source-out;
+          -webkit-mask-source-type: luminance;
+          mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25% 75% / cover no-repeat content-box padding-box subtract luminance;
         }
-      "#},
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
+    test(
       r#"
         .foo {
-          color: red;
-          :not(&) {
-            color: blue;
-          }
+          mask: none center / 100% no-repeat;
+          mask-image: var(--svg);
         }
       "#,
-      indoc! {r#"
+      indoc! { r#"
         .foo {
-          color: red;
-        }
-
-        :not(.foo) {
-          color: #00f;
+          mask: none center / 100% no-repeat;
+          mask-image: var(--svg);
         }
       "#},
     );
 
-    nesting_test(
+    prefix_test(
       r#"
         .foo {
-          color: blue;
-          .bar & {
-            color: red;
-            &.baz {
-              color: green;
-            }
-          }
+          mask-composite: subtract;
         }
       "#,
-      indoc! {r#"
+      indoc! { r#"
         .foo {
-          color: #00f;
-        }
-
-        .bar .foo {
-          color: red;
-        }
-
-        .bar .foo.baz {
-          color: green;
+          -webkit-mask-composite: source-out;
+          mask-composite: subtract;
         }
-      "#},
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
+    prefix_test(
       r#"
         .foo {
-          :not(&) {
-            color: red;
-          }
-
-          & h1 {
-            background: green;
-          }
+          mask-mode: luminance;
         }
       "#,
-      indoc! {r#"
-        :not(.foo) {
-          color: red;
-        }
-
-        .foo h1 {
-          background: green;
+      indoc! { r#"
+        .foo {
+          -webkit-mask-source-type: luminance;
+          mask-mode: luminance;
         }
-      "#},
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
+    prefix_test(
       r#"
         .foo {
-          & h1 {
-            background: green;
-          }
-
-          :not(&) {
-            color: red;
-          }
+          mask-border: url('border-mask.png') 25 / 35px / 12px space luminance;
         }
       "#,
-      indoc! {r#"
-        .foo h1 {
-          background: green;
-        }
-
-        :not(.foo) {
-          color: red;
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image: url("border-mask.png") 25 / 35px / 12px space;
+          mask-border: url("border-mask.png") 25 / 35px / 12px space luminance;
         }
-      "#},
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
+    prefix_test(
       r#"
-        .foo .bar {
-          :is(h1)& {
-            background: green;
-          }
+        .foo {
+          mask-border: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25 / 35px / 12px space luminance;
         }
       "#,
-      indoc! {r#"
-        :is(h1):is(.foo .bar) {
-          background: green;
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image: linear-gradient(#ff0f0e, #7773ff) 25 / 35px / 12px space;
+          mask-border: linear-gradient(#ff0f0e, #7773ff) 25 / 35px / 12px space luminance;
+          -webkit-mask-box-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25 / 35px / 12px space;
+          mask-border: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25 / 35px / 12px space luminance;
         }
-      "#},
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
+    prefix_test(
       r#"
-        @namespace "http://example.com/foo";
-        @namespace toto "http://toto.example.org";
-
-        div {
-          .foo& {
-            color: red;
-          }
-        }
-
-        * {
-          .foo& {
-            color: red;
-          }
-        }
-
-        |x {
-          .foo& {
-            color: red;
-          }
-        }
-
-        *|x {
-          .foo& {
-            color: red;
-          }
-        }
-
-        toto|x {
-          .foo& {
-            color: red;
-          }
+        .foo {
+          mask-border-source: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
         }
       "#,
-      indoc! {r#"
-        @namespace "http://example.com/foo";
-        @namespace toto "http://toto.example.org";
-
-        .foo:is(div) {
-          color: red;
-        }
-
-        .foo:is(*) {
-          color: red;
-        }
-
-        .foo:is(|x) {
-          color: red;
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image-source: linear-gradient(#ff0f0e, #7773ff);
+          mask-border-source: linear-gradient(#ff0f0e, #7773ff);
+          -webkit-mask-box-image-source: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+          mask-border-source: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
         }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
 
-        .foo:is(*|x) {
-          color: red;
+    prefix_test(
+      r#"
+        .foo {
+          mask-border-source: url(foo.png);
+          mask-border-slice: 10 40 10 40;
+          mask-border-width: 10px;
+          mask-border-outset: 0;
+          mask-border-repeat: round round;
+          mask-border-mode: luminance;
         }
-
-        .foo:is(toto|x) {
-          color: red;
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image: url("foo.png") 10 40 / 10px round;
+          mask-border: url("foo.png") 10 40 / 10px round luminance;
         }
-      "#},
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
-      r#"
-        .foo .bar {
-          :is(h1) .baz& {
-            background: green;
-          }
+    prefix_test(
+      r#"
+        .foo {
+          -webkit-mask-box-image-source: url(foo.png);
+          -webkit-mask-box-image-slice: 10 40 10 40;
+          -webkit-mask-box-image-width: 10px;
+          -webkit-mask-box-image-outset: 0;
+          -webkit-mask-box-image-repeat: round round;
         }
       "#,
-      indoc! {r#"
-        :is(h1) .baz:is(.foo .bar) {
-          background: green;
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image: url("foo.png") 10 40 / 10px round;
+          mask-border: url("foo.png") 10 40 / 10px round;
         }
-      "#},
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
-      r#"
-        .foo .bar {
-          .baz& {
-            background: green;
-          }
+    prefix_test(
+      ".foo { -webkit-mask-box-image: url(foo.png) 30 fill / 10px round }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image: url("foo.png") 30 fill / 10px round;
+          mask-border: url("foo.png") 30 fill / 10px round;
         }
-      "#,
-      indoc! {r#"
-        .baz:is(.foo .bar) {
-          background: green;
+    "#},
+      Browsers {
+        safari: Some(15 << 16),
+        firefox: Some(120 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { -webkit-mask-box-image: url(foo.png) 30 }",
+      indoc! { r#"
+        .foo {
+          mask-border: url("foo.png") 30;
         }
-      "#},
+    "#},
+      Browsers {
+        firefox: Some(120 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { -webkit-mask-box-image-source: url(foo.png); -webkit-mask-box-image-slice: 30 }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image-source: url("foo.png");
+          mask-border-source: url("foo.png");
+          -webkit-mask-box-image-slice: 30;
+          mask-border-slice: 30;
+        }
+    "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
+    minify_test(
+      ".foo { -webkit-mask-box-image: url(foo.png) 30 }",
+      ".foo{-webkit-mask-box-image:url(foo.png) 30}",
+    );
+    minify_test_with_minify_options(
+      ".foo { -webkit-mask-box-image: url(foo.png) 30 }",
+      ".foo{-webkit-mask-box-image:url(foo.png) 30}",
+      MinifyOptions {
+        targets: Targets {
+          browsers: Some(Browsers {
+            firefox: Some(120 << 16),
+            ..Browsers::default()
+          }),
+          exclude: Features::VendorPrefixes,
+          ..Targets::default()
+        },
+        ..MinifyOptions::default()
+      },
     );
 
-    nesting_test(
+    prefix_test(
       r#"
-        .foo .bar {
-          .baz & {
-            background: green;
-          }
+        .foo {
+          mask-border-slice: 10 40 10 40;
         }
       "#,
-      indoc! {r#"
-        .baz :is(.foo .bar) {
-          background: green;
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image-slice: 10 40;
+          mask-border-slice: 10 40;
         }
-      "#},
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
+    prefix_test(
       r#"
         .foo {
-          .bar {
-            color: blue;
-          }
-          color: red;
+          mask-border-slice: var(--foo);
         }
       "#,
-      indoc! {r#"
+      indoc! { r#"
         .foo {
-          color: red;
-        }
-
-        .foo .bar {
-          color: #00f;
+          -webkit-mask-box-image-slice: var(--foo);
+          mask-border-slice: var(--foo);
         }
-      "#},
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
+    prefix_test(
       r#"
-        article {
-          color: green;
-          & { color: blue; }
-          color: red;
+        .foo {
+          mask-border: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) var(--foo);
         }
       "#,
-      indoc! {r#"
-        article {
-          color: red;
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image: linear-gradient(#ff0f0e, #7773ff) var(--foo);
+          mask-border: linear-gradient(#ff0f0e, #7773ff) var(--foo);
         }
 
-        article {
-          color: #00f;
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            -webkit-mask-box-image: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) var(--foo);
+            mask-border: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) var(--foo);
+          }
         }
-      "#},
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
+    prefix_test(
       r#"
-        & .foo {
-          color: red;
+        .foo {
+          transition: mask 200ms;
         }
       "#,
-      indoc! {r#"
-        :scope .foo {
-          color: red;
+      indoc! { r#"
+        .foo {
+          transition: -webkit-mask .2s, mask .2s;
         }
-      "#},
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
+    prefix_test(
       r#"
-        &.foo {
-          color: red;
+        .foo {
+          transition: mask-border 200ms;
         }
       "#,
-      indoc! {r#"
-        :scope.foo {
-          color: red;
+      indoc! { r#"
+        .foo {
+          transition: -webkit-mask-box-image .2s, mask-border .2s;
         }
-      "#},
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
+    prefix_test(
       r#"
-        .foo& {
-          color: red;
+        .foo {
+          transition-property: mask;
         }
       "#,
-      indoc! {r#"
-        .foo:scope {
-          color: red;
+      indoc! { r#"
+        .foo {
+          transition-property: -webkit-mask, mask;
         }
-      "#},
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
+    prefix_test(
       r#"
-        &html {
-          color: red;
+        .foo {
+          transition-property: mask-border;
         }
       "#,
-      indoc! {r#"
-        html:scope {
-          color: red;
+      indoc! { r#"
+        .foo {
+          transition-property: -webkit-mask-box-image, mask-border;
         }
-      "#},
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test(
+    prefix_test(
       r#"
         .foo {
-          color: blue;
-          div {
-            color: red;
-          }
+          transition-property: mask-composite, mask-mode;
         }
       "#,
-      indoc! {r#"
+      indoc! { r#"
         .foo {
-          color: #00f;
-        }
-
-        .foo div {
-          color: red;
+          transition-property: -webkit-mask-composite, mask-composite, -webkit-mask-source-type, mask-mode;
         }
-      "#},
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
+  }
 
-    nesting_test(
-      r#"
-        div {
-          color: blue;
-
-          button:focus {
-            color: red;
-          }
-        }
-      "#,
-      indoc! {r#"
-        div {
-          color: #00f;
-        }
+  #[test]
+  fn test_filter() {
+    minify_test(
+      ".foo { filter: url('filters.svg#filter-id'); }",
+      ".foo{filter:url(filters.svg#filter-id)}",
+    );
+    minify_test(".foo { filter: blur(5px); }", ".foo{filter:blur(5px)}");
+    minify_test(".foo { filter: blur(0px); }", ".foo{filter:blur()}");
+    minify_test(".foo { filter: brightness(10%); }", ".foo{filter:brightness(10%)}");
+    minify_test(".foo { filter: brightness(100%); }", ".foo{filter:brightness()}");
+    minify_test(
+      ".foo { filter: drop-shadow(16px 16px 20px yellow); }",
+      ".foo{filter:drop-shadow(16px 16px 20px #ff0)}",
+    );
+    minify_test(
+      ".foo { filter: contrast(175%) brightness(3%); }",
+      ".foo{filter:contrast(175%)brightness(3%)}",
+    );
+    minify_test(".foo { filter: hue-rotate(0) }", ".foo{filter:hue-rotate()}");
 
-        div button:focus {
-          color: red;
+    prefix_test(
+      ".foo { filter: blur(5px) }",
+      indoc! { r#"
+        .foo {
+          -webkit-filter: blur(5px);
+          filter: blur(5px);
         }
       "#},
+      Browsers {
+        chrome: Some(20 << 16),
+        ..Browsers::default()
+      },
     );
-    nesting_test(
-      r#"
-        div {
-          color: blue;
 
-          --button:focus {
-            color: red;
-          }
-        }
-      "#,
-      indoc! {r#"
-        div {
-          color: #00f;
-          --button: focus { color: red; };
+    prefix_test(
+      ".foo { filter: blur(5px) }",
+      indoc! { r#"
+        .foo {
+          filter: blur(5px);
         }
       "#},
+      Browsers {
+        chrome: Some(80 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test_no_targets(
-      r#"
-        .foo {
-          color: blue;
-          @nest .bar & {
-            color: red;
-            &.baz {
-              color: green;
-            }
-          }
-        }
-      "#,
-      indoc! {r#"
+    prefix_test(
+      ".foo { backdrop-filter: blur(5px) }",
+      indoc! { r#"
         .foo {
-          color: #00f;
-
-          @nest .bar & {
-            color: red;
-
-            &.baz {
-              color: green;
-            }
-          }
+          backdrop-filter: blur(5px);
         }
       "#},
+      Browsers {
+        chrome: Some(80 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test_no_targets(
-      r#"
-        .foo {
-          color: blue;
-          &div {
-            color: red;
-          }
-
-          &span {
-            color: purple;
-          }
-        }
-      "#,
-      indoc! {r#"
+    prefix_test(
+      ".foo { backdrop-filter: blur(5px) }",
+      indoc! { r#"
         .foo {
-          color: #00f;
-
-          &div {
-            color: red;
-          }
-
-          &span {
-            color: purple;
-          }
+          -webkit-backdrop-filter: blur(5px);
+          backdrop-filter: blur(5px);
         }
       "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
     );
-
-    nesting_test_no_targets(
+    prefix_test(
       r#"
-        .error, .invalid {
-          &:hover > .baz { color: red; }
-        }
+      .foo {
+        -webkit-backdrop-filter: blur(8px);
+        backdrop-filter: blur(8px);
+      }
       "#,
       indoc! {r#"
-        .error, .invalid {
-          &:hover > .baz {
-            color: red;
-          }
-        }
+      .foo {
+        -webkit-backdrop-filter: blur(8px);
+        backdrop-filter: blur(8px);
+      }
       "#},
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
     );
 
-    nesting_test_with_targets(
-      r#"
-        .foo {
-          color: blue;
-          & > .bar { color: red; }
-        }
-      "#,
-      indoc! {r#"
+    prefix_test(
+      ".foo { filter: var(--foo) }",
+      indoc! { r#"
         .foo {
-          color: #00f;
+          -webkit-filter: var(--foo);
+          filter: var(--foo);
         }
+      "#},
+      Browsers {
+        chrome: Some(20 << 16),
+        ..Browsers::default()
+      },
+    );
 
-        .foo > .bar {
-          color: red;
+    prefix_test(
+      ".foo { filter: drop-shadow(16px 16px 20px lab(40% 56.6 39)) }",
+      indoc! { r#"
+        .foo {
+          -webkit-filter: drop-shadow(16px 16px 20px #b32323);
+          filter: drop-shadow(16px 16px 20px #b32323);
+          filter: drop-shadow(16px 16px 20px lab(40% 56.6 39));
         }
       "#},
-      Targets {
-        browsers: Some(Browsers {
-          chrome: Some(112 << 16),
-          ..Browsers::default()
-        }),
-        include: Features::Nesting,
-        exclude: Features::empty(),
+      Browsers {
+        chrome: Some(20 << 16),
+        ..Browsers::default()
       },
     );
-    nesting_test_with_targets(
-      r#"
+
+    prefix_test(
+      ".foo { filter: contrast(175%) drop-shadow(16px 16px 20px lab(40% 56.6 39)) }",
+      indoc! { r#"
         .foo {
-          color: blue;
-          & > .bar { color: red; }
+          filter: contrast(175%) drop-shadow(16px 16px 20px #b32323);
+          filter: contrast(175%) drop-shadow(16px 16px 20px lab(40% 56.6 39));
         }
-      "#,
-      indoc! {r#"
-        .foo {
-          color: #00f;
+      "#},
+      Browsers {
+        chrome: Some(4 << 16),
+        ..Browsers::default()
+      },
+    );
 
-          & > .bar {
-            color: red;
-          }
+    prefix_test(
+      ".foo { filter: drop-shadow(16px 16px 20px lab(40% 56.6 39)) drop-shadow(16px 16px 20px yellow) }",
+      indoc! { r#"
+        .foo {
+          filter: drop-shadow(16px 16px 20px #b32323) drop-shadow(16px 16px 20px #ff0);
+          filter: drop-shadow(16px 16px 20px lab(40% 56.6 39)) drop-shadow(16px 16px 20px #ff0);
         }
       "#},
-      Targets {
-        browsers: Some(Browsers {
-          chrome: Some(50 << 16),
-          ..Browsers::default()
-        }),
-        include: Features::empty(),
-        exclude: Features::Nesting,
+      Browsers {
+        chrome: Some(4 << 16),
+        ..Browsers::default()
       },
     );
 
-    let mut stylesheet = StyleSheet::parse(
-      r#"
-      .foo {
-        color: blue;
-        .bar {
-          color: red;
+    prefix_test(
+      ".foo { filter: var(--foo) drop-shadow(16px 16px 20px lab(40% 56.6 39)) }",
+      indoc! { r#"
+        .foo {
+          filter: var(--foo) drop-shadow(16px 16px 20px #b32323);
         }
-      }
-      "#,
-      ParserOptions::default(),
-    )
-    .unwrap();
-    stylesheet.minify(MinifyOptions::default()).unwrap();
-    let res = stylesheet
-      .to_css(PrinterOptions {
-        minify: true,
-        ..PrinterOptions::default()
-      })
-      .unwrap();
-    assert_eq!(res.code, ".foo{color:#00f;& .bar{color:red}}");
 
-    nesting_test_with_targets(
-      r#"
-        .a {
-          &.b,
-          &.c {
-            &.d {
-              color: red;
-            }
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            filter: var(--foo) drop-shadow(16px 16px 20px lab(40% 56.6 39));
           }
         }
-      "#,
-      indoc! {r#"
-        .a.b.d {
-          color: red;
-        }
-
-        .a.c.d {
-          color: red;
-        }
       "#},
-      Targets {
-        browsers: Some(Browsers {
-          safari: Some(13 << 16),
-          ..Browsers::default()
-        }),
-        include: Features::Nesting,
-        exclude: Features::empty(),
+      Browsers {
+        chrome: Some(4 << 16),
+        ..Browsers::default()
       },
     );
   }
 
   #[test]
-  fn test_css_modules() {
-    css_modules_test(
-      r#"
-      .foo {
-        color: red;
-      }
-
-      #id {
-        animation: 2s test;
-      }
-
-      @keyframes test {
-        from { color: red }
-        to { color: yellow }
-      }
-
-      @counter-style circles {
-        symbols: Ⓐ Ⓑ Ⓒ;
-      }
-
-      ul {
-        list-style: circles;
-      }
-
-      ol {
-        list-style-type: none;
-      }
-
-      li {
-        list-style-type: disc;
-      }
-
-      @keyframes fade {
-        from { opacity: 0 }
-        to { opacity: 1 }
-      }
-    "#,
-      indoc! {r#"
-      .EgL3uq_foo {
-        color: red;
-      }
-
-      #EgL3uq_id {
-        animation: 2s EgL3uq_test;
-      }
+  fn test_zoom() {
+    minify_test(".foo { zoom: normal }", ".foo{zoom:normal}");
+    minify_test(".foo { zoom: 1.50 }", ".foo{zoom:1.5}");
+    minify_test(".foo { zoom: 150% }", ".foo{zoom:1.5}");
+    minify_test(".foo { zoom: 100% }", ".foo{zoom:1}");
+    minify_test(".foo { zoom: 1% }", ".foo{zoom:1%}");
+    minify_test(".foo { zoom: 0 }", ".foo{zoom:0}");
+    minify_test(".foo { zoom: calc(50% + 25%) }", ".foo{zoom:75%}");
+    minify_test(".foo { zoom: -1 }", ".foo{zoom:-1}");
+
+    let property = Property::parse_string("zoom".into(), "-50%", ParserOptions::default()).unwrap();
+    assert!(matches!(property, Property::Unparsed(..)));
+
+    let property = Property::parse_string("zoom".into(), "2", ParserOptions::default()).unwrap();
+    assert_eq!(
+      property.is_compatible(Browsers {
+        firefox: Some(126 << 16),
+        ..Browsers::default()
+      }),
+      Some(true)
+    );
+    assert_eq!(
+      property.is_compatible(Browsers {
+        firefox: Some(120 << 16),
+        ..Browsers::default()
+      }),
+      Some(false)
+    );
+  }
 
-      @keyframes EgL3uq_test {
-        from {
-          color: red;
-        }
+  #[test]
+  fn test_viewport() {
+    minify_test(
+      r#"
+    @viewport {
+      width: 100vw;
+    }"#,
+      "@viewport{width:100vw}",
+    );
+    minify_test(
+      r#"
+    @-ms-viewport {
+      width: device-width;
+    }"#,
+      "@-ms-viewport{width:device-width}",
+    );
+  }
 
-        to {
-          color: #ff0;
+  #[test]
+  fn test_at_scope() {
+    minify_test(
+      r#"
+      @scope {
+        .foo {
+          display: flex;
         }
       }
-
-      @counter-style EgL3uq_circles {
-        symbols: Ⓐ Ⓑ Ⓒ;
+      "#,
+      "@scope{.foo{display:flex}}",
+    );
+    minify_test(
+      r#"
+      @scope {
+        :scope {
+          display: flex;
+          color: lightblue;
+        }
+      }"#,
+      "@scope{:scope{color:#add8e6;display:flex}}",
+    );
+    minify_test(
+      r#"
+      @scope (.light-scheme) {
+        a { color: yellow; }
       }
-
-      ul {
-        list-style: EgL3uq_circles;
+      "#,
+      "@scope(.light-scheme){a{color:#ff0}}",
+    );
+    minify_test(
+      r#"
+      @scope (.media-object) to (.content > *) {
+        a { color: yellow; }
       }
-
-      ol {
-        list-style-type: none;
+      "#,
+      "@scope(.media-object) to (.content>*){a{color:#ff0}}",
+    );
+    minify_test(
+      r#"
+      @scope to (.content > *) {
+        a { color: yellow; }
       }
-
-      li {
-        list-style-type: disc;
+      "#,
+      "@scope to (.content>*){a{color:#ff0}}",
+    );
+    minify_test(
+      r#"
+      @scope (#my-component) {
+        & { color: yellow; }
       }
-
-      @keyframes EgL3uq_fade {
-        from {
-          opacity: 0;
-        }
-
-        to {
-          opacity: 1;
+      "#,
+      "@scope(#my-component){&{color:#ff0}}",
+    );
+    minify_test(
+      r#"
+      @scope (.parent-scope) {
+        @scope (:scope > .child-scope) to (:scope .limit) {
+          .content { color: yellow; }
         }
       }
-    "#},
-      map! {
-        "foo" => "EgL3uq_foo",
-        "id" => "EgL3uq_id",
-        "test" => "EgL3uq_test" referenced: true,
-        "circles" => "EgL3uq_circles" referenced: true,
-        "fade" => "EgL3uq_fade"
-      },
-      HashMap::new(),
-      Default::default(),
+      "#,
+      "@scope(.parent-scope){@scope(:scope>.child-scope) to (:scope .limit){.content{color:#ff0}}}",
     );
-
-    css_modules_test(
+    minify_test(
       r#"
       .foo {
-        color: red;
-      }
-
-      #id {
-        animation: 2s test;
+        @scope (.bar) {
+          color: yellow;
+        }
       }
-
-      @keyframes test {
-        from { color: red }
-        to { color: yellow }
+      "#,
+      ".foo{@scope(.bar){&{color:#ff0}}}",
+    );
+    nesting_test(
+      r#"
+      .foo {
+        @scope (.bar) {
+          color: yellow;
+        }
       }
-    "#,
+      "#,
       indoc! {r#"
-      .EgL3uq_foo {
-        color: red;
-      }
+        @scope (.bar) {
+          :scope {
+            color: #ff0;
+          }
+        }
+      "#},
+    );
+    nesting_test(
+      r#"
+      .parent {
+        color: blue;
 
-      #EgL3uq_id {
-        animation: 2s test;
+        @scope (& > .scope) to (& .limit) {
+          & .content {
+            color: yellow;
+          }
+        }
       }
-
-      @keyframes test {
-        from {
-          color: red;
+      "#,
+      indoc! {r#"
+        .parent {
+          color: #00f;
         }
 
-        to {
-          color: #ff0;
+        @scope (.parent > .scope) to (.parent > .scope .limit) {
+          :scope .content {
+            color: #ff0;
+          }
         }
-      }
-    "#},
-      map! {
-        "foo" => "EgL3uq_foo",
-        "id" => "EgL3uq_id"
-      },
-      HashMap::new(),
-      crate::css_modules::Config {
-        animation: false,
-        // custom_idents: false,
-        ..Default::default()
-      },
+      "#},
     );
+  }
 
-    css_modules_test(
+  #[test]
+  fn test_custom_media() {
+    custom_media_test(
       r#"
-      @counter-style circles {
-        symbols: Ⓐ Ⓑ Ⓒ;
-      }
-
-      ul {
-        list-style: circles;
-      }
-
-      ol {
-        list-style-type: none;
-      }
+      @custom-media --modern (color), (hover);
 
-      li {
-        list-style-type: disc;
+      @media (--modern) and (width > 1024px) {
+        .a {
+          color: green;
+        }
       }
-    "#,
+      "#,
       indoc! {r#"
-      @counter-style circles {
-        symbols: Ⓐ Ⓑ Ⓒ;
+      @media ((color) or (hover)) and (width > 1024px) {
+        .a {
+          color: green;
+        }
       }
+      "#},
+    );
 
-      ul {
-        list-style: circles;
-      }
+    custom_media_test(
+      r#"
+      @custom-media --color (color);
 
-      ol {
-        list-style-type: none;
+      @media (--color) and (width > 1024px) {
+        .a {
+          color: green;
+        }
       }
-
-      li {
-        list-style-type: disc;
+      "#,
+      indoc! {r#"
+      @media (color) and (width > 1024px) {
+        .a {
+          color: green;
+        }
       }
-    "#},
-      map! {
-        "circles" => "EgL3uq_circles" referenced: true
-      },
-      HashMap::new(),
-      crate::css_modules::Config {
-        custom_idents: false,
-        ..Default::default()
-      },
+      "#},
     );
 
-    #[cfg(feature = "grid")]
-    css_modules_test(
+    custom_media_test(
       r#"
-      body {
-        grid: [header-top] "a a a" [header-bottom]
-              [main-top] "b b b" 1fr [main-bottom]
-              / auto 1fr auto;
-      }
-
-      header {
-        grid-area: a;
-      }
+      @custom-media --a (color);
+      @custom-media --b (--a);
 
-      main {
-        grid-row: main-top / main-bottom;
+      @media (--b) and (width > 1024px) {
+        .a {
+          color: green;
+        }
       }
-    "#,
+      "#,
       indoc! {r#"
-      body {
-        grid: [EgL3uq_header-top] "EgL3uq_a EgL3uq_a EgL3uq_a" [EgL3uq_header-bottom]
-              [EgL3uq_main-top] "EgL3uq_b EgL3uq_b EgL3uq_b" 1fr [EgL3uq_main-bottom]
-              / auto 1fr auto;
-      }
-
-      header {
-        grid-area: EgL3uq_a;
-      }
-
-      main {
-        grid-row: EgL3uq_main-top / EgL3uq_main-bottom;
+      @media (color) and (width > 1024px) {
+        .a {
+          color: green;
+        }
       }
-    "#},
-      map! {
-        "header-top" => "EgL3uq_header-top",
-        "header-bottom" => "EgL3uq_header-bottom",
-        "main-top" => "EgL3uq_main-top",
-        "main-bottom" => "EgL3uq_main-bottom",
-        "a" => "EgL3uq_a",
-        "b" => "EgL3uq_b"
-      },
-      HashMap::new(),
-      Default::default(),
+      "#},
     );
 
-    #[cfg(feature = "grid")]
-    css_modules_test(
+    custom_media_test(
       r#"
-        .grid {
-          grid-template-areas: "foo";
-        }
-
-        .foo {
-          grid-area: foo;
-        }
+      @custom-media --not-color not (color);
 
-        .bar {
-          grid-column-start: foo-start;
+      @media not (--not-color) {
+        .a {
+          color: green;
         }
+      }
       "#,
       indoc! {r#"
-        .EgL3uq_grid {
-          grid-template-areas: "EgL3uq_foo";
-        }
-
-        .EgL3uq_foo {
-          grid-area: EgL3uq_foo;
-        }
-
-        .EgL3uq_bar {
-          grid-column-start: EgL3uq_foo-start;
+      @media (color) {
+        .a {
+          color: green;
         }
+      }
       "#},
-      map! {
-        "foo" => "EgL3uq_foo",
-        "foo-start" => "EgL3uq_foo-start",
-        "grid" => "EgL3uq_grid",
-        "bar" => "EgL3uq_bar"
-      },
-      HashMap::new(),
-      Default::default(),
     );
 
-    #[cfg(feature = "grid")]
-    css_modules_test(
+    custom_media_test(
       r#"
-        .grid {
-          grid-template-areas: "foo";
-        }
-
-        .foo {
-          grid-area: foo;
-        }
+      @custom-media --color-print print and (color);
 
-        .bar {
-          grid-column-start: foo-start;
+      @media (--color-print) {
+        .a {
+          color: green;
         }
+      }
       "#,
       indoc! {r#"
-        .EgL3uq_grid {
-          grid-template-areas: "foo";
+      @media print and (color) {
+        .a {
+          color: green;
         }
+      }
+      "#},
+    );
 
-        .EgL3uq_foo {
-          grid-area: foo;
-        }
+    custom_media_test(
+      r#"
+      @custom-media --color-print print and (color);
 
-        .EgL3uq_bar {
-          grid-column-start: foo-start;
+      @media print and (--color-print) {
+        .a {
+          color: green;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @media print and (color) {
+        .a {
+          color: green;
         }
+      }
       "#},
-      map! {
-        "foo" => "EgL3uq_foo",
-        "grid" => "EgL3uq_grid",
-        "bar" => "EgL3uq_bar"
-      },
-      HashMap::new(),
-      crate::css_modules::Config {
-        grid: false,
-        ..Default::default()
-      },
     );
 
-    css_modules_test(
+    custom_media_test(
       r#"
-      test {
-        transition-property: opacity;
+      @custom-media --not-color-print not print and (color);
+
+      @media not print and (--not-color-print) {
+        .a {
+          color: green;
+        }
       }
-    "#,
+      "#,
       indoc! {r#"
-      test {
-        transition-property: opacity;
+      @media not print and (color) {
+        .a {
+          color: green;
+        }
       }
-    "#},
-      map! {},
-      HashMap::new(),
-      Default::default(),
+      "#},
     );
 
-    css_modules_test(
+    custom_media_test(
       r#"
-      :global(.foo) {
-        color: red;
-      }
-
-      :local(.bar) {
-        color: yellow;
-      }
+      @custom-media --print print;
 
-      .bar :global(.baz) {
-        color: purple;
+      @media (--print) {
+        .a {
+          color: green;
+        }
       }
-    "#,
+      "#,
       indoc! {r#"
-      .foo {
-        color: red;
+      @media print {
+        .a {
+          color: green;
+        }
       }
+      "#},
+    );
 
-      .EgL3uq_bar {
-        color: #ff0;
-      }
+    custom_media_test(
+      r#"
+      @custom-media --print print;
 
-      .EgL3uq_bar .baz {
-        color: purple;
+      @media not (--print) {
+        .a {
+          color: green;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @media not print {
+        .a {
+          color: green;
+        }
       }
-    "#},
-      map! {
-        "bar" => "EgL3uq_bar"
-      },
-      HashMap::new(),
-      Default::default(),
+      "#},
     );
 
-    // :global(:local(.hi)) {
-    //   color: green;
-    // }
-
-    css_modules_test(
+    custom_media_test(
       r#"
-      .test {
-        composes: foo;
-        background: white;
-      }
+      @custom-media --print not print;
 
-      .foo {
-        color: red;
+      @media not (--print) {
+        .a {
+          color: green;
+        }
       }
-    "#,
+      "#,
       indoc! {r#"
-      .EgL3uq_test {
-        background: #fff;
-      }
-
-      .EgL3uq_foo {
-        color: red;
+      @media print {
+        .a {
+          color: green;
+        }
       }
-    "#},
-      map! {
-        "test" => "EgL3uq_test" "EgL3uq_foo",
-        "foo" => "EgL3uq_foo"
-      },
-      HashMap::new(),
-      Default::default(),
+      "#},
     );
 
-    css_modules_test(
+    custom_media_test(
       r#"
-      .a, .b {
-        composes: foo;
-        background: white;
-      }
+      @custom-media --print print;
 
-      .foo {
-        color: red;
+      @media ((--print)) {
+        .a {
+          color: green;
+        }
       }
-    "#,
+      "#,
       indoc! {r#"
-      .EgL3uq_a, .EgL3uq_b {
-        background: #fff;
-      }
-
-      .EgL3uq_foo {
-        color: red;
+      @media print {
+        .a {
+          color: green;
+        }
       }
-    "#},
-      map! {
-        "a" => "EgL3uq_a" "EgL3uq_foo",
-        "b" => "EgL3uq_b" "EgL3uq_foo",
-        "foo" => "EgL3uq_foo"
-      },
-      HashMap::new(),
-      Default::default(),
+      "#},
     );
 
-    css_modules_test(
+    custom_media_test(
       r#"
-      .test {
-        composes: foo bar;
-        background: white;
-      }
-
-      .foo {
-        color: red;
-      }
+      @custom-media --color (color);
+      @custom-media --print print;
 
-      .bar {
-        color: yellow;
+      @media (--print) and (--color) {
+        .a {
+          color: green;
+        }
       }
-    "#,
+      "#,
       indoc! {r#"
-      .EgL3uq_test {
-        background: #fff;
+      @media print and (color) {
+        .a {
+          color: green;
+        }
       }
+      "#},
+    );
 
-      .EgL3uq_foo {
-        color: red;
-      }
+    custom_media_test(
+      r#"
+      @custom-media --color (color);
+      @custom-media --not-print not print;
 
-      .EgL3uq_bar {
-        color: #ff0;
+      @media (--not-print) and (--color) {
+        .a {
+          color: green;
+        }
       }
-    "#},
-      map! {
-        "test" => "EgL3uq_test" "EgL3uq_foo" "EgL3uq_bar",
-        "foo" => "EgL3uq_foo",
-        "bar" => "EgL3uq_bar"
-      },
-      HashMap::new(),
-      Default::default(),
+      "#,
+      indoc! {r#"
+      @media not print and (color) {
+        .a {
+          color: green;
+        }
+      }
+      "#},
     );
 
-    css_modules_test(
+    custom_media_test(
       r#"
-      .test {
-        composes: foo from global;
-        background: white;
+      @custom-media --color (color);
+      @custom-media --screen screen;
+      @custom-media --print print;
+
+      @media (--print) and (--color), (--screen) and (--color) {
+        .a {
+          color: green;
+        }
       }
-    "#,
+      "#,
       indoc! {r#"
-      .EgL3uq_test {
-        background: #fff;
+      @media print and (color), screen and (color) {
+        .a {
+          color: green;
+        }
       }
-    "#},
-      map! {
-        "test" => "EgL3uq_test" "foo" global: true
-      },
-      HashMap::new(),
-      Default::default(),
+      "#},
     );
 
-    css_modules_test(
+    custom_media_test(
       r#"
-      .test {
-        composes: foo bar from global;
-        background: white;
+      @custom-media --color print and (color), print and (script);
+
+      @media (--color) {
+        .a {
+          color: green;
+        }
       }
-    "#,
+      "#,
       indoc! {r#"
-      .EgL3uq_test {
-        background: #fff;
+      @media print and ((color) or (script)) {
+        .a {
+          color: green;
+        }
       }
-    "#},
-      map! {
-        "test" => "EgL3uq_test" "foo" global: true "bar" global: true
-      },
-      HashMap::new(),
-      Default::default(),
+      "#},
     );
 
-    css_modules_test(
+    custom_media_test(
       r#"
-      .test {
-        composes: foo from "foo.css";
-        background: white;
+      @custom-media --color (color);
+      @custom-media --not-color not all and (--color);
+
+      @media (--not-color) {
+        .a {
+          color: green;
+        }
       }
-    "#,
+      "#,
       indoc! {r#"
-      .EgL3uq_test {
-        background: #fff;
-      }
-    "#},
-      map! {
-        "test" => "EgL3uq_test" "foo" from "foo.css"
-      },
-      HashMap::new(),
-      Default::default(),
+        @media not all and (color) {
+          .a {
+            color: green;
+          }
+        }
+      "#},
     );
 
-    css_modules_test(
+    custom_media_test(
       r#"
-      .test {
-        composes: foo bar from "foo.css";
-        background: white;
+      @custom-media --color (color);
+
+      @media not all and (--color) {
+        .a {
+          color: green;
+        }
       }
-    "#,
+      "#,
       indoc! {r#"
-      .EgL3uq_test {
-        background: #fff;
-      }
-    "#},
-      map! {
-        "test" => "EgL3uq_test" "foo" from "foo.css" "bar" from "foo.css"
-      },
-      HashMap::new(),
-      Default::default(),
+        @media not all and (color) {
+          .a {
+            color: green;
+          }
+        }
+      "#},
     );
 
-    css_modules_test(
+    custom_media_test(
       r#"
-      .test {
-        composes: foo;
-        composes: foo from "foo.css";
-        composes: bar from "bar.css";
-        background: white;
+      @media (--print) {
+        .a {
+          color: green;
+        }
       }
 
-      .foo {
-        color: red;
-      }
-    "#,
+      @custom-media --print print;
+      "#,
       indoc! {r#"
-      .EgL3uq_test {
-        background: #fff;
-      }
-
-      .EgL3uq_foo {
-        color: red;
+      @media print {
+        .a {
+          color: green;
+        }
       }
-    "#},
-      map! {
-        "test" => "EgL3uq_test" "EgL3uq_foo" "foo" from "foo.css" "bar" from "bar.css",
-        "foo" => "EgL3uq_foo"
-      },
-      HashMap::new(),
-      Default::default(),
+      "#},
     );
 
-    css_modules_test(
+    custom_media_test(
       r#"
-      .foo {
-        color: red;
+      @custom-media --not-width not (min-width: 300px);
+      @media screen and ((prefers-color-scheme: dark) or (--not-width)) {
+        .foo {
+          order: 6;
+        }
       }
-    "#,
+      "#,
       indoc! {r#"
-      .test-EgL3uq-foo {
-        color: red;
+      @media screen and ((prefers-color-scheme: dark) or (not (width >= 300px))) {
+        .foo {
+          order: 6;
+        }
       }
-    "#},
-      map! {
-        "foo" => "test-EgL3uq-foo"
-      },
-      HashMap::new(),
-      crate::css_modules::Config {
-        pattern: crate::css_modules::Pattern::parse("test-[hash]-[local]").unwrap(),
-        ..Default::default()
-      },
+      "#},
     );
 
-    let stylesheet = StyleSheet::parse(
-      r#"
-        .grid {
-          grid-template-areas: "foo";
+    fn custom_media_error_test(source: &str, err: Error<MinifyErrorKind>) {
+      let mut stylesheet = StyleSheet::parse(
+        &source,
+        ParserOptions {
+          filename: "test.css".into(),
+          flags: ParserFlags::CUSTOM_MEDIA,
+          ..ParserOptions::default()
+        },
+      )
+      .unwrap();
+      let res = stylesheet.minify(MinifyOptions {
+        targets: Browsers {
+          chrome: Some(95 << 16),
+          ..Browsers::default()
         }
+        .into(),
+        ..MinifyOptions::default()
+      });
+      assert_eq!(res, Err(err))
+    }
 
-        .foo {
-          grid-area: foo;
-        }
+    custom_media_error_test(
+      r#"
+      @custom-media --color-print print and (color);
 
-        .bar {
-          grid-column-start: foo-start;
+      @media screen and (--color-print) {
+        .a {
+          color: green;
         }
+      }
       "#,
-      ParserOptions {
-        css_modules: Some(crate::css_modules::Config {
-          pattern: crate::css_modules::Pattern::parse("test-[local]-[hash]").unwrap(),
-          ..Default::default()
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 1,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 3,
+          column: 7,
         }),
-        ..ParserOptions::default()
       },
-    )
-    .unwrap();
-    if let Err(err) = stylesheet.to_css(PrinterOptions::default()) {
-      assert_eq!(err.kind, PrinterErrorKind::InvalidCssModulesPatternInGrid);
-    } else {
-      unreachable!()
-    }
+    );
 
-    css_modules_test(
+    custom_media_error_test(
       r#"
-      @property --foo {
-        syntax: '<color>';
-        inherits: false;
-        initial-value: yellow;
-      }
-
-      .foo {
-        --foo: red;
-        color: var(--foo);
-      }
-    "#,
-      indoc! {r#"
-      @property --foo {
-        syntax: "<color>";
-        inherits: false;
-        initial-value: #ff0;
-      }
+      @custom-media --color-print print and (color);
 
-      .EgL3uq_foo {
-        --foo: red;
-        color: var(--foo);
+      @media not print and (--color-print) {
+        .a {
+          color: green;
+        }
       }
-    "#},
-      map! {
-        "foo" => "EgL3uq_foo"
+      "#,
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 1,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 3,
+          column: 7,
+        }),
       },
-      HashMap::new(),
-      Default::default(),
     );
 
-    css_modules_test(
+    custom_media_error_test(
       r#"
-      @property --foo {
-        syntax: '<color>';
-        inherits: false;
-        initial-value: yellow;
-      }
-
-      @font-palette-values --Cooler {
-        font-family: Bixa;
-        base-palette: 1;
-        override-colors: 1 #7EB7E4;
-      }
-
-      .foo {
-        --foo: red;
-        --bar: green;
-        color: var(--foo);
-        font-palette: --Cooler;
-      }
-
-      .bar {
-        color: var(--color from "./b.css");
-      }
-    "#,
-      indoc! {r#"
-      @property --EgL3uq_foo {
-        syntax: "<color>";
-        inherits: false;
-        initial-value: #ff0;
-      }
+      @custom-media --color-print print and (color);
+      @custom-media --color-screen screen and (color);
 
-      @font-palette-values --EgL3uq_Cooler {
-        font-family: Bixa;
-        base-palette: 1;
-        override-colors: 1 #7eb7e4;
-      }
+      @media (--color-print) or (--color-screen) {}
+      "#,
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 2,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 4,
+          column: 7,
+        }),
+      },
+    );
 
-      .EgL3uq_foo {
-        --EgL3uq_foo: red;
-        --EgL3uq_bar: green;
-        color: var(--EgL3uq_foo);
-        font-palette: --EgL3uq_Cooler;
-      }
+    custom_media_error_test(
+      r#"
+      @custom-media --color-print print and (color);
+      @custom-media --color-screen screen and (color);
 
-      .EgL3uq_bar {
-        color: var(--ma1CsG);
-      }
-    "#},
-      map! {
-        "foo" => "EgL3uq_foo",
-        "--foo" => "--EgL3uq_foo" referenced: true,
-        "--bar" => "--EgL3uq_bar",
-        "bar" => "EgL3uq_bar",
-        "--Cooler" => "--EgL3uq_Cooler" referenced: true
-      },
-      HashMap::from([(
-        "--ma1CsG".into(),
-        CssModuleReference::Dependency {
-          name: "--color".into(),
-          specifier: "./b.css".into(),
+      @media (--color-print) and (--color-screen) {}
+      "#,
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 2,
+            column: 7,
+          },
         },
-      )]),
-      crate::css_modules::Config {
-        dashed_idents: true,
-        ..Default::default()
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 4,
+          column: 7,
+        }),
       },
     );
 
-    css_modules_test(
+    custom_media_error_test(
       r#"
-      .test {
-        animation: rotate var(--duration) linear infinite;
-      }
-    "#,
-      indoc! {r#"
-      .EgL3uq_test {
-        animation: EgL3uq_rotate var(--duration) linear infinite;
-      }
-    "#},
-      map! {
-        "test" => "EgL3uq_test",
-        "rotate" => "EgL3uq_rotate" referenced: true
+      @custom-media --screen screen;
+      @custom-media --print print;
+
+      @media (--print) and (--screen) {}
+      "#,
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 1,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 4,
+          column: 7,
+        }),
       },
-      HashMap::new(),
-      Default::default(),
     );
-    css_modules_test(
+
+    custom_media_error_test(
       r#"
-      .test {
-        animation: none var(--duration);
-      }
-    "#,
-      indoc! {r#"
-      .EgL3uq_test {
-        animation: none var(--duration);
+      @custom-media --not-print not print and (color);
+      @custom-media --not-screen not screen and (color);
+
+      @media ((script) or ((--not-print) and (--not-screen))) {
+        .a {
+          color: green;
+        }
       }
-    "#},
-      map! {
-        "test" => "EgL3uq_test"
+      "#,
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 2,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 4,
+          column: 7,
+        }),
       },
-      HashMap::new(),
-      Default::default(),
     );
-    css_modules_test(
+
+    custom_media_error_test(
       r#"
-      .test {
-        animation: var(--animation);
-      }
-    "#,
-      indoc! {r#"
-      .EgL3uq_test {
-        animation: var(--animation);
+      @custom-media --color screen and (color), print and (color);
+
+      @media (--color) {
+        .a {
+          color: green;
+        }
       }
-    "#},
-      map! {
-        "test" => "EgL3uq_test"
+      "#,
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 1,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 3,
+          column: 7,
+        }),
       },
-      HashMap::new(),
-      Default::default(),
     );
-    css_modules_test(
+
+    custom_media_error_test(
       r#"
-      .test {
-        animation: rotate var(--duration);
-      }
-    "#,
-      indoc! {r#"
-      .EgL3uq_test {
-        animation: rotate var(--duration);
+      @media (--not-defined) {
+        .a {
+          color: green;
+        }
       }
-    "#},
-      map! {
-        "test" => "EgL3uq_test"
-      },
-      HashMap::new(),
-      crate::css_modules::Config {
-        animation: false,
-        ..Default::default()
+      "#,
+      Error {
+        kind: MinifyErrorKind::CustomMediaNotDefined {
+          name: "--not-defined".into(),
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 1,
+          column: 7,
+        }),
       },
     );
-    css_modules_test(
+
+    custom_media_error_test(
       r#"
-      .test {
-        animation: "rotate" var(--duration);
-      }
-    "#,
-      indoc! {r#"
-      .EgL3uq_test {
-        animation: EgL3uq_rotate var(--duration);
+      @custom-media --circular-mq-a (--circular-mq-b);
+      @custom-media --circular-mq-b (--circular-mq-a);
+
+      @media (--circular-mq-a) {
+        body {
+          order: 3;
+        }
       }
-    "#},
-      map! {
-        "test" => "EgL3uq_test",
-        "rotate" => "EgL3uq_rotate" referenced: true
+      "#,
+      Error {
+        kind: MinifyErrorKind::CircularCustomMedia {
+          name: "--circular-mq-a".into(),
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 4,
+          column: 7,
+        }),
       },
-      HashMap::new(),
-      crate::css_modules::Config { ..Default::default() },
     );
+  }
 
-    // Stable hashes between project roots.
-    fn test_project_root(project_root: &str, filename: &str, hash: &str) {
-      let stylesheet = StyleSheet::parse(
-        r#"
-        .foo {
-          background: red;
-        }
-        "#,
+  #[test]
+  fn test_dependencies() {
+    fn dep_test(source: &str, expected: &str, deps: Vec<(&str, &str)>) {
+      let mut stylesheet = StyleSheet::parse(
+        &source,
         ParserOptions {
-          filename: filename.into(),
-          css_modules: Some(Default::default()),
+          filename: "test.css".into(),
           ..ParserOptions::default()
         },
       )
       .unwrap();
+      stylesheet.minify(MinifyOptions::default()).unwrap();
       let res = stylesheet
         .to_css(PrinterOptions {
-          project_root: Some(project_root),
+          analyze_dependencies: Some(Default::default()),
+          minify: true,
           ..PrinterOptions::default()
         })
         .unwrap();
-      assert_eq!(
-        res.code,
-        format!(
-          indoc! {r#"
-      .{}_foo {{
-        background: red;
-      }}
-      "#},
-          hash
-        )
-      );
-    }
-
-    test_project_root("/foo/bar", "/foo/bar/test.css", "EgL3uq");
-    test_project_root("/foo", "/foo/test.css", "EgL3uq");
-    test_project_root("/foo/bar", "/foo/bar/baz/test.css", "xLEkNW");
-    test_project_root("/foo", "/foo/baz/test.css", "xLEkNW");
-  }
-
-  #[test]
-  fn test_pseudo_replacement() {
-    let source = r#"
-      .foo:hover {
-        color: red;
-      }
-
-      .foo:active {
-        color: yellow;
+      assert_eq!(res.code, expected);
+      let dependencies = res.dependencies.unwrap();
+      assert_eq!(dependencies.len(), deps.len());
+      for (i, (url, placeholder)) in deps.into_iter().enumerate() {
+        match &dependencies[i] {
+          Dependency::Url(dep) => {
+            assert_eq!(dep.url, url);
+            assert_eq!(dep.placeholder, placeholder);
+          }
+          Dependency::Import(dep) => {
+            assert_eq!(dep.url, url);
+            assert_eq!(dep.placeholder, placeholder);
+          }
+        }
       }
+    }
 
-      .foo:focus-visible {
-        color: purple;
+    fn dep_error_test(source: &str, error: PrinterErrorKind) {
+      let stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+      let res = stylesheet.to_css(PrinterOptions {
+        analyze_dependencies: Some(Default::default()),
+        ..PrinterOptions::default()
+      });
+      match res {
+        Err(e) => assert_eq!(e.kind, error),
+        _ => unreachable!(),
       }
-    "#;
+    }
 
-    let expected = indoc! { r#"
-      .foo.is-hovered {
-        color: red;
-      }
+    dep_test(
+      ".foo { background: image-set('./img12x.png', './img21x.png' 2x)}",
+      ".foo{background:image-set(\"hXFI8W\" 1x,\"5TkpBa\" 2x)}",
+      vec![("./img12x.png", "hXFI8W"), ("./img21x.png", "5TkpBa")],
+    );
 
-      .foo.is-active {
-        color: #ff0;
-      }
+    dep_test(
+      ".foo { background: image-set(url(./img12x.png), url('./img21x.png') 2x)}",
+      ".foo{background:image-set(\"hXFI8W\" 1x,\"5TkpBa\" 2x)}",
+      vec![("./img12x.png", "hXFI8W"), ("./img21x.png", "5TkpBa")],
+    );
 
-      .foo.focus-visible {
-        color: purple;
-      }
-    "#};
+    dep_test(
+      ".foo { --test: url(/foo.png) }",
+      ".foo{--test:url(\"lDnnrG\")}",
+      vec![("/foo.png", "lDnnrG")],
+    );
 
-    let stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
-    let res = stylesheet
-      .to_css(PrinterOptions {
-        pseudo_classes: Some(PseudoClasses {
-          hover: Some("is-hovered"),
-          active: Some("is-active"),
-          focus_visible: Some("focus-visible"),
-          ..PseudoClasses::default()
-        }),
-        ..PrinterOptions::default()
-      })
-      .unwrap();
-    assert_eq!(res.code, expected);
+    dep_test(
+      ".foo { --test: url(\"/foo.png\") }",
+      ".foo{--test:url(\"lDnnrG\")}",
+      vec![("/foo.png", "lDnnrG")],
+    );
 
-    let source = r#"
-      .foo:hover {
-        color: red;
-      }
-    "#;
+    dep_test(
+      ".foo { --test: url(\"http://example.com/foo.png\") }",
+      ".foo{--test:url(\"3X1zSW\")}",
+      vec![("http://example.com/foo.png", "3X1zSW")],
+    );
 
-    let expected = indoc! { r#"
-      .EgL3uq_foo.EgL3uq_is-hovered {
-        color: red;
-      }
-    "#};
+    dep_test(
+      ".foo { --test: url(\"data:image/svg+xml;utf8,<svg></svg>\") }",
+      ".foo{--test:url(\"-vl-rG\")}",
+      vec![("data:image/svg+xml;utf8,<svg></svg>", "-vl-rG")],
+    );
 
-    let stylesheet = StyleSheet::parse(
-      &source,
-      ParserOptions {
-        filename: "test.css".into(),
-        css_modules: Some(Default::default()),
-        ..ParserOptions::default()
-      },
-    )
-    .unwrap();
-    let res = stylesheet
-      .to_css(PrinterOptions {
-        pseudo_classes: Some(PseudoClasses {
-          hover: Some("is-hovered"),
-          ..PseudoClasses::default()
-        }),
-        ..PrinterOptions::default()
-      })
-      .unwrap();
-    assert_eq!(res.code, expected);
-  }
+    dep_test(
+      ".foo { background: url(\"foo.png\") var(--test) }",
+      ".foo{background:url(\"Vwkwkq\") var(--test)}",
+      vec![("foo.png", "Vwkwkq")],
+    );
 
-  #[test]
-  fn test_unused_symbols() {
-    let source = r#"
-      .foo {
-        color: red;
-      }
+    dep_error_test(
+      ".foo { --test: url(\"foo.png\") }",
+      PrinterErrorKind::AmbiguousUrlInCustomProperty { url: "foo.png".into() },
+    );
 
-      .bar {
-        color: green;
-      }
+    dep_error_test(
+      ".foo { --test: url(foo.png) }",
+      PrinterErrorKind::AmbiguousUrlInCustomProperty { url: "foo.png".into() },
+    );
 
-      .bar:hover {
-        color: purple;
-      }
+    dep_error_test(
+      ".foo { --test: url(./foo.png) }",
+      PrinterErrorKind::AmbiguousUrlInCustomProperty {
+        url: "./foo.png".into(),
+      },
+    );
 
-      .bar .baz {
-        background: red;
-      }
+    dep_test(
+      ".foo { behavior: url(#foo) }",
+      ".foo{behavior:url(\"Zn9-2q\")}",
+      vec![("#foo", "Zn9-2q")],
+    );
 
-      .baz:is(.bar) {
-        background: green;
-      }
+    dep_test(
+      ".foo { --foo: url(#foo) }",
+      ".foo{--foo:url(\"Zn9-2q\")}",
+      vec![("#foo", "Zn9-2q")],
+    );
 
-      #id {
-        animation: 2s test;
-      }
+    dep_test(
+      "@import \"test.css\"; .foo { color: red }",
+      "@import \"hHsogW\";.foo{color:red}",
+      vec![("test.css", "hHsogW")],
+    );
+  }
 
-      #other_id {
-        color: red;
+  #[test]
+  fn test_api() {
+    let stylesheet = StyleSheet::parse(".foo:hover { color: red }", ParserOptions::default()).unwrap();
+    match &stylesheet.rules.0[0] {
+      CssRule::Style(s) => {
+        assert_eq!(&s.selectors.to_string(), ".foo:hover");
       }
+      _ => unreachable!(),
+    }
 
-      @keyframes test {
-        from { color: red }
-        to { color: yellow }
-      }
+    let color = CssColor::parse_string("#f0f").unwrap();
+    assert_eq!(color.to_css_string(PrinterOptions::default()).unwrap(), "#f0f");
 
-      @counter-style circles {
-        symbols: Ⓐ Ⓑ Ⓒ;
-      }
+    let rule = CssRule::parse_string(".foo { color: red }", ParserOptions::default()).unwrap();
+    assert_eq!(
+      rule.to_css_string(PrinterOptions::default()).unwrap(),
+      indoc! {r#"
+    .foo {
+      color: red;
+    }"#}
+    );
 
-      @keyframes fade {
-        from { opacity: 0 }
-        to { opacity: 1 }
-      }
-    "#;
+    let property = Property::parse_string("color".into(), "#f0f", ParserOptions::default()).unwrap();
+    assert_eq!(
+      property.to_css_string(false, PrinterOptions::default()).unwrap(),
+      "color: #f0f"
+    );
+    assert_eq!(
+      property.to_css_string(true, PrinterOptions::default()).unwrap(),
+      "color: #f0f !important"
+    );
 
-    let expected = indoc! {r#"
+    let code = indoc! { r#"
       .foo {
-        color: red;
+        color: green;
       }
 
-      #id {
-        animation: 2s test;
+      .bar {
+        color: red;
+        background: pink;
       }
 
-      @keyframes test {
-        from {
-          color: red;
-        }
-
-        to {
-          color: #ff0;
+      @media print {
+        .baz {
+          color: green;
         }
       }
     "#};
+    let stylesheet = StyleSheet::parse(code, ParserOptions::default()).unwrap();
+    if let CssRule::Style(style) = &stylesheet.rules.0[1] {
+      let (key, val) = style.property_location(code, 0).unwrap();
+      assert_eq!(
+        key,
+        SourceLocation { line: 5, column: 3 }..SourceLocation { line: 5, column: 8 }
+      );
+      assert_eq!(
+        val,
+        SourceLocation { line: 5, column: 10 }..SourceLocation { line: 5, column: 13 }
+      );
+    }
 
-    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
-    stylesheet
-      .minify(MinifyOptions {
-        unused_symbols: vec!["bar", "other_id", "fade", "circles"]
-          .iter()
-          .map(|s| String::from(*s))
-          .collect(),
-        ..MinifyOptions::default()
-      })
-      .unwrap();
-    let res = stylesheet.to_css(PrinterOptions::default()).unwrap();
-    assert_eq!(res.code, expected);
+    if let CssRule::Style(style) = &stylesheet.rules.0[1] {
+      let (key, val) = style.property_location(code, 1).unwrap();
+      assert_eq!(
+        key,
+        SourceLocation { line: 6, column: 3 }..SourceLocation { line: 6, column: 13 }
+      );
+      assert_eq!(
+        val,
+        SourceLocation { line: 6, column: 15 }..SourceLocation { line: 6, column: 19 }
+      );
+    }
+    if let CssRule::Media(media) = &stylesheet.rules.0[2] {
+      if let CssRule::Style(style) = &media.rules.0[0] {
+        let (key, val) = style.property_location(code, 0).unwrap();
+        assert_eq!(
+          key,
+          SourceLocation { line: 11, column: 5 }..SourceLocation { line: 11, column: 10 }
+        );
+        assert_eq!(
+          val,
+          SourceLocation { line: 11, column: 12 }..SourceLocation { line: 11, column: 17 }
+        );
+      }
+    }
+
+    let mut property = Property::Transform(Default::default(), VendorPrefix::WebKit);
+    property.set_prefix(VendorPrefix::None);
+    assert_eq!(property, Property::Transform(Default::default(), VendorPrefix::None));
+    property.set_prefix(VendorPrefix::Moz);
+    assert_eq!(property, Property::Transform(Default::default(), VendorPrefix::Moz));
+    property.set_prefix(VendorPrefix::WebKit | VendorPrefix::Moz);
+    assert_eq!(
+      property,
+      Property::Transform(Default::default(), VendorPrefix::WebKit | VendorPrefix::Moz)
+    );
+
+    let mut property = Property::TextDecorationLine(Default::default(), VendorPrefix::None);
+    property.set_prefix(VendorPrefix::Ms);
+    assert_eq!(
+      property,
+      Property::TextDecorationLine(Default::default(), VendorPrefix::None)
+    );
+    property.set_prefix(VendorPrefix::WebKit | VendorPrefix::Moz | VendorPrefix::Ms);
+    assert_eq!(
+      property,
+      Property::TextDecorationLine(Default::default(), VendorPrefix::WebKit | VendorPrefix::Moz)
+    );
 
-    let source = r#"
-      .foo {
-        color: red;
+    let mut property = Property::AccentColor(Default::default());
+    property.set_prefix(VendorPrefix::WebKit);
+    assert_eq!(property, Property::AccentColor(Default::default()));
+  }
 
-        &.bar {
-          color: green;
-        }
-      }
-    "#;
+  #[cfg(feature = "substitute_variables")]
+  #[test]
+  fn test_substitute_vars() {
+    use crate::properties::custom::TokenList;
+    use crate::traits::ParseWithOptions;
 
-    let expected = indoc! {r#"
-      .foo {
-        color: red;
+    fn test(property: Property, vars: HashMap<&str, &str>, expected: &str) {
+      if let Property::Unparsed(unparsed) = property {
+        let vars = vars
+          .into_iter()
+          .map(|(k, v)| {
+            (
+              k,
+              TokenList::parse_string_with_options(v, ParserOptions::default()).unwrap(),
+            )
+          })
+          .collect();
+        let substituted = unparsed.substitute_variables(&vars).unwrap();
+        assert_eq!(
+          substituted.to_css_string(false, PrinterOptions::default()).unwrap(),
+          expected
+        );
+      } else {
+        panic!("Not an unparsed property");
       }
-    "#};
-
-    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
-    stylesheet
-      .minify(MinifyOptions {
-        unused_symbols: vec!["bar"].iter().map(|s| String::from(*s)).collect(),
-        ..MinifyOptions::default()
-      })
-      .unwrap();
-    let res = stylesheet.to_css(PrinterOptions::default()).unwrap();
-    assert_eq!(res.code, expected);
+    }
 
-    let source = r#"
-      .foo {
-        color: red;
+    let property = Property::parse_string("color".into(), "var(--test)", ParserOptions::default()).unwrap();
+    test(property, HashMap::from([("--test", "yellow")]), "color: #ff0");
 
-        &.bar {
-          color: purple;
-        }
+    let property =
+      Property::parse_string("color".into(), "var(--test, var(--foo))", ParserOptions::default()).unwrap();
+    test(property, HashMap::from([("--foo", "yellow")]), "color: #ff0");
+    let property = Property::parse_string(
+      "color".into(),
+      "var(--test, var(--foo, yellow))",
+      ParserOptions::default(),
+    )
+    .unwrap();
+    test(property, HashMap::new(), "color: #ff0");
 
-        @nest &.bar {
-          color: orange;
-        }
+    let property =
+      Property::parse_string("width".into(), "calc(var(--a) + var(--b))", ParserOptions::default()).unwrap();
+    test(property, HashMap::from([("--a", "2px"), ("--b", "4px")]), "width: 6px");
 
-        @nest :not(&) {
-          color: green;
-        }
+    let property = Property::parse_string("color".into(), "var(--a)", ParserOptions::default()).unwrap();
+    test(
+      property,
+      HashMap::from([("--a", "var(--b)"), ("--b", "yellow")]),
+      "color: #ff0",
+    );
 
-        @media (orientation: portrait) {
-          color: brown;
-        }
-      }
+    let property = Property::parse_string("color".into(), "var(--a)", ParserOptions::default()).unwrap();
+    test(
+      property,
+      HashMap::from([("--a", "var(--b)"), ("--b", "var(--c)"), ("--c", "var(--a)")]),
+      "color: var(--a)",
+    );
+  }
 
-      .x {
-        color: purple;
+  #[cfg(feature = "substitute_variables")]
+  #[test]
+  fn test_substitute_environment_variables() {
+    use crate::properties::custom::TokenList;
+    use crate::traits::ParseWithOptions;
+    use static_self::IntoOwned;
+
+    fn env(vars: &[(&str, &str)]) -> HashMap<String, TokenList<'static>> {
+      vars
+        .iter()
+        .map(|(k, v)| {
+          (
+            k.to_string(),
+            TokenList::parse_string_with_options(v, ParserOptions::default())
+              .unwrap()
+              .into_owned(),
+          )
+        })
+        .collect()
+    }
 
-        &.y {
-          color: green;
-        }
-      }
-    "#;
+    fn test(source: &str, expected: &str, vars: &[(&str, &str)]) {
+      minify_test_with_minify_options(
+        source,
+        expected,
+        MinifyOptions {
+          environment_variables: env(vars),
+          ..MinifyOptions::default()
+        },
+      );
+    }
 
-    let expected = indoc! {r#"
-      :not(.foo) {
-        color: green;
-      }
-    "#};
+    test(
+      ".foo { color: env(--brand) }",
+      ".foo{color:#ff0}",
+      &[("--brand", "yellow")],
+    );
+    test(
+      ".foo { width: calc(env(--gutter) * 2) }",
+      ".foo{width:20px}",
+      &[("--gutter", "10px")],
+    );
+    test(
+      ".foo { margin: env(--gutter) env(--gutter, 4px) }",
+      ".foo{margin:10px}",
+      &[("--gutter", "10px")],
+    );
+    test(
+      ".foo { color: env(--brand, red) }",
+      ".foo{color:red}",
+      &[("--accent", "yellow")],
+    );
+    test(
+      ".foo { color: env(--brand, env(--accent, red)) }",
+      ".foo{color:#ff0}",
+      &[("--accent", "yellow")],
+    );
+    test(
+      ".foo { color: env(--brand) }",
+      ".foo{color:env(--brand)}",
+      &[("--other", "red")],
+    );
+    test(
+      ".foo { --theme: env(--brand) blue }",
+      ".foo{--theme:yellow blue}",
+      &[("--brand", "yellow")],
+    );
+    test(
+      ".foo { padding-top: env(safe-area-inset-top, 20px) }",
+      ".foo{padding-top:env(safe-area-inset-top,20px)}",
+      &[("--brand", "yellow")],
+    );
+    test(
+      ".foo { padding-top: env(safe-area-inset-top, 20px) }",
+      ".foo{padding-top:0}",
+      &[("safe-area-inset-top", "0px")],
+    );
+    test(
+      ".foo { width: env(viewport-segment-width 0 0, 100px) }",
+      ".foo{width:env(viewport-segment-width 0 0,100px)}",
+      &[("viewport-segment-width", "50px")],
+    );
+    test(
+      ".foo { color: env(--a) }",
+      ".foo{color:env(--a)}",
+      &[("--a", "env(--b)"), ("--b", "env(--a)")],
+    );
+    test(
+      "@media (min-width: 100px) { .foo { color: env(--brand); color: red } }",
+      "@media (width>=100px){.foo{color:red}}",
+      &[("--brand", "yellow")],
+    );
 
-    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
-    stylesheet
-      .minify(MinifyOptions {
-        unused_symbols: vec!["foo", "x"].iter().map(|s| String::from(*s)).collect(),
-        ..MinifyOptions::default()
-      })
-      .unwrap();
-    let res = stylesheet
+    let mut attr = StyleAttribute::parse("color: env(--brand)", ParserOptions::default()).unwrap();
+    attr.minify(MinifyOptions {
+      environment_variables: env(&[("--brand", "yellow")]),
+      ..MinifyOptions::default()
+    });
+    let res = attr
       .to_css(PrinterOptions {
-        targets: Browsers {
-          chrome: Some(95 << 16),
-          ..Browsers::default()
-        }
-        .into(),
+        minify: true,
         ..PrinterOptions::default()
       })
       .unwrap();
-    assert_eq!(res.code, expected);
-
-    let source = r#"
-      @property --EgL3uq_foo {
-        syntax: "<color>";
-        inherits: false;
-        initial-value: #ff0;
-      }
-
-      @font-palette-values --EgL3uq_Cooler {
-        font-family: Bixa;
-        base-palette: 1;
-        override-colors: 1 #7EB7E4;
-      }
-
-      .EgL3uq_foo {
-        --EgL3uq_foo: red;
-      }
-
-      .EgL3uq_bar {
-        color: green;
-      }
-    "#;
-
-    let expected = indoc! {r#"
-      .EgL3uq_bar {
-        color: green;
-      }
-    "#};
+    assert_eq!(res.code, "color:#ff0");
 
-    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
-    stylesheet
-      .minify(MinifyOptions {
-        unused_symbols: vec!["--EgL3uq_foo", "--EgL3uq_Cooler"]
-          .iter()
-          .map(|s| String::from(*s))
-          .collect(),
-        ..MinifyOptions::default()
-      })
-      .unwrap();
-    let res = stylesheet.to_css(PrinterOptions::default()).unwrap();
-    assert_eq!(res.code, expected);
+    let property = Property::parse_string("color".into(), "env(--brand, red)", ParserOptions::default()).unwrap();
+    if let Property::Unparsed(unparsed) = property {
+      let vars = env(&[("--brand", "yellow")]);
+      let substituted = unparsed.substitute_environment_variables(&vars).unwrap();
+      assert_eq!(
+        substituted.to_css_string(false, PrinterOptions::default()).unwrap(),
+        "color: #ff0"
+      );
+    } else {
+      panic!("Not an unparsed property");
+    }
   }
 
   #[test]
-  fn test_svg() {
-    minify_test(".foo { fill: yellow; }", ".foo{fill:#ff0}");
-    minify_test(".foo { fill: url(#foo); }", ".foo{fill:url(#foo)}");
-    minify_test(".foo { fill: url(#foo) none; }", ".foo{fill:url(#foo) none}");
-    minify_test(".foo { fill: url(#foo) yellow; }", ".foo{fill:url(#foo) #ff0}");
-    minify_test(".foo { fill: none; }", ".foo{fill:none}");
-    minify_test(".foo { fill: context-fill; }", ".foo{fill:context-fill}");
-    minify_test(".foo { fill: context-stroke; }", ".foo{fill:context-stroke}");
-
-    minify_test(".foo { stroke: yellow; }", ".foo{stroke:#ff0}");
-    minify_test(".foo { stroke: url(#foo); }", ".foo{stroke:url(#foo)}");
-    minify_test(".foo { stroke: url(#foo) none; }", ".foo{stroke:url(#foo) none}");
-    minify_test(".foo { stroke: url(#foo) yellow; }", ".foo{stroke:url(#foo) #ff0}");
-    minify_test(".foo { stroke: none; }", ".foo{stroke:none}");
-    minify_test(".foo { stroke: context-fill; }", ".foo{stroke:context-fill}");
-    minify_test(".foo { stroke: context-stroke; }", ".foo{stroke:context-stroke}");
-
-    minify_test(".foo { marker-start: url(#foo); }", ".foo{marker-start:url(#foo)}");
-
-    minify_test(".foo { stroke-dasharray: 4 1 2; }", ".foo{stroke-dasharray:4 1 2}");
-    minify_test(".foo { stroke-dasharray: 4,1,2; }", ".foo{stroke-dasharray:4 1 2}");
-    minify_test(".foo { stroke-dasharray: 4, 1, 2; }", ".foo{stroke-dasharray:4 1 2}");
-    minify_test(
-      ".foo { stroke-dasharray: 4px, 1px, 2px; }",
-      ".foo{stroke-dasharray:4 1 2}",
-    );
+  fn test_layer() {
+    minify_test("@layer foo;", "@layer foo;");
+    minify_test("@layer foo, bar;", "@layer foo,bar;");
+    minify_test("@layer foo.bar;", "@layer foo.bar;");
+    minify_test("@layer foo.bar, baz;", "@layer foo.bar,baz;");
 
-    minify_test(".foo { mask: url('foo.svg'); }", ".foo{mask:url(foo.svg)}");
-    minify_test(
-      ".foo { mask: url(masks.svg#star) luminance }",
-      ".foo{mask:url(masks.svg#star) luminance}",
-    );
     minify_test(
-      ".foo { mask: url(masks.svg#star) 40px 20px }",
-      ".foo{mask:url(masks.svg#star) 40px 20px}",
+      r#"
+      @layer foo {
+        .bar {
+          color: red;
+        }
+      }
+    "#,
+      "@layer foo{.bar{color:red}}",
     );
     minify_test(
-      ".foo { mask: url(masks.svg#star) 0 0 / 50px 50px }",
-      ".foo{mask:url(masks.svg#star) 0 0/50px 50px}",
+      r#"
+      @layer foo.bar {
+        .bar {
+          color: red;
+        }
+      }
+    "#,
+      "@layer foo.bar{.bar{color:red}}",
     );
+    minify_test(r#"
+      @layer base {
+        p { max-width: 70ch; }
+      }
+
+      @layer framework {
+        @layer base {
+          p { margin-block: 0.75em; }
+        }
+
+        @layer theme {
+          p { color: #222; }
+        }
+      }
+    "#, "@layer base{p{max-width:70ch}}@layer framework{@layer base{p{margin-block:.75em}}@layer theme{p{color:#222}}}");
     minify_test(
-      ".foo { mask: url(masks.svg#star) repeat-x }",
-      ".foo{mask:url(masks.svg#star) repeat-x}",
+      r#"
+      @layer {
+        .bar {
+          color: red;
+        }
+      }
+    "#,
+      "@layer{.bar{color:red}}",
     );
     minify_test(
-      ".foo { mask: url(masks.svg#star) stroke-box }",
-      ".foo{mask:url(masks.svg#star) stroke-box}",
+      r#"
+      @layer foo\20 bar, baz;
+    "#,
+      "@layer foo\\ bar,baz;",
     );
     minify_test(
-      ".foo { mask: url(masks.svg#star) stroke-box stroke-box }",
-      ".foo{mask:url(masks.svg#star) stroke-box}",
+      r#"
+      @layer one.two\20 three\#four\.five {
+        .bar {
+          color: red;
+        }
+      }
+    "#,
+      "@layer one.two\\ three\\#four\\.five{.bar{color:red}}",
     );
+
+    error_test("@layer;", ParserError::UnexpectedToken(Token::Semicolon));
+    error_test("@layer foo, bar {};", ParserError::AtRuleBodyInvalid);
+    minify_test("@import 'test.css' layer;", "@import \"test.css\" layer;");
+    minify_test("@import 'test.css' layer(foo);", "@import \"test.css\" layer(foo);");
     minify_test(
-      ".foo { mask: url(masks.svg#star) border-box }",
-      ".foo{mask:url(masks.svg#star)}",
+      "@import 'test.css' layer(foo.bar);",
+      "@import \"test.css\" layer(foo.bar);",
     );
     minify_test(
-      ".foo { mask: url(masks.svg#star) left / 16px repeat-y, url(masks.svg#circle) right / 16px repeat-y }",
-      ".foo{mask:url(masks.svg#star) 0/16px repeat-y,url(masks.svg#circle) 100%/16px repeat-y}",
+      "@import 'test.css' layer(foo\\20 bar);",
+      "@import \"test.css\" layer(foo\\ bar);",
     );
-
-    minify_test(
-      ".foo { mask-border: url('border-mask.png') 25; }",
-      ".foo{mask-border:url(border-mask.png) 25}",
+    error_test(
+      "@import 'test.css' layer(foo, bar) {};",
+      ParserError::UnexpectedToken(Token::Comma),
     );
     minify_test(
-      ".foo { mask-border: url('border-mask.png') 25 / 35px / 12px space alpha; }",
-      ".foo{mask-border:url(border-mask.png) 25/35px/12px space}",
+      r#"
+      @layer one {
+        body {
+          background: red;
+        }
+      }
+
+      body {
+        background: red;
+      }
+
+      @layer two {
+        body {
+          background: green;
+        }
+      }
+
+      @layer one {
+        body {
+          background: yellow;
+        }
+      }
+      "#,
+      "@layer one{body{background:#ff0}}body{background:red}@layer two{body{background:green}}",
     );
+  }
+
+  #[test]
+  fn test_property() {
     minify_test(
-      ".foo { mask-border: url('border-mask.png') 25 / 35px / 12px space luminance; }",
-      ".foo{mask-border:url(border-mask.png) 25/35px/12px space luminance}",
+      r#"
+      @property --property-name {
+        syntax: '<color>';
+        inherits: false;
+        initial-value: yellow;
+      }
+    "#,
+      "@property --property-name{syntax:\"<color>\";inherits:false;initial-value:#ff0}",
     );
-    minify_test(
-      ".foo { mask-border: url('border-mask.png') luminance 25 / 35px / 12px space; }",
-      ".foo{mask-border:url(border-mask.png) 25/35px/12px space luminance}",
+
+    test(
+      r#"
+      @property --property-name {
+        syntax: '*';
+        inherits: false;
+        initial-value: ;
+      }
+    "#,
+      indoc! {r#"
+      @property --property-name {
+        syntax: "*";
+        inherits: false;
+        initial-value: ;
+      }
+    "#},
     );
 
     minify_test(
-      ".foo { clip-path: url('clip.svg#star'); }",
-      ".foo{clip-path:url(clip.svg#star)}",
+      r#"
+      @property --property-name {
+        syntax: '*';
+        inherits: false;
+        initial-value: ;
+      }
+    "#,
+      "@property --property-name{syntax:\"*\";inherits:false;initial-value:}",
     );
-    minify_test(".foo { clip-path: margin-box; }", ".foo{clip-path:margin-box}");
-    minify_test(
-      ".foo { clip-path: inset(100px 50px); }",
-      ".foo{clip-path:inset(100px 50px)}",
+
+    test(
+      r#"
+      @property --property-name {
+        syntax: '*';
+        inherits: false;
+        initial-value:;
+      }
+    "#,
+      indoc! {r#"
+      @property --property-name {
+        syntax: "*";
+        inherits: false;
+        initial-value: ;
+      }
+    "#},
     );
+
     minify_test(
-      ".foo { clip-path: inset(100px 50px round 5px); }",
-      ".foo{clip-path:inset(100px 50px round 5px)}",
+      r#"
+      @property --property-name {
+        syntax: '*';
+        inherits: false;
+        initial-value:;
+      }
+    "#,
+      "@property --property-name{syntax:\"*\";inherits:false;initial-value:}",
     );
     minify_test(
-      ".foo { clip-path: inset(100px 50px round 5px 5px 5px 5px); }",
-      ".foo{clip-path:inset(100px 50px round 5px)}",
+      r#"
+      @property --property-name {
+        syntax: '*';
+        inherits: false;
+        initial-value: foo bar;
+      }
+    "#,
+      "@property --property-name{syntax:\"*\";inherits:false;initial-value:foo bar}",
     );
-    minify_test(".foo { clip-path: circle(50px); }", ".foo{clip-path:circle(50px)}");
+
     minify_test(
-      ".foo { clip-path: circle(50px at center center); }",
-      ".foo{clip-path:circle(50px)}",
+      r#"
+      @property --property-name {
+        syntax: '<length>';
+        inherits: true;
+        initial-value: 25px;
+      }
+    "#,
+      "@property --property-name{syntax:\"<length>\";inherits:true;initial-value:25px}",
     );
-    minify_test(
-      ".foo { clip-path: circle(50px at 50% 50%); }",
-      ".foo{clip-path:circle(50px)}",
+
+    error_test(
+      r#"
+      @property --property-name {
+        syntax: '<color>';
+        inherits: false;
+        initial-value: 25px;
+      }
+    "#,
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Dimension {
+        has_sign: false,
+        value: 25.0,
+        int_value: Some(25),
+        unit: "px".into(),
+      }),
     );
-    minify_test(
-      ".foo { clip-path: circle(50px at 0 100px); }",
-      ".foo{clip-path:circle(50px at 0 100px)}",
+
+    error_test(
+      r#"
+      @property --property-name {
+        syntax: '<length>';
+        inherits: false;
+        initial-value: var(--some-value);
+      }
+    "#,
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Function("var".into())),
     );
-    minify_test(
-      ".foo { clip-path: circle(closest-side at 0 100px); }",
-      ".foo{clip-path:circle(at 0 100px)}",
+
+    error_test(
+      r#"
+      @property --property-name {
+        syntax: '<color>';
+        inherits: false;
+      }
+    "#,
+      ParserError::AtRuleBodyInvalid,
     );
+
     minify_test(
-      ".foo { clip-path: circle(farthest-side at 0 100px); }",
-      ".foo{clip-path:circle(farthest-side at 0 100px)}",
+      r#"
+      @property --property-name {
+        syntax: '*';
+        inherits: false;
+      }
+    "#,
+      "@property --property-name{syntax:\"*\";inherits:false}",
     );
-    minify_test(
-      ".foo { clip-path: circle(closest-side at 50% 50%); }",
-      ".foo{clip-path:circle()}",
+
+    error_test(
+      r#"
+      @property --property-name {
+        syntax: '*';
+      }
+    "#,
+      ParserError::AtRuleBodyInvalid,
     );
-    minify_test(
-      ".foo { clip-path: ellipse(50px 60px at 0 10% 20%); }",
-      ".foo{clip-path:ellipse(50px 60px at 0 10% 20%)}",
+
+    error_test(
+      r#"
+      @property --property-name {
+        inherits: false;
+      }
+    "#,
+      ParserError::AtRuleBodyInvalid,
     );
-    minify_test(
-      ".foo { clip-path: ellipse(50px 60px at center center); }",
-      ".foo{clip-path:ellipse(50px 60px)}",
+
+    error_test(
+      r#"
+      @property property-name {
+        syntax: '*';
+        inherits: false;
+      }
+    "#,
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("property-name".into())),
     );
+
     minify_test(
-      ".foo { clip-path: ellipse(closest-side closest-side at 50% 50%); }",
-      ".foo{clip-path:ellipse()}",
+      r#"
+      @property --property-name {
+        syntax: 'custom | <color>';
+        inherits: false;
+        initial-value: yellow;
+      }
+    "#,
+      "@property --property-name{syntax:\"custom|<color>\";inherits:false;initial-value:#ff0}",
     );
+
+    // TODO: Re-enable with a better solution
+    //       See: https://github.com/parcel-bundler/lightningcss/issues/288
+    // minify_test(r#"
+    //   @property --property-name {
+    //     syntax: '<transform-list>';
+    //     inherits: false;
+    //     initial-value: translate(200px,300px) translate(100px,200px) scale(2);
+    //   }
+    // "#, "@property --property-name{syntax:\"<transform-list>\";inherits:false;initial-value:matrix(2,0,0,2,300,500)}");
+
     minify_test(
-      ".foo { clip-path: ellipse(closest-side closest-side at 10% 20%); }",
-      ".foo{clip-path:ellipse(at 10% 20%)}",
+      r#"
+      @property --property-name {
+        syntax: '<time>';
+        inherits: false;
+        initial-value: 1000ms;
+      }
+    "#,
+      "@property --property-name{syntax:\"<time>\";inherits:false;initial-value:1s}",
     );
+
     minify_test(
-      ".foo { clip-path: ellipse(farthest-side closest-side at 10% 20%); }",
-      ".foo{clip-path:ellipse(farthest-side closest-side at 10% 20%)}",
+      r#"
+      @property --property-name {
+        syntax: '<url>';
+        inherits: false;
+        initial-value: url("foo.png");
+      }
+    "#,
+      "@property --property-name{syntax:\"<url>\";inherits:false;initial-value:url(foo.png)}",
     );
+
     minify_test(
-      ".foo { clip-path: polygon(50% 0%, 100% 50%, 50% 100%, 0% 50%); }",
-      ".foo{clip-path:polygon(50% 0%,100% 50%,50% 100%,0% 50%)}",
+      r#"
+      @property --property-name {
+        syntax: '<image>';
+        inherits: false;
+        initial-value: linear-gradient(yellow, blue);
+      }
+    "#,
+      "@property --property-name{syntax:\"<image>\";inherits:false;initial-value:linear-gradient(#ff0,#00f)}",
     );
+
     minify_test(
-      ".foo { clip-path: polygon(nonzero, 50% 0%, 100% 50%, 50% 100%, 0% 50%); }",
-      ".foo{clip-path:polygon(50% 0%,100% 50%,50% 100%,0% 50%)}",
+      r#"
+      @property --property-name {
+        initial-value: linear-gradient(yellow, blue);
+        inherits: false;
+        syntax: '<image>';
+      }
+    "#,
+      "@property --property-name{syntax:\"<image>\";inherits:false;initial-value:linear-gradient(#ff0,#00f)}",
     );
-    minify_test(
-      ".foo { clip-path: polygon(evenodd, 50% 0%, 100% 50%, 50% 100%, 0% 50%); }",
-      ".foo{clip-path:polygon(evenodd,50% 0%,100% 50%,50% 100%,0% 50%)}",
+
+    test(
+      r#"
+      @property --property-name {
+        syntax: '<length>|none';
+        inherits: false;
+        initial-value: none;
+      }
+    "#,
+      indoc! {r#"
+      @property --property-name {
+        syntax: "<length> | none";
+        inherits: false;
+        initial-value: none;
+      }
+    "#},
     );
+
     minify_test(
-      ".foo { clip-path: padding-box circle(50px at 0 100px); }",
-      ".foo{clip-path:circle(50px at 0 100px) padding-box}",
+      r#"
+      @property --property-name {
+        syntax: '<color>#';
+        inherits: false;
+        initial-value: yellow, blue;
+      }
+    "#,
+      "@property --property-name{syntax:\"<color>#\";inherits:false;initial-value:#ff0,#00f}",
     );
     minify_test(
-      ".foo { clip-path: circle(50px at 0 100px) padding-box; }",
-      ".foo{clip-path:circle(50px at 0 100px) padding-box}",
+      r#"
+      @property --property-name {
+        syntax: '<color>+';
+        inherits: false;
+        initial-value: yellow blue;
+      }
+    "#,
+      "@property --property-name{syntax:\"<color>+\";inherits:false;initial-value:#ff0 #00f}",
     );
     minify_test(
-      ".foo { clip-path: circle(50px at 0 100px) border-box; }",
-      ".foo{clip-path:circle(50px at 0 100px)}",
+      r#"
+      @property --property-name {
+        syntax: '<color>';
+        inherits: false;
+        initial-value: yellow;
+      }
+      .foo {
+        color: var(--property-name)
+      }
+      @property --property-name {
+        syntax: '<color>';
+        inherits: true;
+        initial-value: blue;
+      }
+    "#,
+      "@property --property-name{syntax:\"<color>\";inherits:true;initial-value:#00f}.foo{color:var(--property-name)}",
     );
+  }
 
-    prefix_test(
-      ".foo { clip-path: circle(50px); }",
-      indoc! { r#"
-        .foo {
-          -webkit-clip-path: circle(50px);
-          clip-path: circle(50px);
-        }
-      "#},
-      Browsers {
-        chrome: Some(30 << 16),
-        ..Browsers::default()
-      },
+  #[test]
+  fn test_quoting_unquoting_urls() {
+    // Quotes remain double quotes when not minifying
+    test(
+      r#".foo {
+      background-image: url("0123abcd");
+    }"#,
+      r#".foo {
+  background-image: url("0123abcd");
+}
+"#,
     );
 
-    prefix_test(
-      ".foo { clip-path: circle(50px); }",
-      indoc! { r#"
-        .foo {
-          clip-path: circle(50px);
-        }
-      "#},
-      Browsers {
-        chrome: Some(80 << 16),
-        ..Browsers::default()
-      },
+    // Quotes removed when minifying
+    minify_test(
+      r#".foo {
+      background-image: url("0123abcd");
+    }"#,
+      r#".foo{background-image:url(0123abcd)}"#,
     );
 
-    prefix_test(
-      ".foo { clip-path: circle(50px); }",
-      indoc! { r#"
-        .foo {
-          -webkit-clip-path: circle(50px);
-          clip-path: circle(50px);
-        }
-      "#},
-      Browsers {
-        safari: Some(8 << 16),
-        ..Browsers::default()
-      },
+    // Doubles quotes added if not present when not minifying
+    test(
+      r#".foo {
+      background-image: url(0123abcd);
+    }"#,
+      r#".foo {
+  background-image: url("0123abcd");
+}
+"#,
     );
 
-    prefix_test(
-      ".foo { clip-path: circle(50px); }",
-      indoc! { r#"
-        .foo {
-          clip-path: circle(50px);
-        }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
+    // No quotes changed if not present when not minifying
+    minify_test(
+      r#".foo {
+      background-image: url(0123abcd);
+    }"#,
+      r#".foo{background-image:url(0123abcd)}"#,
     );
+  }
 
-    prefix_test(
-      ".foo { fill: lch(50.998% 135.363 338) }",
-      indoc! { r#"
-        .foo {
-          fill: #ee00be;
-          fill: color(display-p3 .972962 -.362078 .804206);
-          fill: lch(50.998% 135.363 338);
-        }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
-    );
+  #[test]
+  fn test_zindex() {
+    minify_test(".foo { z-index: 2 }", ".foo{z-index:2}");
+    minify_test(".foo { z-index: -2 }", ".foo{z-index:-2}");
+    minify_test(".foo { z-index: 999999 }", ".foo{z-index:999999}");
+    minify_test(".foo { z-index: 9999999 }", ".foo{z-index:9999999}");
+    minify_test(".foo { z-index: -9999999 }", ".foo{z-index:-9999999}");
+  }
+
+  #[test]
+  #[cfg(feature = "sourcemap")]
+  fn test_input_source_map() {
+    let source = r#".imported {
+      content: "yay, file support!";
+    }
 
-    prefix_test(
-      ".foo { stroke: lch(50.998% 135.363 338) }",
-      indoc! { r#"
-        .foo {
-          stroke: #ee00be;
-          stroke: color(display-p3 .972962 -.362078 .804206);
-          stroke: lch(50.998% 135.363 338);
-        }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
-    );
+    .selector {
+      margin: 1em;
+      background-color: #f60;
+    }
 
-    prefix_test(
-      ".foo { fill: url(#foo) lch(50.998% 135.363 338) }",
-      indoc! { r##"
-        .foo {
-          fill: url("#foo") #ee00be;
-          fill: url("#foo") color(display-p3 .972962 -.362078 .804206);
-          fill: url("#foo") lch(50.998% 135.363 338);
-        }
-      "##},
-      Browsers {
-        chrome: Some(90 << 16),
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
-    );
+    .selector .nested {
+      margin: 0.5em;
+    }
 
-    prefix_test(
-      ".foo { fill: var(--url) lch(50.998% 135.363 338) }",
-      indoc! { r#"
-        .foo {
-          fill: var(--url) #ee00be;
-        }
+    /*# sourceMappingURL=data:application/json;base64,ewoJInZlcnNpb24iOiAzLAoJInNvdXJjZVJvb3QiOiAicm9vdCIsCgkiZmlsZSI6ICJzdGRvdXQiLAoJInNvdXJjZXMiOiBbCgkJInN0ZGluIiwKCQkic2Fzcy9fdmFyaWFibGVzLnNjc3MiLAoJCSJzYXNzL19kZW1vLnNjc3MiCgldLAoJInNvdXJjZXNDb250ZW50IjogWwoJCSJAaW1wb3J0IFwiX3ZhcmlhYmxlc1wiO1xuQGltcG9ydCBcIl9kZW1vXCI7XG5cbi5zZWxlY3RvciB7XG4gIG1hcmdpbjogJHNpemU7XG4gIGJhY2tncm91bmQtY29sb3I6ICRicmFuZENvbG9yO1xuXG4gIC5uZXN0ZWQge1xuICAgIG1hcmdpbjogJHNpemUgLyAyO1xuICB9XG59IiwKCQkiJGJyYW5kQ29sb3I6ICNmNjA7XG4kc2l6ZTogMWVtOyIsCgkJIi5pbXBvcnRlZCB7XG4gIGNvbnRlbnQ6IFwieWF5LCBmaWxlIHN1cHBvcnQhXCI7XG59IgoJXSwKCSJtYXBwaW5ncyI6ICJBRUFBLFNBQVMsQ0FBQztFQUNSLE9BQU8sRUFBRSxvQkFBcUI7Q0FDL0I7O0FGQ0QsU0FBUyxDQUFDO0VBQ1IsTUFBTSxFQ0hELEdBQUc7RURJUixnQkFBZ0IsRUNMTCxJQUFJO0NEVWhCOztBQVBELFNBQVMsQ0FJUCxPQUFPLENBQUM7RUFDTixNQUFNLEVDUEgsS0FBRztDRFFQIiwKCSJuYW1lcyI6IFtdCn0= */"#;
 
-        @supports (color: lab(0% 0 0)) {
-          .foo {
-            fill: var(--url) lab(50.998% 125.506 -50.7078);
-          }
-        }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+    stylesheet.minify(MinifyOptions::default()).unwrap();
+    let mut sm = parcel_sourcemap::SourceMap::new("/");
+    stylesheet
+      .to_css(PrinterOptions {
+        source_map: Some(&mut sm),
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    let map = sm.to_json(None).unwrap();
+    assert_eq!(
+      map,
+      r#"{"version":3,"sourceRoot":null,"mappings":"AAAA,uCCGA,2CAAA","sources":["sass/_demo.scss","stdin"],"sourcesContent":[".imported {\n  content: \"yay, file support!\";\n}","@import \"_variables\";\n@import \"_demo\";\n\n.selector {\n  margin: $size;\n  background-color: $brandColor;\n\n  .nested {\n    margin: $size / 2;\n  }\n}"],"names":[]}"#
     );
+  }
 
-    prefix_test(
-      ".foo { mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
-      indoc! { r#"
-        .foo {
-          -webkit-mask-image: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff));
-          -webkit-mask-image: -webkit-linear-gradient(#ff0f0e, #7773ff);
-          -webkit-mask-image: linear-gradient(#ff0f0e, #7773ff);
-          mask-image: linear-gradient(#ff0f0e, #7773ff);
-          -webkit-mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-          mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-        }
-      "#},
-      Browsers {
-        chrome: Some(8 << 16),
-        ..Browsers::default()
-      },
-    );
+  #[test]
+  fn test_error_recovery() {
+    use std::sync::{Arc, RwLock};
+    let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+    test_with_options(
+      r#"
+      h1(>h1) {
+        color: red;
+      }
 
-    prefix_test(
-      ".foo { mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
-      indoc! { r#"
-        .foo {
-          -webkit-mask-image: linear-gradient(#ff0f0e, #7773ff);
-          mask-image: linear-gradient(#ff0f0e, #7773ff);
-          -webkit-mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-          mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-        }
-      "#},
-      Browsers {
-        chrome: Some(95 << 16),
-        ..Browsers::default()
-      },
-    );
+      .foo {
+        color: red;
+      }
 
-    prefix_test(
-      ".foo { mask-image: linear-gradient(red, green) }",
-      indoc! { r#"
-        .foo {
-          -webkit-mask-image: linear-gradient(red, green);
-          mask-image: linear-gradient(red, green);
-        }
-      "#},
-      Browsers {
-        chrome: Some(95 << 16),
-        ..Browsers::default()
-      },
-    );
+      .clearfix {
+        *zoom: 1;
+        background: red;
+      }
 
-    prefix_test(
-      ".foo { -webkit-mask-image: url(x.svg); mask-image: url(x.svg); }",
-      indoc! { r#"
-        .foo {
-          -webkit-mask-image: url("x.svg");
-          mask-image: url("x.svg");
+      @media (hover) {
+        h1(>h1) {
+          color: red;
         }
-      "#},
-      Browsers {
-        chrome: Some(95 << 16),
-        ..Browsers::default()
-      },
-    );
 
-    prefix_test(
-      ".foo { mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px }",
+        .bar {
+          color: red;
+        }
+      }
+    "#,
       indoc! { r#"
-        .foo {
-          -webkit-mask: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff)) 40px 20px;
-          -webkit-mask: -webkit-linear-gradient(#ff0f0e, #7773ff) 40px 20px;
-          -webkit-mask: linear-gradient(#ff0f0e, #7773ff) 40px 20px;
-          mask: linear-gradient(#ff0f0e, #7773ff) 40px 20px;
-          -webkit-mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px;
-          mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px;
+      .foo {
+        color: red;
+      }
+
+      .clearfix {
+        background: red;
+      }
+
+      @media (hover) {
+        .bar {
+          color: red;
         }
-      "#},
-      Browsers {
-        chrome: Some(8 << 16),
-        ..Browsers::default()
+      }
+    "#},
+      ParserOptions {
+        filename: "test.css".into(),
+        error_recovery: true,
+        warnings: warnings.clone(),
+        ..ParserOptions::default()
       },
     );
+    let w = warnings.unwrap();
+    let warnings = w.read().unwrap();
+    assert_eq!(
+      *warnings,
+      vec![
+        Error {
+          kind: ParserError::SelectorError(SelectorError::EmptySelector),
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 1,
+            column: 7
+          })
+        },
+        Error {
+          kind: ParserError::UnexpectedToken(Token::Semicolon),
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 10,
+            column: 17
+          })
+        },
+        Error {
+          kind: ParserError::SelectorError(SelectorError::EmptySelector),
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 15,
+            column: 9
+          })
+        },
+      ]
+    )
+  }
 
-    prefix_test(
-      ".foo { mask: -webkit-linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px }",
-      indoc! { r#"
-        .foo {
-          -webkit-mask: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff)) 40px 20px;
-          -webkit-mask: -webkit-linear-gradient(#ff0f0e, #7773ff) 40px 20px;
-        }
-      "#},
-      Browsers {
-        chrome: Some(8 << 16),
-        ..Browsers::default()
-      },
+  #[test]
+  fn test_invalid() {
+    error_test(
+      ".a{color: hsla(120, 62.32%;}",
+      ParserError::UnexpectedToken(Token::CloseCurlyBracket),
     );
+    error_test(
+      ".a{--foo: url(foo\\) b\\)ar)}",
+      ParserError::UnexpectedToken(Token::BadUrl("foo\\) b\\)ar".into())),
+    );
+  }
 
-    prefix_test(
-      ".foo { mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px var(--foo) }",
-      indoc! { r#"
+  #[test]
+  fn test_container_queries() {
+    // with name
+    minify_test(
+      r#"
+      @container my-layout (inline-size > 45em) {
         .foo {
-          -webkit-mask: linear-gradient(#ff0f0e, #7773ff) 40px var(--foo);
-          mask: linear-gradient(#ff0f0e, #7773ff) 40px var(--foo);
-        }
-
-        @supports (color: lab(0% 0 0)) {
-          .foo {
-            -webkit-mask: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) 40px var(--foo);
-            mask: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) 40px var(--foo);
-          }
+          color: red;
         }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+    "#,
+      "@container my-layout (inline-size>45em){.foo{color:red}}",
     );
 
-    prefix_test(
-      ".foo { mask: url(masks.svg#star) luminance }",
-      indoc! { r#"
+    minify_test(
+      r#"
+      @container my-layout ( not (width > 500px) ) {
         .foo {
-          -webkit-mask: url("masks.svg#star");
-          -webkit-mask-source-type: luminance;
-          mask: url("masks.svg#star") luminance;
+          color: red;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+    "#,
+      "@container my-layout not (width>500px){.foo{color:red}}",
     );
 
-    prefix_test(
-      ".foo { mask-image: url(masks.svg#star) }",
-      indoc! { r#"
+    minify_test(
+      r#"
+      @container my-layout not (width > 500px) {
         .foo {
-          -webkit-mask-image: url("masks.svg#star");
-          mask-image: url("masks.svg#star");
+          color: red;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+    "#,
+      "@container my-layout not (width>500px){.foo{color:red}}",
     );
 
-    prefix_test(
+    minify_test(
       r#"
+      @container not (width > 500px) {
         .foo {
-          mask-image: url(masks.svg#star);
-          mask-position: 25% 75%;
-          mask-size: cover;
-          mask-repeat: no-repeat;
-          mask-clip: padding-box;
-          mask-origin: content-box;
-          mask-composite: subtract;
-          mask-mode: luminance;
-        }
-      "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask: url("masks.svg#star") 25% 75% / cover no-repeat content-box padding-box;
-          -webkit-mask-composite: source-out;
-          -webkit-mask-source-type: luminance;
-          mask: url("masks.svg#star") 25% 75% / cover no-repeat content-box padding-box subtract luminance;
+          color: red;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+    "#,
+      "@container not (width>500px){.foo{color:red}}",
     );
 
-    prefix_test(
+    minify_test(
       r#"
+      @container my-layout ((width: 100px) and (not (height: 100px))) {
         .foo {
-          mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-          mask-position: 25% 75%;
-          mask-size: cover;
-          mask-repeat: no-repeat;
-          mask-clip: padding-box;
-          mask-origin: content-box;
-          mask-composite: subtract;
-          mask-mode: luminance;
-        }
-      "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask: linear-gradient(#ff0f0e, #7773ff) 25% 75% / cover no-repeat content-box padding-box;
-          -webkit-mask-composite: source-out;
-          -webkit-mask-source-type: luminance;
-          mask: linear-gradient(#ff0f0e, #7773ff) 25% 75% / cover no-repeat content-box padding-box subtract luminance;
-          -webkit-mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25% 75% / cover no-repeat content-box padding-box;
-          -webkit-mask-composite: source-out;
-          -webkit-mask-source-type: luminance;
-          mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25% 75% / cover no-repeat content-box padding-box subtract luminance;
+          color: red;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+    "#,
+      "@container my-layout (width:100px) and (not (height:100px)){.foo{color:red}}",
     );
 
-    test(
+    minify_test(
       r#"
+      @container my-layout (width = max(10px, 10em)) {
         .foo {
-          mask: none center / 100% no-repeat;
-          mask-image: var(--svg);
-        }
-      "#,
-      indoc! { r#"
-        .foo {
-          mask: none center / 100% no-repeat;
-          mask-image: var(--svg);
+          color: red;
         }
-      "#},
+      }
+    "#,
+      "@container my-layout (width=max(10px,10em)){.foo{color:red}}",
     );
 
-    prefix_test(
+    // without name
+    minify_test(
       r#"
+      @container (inline-size > 45em) {
         .foo {
-          mask-composite: subtract;
+          color: red;
         }
-      "#,
-      indoc! { r#"
+      }
+    "#,
+      "@container (inline-size>45em){.foo{color:red}}",
+    );
+
+    minify_test(
+      r#"
+      @container (inline-size > 45em) and (inline-size < 100em) {
         .foo {
-          -webkit-mask-composite: source-out;
-          mask-composite: subtract;
+          color: red;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+    "#,
+      "@container (inline-size>45em) and (inline-size<100em){.foo{color:red}}",
     );
 
-    prefix_test(
+    // calc()
+    minify_test(
       r#"
+      @container (width > calc(100vw - 50px)) {
         .foo {
-          mask-mode: luminance;
+          color: red;
         }
-      "#,
-      indoc! { r#"
+      }
+    "#,
+      "@container (width>calc(100vw - 50px)){.foo{color:red}}",
+    );
+
+    minify_test(
+      r#"
+      @container (calc(100vh - 50px) <= height ) {
         .foo {
-          -webkit-mask-source-type: luminance;
-          mask-mode: luminance;
+          color: red;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+    "#,
+      "@container (height>=calc(100vh - 50px)){.foo{color:red}}",
     );
 
-    prefix_test(
+    // merge adjacent
+    minify_test(
       r#"
+      @container my-layout (inline-size > 45em) {
         .foo {
-          mask-border: url('border-mask.png') 25 / 35px / 12px space luminance;
+          color: red;
         }
-      "#,
-      indoc! { r#"
+      }
+
+      @container my-layout (inline-size > 45em) {
         .foo {
-          -webkit-mask-box-image: url("border-mask.png") 25 / 35px / 12px space;
-          mask-border: url("border-mask.png") 25 / 35px / 12px space luminance;
+          background: yellow;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+
+        .bar {
+          color: white;
+        }
+      }
+    "#,
+      "@container my-layout (inline-size>45em){.foo{color:red;background:#ff0}.bar{color:#fff}}",
+    );
+
+    minify_test(
+      r#"
+    .foo {
+      container-name: foo bar;
+      container-type: size;
+    }
+    "#,
+      ".foo{container:foo bar/size}",
+    );
+    minify_test(
+      r#"
+    .foo {
+      container-name: foo bar;
+      container-type: normal;
+    }
+    "#,
+      ".foo{container:foo bar}",
+    );
+    minify_test(
+      ".foo{ container-type: inline-size }",
+      ".foo{container-type:inline-size}",
+    );
+    minify_test(".foo{ container-name: none; }", ".foo{container-name:none}");
+    minify_test(".foo{ container-name: foo; }", ".foo{container-name:foo}");
+    minify_test(".foo{ container: foo / normal; }", ".foo{container:foo}");
+    minify_test(
+      ".foo{ container: foo / inline-size; }",
+      ".foo{container:foo/inline-size}",
     );
+    minify_test(".foo { width: calc(1cqw + 2cqw) }", ".foo{width:3cqw}");
+    minify_test(".foo { width: calc(1cqh + 2cqh) }", ".foo{width:3cqh}");
+    minify_test(".foo { width: calc(1cqi + 2cqi) }", ".foo{width:3cqi}");
+    minify_test(".foo { width: calc(1cqb + 2cqb) }", ".foo{width:3cqb}");
+    minify_test(".foo { width: calc(1cqmin + 2cqmin) }", ".foo{width:3cqmin}");
+    minify_test(".foo { width: calc(1cqmax + 2cqmax) }", ".foo{width:3cqmax}");
 
+    // Unlike in @media, there is no need to convert the range syntax in @container,
+    // because browsers all support this syntax.
     prefix_test(
       r#"
-        .foo {
-          mask-border: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25 / 35px / 12px space luminance;
-        }
+      @container (width > 100px) {
+        .foo { padding: 5px; }
+      }
       "#,
       indoc! { r#"
-        .foo {
-          -webkit-mask-box-image: linear-gradient(#ff0f0e, #7773ff) 25 / 35px / 12px space;
-          mask-border: linear-gradient(#ff0f0e, #7773ff) 25 / 35px / 12px space luminance;
-          -webkit-mask-box-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25 / 35px / 12px space;
-          mask-border: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25 / 35px / 12px space luminance;
+        @container (width > 100px) {
+          .foo {
+            padding: 5px;
+          }
         }
-    "#},
+      "#},
       Browsers {
-        chrome: Some(90 << 16),
+        chrome: Some(105 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
-        .foo {
-          mask-border-source: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-        }
+      @container (min-width: 100px) {
+        .foo { padding: 5px; }
+      }
       "#,
       indoc! { r#"
-        .foo {
-          -webkit-mask-box-image-source: linear-gradient(#ff0f0e, #7773ff);
-          mask-border-source: linear-gradient(#ff0f0e, #7773ff);
-          -webkit-mask-box-image-source: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-          mask-border-source: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+        @container (width >= 100px) {
+          .foo {
+            padding: 5px;
+          }
         }
-    "#},
+      "#},
       Browsers {
-        chrome: Some(90 << 16),
+        chrome: Some(105 << 16),
         ..Browsers::default()
       },
     );
 
-    prefix_test(
+    minify_test(
       r#"
+      @container style(--responsive: true) {
         .foo {
-          mask-border-source: url(foo.png);
-          mask-border-slice: 10 40 10 40;
-          mask-border-width: 10px;
-          mask-border-outset: 0;
-          mask-border-repeat: round round;
-          mask-border-mode: luminance;
+          color: red;
         }
-      "#,
-      indoc! { r#"
+      }
+    "#,
+      "@container style(--responsive:true){.foo{color:red}}",
+    );
+    minify_test(
+      r#"
+      @container style(--responsive: true) and style(color: yellow) {
         .foo {
-          -webkit-mask-box-image: url("foo.png") 10 40 / 10px round;
-          mask-border: url("foo.png") 10 40 / 10px round luminance;
+          color: red;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+    "#,
+      "@container style((--responsive:true) and (color:#ff0)){.foo{color:red}}",
     );
-
-    prefix_test(
+    minify_test(
       r#"
+      @container not style(--responsive: true) {
         .foo {
-          -webkit-mask-box-image-source: url(foo.png);
-          -webkit-mask-box-image-slice: 10 40 10 40;
-          -webkit-mask-box-image-width: 10px;
-          -webkit-mask-box-image-outset: 0;
-          -webkit-mask-box-image-repeat: round round;
+          color: red;
         }
-      "#,
-      indoc! { r#"
+      }
+    "#,
+      "@container not style(--responsive:true){.foo{color:red}}",
+    );
+    minify_test(
+      r#"
+      @container (inline-size > 45em) and style(--responsive: true) {
         .foo {
-          -webkit-mask-box-image: url("foo.png") 10 40 / 10px round;
+          color: red;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+    "#,
+      "@container (inline-size>45em) and style(--responsive:true){.foo{color:red}}",
     );
-
-    prefix_test(
+    minify_test(
       r#"
+      @container style((accent-color: yellow) or (--bar: 10px)) {
         .foo {
-          mask-border-slice: 10 40 10 40;
+          color: red;
         }
-      "#,
-      indoc! { r#"
+      }
+    "#,
+      "@container style((accent-color:#ff0) or (--bar:10px)){.foo{color:red}}",
+    );
+    minify_test(
+      r#"
+      @container style(not ((width: calc(10px + 20px)) and ((--bar: url(x))))) {
         .foo {
-          -webkit-mask-box-image-slice: 10 40;
-          mask-border-slice: 10 40;
+          color: red;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+    "#,
+      "@container style(not ((width:30px) and (--bar:url(x)))){.foo{color:red}}",
     );
-
-    prefix_test(
+    minify_test(
       r#"
+      @container style(color: yellow !important) {
         .foo {
-          mask-border-slice: var(--foo);
+          color: red;
         }
-      "#,
-      indoc! { r#"
+      }
+    "#,
+      "@container style(color:yellow){.foo{color:red}}",
+    );
+    minify_test(
+      r#"
+      @container style(--foo:) {
         .foo {
-          -webkit-mask-box-image-slice: var(--foo);
-          mask-border-slice: var(--foo);
+          color: red;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+    "#,
+      "@container style(--foo:){.foo{color:red}}",
     );
-
-    prefix_test(
+    minify_test(
       r#"
+      @container style(--foo: ) {
         .foo {
-          mask-border: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) var(--foo);
+          color: red;
         }
-      "#,
-      indoc! { r#"
+      }
+    "#,
+      "@container style(--foo:){.foo{color:red}}",
+    );
+    minify_test(
+      r#"
+      @container style(--my-prop: foo - bar ()) {
         .foo {
-          -webkit-mask-box-image: linear-gradient(#ff0f0e, #7773ff) var(--foo);
-          mask-border: linear-gradient(#ff0f0e, #7773ff) var(--foo);
+          color: red;
         }
+      }
+    "#,
+      "@container style(--my-prop:foo - bar ()){.foo{color:red}}",
+    );
+    minify_test(
+      "@container style(--a: 1) or style(--b: 2) or style(--c: 3) {.foo{color:red}}",
+      "@container style((--a:1) or (--b:2) or (--c:3)){.foo{color:red}}",
+    );
+    minify_test(
+      "@container style(--a: 1) and style((--b: 2) and (--c: 3)) {.foo{color:red}}",
+      "@container style((--a:1) and (--b:2) and (--c:3)){.foo{color:red}}",
+    );
+    minify_test(
+      "@container style(--a: 1) and style(--a:  1) {.foo{color:red}}",
+      "@container style(--a:1){.foo{color:red}}",
+    );
+    minify_test(
+      "@container style((--a: 1) or ((--b: 2) or (--a: 1))) {.foo{color:red}}",
+      "@container style((--a:1) or (--b:2)){.foo{color:red}}",
+    );
+    minify_test(
+      "@container style(not (not (color: yellow))) {.foo{color:red}}",
+      "@container style(color:#ff0){.foo{color:red}}",
+    );
+    minify_test(
+      "@container not (not (width > 10px)) {.foo{color:red}}",
+      "@container (width>10px){.foo{color:red}}",
+    );
+    minify_test(
+      "@container (width > 10px) and ((height > 10px) and (width > 10px)) {.foo{color:red}}",
+      "@container (width>10px) and (height>10px){.foo{color:red}}",
+    );
+    minify_test(
+      "@container (width > 10px) and style(--a: 1) and style(--b: 2) {.foo{color:red}}",
+      "@container (width>10px) and style(--a:1) and style(--b:2){.foo{color:red}}",
+    );
+    minify_test(
+      "@container style(--a: 1) and (not style(--b: 2)) {.foo{color:red}}",
+      "@container style(--a:1) and (not style(--b:2)){.foo{color:red}}",
+    );
+    minify_test(
+      "@container style(--a: 1) or (style(--b: 2) and style(--c: 3)) {.foo{color:red}}",
+      "@container style((--a:1) or ((--b:2) and (--c:3))){.foo{color:red}}",
+    );
 
-        @supports (color: lab(0% 0 0)) {
-          .foo {
-            -webkit-mask-box-image: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) var(--foo);
-            mask-border: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) var(--foo);
-          }
-        }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+    // Disallow 'none', 'not', 'and', 'or' as a `<container-name>`
+    // https://github.com/w3c/csswg-drafts/issues/7203#issuecomment-1144257312
+    // https://chromium-review.googlesource.com/c/chromium/src/+/3698402
+    error_test(
+      "@container none (width < 100vw) {}",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("none".into())),
+    );
+
+    error_test(
+      "@container and (width < 100vw) {}",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("and".into())),
+    );
+
+    error_test(
+      "@container or (width < 100vw) {}",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("or".into())),
+    );
+
+    // Disallow CSS wide keywords as a `<container-name>`
+    error_test(
+      "@container revert-layer (width < 100vw) {}",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("revert-layer".into())),
+    );
+
+    error_test(
+      "@container initial (width < 100vw) {}",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("initial".into())),
+    );
+
+    // <ident> contains spaces
+    // https://github.com/web-platform-tests/wpt/blob/39f0da08fbbe33d0582a35749b6dbf8bd067a52d/css/css-contain/container-queries/at-container-parsing.html#L160-L178
+    error_test(
+      "@container foo bar (width < 100vw) {}",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("bar".into())),
+    );
+
+    error_test("@container (inline-size <= foo) {}", ParserError::InvalidMediaQuery);
+    error_test("@container (orientation <= 10px) {}", ParserError::InvalidMediaQuery);
+
+    error_test("@container style(width) {}", ParserError::EndOfInput);
+    error_test(
+      "@container style(style(--foo: bar)) {}",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Function("style".into())),
+    );
+  }
+
+  #[test]
+  fn test_unknown_at_rules() {
+    minify_test("@foo;", "@foo;");
+    minify_test("@foo bar;", "@foo bar;");
+    minify_test("@foo (bar: baz);", "@foo (bar: baz);");
+    test(
+      r#"@foo test {
+      div {
+        color: red;
+      }
+    }"#,
+      indoc! {r#"
+      @foo test {
+        div { color: red; }
+      }
+      "#},
+    );
+    minify_test(
+      r#"@foo test {
+      div {
+        color: red;
+      }
+    }"#,
+      "@foo test{div { color: red; }}",
+    );
+    minify_test(
+      r#"@foo test {
+        foo: bar;
+      }"#,
+      "@foo test{foo: bar;}",
     );
-
-    prefix_test(
-      r#"
-        .foo {
-          transition: mask 200ms;
-        }
-      "#,
-      indoc! { r#"
-        .foo {
-          transition: -webkit-mask .2s, mask .2s;
-        }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+    test(
+      r#"@foo {
+        foo: bar;
+      }"#,
+      indoc! {r#"
+      @foo {
+        foo: bar;
+      }
+      "#},
+    );
+    minify_test(
+      r#"@foo {
+        foo: bar;
+      }"#,
+      "@foo{foo: bar;}",
     );
+  }
 
+  #[test]
+  fn test_resolution() {
     prefix_test(
       r#"
-        .foo {
-          transition: mask-border 200ms;
+      @media (resolution: 1dppx) {
+        body {
+          background: red;
         }
+      }
       "#,
       indoc! { r#"
-        .foo {
-          transition: -webkit-mask-box-image .2s, mask-border .2s;
+      @media (resolution: 1dppx) {
+        body {
+          background: red;
         }
-    "#},
+      }
+      "#},
       Browsers {
-        chrome: Some(90 << 16),
+        chrome: Some(50 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-        .foo {
-          transition-property: mask;
+      @media (resolution: 1dppx) {
+        body {
+          background: red;
         }
+      }
       "#,
       indoc! { r#"
-        .foo {
-          transition-property: -webkit-mask, mask;
+      @media (resolution: 1x) {
+        body {
+          background: red;
         }
-    "#},
+      }
+      "#},
       Browsers {
-        chrome: Some(90 << 16),
+        chrome: Some(95 << 16),
         ..Browsers::default()
       },
     );
+  }
 
-    prefix_test(
+  #[test]
+  fn test_environment() {
+    minify_test(
       r#"
-        .foo {
-          transition-property: mask-border;
-        }
-      "#,
-      indoc! { r#"
-        .foo {
-          transition-property: -webkit-mask-box-image, mask-border;
+      @media (max-width: env(--branding-small)) {
+        body {
+          padding: env(--branding-padding);
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+    "#,
+      "@media (width<=env(--branding-small)){body{padding:env(--branding-padding)}}",
     );
 
-    prefix_test(
+    minify_test(
       r#"
-        .foo {
-          transition-property: mask-composite, mask-mode;
-        }
-      "#,
-      indoc! { r#"
-        .foo {
-          transition-property: -webkit-mask-composite, mask-composite, -webkit-mask-source-type, mask-mode;
+      @media (max-width: env(--branding-small 1)) {
+        body {
+          padding: env(--branding-padding 2);
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+    "#,
+      "@media (width<=env(--branding-small 1)){body{padding:env(--branding-padding 2)}}",
     );
-  }
 
-  #[test]
-  fn test_filter() {
-    minify_test(
-      ".foo { filter: url('filters.svg#filter-id'); }",
-      ".foo{filter:url(filters.svg#filter-id)}",
-    );
-    minify_test(".foo { filter: blur(5px); }", ".foo{filter:blur(5px)}");
-    minify_test(".foo { filter: blur(0px); }", ".foo{filter:blur()}");
-    minify_test(".foo { filter: brightness(10%); }", ".foo{filter:brightness(10%)}");
-    minify_test(".foo { filter: brightness(100%); }", ".foo{filter:brightness()}");
-    minify_test(
-      ".foo { filter: drop-shadow(16px 16px 20px yellow); }",
-      ".foo{filter:drop-shadow(16px 16px 20px #ff0)}",
-    );
     minify_test(
-      ".foo { filter: contrast(175%) brightness(3%); }",
-      ".foo{filter:contrast(175%)brightness(3%)}",
-    );
-    minify_test(".foo { filter: hue-rotate(0) }", ".foo{filter:hue-rotate()}");
-
-    prefix_test(
-      ".foo { filter: blur(5px) }",
-      indoc! { r#"
-        .foo {
-          -webkit-filter: blur(5px);
-          filter: blur(5px);
+      r#"
+      @media (max-width: env(--branding-small 1, 20px)) {
+        body {
+          padding: env(--branding-padding 2, 20px);
         }
-      "#},
-      Browsers {
-        chrome: Some(20 << 16),
-        ..Browsers::default()
-      },
+      }
+    "#,
+      "@media (width<=env(--branding-small 1,20px)){body{padding:env(--branding-padding 2,20px)}}",
     );
 
-    prefix_test(
-      ".foo { filter: blur(5px) }",
-      indoc! { r#"
-        .foo {
-          filter: blur(5px);
+    minify_test(
+      r#"
+      @media (max-width: env(safe-area-inset-top)) {
+        body {
+          padding: env(safe-area-inset-top);
         }
-      "#},
-      Browsers {
-        chrome: Some(80 << 16),
-        ..Browsers::default()
-      },
+      }
+    "#,
+      "@media (width<=env(safe-area-inset-top)){body{padding:env(safe-area-inset-top)}}",
     );
 
-    prefix_test(
-      ".foo { backdrop-filter: blur(5px) }",
-      indoc! { r#"
-        .foo {
-          backdrop-filter: blur(5px);
+    minify_test(
+      r#"
+      @media (max-width: env(unknown)) {
+        body {
+          padding: env(unknown);
         }
-      "#},
-      Browsers {
-        chrome: Some(80 << 16),
-        ..Browsers::default()
-      },
+      }
+    "#,
+      "@media (width<=env(unknown)){body{padding:env(unknown)}}",
     );
 
-    prefix_test(
-      ".foo { backdrop-filter: blur(5px) }",
-      indoc! { r#"
-        .foo {
-          -webkit-backdrop-filter: blur(5px);
-          backdrop-filter: blur(5px);
-        }
-      "#},
-      Browsers {
-        safari: Some(15 << 16),
-        ..Browsers::default()
-      },
-    );
     prefix_test(
       r#"
       .foo {
-        -webkit-backdrop-filter: blur(8px);
-        backdrop-filter: blur(8px);
+        color: env(--brand-color, color(display-p3 0 1 0));
       }
-      "#,
+    "#,
       indoc! {r#"
       .foo {
-        -webkit-backdrop-filter: blur(8px);
-        backdrop-filter: blur(8px);
+        color: env(--brand-color, #00f942);
       }
-      "#},
-      Browsers {
-        safari: Some(16 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    prefix_test(
-      ".foo { filter: var(--foo) }",
-      indoc! { r#"
-        .foo {
-          -webkit-filter: var(--foo);
-          filter: var(--foo);
-        }
-      "#},
-      Browsers {
-        chrome: Some(20 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    prefix_test(
-      ".foo { filter: drop-shadow(16px 16px 20px lab(40% 56.6 39)) }",
-      indoc! { r#"
-        .foo {
-          -webkit-filter: drop-shadow(16px 16px 20px #b32323);
-          filter: drop-shadow(16px 16px 20px #b32323);
-          filter: drop-shadow(16px 16px 20px lab(40% 56.6 39));
-        }
-      "#},
-      Browsers {
-        chrome: Some(20 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    prefix_test(
-      ".foo { filter: contrast(175%) drop-shadow(16px 16px 20px lab(40% 56.6 39)) }",
-      indoc! { r#"
-        .foo {
-          filter: contrast(175%) drop-shadow(16px 16px 20px #b32323);
-          filter: contrast(175%) drop-shadow(16px 16px 20px lab(40% 56.6 39));
-        }
-      "#},
-      Browsers {
-        chrome: Some(4 << 16),
-        ..Browsers::default()
-      },
-    );
 
-    prefix_test(
-      ".foo { filter: drop-shadow(16px 16px 20px lab(40% 56.6 39)) drop-shadow(16px 16px 20px yellow) }",
-      indoc! { r#"
+      @supports (color: color(display-p3 0 0 0)) {
         .foo {
-          filter: drop-shadow(16px 16px 20px #b32323) drop-shadow(16px 16px 20px #ff0);
-          filter: drop-shadow(16px 16px 20px lab(40% 56.6 39)) drop-shadow(16px 16px 20px #ff0);
+          color: env(--brand-color, color(display-p3 0 1 0));
         }
-      "#},
+      }
+    "#},
       Browsers {
-        chrome: Some(4 << 16),
+        safari: Some(15 << 16),
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
 
-    prefix_test(
-      ".foo { filter: var(--foo) drop-shadow(16px 16px 20px lab(40% 56.6 39)) }",
-      indoc! { r#"
+    css_modules_test(
+      r#"
+      @media (max-width: env(--branding-small)) {
         .foo {
-          filter: var(--foo) drop-shadow(16px 16px 20px #b32323);
+          color: env(--brand-color);
         }
-
-        @supports (color: lab(0% 0 0)) {
-          .foo {
-            filter: var(--foo) drop-shadow(16px 16px 20px lab(40% 56.6 39));
-          }
+      }
+    "#,
+      indoc! {r#"
+      @media (width <= env(--EgL3uq_branding-small)) {
+        .EgL3uq_foo {
+          color: env(--EgL3uq_brand-color);
         }
-      "#},
-      Browsers {
-        chrome: Some(4 << 16),
-        ..Browsers::default()
+      }
+    "#},
+      map! {
+        "foo" => "EgL3uq_foo",
+        "--brand-color" => "--EgL3uq_brand-color" referenced: true,
+        "--branding-small" => "--EgL3uq_branding-small" referenced: true
+      },
+      HashMap::new(),
+      crate::css_modules::Config {
+        dashed_idents: true,
+        ..Default::default()
       },
     );
   }
 
   #[test]
-  fn test_viewport() {
+  fn test_attr() {
     minify_test(
-      r#"
-    @viewport {
-      width: 100vw;
-    }"#,
-      "@viewport{width:100vw}",
+      ".foo { width: attr(data-size px, 10px) }",
+      ".foo{width:attr(data-size px,10px)}",
     );
+    minify_test(".foo { width: attr(data-size %) }", ".foo{width:attr(data-size %)}");
     minify_test(
-      r#"
-    @-ms-viewport {
-      width: device-width;
-    }"#,
-      "@-ms-viewport{width:device-width}",
+      ".foo { opacity: attr(data-o number, 1) }",
+      ".foo{opacity:attr(data-o number,1)}",
     );
-  }
-
-  #[test]
-  fn test_at_scope() {
     minify_test(
-      r#"
-      @scope {
-        .foo {
-          display: flex;
-        }
-      }
-      "#,
-      "@scope{.foo{display:flex}}",
+      ".foo { width: attr( data-size type(<length> | <percentage>) , 1px ) }",
+      ".foo{width:attr(data-size type(<length>|<percentage>),1px)}",
     );
     minify_test(
-      r#"
-      @scope {
-        :scope {
-          display: flex;
-          color: lightblue;
-        }
-      }"#,
-      "@scope{:scope{color:#add8e6;display:flex}}",
+      ".foo { content: attr(title raw-string, 'x') }",
+      ".foo{content:attr(title raw-string,\"x\")}",
     );
     minify_test(
-      r#"
-      @scope (.light-scheme) {
-        a { color: yellow; }
-      }
-      "#,
-      "@scope(.light-scheme){a{color:#ff0}}",
+      ".foo { color: attr(data-color type(<color>), rgb(255 0 0)) }",
+      ".foo{color:attr(data-color type(<color>),red)}",
     );
     minify_test(
-      r#"
-      @scope (.media-object) to (.content > *) {
-        a { color: yellow; }
-      }
-      "#,
-      "@scope(.media-object) to (.content>*){a{color:#ff0}}",
+      ".foo { width: calc(attr(data-w px) * 2) }",
+      ".foo{width:calc(attr(data-w px)*2)}",
     );
+    minify_test(".foo { --x: attr(data-x number) }", ".foo{--x:attr(data-x number)}");
+    // Invalid attr() references are preserved as-is.
+    minify_test(".foo { width: attr(data-x px px) }", ".foo{width:attr(data-x px px)}");
     minify_test(
-      r#"
-      @scope to (.content > *) {
-        a { color: yellow; }
-      }
-      "#,
-      "@scope to (.content>*){a{color:#ff0}}",
+      ".foo { width: attr(data-x type(<bogus>)) }",
+      ".foo{width:attr(data-x type(<bogus>))}",
     );
+
+    prefix_test(
+      ".foo { color: attr(data-color type(<color>), lab(40% 56.6 39)) }",
+      indoc! {r#"
+        .foo {
+          color: attr(data-color type(<color>), #b32323);
+        }
+
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            color: attr(data-color type(<color>), lab(40% 56.6 39));
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    let stylesheet =
+      StyleSheet::parse(".foo { width: attr(data-size px, 10px) }", ParserOptions::default()).unwrap();
+    let rule = match &stylesheet.rules.0[0] {
+      CssRule::Style(rule) => rule,
+      _ => unreachable!(),
+    };
+    match &rule.declarations.declarations[0] {
+      Property::Unparsed(unparsed) => match &unparsed.value.0[0] {
+        TokenOrValue::Attr(attr) => {
+          assert_eq!(attr.name.0.as_ref(), "data-size");
+          assert_eq!(attr.attr_type, Some(AttrType::Unit("px".into())));
+        }
+        token => panic!("expected attr(), got {:?}", token),
+      },
+      property => panic!("expected unparsed property, got {:?}", property),
+    }
+  }
+
+  #[test]
+  fn test_toggle() {
     minify_test(
-      r#"
-      @scope (#my-component) {
-        & { color: yellow; }
-      }
-      "#,
-      "@scope(#my-component){&{color:#ff0}}",
+      ".foo { font-style: toggle(italic, normal) }",
+      ".foo{font-style:toggle(italic,normal)}",
     );
     minify_test(
-      r#"
-      @scope (.parent-scope) {
-        @scope (:scope > .child-scope) to (:scope .limit) {
-          .content { color: yellow; }
-        }
-      }
-      "#,
-      "@scope(.parent-scope){@scope(:scope>.child-scope) to (:scope .limit){.content{color:#ff0}}}",
+      ".foo { color: toggle( #ff0000 , rgb(0 0 255) ) }",
+      ".foo{color:toggle(red,#00f)}",
     );
     minify_test(
-      r#"
-      .foo {
-        @scope (.bar) {
-          color: yellow;
-        }
-      }
-      "#,
-      ".foo{@scope(.bar){&{color:#ff0}}}",
+      ".foo { list-style-type: toggle(disc,  circle, square) }",
+      ".foo{list-style-type:toggle(disc,circle,square)}",
     );
-    nesting_test(
-      r#"
-      .foo {
-        @scope (.bar) {
-          color: yellow;
-        }
-      }
-      "#,
-      indoc! {r#"
-        @scope (.bar) {
-          :scope {
-            color: #ff0;
-          }
-        }
-      "#},
+    minify_test(".foo { --x: toggle( a b , c ) }", ".foo{--x:toggle(a b,c)}");
+    // Invalid toggle() values are preserved as-is.
+    minify_test(".foo { color: toggle(red,,blue) }", ".foo{color:toggle(red,,blue)}");
+    minify_test(
+      ".foo { color: toggle(red, toggle(blue, green)) }",
+      ".foo{color:toggle(red,toggle(blue,green))}",
     );
-    nesting_test(
-      r#"
-      .parent {
-        color: blue;
 
-        @scope (& > .scope) to (& .limit) {
-          & .content {
-            color: yellow;
-          }
-        }
-      }
-      "#,
+    prefix_test(
+      ".foo { color: toggle(lab(40% 56.6 39), red) }",
       indoc! {r#"
-        .parent {
-          color: #00f;
+        .foo {
+          color: toggle(#b32323, red);
         }
 
-        @scope (.parent > .scope) to (.parent > .scope .limit) {
-          :scope .content {
-            color: #ff0;
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            color: toggle(lab(40% 56.6 39), red);
           }
         }
       "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
+
+    let stylesheet = StyleSheet::parse(".foo { color: toggle(red, blue) }", ParserOptions::default()).unwrap();
+    let rule = match &stylesheet.rules.0[0] {
+      CssRule::Style(rule) => rule,
+      _ => unreachable!(),
+    };
+    match &rule.declarations.declarations[0] {
+      Property::Unparsed(unparsed) => match &unparsed.value.0[0] {
+        TokenOrValue::Toggle(toggle) => assert_eq!(toggle.values.len(), 2),
+        token => panic!("expected toggle(), got {:?}", token),
+      },
+      property => panic!("expected unparsed property, got {:?}", property),
+    }
   }
 
   #[test]
-  fn test_custom_media() {
-    custom_media_test(
+  fn test_license_comments() {
+    minify_test(
       r#"
-      @custom-media --modern (color), (hover);
-
-      @media (--modern) and (width > 1024px) {
-        .a {
-          color: green;
-        }
+      /*! Copyright 2023 Someone awesome */
+      /* Some other comment */
+      .foo {
+        color: red;
       }
-      "#,
+    "#,
       indoc! {r#"
-      @media ((color) or (hover)) and (width > 1024px) {
-        .a {
-          color: green;
-        }
-      }
-      "#},
+      /*! Copyright 2023 Someone awesome */
+      .foo{color:red}"#},
     );
 
-    custom_media_test(
+    minify_test(
       r#"
-      @custom-media --color (color);
-
-      @media (--color) and (width > 1024px) {
-        .a {
-          color: green;
-        }
+      /*! Copyright 2023 Someone awesome */
+      /*! Copyright 2023 Someone else */
+      .foo {
+        color: red;
       }
-      "#,
+    "#,
       indoc! {r#"
-      @media (color) and (width > 1024px) {
-        .a {
-          color: green;
-        }
-      }
-      "#},
+      /*! Copyright 2023 Someone awesome */
+      /*! Copyright 2023 Someone else */
+      .foo{color:red}"#},
     );
+  }
 
-    custom_media_test(
+  #[test]
+  fn test_starting_style() {
+    minify_test(
       r#"
-      @custom-media --a (color);
-      @custom-media --b (--a);
-
-      @media (--b) and (width > 1024px) {
-        .a {
-          color: green;
+      @starting-style {
+        h1 {
+          background: yellow;
         }
       }
       "#,
-      indoc! {r#"
-      @media (color) and (width > 1024px) {
-        .a {
-          color: green;
-        }
-      }
-      "#},
+      "@starting-style{h1{background:#ff0}}",
+    );
+    minify_test("@starting-style {}", "");
+    minify_test("@starting-style { h1 {} }", "");
+    minify_test(
+      "@starting-style { h1 { color: red } } @starting-style { h2 { color: red } }",
+      "@starting-style{h1,h2{color:red}}",
+    );
+    minify_test(
+      "@starting-style { h1 { color: red } } @starting-style { h1 { color: green } }",
+      "@starting-style{h1{color:green}}",
+    );
+    minify_test(
+      "@starting-style { h1 { color: red } } h2 { color: red } @starting-style { h3 { color: red } }",
+      "@starting-style{h1{color:red}}h2{color:red}@starting-style{h3{color:red}}",
     );
+    minify_test(
+      "h1 { color: red; @starting-style { color: green } }",
+      "h1{color:red;@starting-style{&{color:green}}}",
+    );
+    minify_test(
+      "h1 { @starting-style { color: green } @starting-style { opacity: 0 } }",
+      "h1{@starting-style{&{color:green;opacity:0}}}",
+    );
+    minify_test("h1 { color: red; @starting-style {} }", "h1{color:red}");
 
-    custom_media_test(
+    nesting_test(
       r#"
-      @custom-media --not-color not (color);
-
-      @media not (--not-color) {
-        .a {
-          color: green;
+      h1 {
+        background: red;
+        @starting-style {
+          background: yellow;
         }
       }
       "#,
       indoc! {r#"
-      @media (color) {
-        .a {
-          color: green;
+      h1 {
+        background: red;
+      }
+
+      @starting-style {
+        h1 {
+          background: #ff0;
         }
       }
       "#},
     );
 
-    custom_media_test(
+    nesting_test(
       r#"
-      @custom-media --color-print print and (color);
-
-      @media (--color-print) {
-        .a {
-          color: green;
+      .dialog {
+        opacity: 1;
+        transition: opacity 1s;
+        @starting-style {
+          opacity: 0;
+        }
+        &.open {
+          @starting-style {
+            transform: scale(0);
+          }
         }
       }
       "#,
       indoc! {r#"
-      @media print and (color) {
-        .a {
-          color: green;
-        }
+      .dialog {
+        opacity: 1;
+        transition: opacity 1s;
       }
-      "#},
-    );
-
-    custom_media_test(
-      r#"
-      @custom-media --color-print print and (color);
 
-      @media print and (--color-print) {
-        .a {
-          color: green;
+      @starting-style {
+        .dialog {
+          opacity: 0;
         }
       }
-      "#,
-      indoc! {r#"
-      @media print and (color) {
-        .a {
-          color: green;
+
+      @starting-style {
+        .dialog.open {
+          transform: scale(0);
         }
       }
       "#},
     );
+  }
 
-    custom_media_test(
-      r#"
-      @custom-media --not-color-print not print and (color);
-
-      @media not print and (--not-color-print) {
-        .a {
-          color: green;
-        }
+  #[test]
+  fn test_color_scheme() {
+    minify_test(".foo { color-scheme: normal; }", ".foo{color-scheme:normal}");
+    minify_test(".foo { color-scheme: light; }", ".foo{color-scheme:light}");
+    minify_test(".foo { color-scheme: dark; }", ".foo{color-scheme:dark}");
+    minify_test(".foo { color-scheme: light dark; }", ".foo{color-scheme:light dark}");
+    minify_test(".foo { color-scheme: dark light; }", ".foo{color-scheme:light dark}");
+    minify_test(".foo { color-scheme: only light; }", ".foo{color-scheme:light only}");
+    minify_test(".foo { color-scheme: only dark; }", ".foo{color-scheme:dark only}");
+    minify_test(
+      ".foo { color-scheme: dark light only; }",
+      ".foo{color-scheme:light dark only}",
+    );
+    minify_test(".foo { color-scheme: foo bar light; }", ".foo{color-scheme:light}");
+    minify_test(
+      ".foo { color-scheme: only foo dark bar; }",
+      ".foo{color-scheme:dark only}",
+    );
+    prefix_test(
+      ".foo { color-scheme: dark; }",
+      indoc! { r#"
+      .foo {
+        --lightningcss-light: ;
+        --lightningcss-dark: initial;
+        color-scheme: dark;
       }
-      "#,
-      indoc! {r#"
-      @media not print and (color) {
-        .a {
-          color: green;
-        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { color-scheme: light; }",
+      indoc! { r#"
+      .foo {
+        --lightningcss-light: initial;
+        --lightningcss-dark: ;
+        color-scheme: light;
       }
       "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
+    prefix_test(
+      ".foo { color-scheme: light dark; }",
+      indoc! { r#"
+      .foo {
+        --lightningcss-light: initial;
+        --lightningcss-dark: ;
+        color-scheme: light dark;
+      }
 
-    custom_media_test(
-      r#"
-      @custom-media --print print;
-
-      @media (--print) {
-        .a {
-          color: green;
+      @media (prefers-color-scheme: dark) {
+        .foo {
+          --lightningcss-light: ;
+          --lightningcss-dark: initial;
         }
       }
-      "#,
-      indoc! {r#"
-      @media print {
-        .a {
-          color: green;
-        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { color-scheme: light dark; }",
+      indoc! { r#"
+      .foo {
+        color-scheme: light dark;
       }
       "#},
+      Browsers {
+        firefox: Some(120 << 16),
+        ..Browsers::default()
+      },
     );
 
-    custom_media_test(
-      r#"
-      @custom-media --print print;
-
-      @media not (--print) {
-        .a {
-          color: green;
-        }
+    minify_test(
+      ".foo { color: light-dark(yellow, red); }",
+      ".foo{color:light-dark(#ff0,red)}",
+    );
+    minify_test(
+      ".foo { color: light-dark(light-dark(yellow, red), light-dark(yellow, red)); }",
+      ".foo{color:light-dark(#ff0,red)}",
+    );
+    minify_test(
+      ".foo { color: light-dark(rgb(0, 0, 255), hsl(120deg, 50%, 50%)); }",
+      ".foo{color:light-dark(#00f,#40bf40)}",
+    );
+    prefix_test(
+      ".foo { color: light-dark(oklch(40% 0.1268735435 34.568626), oklab(59.686% 0.1009 0.1192)); }",
+      indoc! { r#"
+      .foo {
+        color: var(--lightningcss-light, #7e250f) var(--lightningcss-dark, #c65d07);
+        color: var(--lightningcss-light, lab(29.2661% 38.2437 35.3889)) var(--lightningcss-dark, lab(52.2319% 40.1449 59.9171));
       }
-      "#,
-      indoc! {r#"
-      @media not print {
-        .a {
-          color: green;
-        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { color: light-dark(oklch(40% 0.1268735435 34.568626), oklab(59.686% 0.1009 0.1192)); }",
+      indoc! { r#"
+      .foo {
+        color: light-dark(oklch(40% .126874 34.5686), oklab(59.686% .1009 .1192));
       }
       "#},
+      Browsers {
+        firefox: Some(120 << 16),
+        ..Browsers::default()
+      },
     );
-
-    custom_media_test(
+    prefix_test(
       r#"
-      @custom-media --print not print;
-
-      @media not (--print) {
-        .a {
-          color: green;
-        }
+      .foo {
+        box-shadow:
+            oklch(100% 0 0deg / 50%) 0 0.63rem 0.94rem -0.19rem,
+            currentColor 0 0.44rem 0.8rem -0.58rem;
       }
-      "#,
-      indoc! {r#"
-      @media print {
-        .a {
-          color: green;
-        }
+    "#,
+      indoc! { r#"
+      .foo {
+        box-shadow: 0 .63rem .94rem -.19rem #ffffff80, 0 .44rem .8rem -.58rem;
+        box-shadow: 0 .63rem .94rem -.19rem lab(100% 0 0 / .5), 0 .44rem .8rem -.58rem;
       }
       "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
     );
 
-    custom_media_test(
-      r#"
-      @custom-media --print print;
-
-      @media ((--print)) {
-        .a {
-          color: green;
-        }
-      }
-      "#,
-      indoc! {r#"
-      @media print {
-        .a {
-          color: green;
-        }
+    prefix_test(
+      ".foo { color: light-dark(var(--light), var(--dark)); }",
+      indoc! { r#"
+      .foo {
+        color: var(--lightningcss-light, var(--light)) var(--lightningcss-dark, var(--dark));
       }
       "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-
-    custom_media_test(
-      r#"
-      @custom-media --color (color);
-      @custom-media --print print;
-
-      @media (--print) and (--color) {
-        .a {
-          color: green;
-        }
-      }
-      "#,
-      indoc! {r#"
-      @media print and (color) {
-        .a {
-          color: green;
-        }
+    prefix_test(
+      ".foo { color: rgb(from light-dark(yellow, red) r g b / 10%); }",
+      indoc! { r#"
+      .foo {
+        color: var(--lightningcss-light, #ffff001a) var(--lightningcss-dark, #ff00001a);
       }
       "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-
-    custom_media_test(
-      r#"
-      @custom-media --color (color);
-      @custom-media --not-print not print;
-
-      @media (--not-print) and (--color) {
-        .a {
-          color: green;
-        }
-      }
-      "#,
-      indoc! {r#"
-      @media not print and (color) {
-        .a {
-          color: green;
-        }
+    prefix_test(
+      ".foo { color: rgb(from light-dark(yellow, red) r g b / var(--alpha)); }",
+      indoc! { r#"
+      .foo {
+        color: var(--lightningcss-light, rgb(255 255 0 / var(--alpha))) var(--lightningcss-dark, rgb(255 0 0 / var(--alpha)));
       }
       "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-
-    custom_media_test(
-      r#"
-      @custom-media --color (color);
-      @custom-media --screen screen;
-      @custom-media --print print;
-
-      @media (--print) and (--color), (--screen) and (--color) {
-        .a {
-          color: green;
-        }
+    prefix_test(
+      ".foo { color: color(from light-dark(yellow, red) srgb r g b / 10%); }",
+      indoc! { r#"
+      .foo {
+        color: var(--lightningcss-light, #ffff001a) var(--lightningcss-dark, #ff00001a);
+        color: var(--lightningcss-light, color(srgb 1 1 0 / .1)) var(--lightningcss-dark, color(srgb 1 0 0 / .1));
       }
-      "#,
-      indoc! {r#"
-      @media print and (color), screen and (color) {
-        .a {
-          color: green;
-        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { color: color-mix(in srgb, light-dark(yellow, red), light-dark(red, pink)); }",
+      indoc! { r#"
+      .foo {
+        color: var(--lightningcss-light, #ff8000) var(--lightningcss-dark, #ff6066);
       }
       "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
+  }
 
-    custom_media_test(
-      r#"
-      @custom-media --color print and (color), print and (script);
-
-      @media (--color) {
-        .a {
-          color: green;
-        }
+  #[test]
+  fn test_contrast_color() {
+    minify_test(
+      ".foo { color: contrast-color(red); }",
+      ".foo{color:contrast-color(red)}",
+    );
+    minify_test(
+      ".foo { color: contrast-color(rgb(0, 0, 255)); }",
+      ".foo{color:contrast-color(#00f)}",
+    );
+    minify_test(
+      ".foo { color: contrast-color(currentColor); }",
+      ".foo{color:contrast-color(currentColor)}",
+    );
+    minify_test(
+      ".foo { color: color-mix(in srgb, contrast-color(red), blue); }",
+      ".foo{color:color-mix(in srgb,contrast-color(red),blue)}",
+    );
+    prefix_test(
+      ".foo { color: contrast-color(red); background: contrast-color(navy); }",
+      indoc! { r#"
+      .foo {
+        color: #000;
+        background: #fff;
       }
-      "#,
-      indoc! {r#"
-      @media print and ((color) or (script)) {
-        .a {
-          color: green;
-        }
+      "#},
+      Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { color: contrast-color(oklch(90% 0.1 200)); }",
+      indoc! { r#"
+      .foo {
+        color: #000;
       }
       "#},
+      Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
+      },
     );
-
-    custom_media_test(
-      r#"
-      @custom-media --color (color);
-      @custom-media --not-color not all and (--color);
-
-      @media (--not-color) {
-        .a {
-          color: green;
-        }
+    prefix_test(
+      ".foo { color: contrast-color(light-dark(yellow, navy)); }",
+      indoc! { r#"
+      .foo {
+        color: light-dark(#000, #fff);
       }
-      "#,
-      indoc! {r#"
-        @media not all and (color) {
-          .a {
-            color: green;
-          }
-        }
       "#},
+      Browsers {
+        chrome: Some(125 << 16),
+        ..Browsers::default()
+      },
     );
-
-    custom_media_test(
-      r#"
-      @custom-media --color (color);
-
-      @media not all and (--color) {
-        .a {
-          color: green;
-        }
+    prefix_test(
+      ".foo { color: contrast-color(currentColor); }",
+      indoc! { r#"
+      .foo {
+        color: contrast-color(currentColor);
       }
-      "#,
-      indoc! {r#"
-        @media not all and (color) {
-          .a {
-            color: green;
-          }
-        }
       "#},
+      Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
+      },
     );
-
-    custom_media_test(
-      r#"
-      @media (--print) {
-        .a {
-          color: green;
-        }
+    prefix_test(
+      ".foo { color: contrast-color(red); }",
+      indoc! { r#"
+      .foo {
+        color: contrast-color(red);
       }
+      "#},
+      Browsers {
+        safari: Some(26 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
 
-      @custom-media --print print;
-      "#,
-      indoc! {r#"
-      @media print {
-        .a {
-          color: green;
-        }
+  #[test]
+  fn test_device_cmyk() {
+    minify_test(
+      ".foo { color: device-cmyk(0 81% 81% 30%); }",
+      ".foo{color:device-cmyk(0 .81 .81 .3)}",
+    );
+    minify_test(
+      ".foo { color: device-cmyk(0, 0.81, 0.81, 0.3); }",
+      ".foo{color:device-cmyk(0 .81 .81 .3)}",
+    );
+    minify_test(
+      ".foo { color: device-cmyk(0 0.81 0.81 0.3 / 50%); }",
+      ".foo{color:device-cmyk(0 .81 .81 .3/.5)}",
+    );
+    minify_test(
+      ".foo { color: device-cmyk(0 0.81 none 0.3 / 1); }",
+      ".foo{color:device-cmyk(0 .81 none .3)}",
+    );
+    minify_test(
+      ".foo { color: device-cmyk(0 0.81 0.81 0.3, rgb(178, 34, 34)); }",
+      ".foo{color:device-cmyk(0 .81 .81 .3,#b22222)}",
+    );
+    minify_test(
+      ".foo { color: device-cmyk(0, 81%, 0.81, 0.3); }",
+      ".foo{color:device-cmyk(0,81%,.81,.3)}",
+    );
+    minify_test(
+      ".foo { color: device-cmyk(0 0.81 0.81); }",
+      ".foo{color:device-cmyk(0 .81 .81)}",
+    );
+    prefix_test(
+      ".foo { color: device-cmyk(0 81% 81% 30%); }",
+      indoc! { r#"
+      .foo {
+        color: #b32222;
       }
       "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-
-    custom_media_test(
-      r#"
-      @custom-media --not-width not (min-width: 300px);
-      @media screen and ((prefers-color-scheme: dark) or (--not-width)) {
-        .foo {
-          order: 6;
-        }
+    prefix_test(
+      ".foo { color: device-cmyk(0 81% 81% 30% / 50%); }",
+      indoc! { r#"
+      .foo {
+        color: #b3222280;
       }
-      "#,
-      indoc! {r#"
-      @media screen and ((prefers-color-scheme: dark) or (not (width >= 300px))) {
-        .foo {
-          order: 6;
-        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { color: device-cmyk(0 81% 81% 30%, firebrick); }",
+      indoc! { r#"
+      .foo {
+        color: #b22222;
       }
       "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
+  }
 
-    fn custom_media_error_test(source: &str, err: Error<MinifyErrorKind>) {
-      let mut stylesheet = StyleSheet::parse(
-        &source,
-        ParserOptions {
-          filename: "test.css".into(),
-          flags: ParserFlags::CUSTOM_MEDIA,
-          ..ParserOptions::default()
-        },
-      )
-      .unwrap();
-      let res = stylesheet.minify(MinifyOptions {
-        targets: Browsers {
-          chrome: Some(95 << 16),
+  #[test]
+  fn test_gamut_mapping() {
+    use crate::values::color::GamutMapping;
+
+    fn gamut_mapping_test(source: &str, expected: &str, gamut_mapping: GamutMapping) {
+      let targets = Targets {
+        browsers: Some(Browsers {
+          chrome: Some(90 << 16),
           ..Browsers::default()
-        }
-        .into(),
-        ..MinifyOptions::default()
-      });
-      assert_eq!(res, Err(err))
+        }),
+        ..Targets::default()
+      };
+      minify_test_with_minify_options(
+        source,
+        expected,
+        MinifyOptions {
+          targets,
+          gamut_mapping,
+          ..MinifyOptions::default()
+        },
+      );
     }
 
-    custom_media_error_test(
-      r#"
-      @custom-media --color-print print and (color);
+    gamut_mapping_test(
+      ".foo { color: oklch(60% 0.3 140); }",
+      ".foo{color:#219b00;color:lab(56.6135% -75.9817 131.931)}",
+      GamutMapping::ChromaReduction,
+    );
+    gamut_mapping_test(
+      ".foo { color: oklch(60% 0.3 140); }",
+      ".foo{color:#00a400;color:lab(56.6135% -75.9817 131.931)}",
+      GamutMapping::Clip,
+    );
+    gamut_mapping_test(
+      ".foo { color: color(display-p3 0 1 0); }",
+      ".foo{color:#0f0;color:color(display-p3 0 1 0)}",
+      GamutMapping::Clip,
+    );
+    gamut_mapping_test(".foo { background: linear-gradient(oklch(60% 0.3 140), red); }", ".foo{background:linear-gradient(#00a400,red);background:linear-gradient(lab(56.6135% -75.9817 131.931),red)}", GamutMapping::Clip);
+    gamut_mapping_test(
+      ".foo { box-shadow: 0 0 2px oklch(60% 0.3 140); }",
+      ".foo{box-shadow:0 0 2px #00a400;box-shadow:0 0 2px lab(56.6135% -75.9817 131.931)}",
+      GamutMapping::Clip,
+    );
+    gamut_mapping_test(
+      ".foo { color: oklch(60% 0.1 140); }",
+      ".foo{color:#5f8f54;color:lab(54.789% -25.7556 25.7172)}",
+      GamutMapping::Clip,
+    );
+  }
 
-      @media screen and (--color-print) {
-        .a {
-          color: green;
-        }
-      }
-      "#,
-      Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 1,
-            column: 7,
-          },
+  #[test]
+  fn test_dir_attribute() {
+    use crate::stylesheet::DirStrategy;
+
+    fn test(source: &str, expected: &str, browsers: Browsers) {
+      minify_test_with_minify_options(
+        source,
+        expected,
+        MinifyOptions {
+          targets: browsers.into(),
+          dir_strategy: DirStrategy::Attribute,
+          ..MinifyOptions::default()
         },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 3,
-          column: 7,
-        }),
+      );
+    }
+
+    let browsers = Browsers {
+      safari: Some(14 << 16),
+      ..Browsers::default()
+    };
+    test("a:dir(rtl) {color:red}", "[dir=rtl] a{color:red}", browsers);
+    test(
+      "div.foo:dir(rtl):hover {color:red}",
+      "[dir=rtl] div.foo:hover{color:red}",
+      browsers,
+    );
+    test(
+      ".foo:dir(ltr) .bar:dir(ltr) {color:red}",
+      "[dir=ltr] .foo .bar{color:red}",
+      browsers,
+    );
+    test(":dir(rtl) > .bar {color:red}", "[dir=rtl]>.bar{color:red}", browsers);
+    test(":dir(rtl) {color:red}", "[dir=rtl]{color:red}", browsers);
+    test(
+      "a:dir(rtl)::after {color:red}",
+      "[dir=rtl] a:after{color:red}",
+      browsers,
+    );
+    test(
+      "a:not(:dir(rtl)) {color:red}",
+      "a:not(:lang(ae,ar,arc,bcc,bqi,ckb,dv,fa,glk,he,ku,mzn,nqo,pnb,ps,sd,ug,ur,yi)){color:red}",
+      browsers,
+    );
+    test(
+      "a:dir(rtl) {color:red}",
+      "a:dir(rtl){color:red}",
+      Browsers {
+        safari: Some(17 << 16),
+        ..Browsers::default()
       },
     );
+  }
 
-    custom_media_error_test(
-      r#"
-      @custom-media --color-print print and (color);
-
-      @media not print and (--color-print) {
-        .a {
-          color: green;
-        }
-      }
-      "#,
-      Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 1,
-            column: 7,
-          },
+  #[cfg(feature = "visitor")]
+  #[test]
+  fn test_light_dark_media_query() {
+    use crate::stylesheet::LightDarkStrategy;
+
+    fn test(source: &str, expected: &str, browsers: Browsers) {
+      minify_test_with_minify_options(
+        source,
+        expected,
+        MinifyOptions {
+          targets: browsers.into(),
+          light_dark: LightDarkStrategy::MediaQuery,
+          ..MinifyOptions::default()
         },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 3,
-          column: 7,
-        }),
-      },
-    );
+      );
+    }
 
-    custom_media_error_test(
-      r#"
-      @custom-media --color-print print and (color);
-      @custom-media --color-screen screen and (color);
+    let old = Browsers {
+      chrome: Some(90 << 16),
+      ..Browsers::default()
+    };
 
-      @media (--color-print) or (--color-screen) {}
-      "#,
-      Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 2,
-            column: 7,
-          },
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 4,
-          column: 7,
-        }),
+    test(
+      ".foo { color: light-dark(yellow, red); background: green; }",
+      ".foo{color:#ff0;background:green}@media (prefers-color-scheme:dark){.foo{color:red}}",
+      old,
+    );
+    test(
+      ".foo { border: 1px solid light-dark(yellow, red); box-shadow: 0 0 2px light-dark(#000, #fff); }",
+      ".foo{border:1px solid #ff0;box-shadow:0 0 2px #000}@media (prefers-color-scheme:dark){.foo{border:1px solid red;box-shadow:0 0 2px #fff}}",
+      old,
+    );
+    test(
+      ".foo { color: light-dark(var(--light), var(--dark)); --accent: light-dark(yellow, red); }",
+      ".foo{color:var(--light);--accent:#ff0}@media (prefers-color-scheme:dark){.foo{color:var(--dark);--accent:red}}",
+      old,
+    );
+    test(
+      ".foo { color: light-dark(yellow, red) !important; }",
+      ".foo{color:var(--lightningcss-light,#ff0)var(--lightningcss-dark,red)!important}",
+      old,
+    );
+    test(
+      "@keyframes foo { from { color: light-dark(yellow, red) } }",
+      "@keyframes foo{0%{color:var(--lightningcss-light,#ff0)var(--lightningcss-dark,red)}}",
+      old,
+    );
+    test(
+      ".foo { color: light-dark(yellow, red); }",
+      ".foo{color:light-dark(#ff0,red)}",
+      Browsers {
+        chrome: Some(123 << 16),
+        ..Browsers::default()
       },
     );
+  }
 
-    custom_media_error_test(
-      r#"
-      @custom-media --color-print print and (color);
-      @custom-media --color-screen screen and (color);
-
-      @media (--color-print) and (--color-screen) {}
-      "#,
-      Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 2,
-            column: 7,
-          },
+  #[cfg(feature = "visitor")]
+  #[test]
+  fn test_px_to_rem() {
+    use crate::stylesheet::PxToRemOptions;
+
+    fn test(source: &str, expected: &str, options: PxToRemOptions) {
+      minify_test_with_minify_options(
+        source,
+        expected,
+        MinifyOptions {
+          px_to_rem: Some(options),
+          ..MinifyOptions::default()
         },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 4,
-          column: 7,
-        }),
+      );
+    }
+
+    test(
+      ".foo { width: 32px; margin: 8px 0 4px; font-size: 14px }",
+      ".foo{width:2rem;margin:.5rem 0 .25rem;font-size:.875rem}",
+      PxToRemOptions::default(),
+    );
+    test(
+      ".foo { width: calc(100% - 16px); transform: translate(8px, 2em) }",
+      ".foo{width:calc(100% - 1rem);transform:translate(.5rem,2em)}",
+      PxToRemOptions::default(),
+    );
+    test(
+      ".foo { --gap: 24px; padding: var(--pad, 12px) }",
+      ".foo{--gap:24px;padding:var(--pad,.75rem)}",
+      PxToRemOptions::default(),
+    );
+    test(
+      ".foo { --gap: 24px; foo-bar: 24px }",
+      ".foo{--gap:24px;foo-bar:1.5rem}",
+      PxToRemOptions::default(),
+    );
+    test(
+      ".foo { border: 1px solid red; width: 32px }",
+      ".foo{border:.0625rem solid red;width:2rem}",
+      PxToRemOptions::default(),
+    );
+    test(
+      ".foo { width: 20px }",
+      ".foo{width:2rem}",
+      PxToRemOptions {
+        root_font_size: 10.0,
+        ..PxToRemOptions::default()
+      },
+    );
+    test(
+      ".foo { border: 1px solid red; padding: 16px; box-shadow: 0 1px 2px red }",
+      ".foo{border:1px solid red;padding:1rem;box-shadow:0 .0625rem .125rem red}",
+      PxToRemOptions {
+        min_px: 0.0,
+        exclude: vec!["border".into()],
+        ..PxToRemOptions::default()
+      },
+    );
+    test(
+      ".foo { border-top-width: 1px; border-radius: 8px; padding: 16px 1px }",
+      ".foo{border-top-width:1px;border-radius:8px;padding:1rem 1px}",
+      PxToRemOptions {
+        min_px: 2.0,
+        exclude: vec!["border".into()],
+        ..PxToRemOptions::default()
       },
     );
+    test(
+      "@media (min-width: 768px) { .foo { width: 16px } }",
+      "@media (width>=768px){.foo{width:1rem}}",
+      PxToRemOptions::default(),
+    );
 
-    custom_media_error_test(
-      r#"
-      @custom-media --screen screen;
-      @custom-media --print print;
+    let mut attr = StyleAttribute::parse("width: 16px", ParserOptions::default()).unwrap();
+    attr.minify(MinifyOptions {
+      px_to_rem: Some(PxToRemOptions::default()),
+      ..MinifyOptions::default()
+    });
+    let res = attr
+      .to_css(PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(res.code, "width:1rem");
+  }
 
-      @media (--print) and (--screen) {}
-      "#,
-      Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 1,
-            column: 7,
-          },
+  #[cfg(feature = "into_owned")]
+  #[test]
+  fn test_scope_selectors() {
+    use crate::selector::{ParseSelector, Selector};
+    use crate::stylesheet::SelectorScope;
+
+    fn test(source: &str, expected: &str, scope: SelectorScope) {
+      minify_test_with_minify_options(
+        source,
+        expected,
+        MinifyOptions {
+          scope: Some(scope),
+          ..MinifyOptions::default()
         },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 4,
-          column: 7,
-        }),
+      );
+    }
+
+    let ancestor = SelectorScope::Ancestor(Selector::parse_string(".widget").unwrap());
+    test(
+      ".foo > .bar { color: red }",
+      ".widget .foo>.bar{color:red}",
+      ancestor.clone(),
+    );
+    test(":root { --gap: 2px }", ".widget{--gap:2px}", ancestor.clone());
+    test(
+      ":root.dark .foo, html body { color: red }",
+      ".widget.dark .foo,.widget body{color:red}",
+      ancestor.clone(),
+    );
+    test(
+      ":global(.foo) .bar { color: red }",
+      ":global(.foo) .bar{color:red}",
+      ancestor.clone(),
+    );
+    test(
+      ":root.dark.large div.foo { color: red }",
+      "div.widget.dark.large div.foo{color:red}",
+      SelectorScope::Ancestor(Selector::parse_string("div.widget").unwrap()),
+    );
+    test(
+      "@media (min-width: 100px) { .foo { color: red } }",
+      "@media (width>=100px){.widget .foo{color:red}}",
+      ancestor.clone(),
+    );
+    test(
+      ".foo { color: red; .bar { color: green } }",
+      ".widget .foo{color:red;& .bar{color:green}}",
+      ancestor.clone(),
+    );
+    test(
+      "@keyframes fade { from { opacity: 0 } to { opacity: 1 } }",
+      "@keyframes fade{0%{opacity:0}to{opacity:1}}",
+      ancestor.clone(),
+    );
+
+    let attribute = SelectorScope::Attribute {
+      name: "data-v-123".into(),
+      value: None,
+    };
+    test(
+      ".foo .bar::before { color: red }",
+      ".foo .bar[data-v-123]:before{color:red}",
+      attribute.clone(),
+    );
+    test(
+      "div.foo:hover::before { color: red }",
+      "div.foo:hover[data-v-123]:before{color:red}",
+      attribute.clone(),
+    );
+    test(
+      "::selection { color: red }",
+      "[data-v-123]::selection{color:red}",
+      attribute.clone(),
+    );
+    test(":root, html { color: red }", ":root,html{color:red}", attribute.clone());
+    test(
+      ".foo { color: red; .bar { color: green } &:hover { color: blue } }",
+      ".foo[data-v-123]{color:red;& .bar[data-v-123]{color:green}&:hover{color:#00f}}",
+      attribute.clone(),
+    );
+    test(
+      "@supports (display: grid) { .foo + .bar { color: red } }",
+      "@supports (display:grid){.foo+.bar[data-v-123]{color:red}}",
+      attribute.clone(),
+    );
+    test(
+      ".foo { color: red }",
+      ".foo[data-scope=app]{color:red}",
+      SelectorScope::Attribute {
+        name: "data-scope".into(),
+        value: Some("app".into()),
       },
     );
+  }
+
+  #[test]
+  fn test_all() {
+    minify_test(".foo { all: initial; all: initial }", ".foo{all:initial}");
+    minify_test(".foo { all: initial; all: revert }", ".foo{all:revert}");
+    minify_test(".foo { background: red; all: revert-layer }", ".foo{all:revert-layer}");
+    minify_test(
+      ".foo { background: red; all: revert-layer; background: green }",
+      ".foo{all:revert-layer;background:green}",
+    );
+    minify_test(
+      ".foo { --test: red; all: revert-layer }",
+      ".foo{--test:red;all:revert-layer}",
+    );
+    minify_test(
+      ".foo { unicode-bidi: embed; all: revert-layer }",
+      ".foo{all:revert-layer;unicode-bidi:embed}",
+    );
+    minify_test(
+      ".foo { direction: rtl; all: revert-layer }",
+      ".foo{all:revert-layer;direction:rtl}",
+    );
+    minify_test(
+      ".foo { direction: rtl; all: revert-layer; direction: ltr }",
+      ".foo{all:revert-layer;direction:ltr}",
+    );
+    minify_test(".foo { background: var(--foo); all: unset; }", ".foo{all:unset}");
+    minify_test(
+      ".foo { all: unset; background: var(--foo); }",
+      ".foo{all:unset;background:var(--foo)}",
+    );
+  }
 
-    custom_media_error_test(
-      r#"
-      @custom-media --not-print not print and (color);
-      @custom-media --not-screen not screen and (color);
-
-      @media ((script) or ((--not-print) and (--not-screen))) {
-        .a {
-          color: green;
+  #[test]
+  fn test_fallback_control() {
+    use std::sync::Arc;
+
+    struct Control;
+    impl FallbackControl for Control {
+      fn fallbacks<'i>(
+        &self,
+        property: &Property<'i>,
+        kind: FallbackKind,
+        _targets: &Targets,
+        fallbacks: Vec<Property<'i>>,
+      ) -> Vec<Property<'i>> {
+        match (property, kind) {
+          // Suppress color fallbacks, but keep the ones for other properties.
+          (Property::Color(_), FallbackKind::Value) => vec![],
+          // Replace the -webkit- prefixed user-select with a -moz- one.
+          (Property::UserSelect(val, _), FallbackKind::Prefix) => {
+            vec![Property::UserSelect(*val, VendorPrefix::Moz)]
+          }
+          // Suppress :dir() fallbacks for padding.
+          (Property::PaddingLeft(_), FallbackKind::Logical) => vec![],
+          // Suppress prefixed transitions and display values.
+          (Property::Transition(..) | Property::Display(_), FallbackKind::Prefix) => vec![],
+          _ => fallbacks,
         }
       }
-      "#,
-      Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 2,
-            column: 7,
-          },
+    }
+
+    fn fallback_test(source: &str, expected: &str, targets: Targets) {
+      minify_test_with_minify_options(
+        source,
+        expected,
+        MinifyOptions {
+          targets,
+          fallback_control: Some(Arc::new(Control)),
+          ..MinifyOptions::default()
         },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 4,
-          column: 7,
-        }),
-      },
-    );
+      );
+    }
 
-    custom_media_error_test(
-      r#"
-      @custom-media --color screen and (color), print and (color);
+    let targets = Targets {
+      browsers: Some(Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      }),
+      exclude: Features::DirSelector,
+      ..Targets::default()
+    };
 
-      @media (--color) {
-        .a {
-          color: green;
-        }
+    fallback_test(
+      r#"
+      .foo {
+        color: lab(40% 56.6 39);
+        background-color: lab(40% 56.6 39);
       }
-      "#,
-      Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 1,
-            column: 7,
-          },
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 3,
-          column: 7,
-        }),
-      },
+    "#,
+      ".foo{color:lab(40% 56.6 39);background-color:#b32323;background-color:lab(40% 56.6 39)}",
+      targets,
     );
 
-    custom_media_error_test(
+    fallback_test(
       r#"
-      @media (--not-defined) {
-        .a {
-          color: green;
-        }
+      .foo {
+        user-select: none;
       }
-      "#,
-      Error {
-        kind: MinifyErrorKind::CustomMediaNotDefined {
-          name: "--not-defined".into(),
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 1,
-          column: 7,
-        }),
-      },
+    "#,
+      ".foo{-moz-user-select:none;user-select:none}",
+      targets,
     );
 
-    custom_media_error_test(
+    fallback_test(
       r#"
-      @custom-media --circular-mq-a (--circular-mq-b);
-      @custom-media --circular-mq-b (--circular-mq-a);
+      .foo {
+        padding-inline-start: 2px;
+        margin-inline-start: 2px;
+      }
+    "#,
+      ".foo:dir(ltr){margin-left:2px}.foo:dir(rtl){margin-right:2px}",
+      targets,
+    );
 
-      @media (--circular-mq-a) {
-        body {
-          order: 3;
-        }
+    fallback_test(
+      r#"
+      .foo {
+        transition: opacity 1s;
+        display: flex;
       }
-      "#,
-      Error {
-        kind: MinifyErrorKind::CircularCustomMedia {
-          name: "--circular-mq-a".into(),
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 4,
-          column: 7,
+    "#,
+      ".foo{transition:opacity 1s;display:flex}",
+      Targets {
+        browsers: Some(Browsers {
+          safari: Some(4 << 16),
+          ..Browsers::default()
         }),
+        ..Targets::default()
       },
     );
+
+    // A hook that returns the default fallbacks should produce the same output as no hook.
+    struct Identity;
+    impl FallbackControl for Identity {
+      fn fallbacks<'i>(
+        &self,
+        _property: &Property<'i>,
+        _kind: FallbackKind,
+        _targets: &Targets,
+        fallbacks: Vec<Property<'i>>,
+      ) -> Vec<Property<'i>> {
+        fallbacks
+      }
+    }
+
+    fn identity_test(source: &str, targets: Targets) {
+      let minify = |fallback_control: Option<Arc<dyn FallbackControl>>| {
+        let mut stylesheet = StyleSheet::parse(source, ParserOptions::default()).unwrap();
+        stylesheet
+          .minify(MinifyOptions {
+            targets,
+            fallback_control,
+            ..MinifyOptions::default()
+          })
+          .unwrap();
+        stylesheet.to_css(PrinterOptions::default()).unwrap().code
+      };
+      assert_eq!(minify(Some(Arc::new(Identity))), minify(None));
+    }
+
+    let targets = Targets {
+      browsers: Some(Browsers {
+        safari: Some(4 << 16),
+        firefox: Some(10 << 16),
+        ie: Some(10 << 16),
+        ..Browsers::default()
+      }),
+      ..Targets::default()
+    };
+
+    identity_test(".foo { user-select: none; user-select: text; }", targets);
+    identity_test(".foo { -webkit-user-select: none; user-select: none; }", targets);
+    identity_test(".foo { user-select: none; -moz-user-select: none; }", targets);
+    identity_test(
+      ".foo { transition: opacity 1s; -webkit-transition: opacity 1s; }",
+      targets,
+    );
+    identity_test(".foo { display: flex; flex: 1; flex-direction: column; }", targets);
+    identity_test(".foo { align-items: center; justify-content: space-between; }", targets);
+    identity_test(".foo { box-shadow: 0 0 2px lab(40% 56.6 39); }", targets);
+    identity_test(
+      ".foo { filter: blur(2px); filter: drop-shadow(0 0 2px lab(40% 56.6 39)); }",
+      targets,
+    );
+    identity_test(
+      ".foo { mask-image: linear-gradient(red, green); mask-size: 10px; }",
+      targets,
+    );
+    identity_test(".foo { mask: url(foo.svg) center / 10px no-repeat; }", targets);
+    identity_test(".foo { mask-border: url(foo.png) 30 fill; }", targets);
+    identity_test(".foo { border-image: linear-gradient(red, green) 30; }", targets);
+    identity_test(".foo { border-radius: 2px; transform: rotate(45deg); }", targets);
+    identity_test(
+      ".foo { animation: foo 1s; text-decoration: underline dotted red; }",
+      targets,
+    );
+    identity_test(".foo { text-emphasis: filled red; width: stretch; }", targets);
+    identity_test(".foo { transform: var(--foo); transition: var(--bar); }", targets);
   }
 
   #[test]
-  fn test_dependencies() {
-    fn dep_test(source: &str, expected: &str, deps: Vec<(&str, &str)>) {
-      let mut stylesheet = StyleSheet::parse(
-        &source,
-        ParserOptions {
-          filename: "test.css".into(),
-          ..ParserOptions::default()
-        },
-      )
-      .unwrap();
-      stylesheet.minify(MinifyOptions::default()).unwrap();
-      let res = stylesheet
-        .to_css(PrinterOptions {
-          analyze_dependencies: Some(Default::default()),
+  fn test_parse_selector() {
+    use crate::selector::{ParseSelector, Selector, SelectorList, SelectorParseOptions};
+
+    let selectors = SelectorList::parse_string(".foo > .bar,   #baz:hover").unwrap();
+    assert_eq!(selectors.0.len(), 2);
+    assert_eq!(
+      selectors.to_css_string(PrinterOptions::default()).unwrap(),
+      ".foo > .bar, #baz:hover"
+    );
+    assert_eq!(
+      selectors
+        .to_css_string(PrinterOptions {
           minify: true,
           ..PrinterOptions::default()
         })
-        .unwrap();
-      assert_eq!(res.code, expected);
-      let dependencies = res.dependencies.unwrap();
-      assert_eq!(dependencies.len(), deps.len());
-      for (i, (url, placeholder)) in deps.into_iter().enumerate() {
-        match &dependencies[i] {
-          Dependency::Url(dep) => {
-            assert_eq!(dep.url, url);
-            assert_eq!(dep.placeholder, placeholder);
-          }
-          Dependency::Import(dep) => {
-            assert_eq!(dep.url, url);
-            assert_eq!(dep.placeholder, placeholder);
-          }
-        }
-      }
-    }
+        .unwrap(),
+      ".foo>.bar,#baz:hover"
+    );
 
-    fn dep_error_test(source: &str, error: PrinterErrorKind) {
-      let stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
-      let res = stylesheet.to_css(PrinterOptions {
-        analyze_dependencies: Some(Default::default()),
-        ..PrinterOptions::default()
-      });
-      match res {
-        Err(e) => assert_eq!(e.kind, error),
-        _ => unreachable!(),
-      }
+    assert_eq!(selectors.0[0].specificity(), 0x800);
+    assert_eq!(selectors.0[1].specificity(), 0x100400);
+    assert_eq!(
+      Selector::parse_string(".a.b").unwrap(),
+      Selector::parse_string(".a.b").unwrap()
+    );
+
+    assert!(SelectorList::parse_string(".foo {").is_err());
+    assert!(SelectorList::parse_string(".foo,").is_err());
+    assert!(Selector::parse_string(".foo, .bar").is_err());
+
+    assert!(SelectorList::parse_string("& .foo").is_err());
+    let selectors = SelectorList::parse_string_with(
+      "& .foo",
+      SelectorParseOptions {
+        allow_nesting: true,
+        ..SelectorParseOptions::default()
+      },
+    )
+    .unwrap();
+    assert_eq!(selectors.to_css_string(PrinterOptions::default()).unwrap(), "& .foo");
+  }
+
+  #[test]
+  fn test_expand_is_selectors() {
+    fn test(source: &str, expected: &str, include: Features, limit: usize, warnings: usize) {
+      let warning_list = std::sync::Arc::new(std::sync::RwLock::new(Vec::new()));
+      minify_test_with_parser_and_minify_options(
+        source,
+        expected,
+        ParserOptions {
+          warnings: Some(warning_list.clone()),
+          ..ParserOptions::default()
+        },
+        MinifyOptions {
+          targets: Targets {
+            include,
+            ..Targets::default()
+          },
+          expand_is_selectors: Some(limit),
+          ..MinifyOptions::default()
+        },
+      );
+      assert_eq!(warning_list.read().unwrap().len(), warnings);
     }
 
-    dep_test(
-      ".foo { background: image-set('./img12x.png', './img21x.png' 2x)}",
-      ".foo{background:image-set(\"hXFI8W\" 1x,\"5TkpBa\" 2x)}",
-      vec![("./img12x.png", "hXFI8W"), ("./img21x.png", "5TkpBa")],
+    test(
+      ":is(.a, .b) .c { color: red }",
+      ".a .c,.b .c{color:red}",
+      Features::IsSelector,
+      10,
+      0,
     );
-
-    dep_test(
-      ".foo { background: image-set(url(./img12x.png), url('./img21x.png') 2x)}",
-      ".foo{background:image-set(\"hXFI8W\" 1x,\"5TkpBa\" 2x)}",
-      vec![("./img12x.png", "hXFI8W"), ("./img21x.png", "5TkpBa")],
+    test(
+      "div:is(.a, .b):hover { color: red }",
+      "div.a:hover,div.b:hover{color:red}",
+      Features::IsSelector,
+      10,
+      0,
     );
-
-    dep_test(
-      ".foo { --test: url(/foo.png) }",
-      ".foo{--test:url(\"lDnnrG\")}",
-      vec![("/foo.png", "lDnnrG")],
+    test(
+      ".foo:is(div, span) { color: red }",
+      "div.foo,span.foo{color:red}",
+      Features::IsSelector,
+      10,
+      0,
+    );
+    test(
+      ":is(.a, .b) > :where(*) { color: red }",
+      ".a>*,.b>*{color:red}",
+      Features::IsSelector,
+      10,
+      0,
+    );
+    test(
+      ":is(.a, .b) :is(.c, .d) { color: red }",
+      ".a .c,.a .d,.b .c,.b .d{color:red}",
+      Features::IsSelector,
+      4,
+      0,
+    );
+    test(
+      ":is(.a, .b) :is(.c, .d) { color: red }",
+      ":is(.a,.b) :is(.c,.d){color:red}",
+      Features::IsSelector,
+      3,
+      1,
+    );
+    test(
+      ":is(.a, #b) .c { color: red }",
+      ":is(.a,#b) .c{color:red}",
+      Features::IsSelector,
+      10,
+      1,
+    );
+    test(
+      ":where(.a, .b) .c { color: red }",
+      ":where(.a,.b) .c{color:red}",
+      Features::IsSelector,
+      10,
+      1,
+    );
+    test(
+      ":is(.a .b, .c) .d, .e { color: red }",
+      ":is(.a .b,.c) .d,.e{color:red}",
+      Features::IsSelector,
+      10,
+      1,
+    );
+    test(
+      ":is(.a, .b) .c { color: red }",
+      ":is(.a,.b) .c{color:red}",
+      Features::empty(),
+      10,
+      0,
     );
+  }
 
-    dep_test(
-      ".foo { --test: url(\"/foo.png\") }",
-      ".foo{--test:url(\"lDnnrG\")}",
-      vec![("/foo.png", "lDnnrG")],
+  #[test]
+  fn test_selector_builder() {
+    use crate::selector::{
+      AttrSelectorOperator, Combinator, ParseSelector, PseudoClass, PseudoElement, Selector, SelectorBuilder,
+    };
+
+    let selector = SelectorBuilder::new()
+      .local_name("div")
+      .class("foo")
+      .child()
+      .id("bar")
+      .pseudo_class(PseudoClass::Hover)
+      .build();
+    assert_eq!(
+      selector.to_css_string(PrinterOptions::default()).unwrap(),
+      "div.foo > #bar:hover"
+    );
+    assert_eq!(selector, Selector::parse_string("div.foo > #bar:hover").unwrap());
+    assert_eq!(selector.specificity(), 0x100801);
+
+    let selector = SelectorBuilder::new()
+      .attribute("data-Foo", AttrSelectorOperator::Prefix, "bar")
+      .later_sibling()
+      .attribute_exists("hidden")
+      .next_sibling()
+      .local_name("p")
+      .pseudo_element(PseudoElement::Before)
+      .build();
+    assert_eq!(
+      selector.to_css_string(PrinterOptions::default()).unwrap(),
+      "[data-Foo^=\"bar\"] ~ [hidden] + p:before"
+    );
+    assert_eq!(
+      selector,
+      Selector::parse_string("[data-Foo^=bar] ~ [hidden] + p::before").unwrap()
     );
+    assert!(selector.has_pseudo_element());
 
-    dep_test(
-      ".foo { --test: url(\"http://example.com/foo.png\") }",
-      ".foo{--test:url(\"3X1zSW\")}",
-      vec![("http://example.com/foo.png", "3X1zSW")],
+    let selector = Selector::parse_string("div.foo .bar:hover").unwrap();
+    let selector = SelectorBuilder::from_selector(&selector)
+      .combinator(Combinator::Child)
+      .class("baz")
+      .build();
+    assert_eq!(
+      selector.to_css_string(PrinterOptions::default()).unwrap(),
+      "div.foo .bar:hover > .baz"
     );
+  }
+
+  #[test]
+  fn test_supports_evaluate() {
+    use crate::rules::supports::{SupportsCondition, SupportsResult};
+
+    fn evaluate(condition: &str, browsers: Option<Browsers>) -> SupportsResult {
+      let condition = SupportsCondition::parse_string(condition).unwrap();
+      condition.evaluate(&browsers.into())
+    }
+
+    let modern = Some(Browsers {
+      chrome: Some(120 << 16),
+      safari: Some(17 << 16),
+      ..Browsers::default()
+    });
+    let old = Some(Browsers {
+      chrome: Some(80 << 16),
+      safari: Some(13 << 16),
+      ..Browsers::default()
+    });
+    let mixed = Some(Browsers {
+      chrome: Some(120 << 16),
+      safari: Some(13 << 16),
+      ..Browsers::default()
+    });
 
-    dep_test(
-      ".foo { --test: url(\"data:image/svg+xml;utf8,<svg></svg>\") }",
-      ".foo{--test:url(\"-vl-rG\")}",
-      vec![("data:image/svg+xml;utf8,<svg></svg>", "-vl-rG")],
+    assert_eq!(evaluate("(color: lab(40% 56.6 39))", modern), SupportsResult::Supported);
+    assert_eq!(evaluate("(color: lab(40% 56.6 39))", old), SupportsResult::Unsupported);
+    assert_eq!(evaluate("(color: lab(40% 56.6 39))", mixed), SupportsResult::Unknown);
+    assert_eq!(
+      evaluate("not (color: lab(40% 56.6 39))", old),
+      SupportsResult::Supported
     );
+    assert_eq!(evaluate("(color: lab(40% 56.6 39))", None), SupportsResult::Unknown);
 
-    dep_test(
-      ".foo { background: url(\"foo.png\") var(--test) }",
-      ".foo{background:url(\"Vwkwkq\") var(--test)}",
-      vec![("foo.png", "Vwkwkq")],
+    assert_eq!(
+      evaluate("(color: lab(40% 56.6 39)) or (color: red)", old),
+      SupportsResult::Supported
     );
-
-    dep_error_test(
-      ".foo { --test: url(\"foo.png\") }",
-      PrinterErrorKind::AmbiguousUrlInCustomProperty { url: "foo.png".into() },
+    assert_eq!(
+      evaluate("(color: lab(40% 56.6 39)) and (color: red)", old),
+      SupportsResult::Unsupported
     );
-
-    dep_error_test(
-      ".foo { --test: url(foo.png) }",
-      PrinterErrorKind::AmbiguousUrlInCustomProperty { url: "foo.png".into() },
+    assert_eq!(
+      evaluate("(color: lab(40% 56.6 39)) and (display: grid)", modern),
+      SupportsResult::Unknown
     );
-
-    dep_error_test(
-      ".foo { --test: url(./foo.png) }",
-      PrinterErrorKind::AmbiguousUrlInCustomProperty {
-        url: "./foo.png".into(),
-      },
+    assert_eq!(evaluate("(translate: 10px)", modern), SupportsResult::Supported);
+    assert_eq!(evaluate("(translate: 10px)", old), SupportsResult::Unsupported);
+    assert_eq!(evaluate("(place-items: center)", old), SupportsResult::Supported);
+    assert_eq!(evaluate("(margin-inline: 10px)", modern), SupportsResult::Supported);
+    assert_eq!(
+      evaluate(
+        "(margin-inline-start: 10px)",
+        Some(Browsers {
+          safari: Some(11 << 16),
+          ..Browsers::default()
+        })
+      ),
+      SupportsResult::Unsupported
     );
+    assert_eq!(evaluate("(color: var(--foo))", modern), SupportsResult::Unknown);
+    assert_eq!(evaluate("(foo: bar)", modern), SupportsResult::Unknown);
+    assert_eq!(evaluate("foo(bar)", modern), SupportsResult::Unknown);
 
-    dep_test(
-      ".foo { behavior: url(#foo) }",
-      ".foo{behavior:url(\"Zn9-2q\")}",
-      vec![("#foo", "Zn9-2q")],
+    assert_eq!(evaluate("selector(:is(.a, .b))", modern), SupportsResult::Supported);
+    assert_eq!(evaluate("selector(:has(.a))", old), SupportsResult::Unsupported);
+    assert_eq!(evaluate("selector(:has(.a))", mixed), SupportsResult::Unknown);
+    assert_eq!(evaluate("selector(.a:::b)", modern), SupportsResult::Unsupported);
+    assert_eq!(
+      evaluate(
+        "selector(:is(.a))",
+        Some(Browsers {
+          chrome: Some(80 << 16),
+          ..Browsers::default()
+        })
+      ),
+      SupportsResult::Unsupported
     );
 
-    dep_test(
-      ".foo { --foo: url(#foo) }",
-      ".foo{--foo:url(\"Zn9-2q\")}",
-      vec![("#foo", "Zn9-2q")],
+    assert_eq!(evaluate("font-format(woff2)", modern), SupportsResult::Supported);
+    assert_eq!(evaluate("font-format(truetype)", modern), SupportsResult::Supported);
+    assert_eq!(
+      evaluate("font-format(embedded-opentype)", modern),
+      SupportsResult::Unsupported
     );
+    assert_eq!(evaluate("font-format(collection)", modern), SupportsResult::Unknown);
+    assert_eq!(evaluate("font-format(woff2)", old), SupportsResult::Unsupported);
+    assert_eq!(evaluate("font-format(woff2)", mixed), SupportsResult::Unknown);
+    assert_eq!(evaluate("font-format(woff2)", None), SupportsResult::Unknown);
+    assert_eq!(evaluate("not font-format(woff2)", old), SupportsResult::Supported);
 
-    dep_test(
-      "@import \"test.css\"; .foo { color: red }",
-      "@import \"hHsogW\";.foo{color:red}",
-      vec![("test.css", "hHsogW")],
+    assert_eq!(evaluate("font-tech(color-colrv1)", modern), SupportsResult::Unknown);
+    assert_eq!(evaluate("font-tech(color-colrv1)", old), SupportsResult::Unsupported);
+    assert_eq!(
+      evaluate("font-tech(variations) or (color: lab(40% 56.6 39))", modern),
+      SupportsResult::Supported
     );
   }
 
   #[test]
-  fn test_api() {
-    let stylesheet = StyleSheet::parse(".foo:hover { color: red }", ParserOptions::default()).unwrap();
-    match &stylesheet.rules.0[0] {
-      CssRule::Style(s) => {
-        assert_eq!(&s.selectors.to_string(), ".foo:hover");
-      }
-      _ => unreachable!(),
-    }
+  fn test_duplicate_declarations() {
+    use crate::analysis::{find_duplicate_declarations, DuplicateDeclarationOptions};
 
-    let color = CssColor::parse_string("#f0f").unwrap();
-    assert_eq!(color.to_css_string(PrinterOptions::default()).unwrap(), "#f0f");
+    let source = r#"
+      .a {
+        display: flex;
+        align-items: center;
+        justify-content: center;
+        color: red;
+      }
+      .b {
+        justify-content: center;
+        align-items: center;
+        display: flex;
+      }
+      @media (width > 100px) {
+        .c {
+          display: flex;
+          align-items: center;
+          justify-content: center;
+          color: red !important;
+        }
+      }
+      .d {
+        color: red;
+        background: green;
+      }
+    "#;
 
-    let rule = CssRule::parse_string(".foo { color: red }", ParserOptions::default()).unwrap();
+    let stylesheet = StyleSheet::parse(source, ParserOptions::default()).unwrap();
+    let duplicates = find_duplicate_declarations(&stylesheet, &DuplicateDeclarationOptions::default());
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!(duplicates[0].count, 3);
     assert_eq!(
-      rule.to_css_string(PrinterOptions::default()).unwrap(),
-      indoc! {r#"
-    .foo {
-      color: red;
-    }"#}
+      duplicates[0]
+        .declarations
+        .to_css_string(PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        })
+        .unwrap(),
+      "display:flex;align-items:center;justify-content:center"
+    );
+    assert_eq!(
+      duplicates[0].locations.iter().map(|loc| loc.line).collect::<Vec<_>>(),
+      vec![1, 7, 13]
     );
 
-    let property = Property::parse_string("color".into(), "#f0f", ParserOptions::default()).unwrap();
+    let duplicates = find_duplicate_declarations(
+      &stylesheet,
+      &DuplicateDeclarationOptions {
+        min_declarations: 1,
+        min_occurrences: 2,
+      },
+    );
+    assert_eq!(duplicates.len(), 2);
+    assert_eq!(duplicates[0].count, 3);
+    assert_eq!(duplicates[1].count, 2);
     assert_eq!(
-      property.to_css_string(false, PrinterOptions::default()).unwrap(),
-      "color: #f0f"
+      duplicates[1].declarations.to_css_string(PrinterOptions::default()).unwrap(),
+      "color: red"
     );
     assert_eq!(
-      property.to_css_string(true, PrinterOptions::default()).unwrap(),
-      "color: #f0f !important"
+      duplicates[1].locations.iter().map(|loc| loc.line).collect::<Vec<_>>(),
+      vec![1, 20]
     );
+  }
 
-    let code = indoc! { r#"
-      .foo {
-        color: green;
-      }
-
-      .bar {
-        color: red;
-        background: pink;
-      }
+  #[test]
+  fn test_lint_deprecated() {
+    use crate::lint::{lint, Lint};
 
-      @media print {
-        .baz {
-          color: green;
-        }
-      }
-    "#};
-    let stylesheet = StyleSheet::parse(code, ParserOptions::default()).unwrap();
-    if let CssRule::Style(style) = &stylesheet.rules.0[1] {
-      let (key, val) = style.property_location(code, 0).unwrap();
-      assert_eq!(
-        key,
-        SourceLocation { line: 5, column: 3 }..SourceLocation { line: 5, column: 8 }
-      );
+    fn lint_test(source: &str, expected: Vec<(&str, &str)>) {
+      let stylesheet = StyleSheet::parse(source, ParserOptions::default()).unwrap();
+      let diagnostics = lint(&stylesheet);
       assert_eq!(
-        val,
-        SourceLocation { line: 5, column: 10 }..SourceLocation { line: 5, column: 13 }
+        diagnostics
+          .iter()
+          .map(|d| match &d.lint {
+            Lint::Deprecated { feature, replacement } => (feature.as_str(), replacement.as_str()),
+            _ => unreachable!(),
+          })
+          .collect::<Vec<_>>(),
+        expected
       );
     }
 
-    if let CssRule::Style(style) = &stylesheet.rules.0[1] {
-      let (key, val) = style.property_location(code, 1).unwrap();
-      assert_eq!(
-        key,
-        SourceLocation { line: 6, column: 3 }..SourceLocation { line: 6, column: 13 }
-      );
+    lint_test(
+      ".foo { clip: rect(0 0 0 0); word-break: break-word }",
+      vec![
+        ("clip", "clip-path"),
+        ("word-break: break-word", "overflow-wrap: anywhere"),
+      ],
+    );
+    lint_test(".foo { word-break: break-all; clip-path: inset(0) }", vec![]);
+    lint_test(
+      ".foo { display: -webkit-box; -webkit-box-orient: vertical; -webkit-box-pack: center }",
+      vec![
+        ("-webkit-box-orient", "flex-direction"),
+        ("-webkit-box-pack", "justify-content"),
+      ],
+    );
+    lint_test(
+      ".foo { -webkit-box-pack: center; -webkit-box-align: center; display: flex; justify-content: center; align-items: center }",
+      vec![],
+    );
+    lint_test(
+      ".foo { display: -webkit-box; -webkit-box-orient: vertical; -webkit-line-clamp: 3; overflow: hidden }",
+      vec![],
+    );
+    lint_test(
+      ".foo { width: expression(document.body.clientWidth > 800 ? \"800px\" : \"auto\") }",
+      vec![("expression()", "calc()")],
+    );
+    lint_test(
+      "@media print { .foo { .bar { -moz-box-flex: 1 } } }",
+      vec![("-moz-box-flex", "flex")],
+    );
+
+    let stylesheet = StyleSheet::parse(".foo { clip: auto }", ParserOptions::default()).unwrap();
+    let diagnostics = lint(&stylesheet);
+    assert_eq!(
+      diagnostics[0].to_string(),
+      "`clip` is deprecated, use `clip-path` instead"
+    );
+    assert_eq!(diagnostics[0].loc.line, 0);
+  }
+
+  #[test]
+  fn test_lint_fix() {
+    use crate::lint::{fix, lint};
+
+    fn fix_test(source: &str, expected: &str, fixed: Vec<&str>, remaining: Vec<&str>) {
+      let mut stylesheet = StyleSheet::parse(source, ParserOptions::default()).unwrap();
+      let diagnostics = lint(&stylesheet);
+      let result = fix(&mut stylesheet);
+      assert_eq!(diagnostics.len(), result.fixed.len() + result.remaining.len());
+      assert!(result.fixed.iter().all(|d| d.lint.is_fixable()));
+      assert!(result.remaining.iter().all(|d| !d.lint.is_fixable()));
+      assert_eq!(result.fixed.iter().map(|d| d.to_string()).collect::<Vec<_>>(), fixed);
       assert_eq!(
-        val,
-        SourceLocation { line: 6, column: 15 }..SourceLocation { line: 6, column: 19 }
+        result.remaining.iter().map(|d| d.to_string()).collect::<Vec<_>>(),
+        remaining
       );
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(res.code, expected);
     }
-    if let CssRule::Media(media) = &stylesheet.rules.0[2] {
-      if let CssRule::Style(style) = &media.rules.0[0] {
-        let (key, val) = style.property_location(code, 0).unwrap();
-        assert_eq!(
-          key,
-          SourceLocation { line: 11, column: 5 }..SourceLocation { line: 11, column: 10 }
-        );
-        assert_eq!(
-          val,
-          SourceLocation { line: 11, column: 12 }..SourceLocation { line: 11, column: 17 }
-        );
+
+    fix_test(
+      ".foo { color: red; background: green; color: red }",
+      ".foo{background:green;color:red}",
+      vec!["duplicate declaration of `color`"],
+      vec![],
+    );
+    fix_test(
+      ".foo { color: red; color: lab(50% 20 30) }",
+      ".foo{color:red;color:lab(50% 20 30)}",
+      vec![],
+      vec![],
+    );
+    fix_test(
+      ".foo { margin-top: 10px; border-top-color: red; margin: 0 auto; border: 1px solid }",
+      ".foo{margin:0 auto;border:1px solid}",
+      vec![
+        "`margin-top` is overridden by the `margin` shorthand",
+        "`border-top-color` is overridden by the `border` shorthand",
+      ],
+      vec![],
+    );
+    fix_test(
+      ".foo { margin: 0 auto; margin-top: 10px }",
+      ".foo{margin:0 auto;margin-top:10px}",
+      vec![],
+      vec![],
+    );
+    fix_test(
+      ".foo { margin-top: 10px !important; margin: 0 }",
+      ".foo{margin:0;margin-top:10px!important}",
+      vec![],
+      vec![],
+    );
+    fix_test(
+      ".foo { margin: 0px var(--x); line-height: 0px var(--y); width: calc(var(--x) + 0px) }",
+      ".foo{margin:0 var(--x);line-height:0px var(--y);width:calc(var(--x) + 0px)}",
+      vec!["unnecessary unit on zero length in `margin`"],
+      vec![],
+    );
+    fix_test(
+      ".foo { .bar { -webkit-box-pack: center; -webkit-box-pack: center } }",
+      ".foo{& .bar{-webkit-box-pack:center}}",
+      vec!["duplicate declaration of `-webkit-box-pack`"],
+      vec![
+        "`-webkit-box-pack` is deprecated, use `justify-content` instead",
+        "`-webkit-box-pack` is deprecated, use `justify-content` instead",
+      ],
+    );
+  }
+
+  #[test]
+  fn test_font_usage() {
+    use crate::analysis::font_usage;
+
+    let stylesheet = StyleSheet::parse(
+      r#"
+      @font-face {
+        font-family: "Inter";
+        font-weight: 100 900;
+        src: url(inter.woff2) format("woff2");
+        unicode-range: U+0000-00FF;
       }
-    }
+      @font-face {
+        font-family: Unused;
+        src: local(Unused);
+      }
+      .a {
+        font-family: inter, sans-serif;
+        font-weight: bold;
+        text-transform: uppercase;
+      }
+      @media print {
+        .b {
+          font: italic 12px Inter, "Fallback";
+        }
+        .b::before {
+          font-family: Inter;
+          content: "→ " attr(title);
+        }
+      }
+    "#,
+      ParserOptions::default(),
+    )
+    .unwrap();
 
-    let mut property = Property::Transform(Default::default(), VendorPrefix::WebKit);
-    property.set_prefix(VendorPrefix::None);
-    assert_eq!(property, Property::Transform(Default::default(), VendorPrefix::None));
-    property.set_prefix(VendorPrefix::Moz);
-    assert_eq!(property, Property::Transform(Default::default(), VendorPrefix::Moz));
-    property.set_prefix(VendorPrefix::WebKit | VendorPrefix::Moz);
+    let report = font_usage(&stylesheet);
+    assert_eq!(report.font_faces.len(), 2);
+    assert_eq!(report.font_faces[0].family.as_deref(), Some("Inter"));
+    assert_eq!(report.font_faces[0].weight.as_deref(), Some("100 900"));
     assert_eq!(
-      property,
-      Property::Transform(Default::default(), VendorPrefix::WebKit | VendorPrefix::Moz)
+      report.font_faces[0].sources,
+      vec!["url(\"inter.woff2\") format(\"woff2\")"]
     );
+    assert_eq!(report.font_faces[0].unicode_range.len(), 1);
+    assert!(report.font_faces[0].used);
+    assert!(!report.font_faces[1].used);
 
-    let mut property = Property::TextDecorationLine(Default::default(), VendorPrefix::None);
-    property.set_prefix(VendorPrefix::Ms);
     assert_eq!(
-      property,
-      Property::TextDecorationLine(Default::default(), VendorPrefix::None)
+      report
+        .families
+        .iter()
+        .map(|f| (f.family.as_str(), f.declared))
+        .collect::<Vec<_>>(),
+      vec![("inter", true), ("Fallback", false)]
+    );
+    let inter = &report.families[0];
+    assert_eq!(inter.weights, vec!["bold", "normal"]);
+    assert_eq!(inter.styles, vec!["italic"]);
+    assert_eq!(inter.features, vec!["text-transform: uppercase"]);
+    assert_eq!(inter.content, vec!["→ "]);
+    assert_eq!(
+      inter.locations.iter().map(|l| l.line).collect::<Vec<_>>(),
+      vec![11, 17, 20]
+    );
+  }
+
+  #[test]
+  fn test_font_face_src_targets() {
+    prefix_test(
+      r#"
+      @font-face {
+        font-family: Test;
+        src: url(test.svg#font) format("svg"), url(test.ttf) format("truetype"), url(test.woff) format("woff"), local(Test), url(test.woff2) format("woff2");
+      }
+    "#,
+      indoc! {r#"
+      @font-face {
+        font-family: Test;
+        src: url("test.woff"), url("test.ttf") format("truetype"), local(Test), url("test.woff2");
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        firefox: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      @font-face {
+        font-family: Test;
+        src: url(test.woff) format("woff"), url(test.woff2) format("woff2") tech(variations, variations), url(test.svg#font) format("svg");
+      }
+    "#,
+      indoc! {r#"
+      @font-face {
+        font-family: Test;
+        src: url("test.woff2") format("woff2") tech(variations), url("test.woff"), url("test.svg#font") format("svg");
+      }
+    "#},
+      Browsers {
+        chrome: Some(40 << 16),
+        safari: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      @font-face {
+        font-family: Test;
+        src: url(test.eot?#iefix) format("embedded-opentype"), url(test.woff2) format("woff2"), url(test.woff) format("woff");
+      }
+    "#,
+      indoc! {r#"
+      @font-face {
+        font-family: Test;
+        src: url("test.eot?#iefix") format("embedded-opentype"), url("test.woff2") format("woff2"), url("test.woff") format("woff");
+      }
+    "#},
+      Browsers {
+        ie: Some(8 << 16),
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      @font-face {
+        font-family: Test;
+        src: url(test.eot) format("embedded-opentype");
+      }
+    "#,
+      indoc! {r#"
+      @font-face {
+        font-family: Test;
+        src: url("test.eot") format("embedded-opentype");
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    property.set_prefix(VendorPrefix::WebKit | VendorPrefix::Moz | VendorPrefix::Ms);
-    assert_eq!(
-      property,
-      Property::TextDecorationLine(Default::default(), VendorPrefix::WebKit | VendorPrefix::Moz)
+    minify_test(
+      "@font-face {src: url(test.woff2) format(woff2), url(test.eot) format(embedded-opentype), url(test.woff2) format(woff2)}",
+      "@font-face{src:url(test.woff2)format(\"woff2\"),url(test.eot)format(\"embedded-opentype\")}",
     );
-
-    let mut property = Property::AccentColor(Default::default());
-    property.set_prefix(VendorPrefix::WebKit);
-    assert_eq!(property, Property::AccentColor(Default::default()));
   }
 
-  #[cfg(feature = "substitute_variables")]
   #[test]
-  fn test_substitute_vars() {
-    use crate::properties::custom::TokenList;
-    use crate::traits::ParseWithOptions;
-
-    fn test(property: Property, vars: HashMap<&str, &str>, expected: &str) {
-      if let Property::Unparsed(unparsed) = property {
-        let vars = vars
-          .into_iter()
-          .map(|(k, v)| {
-            (
-              k,
-              TokenList::parse_string_with_options(v, ParserOptions::default()).unwrap(),
-            )
-          })
-          .collect();
-        let substituted = unparsed.substitute_variables(&vars).unwrap();
-        assert_eq!(
-          substituted.to_css_string(false, PrinterOptions::default()).unwrap(),
-          expected
-        );
-      } else {
-        panic!("Not an unparsed property");
-      }
-    }
-
-    let property = Property::parse_string("color".into(), "var(--test)", ParserOptions::default()).unwrap();
-    test(property, HashMap::from([("--test", "yellow")]), "color: #ff0");
-
-    let property =
-      Property::parse_string("color".into(), "var(--test, var(--foo))", ParserOptions::default()).unwrap();
-    test(property, HashMap::from([("--foo", "yellow")]), "color: #ff0");
-    let property = Property::parse_string(
-      "color".into(),
-      "var(--test, var(--foo, yellow))",
-      ParserOptions::default(),
-    )
-    .unwrap();
-    test(property, HashMap::new(), "color: #ff0");
-
-    let property =
-      Property::parse_string("width".into(), "calc(var(--a) + var(--b))", ParserOptions::default()).unwrap();
-    test(property, HashMap::from([("--a", "2px"), ("--b", "4px")]), "width: 6px");
-
-    let property = Property::parse_string("color".into(), "var(--a)", ParserOptions::default()).unwrap();
-    test(
-      property,
-      HashMap::from([("--a", "var(--b)"), ("--b", "yellow")]),
-      "color: #ff0",
+  fn test_scroll_start() {
+    minify_test(".foo { scroll-start: 10px }", ".foo{scroll-start:10px}");
+    minify_test(".foo { scroll-start: center start }", ".foo{scroll-start:center}");
+    minify_test(".foo { scroll-start: auto 20% }", ".foo{scroll-start:auto 20%}");
+    minify_test(
+      ".foo { scroll-start: calc(10px + 5%) end }",
+      ".foo{scroll-start:calc(10px + 5%) end}",
     );
-
-    let property = Property::parse_string("color".into(), "var(--a)", ParserOptions::default()).unwrap();
-    test(
-      property,
-      HashMap::from([("--a", "var(--b)"), ("--b", "var(--c)"), ("--c", "var(--a)")]),
-      "color: var(--a)",
+    minify_test(".foo { scroll-start-block: end }", ".foo{scroll-start-block:end}");
+    minify_test(".foo { scroll-start-inline: 0px }", ".foo{scroll-start-inline:0}");
+    minify_test(".foo { scroll-start-x: right }", ".foo{scroll-start-x:right}");
+    minify_test(".foo { scroll-start-y: 2em }", ".foo{scroll-start-y:2em}");
+    minify_test(".foo { scroll-start: -10px }", ".foo{scroll-start:-10px}");
+    minify_test(".foo { scroll-start-target: auto }", ".foo{scroll-start-target:auto}");
+    minify_test(
+      ".foo { scroll-start-target: auto none }",
+      ".foo{scroll-start-target:auto none}",
+    );
+    minify_test(
+      ".foo { scroll-start-target: none none }",
+      ".foo{scroll-start-target:none}",
+    );
+    minify_test(
+      ".foo { scroll-start-target-x: auto }",
+      ".foo{scroll-start-target-x:auto}",
+    );
+    minify_test(
+      ".foo { scroll-initial-target: nearest }",
+      ".foo{scroll-initial-target:nearest}",
+    );
+    minify_test(
+      ".foo { scroll-initial-target: NONE }",
+      ".foo{scroll-initial-target:none}",
     );
+
+    let property = Property::parse_string("scroll-start".into(), "start 10px", ParserOptions::default()).unwrap();
+    assert!(matches!(property, Property::ScrollStart(..)));
+    for (name, value) in [
+      ("scroll-start", "-10px"),
+      ("scroll-start-block", "foo"),
+      ("scroll-start", "start end center"),
+      ("scroll-start-target", "nearest"),
+      ("scroll-initial-target", "auto"),
+    ] {
+      let property = Property::parse_string(name.into(), value, ParserOptions::default()).unwrap();
+      assert!(matches!(property, Property::Unparsed(..)), "{}: {}", name, value);
+    }
   }
 
   #[test]
-  fn test_layer() {
-    minify_test("@layer foo;", "@layer foo;");
-    minify_test("@layer foo, bar;", "@layer foo,bar;");
-    minify_test("@layer foo.bar;", "@layer foo.bar;");
-    minify_test("@layer foo.bar, baz;", "@layer foo.bar,baz;");
-
+  fn test_overscroll_behavior() {
     minify_test(
-      r#"
-      @layer foo {
-        .bar {
-          color: red;
-        }
-      }
-    "#,
-      "@layer foo{.bar{color:red}}",
+      ".foo { overscroll-behavior: contain }",
+      ".foo{overscroll-behavior:contain}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior: contain contain }",
+      ".foo{overscroll-behavior:contain}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior: none auto }",
+      ".foo{overscroll-behavior:none auto}",
     );
     minify_test(
+      ".foo { overscroll-behavior-x: none }",
+      ".foo{overscroll-behavior-x:none}",
+    );
+
+    test(
       r#"
-      @layer foo.bar {
-        .bar {
-          color: red;
-        }
+      .foo {
+        overscroll-behavior-x: contain;
+        overscroll-behavior-y: none;
       }
     "#,
-      "@layer foo.bar{.bar{color:red}}",
+      indoc! {r#"
+      .foo {
+        overscroll-behavior: contain none;
+      }
+    "#},
     );
-    minify_test(r#"
-      @layer base {
-        p { max-width: 70ch; }
+    test(
+      r#"
+      .foo {
+        overscroll-behavior: none;
+        overscroll-behavior-y: auto;
       }
-
-      @layer framework {
-        @layer base {
-          p { margin-block: 0.75em; }
-        }
-
-        @layer theme {
-          p { color: #222; }
-        }
+    "#,
+      indoc! {r#"
+      .foo {
+        overscroll-behavior: none auto;
       }
-    "#, "@layer base{p{max-width:70ch}}@layer framework{@layer base{p{margin-block:.75em}}@layer theme{p{color:#222}}}");
-    minify_test(
+    "#},
+    );
+    test(
       r#"
-      @layer {
-        .bar {
-          color: red;
-        }
+      .foo {
+        overscroll-behavior: none;
+        overscroll-behavior-y: var(--y);
       }
     "#,
-      "@layer{.bar{color:red}}",
+      indoc! {r#"
+      .foo {
+        overscroll-behavior: none;
+        overscroll-behavior-y: var(--y);
+      }
+    "#},
     );
-    minify_test(
+    prefix_test(
       r#"
-      @layer foo\20 bar, baz;
+      .foo {
+        overscroll-behavior: contain none;
+      }
     "#,
-      "@layer foo\\ bar,baz;",
+      indoc! {r#"
+      .foo {
+        overscroll-behavior-x: contain;
+        overscroll-behavior-y: none;
+      }
+    "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
+    prefix_test(
       r#"
-      @layer one.two\20 three\#four\.five {
-        .bar {
-          color: red;
-        }
+      .foo {
+        overscroll-behavior-x: contain;
+        overscroll-behavior-y: contain;
       }
     "#,
-      "@layer one.two\\ three\\#four\\.five{.bar{color:red}}",
+      indoc! {r#"
+      .foo {
+        overscroll-behavior: contain;
+      }
+    "#},
+      Browsers {
+        chrome: Some(80 << 16),
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
     );
+  }
 
-    error_test("@layer;", ParserError::UnexpectedToken(Token::Semicolon));
-    error_test("@layer foo, bar {};", ParserError::AtRuleBodyInvalid);
-    minify_test("@import 'test.css' layer;", "@import \"test.css\" layer;");
-    minify_test("@import 'test.css' layer(foo);", "@import \"test.css\" layer(foo);");
+  #[test]
+  fn test_text_autospace() {
+    minify_test(".foo { text-autospace: normal }", ".foo{text-autospace:normal}");
+    minify_test(".foo { text-autospace: auto }", ".foo{text-autospace:auto}");
     minify_test(
-      "@import 'test.css' layer(foo.bar);",
-      "@import \"test.css\" layer(foo.bar);",
+      ".foo { text-autospace: no-autospace }",
+      ".foo{text-autospace:no-autospace}",
     );
     minify_test(
-      "@import 'test.css' layer(foo\\20 bar);",
-      "@import \"test.css\" layer(foo\\ bar);",
+      ".foo { text-autospace: ideograph-alpha }",
+      ".foo{text-autospace:ideograph-alpha}",
     );
-    error_test(
-      "@import 'test.css' layer(foo, bar) {};",
-      ParserError::UnexpectedToken(Token::Comma),
+    minify_test(
+      ".foo { text-autospace: ideograph-alpha ideograph-numeric }",
+      ".foo{text-autospace:normal}",
     );
     minify_test(
-      r#"
-      @layer one {
-        body {
-          background: red;
-        }
-      }
+      ".foo { text-autospace: ideograph-numeric insert ideograph-alpha }",
+      ".foo{text-autospace:normal}",
+    );
+    minify_test(
+      ".foo { text-autospace: punctuation ideograph-alpha insert }",
+      ".foo{text-autospace:ideograph-alpha punctuation}",
+    );
+    minify_test(
+      ".foo { text-autospace: replace ideograph-numeric }",
+      ".foo{text-autospace:ideograph-numeric replace}",
+    );
+    minify_test(
+      ".foo { text-autospace: ideograph-alpha ideograph-numeric replace }",
+      ".foo{text-autospace:ideograph-alpha ideograph-numeric replace}",
+    );
+    minify_test(".foo { text-autospace: insert }", ".foo{text-autospace:insert}");
+    minify_test(".foo { text-autospace: REPLACE }", ".foo{text-autospace:replace}");
 
-      body {
-        background: red;
-      }
+    for value in [
+      "ideograph-alpha ideograph-alpha",
+      "insert replace",
+      "normal ideograph-alpha",
+      "ideograph-alpha auto",
+      "no-autospace insert",
+      "ideograph-alpha foo",
+    ] {
+      let property = Property::parse_string("text-autospace".into(), value, ParserOptions::default()).unwrap();
+      assert!(matches!(property, Property::Unparsed(..)), "{}", value);
+    }
+  }
 
-      @layer two {
-        body {
-          background: green;
-        }
+  #[test]
+  fn test_text_spacing() {
+    minify_test(".foo { text-spacing-trim: normal }", ".foo{text-spacing-trim:normal}");
+    minify_test(
+      ".foo { text-spacing-trim: TRIM-START }",
+      ".foo{text-spacing-trim:trim-start}",
+    );
+    minify_test(
+      ".foo { text-spacing-trim: space-all }",
+      ".foo{text-spacing-trim:space-all}",
+    );
+    minify_test(".foo { text-spacing: normal }", ".foo{text-spacing:normal}");
+    minify_test(".foo { text-spacing: none }", ".foo{text-spacing:none}");
+    minify_test(".foo { text-spacing: auto }", ".foo{text-spacing:auto}");
+    minify_test(
+      ".foo { text-spacing: space-all no-autospace }",
+      ".foo{text-spacing:none}",
+    );
+    minify_test(".foo { text-spacing: trim-start }", ".foo{text-spacing:trim-start}");
+    minify_test(
+      ".foo { text-spacing: trim-start normal }",
+      ".foo{text-spacing:trim-start}",
+    );
+    minify_test(
+      ".foo { text-spacing: ideograph-alpha trim-both }",
+      ".foo{text-spacing:trim-both ideograph-alpha}",
+    );
+    minify_test(
+      ".foo { text-spacing: ideograph-alpha punctuation }",
+      ".foo{text-spacing:ideograph-alpha punctuation}",
+    );
+    minify_test(".foo { text-spacing: auto normal }", ".foo{text-spacing:auto normal}");
+    minify_test(".foo { text-spacing: normal auto }", ".foo{text-spacing:normal auto}");
+    minify_test(".foo { text-spacing: no-autospace }", ".foo{text-spacing:no-autospace}");
+    minify_test(".foo { text-spacing: auto auto }", ".foo{text-spacing:auto}");
+    minify_test(
+      ".foo { text-spacing-trim: trim-all; text-autospace: no-autospace }",
+      ".foo{text-spacing:trim-all no-autospace}",
+    );
+    minify_test(
+      ".foo { text-spacing: none; text-spacing-trim: trim-start }",
+      ".foo{text-spacing:trim-start no-autospace}",
+    );
+    minify_test(
+      ".foo { text-spacing: none; text-autospace: var(--x) }",
+      ".foo{text-spacing:none;text-autospace:var(--x)}",
+    );
+
+    prefix_test(
+      ".foo { text-spacing: trim-start ideograph-alpha }",
+      indoc! {r#"
+      .foo {
+        text-spacing-trim: trim-start;
+        text-autospace: ideograph-alpha;
+      }
+      "#},
+      Browsers {
+        chrome: Some(140 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { text-spacing-trim: normal; text-spacing-trim: trim-both }",
+      indoc! {r#"
+      .foo {
+        text-spacing-trim: normal;
+        text-spacing-trim: trim-both;
+      }
+      "#},
+      Browsers {
+        safari: Some(18 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { text-spacing-trim: normal; text-spacing-trim: trim-both }",
+      indoc! {r#"
+      .foo {
+        text-spacing-trim: trim-both;
       }
+      "#},
+      Browsers {
+        chrome: Some(123 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      @layer one {
-        body {
-          background: yellow;
-        }
-      }
-      "#,
-      "@layer one{body{background:#ff0}}body{background:red}@layer two{body{background:green}}",
+    for value in ["none normal", "auto none", "trim-start trim-both", "foo"] {
+      let property = Property::parse_string("text-spacing".into(), value, ParserOptions::default()).unwrap();
+      assert!(matches!(property, Property::Unparsed(..)), "{}", value);
+    }
+  }
+
+  #[test]
+  fn test_math() {
+    minify_test(".foo { math-depth: auto-add }", ".foo{math-depth:auto-add}");
+    minify_test(".foo { math-depth: 2 }", ".foo{math-depth:2}");
+    minify_test(".foo { math-depth: -1 }", ".foo{math-depth:-1}");
+    minify_test(".foo { math-depth: add(2) }", ".foo{math-depth:add(2)}");
+    minify_test(".foo { math-depth: ADD( -1 ) }", ".foo{math-depth:add(-1)}");
+    minify_test(".foo { math-style: compact }", ".foo{math-style:compact}");
+    minify_test(".foo { math-style: normal }", ".foo{math-style:normal}");
+    minify_test(".foo { math-shift: compact }", ".foo{math-shift:compact}");
+
+    for (name, value) in [
+      ("math-depth", "1.5"),
+      ("math-depth", "add(1.5)"),
+      ("math-depth", "add()"),
+      ("math-depth", "auto"),
+      ("math-style", "inline"),
+      ("math-shift", "auto"),
+    ] {
+      let property = Property::parse_string(name.into(), value, ParserOptions::default()).unwrap();
+      assert!(matches!(property, Property::Unparsed(..)), "{}: {}", name, value);
+    }
+
+    let property = Property::parse_string("math-depth".into(), "add(1)", ParserOptions::default()).unwrap();
+    assert_eq!(
+      property,
+      Property::MathDepth(crate::properties::math::MathDepth::Add(1))
     );
   }
 
   #[test]
-  fn test_property() {
+  fn test_webkit_mobile_properties() {
     minify_test(
-      r#"
-      @property --property-name {
-        syntax: '<color>';
-        inherits: false;
-        initial-value: yellow;
-      }
-    "#,
-      "@property --property-name{syntax:\"<color>\";inherits:false;initial-value:#ff0}",
+      ".foo { -webkit-tap-highlight-color: rgba(0, 0, 0, 0) }",
+      ".foo{-webkit-tap-highlight-color:#0000}",
+    );
+    minify_test(
+      ".foo { -webkit-tap-highlight-color: transparent }",
+      ".foo{-webkit-tap-highlight-color:#0000}",
+    );
+    minify_test(
+      ".foo { -webkit-touch-callout: none }",
+      ".foo{-webkit-touch-callout:none}",
+    );
+    minify_test(
+      ".foo { -webkit-overflow-scrolling: touch }",
+      ".foo{-webkit-overflow-scrolling:touch}",
     );
 
-    test(
+    for (name, value) in [
+      ("-webkit-tap-highlight-color", "10px"),
+      ("-webkit-touch-callout", "auto"),
+      ("-webkit-overflow-scrolling", "momentum"),
+    ] {
+      let property = Property::parse_string(name.into(), value, ParserOptions::default()).unwrap();
+      assert!(matches!(property, Property::Unparsed(..)), "{}: {}", name, value);
+    }
+
+    prefix_test(
       r#"
-      @property --property-name {
-        syntax: '*';
-        inherits: false;
-        initial-value: ;
+      .foo {
+        -webkit-tap-highlight-color: transparent;
+        -webkit-touch-callout: none;
+        -webkit-overflow-scrolling: touch;
+        color: red;
       }
     "#,
       indoc! {r#"
-      @property --property-name {
-        syntax: "*";
-        inherits: false;
-        initial-value: ;
+      .foo {
+        color: red;
       }
     "#},
+      Browsers {
+        firefox: Some(100 << 16),
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
     );
 
-    minify_test(
+    prefix_test(
       r#"
-      @property --property-name {
-        syntax: '*';
-        inherits: false;
-        initial-value: ;
+      .foo {
+        -webkit-tap-highlight-color: transparent;
+        -webkit-touch-callout: none;
+        -webkit-overflow-scrolling: touch;
       }
     "#,
-      "@property --property-name{syntax:\"*\";inherits:false;initial-value:}",
+      indoc! {r#"
+      .foo {
+        -webkit-tap-highlight-color: #0000;
+        -webkit-touch-callout: none;
+        -webkit-overflow-scrolling: touch;
+      }
+    "#},
+      Browsers {
+        firefox: Some(100 << 16),
+        ios_saf: Some(12 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
-      @property --property-name {
-        syntax: '*';
-        inherits: false;
-        initial-value:;
+      .foo {
+        -webkit-tap-highlight-color: var(--highlight);
+        -webkit-touch-callout: none;
       }
     "#,
       indoc! {r#"
-      @property --property-name {
-        syntax: "*";
-        inherits: false;
-        initial-value: ;
+      .foo {
+        -webkit-tap-highlight-color: var(--highlight);
       }
     "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
     );
+  }
 
+  #[test]
+  fn test_svg_geometry() {
+    minify_test(".foo { d: none }", ".foo{d:none}");
+    minify_test(".foo { d: path('M 10 10 L 20 20') }", ".foo{d:path(\"M10 10 20 20\")}");
     minify_test(
-      r#"
-      @property --property-name {
-        syntax: '*';
-        inherits: false;
-        initial-value:;
-      }
-    "#,
-      "@property --property-name{syntax:\"*\";inherits:false;initial-value:}",
+      ".foo { d: path(\"M 0.5,0.5 L -0.5, 1.0 L 2 3 Z\") }",
+      ".foo{d:path(\"M.5.5-.5 1 2 3Z\")}",
     );
     minify_test(
-      r#"
-      @property --property-name {
-        syntax: '*';
-        inherits: false;
-        initial-value: foo bar;
-      }
-    "#,
-      "@property --property-name{syntax:\"*\";inherits:false;initial-value:foo bar}",
+      ".foo { d: path('m 10 10 l 5 5 l 5 5 h 10 h 10 z') }",
+      ".foo{d:path(\"m10 10 5 5 5 5h10 10z\")}",
     );
-
     minify_test(
-      r#"
-      @property --property-name {
-        syntax: '<length>';
-        inherits: true;
-        initial-value: 25px;
-      }
-    "#,
-      "@property --property-name{syntax:\"<length>\";inherits:true;initial-value:25px}",
+      ".foo { d: path('M10 10 m5 5 M 20 20') }",
+      ".foo{d:path(\"M10 10m5 5M20 20\")}",
+    );
+    minify_test(
+      ".foo { d: path('M 0 0 A 25 25 0 0 1 50 50 a 25,25 -30 1,0 10,10') }",
+      ".foo{d:path(\"M0 0A25 25 0 0 1 50 50a25 25-30 1 0 10 10\")}",
+    );
+    minify_test(
+      ".foo { d: path('M0 0a25 25 0 0150 50') }",
+      ".foo{d:path(\"M0 0a25 25 0 0 1 50 50\")}",
+    );
+    minify_test(
+      ".foo { d: path('M 1e2 1.50E-1 C 1 2 3 4 5 6') }",
+      ".foo{d:path(\"M100 .15C1 2 3 4 5 6\")}",
     );
+    minify_test(".foo { d: path('') }", ".foo{d:path(\"\")}");
 
-    error_test(
-      r#"
-      @property --property-name {
-        syntax: '<color>';
-        inherits: false;
-        initial-value: 25px;
-      }
-    "#,
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Dimension {
-        has_sign: false,
-        value: 25.0,
-        int_value: Some(25),
-        unit: "px".into(),
-      }),
+    for value in [
+      "path('L 10 10')",
+      "path('M 10')",
+      "path('M 10 10 X 5')",
+      "path('M 10 10 L')",
+      "path('M 0 0 A 1 1 0 2 0 1 1')",
+      "path(M 10 10)",
+      "url(#foo)",
+    ] {
+      let property = Property::parse_string("d".into(), value, ParserOptions::default()).unwrap();
+      assert!(matches!(property, Property::Unparsed(..)), "{}", value);
+    }
+
+    minify_test(".foo { cx: 10px }", ".foo{cx:10px}");
+    minify_test(".foo { cy: 50% }", ".foo{cy:50%}");
+    minify_test(".foo { r: 0px }", ".foo{r:0}");
+    minify_test(".foo { rx: auto }", ".foo{rx:auto}");
+    minify_test(".foo { ry: calc(10px + 5px) }", ".foo{ry:15px}");
+    minify_test(".foo { x: 10px }", ".foo{x:10px}");
+    minify_test(".foo { y: calc(50% - 0px) }", ".foo{y:50%}");
+    minify_test(".foo { x: auto }", ".foo{x:auto}");
+    minify_test(
+      ".foo { vector-effect: non-scaling-stroke }",
+      ".foo{vector-effect:non-scaling-stroke}",
     );
+    minify_test(".foo { dominant-baseline: central }", ".foo{dominant-baseline:central}");
+    minify_test(".foo { stroke-dasharray: 5px, 10.0px }", ".foo{stroke-dasharray:5 10}");
+  }
 
-    error_test(
-      r#"
-      @property --property-name {
-        syntax: '<length>';
-        inherits: false;
-        initial-value: var(--some-value);
-      }
-    "#,
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Function("var".into())),
+  #[test]
+  fn test_svg_minify() {
+    minify_test(
+      ".foo { stroke-width: 1px; stroke-width: 2px }",
+      ".foo{stroke-width:2px}",
+    );
+    minify_test(
+      ".foo { stroke-linecap: round; stroke-linejoin: bevel; stroke-linecap: butt }",
+      ".foo{stroke-linecap:butt;stroke-linejoin:bevel}",
+    );
+    minify_test(
+      ".foo { fill-opacity: 50%; fill-opacity: 0.60; stroke-miterlimit: 4.000 }",
+      ".foo{fill-opacity:.6;stroke-miterlimit:4}",
+    );
+    minify_test(
+      ".foo { stroke-dasharray: 1px; stroke-dasharray: 2px 3.0px; stroke-dashoffset: 0px }",
+      ".foo{stroke-dasharray:2 3;stroke-dashoffset:0}",
+    );
+    minify_test(
+      ".foo { fill-rule: evenodd; vector-effect: none; fill-rule: nonzero }",
+      ".foo{fill-rule:nonzero;vector-effect:none}",
+    );
+    minify_test(
+      ".foo { cx: 1px; cx: 2px; d: none; d: path('M 0 0') }",
+      ".foo{d:path(\"M0 0\");cx:2px}",
+    );
+    minify_test(
+      ".foo { stroke-width: 1px; stroke-width: var(--width) }",
+      ".foo{stroke-width:1px;stroke-width:var(--width)}",
+    );
+    minify_test(
+      ".foo { stroke-width: var(--width); stroke-width: 2px }",
+      ".foo{stroke-width:var(--width);stroke-width:2px}",
     );
 
-    error_test(
-      r#"
-      @property --property-name {
-        syntax: '<color>';
-        inherits: false;
-      }
-    "#,
-      ParserError::AtRuleBodyInvalid,
+    prefix_test(
+      ".foo { stroke-width: 1px; stroke-width: 2cqw }",
+      indoc! {r#"
+        .foo {
+          stroke-width: 1px;
+          stroke-width: 2cqw;
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { stroke-width: 1px; stroke-width: 2cqw }",
+      indoc! {r#"
+        .foo {
+          stroke-width: 2cqw;
+        }
+      "#},
+      Browsers {
+        chrome: Some(110 << 16),
+        ..Browsers::default()
+      },
     );
+  }
 
+  #[test]
+  fn test_marker() {
+    minify_test(".foo { marker: none }", ".foo{marker:none}");
+    minify_test(".foo { marker: url(#arrow) }", ".foo{marker:url(#arrow)}");
     minify_test(
-      r#"
-      @property --property-name {
-        syntax: '*';
-        inherits: false;
-      }
-    "#,
-      "@property --property-name{syntax:\"*\";inherits:false}",
+      ".foo { marker-start: url(#a); marker-mid: url(#a); marker-end: url(#a) }",
+      ".foo{marker:url(#a)}",
     );
+    minify_test(
+      ".foo { marker-start: url(#a); marker-mid: url(#b); marker-end: url(#a) }",
+      ".foo{marker-start:url(#a);marker-mid:url(#b);marker-end:url(#a)}",
+    );
+    minify_test(
+      ".foo { marker: url(#a); marker-mid: none }",
+      ".foo{marker-start:url(#a);marker-mid:none;marker-end:url(#a)}",
+    );
+    minify_test(".foo { marker-mid: none; marker: url(#a) }", ".foo{marker:url(#a)}");
+    minify_test(
+      ".foo { marker: url(#a); marker-end: var(--end) }",
+      ".foo{marker:url(#a);marker-end:var(--end)}",
+    );
+    minify_test(".foo { marker-end: url(#a) }", ".foo{marker-end:url(#a)}");
 
-    error_test(
+    css_modules_test(
       r#"
-      @property --property-name {
-        syntax: '*';
+      .foo {
+        marker: url(#arrow);
+      }
+
+      .bar {
+        marker-start: url(markers.svg#dot);
+        marker-end: url(#dot);
       }
     "#,
-      ParserError::AtRuleBodyInvalid,
+      indoc! {r#"
+      .EgL3uq_foo {
+        marker: url(#EgL3uq_arrow);
+      }
+
+      .EgL3uq_bar {
+        marker-start: url("markers.svg#dot");
+        marker-end: url(#EgL3uq_dot);
+      }
+    "#},
+      map! {
+        "foo" => "EgL3uq_foo",
+        "arrow" => "EgL3uq_arrow",
+        "bar" => "EgL3uq_bar",
+        "dot" => "EgL3uq_dot"
+      },
+      HashMap::new(),
+      Default::default(),
+    );
+  }
+
+  #[test]
+  fn test_source_map_url() {
+    fn print(source: &str, minify: bool, source_map_url: Option<SourceMapUrl>) -> String {
+      let stylesheet = StyleSheet::parse(source, ParserOptions::default()).unwrap();
+      stylesheet
+        .to_css(PrinterOptions {
+          minify,
+          source_map_url,
+          ..PrinterOptions::default()
+        })
+        .unwrap()
+        .code
+    }
+
+    let source = ".foo { color: red }\n/*# sourceMappingURL=input.css.map */\n";
+    assert_eq!(print(source, true, None), ".foo{color:red}");
+    assert_eq!(
+      print(source, true, Some(SourceMapUrl::Preserve)),
+      ".foo{color:red}\n/*# sourceMappingURL=input.css.map */"
+    );
+    assert_eq!(
+      print(source, true, Some(SourceMapUrl::Url("output.css.map"))),
+      ".foo{color:red}\n/*# sourceMappingURL=output.css.map */"
+    );
+    assert_eq!(
+      print(source, false, Some(SourceMapUrl::Url("output.css.map"))),
+      ".foo {\n  color: red;\n}\n/*# sourceMappingURL=output.css.map */\n"
+    );
+    assert_eq!(
+      print(".foo { color: red }", true, Some(SourceMapUrl::Preserve)),
+      ".foo{color:red}"
+    );
+  }
+
+  #[test]
+  #[cfg(feature = "timings")]
+  fn test_timings() {
+    crate::timings::start();
+    let mut stylesheet =
+      StyleSheet::parse(".foo { color: red } .foo { color: blue }", ParserOptions::default()).unwrap();
+    stylesheet.minify(MinifyOptions::default()).unwrap();
+    stylesheet.to_css(PrinterOptions::default()).unwrap();
+    let timings = crate::timings::finish();
+
+    for name in [
+      "parse",
+      "minify",
+      "minify: rules",
+      "minify: handle properties",
+      "minify: handle background",
+      "minify: handle fallback",
+      "minify: finalize prefix",
+      "minify: remove unused rules",
+      "minify: merge rules",
+      "print",
+    ] {
+      let phase = timings.get(name).unwrap();
+      assert!(phase.count >= 1);
+    }
+    assert!(timings.to_string().starts_with("phase"));
+  }
+
+  #[test]
+  fn test_scroll_timeline() {
+    minify_test(
+      ".foo { scroll-timeline-name: --foo }",
+      ".foo{scroll-timeline-name:--foo}",
     );
-
-    error_test(
-      r#"
-      @property --property-name {
-        inherits: false;
-      }
-    "#,
-      ParserError::AtRuleBodyInvalid,
+    minify_test(".foo { scroll-timeline-name: none }", ".foo{scroll-timeline-name:none}");
+    minify_test(
+      ".foo { scroll-timeline-name: --foo, --bar }",
+      ".foo{scroll-timeline-name:--foo,--bar}",
     );
-
-    error_test(
-      r#"
-      @property property-name {
-        syntax: '*';
-        inherits: false;
-      }
-    "#,
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("property-name".into())),
+    minify_test(".foo { scroll-timeline-name: foo }", ".foo{scroll-timeline-name:foo}");
+    minify_test(
+      ".foo { scroll-timeline-axis: inline }",
+      ".foo{scroll-timeline-axis:inline}",
     );
-
+    minify_test(".foo { scroll-timeline-axis: x, y }", ".foo{scroll-timeline-axis:x,y}");
+    minify_test(".foo { scroll-timeline: --foo }", ".foo{scroll-timeline:--foo}");
+    minify_test(".foo { scroll-timeline: --foo block }", ".foo{scroll-timeline:--foo}");
+    minify_test(".foo { scroll-timeline: --foo x }", ".foo{scroll-timeline:--foo x}");
     minify_test(
-      r#"
-      @property --property-name {
-        syntax: 'custom | <color>';
-        inherits: false;
-        initial-value: yellow;
-      }
-    "#,
-      "@property --property-name{syntax:\"custom|<color>\";inherits:false;initial-value:#ff0}",
+      ".foo { scroll-timeline: --foo x, none inline }",
+      ".foo{scroll-timeline:--foo x,none inline}",
     );
-
-    // TODO: Re-enable with a better solution
-    //       See: https://github.com/parcel-bundler/lightningcss/issues/288
-    // minify_test(r#"
-    //   @property --property-name {
-    //     syntax: '<transform-list>';
-    //     inherits: false;
-    //     initial-value: translate(200px,300px) translate(100px,200px) scale(2);
-    //   }
-    // "#, "@property --property-name{syntax:\"<transform-list>\";inherits:false;initial-value:matrix(2,0,0,2,300,500)}");
-
     minify_test(
-      r#"
-      @property --property-name {
-        syntax: '<time>';
-        inherits: false;
-        initial-value: 1000ms;
-      }
-    "#,
-      "@property --property-name{syntax:\"<time>\";inherits:false;initial-value:1s}",
+      ".foo { scroll-timeline-name: --foo; scroll-timeline-axis: y }",
+      ".foo{scroll-timeline:--foo y}",
     );
-
     minify_test(
-      r#"
-      @property --property-name {
-        syntax: '<url>';
-        inherits: false;
-        initial-value: url("foo.png");
-      }
-    "#,
-      "@property --property-name{syntax:\"<url>\";inherits:false;initial-value:url(foo.png)}",
+      ".foo { scroll-timeline-name: --foo, --bar; scroll-timeline-axis: y }",
+      ".foo{scroll-timeline-name:--foo,--bar;scroll-timeline-axis:y}",
     );
-
     minify_test(
-      r#"
-      @property --property-name {
-        syntax: '<image>';
-        inherits: false;
-        initial-value: linear-gradient(yellow, blue);
-      }
-    "#,
-      "@property --property-name{syntax:\"<image>\";inherits:false;initial-value:linear-gradient(#ff0,#00f)}",
+      ".foo { scroll-timeline: --foo x; scroll-timeline-axis: y }",
+      ".foo{scroll-timeline:--foo y}",
     );
-
     minify_test(
-      r#"
-      @property --property-name {
-        initial-value: linear-gradient(yellow, blue);
-        inherits: false;
-        syntax: '<image>';
-      }
-    "#,
-      "@property --property-name{syntax:\"<image>\";inherits:false;initial-value:linear-gradient(#ff0,#00f)}",
+      ".foo { scroll-timeline: --foo x; scroll-timeline-name: --bar }",
+      ".foo{scroll-timeline:--bar x}",
+    );
+    minify_test(
+      ".foo { scroll-timeline: --foo; scroll-timeline-axis: var(--axis) }",
+      ".foo{scroll-timeline:--foo;scroll-timeline-axis:var(--axis)}",
     );
-
     test(
       r#"
-      @property --property-name {
-        syntax: '<length>|none';
-        inherits: false;
-        initial-value: none;
+      .foo {
+        scroll-timeline-name: --foo;
+        scroll-timeline-axis: inline;
       }
     "#,
       indoc! {r#"
-      @property --property-name {
-        syntax: "<length> | none";
-        inherits: false;
-        initial-value: none;
+      .foo {
+        scroll-timeline: --foo inline;
       }
     "#},
     );
+  }
 
+  #[test]
+  fn test_view_timeline() {
+    minify_test(".foo { view-timeline-name: --foo }", ".foo{view-timeline-name:--foo}");
     minify_test(
-      r#"
-      @property --property-name {
-        syntax: '<color>#';
-        inherits: false;
-        initial-value: yellow, blue;
-      }
-    "#,
-      "@property --property-name{syntax:\"<color>#\";inherits:false;initial-value:#ff0,#00f}",
+      ".foo { view-timeline-name: --foo, none }",
+      ".foo{view-timeline-name:--foo,none}",
     );
+    minify_test(".foo { view-timeline-axis: x }", ".foo{view-timeline-axis:x}");
+    minify_test(".foo { view-timeline-inset: auto }", ".foo{view-timeline-inset:auto}");
     minify_test(
-      r#"
-      @property --property-name {
-        syntax: '<color>+';
-        inherits: false;
-        initial-value: yellow blue;
-      }
-    "#,
-      "@property --property-name{syntax:\"<color>+\";inherits:false;initial-value:#ff0 #00f}",
+      ".foo { view-timeline-inset: 10px auto }",
+      ".foo{view-timeline-inset:10px auto}",
     );
     minify_test(
-      r#"
-      @property --property-name {
-        syntax: '<color>';
-        inherits: false;
-        initial-value: yellow;
-      }
-      .foo {
-        color: var(--property-name)
-      }
-      @property --property-name {
-        syntax: '<color>';
-        inherits: true;
-        initial-value: blue;
-      }
-    "#,
-      "@property --property-name{syntax:\"<color>\";inherits:true;initial-value:#00f}.foo{color:var(--property-name)}",
+      ".foo { view-timeline-inset: 10px 10px }",
+      ".foo{view-timeline-inset:10px}",
+    );
+    minify_test(
+      ".foo { view-timeline-inset: 10px, 20% 5px }",
+      ".foo{view-timeline-inset:10px,20% 5px}",
+    );
+    minify_test(".foo { view-timeline: --foo }", ".foo{view-timeline:--foo}");
+    minify_test(".foo { view-timeline: --foo block auto }", ".foo{view-timeline:--foo}");
+    minify_test(
+      ".foo { view-timeline: --foo 10px x }",
+      ".foo{view-timeline:--foo x 10px}",
+    );
+    minify_test(
+      ".foo { view-timeline: --foo inline, --bar 10% 20% }",
+      ".foo{view-timeline:--foo inline,--bar 10% 20%}",
+    );
+    minify_test(
+      ".foo { view-timeline-name: --foo; view-timeline-axis: y; view-timeline-inset: 5px }",
+      ".foo{view-timeline:--foo y 5px}",
+    );
+    minify_test(
+      ".foo { view-timeline-name: --foo; view-timeline-axis: y }",
+      ".foo{view-timeline-name:--foo;view-timeline-axis:y}",
+    );
+    minify_test(
+      ".foo { view-timeline: --foo; view-timeline-inset: 10px }",
+      ".foo{view-timeline:--foo 10px}",
+    );
+    minify_test(
+      ".foo { view-timeline: --foo; view-timeline-axis: x, y }",
+      ".foo{view-timeline-name:--foo;view-timeline-axis:x,y;view-timeline-inset:auto}",
+    );
+    minify_test(
+      ".foo { view-timeline: --foo; view-timeline-inset: var(--inset) }",
+      ".foo{view-timeline:--foo;view-timeline-inset:var(--inset)}",
     );
   }
 
   #[test]
-  fn test_quoting_unquoting_urls() {
-    // Quotes remain double quotes when not minifying
-    test(
-      r#".foo {
-      background-image: url("0123abcd");
-    }"#,
-      r#".foo {
-  background-image: url("0123abcd");
-}
-"#,
-    );
+  fn test_remove_unused_keyframes() {
+    fn keyframes_test(source: &str, expected: &str) {
+      minify_test_with_minify_options(
+        source,
+        expected,
+        MinifyOptions {
+          remove_unused_keyframes: true,
+          ..MinifyOptions::default()
+        },
+      );
+    }
 
-    // Quotes removed when minifying
+    keyframes_test(
+      "@keyframes used { to { opacity: 0 } } @keyframes unused { to { opacity: 1 } } .foo { animation-name: used }",
+      "@keyframes used{to{opacity:0}}.foo{animation-name:used}",
+    );
+    keyframes_test(
+      "@keyframes used { to { opacity: 0 } } @keyframes unused { to { opacity: 1 } } .foo { animation: used 1s }",
+      "@keyframes used{to{opacity:0}}.foo{animation:1s used}",
+    );
+    keyframes_test(
+      r#"@keyframes "used" { to { opacity: 0 } } @keyframes unused { to { opacity: 1 } } .foo { animation-name: used, none }"#,
+      "@keyframes used{to{opacity:0}}.foo{animation-name:used,none}",
+    );
+    keyframes_test(
+      "@keyframes used { to { opacity: 0 } } @media print { .foo { .bar { animation-name: used } } }",
+      "@keyframes used{to{opacity:0}}@media print{.foo{& .bar{animation-name:used}}}",
+    );
+    keyframes_test(
+      "@media print { @keyframes unused { to { opacity: 1 } } } .foo { color: red }",
+      ".foo{color:red}",
+    );
+    keyframes_test(
+      "@keyframes a { to { opacity: 0 } } @keyframes b { to { opacity: 1 } } .foo { animation-name: a; animation-name: b }",
+      "@keyframes b{to{opacity:1}}.foo{animation-name:b}",
+    );
+    keyframes_test(
+      "@keyframes a { to { opacity: 0 } } @keyframes b { to { opacity: 1 } } .foo { animation-name: a !important; animation-name: b }",
+      "@keyframes a{to{opacity:0}}@keyframes b{to{opacity:1}}.foo{animation-name:b;animation-name:a!important}",
+    );
+    keyframes_test(
+      "@-webkit-keyframes a { to { opacity: 0 } } @keyframes a { to { opacity: 0 } } @keyframes b { to { opacity: 1 } } .foo { -webkit-animation-name: a; animation-name: b }",
+      "@-webkit-keyframes a{to{opacity:0}}@keyframes a{to{opacity:0}}@keyframes b{to{opacity:1}}.foo{-webkit-animation-name:a;animation-name:b}",
+    );
+    keyframes_test(
+      "@keyframes a { to { opacity: 0 } } .foo { animation-name: var(--name) }",
+      "@keyframes a{to{opacity:0}}.foo{animation-name:var(--name)}",
+    );
+    keyframes_test(
+      "@keyframes a { to { opacity: 0 } } .foo { animation: var(--anim) }",
+      "@keyframes a{to{opacity:0}}.foo{animation:var(--anim)}",
+    );
+    keyframes_test(
+      "@keyframes a { to { opacity: 0 } } @unknown { .foo { animation: a 1s } }",
+      "@keyframes a{to{opacity:0}}@unknown{.foo { animation: a 1s }}",
+    );
     minify_test(
-      r#".foo {
-      background-image: url("0123abcd");
-    }"#,
-      r#".foo{background-image:url(0123abcd)}"#,
+      "@keyframes unused { to { opacity: 1 } } .foo { color: red }",
+      "@keyframes unused{to{opacity:1}}.foo{color:red}",
     );
 
-    // Doubles quotes added if not present when not minifying
-    test(
-      r#".foo {
-      background-image: url(0123abcd);
-    }"#,
-      r#".foo {
-  background-image: url("0123abcd");
-}
-"#,
+    fn when_else_test(source: &str, expected: &str) {
+      minify_test_with_parser_and_minify_options(
+        source,
+        expected,
+        ParserOptions {
+          flags: ParserFlags::WHEN_ELSE,
+          ..ParserOptions::default()
+        },
+        MinifyOptions {
+          remove_unused_keyframes: true,
+          ..MinifyOptions::default()
+        },
+      );
+    }
+
+    when_else_test(
+      "@keyframes spin { to { opacity: 0 } } @when media(print) { .a { animation: spin 1s } }",
+      "@keyframes spin{to{opacity:0}}@when media(print){.a{animation:1s spin}}",
+    );
+    when_else_test(
+      "@keyframes spin { to { opacity: 0 } } @when media(print) { .a { color: red } } @else { .a { animation-name: spin } }",
+      "@keyframes spin{to{opacity:0}}@when media(print){.a{color:red}}@else{.a{animation-name:spin}}",
+    );
+    when_else_test(
+      "@when media(print) { @keyframes unused { to { opacity: 0 } } } @else { @keyframes used { to { opacity: 0 } } .a { animation-name: used } }",
+      "@when media(print){}@else{@keyframes used{to{opacity:0}}.a{animation-name:used}}",
+    );
+    when_else_test(
+      "@keyframes a { to { opacity: 0 } } @when media(print) { .a { animation-name: var(--name) } }",
+      "@keyframes a{to{opacity:0}}@when media(print){.a{animation-name:var(--name)}}",
     );
 
-    // No quotes changed if not present when not minifying
+    fn mixin_test(source: &str, expected: &str) {
+      minify_test_with_parser_and_minify_options(
+        source,
+        expected,
+        ParserOptions {
+          flags: ParserFlags::MIXINS,
+          ..ParserOptions::default()
+        },
+        MinifyOptions {
+          remove_unused_keyframes: true,
+          ..MinifyOptions::default()
+        },
+      );
+    }
+
+    mixin_test(
+      "@keyframes spin { to { opacity: 0 } } @mixin --m { animation: spin 1s } .a { @apply --m; }",
+      "@keyframes spin{to{opacity:0}}@mixin --m{animation:1s spin}.a{@apply --m;}",
+    );
+    mixin_test(
+      "@keyframes spin { to { opacity: 0 } } @keyframes unused { to { opacity: 1 } } @mixin --m { .b { animation-name: spin } } .a { @apply --m; }",
+      "@keyframes spin{to{opacity:0}}@mixin --m{& .b{animation-name:spin}}.a{@apply --m;}",
+    );
+    mixin_test(
+      "@keyframes spin { to { opacity: 0 } } @mixin --m(--name) { animation-name: var(--name) } .a { @apply --m(spin); }",
+      "@keyframes spin{to{opacity:0}}@mixin --m(--name){animation-name:var(--name)}.a{@apply --m(spin);}",
+    );
+    mixin_test(
+      "@keyframes spin { to { opacity: 0 } } .a { @apply --undefined; }",
+      "@keyframes spin{to{opacity:0}}.a{@apply --undefined;}",
+    );
+  }
+  #[test]
+  fn test_purge() {
+    use std::collections::HashSet;
+
+    fn purge_test(source: &str, expected: &str) {
+      minify_test_with_minify_options(
+        source,
+        expected,
+        MinifyOptions {
+          purge: Some(PurgeOptions {
+            classes: Some(HashSet::from(["foo".into(), "active".into()])),
+            ids: Some(HashSet::from(["main".into()])),
+            tags: Some(HashSet::from(["div".into(), "a".into()])),
+            safelist: vec!["is-*".into()],
+          }),
+          ..MinifyOptions::default()
+        },
+      );
+    }
+
+    purge_test(".foo { color: red } .bar { color: blue }", ".foo{color:red}");
+    purge_test("div .foo, span .foo { color: red }", "div .foo{color:red}");
+    purge_test("#main > a:hover, #other { color: red }", "#main>a:hover{color:red}");
+    purge_test(".is-open .foo, .bar.is-open { color: red }", ".is-open .foo{color:red}");
+    purge_test(
+      ":is(.foo, .bar) .active, :where(.bar, .baz) div { color: red }",
+      ":is(.foo,.bar) .active{color:red}",
+    );
+    purge_test(
+      "* { margin: 0 } [hidden] { display: none }",
+      "*{margin:0}[hidden]{display:none}",
+    );
+    purge_test(
+      "@media print { .bar { color: red } } @supports (display: grid) { span { color: red } }",
+      "",
+    );
+    purge_test(
+      ".foo { .bar { color: red } &.active { color: blue } }",
+      ".foo{&.active{color:#00f}}",
+    );
+    purge_test(".bar { color: red; & .foo { color: blue } }", "");
+    purge_test(
+      "@keyframes fade { to { opacity: 0 } } @keyframes spin { to { opacity: 1 } } @keyframes idle { to { opacity: .5 } } .bar { animation: fade 1s } .foo { animation-name: spin }",
+      "@keyframes spin{to{opacity:1}}@keyframes idle{to{opacity:.5}}.foo{animation-name:spin}",
+    );
+  }
+
+  #[test]
+  fn test_deduplicate_keyframes() {
+    fn keyframes_test(source: &str, expected: &str) {
+      minify_test_with_minify_options(
+        source,
+        expected,
+        MinifyOptions {
+          deduplicate_keyframes: true,
+          ..MinifyOptions::default()
+        },
+      );
+    }
+
+    keyframes_test(
+      r#"
+      @keyframes a { from { opacity: 0 } to { opacity: 1 } }
+      @keyframes b { 0% { opacity: 0 } 100% { opacity: 1.0 } }
+      .foo { animation-name: b }
+      .bar { animation: b 1s, a 2s }
+      "#,
+      "@keyframes a{0%{opacity:0}to{opacity:1}}.foo{animation-name:a}.bar{animation:1s a,2s a}",
+    );
+    keyframes_test(
+      r#"
+      @keyframes a { to { opacity: 1 } }
+      @keyframes b { to { opacity: 0 } }
+      .foo { animation-name: b }
+      "#,
+      "@keyframes a{to{opacity:1}}@keyframes b{to{opacity:0}}.foo{animation-name:b}",
+    );
+    keyframes_test(
+      r#"
+      @keyframes "a" { to { opacity: 1 } }
+      @keyframes b { to { opacity: 1 } }
+      @media print { .foo { animation-name: b !important } }
+      "#,
+      "@keyframes a{to{opacity:1}}@media print{.foo{animation-name:a!important}}",
+    );
+    keyframes_test(
+      r#"
+      @-webkit-keyframes a { to { opacity: 1 } }
+      @keyframes b { to { opacity: 1 } }
+      .foo { -webkit-animation-name: a; animation-name: b }
+      "#,
+      "@-webkit-keyframes a{to{opacity:1}}@keyframes b{to{opacity:1}}.foo{-webkit-animation-name:a;animation-name:b}",
+    );
+    keyframes_test(
+      r#"
+      @keyframes a { to { opacity: 1 } }
+      @keyframes b { to { opacity: 1 } }
+      @media print { @keyframes b { to { opacity: 0 } } }
+      .foo { animation-name: b }
+      "#,
+      "@keyframes a{to{opacity:1}}@keyframes b{to{opacity:1}}@media print{@keyframes b{to{opacity:0}}}.foo{animation-name:b}",
+    );
+    keyframes_test(
+      r#"
+      @keyframes a { to { opacity: 1 } }
+      @keyframes b { to { opacity: 1 } }
+      .foo { animation-name: var(--name) }
+      "#,
+      "@keyframes a{to{opacity:1}}@keyframes b{to{opacity:1}}.foo{animation-name:var(--name)}",
+    );
     minify_test(
-      r#".foo {
-      background-image: url(0123abcd);
-    }"#,
-      r#".foo{background-image:url(0123abcd)}"#,
+      "@keyframes a { to { opacity: 1 } } @keyframes b { to { opacity: 1 } } .foo { animation-name: b }",
+      "@keyframes a{to{opacity:1}}@keyframes b{to{opacity:1}}.foo{animation-name:b}",
+    );
+
+    fn when_else_test(source: &str, expected: &str) {
+      minify_test_with_parser_and_minify_options(
+        source,
+        expected,
+        ParserOptions {
+          flags: ParserFlags::WHEN_ELSE,
+          ..ParserOptions::default()
+        },
+        MinifyOptions {
+          deduplicate_keyframes: true,
+          ..MinifyOptions::default()
+        },
+      );
+    }
+
+    when_else_test(
+      "@keyframes a { to { opacity: 1 } } @keyframes b { to { opacity: 1 } } @when media(print) { .foo { animation-name: b } } @else { .foo { animation: b 1s } }",
+      "@keyframes a{to{opacity:1}}@when media(print){.foo{animation-name:a}}@else{.foo{animation:1s a}}",
+    );
+    when_else_test(
+      "@keyframes a { to { opacity: 1 } } @keyframes b { to { opacity: 1 } } @when media(print) { @keyframes b { to { opacity: 0 } } } .foo { animation-name: b }",
+      "@keyframes a{to{opacity:1}}@keyframes b{to{opacity:1}}@when media(print){@keyframes b{to{opacity:0}}}.foo{animation-name:b}",
+    );
+    when_else_test(
+      "@keyframes a { to { opacity: 1 } } @keyframes b { to { opacity: 1 } } @when media(print) { .foo { animation-name: var(--name) } }",
+      "@keyframes a{to{opacity:1}}@keyframes b{to{opacity:1}}@when media(print){.foo{animation-name:var(--name)}}",
+    );
+
+    fn mixin_test(source: &str, expected: &str) {
+      minify_test_with_parser_and_minify_options(
+        source,
+        expected,
+        ParserOptions {
+          flags: ParserFlags::MIXINS,
+          ..ParserOptions::default()
+        },
+        MinifyOptions {
+          deduplicate_keyframes: true,
+          ..MinifyOptions::default()
+        },
+      );
+    }
+
+    mixin_test(
+      "@keyframes a { to { opacity: 1 } } @keyframes b { to { opacity: 1 } } @mixin --m { animation-name: b; .c { animation: b 1s } } .foo { @apply --m; }",
+      "@keyframes a{to{opacity:1}}@mixin --m{animation-name:a;& .c{animation:1s a}}.foo{@apply --m;}",
+    );
+    mixin_test(
+      "@keyframes a { to { opacity: 1 } } @keyframes b { to { opacity: 1 } } .foo { @apply --undefined; }",
+      "@keyframes a{to{opacity:1}}@keyframes b{to{opacity:1}}.foo{@apply --undefined;}",
     );
   }
 
   #[test]
-  fn test_zindex() {
-    minify_test(".foo { z-index: 2 }", ".foo{z-index:2}");
-    minify_test(".foo { z-index: -2 }", ".foo{z-index:-2}");
-    minify_test(".foo { z-index: 999999 }", ".foo{z-index:999999}");
-    minify_test(".foo { z-index: 9999999 }", ".foo{z-index:9999999}");
-    minify_test(".foo { z-index: -9999999 }", ".foo{z-index:-9999999}");
+  fn test_deduplicate_font_faces() {
+    fn font_face_test(source: &str, expected: &str) {
+      minify_test_with_minify_options(
+        source,
+        expected,
+        MinifyOptions {
+          deduplicate_font_faces: true,
+          ..MinifyOptions::default()
+        },
+      );
+    }
+
+    font_face_test(
+      "@font-face { font-family: Foo; src: url(a.woff) } .foo { font-family: Foo } @font-face { font-family: Foo; src: url(a.woff) }",
+      ".foo{font-family:Foo}@font-face{font-family:Foo;src:url(a.woff)}",
+    );
+    font_face_test(
+      "@font-face { font-family: Foo; src: url(a.woff) } @font-face { font-family: Foo; src: url(b.woff) } @font-face { font-family: Foo; src: url(a.woff) }",
+      "@font-face{font-family:Foo;src:url(b.woff)}@font-face{font-family:Foo;src:url(a.woff)}",
+    );
+    font_face_test(
+      "@font-face { font-family: Foo; src: url(a.woff) } @font-face { font-family: Bar; src: url(a.woff) }",
+      "@font-face{font-family:Foo;src:url(a.woff)}@font-face{font-family:Bar;src:url(a.woff)}",
+    );
+    font_face_test(
+      "@font-face { font-family: Foo; src: url(a.woff) } @media print { @font-face { font-family: Foo; src: url(a.woff) } }",
+      "@font-face{font-family:Foo;src:url(a.woff)}@media print{@font-face{font-family:Foo;src:url(a.woff)}}",
+    );
   }
 
   #[test]
-  #[cfg(feature = "sourcemap")]
-  fn test_input_source_map() {
-    let source = r#".imported {
-      content: "yay, file support!";
+  fn test_remove_unused_font_faces() {
+    fn font_face_test(source: &str, expected: &str, used_font_families: &[&str]) {
+      minify_test_with_minify_options(
+        source,
+        expected,
+        MinifyOptions {
+          remove_unused_font_faces: true,
+          used_font_families: used_font_families.iter().map(|family| family.to_string()).collect(),
+          ..MinifyOptions::default()
+        },
+      );
     }
 
-    .selector {
-      margin: 1em;
-      background-color: #f60;
+    font_face_test(
+      "@font-face { font-family: Used; src: url(a.woff) } @font-face { font-family: Unused; src: url(b.woff) } .foo { font-family: used, sans-serif }",
+      "@font-face{font-family:Used;src:url(a.woff)}.foo{font-family:used,sans-serif}",
+      &[],
+    );
+    font_face_test(
+      r#"@font-face { font-family: "Used Font"; src: url(a.woff) } @font-face { font-family: Unused; src: url(b.woff) } @media print { .foo { font: 12px "Used Font" } }"#,
+      "@font-face{font-family:Used Font;src:url(a.woff)}@media print{.foo{font:12px Used Font}}",
+      &[],
+    );
+    font_face_test(
+      "@font-face { font-family: Dynamic; src: url(a.woff) } @font-face { font-family: Unused; src: url(b.woff) } .foo { font-family: Used }",
+      "@font-face{font-family:Dynamic;src:url(a.woff)}.foo{font-family:Used}",
+      &["dynamic"],
+    );
+    font_face_test(
+      "@font-face { font-family: Foo; src: url(a.woff) } .foo { font-family: var(--font) }",
+      "@font-face{font-family:Foo;src:url(a.woff)}.foo{font-family:var(--font)}",
+      &[],
+    );
+    font_face_test(
+      "@font-face { font-family: Foo; src: url(a.woff) } @unknown { .foo { font-family: Foo } }",
+      "@font-face{font-family:Foo;src:url(a.woff)}@unknown{.foo { font-family: Foo }}",
+      &[],
+    );
+    font_face_test(
+      "@font-face { font-family: Foo; src: url(a.woff) } @keyframes fade { to { font-family: Foo } }",
+      "@font-face{font-family:Foo;src:url(a.woff)}@keyframes fade{to{font-family:Foo}}",
+      &[],
+    );
+    font_face_test(
+      "@font-face { font-family: Foo; src: url(a.woff) } @page { @top-center { font-family: Foo } }",
+      "@font-face{font-family:Foo;src:url(a.woff)}@page{@top-center{font-family:Foo}}",
+      &[],
+    );
+    font_face_test(
+      "@import 'other.css'; @font-face { font-family: Foo; src: url(a.woff) }",
+      "@import \"other.css\";@font-face{font-family:Foo;src:url(a.woff)}",
+      &[],
+    );
+    font_face_test(
+      "@font-face { font-family: Foo; src: url(a.woff) } .foo { color: red }",
+      ".foo{color:red}",
+      &[],
+    );
+
+    fn when_else_test(source: &str, expected: &str) {
+      minify_test_with_parser_and_minify_options(
+        source,
+        expected,
+        ParserOptions {
+          flags: ParserFlags::WHEN_ELSE,
+          ..ParserOptions::default()
+        },
+        MinifyOptions {
+          remove_unused_font_faces: true,
+          ..MinifyOptions::default()
+        },
+      );
     }
 
-    .selector .nested {
-      margin: 0.5em;
+    when_else_test(
+      "@font-face { font-family: Foo; src: url(a.woff) } @when media(print) { .a { font-family: Foo } }",
+      "@font-face{font-family:Foo;src:url(a.woff)}@when media(print){.a{font-family:Foo}}",
+    );
+    when_else_test(
+      "@font-face { font-family: Foo; src: url(a.woff) } @when media(print) { .a { color: red } } @else { .a { font: 12px Foo } }",
+      "@font-face{font-family:Foo;src:url(a.woff)}@when media(print){.a{color:red}}@else{.a{font:12px Foo}}",
+    );
+    when_else_test(
+      "@when media(print) { @font-face { font-family: Foo; src: url(a.woff) } } @else { .a { color: red } }",
+      "@when media(print){}@else{.a{color:red}}",
+    );
+    when_else_test(
+      "@font-face { font-family: Foo; src: url(a.woff) } @when media(print) { .a { font-family: var(--font) } }",
+      "@font-face{font-family:Foo;src:url(a.woff)}@when media(print){.a{font-family:var(--font)}}",
+    );
+
+    fn mixin_test(source: &str, expected: &str) {
+      minify_test_with_parser_and_minify_options(
+        source,
+        expected,
+        ParserOptions {
+          flags: ParserFlags::MIXINS,
+          ..ParserOptions::default()
+        },
+        MinifyOptions {
+          remove_unused_font_faces: true,
+          ..MinifyOptions::default()
+        },
+      );
     }
 
-    /*# sourceMappingURL=data:application/json;base64,ewoJInZlcnNpb24iOiAzLAoJInNvdXJjZVJvb3QiOiAicm9vdCIsCgkiZmlsZSI6ICJzdGRvdXQiLAoJInNvdXJjZXMiOiBbCgkJInN0ZGluIiwKCQkic2Fzcy9fdmFyaWFibGVzLnNjc3MiLAoJCSJzYXNzL19kZW1vLnNjc3MiCgldLAoJInNvdXJjZXNDb250ZW50IjogWwoJCSJAaW1wb3J0IFwiX3ZhcmlhYmxlc1wiO1xuQGltcG9ydCBcIl9kZW1vXCI7XG5cbi5zZWxlY3RvciB7XG4gIG1hcmdpbjogJHNpemU7XG4gIGJhY2tncm91bmQtY29sb3I6ICRicmFuZENvbG9yO1xuXG4gIC5uZXN0ZWQge1xuICAgIG1hcmdpbjogJHNpemUgLyAyO1xuICB9XG59IiwKCQkiJGJyYW5kQ29sb3I6ICNmNjA7XG4kc2l6ZTogMWVtOyIsCgkJIi5pbXBvcnRlZCB7XG4gIGNvbnRlbnQ6IFwieWF5LCBmaWxlIHN1cHBvcnQhXCI7XG59IgoJXSwKCSJtYXBwaW5ncyI6ICJBRUFBLFNBQVMsQ0FBQztFQUNSLE9BQU8sRUFBRSxvQkFBcUI7Q0FDL0I7O0FGQ0QsU0FBUyxDQUFDO0VBQ1IsTUFBTSxFQ0hELEdBQUc7RURJUixnQkFBZ0IsRUNMTCxJQUFJO0NEVWhCOztBQVBELFNBQVMsQ0FJUCxPQUFPLENBQUM7RUFDTixNQUFNLEVDUEgsS0FBRztDRFFQIiwKCSJuYW1lcyI6IFtdCn0= */"#;
+    mixin_test(
+      "@font-face { font-family: Foo; src: url(a.woff) } @font-face { font-family: Bar; src: url(b.woff) } @mixin --m { font-family: Foo } .a { @apply --m; }",
+      "@font-face{font-family:Foo;src:url(a.woff)}@mixin --m{font-family:Foo}.a{@apply --m;}",
+    );
+    mixin_test(
+      "@font-face { font-family: Foo; src: url(a.woff) } @mixin --m { .b { font: 12px Foo } } .a { @apply --m; }",
+      "@font-face{font-family:Foo;src:url(a.woff)}@mixin --m{& .b{font:12px Foo}}.a{@apply --m;}",
+    );
+    mixin_test(
+      "@font-face { font-family: Foo; src: url(a.woff) } .a { @apply --undefined; }",
+      "@font-face{font-family:Foo;src:url(a.woff)}.a{@apply --undefined;}",
+    );
 
-    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
-    stylesheet.minify(MinifyOptions::default()).unwrap();
-    let mut sm = parcel_sourcemap::SourceMap::new("/");
-    stylesheet
-      .to_css(PrinterOptions {
-        source_map: Some(&mut sm),
-        minify: true,
-        ..PrinterOptions::default()
-      })
-      .unwrap();
-    let map = sm.to_json(None).unwrap();
-    assert_eq!(
-      map,
-      r#"{"version":3,"sourceRoot":null,"mappings":"AAAA,uCCGA,2CAAA","sources":["sass/_demo.scss","stdin"],"sourcesContent":[".imported {\n  content: \"yay, file support!\";\n}","@import \"_variables\";\n@import \"_demo\";\n\n.selector {\n  margin: $size;\n  background-color: $brandColor;\n\n  .nested {\n    margin: $size / 2;\n  }\n}"],"names":[]}"#
+    minify_test_with_parser_and_minify_options(
+      "@font-face { font-family: Foo; src: url(a.woff) } @font-face { font-family: Bar; src: url(b.woff) } @when media(print) { @mixin --m { font-family: Foo } } .a { @apply --m; }",
+      "@font-face{font-family:Foo;src:url(a.woff)}@when media(print){@mixin --m{font-family:Foo}}.a{@apply --m;}",
+      ParserOptions {
+        flags: ParserFlags::WHEN_ELSE | ParserFlags::MIXINS,
+        ..ParserOptions::default()
+      },
+      MinifyOptions {
+        remove_unused_font_faces: true,
+        ..MinifyOptions::default()
+      },
     );
   }
 
   #[test]
-  fn test_error_recovery() {
-    use std::sync::{Arc, RwLock};
-    let warnings = Some(Arc::new(RwLock::new(Vec::new())));
-    test_with_options(
+  fn test_keyframes_timeline_range() {
+    minify_test(
+      "@keyframes foo { entry 0% { opacity: 0 } entry 100% { opacity: 1 } }",
+      "@keyframes foo{entry 0%{opacity:0}entry 100%{opacity:1}}",
+    );
+    minify_test(
+      "@keyframes foo { cover 50.0%, EXIT-CROSSING 100% { opacity: 0 } }",
+      "@keyframes foo{cover 50%,exit-crossing 100%{opacity:0}}",
+    );
+    minify_test(
+      "@keyframes foo { from { opacity: 0 } contain 25% { opacity: .5 } exit 75% { opacity: 1 } }",
+      "@keyframes foo{0%{opacity:0}contain 25%{opacity:.5}exit 75%{opacity:1}}",
+    );
+    minify_test(
+      "@keyframes foo { entry-crossing 10% { opacity: 0 } }",
+      "@keyframes foo{entry-crossing 10%{opacity:0}}",
+    );
+    minify_test("@keyframes foo { entry { opacity: 0 } }", "@keyframes foo{}");
+    minify_test("@keyframes foo { foo 10% { opacity: 0 } }", "@keyframes foo{}");
+    test(
       r#"
-      h1(>h1) {
-        color: red;
-      }
-
-      .foo {
-        color: red;
-      }
-
-      .clearfix {
-        *zoom: 1;
-        background: red;
-      }
-
-      @media (hover) {
-        h1(>h1) {
-          color: red;
-        }
-
-        .bar {
-          color: red;
+      @keyframes foo {
+        entry 25% {
+          opacity: 0;
         }
       }
     "#,
-      indoc! { r#"
-      .foo {
-        color: red;
-      }
-
-      .clearfix {
-        background: red;
-      }
-
-      @media (hover) {
-        .bar {
-          color: red;
+      indoc! {r#"
+      @keyframes foo {
+        entry 25% {
+          opacity: 0;
         }
       }
     "#},
-      ParserOptions {
-        filename: "test.css".into(),
-        error_recovery: true,
-        warnings: warnings.clone(),
-        ..ParserOptions::default()
-      },
     );
-    let w = warnings.unwrap();
-    let warnings = w.read().unwrap();
-    assert_eq!(
-      *warnings,
-      vec![
-        Error {
-          kind: ParserError::SelectorError(SelectorError::EmptySelector),
-          loc: Some(ErrorLocation {
-            filename: "test.css".into(),
-            line: 1,
-            column: 7
-          })
-        },
-        Error {
-          kind: ParserError::UnexpectedToken(Token::Semicolon),
-          loc: Some(ErrorLocation {
-            filename: "test.css".into(),
-            line: 10,
-            column: 17
-          })
-        },
-        Error {
-          kind: ParserError::SelectorError(SelectorError::EmptySelector),
-          loc: Some(ErrorLocation {
-            filename: "test.css".into(),
-            line: 15,
-            column: 9
-          })
-        },
-      ]
-    )
   }
 
   #[test]
-  fn test_invalid() {
-    error_test(
-      ".a{color: hsla(120, 62.32%;}",
-      ParserError::UnexpectedToken(Token::CloseCurlyBracket),
+  fn test_overlay() {
+    minify_test(".foo { overlay: none }", ".foo{overlay:none}");
+    minify_test(".foo { overlay: AUTO }", ".foo{overlay:auto}");
+    minify_test(".foo { overlay: top }", ".foo{overlay:top}");
+    minify_test(
+      ".foo { transition-property: overlay }",
+      ".foo{transition-property:overlay}",
     );
-    error_test(
-      ".a{--foo: url(foo\\) b\\)ar)}",
-      ParserError::UnexpectedToken(Token::BadUrl("foo\\) b\\)ar".into())),
+    minify_test(
+      ".foo { transition: overlay 0.3s allow-discrete, opacity 0.3s }",
+      ".foo{transition:overlay .3s allow-discrete,opacity .3s}",
+    );
+    minify_test(
+      ".foo { transition: overlay 0.3s allow-discrete; transition-duration: 1s }",
+      ".foo{transition:overlay 1s allow-discrete}",
     );
   }
 
   #[test]
-  fn test_container_queries() {
-    // with name
+  fn test_transition_behavior() {
+    minify_test(
+      ".foo { transition-behavior: allow-discrete }",
+      ".foo{transition-behavior:allow-discrete}",
+    );
+    minify_test(
+      ".foo { transition-behavior: normal, allow-discrete }",
+      ".foo{transition-behavior:normal,allow-discrete}",
+    );
+    minify_test(
+      ".foo { transition: overlay 0.3s allow-discrete }",
+      ".foo{transition:overlay .3s allow-discrete}",
+    );
+    minify_test(
+      ".foo { transition: allow-discrete display 0.3s }",
+      ".foo{transition:display .3s allow-discrete}",
+    );
+    minify_test(
+      ".foo { transition: opacity 0.3s normal }",
+      ".foo{transition:opacity .3s}",
+    );
+    minify_test(
+      ".foo { transition: opacity 0.3s, display 0.3s allow-discrete }",
+      ".foo{transition:opacity .3s,display .3s allow-discrete}",
+    );
     minify_test(
       r#"
-      @container my-layout (inline-size > 45em) {
-        .foo {
-          color: red;
-        }
+      .foo {
+        transition-property: opacity, display;
+        transition-duration: 0.3s;
+        transition-delay: 0s;
+        transition-timing-function: ease;
+        transition-behavior: allow-discrete;
       }
     "#,
-      "@container my-layout (inline-size>45em){.foo{color:red}}",
+      ".foo{transition:opacity .3s allow-discrete,display .3s allow-discrete}",
     );
-
     minify_test(
-      r#"
-      @container my-layout ( not (width > 500px) ) {
+      ".foo { transition: display 0.3s; transition-behavior: allow-discrete }",
+      ".foo{transition:display .3s allow-discrete}",
+    );
+    minify_test(
+      ".foo { transition: display 0.3s allow-discrete; transition-behavior: normal }",
+      ".foo{transition:display .3s}",
+    );
+    minify_test(
+      ".foo { transition-property: display; transition-behavior: allow-discrete }",
+      ".foo{transition-property:display;transition-behavior:allow-discrete}",
+    );
+    minify_test(
+      ".foo { transition: display 0.3s allow-discrete; transition-behavior: var(--behavior) }",
+      ".foo{transition:display .3s allow-discrete;transition-behavior:var(--behavior)}",
+    );
+
+    prefix_test(
+      ".foo { transition: display 0.3s allow-discrete }",
+      indoc! {r#"
         .foo {
-          color: red;
+          transition: display .3s;
+          transition-behavior: allow-discrete;
         }
-      }
-    "#,
-      "@container my-layout not (width>500px){.foo{color:red}}",
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
     );
-
-    minify_test(
-      r#"
-      @container my-layout not (width > 500px) {
+    prefix_test(
+      ".foo { transition: display 0.3s allow-discrete }",
+      indoc! {r#"
         .foo {
-          color: red;
+          transition: display .3s allow-discrete;
         }
-      }
-    "#,
-      "@container my-layout not (width>500px){.foo{color:red}}",
+      "#},
+      Browsers {
+        chrome: Some(117 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { transition: opacity 0.3s }",
+      indoc! {r#"
+        .foo {
+          transition: opacity .3s;
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { transition: opacity 0.3s allow-discrete }",
+      indoc! {r#"
+        .foo {
+          -webkit-transition: opacity .3s;
+          transition: opacity .3s;
+          transition-behavior: allow-discrete;
+        }
+      "#},
+      Browsers {
+        safari: Some(5 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_offset() {
+    minify_test(".foo { offset-path: none }", ".foo{offset-path:none}");
+    minify_test(".foo { offset-path: url(#path) }", ".foo{offset-path:url(#path)}");
+    minify_test(
+      ".foo { offset-path: path('M 0 0 L 100 100') }",
+      ".foo{offset-path:path(\"M0 0 100 100\")}",
+    );
+    minify_test(".foo { offset-path: ray(45deg) }", ".foo{offset-path:ray(45deg)}");
+    minify_test(
+      ".foo { offset-path: ray(closest-side 45deg) }",
+      ".foo{offset-path:ray(45deg)}",
+    );
+    minify_test(
+      ".foo { offset-path: ray(contain farthest-corner 0.5turn at 10px 20px) }",
+      ".foo{offset-path:ray(.5turn farthest-corner contain at 10px 20px)}",
+    );
+    minify_test(".foo { offset-path: ray(0) }", ".foo{offset-path:ray(0)}");
+    minify_test(
+      ".foo { offset-path: ray(closest-side) }",
+      ".foo{offset-path:ray(closest-side)}",
+    );
+    minify_test(
+      ".foo { offset-path: circle(50px at 0 0) border-box }",
+      ".foo{offset-path:circle(50px at 0 0)}",
+    );
+    minify_test(
+      ".foo { offset-path: content-box inset(10px) }",
+      ".foo{offset-path:inset(10px) content-box}",
+    );
+    minify_test(".foo { offset-path: padding-box }", ".foo{offset-path:padding-box}");
+    minify_test(".foo { offset-distance: 50% }", ".foo{offset-distance:50%}");
+    minify_test(".foo { offset-rotate: auto }", ".foo{offset-rotate:auto}");
+    minify_test(".foo { offset-rotate: reverse }", ".foo{offset-rotate:reverse}");
+    minify_test(".foo { offset-rotate: auto 180deg }", ".foo{offset-rotate:reverse}");
+    minify_test(".foo { offset-rotate: 45deg auto }", ".foo{offset-rotate:auto 45deg}");
+    minify_test(
+      ".foo { offset-rotate: reverse 45deg }",
+      ".foo{offset-rotate:auto 225deg}",
     );
+    minify_test(".foo { offset-rotate: 30deg }", ".foo{offset-rotate:30deg}");
+    minify_test(".foo { offset-anchor: auto }", ".foo{offset-anchor:auto}");
+    minify_test(".foo { offset-anchor: right bottom }", ".foo{offset-anchor:100% 100%}");
+    minify_test(".foo { offset-position: normal }", ".foo{offset-position:normal}");
+    minify_test(".foo { offset-position: auto }", ".foo{offset-position:auto}");
+    minify_test(".foo { offset-position: left top }", ".foo{offset-position:0 0}");
 
     minify_test(
-      r#"
-      @container not (width > 500px) {
-        .foo {
-          color: red;
-        }
-      }
-    "#,
-      "@container not (width>500px){.foo{color:red}}",
+      ".foo { offset: path('M 0 0 H 100') 50% auto }",
+      ".foo{offset:path(\"M0 0H100\") 50%}",
     );
-
     minify_test(
-      r#"
-      @container my-layout ((width: 100px) and (not (height: 100px))) {
-        .foo {
-          color: red;
-        }
-      }
-    "#,
-      "@container my-layout (width:100px) and (not (height:100px)){.foo{color:red}}",
+      ".foo { offset: path('M 0 0 H 100') reverse 10px }",
+      ".foo{offset:path(\"M0 0H100\") 10px reverse}",
     );
-
     minify_test(
-      r#"
-      @container my-layout (width = max(10px, 10em)) {
-        .foo {
-          color: red;
-        }
-      }
-    "#,
-      "@container my-layout (width=max(10px,10em)){.foo{color:red}}",
+      ".foo { offset: 10px 20px ray(45deg) / left top }",
+      ".foo{offset:10px 20px ray(45deg)/0 0}",
     );
+    minify_test(".foo { offset: auto }", ".foo{offset:auto}");
+    minify_test(".foo { offset: none }", ".foo{offset:none}");
+    minify_test(".foo { offset: normal none }", ".foo{offset:none}");
+    minify_test(".foo { offset: none 10px }", ".foo{offset:none 10px}");
+    minify_test(".foo { offset: none / center }", ".foo{offset:none/50%}");
 
-    // without name
     minify_test(
       r#"
-      @container (inline-size > 45em) {
-        .foo {
-          color: red;
-        }
+      .foo {
+        offset-position: normal;
+        offset-path: ray(45deg);
+        offset-distance: 10px;
+        offset-rotate: auto;
+        offset-anchor: auto;
       }
     "#,
-      "@container (inline-size>45em){.foo{color:red}}",
+      ".foo{offset:ray(45deg) 10px}",
     );
-
     minify_test(
-      r#"
-      @container (inline-size > 45em) and (inline-size < 100em) {
-        .foo {
-          color: red;
-        }
-      }
-    "#,
-      "@container (inline-size>45em) and (inline-size<100em){.foo{color:red}}",
+      ".foo { offset-path: ray(45deg); offset-distance: 10px }",
+      ".foo{offset-path:ray(45deg);offset-distance:10px}",
+    );
+    minify_test(
+      ".foo { offset: ray(45deg); offset-distance: 50% }",
+      ".foo{offset:ray(45deg) 50%}",
+    );
+    minify_test(
+      ".foo { offset: ray(45deg); offset-distance: var(--distance) }",
+      ".foo{offset:ray(45deg);offset-distance:var(--distance)}",
     );
+  }
 
-    // calc()
+  #[test]
+  fn test_anchor_position() {
+    minify_test(".foo { anchor-name: none }", ".foo{anchor-name:none}");
+    minify_test(".foo { anchor-name: --foo }", ".foo{anchor-name:--foo}");
+    minify_test(".foo { anchor-name: --foo, --bar }", ".foo{anchor-name:--foo,--bar}");
+    minify_test(".foo { anchor-name: foo }", ".foo{anchor-name:foo}");
+    minify_test(".foo { position-anchor: auto }", ".foo{position-anchor:auto}");
+    minify_test(".foo { position-anchor: --foo }", ".foo{position-anchor:--foo}");
+
+    minify_test(".foo { position-area: none }", ".foo{position-area:none}");
+    minify_test(".foo { position-area: top }", ".foo{position-area:top}");
+    minify_test(".foo { position-area: left top }", ".foo{position-area:left top}");
+    minify_test(".foo { position-area: top left }", ".foo{position-area:left top}");
+    minify_test(".foo { position-area: top span-all }", ".foo{position-area:top}");
+    minify_test(".foo { position-area: span-all top }", ".foo{position-area:top}");
+    minify_test(".foo { position-area: center top }", ".foo{position-area:center top}");
+    minify_test(".foo { position-area: top center }", ".foo{position-area:center top}");
+    minify_test(".foo { position-area: left center }", ".foo{position-area:left center}");
+    minify_test(".foo { position-area: center center }", ".foo{position-area:center}");
     minify_test(
-      r#"
-      @container (width > calc(100vw - 50px)) {
-        .foo {
-          color: red;
-        }
-      }
-    "#,
-      "@container (width>calc(100vw - 50px)){.foo{color:red}}",
+      ".foo { position-area: span-all center }",
+      ".foo{position-area:span-all center}",
+    );
+    minify_test(
+      ".foo { position-area: inline-start block-end }",
+      ".foo{position-area:block-end inline-start}",
+    );
+    minify_test(
+      ".foo { position-area: span-self-inline-end self-block-start }",
+      ".foo{position-area:self-block-start span-self-inline-end}",
+    );
+    minify_test(".foo { position-area: start end }", ".foo{position-area:start end}");
+    minify_test(".foo { position-area: end start }", ".foo{position-area:end start}");
+    minify_test(".foo { position-area: start start }", ".foo{position-area:start}");
+    minify_test(
+      ".foo { position-area: span-start center }",
+      ".foo{position-area:span-start center}",
+    );
+    minify_test(
+      ".foo { position-area: self-start self-end }",
+      ".foo{position-area:self-start self-end}",
+    );
+    minify_test(".foo { position-area: left right }", ".foo{position-area:left right}");
+    minify_test(
+      ".foo { position-area: left block-start }",
+      ".foo{position-area:left block-start}",
+    );
+    minify_test(".foo { position-area: start top }", ".foo{position-area:start top}");
+    minify_test(
+      ".foo { position-area: start self-end }",
+      ".foo{position-area:start self-end}",
     );
+    minify_test(".foo { inset-area: top left }", ".foo{inset-area:left top}");
 
     minify_test(
-      r#"
-      @container (calc(100vh - 50px) <= height ) {
-        .foo {
-          color: red;
-        }
-      }
-    "#,
-      "@container (height>=calc(100vh - 50px)){.foo{color:red}}",
+      ".foo { position-try-order: most-width }",
+      ".foo{position-try-order:most-width}",
+    );
+    minify_test(
+      ".foo { position-try-fallbacks: none }",
+      ".foo{position-try-fallbacks:none}",
+    );
+    minify_test(
+      ".foo { position-try-fallbacks: flip-inline, flip-start flip-block }",
+      ".foo{position-try-fallbacks:flip-inline,flip-block flip-start}",
+    );
+    minify_test(
+      ".foo { position-try-fallbacks: --foo, flip-block --bar, --baz flip-inline }",
+      ".foo{position-try-fallbacks:--foo,--bar flip-block,--baz flip-inline}",
+    );
+    minify_test(
+      ".foo { position-try-fallbacks: top left, bottom }",
+      ".foo{position-try-fallbacks:left top,bottom}",
+    );
+    minify_test(
+      ".foo { position-try-fallbacks: flip-block flip-block }",
+      ".foo{position-try-fallbacks:flip-block flip-block}",
+    );
+    minify_test(
+      ".foo { position-try-fallbacks: --foo --bar }",
+      ".foo{position-try-fallbacks:--foo --bar}",
     );
 
-    // merge adjacent
+    minify_test(".foo { position-try: none }", ".foo{position-try:none}");
+    minify_test(
+      ".foo { position-try: normal flip-block }",
+      ".foo{position-try:flip-block}",
+    );
+    minify_test(
+      ".foo { position-try: most-height --foo, flip-inline }",
+      ".foo{position-try:most-height --foo,flip-inline}",
+    );
     minify_test(
+      ".foo { position-try-order: most-width; position-try-fallbacks: --foo }",
+      ".foo{position-try:most-width --foo}",
+    );
+    minify_test(
+      ".foo { position-try: --foo; position-try-order: most-width }",
+      ".foo{position-try:most-width --foo}",
+    );
+    minify_test(
+      ".foo { position-try-fallbacks: --foo }",
+      ".foo{position-try-fallbacks:--foo}",
+    );
+    minify_test(
+      ".foo { position-try: --foo; position-try-fallbacks: var(--fallbacks) }",
+      ".foo{position-try:--foo;position-try-fallbacks:var(--fallbacks)}",
+    );
+
+    css_modules_test(
       r#"
-      @container my-layout (inline-size > 45em) {
-        .foo {
-          color: red;
-        }
+      .anchor {
+        anchor-name: --anchor, --other;
       }
 
-      @container my-layout (inline-size > 45em) {
-        .foo {
-          background: yellow;
-        }
+      .foo {
+        position-anchor: --anchor;
+      }
 
-        .bar {
-          color: white;
-        }
+      .bar {
+        position-anchor: --external from "./b.css";
       }
     "#,
-      "@container my-layout (inline-size>45em){.foo{color:red;background:#ff0}.bar{color:#fff}}",
+      indoc! {r#"
+      .EgL3uq_anchor {
+        anchor-name: --EgL3uq_anchor, --EgL3uq_other;
+      }
+
+      .EgL3uq_foo {
+        position-anchor: --EgL3uq_anchor;
+      }
+
+      .EgL3uq_bar {
+        position-anchor: --cVF-ia;
+      }
+    "#},
+      map! {
+        "anchor" => "EgL3uq_anchor",
+        "--anchor" => "--EgL3uq_anchor" referenced: true,
+        "--other" => "--EgL3uq_other",
+        "foo" => "EgL3uq_foo",
+        "bar" => "EgL3uq_bar"
+      },
+      HashMap::from([(
+        "--cVF-ia".into(),
+        CssModuleReference::Dependency {
+          name: "--external".into(),
+          specifier: "./b.css".into(),
+        },
+      )]),
+      crate::css_modules::Config {
+        dashed_idents: true,
+        ..Default::default()
+      },
     );
+  }
 
+  #[test]
+  fn test_anchor_functions() {
+    minify_test(".foo { top: anchor(--a top) }", ".foo{top:anchor(--a top)}");
+    minify_test(".foo { top: anchor(top --a) }", ".foo{top:anchor(--a top)}");
+    minify_test(".foo { top: anchor(bottom) }", ".foo{top:anchor(bottom)}");
+    minify_test(".foo { left: anchor(--a 50%) }", ".foo{left:anchor(--a 50%)}");
     minify_test(
-      r#"
-    .foo {
-      container-name: foo bar;
-      container-type: size;
-    }
-    "#,
-      ".foo{container:foo bar/size}",
+      ".foo { left: anchor(--a self-start) }",
+      ".foo{left:anchor(--a self-start)}",
     );
+    minify_test(".foo { top: anchor(--a top, 10px) }", ".foo{top:anchor(--a top,10px)}");
     minify_test(
-      r#"
-    .foo {
-      container-name: foo bar;
-      container-type: normal;
-    }
-    "#,
-      ".foo{container:foo bar}",
+      ".foo { top: anchor(--a top, calc(10px + 5px)) }",
+      ".foo{top:anchor(--a top,15px)}",
     );
     minify_test(
-      ".foo{ container-type: inline-size }",
-      ".foo{container-type:inline-size}",
+      ".foo { top: anchor(--a top, anchor(--b bottom)) }",
+      ".foo{top:anchor(--a top,anchor(--b bottom))}",
     );
-    minify_test(".foo{ container-name: none; }", ".foo{container-name:none}");
-    minify_test(".foo{ container-name: foo; }", ".foo{container-name:foo}");
-    minify_test(".foo{ container: foo / normal; }", ".foo{container:foo}");
     minify_test(
-      ".foo{ container: foo / inline-size; }",
-      ".foo{container:foo/inline-size}",
+      ".foo { top: calc(anchor(--a top) + 10px) }",
+      ".foo{top:calc(anchor(--a top) + 10px)}",
     );
-    minify_test(".foo { width: calc(1cqw + 2cqw) }", ".foo{width:3cqw}");
-    minify_test(".foo { width: calc(1cqh + 2cqh) }", ".foo{width:3cqh}");
-    minify_test(".foo { width: calc(1cqi + 2cqi) }", ".foo{width:3cqi}");
-    minify_test(".foo { width: calc(1cqb + 2cqb) }", ".foo{width:3cqb}");
-    minify_test(".foo { width: calc(1cqmin + 2cqmin) }", ".foo{width:3cqmin}");
-    minify_test(".foo { width: calc(1cqmax + 2cqmax) }", ".foo{width:3cqmax}");
-
-    // Unlike in @media, there is no need to convert the range syntax in @container,
-    // because browsers all support this syntax.
-    prefix_test(
-      r#"
-      @container (width > 100px) {
-        .foo { padding: 5px; }
-      }
-      "#,
-      indoc! { r#"
-        @container (width > 100px) {
-          .foo {
-            padding: 5px;
-          }
-        }
-      "#},
-      Browsers {
-        chrome: Some(105 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { top: calc(anchor(--a top) * 2) }",
+      ".foo{top:calc(2*anchor(--a top))}",
     );
-    prefix_test(
-      r#"
-      @container (min-width: 100px) {
-        .foo { padding: 5px; }
-      }
-      "#,
-      indoc! { r#"
-        @container (width >= 100px) {
-          .foo {
-            padding: 5px;
-          }
-        }
-      "#},
-      Browsers {
-        chrome: Some(105 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { width: anchor-size(--a width) }",
+      ".foo{width:anchor-size(--a width)}",
     );
-
     minify_test(
-      r#"
-      @container style(--responsive: true) {
-        .foo {
-          color: red;
-        }
-      }
-    "#,
-      "@container style(--responsive:true){.foo{color:red}}",
+      ".foo { width: anchor-size(width --a) }",
+      ".foo{width:anchor-size(--a width)}",
     );
+    minify_test(".foo { width: anchor-size() }", ".foo{width:anchor-size()}");
+    minify_test(".foo { width: anchor-size(--a) }", ".foo{width:anchor-size(--a)}");
+    minify_test(".foo { width: anchor-size(10px) }", ".foo{width:anchor-size(10px)}");
     minify_test(
-      r#"
-      @container style(--responsive: true) and style(color: yellow) {
-        .foo {
-          color: red;
-        }
-      }
-    "#,
-      "@container style(--responsive:true) and style(color:#ff0){.foo{color:red}}",
+      ".foo { width: anchor-size(self-inline, 10px) }",
+      ".foo{width:anchor-size(self-inline,10px)}",
+    );
+    minify_test(
+      ".foo { max-height: calc(anchor-size(--a height) - 2em) }",
+      ".foo{max-height:calc(anchor-size(--a height) - 2em)}",
+    );
+    minify_test(
+      ".foo { top: anchor(--a top); right: anchor(--a right); bottom: anchor(--a bottom); left: anchor(--a left) }",
+      ".foo{inset:anchor(--a top) anchor(--a right) anchor(--a bottom) anchor(--a left)}",
+    );
+    minify_test(
+      ".foo { inset: anchor(--a bottom) auto auto anchor(--a left) }",
+      ".foo{inset:anchor(--a bottom) auto auto anchor(--a left)}",
     );
+    minify_test(".foo { top: anchor(--a) }", ".foo{top:anchor(--a)}");
+    minify_test(".foo { top: anchor(--a width) }", ".foo{top:anchor(--a width)}");
     minify_test(
+      ".foo { width: anchor-size(--a top) }",
+      ".foo{width:anchor-size(--a top)}",
+    );
+    minify_test(".foo { top: anchor(a top) }", ".foo{top:anchor(a top)}");
+
+    css_modules_test(
       r#"
-      @container not style(--responsive: true) {
-        .foo {
-          color: red;
-        }
+      .foo {
+        width: anchor-size(--b width);
+        top: anchor(--a top);
       }
     "#,
-      "@container not style(--responsive:true){.foo{color:red}}",
+      indoc! {r#"
+      .EgL3uq_foo {
+        width: anchor-size(--EgL3uq_b width);
+        top: anchor(--EgL3uq_a top);
+      }
+    "#},
+      map! {
+        "foo" => "EgL3uq_foo",
+        "--a" => "--EgL3uq_a" referenced: true,
+        "--b" => "--EgL3uq_b" referenced: true
+      },
+      HashMap::new(),
+      crate::css_modules::Config {
+        dashed_idents: true,
+        ..Default::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_position_try_rule() {
+    minify_test(
+      "@position-try --top { bottom: anchor(--a top); left: anchor(--a left) }",
+      "@position-try --top{bottom:anchor(--a top);left:anchor(--a left)}",
     );
     minify_test(
-      r#"
-      @container (inline-size > 45em) and style(--responsive: true) {
-        .foo {
-          color: red;
-        }
-      }
-    "#,
-      "@container (inline-size>45em) and style(--responsive:true){.foo{color:red}}",
+      "@position-try --foo { margin-top: 10px; margin-bottom: 10px; margin-left: 10px; margin-right: 10px }",
+      "@position-try --foo{margin:10px}",
     );
     minify_test(
-      r#"
-      @container style((accent-color: yellow) or (--bar: 10px)) {
-        .foo {
-          color: red;
-        }
-      }
-    "#,
-      "@container style((accent-color:#ff0) or (--bar:10px)){.foo{color:red}}",
+      "@position-try --foo { top: auto; right: auto; bottom: anchor(--a top); left: auto }",
+      "@position-try --foo{inset:auto auto anchor(--a top)}",
     );
     minify_test(
-      r#"
-      @container style(not ((width: calc(10px + 20px)) and ((--bar: url(x))))) {
-        .foo {
-          color: red;
-        }
-      }
-    "#,
-      "@container style(not ((width:30px) and (--bar:url(x)))){.foo{color:red}}",
+      "@position-try --foo { width: 100px; min-block-size: 20px; max-height: anchor-size(--a height) }",
+      "@position-try --foo{width:100px;min-block-size:20px;max-height:anchor-size(--a height)}",
     );
     minify_test(
-      r#"
-      @container style(color: yellow !important) {
-        .foo {
-          color: red;
-        }
-      }
-    "#,
-      "@container style(color:yellow){.foo{color:red}}",
+      "@position-try --foo { align-self: center; justify-self: center }",
+      "@position-try --foo{place-self:center}",
     );
     minify_test(
-      r#"
-      @container style(--foo:) {
-        .foo {
-          color: red;
-        }
-      }
-    "#,
-      "@container style(--foo:){.foo{color:red}}",
+      "@position-try --foo { position-area: top; position-anchor: --a }",
+      "@position-try --foo{position-area:top;position-anchor:--a}",
+    );
+    minify_test(
+      "@position-try --foo { inset-inline-start: anchor(--a end) }",
+      "@position-try --foo{inset-inline-start:anchor(--a end)}",
     );
     minify_test(
+      "@position-try --foo { top: 10px } @position-try --foo { top: 20px }",
+      "@position-try --foo{top:20px}",
+    );
+    minify_test(
+      "@position-try --foo { top: 10px } @position-try --bar { top: 20px }",
+      "@position-try --foo{top:10px}@position-try --bar{top:20px}",
+    );
+    test(
       r#"
-      @container style(--foo: ) {
-        .foo {
-          color: red;
-        }
+      @position-try --foo {
+        margin-inline: 5px;
+        top: anchor(--a bottom);
       }
     "#,
-      "@container style(--foo:){.foo{color:red}}",
+      indoc! {r#"
+      @position-try --foo {
+        margin-inline: 5px;
+        top: anchor(--a bottom);
+      }
+    "#},
     );
-    minify_test(
+    prefix_test(
       r#"
-      @container style(--my-prop: foo - bar ()) {
-        .foo {
-          color: red;
-        }
+      @position-try --foo {
+        inset-inline-start: 10px;
       }
     "#,
-      "@container style(--my-prop:foo - bar ()){.foo{color:red}}",
+      indoc! {r#"
+      @position-try --foo {
+        inset-inline-start: 10px;
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
     );
 
-    // Disallow 'none', 'not', 'and', 'or' as a `<container-name>`
-    // https://github.com/w3c/csswg-drafts/issues/7203#issuecomment-1144257312
-    // https://chromium-review.googlesource.com/c/chromium/src/+/3698402
     error_test(
-      "@container none (width < 100vw) {}",
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("none".into())),
+      "@position-try --foo { color: red }",
+      ParserError::DisallowedProperty("color".into()),
     );
-
     error_test(
-      "@container and (width < 100vw) {}",
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("and".into())),
+      "@position-try --foo { --custom: red }",
+      ParserError::DisallowedProperty("--custom".into()),
     );
-
     error_test(
-      "@container or (width < 100vw) {}",
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("or".into())),
+      "@position-try --foo { top: 10px !important }",
+      ParserError::InvalidDeclaration,
     );
-
-    // Disallow CSS wide keywords as a `<container-name>`
     error_test(
-      "@container revert-layer (width < 100vw) {}",
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("revert-layer".into())),
+      "@position-try foo { top: 10px }",
+      ParserError::UnexpectedToken(Token::Ident("foo".into())),
     );
-
     error_test(
-      "@container initial (width < 100vw) {}",
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("initial".into())),
+      ".foo { @position-try --foo { top: 10px } }",
+      ParserError::AtRuleInvalid("position-try".into()),
     );
 
-    // <ident> contains spaces
-    // https://github.com/web-platform-tests/wpt/blob/39f0da08fbbe33d0582a35749b6dbf8bd067a52d/css/css-contain/container-queries/at-container-parsing.html#L160-L178
-    error_test(
-      "@container foo bar (width < 100vw) {}",
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("bar".into())),
+    use std::sync::{Arc, RwLock};
+    let warnings = Arc::new(RwLock::new(Vec::new()));
+    test_with_options(
+      r#"
+      @position-try --foo {
+        color: red;
+        top: 10px;
+        left: 10px !important;
+      }
+    "#,
+      indoc! {r#"
+      @position-try --foo {
+        top: 10px;
+      }
+    "#},
+      ParserOptions {
+        filename: "test.css".into(),
+        error_recovery: true,
+        warnings: Some(warnings.clone()),
+        ..ParserOptions::default()
+      },
+    );
+    let warnings = warnings.read().unwrap();
+    assert_eq!(
+      *warnings,
+      vec![
+        Error {
+          kind: ParserError::DisallowedProperty("color".into()),
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 2,
+            column: 15
+          })
+        },
+        Error {
+          kind: ParserError::InvalidDeclaration,
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 4,
+            column: 30
+          })
+        },
+      ]
     );
 
-    error_test("@container (inline-size <= foo) {}", ParserError::InvalidMediaQuery);
-    error_test("@container (orientation <= 10px) {}", ParserError::InvalidMediaQuery);
+    css_modules_test(
+      r#"
+      @position-try --foo {
+        top: anchor(--a bottom);
+      }
 
-    error_test("@container style(width) {}", ParserError::EndOfInput);
-    error_test(
-      "@container style(style(--foo: bar)) {}",
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Function("style".into())),
+      .foo {
+        position-try-fallbacks: --foo;
+      }
+    "#,
+      indoc! {r#"
+      @position-try --EgL3uq_foo {
+        top: anchor(--EgL3uq_a bottom);
+      }
+
+      .EgL3uq_foo {
+        position-try-fallbacks: --EgL3uq_foo;
+      }
+    "#},
+      map! {
+        "--foo" => "--EgL3uq_foo",
+        "--a" => "--EgL3uq_a" referenced: true,
+        "foo" => "EgL3uq_foo"
+      },
+      HashMap::new(),
+      crate::css_modules::Config {
+        dashed_idents: true,
+        ..Default::default()
+      },
     );
   }
 
   #[test]
-  fn test_unknown_at_rules() {
-    minify_test("@foo;", "@foo;");
-    minify_test("@foo bar;", "@foo bar;");
-    minify_test("@foo (bar: baz);", "@foo (bar: baz);");
-    test(
-      r#"@foo test {
-      div {
-        color: red;
-      }
-    }"#,
-      indoc! {r#"
-      @foo test {
-        div { color: red; }
-      }
-      "#},
+  fn test_view_transition_properties() {
+    minify_test(".foo { view-transition-name: none }", ".foo{view-transition-name:none}");
+    minify_test(".foo { view-transition-name: auto }", ".foo{view-transition-name:auto}");
+    minify_test(
+      ".foo { view-transition-name: match-element }",
+      ".foo{view-transition-name:match-element}",
     );
+    minify_test(".foo { view-transition-name: hero }", ".foo{view-transition-name:hero}");
     minify_test(
-      r#"@foo test {
-      div {
-        color: red;
-      }
-    }"#,
-      "@foo test{div { color: red; }}",
+      ".foo { view-transition-name: hero card }",
+      ".foo{view-transition-name:hero card}",
     );
     minify_test(
-      r#"@foo test {
-        foo: bar;
-      }"#,
-      "@foo test{foo: bar;}",
+      ".foo { view-transition-class: none }",
+      ".foo{view-transition-class:none}",
     );
-    test(
-      r#"@foo {
-        foo: bar;
-      }"#,
-      indoc! {r#"
-      @foo {
-        foo: bar;
-      }
-      "#},
+    minify_test(
+      ".foo { view-transition-class: card }",
+      ".foo{view-transition-class:card}",
     );
     minify_test(
-      r#"@foo {
-        foo: bar;
-      }"#,
-      "@foo{foo: bar;}",
+      ".foo { view-transition-class: card  slide }",
+      ".foo{view-transition-class:card slide}",
+    );
+    minify_test(
+      ".foo { view-transition-class: card none }",
+      ".foo{view-transition-class:card none}",
+    );
+    minify_test(
+      ".foo { view-transition-class: initial card }",
+      ".foo{view-transition-class:initial card}",
     );
-  }
 
-  #[test]
-  fn test_resolution() {
-    prefix_test(
+    css_modules_test(
       r#"
-      @media (resolution: 1dppx) {
-        body {
-          background: red;
-        }
+      .foo {
+        view-transition-name: hero;
+        view-transition-class: card;
       }
-      "#,
-      indoc! { r#"
-      @media (resolution: 1dppx) {
-        body {
-          background: red;
-        }
+
+      .bar {
+        view-transition-name: none;
       }
-      "#},
-      Browsers {
-        chrome: Some(50 << 16),
-        ..Browsers::default()
-      },
-    );
 
-    prefix_test(
-      r#"
-      @media (resolution: 1dppx) {
-        body {
-          background: red;
-        }
+      ::view-transition-group(hero.card) {
+        animation-duration: 1s;
       }
-      "#,
-      indoc! { r#"
-      @media (resolution: 1x) {
-        body {
-          background: red;
-        }
+
+      ::view-transition-old(*.card) {
+        animation-duration: 1s;
       }
-      "#},
-      Browsers {
-        chrome: Some(95 << 16),
-        ..Browsers::default()
-      },
-    );
-  }
 
-  #[test]
-  fn test_environment() {
-    minify_test(
-      r#"
-      @media (max-width: env(--branding-small)) {
-        body {
-          padding: env(--branding-padding);
-        }
+      ::view-transition-new(other) {
+        animation-duration: 1s;
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_foo {
+        view-transition-name: EgL3uq_hero;
+        view-transition-class: EgL3uq_card;
+      }
+
+      .EgL3uq_bar {
+        view-transition-name: none;
+      }
+
+      ::view-transition-group(EgL3uq_hero.EgL3uq_card) {
+        animation-duration: 1s;
+      }
+
+      ::view-transition-old(*.EgL3uq_card) {
+        animation-duration: 1s;
       }
-    "#,
-      "@media (width<=env(--branding-small)){body{padding:env(--branding-padding)}}",
-    );
 
-    minify_test(
-      r#"
-      @media (max-width: env(--branding-small 1)) {
-        body {
-          padding: env(--branding-padding 2);
-        }
+      ::view-transition-new(EgL3uq_other) {
+        animation-duration: 1s;
       }
-    "#,
-      "@media (width<=env(--branding-small 1)){body{padding:env(--branding-padding 2)}}",
+    "#},
+      map! {
+        "foo" => "EgL3uq_foo",
+        "bar" => "EgL3uq_bar",
+        "hero" => "EgL3uq_hero" referenced: true,
+        "card" => "EgL3uq_card" referenced: true,
+        "other" => "EgL3uq_other" referenced: true
+      },
+      HashMap::new(),
+      Default::default(),
     );
 
-    minify_test(
+    css_modules_test(
       r#"
-      @media (max-width: env(--branding-small 1, 20px)) {
-        body {
-          padding: env(--branding-padding 2, 20px);
-        }
+      .foo {
+        view-transition-name: hero;
       }
-    "#,
-      "@media (width<=env(--branding-small 1,20px)){body{padding:env(--branding-padding 2,20px)}}",
-    );
 
-    minify_test(
-      r#"
-      @media (max-width: env(safe-area-inset-top)) {
-        body {
-          padding: env(safe-area-inset-top);
-        }
+      ::view-transition-group(hero) {
+        animation-duration: 1s;
       }
     "#,
-      "@media (width<=env(safe-area-inset-top)){body{padding:env(safe-area-inset-top)}}",
-    );
+      indoc! {r#"
+      .EgL3uq_foo {
+        view-transition-name: hero;
+      }
 
-    minify_test(
-      r#"
-      @media (max-width: env(unknown)) {
-        body {
-          padding: env(unknown);
-        }
+      ::view-transition-group(hero) {
+        animation-duration: 1s;
       }
-    "#,
-      "@media (width<=env(unknown)){body{padding:env(unknown)}}",
+    "#},
+      map! {
+        "foo" => "EgL3uq_foo"
+      },
+      HashMap::new(),
+      crate::css_modules::Config {
+        custom_idents: false,
+        ..Default::default()
+      },
     );
 
-    prefix_test(
+    css_modules_test(
       r#"
       .foo {
-        color: env(--brand-color, color(display-p3 0 1 0));
+        background-image: paint(checker, 10px);
       }
     "#,
       indoc! {r#"
-      .foo {
-        color: env(--brand-color, #00f942);
-      }
-
-      @supports (color: color(display-p3 0 0 0)) {
-        .foo {
-          color: env(--brand-color, color(display-p3 0 1 0));
-        }
+      .EgL3uq_foo {
+        background-image: paint(checker, 10px);
       }
     "#},
-      Browsers {
-        safari: Some(15 << 16),
-        chrome: Some(90 << 16),
-        ..Browsers::default()
+      map! {
+        "foo" => "EgL3uq_foo"
       },
+      HashMap::new(),
+      Default::default(),
     );
 
     css_modules_test(
       r#"
-      @media (max-width: env(--branding-small)) {
-        .foo {
-          color: env(--brand-color);
-        }
+      .foo {
+        background-image: paint(checker, 10px);
       }
     "#,
       indoc! {r#"
-      @media (width <= env(--EgL3uq_branding-small)) {
-        .EgL3uq_foo {
-          color: env(--EgL3uq_brand-color);
-        }
+      .EgL3uq_foo {
+        background-image: paint(EgL3uq_checker, 10px);
       }
     "#},
       map! {
         "foo" => "EgL3uq_foo",
-        "--brand-color" => "--EgL3uq_brand-color" referenced: true,
-        "--branding-small" => "--EgL3uq_branding-small" referenced: true
+        "checker" => "EgL3uq_checker"
       },
       HashMap::new(),
       crate::css_modules::Config {
-        dashed_idents: true,
+        paint: true,
         ..Default::default()
       },
     );
   }
 
   #[test]
-  fn test_license_comments() {
+  fn test_view_transition_rule() {
+    minify_test(
+      "@view-transition { navigation: auto }",
+      "@view-transition{navigation:auto}",
+    );
+    minify_test(
+      "@view-transition { navigation: none }",
+      "@view-transition{navigation:none}",
+    );
+    minify_test(
+      "@view-transition { navigation: auto; types: slide forwards }",
+      "@view-transition{navigation:auto;types:slide forwards}",
+    );
+    minify_test("@view-transition { types: none }", "@view-transition{types:none}");
     minify_test(
+      "@view-transition { navigation: auto; navigation: none }",
+      "@view-transition{navigation:none}",
+    );
+    minify_test(
+      "@view-transition { navigation: auto } @view-transition { types: slide }",
+      "@view-transition{navigation:auto;types:slide}",
+    );
+    minify_test(
+      "@view-transition { navigation: auto; types: slide } @view-transition { navigation: none }",
+      "@view-transition{types:slide;navigation:none}",
+    );
+    minify_test(
+      "@view-transition { navigation: auto } @media (prefers-reduced-motion) { @view-transition { navigation: none } } @view-transition { types: slide }",
+      "@view-transition{navigation:auto}@media (prefers-reduced-motion){@view-transition{navigation:none}}@view-transition{types:slide}",
+    );
+    minify_test(
+      "@view-transition { navigation: var(--nav) }",
+      "@view-transition{navigation:var(--nav)}",
+    );
+    minify_test(
+      "@view-transition { navigation: auto; foo: bar }",
+      "@view-transition{navigation:auto}",
+    );
+    minify_test(
+      "@view-transition { navigation: always }",
+      "@view-transition{navigation:always}",
+    );
+    minify_test(
+      "@view-transition { types: slide none }",
+      "@view-transition{types:slide none}",
+    );
+    minify_test(
+      "@view-transition { types: -ua-slide }",
+      "@view-transition{types:-ua-slide}",
+    );
+    error_test(
+      ".foo { color: red; @view-transition { navigation: auto } }",
+      ParserError::AtRuleInvalid("view-transition".into()),
+    );
+    test(
       r#"
-      /*! Copyright 2023 Someone awesome */
-      /* Some other comment */
-      .foo {
-        color: red;
+      @view-transition {
+        navigation: auto;
+        types: slide;
       }
     "#,
       indoc! {r#"
-      /*! Copyright 2023 Someone awesome */
-      .foo{color:red}"#},
+      @view-transition {
+        navigation: auto;
+        types: slide;
+      }
+    "#},
     );
 
-    minify_test(
+    css_modules_test(
       r#"
-      /*! Copyright 2023 Someone awesome */
-      /*! Copyright 2023 Someone else */
-      .foo {
-        color: red;
+      @view-transition {
+        navigation: auto;
+        types: slide;
       }
     "#,
       indoc! {r#"
-      /*! Copyright 2023 Someone awesome */
-      /*! Copyright 2023 Someone else */
-      .foo{color:red}"#},
+      @view-transition {
+        navigation: auto;
+        types: slide;
+      }
+    "#},
+      map! {},
+      HashMap::new(),
+      Default::default(),
     );
   }
 
   #[test]
-  fn test_starting_style() {
-    minify_test(
+  fn test_when_else() {
+    fn when_else_test(source: &str, expected: &str, targets: Option<Browsers>) {
+      minify_test_with_parser_and_minify_options(
+        source,
+        expected,
+        ParserOptions {
+          flags: ParserFlags::WHEN_ELSE,
+          ..ParserOptions::default()
+        },
+        MinifyOptions {
+          targets: targets.into(),
+          ..MinifyOptions::default()
+        },
+      );
+    }
+
+    let options = ParserOptions {
+      flags: ParserFlags::WHEN_ELSE,
+      ..ParserOptions::default()
+    };
+
+    test_with_options(
       r#"
-      @starting-style {
-        h1 {
-          background: yellow;
+      @when media(width >= 400px) and media(pointer: fine) and supports(display: flex) {
+        .foo { color: red }
+      } @else supports(caret-color: pink) or (not media(screen and (color))) {
+        .foo { color: green }
+      }
+      @else {
+        .foo { color: blue }
+      }
+    "#,
+      indoc! {r#"
+      @when media(width >= 400px) and media(pointer: fine) and supports(display: flex) {
+        .foo {
+          color: red;
         }
+      } @else supports(caret-color: pink) or (not media(screen and (color))) {
+        .foo {
+          color: green;
+        }
+      } @else {
+        .foo {
+          color: #00f;
+        }
+      }
+    "#},
+      options.clone(),
+    );
+    minify_test_with_options(
+      "@when (media((min-width: 100px)) or supports(selector(:has(a)))) and (not supports((display: grid) and (gap: 1px))) { .foo { color: red } }",
+      "@when (media(width>=100px) or supports(selector(:has(a)))) and (not supports((display:grid) and (gap:1px))){.foo{color:red}}",
+      options.clone(),
+    );
+    minify_test_with_options(
+      "@when media(100px <= width <= 200px) { .foo { color: red } } @else media(print) { .foo { color: green } }",
+      "@when media((100px<=width<=200px)){.foo{color:red}}@else media(print){.foo{color:green}}",
+      options.clone(),
+    );
+    minify_test_with_options(
+      ".foo { @when media(width >= 400px) { color: red } @else { color: green } }",
+      ".foo{@when media(width>=400px){&{color:red}}@else{&{color:green}}}",
+      options.clone(),
+    );
+
+    // Without the flag, the rules are unknown.
+    minify_test(
+      "@when media(width >= 400px) { .foo { color: red } }",
+      "@when media(width>=400px){.foo { color: red }}",
+    );
+
+    // Constant branches.
+    when_else_test(
+      "@when media(all) { .foo { color: red } } @else { .foo { color: green } }",
+      ".foo{color:red}",
+      None,
+    );
+    when_else_test(
+      "@when media(not all) { .foo { color: red } } @else media(print) { .foo { color: green } } @else { .foo { color: blue } }",
+      "@when media(print){.foo{color:green}}@else{.foo{color:#00f}}",
+      None,
+    );
+    when_else_test(
+      "@when media(not all) { .foo { color: red } } @else { .foo { color: green } }",
+      ".foo{color:green}",
+      None,
+    );
+    when_else_test(
+      "@when media(print) { .foo { color: red } } @else media(all) { .foo { color: green } } @else { .foo { color: blue } }",
+      "@when media(print){.foo{color:red}}@else{.foo{color:green}}",
+      None,
+    );
+    when_else_test(
+      "@when media(print) { .foo { color: red } } @else media(screen) {} @else {}",
+      "@when media(print){.foo{color:red}}",
+      None,
+    );
+    when_else_test(
+      "@when media(print) {} @else media(screen) { .foo { color: red } }",
+      "@when media(print){}@else media(screen){.foo{color:red}}",
+      None,
+    );
+    when_else_test("@when media(print) {}", "", None);
+    when_else_test(
+      "@when supports(display: grid) { .foo { display: grid } } @else { .foo { display: block } }",
+      "@when supports(display:grid){.foo{display:grid}}@else{.foo{display:block}}",
+      None,
+    );
+    when_else_test(
+      "@when supports(color: lab(40% 56.6 39)) { .foo { color: red } } @else { .foo { color: green } }",
+      ".foo{color:red}",
+      Some(Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
+      }),
+    );
+    when_else_test(
+      "@when supports(color: lab(40% 56.6 39)) { .foo { color: red } } @else { .foo { color: green } }",
+      ".foo{color:green}",
+      Some(Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      }),
+    );
+    when_else_test(
+      "@when not supports(color: lab(40% 56.6 39)) { .foo { color: red } } @else media(print) { .foo { color: green } }",
+      "@when media(print){.foo{color:green}}",
+      Some(Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
+      }),
+    );
+
+    fn error_test(source: &str, error: ParserError) {
+      let options = ParserOptions {
+        flags: ParserFlags::WHEN_ELSE,
+        ..ParserOptions::default()
+      };
+      match StyleSheet::parse(source, options) {
+        Ok(_) => unreachable!(),
+        Err(e) => assert_eq!(e.kind, error),
+      }
+    }
+    error_test(
+      "@else { .foo { color: red } }",
+      ParserError::AtRuleInvalid("else".into()),
+    );
+    error_test(
+      "@when media(print) {} .foo {} @else { .foo { color: red } }",
+      ParserError::AtRuleInvalid("else".into()),
+    );
+    error_test(
+      "@when media(print) {} @else {} @else { .foo { color: red } }",
+      ParserError::AtRuleInvalid("else".into()),
+    );
+    error_test(
+      "@when media(print) and media(screen) or media(all) {}",
+      ParserError::UnexpectedToken(Token::Ident("or".into())),
+    );
+    error_test(
+      "@when (min-width: 100px) {}",
+      ParserError::UnexpectedToken(Token::Ident("min-width".into())),
+    );
+  }
+
+  #[test]
+  fn test_mixins() {
+    let options = ParserOptions {
+      flags: ParserFlags::MIXINS,
+      ..ParserOptions::default()
+    };
+
+    test_with_options(
+      r#"
+      @function --negate(--value <length>) returns <length> {
+        result: calc(-1 * var(--value));
+      }
+    "#,
+      indoc! {r#"
+      @function --negate(--value <length>) returns <length> {
+        result: calc(-1 * var(--value));
+      }
+    "#},
+      options.clone(),
+    );
+
+    minify_test_with_options(
+      r#"
+      @function --shadow(--color <color>: red, --size type(<length> | auto), --rest) {
+        --double: calc(var(--size) * 2);
+        result: 0 0 var(--double) var(--color);
+        foo: bar baz;
+      }
+    "#,
+      "@function --shadow(--color <color>:red,--size type(<length>|auto),--rest){--double:calc(var(--size)*2);result:0 0 var(--double)var(--color);foo:bar baz}",
+      options.clone(),
+    );
+    minify_test_with_options(
+      "@function --list(--items <length>#) returns type(*) { result: var(--items) }",
+      "@function --list(--items <length>#) returns *{result:var(--items)}",
+      options.clone(),
+    );
+    minify_test_with_options(
+      "@function --empty() { result: 1 }",
+      "@function --empty(){result:1}",
+      options.clone(),
+    );
+
+    minify_test_with_options(
+      r#"
+      @mixin --center {
+        display: flex;
+        align-items: center;
+        & > .child { margin: 0px }
+      }
+    "#,
+      "@mixin --center{align-items:center;display:flex;&>.child{margin:0}}",
+      options.clone(),
+    );
+    minify_test_with_options(
+      r#"
+      @mixin --gap(--size <length>: 8px) {
+        gap: var(--size);
+      }
+    "#,
+      "@mixin --gap(--size <length>:8px){gap:var(--size)}",
+      options.clone(),
+    );
+    minify_test_with_options(
+      r#"
+      .foo {
+        color: red;
+        @apply --center;
+        @apply --gap(16px);
+        @apply --shadow(red, 2px);
       }
-      "#,
-      "@starting-style{h1{background:#ff0}}",
+    "#,
+      ".foo{color:red;@apply --center;@apply --gap(16px);@apply --shadow(red,2px);}",
+      options.clone(),
     );
-    minify_test("@starting-style {}", "");
 
-    nesting_test(
+    // Without the flag, the rules are preserved as unknown at-rules.
+    minify_test(
+      "@function --foo(--x) { result: var(--x) }",
+      "@function --foo(--x){result: var(--x)}",
+    );
+    minify_test(".foo { @apply --center; }", ".foo{@apply --center;}");
+
+    // Function and mixin names are referenced by function tokens and @apply rules, so they are not scoped by CSS modules.
+    let stylesheet = StyleSheet::parse(
       r#"
-      h1 {
-        background: red;
-        @starting-style {
-          background: yellow;
-        }
-      }
-      "#,
-      indoc! {r#"
-      h1 {
-        background: red;
+      @mixin --center {
+        display: flex;
       }
-
-      @starting-style {
-        h1 {
-          background: #ff0;
-        }
+      @function --double(--x) {
+        result: calc(var(--x) * 2);
       }
-      "#},
+    "#,
+      ParserOptions {
+        filename: "test.css".into(),
+        css_modules: Some(crate::css_modules::Config {
+          dashed_idents: true,
+          ..Default::default()
+        }),
+        ..options.clone()
+      },
+    )
+    .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(
+      res.code,
+      "@mixin --center{display:flex}@function --double(--EgL3uq_x){result:calc(var(--EgL3uq_x)*2)}"
     );
+
+    match StyleSheet::parse("@function foo(--x) { result: var(--x) }", options.clone()) {
+      Ok(_) => unreachable!(),
+      Err(e) => assert_eq!(e.kind, ParserError::UnexpectedToken(Token::Function("foo".into()))),
+    }
   }
 
   #[test]
-  fn test_color_scheme() {
-    minify_test(".foo { color-scheme: normal; }", ".foo{color-scheme:normal}");
-    minify_test(".foo { color-scheme: light; }", ".foo{color-scheme:light}");
-    minify_test(".foo { color-scheme: dark; }", ".foo{color-scheme:dark}");
-    minify_test(".foo { color-scheme: light dark; }", ".foo{color-scheme:light dark}");
-    minify_test(".foo { color-scheme: dark light; }", ".foo{color-scheme:light dark}");
-    minify_test(".foo { color-scheme: only light; }", ".foo{color-scheme:light only}");
-    minify_test(".foo { color-scheme: only dark; }", ".foo{color-scheme:dark only}");
+  fn test_text_box() {
+    minify_test(".foo { text-box-trim: trim-start }", ".foo{text-box-trim:trim-start}");
+    minify_test(".foo { text-box-trim: NONE }", ".foo{text-box-trim:none}");
+    minify_test(".foo { text-box-edge: auto }", ".foo{text-box-edge:auto}");
+    minify_test(".foo { text-box-edge: cap }", ".foo{text-box-edge:cap}");
+    minify_test(".foo { text-box-edge: cap text }", ".foo{text-box-edge:cap}");
     minify_test(
-      ".foo { color-scheme: dark light only; }",
-      ".foo{color-scheme:light dark only}",
+      ".foo { text-box-edge: cap alphabetic }",
+      ".foo{text-box-edge:cap alphabetic}",
     );
-    minify_test(".foo { color-scheme: foo bar light; }", ".foo{color-scheme:light}");
+    minify_test(".foo { text-box-edge: text text }", ".foo{text-box-edge:text}");
     minify_test(
-      ".foo { color-scheme: only foo dark bar; }",
-      ".foo{color-scheme:dark only}",
-    );
-    prefix_test(
-      ".foo { color-scheme: dark; }",
-      indoc! { r#"
-      .foo {
-        --lightningcss-light: ;
-        --lightningcss-dark: initial;
-        color-scheme: dark;
-      }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      ".foo { text-box-edge: ideographic ideographic }",
+      ".foo{text-box-edge:ideographic}",
     );
-    prefix_test(
-      ".foo { color-scheme: light; }",
-      indoc! { r#"
-      .foo {
-        --lightningcss-light: initial;
-        --lightningcss-dark: ;
-        color-scheme: light;
-      }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { text-box-edge: ideographic-ink text }",
+      ".foo{text-box-edge:ideographic-ink text}",
     );
-    prefix_test(
-      ".foo { color-scheme: light dark; }",
-      indoc! { r#"
-      .foo {
-        --lightningcss-light: initial;
-        --lightningcss-dark: ;
-        color-scheme: light dark;
-      }
+    minify_test(".foo { text-box-edge: alphabetic }", ".foo{text-box-edge:alphabetic}");
+    minify_test(".foo { text-box-edge: text cap }", ".foo{text-box-edge:text cap}");
 
-      @media (prefers-color-scheme: dark) {
-        .foo {
-          --lightningcss-light: ;
-          --lightningcss-dark: initial;
-        }
-      }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+    minify_test(".foo { text-box: normal }", ".foo{text-box:normal}");
+    minify_test(".foo { text-box: none auto }", ".foo{text-box:normal}");
+    minify_test(".foo { text-box: trim-both }", ".foo{text-box:trim-both}");
+    minify_test(".foo { text-box: trim-both auto }", ".foo{text-box:trim-both}");
+    minify_test(".foo { text-box: cap alphabetic }", ".foo{text-box:cap alphabetic}");
+    minify_test(
+      ".foo { text-box: trim-both cap alphabetic }",
+      ".foo{text-box:cap alphabetic}",
     );
-    prefix_test(
-      ".foo { color-scheme: light dark; }",
-      indoc! { r#"
-      .foo {
-        color-scheme: light dark;
-      }
-      "#},
-      Browsers {
-        firefox: Some(120 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { text-box: cap alphabetic trim-both }",
+      ".foo{text-box:cap alphabetic}",
     );
+    minify_test(".foo { text-box: trim-start ex }", ".foo{text-box:trim-start ex}");
+    minify_test(".foo { text-box: none text }", ".foo{text-box:none text}");
+    minify_test(".foo { text-box: trim-end auto }", ".foo{text-box:trim-end}");
+    minify_test(".foo { text-box: normal auto }", ".foo{text-box:normal auto}");
 
     minify_test(
-      ".foo { color: light-dark(yellow, red); }",
-      ".foo{color:light-dark(#ff0,red)}",
+      ".foo { text-box-trim: trim-both; text-box-edge: cap alphabetic }",
+      ".foo{text-box:cap alphabetic}",
     );
     minify_test(
-      ".foo { color: light-dark(light-dark(yellow, red), light-dark(yellow, red)); }",
-      ".foo{color:light-dark(#ff0,red)}",
+      ".foo { text-box-trim: none; text-box-edge: auto }",
+      ".foo{text-box:normal}",
     );
     minify_test(
-      ".foo { color: light-dark(rgb(0, 0, 255), hsl(120deg, 50%, 50%)); }",
-      ".foo{color:light-dark(#00f,#40bf40)}",
+      ".foo { text-box: normal; text-box-trim: trim-start }",
+      ".foo{text-box:trim-start}",
     );
-    prefix_test(
-      ".foo { color: light-dark(oklch(40% 0.1268735435 34.568626), oklab(59.686% 0.1009 0.1192)); }",
-      indoc! { r#"
-      .foo {
-        color: var(--lightningcss-light, #7e250f) var(--lightningcss-dark, #c65d07);
-        color: var(--lightningcss-light, lab(29.2661% 38.2437 35.3889)) var(--lightningcss-dark, lab(52.2319% 40.1449 59.9171));
-      }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+    minify_test(".foo { text-box: trim-both; text-box-edge: ex }", ".foo{text-box:ex}");
+    minify_test(".foo { text-box-trim: trim-end }", ".foo{text-box-trim:trim-end}");
+    minify_test(
+      ".foo { text-box: trim-both; text-box-edge: var(--edge) }",
+      ".foo{text-box:trim-both;text-box-edge:var(--edge)}",
     );
-    prefix_test(
-      ".foo { color: light-dark(oklch(40% 0.1268735435 34.568626), oklab(59.686% 0.1009 0.1192)); }",
-      indoc! { r#"
-      .foo {
-        color: light-dark(oklch(40% .126874 34.5686), oklab(59.686% .1009 .1192));
-      }
-      "#},
-      Browsers {
-        firefox: Some(120 << 16),
-        ..Browsers::default()
-      },
+  }
+
+  #[test]
+  fn test_initial_letter() {
+    minify_test(".foo { initial-letter: normal }", ".foo{initial-letter:normal}");
+    minify_test(".foo { initial-letter: 3 }", ".foo{initial-letter:3}");
+    minify_test(".foo { initial-letter: 3 drop }", ".foo{initial-letter:3}");
+    minify_test(".foo { initial-letter: drop 3 }", ".foo{initial-letter:3}");
+    minify_test(".foo { initial-letter: 3 3 }", ".foo{initial-letter:3}");
+    minify_test(".foo { initial-letter: 3.5 3 }", ".foo{initial-letter:3.5}");
+    minify_test(".foo { initial-letter: 3 2 }", ".foo{initial-letter:3 2}");
+    minify_test(".foo { initial-letter: 3 raise }", ".foo{initial-letter:3 raise}");
+    minify_test(".foo { initial-letter: raise 2.5 }", ".foo{initial-letter:2.5 raise}");
+    minify_test(".foo { initial-letter: 0.5 }", ".foo{initial-letter:.5}");
+    minify_test(".foo { initial-letter: 3 0 }", ".foo{initial-letter:3 0}");
+    minify_test(".foo { initial-letter: 3 2.5 }", ".foo{initial-letter:3 2.5}");
+    minify_test(
+      ".foo { initial-letter-align: alphabetic }",
+      ".foo{initial-letter-align:alphabetic}",
+    );
+    minify_test(
+      ".foo { initial-letter-align: border-box }",
+      ".foo{initial-letter-align:border-box}",
+    );
+    minify_test(
+      ".foo { initial-letter-align: border-box hanging }",
+      ".foo{initial-letter-align:border-box hanging}",
+    );
+    minify_test(
+      ".foo { initial-letter-align: ideographic border-box }",
+      ".foo{initial-letter-align:ideographic border-box}",
     );
+
     prefix_test(
       r#"
       .foo {
-        box-shadow:
-            oklch(100% 0 0deg / 50%) 0 0.63rem 0.94rem -0.19rem,
-            currentColor 0 0.44rem 0.8rem -0.58rem;
+        initial-letter: 3 2;
       }
     "#,
-      indoc! { r#"
-      .foo {
-        box-shadow: 0 .63rem .94rem -.19rem #ffffff80, 0 .44rem .8rem -.58rem;
-        box-shadow: 0 .63rem .94rem -.19rem lab(100% 0 0 / .5), 0 .44rem .8rem -.58rem;
-      }
-      "#},
-      Browsers {
-        chrome: Some(95 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    prefix_test(
-      ".foo { color: light-dark(var(--light), var(--dark)); }",
-      indoc! { r#"
+      indoc! {r#"
       .foo {
-        color: var(--lightningcss-light, var(--light)) var(--lightningcss-dark, var(--dark));
+        -webkit-initial-letter: 3 2;
+        initial-letter: 3 2;
       }
-      "#},
+    "#},
       Browsers {
-        chrome: Some(90 << 16),
+        safari: Some(17 << 16),
+        chrome: Some(110 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
-      ".foo { color: rgb(from light-dark(yellow, red) r g b / 10%); }",
-      indoc! { r#"
+      r#"
       .foo {
-        color: var(--lightningcss-light, #ffff001a) var(--lightningcss-dark, #ff00001a);
+        -webkit-initial-letter: 3;
+        initial-letter: 3;
       }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
-    );
-    prefix_test(
-      ".foo { color: rgb(from light-dark(yellow, red) r g b / var(--alpha)); }",
-      indoc! { r#"
+    "#,
+      indoc! {r#"
       .foo {
-        color: var(--lightningcss-light, rgb(255 255 0 / var(--alpha))) var(--lightningcss-dark, rgb(255 0 0 / var(--alpha)));
+        initial-letter: 3;
       }
-      "#},
+    "#},
       Browsers {
-        chrome: Some(90 << 16),
+        chrome: Some(110 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
-      ".foo { color: color(from light-dark(yellow, red) srgb r g b / 10%); }",
-      indoc! { r#"
+      r#"
       .foo {
-        color: var(--lightningcss-light, #ffff001a) var(--lightningcss-dark, #ff00001a);
-        color: var(--lightningcss-light, color(srgb 1 1 0 / .1)) var(--lightningcss-dark, color(srgb 1 0 0 / .1));
+        -webkit-initial-letter: 3;
       }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
-    );
-    prefix_test(
-      ".foo { color: color-mix(in srgb, light-dark(yellow, red), light-dark(red, pink)); }",
-      indoc! { r#"
+    "#,
+      indoc! {r#"
       .foo {
-        color: var(--lightningcss-light, #ff8000) var(--lightningcss-dark, #ff6066);
+        -webkit-initial-letter: 3;
       }
-      "#},
+    "#},
       Browsers {
-        chrome: Some(90 << 16),
+        safari: Some(17 << 16),
         ..Browsers::default()
       },
     );
   }
-
-  #[test]
-  fn test_all() {
-    minify_test(".foo { all: initial; all: initial }", ".foo{all:initial}");
-    minify_test(".foo { all: initial; all: revert }", ".foo{all:revert}");
-    minify_test(".foo { background: red; all: revert-layer }", ".foo{all:revert-layer}");
-    minify_test(
-      ".foo { background: red; all: revert-layer; background: green }",
-      ".foo{all:revert-layer;background:green}",
-    );
-    minify_test(
-      ".foo { --test: red; all: revert-layer }",
-      ".foo{--test:red;all:revert-layer}",
-    );
-    minify_test(
-      ".foo { unicode-bidi: embed; all: revert-layer }",
-      ".foo{all:revert-layer;unicode-bidi:embed}",
-    );
-    minify_test(
-      ".foo { direction: rtl; all: revert-layer }",
-      ".foo{all:revert-layer;direction:rtl}",
-    );
-    minify_test(
-      ".foo { direction: rtl; all: revert-layer; direction: ltr }",
-      ".foo{all:revert-layer;direction:ltr}",
-    );
-    minify_test(".foo { background: var(--foo); all: unset; }", ".foo{all:unset}");
-    minify_test(
-      ".foo { all: unset; background: var(--foo); }",
-      ".foo{all:unset;background:var(--foo)}",
-    );
-  }
 }
@@ -0,0 +1,384 @@
+//! Linting.
+//!
+//! The [lint](lint) function inspects a parsed [StyleSheet](crate::stylesheet::StyleSheet)
+//! and returns a list of [diagnostics](Diagnostic), e.g. for obsolete properties and values
+//! that should be replaced with their modern equivalents. Lints with an unambiguous fix
+//! can be applied to the style sheet in place using the [fix](fix) function.
+
+use crate::declaration::DeclarationBlock;
+use crate::properties::custom::{Token, TokenList, TokenOrValue, UnparsedProperty};
+use crate::properties::text::WordBreak;
+use crate::properties::{Property, PropertyId};
+use crate::rules::{style::StyleRule, CssRule, CssRuleList, Location};
+use crate::stylesheet::{ParserOptions, StyleSheet};
+use crate::vendor_prefix::VendorPrefix;
+use std::fmt;
+
+/// A lint reported by [lint](lint).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  any(feature = "serde", feature = "nodejs"),
+  derive(serde::Serialize),
+  serde(tag = "type", rename_all = "camelCase")
+)]
+pub enum Lint {
+  /// An obsolete property or value was used.
+  Deprecated {
+    /// The deprecated property or value, e.g. `word-break: break-word`.
+    feature: String,
+    /// The suggested modern replacement.
+    replacement: String,
+  },
+  /// A declaration is repeated later in the same rule with the same value.
+  DuplicateDeclaration {
+    /// The name of the property.
+    property: String,
+  },
+  /// A zero length has an unnecessary unit.
+  ZeroUnit {
+    /// The name of the property.
+    property: String,
+  },
+  /// A longhand property is overridden by a shorthand later in the same rule.
+  OverriddenByShorthand {
+    /// The name of the overridden longhand property.
+    longhand: String,
+    /// The name of the shorthand property.
+    shorthand: String,
+  },
+}
+
+impl Lint {
+  /// Returns whether the lint can be automatically fixed by [fix](fix).
+  pub fn is_fixable(&self) -> bool {
+    !matches!(self, Lint::Deprecated { .. })
+  }
+}
+
+impl fmt::Display for Lint {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Lint::Deprecated { feature, replacement } => {
+        write!(f, "`{}` is deprecated, use `{}` instead", feature, replacement)
+      }
+      Lint::DuplicateDeclaration { property } => write!(f, "duplicate declaration of `{}`", property),
+      Lint::ZeroUnit { property } => write!(f, "unnecessary unit on zero length in `{}`", property),
+      Lint::OverriddenByShorthand { longhand, shorthand } => {
+        write!(f, "`{}` is overridden by the `{}` shorthand", longhand, shorthand)
+      }
+    }
+  }
+}
+
+/// A diagnostic reported by [lint](lint).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(any(feature = "serde", feature = "nodejs"), derive(serde::Serialize))]
+pub struct Diagnostic {
+  /// The lint that was reported.
+  pub lint: Lint,
+  /// The location of the rule containing the offending declaration.
+  pub loc: Location,
+}
+
+impl fmt::Display for Diagnostic {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    self.lint.fmt(f)
+  }
+}
+
+/// The result of [fix](fix).
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(any(feature = "serde", feature = "nodejs"), derive(serde::Serialize))]
+pub struct FixResult {
+  /// Diagnostics that were fixed.
+  pub fixed: Vec<Diagnostic>,
+  /// Diagnostics that could not be fixed automatically.
+  pub remaining: Vec<Diagnostic>,
+}
+
+/// Lints a style sheet, and returns a list of diagnostics in source order.
+pub fn lint<T>(stylesheet: &StyleSheet<'_, '_, T>) -> Vec<Diagnostic> {
+  let mut diagnostics = Vec::new();
+  lint_rules(&stylesheet.rules, &mut diagnostics);
+  diagnostics
+}
+
+/// Lints a style sheet, and applies fixes for all [fixable](Lint::is_fixable) lints in place.
+///
+/// Duplicate declarations and longhands overridden by a later shorthand are removed,
+/// and units are omitted from zero lengths where this does not change the meaning
+/// of the value. The diagnostics reported by [lint](lint) are split into those that
+/// were fixed and those that remain.
+pub fn fix<T>(stylesheet: &mut StyleSheet<'_, '_, T>) -> FixResult {
+  let mut result = FixResult::default();
+  fix_rules(&mut stylesheet.rules, &mut result);
+  result
+}
+
+fn lint_rules<T>(rules: &CssRuleList<'_, T>, dest: &mut Vec<Diagnostic>) {
+  for rule in &rules.0 {
+    match rule {
+      CssRule::Style(style) => lint_style_rule(style, dest),
+      CssRule::Nesting(nesting) => lint_style_rule(&nesting.style, dest),
+      CssRule::Media(media) => lint_rules(&media.rules, dest),
+      CssRule::Supports(supports) => lint_rules(&supports.rules, dest),
+      CssRule::Container(container) => lint_rules(&container.rules, dest),
+      CssRule::LayerBlock(layer) => lint_rules(&layer.rules, dest),
+      CssRule::MozDocument(document) => lint_rules(&document.rules, dest),
+      CssRule::Scope(scope) => lint_rules(&scope.rules, dest),
+      CssRule::StartingStyle(starting_style) => lint_rules(&starting_style.rules, dest),
+      _ => {}
+    }
+  }
+}
+
+fn lint_style_rule<T>(style: &StyleRule<'_, T>, dest: &mut Vec<Diagnostic>) {
+  lint_deprecated(&style.declarations, style.loc, dest);
+  dest.extend(find_fixes(&style.declarations).into_iter().map(|fix| Diagnostic {
+    lint: fix.lint,
+    loc: style.loc,
+  }));
+  lint_rules(&style.rules, dest);
+}
+
+fn fix_rules<T>(rules: &mut CssRuleList<'_, T>, result: &mut FixResult) {
+  for rule in &mut rules.0 {
+    match rule {
+      CssRule::Style(style) => fix_style_rule(style, result),
+      CssRule::Nesting(nesting) => fix_style_rule(&mut nesting.style, result),
+      CssRule::Media(media) => fix_rules(&mut media.rules, result),
+      CssRule::Supports(supports) => fix_rules(&mut supports.rules, result),
+      CssRule::Container(container) => fix_rules(&mut container.rules, result),
+      CssRule::LayerBlock(layer) => fix_rules(&mut layer.rules, result),
+      CssRule::MozDocument(document) => fix_rules(&mut document.rules, result),
+      CssRule::Scope(scope) => fix_rules(&mut scope.rules, result),
+      CssRule::StartingStyle(starting_style) => fix_rules(&mut starting_style.rules, result),
+      _ => {}
+    }
+  }
+}
+
+fn fix_style_rule<T>(style: &mut StyleRule<'_, T>, result: &mut FixResult) {
+  lint_deprecated(&style.declarations, style.loc, &mut result.remaining);
+
+  let fixes = find_fixes(&style.declarations);
+  let mut removed = Vec::new();
+  for fix in fixes {
+    let list = if fix.important {
+      &mut style.declarations.important_declarations
+    } else {
+      &mut style.declarations.declarations
+    };
+    match fix.replacement {
+      Some(replacement) => list[fix.index] = replacement,
+      None => removed.push((fix.important, fix.index)),
+    }
+    result.fixed.push(Diagnostic {
+      lint: fix.lint,
+      loc: style.loc,
+    });
+  }
+
+  // Fixes are sorted by index within each list, so remove in reverse to keep indices valid.
+  for (important, index) in removed.into_iter().rev() {
+    if important {
+      style.declarations.important_declarations.remove(index);
+    } else {
+      style.declarations.declarations.remove(index);
+    }
+  }
+
+  fix_rules(&mut style.rules, result);
+}
+
+fn lint_deprecated(declarations: &DeclarationBlock, loc: Location, dest: &mut Vec<Diagnostic>) {
+  let has_property = |name: &str| declarations.iter().any(|(property, _)| property.property_id().name() == name);
+
+  for (property, _) in declarations.iter() {
+    let mut deprecated = |feature: String, replacement: &str| {
+      dest.push(Diagnostic {
+        lint: Lint::Deprecated {
+          feature,
+          replacement: replacement.into(),
+        },
+        loc,
+      })
+    };
+
+    let property_id = property.property_id();
+    match property {
+      Property::WordBreak(WordBreak::BreakWord) => {
+        deprecated("word-break: break-word".into(), "overflow-wrap: anywhere");
+      }
+      _ if property_id.name() == "clip" && property_id.prefix().or_none() == VendorPrefix::None => {
+        deprecated("clip".into(), "clip-path");
+      }
+      _ => {}
+    }
+
+    // Legacy flexbox properties are fine as fallbacks alongside the modern equivalent.
+    // `-webkit-box-orient` is also required for `-webkit-line-clamp` to work.
+    let modern = match property_id {
+      PropertyId::BoxOrient(..) if has_property("line-clamp") || has_property("-webkit-line-clamp") => None,
+      PropertyId::BoxOrient(..) | PropertyId::BoxDirection(..) => Some("flex-direction"),
+      PropertyId::BoxOrdinalGroup(..) => Some("order"),
+      PropertyId::BoxAlign(..) => Some("align-items"),
+      PropertyId::BoxFlex(..) | PropertyId::BoxFlexGroup(..) => Some("flex"),
+      PropertyId::BoxPack(..) => Some("justify-content"),
+      PropertyId::BoxLines(..) => Some("flex-wrap"),
+      _ => None,
+    };
+
+    if let Some(modern) = modern {
+      if !has_property(modern) {
+        deprecated(property_name(&property_id), modern);
+      }
+    }
+
+    let tokens = match property {
+      Property::Unparsed(unparsed) => Some(&unparsed.value),
+      Property::Custom(custom) => Some(&custom.value),
+      _ => None,
+    };
+
+    if let Some(tokens) = tokens {
+      if has_function(tokens, "expression") {
+        deprecated("expression()".into(), "calc()");
+      }
+    }
+  }
+}
+
+struct Fix<'i> {
+  important: bool,
+  index: usize,
+  /// The declaration to replace the original with, or `None` to remove it.
+  replacement: Option<Property<'i>>,
+  lint: Lint,
+}
+
+/// Finds fixable lints in a declaration block, sorted by index within
+/// the normal declarations and then the important declarations.
+fn find_fixes<'i>(declarations: &DeclarationBlock<'i>) -> Vec<Fix<'i>> {
+  let mut fixes = Vec::new();
+  find_list_fixes(&declarations.declarations, false, &mut fixes);
+  find_list_fixes(&declarations.important_declarations, true, &mut fixes);
+  fixes
+}
+
+fn find_list_fixes<'i>(list: &[Property<'i>], important: bool, fixes: &mut Vec<Fix<'i>>) {
+  for (index, property) in list.iter().enumerate() {
+    let property_id = property.property_id();
+    let later = &list[index + 1..];
+
+    let (replacement, lint) = if later.contains(property) {
+      (
+        None,
+        Lint::DuplicateDeclaration {
+          property: property_name(&property_id),
+        },
+      )
+    } else if let Some(shorthand) = later
+      .iter()
+      .map(|p| p.property_id())
+      .find(|shorthand| is_overridden_by(&property_id, shorthand))
+    {
+      (
+        None,
+        Lint::OverriddenByShorthand {
+          longhand: property_name(&property_id),
+          shorthand: property_name(&shorthand),
+        },
+      )
+    } else if let Some(unparsed) = match property {
+      Property::Unparsed(unparsed) => remove_zero_units(unparsed),
+      _ => None,
+    } {
+      (
+        Some(Property::Unparsed(unparsed)),
+        Lint::ZeroUnit {
+          property: property_name(&property_id),
+        },
+      )
+    } else {
+      continue;
+    };
+
+    fixes.push(Fix {
+      important,
+      index,
+      replacement,
+      lint,
+    });
+  }
+}
+
+fn is_overridden_by(longhand: &PropertyId, shorthand: &PropertyId) -> bool {
+  shorthand.longhands().map_or(false, |longhands| {
+    longhands.iter().any(|l| l == longhand || is_overridden_by(longhand, l))
+  })
+}
+
+/// Replaces top-level zero lengths in an unparsed value (e.g. one containing `var()`) with `0`.
+/// Lengths within functions are left alone, since a unitless zero is not a length inside `calc()`.
+fn remove_zero_units<'i>(unparsed: &UnparsedProperty<'i>) -> Option<UnparsedProperty<'i>> {
+  let is_zero =
+    |token: &TokenOrValue| matches!(token, TokenOrValue::Length(length) if length.to_unit_value().0 == 0.0);
+  let mut depth = 0;
+  let zeros: Vec<usize> = unparsed
+    .value
+    .0
+    .iter()
+    .enumerate()
+    .filter(|(_, token)| {
+      match token {
+        TokenOrValue::Token(
+          Token::ParenthesisBlock | Token::SquareBracketBlock | Token::CurlyBracketBlock | Token::Function(..),
+        ) => depth += 1,
+        TokenOrValue::Token(Token::CloseParenthesis | Token::CloseSquareBracket | Token::CloseCurlyBracket) => {
+          depth -= 1
+        }
+        _ => {}
+      }
+      depth == 0 && is_zero(token)
+    })
+    .map(|(i, _)| i)
+    .collect();
+
+  // Only omit the unit if the property treats `0` and `0px` the same,
+  // e.g. not `line-height` or `flex`, where a unitless zero is a number.
+  if zeros.is_empty() || !zero_unit_is_equivalent(&unparsed.property_id) {
+    return None;
+  }
+
+  let mut res = unparsed.clone();
+  for i in zeros {
+    res.value.0[i] = TokenOrValue::Token(Token::Number {
+      has_sign: false,
+      value: 0.0,
+      int_value: Some(0),
+    });
+  }
+  Some(res)
+}
+
+fn zero_unit_is_equivalent(property_id: &PropertyId) -> bool {
+  let parse = |value| Property::parse_string(property_id.clone(), value, ParserOptions::default());
+  matches!((parse("0"), parse("0px")), (Ok(a), Ok(b)) if a == b)
+}
+
+fn property_name(property_id: &PropertyId) -> String {
+  let prefix = cssparser::ToCss::to_css_string(&property_id.prefix());
+  format!("{}{}", prefix, property_id.name())
+}
+
+fn has_function(tokens: &TokenList, name: &str) -> bool {
+  tokens.0.iter().any(|token| match token {
+    TokenOrValue::Function(f) => f.name.eq_ignore_ascii_case(name) || has_function(&f.arguments, name),
+    TokenOrValue::Var(v) => v.fallback.as_ref().map_or(false, |fallback| has_function(fallback, name)),
+    TokenOrValue::Env(e) => e.fallback.as_ref().map_or(false, |fallback| has_function(fallback, name)),
+    TokenOrValue::Attr(a) => a.fallback.as_ref().map_or(false, |fallback| has_function(fallback, name)),
+    TokenOrValue::Toggle(t) => t.values.iter().any(|value| has_function(value, name)),
+    _ => false,
+  })
+}
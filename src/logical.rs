@@ -16,6 +16,7 @@ pub enum LogicalGroup {
   BorderStyle,
   BorderWidth,
   BorderRadius,
+  CornerShape,
   Margin,
   ScrollMargin,
   Padding,
@@ -251,7 +251,7 @@ pub(crate) use shorthand_property_bitflags;
 
 macro_rules! shorthand_handler {
   (
-    $name: ident -> $shorthand: ident$(<$l: lifetime>)? $(fallbacks: $shorthand_fallback: literal)?
+    $name: ident -> $shorthand: ident$(<$l: lifetime>)? $(fallbacks: $shorthand_fallback: literal)? $(feature: $shorthand_feature: ident)?
     { $( $key: ident: $prop: ident($type: ty $(, fallback: $fallback: literal)? $(, image: $image: literal)?), )+ }
   ) => {
     crate::macros::shorthand_property_bitflags!($shorthand, $($prop),*);
@@ -325,7 +325,7 @@ macro_rules! shorthand_handler {
           let $key = std::mem::take(&mut self.$key);
         )+
 
-        if $( $key.is_some() && )* true {
+        if $( $key.is_some() && )* $( context.targets.is_compatible(crate::compat::Feature::$shorthand_feature) && )? true {
           #[allow(unused_mut)]
           let mut shorthand = $shorthand {
             $(
@@ -335,10 +335,11 @@ macro_rules! shorthand_handler {
 
           $(
             if $shorthand_fallback && !self.flushed_properties.intersects(paste::paste!([<$shorthand Property>]::$shorthand)) {
-              let fallbacks = shorthand.get_fallbacks(context.targets);
-              for fallback in fallbacks {
-                dest.push(Property::$shorthand(fallback));
-              }
+              let fallbacks = shorthand.get_fallbacks(context.targets, context.gamut_mapping);
+              dest.extend(context.fallbacks(
+                || Property::$shorthand(shorthand.clone()),
+                fallbacks.into_iter().map(Property::$shorthand).collect()
+              ));
             }
           )?
 
@@ -352,10 +353,11 @@ macro_rules! shorthand_handler {
             if let Some(mut val) = $key {
               $(
                 if $fallback && !self.flushed_properties.intersects(paste::paste!([<$shorthand Property>]::$prop)) {
-                  let fallbacks = val.get_fallbacks(context.targets);
-                  for fallback in fallbacks {
-                    dest.push(Property::$prop(fallback));
-                  }
+                  let fallbacks = val.get_fallbacks(context.targets, context.gamut_mapping);
+                  dest.extend(context.fallbacks(
+                    || Property::$prop(val.clone()),
+                    fallbacks.into_iter().map(Property::$prop).collect()
+                  ));
                 }
               )?
 
@@ -610,6 +612,25 @@ macro_rules! define_list_shorthand {
         $key: ident: $prop: ident($type: ty $(, $vp: ty)?),
       )+
     }
+  ) => {
+    define_list_shorthand! {
+      $(#[$outer])*
+      $vis struct $name$(<$l>)?$(($prefix))? for $name {
+        $(
+          $(#[$meta])*
+          $key: $prop($type $(, $vp)?),
+        )+
+      }
+    }
+  };
+  (
+    $(#[$outer:meta])*
+    $vis:vis struct $name: ident$(<$l: lifetime>)?$(($prefix: ty))? for $shorthand: ident {
+      $(
+        $(#[$meta: meta])*
+        $key: ident: $prop: ident($type: ty $(, $vp: ty)?),
+      )+
+    }
   ) => {
     $(#[$outer])*
     #[derive(Debug, Clone, PartialEq)]
@@ -663,7 +684,7 @@ macro_rules! define_list_shorthand {
                 }
               }
             )+
-            Property::$name(val $(, vp_name!($prefix, p))?) => {
+            Property::$shorthand(val $(, vp_name!($prefix, p))?) => {
               $(
                 if *vp_name!($prefix, p) != vendor_prefix {
                   return None
@@ -891,3 +912,12 @@ macro_rules! property_bitflags {
 }
 
 pub(crate) use property_bitflags;
+
+macro_rules! phase {
+  ($name: expr) => {
+    #[cfg(feature = "timings")]
+    let _phase = crate::timings::Phase::start($name);
+  };
+}
+
+pub(crate) use phase;
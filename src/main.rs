@@ -1,7 +1,9 @@
 use atty::Stream;
 use clap::{ArgGroup, Parser};
 use lightningcss::bundler::{Bundler, FileProvider};
-use lightningcss::stylesheet::{MinifyOptions, ParserFlags, ParserOptions, PrinterOptions, StyleSheet};
+use lightningcss::stylesheet::{
+  MinifyOptions, ParserFlags, ParserOptions, PrinterOptions, SourceMapUrl, StyleSheet,
+};
 use lightningcss::targets::Browsers;
 use parcel_sourcemap::SourceMap;
 use serde::Serialize;
@@ -9,10 +11,20 @@ use std::borrow::Cow;
 use std::sync::{Arc, RwLock};
 use std::{ffi, fs, io, path::Path};
 
-#[cfg(target_os = "macos")]
+#[cfg(all(target_os = "macos", not(feature = "timings")))]
 #[global_allocator]
 static GLOBAL: jemallocator::Jemalloc = jemallocator::Jemalloc;
 
+#[cfg(all(target_os = "macos", feature = "timings"))]
+#[global_allocator]
+static GLOBAL: lightningcss::timings::CountingAllocator<jemallocator::Jemalloc> =
+  lightningcss::timings::CountingAllocator(jemallocator::Jemalloc);
+
+#[cfg(all(not(target_os = "macos"), feature = "timings"))]
+#[global_allocator]
+static GLOBAL: lightningcss::timings::CountingAllocator<std::alloc::System> =
+  lightningcss::timings::CountingAllocator(std::alloc::System);
+
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
 #[clap(group(
@@ -32,6 +44,9 @@ struct CliArgs {
   /// Minify the output
   #[clap(short, long, value_parser)]
   minify: bool,
+  /// Maximum number of decimal places to output numbers with
+  #[clap(long, value_parser)]
+  precision: Option<u8>,
   /// Enable parsing CSS nesting
   // Now on by default, but left for backward compatibility.
   #[clap(long, value_parser, hide = true)]
@@ -39,6 +54,12 @@ struct CliArgs {
   /// Enable parsing custom media queries
   #[clap(long, value_parser)]
   custom_media: bool,
+  /// Enable parsing @when and @else rules
+  #[clap(long, value_parser)]
+  when_else: bool,
+  /// Enable parsing @function, @mixin, and @apply rules
+  #[clap(long, value_parser)]
+  mixins: bool,
   /// Enable CSS modules in output.
   /// If no filename is provided, <output_file>.json will be used.
   /// If no --output-file is specified, code and exports will be printed to stdout as JSON.
@@ -59,6 +80,10 @@ struct CliArgs {
   browserslist: bool,
   #[clap(long, value_parser)]
   error_recovery: bool,
+  /// Print the time and allocations spent in each phase to stderr.
+  /// Requires lightningcss to be built with the timings feature.
+  #[clap(long, value_parser)]
+  timings: bool,
 }
 
 #[derive(Serialize)]
@@ -75,6 +100,16 @@ pub fn main() -> Result<(), std::io::Error> {
   let cli_args = CliArgs::parse();
   let project_root = std::env::current_dir()?;
 
+  if cli_args.timings {
+    #[cfg(feature = "timings")]
+    lightningcss::timings::start();
+    #[cfg(not(feature = "timings"))]
+    {
+      eprintln!("The --timings option requires lightningcss to be built with the timings feature.");
+      std::process::exit(1);
+    }
+  }
+
   // If we're given an input file, read from it and adjust its name.
   //
   // If we're not given an input file and stdin was redirected, read
@@ -163,9 +198,17 @@ pub fn main() -> Result<(), std::io::Error> {
       None
     };
 
+    let map_filename = if cli_args.sourcemap {
+      output_file.as_ref().map(|output_file| output_file.to_string_lossy() + ".map")
+    } else {
+      None
+    };
+
     let res = {
       let mut flags = ParserFlags::empty();
       flags.set(ParserFlags::CUSTOM_MEDIA, cli_args.custom_media);
+      flags.set(ParserFlags::WHEN_ELSE, cli_args.when_else);
+      flags.set(ParserFlags::MIXINS, cli_args.mixins);
 
       let mut options = ParserOptions {
         flags,
@@ -209,12 +252,16 @@ pub fn main() -> Result<(), std::io::Error> {
           source_map: source_map.as_mut(),
           project_root: Some(&project_root.to_string_lossy()),
           targets,
+          source_map_url: map_filename.as_deref().map(SourceMapUrl::Url),
+          precision: cli_args.precision,
           ..PrinterOptions::default()
         })
         .unwrap()
     };
 
     let map = if let Some(ref mut source_map) = source_map {
+      #[cfg(feature = "timings")]
+      let _phase = lightningcss::timings::Phase::start("source map");
       let mut vlq_output: Vec<u8> = Vec::new();
       source_map
         .write_vlq(&mut vlq_output)
@@ -241,19 +288,14 @@ pub fn main() -> Result<(), std::io::Error> {
     }
 
     if let Some(output_file) = &output_file {
-      let mut code = res.code;
-      if cli_args.sourcemap {
-        if let Some(map_buf) = map {
-          let map_filename = output_file.to_string_lossy() + ".map";
-          code += &format!("\n/*# sourceMappingURL={} */\n", map_filename);
-          fs::write(map_filename.as_ref(), map_buf)?;
-        }
+      if let (Some(map_filename), Some(map_buf)) = (&map_filename, map) {
+        fs::write(map_filename.as_ref(), map_buf)?;
       }
 
       if let Some(p) = output_file.parent() {
         fs::create_dir_all(p)?
       };
-      fs::write(output_file, code.as_bytes())?;
+      fs::write(output_file, res.code.as_bytes())?;
 
       if let Some(css_modules) = &cli_args.css_modules {
         let css_modules_filename = if let Some(name) = css_modules {
@@ -281,6 +323,11 @@ pub fn main() -> Result<(), std::io::Error> {
     }
   }
 
+  #[cfg(feature = "timings")]
+  if cli_args.timings {
+    eprint!("{}", lightningcss::timings::finish());
+  }
+
   Ok(())
 }
 
@@ -332,6 +332,73 @@ impl<'i> MediaQuery<'i> {
     }
   }
 
+  /// Creates a media query for the given media type, with no qualifier or condition.
+  pub fn new(media_type: MediaType<'i>) -> Self {
+    MediaQuery {
+      qualifier: None,
+      media_type,
+      condition: None,
+    }
+  }
+
+  /// Creates a media query that matches the given condition, e.g. `(width >= 640px)`.
+  pub fn from_condition(condition: MediaCondition<'i>) -> Self {
+    MediaQuery {
+      qualifier: None,
+      media_type: MediaType::All,
+      condition: Some(condition),
+    }
+  }
+
+  /// Adds a feature to the media query. The resulting media query matches
+  /// if both the original condition and the feature match.
+  pub fn add_feature(&mut self, feature: MediaFeature<'i>) {
+    self.add_condition(MediaCondition::Feature(feature))
+  }
+
+  /// Adds a condition to the media query. The resulting media query matches
+  /// if both the original condition and the given condition match.
+  pub fn add_condition(&mut self, condition: MediaCondition<'i>) {
+    self.condition = Some(match self.condition.take() {
+      Some(cur) => cur.and(condition),
+      None => condition,
+    });
+  }
+
+  /// Removes all features with the given name from the media query.
+  /// Returns whether any features were removed.
+  pub fn remove_feature(&mut self, name: &MediaFeatureName<'i, MediaFeatureId>) -> bool {
+    let mut removed = false;
+    self.retain_features(|feature| {
+      if feature.name() == name {
+        removed = true;
+        false
+      } else {
+        true
+      }
+    });
+    removed
+  }
+
+  /// Retains only the features for which the predicate returns `true`.
+  /// Operations and negations that become empty are removed as well.
+  pub fn retain_features<F: FnMut(&MediaFeature<'i>) -> bool>(&mut self, mut f: F) {
+    if let Some(condition) = &mut self.condition {
+      if !condition.retain_features(&mut f) {
+        self.condition = None;
+      }
+    }
+  }
+
+  /// Returns an iterator over all of the features within the media query condition.
+  pub fn features(&self) -> impl Iterator<Item = &MediaFeature<'i>> {
+    let mut features = Vec::new();
+    if let Some(condition) = &self.condition {
+      condition.collect_features(&mut features);
+    }
+    features.into_iter()
+  }
+
   /// Returns whether the media query is guaranteed to always match.
   pub fn always_matches(&self) -> bool {
     self.qualifier == None && self.media_type == MediaType::All && self.condition == None
@@ -613,11 +680,7 @@ impl<'i> MediaCondition<'i> {
               _ => unreachable!(),
             }),
             operator: *operator,
-            value: MediaFeatureValue::Number(match value {
-              Resolution::Dpi(dpi) => *dpi / 96.0,
-              Resolution::Dpcm(dpcm) => *dpcm * 2.54 / 96.0,
-              Resolution::Dppx(dppx) => *dppx,
-            }),
+            value: MediaFeatureValue::Number(value.to_dppx()),
           });
         }
         _ => {}
@@ -633,6 +696,79 @@ impl<'i> MediaCondition<'i> {
   }
 }
 
+impl<'i> MediaCondition<'i> {
+  /// Creates a negation of the given condition, e.g. `not (hover)`.
+  pub fn negate(condition: MediaCondition<'i>) -> Self {
+    MediaCondition::Not(Box::new(condition))
+  }
+
+  /// Combines this condition with another using the `and` operator.
+  /// If this condition is already an `and` operation, the other condition is appended to it.
+  pub fn and(self, other: MediaCondition<'i>) -> Self {
+    self.combine(Operator::And, other)
+  }
+
+  /// Combines this condition with another using the `or` operator.
+  /// If this condition is already an `or` operation, the other condition is appended to it.
+  pub fn or(self, other: MediaCondition<'i>) -> Self {
+    self.combine(Operator::Or, other)
+  }
+
+  fn combine(self, op: Operator, other: MediaCondition<'i>) -> Self {
+    match self {
+      MediaCondition::Operation {
+        operator,
+        mut conditions,
+      } if operator == op => {
+        conditions.push(other);
+        MediaCondition::Operation { operator, conditions }
+      }
+      _ => MediaCondition::Operation {
+        operator: op,
+        conditions: vec![self, other],
+      },
+    }
+  }
+
+  /// Retains only the features for which the predicate returns `true`.
+  /// Returns `false` if the entire condition was removed.
+  pub fn retain_features<F: FnMut(&MediaFeature<'i>) -> bool>(&mut self, f: &mut F) -> bool {
+    match self {
+      MediaCondition::Feature(feature) => f(feature),
+      MediaCondition::Not(condition) => condition.retain_features(f),
+      MediaCondition::Operation { conditions, .. } => {
+        conditions.retain_mut(|condition| condition.retain_features(f));
+        match conditions.len() {
+          0 => false,
+          1 => {
+            *self = conditions.pop().unwrap();
+            true
+          }
+          _ => true,
+        }
+      }
+    }
+  }
+
+  fn collect_features<'a>(&'a self, features: &mut Vec<&'a MediaFeature<'i>>) {
+    match self {
+      MediaCondition::Feature(feature) => features.push(feature),
+      MediaCondition::Not(condition) => condition.collect_features(features),
+      MediaCondition::Operation { conditions, .. } => {
+        for condition in conditions {
+          condition.collect_features(features);
+        }
+      }
+    }
+  }
+}
+
+impl<'i> From<MediaFeature<'i>> for MediaCondition<'i> {
+  fn from(feature: MediaFeature<'i>) -> Self {
+    MediaCondition::Feature(feature)
+  }
+}
+
 impl<'i> Parse<'i> for MediaCondition<'i> {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     Self::parse_with_flags(input, QueryConditionFlags::ALLOW_OR)
@@ -900,6 +1036,69 @@ pub enum QueryFeature<'i, FeatureId> {
 /// A [media feature](https://drafts.csswg.org/mediaqueries/#typedef-media-feature)
 pub type MediaFeature<'i> = QueryFeature<'i, MediaFeatureId>;
 
+impl<'i, FeatureId> QueryFeature<'i, FeatureId> {
+  /// Creates a plain feature, e.g. `(orientation: landscape)`.
+  pub fn plain(name: FeatureId, value: MediaFeatureValue<'i>) -> Self {
+    QueryFeature::Plain {
+      name: MediaFeatureName::Standard(name),
+      value,
+    }
+  }
+
+  /// Creates a boolean feature, e.g. `(hover)`.
+  pub fn boolean(name: FeatureId) -> Self {
+    QueryFeature::Boolean {
+      name: MediaFeatureName::Standard(name),
+    }
+  }
+
+  /// Creates a range feature, e.g. `(width >= 640px)`.
+  pub fn range(name: FeatureId, operator: MediaFeatureComparison, value: MediaFeatureValue<'i>) -> Self {
+    QueryFeature::Range {
+      name: MediaFeatureName::Standard(name),
+      operator,
+      value,
+    }
+  }
+
+  /// Creates an interval feature, e.g. `(640px <= width < 1024px)`.
+  pub fn interval(
+    name: FeatureId,
+    start: MediaFeatureValue<'i>,
+    start_operator: MediaFeatureComparison,
+    end: MediaFeatureValue<'i>,
+    end_operator: MediaFeatureComparison,
+  ) -> Self {
+    QueryFeature::Interval {
+      name: MediaFeatureName::Standard(name),
+      start,
+      start_operator,
+      end,
+      end_operator,
+    }
+  }
+
+  /// Returns the name of the feature.
+  pub fn name(&self) -> &MediaFeatureName<'i, FeatureId> {
+    match self {
+      QueryFeature::Plain { name, .. }
+      | QueryFeature::Boolean { name }
+      | QueryFeature::Range { name, .. }
+      | QueryFeature::Interval { name, .. } => name,
+    }
+  }
+
+  /// Returns a mutable reference to the name of the feature.
+  pub fn name_mut(&mut self) -> &mut MediaFeatureName<'i, FeatureId> {
+    match self {
+      QueryFeature::Plain { name, .. }
+      | QueryFeature::Boolean { name }
+      | QueryFeature::Range { name, .. }
+      | QueryFeature::Interval { name, .. } => name,
+    }
+  }
+}
+
 impl<'i, FeatureId> Parse<'i> for QueryFeature<'i, FeatureId>
 where
   FeatureId: for<'x> Parse<'x> + std::fmt::Debug + PartialEq + ValueType,
@@ -1820,4 +2019,57 @@ mod tests {
       "screen and not ((min-width: 200px) and (max-width: 499.999px))"
     );
   }
+
+  #[test]
+  fn test_construct() {
+    use crate::values::length::{Length, LengthValue};
+
+    let mut query = MediaQuery::new(MediaType::Screen);
+    query.add_feature(MediaFeature::range(
+      MediaFeatureId::Width,
+      MediaFeatureComparison::GreaterThanEqual,
+      MediaFeatureValue::Length(Length::Value(LengthValue::Px(640.0))),
+    ));
+    query.add_feature(MediaFeature::boolean(MediaFeatureId::Hover));
+    assert_eq!(
+      query.to_css_string(PrinterOptions::default()).unwrap(),
+      "screen and (width >= 640px) and (hover)"
+    );
+    assert_eq!(query.features().count(), 2);
+
+    assert!(query.remove_feature(&MediaFeatureName::Standard(MediaFeatureId::Width)));
+    assert!(!query.remove_feature(&MediaFeatureName::Standard(MediaFeatureId::Width)));
+    assert_eq!(
+      query.to_css_string(PrinterOptions::default()).unwrap(),
+      "screen and (hover)"
+    );
+
+    query.remove_feature(&MediaFeatureName::Standard(MediaFeatureId::Hover));
+    assert_eq!(query.to_css_string(PrinterOptions::default()).unwrap(), "screen");
+
+    let condition = MediaCondition::from(MediaFeature::plain(
+      MediaFeatureId::Orientation,
+      MediaFeatureValue::Ident("landscape".into()),
+    ))
+    .or(MediaCondition::negate(
+      MediaFeature::boolean(MediaFeatureId::Hover).into(),
+    ));
+    let mut query = MediaQuery::from_condition(condition);
+    assert_eq!(
+      query.to_css_string(PrinterOptions::default()).unwrap(),
+      "(orientation: landscape) or (not (hover))"
+    );
+
+    query.add_feature(MediaFeature::boolean(MediaFeatureId::Color));
+    assert_eq!(
+      query.to_css_string(PrinterOptions::default()).unwrap(),
+      "((orientation: landscape) or (not (hover))) and (color)"
+    );
+
+    query.retain_features(|feature| feature.name() != &MediaFeatureName::Standard(MediaFeatureId::Hover));
+    assert_eq!(
+      query.to_css_string(PrinterOptions::default()).unwrap(),
+      "(orientation: landscape) and (color)"
+    );
+  }
 }
@@ -3,12 +3,15 @@ use crate::error::{Error, ParserError, PrinterError};
 use crate::media_query::*;
 use crate::printer::Printer;
 use crate::properties::custom::TokenList;
+use crate::rules::color_profile::{ColorProfileName, ColorProfileRule};
 use crate::rules::container::{ContainerCondition, ContainerName, ContainerRule};
 use crate::rules::font_palette_values::FontPaletteValuesRule;
 use crate::rules::layer::{LayerBlockRule, LayerStatementRule};
+use crate::rules::position_try::PositionTryRule;
 use crate::rules::property::PropertyRule;
 use crate::rules::scope::ScopeRule;
 use crate::rules::starting_style::StartingStyleRule;
+use crate::rules::view_transition::ViewTransitionRule;
 use crate::rules::viewport::ViewportRule;
 
 use crate::rules::{
@@ -16,28 +19,33 @@ use crate::rules::{
   custom_media::CustomMediaRule,
   document::MozDocumentRule,
   font_face::{FontFaceDeclarationParser, FontFaceRule},
+  function::{FunctionParameter, FunctionRule},
   import::ImportRule,
   keyframes::{KeyframeListParser, KeyframesName, KeyframesRule},
   layer::LayerName,
   media::MediaRule,
+  mixin::{ApplyRule, MixinRule},
   namespace::NamespaceRule,
   nesting::NestingRule,
   page::{PageRule, PageSelector},
   style::StyleRule,
   supports::{SupportsCondition, SupportsRule},
   unknown::UnknownAtRule,
+  when::{ElseRule, WhenCondition, WhenRule},
   CssRule, CssRuleList, Location,
 };
-use crate::selector::{Component, SelectorList, SelectorParser};
+use crate::selector::{Component, CustomPseudoSyntax, SelectorList, SelectorParser};
 use crate::traits::Parse;
 use crate::values::ident::{CustomIdent, DashedIdent};
 use crate::values::string::CowArcStr;
+use crate::values::syntax::SyntaxString;
 use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
 use crate::visitor::{Visit, VisitTypes, Visitor};
 use bitflags::bitflags;
 use cssparser::*;
 use parcel_selectors::parser::{NestingRequirement, ParseErrorRecovery};
+use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
 bitflags! {
@@ -50,6 +58,10 @@ bitflags! {
     const CUSTOM_MEDIA = 1 << 1;
     /// Whether to enable the non-standard >>> and /deep/ selector combinators used by Vue and Angular.
     const DEEP_SELECTOR_COMBINATOR = 1 << 2;
+    /// Whether to enable the [@when and @else](https://drafts.csswg.org/css-conditional-5/#when-rule) draft syntax.
+    const WHEN_ELSE = 1 << 3;
+    /// Whether to enable the [@function, @mixin, and @apply](https://drafts.csswg.org/css-mixins-1/) draft syntax.
+    const MIXINS = 1 << 4;
   }
 }
 
@@ -69,6 +81,12 @@ pub struct ParserOptions<'o, 'i> {
   pub warnings: Option<Arc<RwLock<Vec<Error<ParserError<'i>>>>>>,
   /// Feature flags to enable.
   pub flags: ParserFlags,
+  /// Non-standard pseudo classes to accept, keyed by lowercase name without the leading colon,
+  /// along with the syntax of their arguments.
+  pub custom_pseudo_classes: HashMap<String, CustomPseudoSyntax>,
+  /// Non-standard pseudo elements to accept, keyed by lowercase name without the leading colons,
+  /// along with the syntax of their arguments.
+  pub custom_pseudo_elements: HashMap<String, CustomPseudoSyntax>,
 }
 
 impl<'o, 'i> ParserOptions<'o, 'i> {
@@ -175,6 +193,8 @@ pub enum AtRulePrelude<'i, T> {
   FontFeatureValues, //(Vec<FamilyName>),
   /// A @font-palette-values rule prelude, with its name.
   FontPaletteValues(DashedIdent<'i>),
+  /// A @color-profile rule prelude, with its name.
+  ColorProfile(ColorProfileName<'i>),
   /// A @counter-style rule prelude, with its counter style name.
   CounterStyle(CustomIdent<'i>),
   /// A @media rule prelude, with its media queries.
@@ -212,6 +232,20 @@ pub enum AtRulePrelude<'i, T> {
   Container(Option<ContainerName<'i>>, ContainerCondition<'i>),
   /// A @starting-style prelude.
   StartingStyle,
+  /// A @position-try prelude.
+  PositionTry(DashedIdent<'i>),
+  /// A @view-transition prelude.
+  ViewTransition,
+  /// A @when rule prelude, with its condition.
+  When(WhenCondition<'i>),
+  /// An @else rule prelude, with its optional condition.
+  Else(Option<WhenCondition<'i>>),
+  /// A @function rule prelude, with its name, parameters, and return type.
+  Function(DashedIdent<'i>, Vec<FunctionParameter<'i>>, Option<SyntaxString>),
+  /// A @mixin rule prelude, with its name and optional parameters.
+  Mixin(DashedIdent<'i>, Option<Vec<FunctionParameter<'i>>>),
+  /// An @apply rule prelude, with the mixin name and optional arguments.
+  Apply(DashedIdent<'i>, Option<Vec<TokenList<'i>>>),
   /// A @scope rule prelude.
   Scope(Option<SelectorList<'i>>, Option<SelectorList<'i>>),
   /// An unknown prelude.
@@ -234,6 +268,9 @@ impl<'i, T> AtRulePrelude<'i, T> {
       | Self::Layer(..)
       | Self::StartingStyle
       | Self::Scope(..)
+      | Self::When(..)
+      | Self::Else(..)
+      | Self::Apply(..)
       | Self::Nest(..)
       | Self::Unknown(..)
       | Self::Custom(..) => true,
@@ -242,10 +279,15 @@ impl<'i, T> AtRulePrelude<'i, T> {
       | Self::FontFace
       | Self::FontFeatureValues
       | Self::FontPaletteValues(..)
+      | Self::ColorProfile(..)
       | Self::CounterStyle(..)
       | Self::Keyframes(..)
       | Self::Page(..)
       | Self::Property(..)
+      | Self::PositionTry(..)
+      | Self::ViewTransition
+      | Self::Function(..)
+      | Self::Mixin(..)
       | Self::Import(..)
       | Self::CustomMedia(..)
       | Self::Viewport(..)
@@ -574,6 +616,10 @@ impl<'a, 'o, 'b, 'i, T: crate::traits::AtRuleParser<'i>> AtRuleParser<'i> for Ne
         let name = DashedIdent::parse(input)?;
         AtRulePrelude::FontPaletteValues(name)
       },
+      "color-profile" => {
+        let name = ColorProfileName::parse(input)?;
+        AtRulePrelude::ColorProfile(name)
+      },
       "counter-style" => {
         let name = CustomIdent::parse(input)?;
         AtRulePrelude::CounterStyle(name)
@@ -642,6 +688,50 @@ impl<'a, 'o, 'b, 'i, T: crate::traits::AtRuleParser<'i>> AtRuleParser<'i> for Ne
       "starting-style" => {
         AtRulePrelude::StartingStyle
       },
+      "position-try" => {
+        let name = DashedIdent::parse(input)?;
+        AtRulePrelude::PositionTry(name)
+      },
+      "view-transition" => {
+        AtRulePrelude::ViewTransition
+      },
+      "when" if self.options.flags.contains(ParserFlags::WHEN_ELSE) => {
+        let condition = WhenCondition::parse(input)?;
+        AtRulePrelude::When(condition)
+      },
+      "else" if self.options.flags.contains(ParserFlags::WHEN_ELSE) => {
+        // An @else rule is only valid immediately following a @when or another @else rule.
+        if !matches!(self.rules.0.last(), Some(CssRule::When(rule)) if rule.accepts_else()) {
+          return Err(input.new_error(BasicParseErrorKind::AtRuleInvalid(name.clone())));
+        }
+
+        let condition = if input.is_exhausted() {
+          None
+        } else {
+          Some(WhenCondition::parse(input)?)
+        };
+        AtRulePrelude::Else(condition)
+      },
+      "function" if self.options.flags.contains(ParserFlags::MIXINS) => {
+        let (name, parameters, returns) = FunctionRule::parse_prelude(input, self.options)?;
+        AtRulePrelude::Function(name, parameters, returns)
+      },
+      "mixin" if self.options.flags.contains(ParserFlags::MIXINS) => {
+        let location = input.current_source_location();
+        let (name, parameters) = match input.next()?.clone() {
+          Token::Ident(name) if name.starts_with("--") => (name, None),
+          Token::Function(name) if name.starts_with("--") => {
+            let parameters = input.parse_nested_block(|input| FunctionParameter::parse_list(input, self.options))?;
+            (name, Some(parameters))
+          },
+          t => return Err(location.new_unexpected_token_error(t))
+        };
+        AtRulePrelude::Mixin(DashedIdent(name.into()), parameters)
+      },
+      "apply" if self.is_in_style_rule && self.options.flags.contains(ParserFlags::MIXINS) => {
+        let (name, arguments) = ApplyRule::parse_prelude(input, self.options)?;
+        AtRulePrelude::Apply(name, arguments)
+      },
       "scope" => {
         let selector_parser = SelectorParser {
           is_nesting_allowed: true,
@@ -729,12 +819,14 @@ impl<'a, 'o, 'b, 'i, T: crate::traits::AtRuleParser<'i>> AtRuleParser<'i> for Ne
         self.rules.0.push(CssRule::FontPaletteValues(rule));
         Ok(())
       }
+      AtRulePrelude::ColorProfile(name) => {
+        let rule = ColorProfileRule::parse(name, input, loc)?;
+        self.rules.0.push(CssRule::ColorProfile(rule));
+        Ok(())
+      }
       AtRulePrelude::CounterStyle(name) => {
-        self.rules.0.push(CssRule::CounterStyle(CounterStyleRule {
-          name,
-          declarations: DeclarationBlock::parse(input, self.options)?,
-          loc,
-        }));
+        let rule = CounterStyleRule::parse(name, input, loc)?;
+        self.rules.0.push(CssRule::CounterStyle(rule));
         Ok(())
       }
       AtRulePrelude::Media(query) => {
@@ -818,6 +910,7 @@ impl<'a, 'o, 'b, 'i, T: crate::traits::AtRuleParser<'i>> AtRuleParser<'i> for Ne
       AtRulePrelude::Import(..)
       | AtRulePrelude::Namespace(..)
       | AtRulePrelude::CustomMedia(..)
+      | AtRulePrelude::Apply(..)
       | AtRulePrelude::Charset => {
         // These rules don't have blocks.
         Err(input.new_unexpected_token_error(Token::CurlyBracketBlock))
@@ -827,6 +920,52 @@ impl<'a, 'o, 'b, 'i, T: crate::traits::AtRuleParser<'i>> AtRuleParser<'i> for Ne
         self.rules.0.push(CssRule::StartingStyle(StartingStyleRule { rules, loc }));
         Ok(())
       }
+      AtRulePrelude::PositionTry(name) => {
+        let rule = PositionTryRule::parse(name, input, loc, self.options)?;
+        self.rules.0.push(CssRule::PositionTry(rule));
+        Ok(())
+      }
+      AtRulePrelude::ViewTransition => {
+        let rule = ViewTransitionRule::parse(input, loc)?;
+        self.rules.0.push(CssRule::ViewTransition(rule));
+        Ok(())
+      }
+      AtRulePrelude::When(condition) => {
+        let rules = self.parse_style_block(input)?;
+        self.rules.0.push(CssRule::When(WhenRule {
+          condition,
+          rules,
+          else_rules: Vec::new(),
+          loc,
+        }));
+        Ok(())
+      }
+      AtRulePrelude::Else(condition) => {
+        let rules = self.parse_style_block(input)?;
+        match self.rules.0.last_mut() {
+          Some(CssRule::When(rule)) => {
+            rule.else_rules.push(ElseRule { condition, rules, loc });
+            Ok(())
+          }
+          _ => Err(input.new_custom_error(ParserError::AtRuleBodyInvalid)),
+        }
+      }
+      AtRulePrelude::Function(name, parameters, returns) => {
+        let rule = FunctionRule::parse(name, parameters, returns, input, loc, self.options)?;
+        self.rules.0.push(CssRule::Function(rule));
+        Ok(())
+      }
+      AtRulePrelude::Mixin(name, parameters) => {
+        let (declarations, rules) = self.parse_nested(input, true)?;
+        self.rules.0.push(CssRule::Mixin(MixinRule {
+          name,
+          parameters,
+          declarations,
+          rules,
+          loc,
+        }));
+        Ok(())
+      }
       AtRulePrelude::Nest(selectors) => {
         let (declarations, rules) = self.parse_nested(input, true)?;
         self.rules.0.push(CssRule::Nesting(NestingRule {
@@ -881,6 +1020,10 @@ impl<'a, 'o, 'b, 'i, T: crate::traits::AtRuleParser<'i>> AtRuleParser<'i> for Ne
         self.rules.0.push(CssRule::LayerStatement(LayerStatementRule { names, loc }));
         Ok(())
       }
+      AtRulePrelude::Apply(name, arguments) => {
+        self.rules.0.push(CssRule::Apply(ApplyRule { name, arguments, loc }));
+        Ok(())
+      }
       AtRulePrelude::Unknown(name, prelude) => {
         self.rules.0.push(CssRule::Unknown(UnknownAtRule {
           name,
@@ -96,11 +96,13 @@ pub enum Feature {
   Hyphens,
   ImageRendering,
   ImageSet,
+  InitialLetter,
   InlineFlex,
   InlineGrid,
   Isolate,
   IsolateOverride,
   JustifyContent,
+  LineClamp,
   LinearGradient,
   MarginBlockEnd,
   MarginBlockStart,
@@ -195,6 +197,7 @@ impl Feature {
   pub fn prefixes_for(&self, browsers: Browsers) -> VendorPrefix {
     let mut prefixes = VendorPrefix::None;
     match self {
+      Feature::LineClamp => {}
       Feature::BorderRadius
       | Feature::BorderTopLeftRadius
       | Feature::BorderTopRightRadius
@@ -2236,6 +2239,18 @@ impl Feature {
           }
         }
       }
+      Feature::InitialLetter => {
+        if let Some(version) = browsers.ios_saf {
+          if version >= 589824 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version >= 589824 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+      }
     }
     prefixes
   }
@@ -35,6 +35,24 @@ pub struct PrinterOptions<'a> {
   /// A mapping of pseudo classes to replace with class names that can be applied
   /// from JavaScript. Useful for polyfills, for example.
   pub pseudo_classes: Option<PseudoClasses<'a>>,
+  /// Whether to write a `/*# sourceMappingURL=... */` comment at the end of the output.
+  /// By default, any such comments found in the input are stripped.
+  pub source_map_url: Option<SourceMapUrl<'a>>,
+  /// The maximum number of decimal places to serialize numbers with.
+  /// By default, numbers are serialized with all of their significant digits.
+  pub precision: Option<u8>,
+}
+
+/// A `sourceMappingURL` comment to write at the end of a style sheet.
+///
+/// See [PrinterOptions](PrinterOptions).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SourceMapUrl<'a> {
+  /// Preserve the `sourceMappingURL` comment found in the input.
+  /// This only applies when the style sheet has a single source, e.g. it is not bundled.
+  Preserve,
+  /// Point to the given URL, e.g. the path of the newly generated source map.
+  Url(&'a str),
 }
 
 /// A mapping of user action pseudo classes to replace with class names.
@@ -86,6 +104,7 @@ pub struct Printer<'a, 'b, 'c, W> {
   pub(crate) dependencies: Option<Vec<Dependency>>,
   pub(crate) remove_imports: bool,
   pub(crate) pseudo_classes: Option<PseudoClasses<'a>>,
+  pub(crate) precision: Option<u8>,
   context: Option<&'a StyleContext<'a, 'b>>,
 }
 
@@ -119,6 +138,7 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
       },
       remove_imports: matches!(&options.analyze_dependencies, Some(d) if d.remove_imports),
       pseudo_classes: options.pseudo_classes,
+      precision: options.precision,
       context: None,
     }
   }
@@ -136,6 +156,23 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
     }
   }
 
+  /// Rounds a number to the configured precision, if any.
+  pub(crate) fn round(&self, value: f32) -> f32 {
+    match self.precision {
+      Some(precision) => {
+        let factor = 10f64.powi(precision as i32);
+        let rounded = ((value as f64 * factor).round() / factor) as f32;
+        // Avoid serializing negative zero.
+        if rounded == 0.0 {
+          0.0
+        } else {
+          rounded
+        }
+      }
+      None => value,
+    }
+  }
+
   /// Writes a raw string to the underlying destination.
   ///
   /// NOTE: Is is assumed that the string does not contain any newline characters.
@@ -1171,7 +1171,10 @@ impl AlignHandler {
         if let Some((val, prefix)) = $key {
           // If we have an unprefixed property, override necessary prefixes.
           let prefix = if prefix.contains(VendorPrefix::None) {
-            prefixes!($prop)
+            let (prefix, prefixed) =
+              context.split_prefixes(prefix, prefixes!($prop), |vp| Property::$prop(val.clone(), vp));
+            dest.extend(prefixed);
+            prefix
           } else {
             prefix
           };
@@ -1185,6 +1188,7 @@ impl AlignHandler {
         if let Some((val, prefix)) = &$key {
           // If we have an unprefixed standard property, generate legacy prefixed versions.
           let mut prefix = context.targets.prefixes(*prefix, Feature::$prop);
+          let mut legacy = Vec::new();
 
           if prefix.contains(VendorPrefix::None) {
             $(
@@ -1199,7 +1203,7 @@ impl AlignHandler {
                 }
                 if !prefixes_2009.is_empty() {
                   if let Some(v) = $prop_2009::from_standard(&val) {
-                    dest.push(Property::$prop_2009(v, prefixes_2009));
+                    legacy.push(Property::$prop_2009(v, prefixes_2009));
                   }
                 }
               }
@@ -1209,10 +1213,12 @@ impl AlignHandler {
           // 2012 spec, implemented by microsoft.
           if prefix.contains(VendorPrefix::Ms) {
             if let Some(v) = $prop_2012::from_standard(&val) {
-              dest.push(Property::$prop_2012(v, VendorPrefix::Ms));
+              legacy.push(Property::$prop_2012(v, VendorPrefix::Ms));
             }
           }
 
+          dest.extend(context.prefixed(|| Property::$prop(val.clone(), prefix), legacy));
+
           // Remove Firefox and IE from standard prefixes.
           prefix.remove(VendorPrefix::Moz | VendorPrefix::Ms);
         }
@@ -1248,10 +1254,11 @@ impl AlignHandler {
           // Only use shorthand if unprefixed.
           if intersection.contains(VendorPrefix::None) {
             // Add prefixed longhands if needed.
+            let mut prefixed = Vec::new();
             *align_prefix = prefixes!($align_prop);
             align_prefix.remove(VendorPrefix::None);
             if !align_prefix.is_empty() {
-              dest.push(Property::$align_prop(align.clone(), *align_prefix))
+              prefixed.push(Property::$align_prop(align.clone(), *align_prefix))
             }
 
             $(
@@ -1260,15 +1267,17 @@ impl AlignHandler {
               justify_prefix.remove(VendorPrefix::None);
 
               if !justify_prefix.is_empty() {
-                dest.push(Property::$justify_prop(justify.clone(), *justify_prefix))
+                prefixed.push(Property::$justify_prop(justify.clone(), *justify_prefix))
               }
             )?
 
             // Add shorthand.
-            dest.push(Property::$prop($prop {
+            let shorthand = Property::$prop($prop {
               align: align.clone(),
               justify: justify.clone()
-            }));
+            });
+            dest.extend(context.prefixed(|| shorthand.clone(), prefixed));
+            dest.push(shorthand);
 
             $align = None;
             $justify = None;
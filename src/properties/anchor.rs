@@ -0,0 +1,655 @@
+//! CSS properties related to anchor positioning.
+
+use super::{Property, PropertyId};
+use crate::context::PropertyHandlerContext;
+use crate::declaration::{DeclarationBlock, DeclarationList};
+use crate::error::{ParserError, PrinterError};
+use crate::macros::{define_shorthand, enum_property, shorthand_handler};
+use crate::printer::Printer;
+use crate::stylesheet::ParserOptions;
+use crate::targets::Browsers;
+use crate::traits::{IsCompatible, Parse, ParseWithOptions, PropertyHandler, Shorthand, ToCss};
+use crate::values::ident::{DashedIdent, DashedIdentReference};
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use bitflags::bitflags;
+use cssparser::*;
+use smallvec::SmallVec;
+
+/// A value for the [anchor-name](https://drafts.csswg.org/css-anchor-position-1/#name) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum AnchorName<'i> {
+  /// The element is not an anchor element.
+  None,
+  /// A list of anchor names.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Names(SmallVec<[DashedIdent<'i>; 1]>),
+}
+
+impl<'i> Parse<'i> for AnchorName<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(AnchorName::None);
+    }
+
+    Ok(AnchorName::Names(
+      input.parse_comma_separated(DashedIdent::parse)?.into(),
+    ))
+  }
+}
+
+impl<'i> ToCss for AnchorName<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      AnchorName::None => dest.write_str("none"),
+      AnchorName::Names(names) => {
+        let mut first = true;
+        for name in names {
+          if first {
+            first = false;
+          } else {
+            dest.delim(',', false)?;
+          }
+          name.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+/// A value for the [position-anchor](https://drafts.csswg.org/css-anchor-position-1/#position-anchor) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum PositionAnchor<'i> {
+  /// The element's implicit anchor element is used, if any.
+  Auto,
+  /// A reference to an anchor name.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Name(DashedIdentReference<'i>),
+}
+
+impl<'i> ParseWithOptions<'i> for PositionAnchor<'i> {
+  fn parse_with_options<'t>(
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions<'_, 'i>,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(PositionAnchor::Auto);
+    }
+
+    Ok(PositionAnchor::Name(DashedIdentReference::parse_with_options(
+      input, options,
+    )?))
+  }
+}
+
+impl<'i> ToCss for PositionAnchor<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      PositionAnchor::Auto => dest.write_str("auto"),
+      PositionAnchor::Name(name) => name.to_css(dest),
+    }
+  }
+}
+
+enum_property! {
+  /// A keyword in a [`<position-area>`](https://drafts.csswg.org/css-anchor-position-1/#typedef-position-area) value.
+  pub enum PositionAreaKeyword {
+    /// The center region of the axis.
+    "center": Center,
+    /// All three regions of the axis.
+    "span-all": SpanAll,
+
+    /// The left region of the x-axis.
+    "left": Left,
+    /// The right region of the x-axis.
+    "right": Right,
+    /// The left and center regions of the x-axis.
+    "span-left": SpanLeft,
+    /// The center and right regions of the x-axis.
+    "span-right": SpanRight,
+    /// The start region of the x-axis, according to the containing block's writing mode.
+    "x-start": XStart,
+    /// The end region of the x-axis, according to the containing block's writing mode.
+    "x-end": XEnd,
+    /// The start and center regions of the x-axis, according to the containing block's writing mode.
+    "span-x-start": SpanXStart,
+    /// The center and end regions of the x-axis, according to the containing block's writing mode.
+    "span-x-end": SpanXEnd,
+    /// The start region of the x-axis, according to the element's own writing mode.
+    "x-self-start": XSelfStart,
+    /// The end region of the x-axis, according to the element's own writing mode.
+    "x-self-end": XSelfEnd,
+    /// The start and center regions of the x-axis, according to the element's own writing mode.
+    "span-x-self-start": SpanXSelfStart,
+    /// The center and end regions of the x-axis, according to the element's own writing mode.
+    "span-x-self-end": SpanXSelfEnd,
+
+    /// The top region of the y-axis.
+    "top": Top,
+    /// The bottom region of the y-axis.
+    "bottom": Bottom,
+    /// The top and center regions of the y-axis.
+    "span-top": SpanTop,
+    /// The center and bottom regions of the y-axis.
+    "span-bottom": SpanBottom,
+    /// The start region of the y-axis, according to the containing block's writing mode.
+    "y-start": YStart,
+    /// The end region of the y-axis, according to the containing block's writing mode.
+    "y-end": YEnd,
+    /// The start and center regions of the y-axis, according to the containing block's writing mode.
+    "span-y-start": SpanYStart,
+    /// The center and end regions of the y-axis, according to the containing block's writing mode.
+    "span-y-end": SpanYEnd,
+    /// The start region of the y-axis, according to the element's own writing mode.
+    "y-self-start": YSelfStart,
+    /// The end region of the y-axis, according to the element's own writing mode.
+    "y-self-end": YSelfEnd,
+    /// The start and center regions of the y-axis, according to the element's own writing mode.
+    "span-y-self-start": SpanYSelfStart,
+    /// The center and end regions of the y-axis, according to the element's own writing mode.
+    "span-y-self-end": SpanYSelfEnd,
+
+    /// The start region of the block axis, according to the containing block's writing mode.
+    "block-start": BlockStart,
+    /// The end region of the block axis, according to the containing block's writing mode.
+    "block-end": BlockEnd,
+    /// The start and center regions of the block axis, according to the containing block's writing mode.
+    "span-block-start": SpanBlockStart,
+    /// The center and end regions of the block axis, according to the containing block's writing mode.
+    "span-block-end": SpanBlockEnd,
+    /// The start region of the inline axis, according to the containing block's writing mode.
+    "inline-start": InlineStart,
+    /// The end region of the inline axis, according to the containing block's writing mode.
+    "inline-end": InlineEnd,
+    /// The start and center regions of the inline axis, according to the containing block's writing mode.
+    "span-inline-start": SpanInlineStart,
+    /// The center and end regions of the inline axis, according to the containing block's writing mode.
+    "span-inline-end": SpanInlineEnd,
+
+    /// The start region of the block axis, according to the element's own writing mode.
+    "self-block-start": SelfBlockStart,
+    /// The end region of the block axis, according to the element's own writing mode.
+    "self-block-end": SelfBlockEnd,
+    /// The start and center regions of the block axis, according to the element's own writing mode.
+    "span-self-block-start": SpanSelfBlockStart,
+    /// The center and end regions of the block axis, according to the element's own writing mode.
+    "span-self-block-end": SpanSelfBlockEnd,
+    /// The start region of the inline axis, according to the element's own writing mode.
+    "self-inline-start": SelfInlineStart,
+    /// The end region of the inline axis, according to the element's own writing mode.
+    "self-inline-end": SelfInlineEnd,
+    /// The start and center regions of the inline axis, according to the element's own writing mode.
+    "span-self-inline-start": SpanSelfInlineStart,
+    /// The center and end regions of the inline axis, according to the element's own writing mode.
+    "span-self-inline-end": SpanSelfInlineEnd,
+
+    /// The start region of the axis, according to the containing block's writing mode.
+    "start": Start,
+    /// The end region of the axis, according to the containing block's writing mode.
+    "end": End,
+    /// The start and center regions of the axis, according to the containing block's writing mode.
+    "span-start": SpanStart,
+    /// The center and end regions of the axis, according to the containing block's writing mode.
+    "span-end": SpanEnd,
+    /// The start region of the axis, according to the element's own writing mode.
+    "self-start": SelfStart,
+    /// The end region of the axis, according to the element's own writing mode.
+    "self-end": SelfEnd,
+    /// The start and center regions of the axis, according to the element's own writing mode.
+    "span-self-start": SpanSelfStart,
+    /// The center and end regions of the axis, according to the element's own writing mode.
+    "span-self-end": SpanSelfEnd,
+  }
+}
+
+#[derive(PartialEq, Clone, Copy)]
+enum PositionAreaAxis {
+  /// `center` or `span-all`, which are valid in any axis.
+  Any,
+  X,
+  Y,
+  Block,
+  Inline,
+  SelfBlock,
+  SelfInline,
+  /// Keywords that are resolved to the block axis in the first position, and the inline axis in the second.
+  Logical,
+  SelfLogical,
+}
+
+impl PositionAreaKeyword {
+  fn axis(&self) -> PositionAreaAxis {
+    use PositionAreaKeyword::*;
+    match self {
+      Center | SpanAll => PositionAreaAxis::Any,
+      Left | Right | SpanLeft | SpanRight | XStart | XEnd | SpanXStart | SpanXEnd | XSelfStart | XSelfEnd
+      | SpanXSelfStart | SpanXSelfEnd => PositionAreaAxis::X,
+      Top | Bottom | SpanTop | SpanBottom | YStart | YEnd | SpanYStart | SpanYEnd | YSelfStart | YSelfEnd
+      | SpanYSelfStart | SpanYSelfEnd => PositionAreaAxis::Y,
+      BlockStart | BlockEnd | SpanBlockStart | SpanBlockEnd => PositionAreaAxis::Block,
+      InlineStart | InlineEnd | SpanInlineStart | SpanInlineEnd => PositionAreaAxis::Inline,
+      SelfBlockStart | SelfBlockEnd | SpanSelfBlockStart | SpanSelfBlockEnd => PositionAreaAxis::SelfBlock,
+      SelfInlineStart | SelfInlineEnd | SpanSelfInlineStart | SpanSelfInlineEnd => PositionAreaAxis::SelfInline,
+      Start | End | SpanStart | SpanEnd => PositionAreaAxis::Logical,
+      SelfStart | SelfEnd | SpanSelfStart | SpanSelfEnd => PositionAreaAxis::SelfLogical,
+    }
+  }
+}
+
+/// A value for the [position-area](https://drafts.csswg.org/css-anchor-position-1/#position-area) property.
+///
+/// Values are normalized when parsed: keywords for the x or block axis are listed first,
+/// and a second keyword is omitted when it is implied by the first.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum PositionArea {
+  /// The property has no effect.
+  None,
+  /// A grid area, specified by one or two keywords.
+  Area(PositionAreaKeyword, Option<PositionAreaKeyword>),
+}
+
+impl<'i> Parse<'i> for PositionArea {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(PositionArea::None);
+    }
+
+    PositionArea::parse_area(input)
+  }
+}
+
+impl PositionArea {
+  fn parse_area<'i>(input: &mut Parser<'i, '_>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    use PositionAreaAxis::*;
+
+    let location = input.current_source_location();
+    let first = PositionAreaKeyword::parse(input)?;
+    let Ok(second) = input.try_parse(PositionAreaKeyword::parse) else {
+      return Ok(PositionArea::Area(first, None));
+    };
+
+    let (first, second) = match (first.axis(), second.axis()) {
+      // Keywords without an explicit axis are resolved by their position.
+      (Any | Logical, Any | Logical) | (Any | SelfLogical, Any | SelfLogical) => {
+        if first == second {
+          return Ok(PositionArea::Area(first, None));
+        }
+        (first, second)
+      }
+      (Logical | SelfLogical, _) | (_, Logical | SelfLogical) => {
+        return Err(location.new_custom_error(ParserError::InvalidValue))
+      }
+      (X, Y) | (Block, Inline) | (SelfBlock, SelfInline) => (first, second),
+      (Y, X) | (Inline, Block) | (SelfInline, SelfBlock) => (second, first),
+      (Any, Inline | SelfInline | Y) | (Block | SelfBlock | X, Any) => (first, second),
+      (Inline | SelfInline | Y, Any) | (Any, Block | SelfBlock | X) => (second, first),
+      _ => return Err(location.new_custom_error(ParserError::InvalidValue)),
+    };
+
+    // A single keyword with an explicit axis implies `span-all` in the other axis.
+    if first.axis() != Any && second == PositionAreaKeyword::SpanAll {
+      return Ok(PositionArea::Area(first, None));
+    }
+    if second.axis() != Any && first == PositionAreaKeyword::SpanAll {
+      return Ok(PositionArea::Area(second, None));
+    }
+
+    Ok(PositionArea::Area(first, Some(second)))
+  }
+}
+
+impl ToCss for PositionArea {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      PositionArea::None => dest.write_str("none"),
+      PositionArea::Area(first, second) => {
+        first.to_css(dest)?;
+        if let Some(second) = second {
+          dest.write_char(' ')?;
+          second.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+bitflags! {
+  /// A [`<try-tactic>`](https://drafts.csswg.org/css-anchor-position-1/#typedef-position-try-fallbacks-try-tactic)
+  /// value, which transforms a fallback position.
+  #[cfg_attr(feature = "visitor", derive(Visit))]
+  #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(from = "SerializedTryTactic", into = "SerializedTryTactic"))]
+  #[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+  #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+  pub struct TryTactic: u8 {
+    /// Swaps the values in the block axis.
+    const FlipBlock  = 0b001;
+    /// Swaps the values in the inline axis.
+    const FlipInline = 0b010;
+    /// Swaps the values between the block and inline axes.
+    const FlipStart  = 0b100;
+  }
+}
+
+impl<'i> Parse<'i> for TryTactic {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut res = TryTactic::empty();
+    while let Ok(flag) = input.try_parse(|input| -> Result<_, ParseError<'i, ParserError<'i>>> {
+      let location = input.current_source_location();
+      let ident = input.expect_ident_cloned()?;
+      match_ignore_ascii_case! { &ident,
+        "flip-block" => Ok(TryTactic::FlipBlock),
+        "flip-inline" => Ok(TryTactic::FlipInline),
+        "flip-start" => Ok(TryTactic::FlipStart),
+        _ => Err(location.new_custom_error(ParserError::InvalidValue)),
+      }
+    }) {
+      if res.contains(flag) {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
+      res |= flag;
+    }
+
+    if res.is_empty() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(res)
+  }
+}
+
+impl ToCss for TryTactic {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let mut first = true;
+    let mut write = |s: &str, dest: &mut Printer<W>| -> Result<(), PrinterError> {
+      if first {
+        first = false;
+      } else {
+        dest.write_char(' ')?;
+      }
+      dest.write_str(s)
+    };
+
+    if self.contains(TryTactic::FlipBlock) {
+      write("flip-block", dest)?;
+    }
+    if self.contains(TryTactic::FlipInline) {
+      write("flip-inline", dest)?;
+    }
+    if self.contains(TryTactic::FlipStart) {
+      write("flip-start", dest)?;
+    }
+    Ok(())
+  }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+struct SerializedTryTactic {
+  flip_block: bool,
+  flip_inline: bool,
+  flip_start: bool,
+}
+
+impl From<TryTactic> for SerializedTryTactic {
+  fn from(tactic: TryTactic) -> Self {
+    Self {
+      flip_block: tactic.contains(TryTactic::FlipBlock),
+      flip_inline: tactic.contains(TryTactic::FlipInline),
+      flip_start: tactic.contains(TryTactic::FlipStart),
+    }
+  }
+}
+
+impl From<SerializedTryTactic> for TryTactic {
+  fn from(s: SerializedTryTactic) -> TryTactic {
+    let mut tactic = TryTactic::empty();
+    tactic.set(TryTactic::FlipBlock, s.flip_block);
+    tactic.set(TryTactic::FlipInline, s.flip_inline);
+    tactic.set(TryTactic::FlipStart, s.flip_start);
+    tactic
+  }
+}
+
+#[cfg(feature = "jsonschema")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jsonschema")))]
+impl<'a> schemars::JsonSchema for TryTactic {
+  fn is_referenceable() -> bool {
+    true
+  }
+
+  fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    SerializedTryTactic::json_schema(gen)
+  }
+
+  fn schema_name() -> String {
+    "TryTactic".into()
+  }
+}
+
+/// A fallback position in the [position-try-fallbacks](https://drafts.csswg.org/css-anchor-position-1/#position-try-fallbacks) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum PositionTryFallback<'i> {
+  /// The name of a `@position-try` rule, optionally transformed by a try tactic.
+  Name {
+    /// The name of the `@position-try` rule.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    name: DashedIdent<'i>,
+    /// The try tactic applied to the rule.
+    tactic: TryTactic,
+  },
+  /// The element's base position, transformed by a try tactic.
+  #[cfg_attr(feature = "serde", serde(with = "crate::serialization::ValueWrapper::<TryTactic>"))]
+  Tactic(TryTactic),
+  /// A position area.
+  #[cfg_attr(
+    feature = "serde",
+    serde(with = "crate::serialization::ValueWrapper::<PositionArea>")
+  )]
+  PositionArea(PositionArea),
+}
+
+impl<'i> Parse<'i> for PositionTryFallback<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(area) = input.try_parse(PositionArea::parse_area) {
+      return Ok(PositionTryFallback::PositionArea(area));
+    }
+
+    let tactic = input.try_parse(TryTactic::parse).ok();
+    if let Ok(name) = input.try_parse(DashedIdent::parse) {
+      let tactic = tactic
+        .or_else(|| input.try_parse(TryTactic::parse).ok())
+        .unwrap_or(TryTactic::empty());
+      return Ok(PositionTryFallback::Name { name, tactic });
+    }
+
+    match tactic {
+      Some(tactic) => Ok(PositionTryFallback::Tactic(tactic)),
+      None => Err(input.new_custom_error(ParserError::InvalidValue)),
+    }
+  }
+}
+
+impl<'i> ToCss for PositionTryFallback<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      PositionTryFallback::Name { name, tactic } => {
+        name.to_css(dest)?;
+        if !tactic.is_empty() {
+          dest.write_char(' ')?;
+          tactic.to_css(dest)?;
+        }
+        Ok(())
+      }
+      PositionTryFallback::Tactic(tactic) => tactic.to_css(dest),
+      PositionTryFallback::PositionArea(area) => area.to_css(dest),
+    }
+  }
+}
+
+/// A value for the [position-try-fallbacks](https://drafts.csswg.org/css-anchor-position-1/#position-try-fallbacks) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum PositionTryFallbacks<'i> {
+  /// The element has no fallback positions.
+  None,
+  /// A list of fallback positions.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Fallbacks(SmallVec<[PositionTryFallback<'i>; 1]>),
+}
+
+impl<'i> Parse<'i> for PositionTryFallbacks<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(PositionTryFallbacks::None);
+    }
+
+    Ok(PositionTryFallbacks::Fallbacks(
+      input.parse_comma_separated(PositionTryFallback::parse)?.into(),
+    ))
+  }
+}
+
+impl<'i> ToCss for PositionTryFallbacks<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      PositionTryFallbacks::None => dest.write_str("none"),
+      PositionTryFallbacks::Fallbacks(fallbacks) => {
+        let mut first = true;
+        for fallback in fallbacks {
+          if first {
+            first = false;
+          } else {
+            dest.delim(',', false)?;
+          }
+          fallback.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+impl IsCompatible for PositionTryFallbacks<'_> {
+  fn is_compatible(&self, _browsers: Browsers) -> bool {
+    true
+  }
+}
+
+enum_property! {
+  /// A value for the [position-try-order](https://drafts.csswg.org/css-anchor-position-1/#position-try-order-property) property.
+  pub enum PositionTryOrder {
+    /// Fallback positions are tried in the order they are specified.
+    "normal": Normal,
+    /// Fallback positions are ordered by the width of their containing block, largest first.
+    "most-width": MostWidth,
+    /// Fallback positions are ordered by the height of their containing block, largest first.
+    "most-height": MostHeight,
+    /// Fallback positions are ordered by the block size of their containing block, largest first.
+    "most-block-size": MostBlockSize,
+    /// Fallback positions are ordered by the inline size of their containing block, largest first.
+    "most-inline-size": MostInlineSize,
+  }
+}
+
+impl IsCompatible for PositionTryOrder {
+  fn is_compatible(&self, _browsers: Browsers) -> bool {
+    true
+  }
+}
+
+define_shorthand! {
+  /// A value for the [position-try](https://drafts.csswg.org/css-anchor-position-1/#position-try-prop) shorthand property.
+  pub struct PositionTry<'i> {
+    /// The order in which fallback positions are tried.
+    order: PositionTryOrder(PositionTryOrder),
+    /// The fallback positions.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    fallbacks: PositionTryFallbacks(PositionTryFallbacks<'i>),
+  }
+}
+
+impl<'i> Parse<'i> for PositionTry<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let order = input.try_parse(PositionTryOrder::parse).unwrap_or(PositionTryOrder::Normal);
+    let fallbacks = PositionTryFallbacks::parse(input)?;
+    Ok(PositionTry { order, fallbacks })
+  }
+}
+
+impl<'i> ToCss for PositionTry<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.order != PositionTryOrder::Normal {
+      self.order.to_css(dest)?;
+      dest.write_char(' ')?;
+    }
+    self.fallbacks.to_css(dest)
+  }
+}
+
+shorthand_handler!(PositionTryHandler -> PositionTry<'i> {
+  order: PositionTryOrder(PositionTryOrder),
+  fallbacks: PositionTryFallbacks(PositionTryFallbacks<'i>),
+});
@@ -344,6 +344,25 @@ impl Default for ScrollAxis {
   }
 }
 
+enum_property! {
+  /// A [view progress timeline range](https://drafts.csswg.org/scroll-animations-1/#view-timelines-ranges) name.
+  pub enum TimelineRangeName {
+    /// Represents the full range of the view progress timeline.
+    "cover": Cover,
+    /// Represents the range during which the principal box is either fully contained by,
+    /// or fully covers, its view progress visibility range within the scrollport.
+    "contain": Contain,
+    /// Represents the range during which the principal box is entering the view progress visibility range.
+    "entry": Entry,
+    /// Represents the range during which the principal box is exiting the view progress visibility range.
+    "exit": Exit,
+    /// Represents the range during which the principal box crosses the end border edge.
+    "entry-crossing": EntryCrossing,
+    /// Represents the range during which the principal box crosses the start border edge.
+    "exit-crossing": ExitCrossing,
+  }
+}
+
 /// The [view()](https://drafts.csswg.org/scroll-animations-1/#view-notation) function.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
@@ -453,6 +472,141 @@ impl<'i> ToCss for AnimationTimeline<'i> {
   }
 }
 
+/// A value for the [scroll-timeline-name](https://drafts.csswg.org/scroll-animations-1/#scroll-timeline-name)
+/// and [view-timeline-name](https://drafts.csswg.org/scroll-animations-1/#view-timeline-name) properties.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum TimelineName<'i> {
+  /// The timeline has no name.
+  None,
+  /// The name of the timeline.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  DashedIdent(DashedIdent<'i>),
+}
+
+impl<'i> Parse<'i> for TimelineName<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|i| i.expect_ident_matching("none")).is_ok() {
+      return Ok(TimelineName::None);
+    }
+
+    Ok(TimelineName::DashedIdent(DashedIdent::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for TimelineName<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      TimelineName::None => dest.write_str("none"),
+      TimelineName::DashedIdent(name) => name.to_css(dest),
+    }
+  }
+}
+
+define_list_shorthand! {
+  /// A value for the [scroll-timeline](https://drafts.csswg.org/scroll-animations-1/#scroll-timeline-shorthand) shorthand property.
+  pub struct ScrollTimelineDefinition<'i> for ScrollTimeline {
+    /// The name of the scroll timeline.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    name: ScrollTimelineName(TimelineName<'i>),
+    /// The scroll axis used for progress of the timeline.
+    axis: ScrollTimelineAxis(ScrollAxis),
+  }
+}
+
+impl<'i> Parse<'i> for ScrollTimelineDefinition<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let name = TimelineName::parse(input)?;
+    let axis = input.try_parse(ScrollAxis::parse).unwrap_or_default();
+    Ok(ScrollTimelineDefinition { name, axis })
+  }
+}
+
+impl<'i> ToCss for ScrollTimelineDefinition<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.name.to_css(dest)?;
+    if self.axis != ScrollAxis::default() {
+      dest.write_char(' ')?;
+      self.axis.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+define_list_shorthand! {
+  /// A value for the [view-timeline](https://drafts.csswg.org/scroll-animations-1/#view-timeline-shorthand) shorthand property.
+  pub struct ViewTimelineDefinition<'i> for ViewTimeline {
+    /// The name of the view timeline.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    name: ViewTimelineName(TimelineName<'i>),
+    /// The scroll axis used for progress of the timeline.
+    axis: ViewTimelineAxis(ScrollAxis),
+    /// An adjustment of the view progress visibility range.
+    inset: ViewTimelineInset(Size2D<LengthPercentageOrAuto>),
+  }
+}
+
+impl<'i> Parse<'i> for ViewTimelineDefinition<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let name = TimelineName::parse(input)?;
+    let mut axis = None;
+    let mut inset = None;
+    loop {
+      if axis.is_none() {
+        if let Ok(value) = input.try_parse(ScrollAxis::parse) {
+          axis = Some(value);
+          continue;
+        }
+      }
+
+      if inset.is_none() {
+        if let Ok(value) = input.try_parse(Size2D::parse) {
+          inset = Some(value);
+          continue;
+        }
+      }
+      break;
+    }
+
+    Ok(ViewTimelineDefinition {
+      name,
+      axis: axis.unwrap_or_default(),
+      inset: inset.unwrap_or(Size2D(LengthPercentageOrAuto::Auto, LengthPercentageOrAuto::Auto)),
+    })
+  }
+}
+
+impl<'i> ToCss for ViewTimelineDefinition<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.name.to_css(dest)?;
+    if self.axis != ScrollAxis::default() {
+      dest.write_char(' ')?;
+      self.axis.to_css(dest)?;
+    }
+    if self.inset.0 != LengthPercentageOrAuto::Auto || self.inset.1 != LengthPercentageOrAuto::Auto {
+      dest.write_char(' ')?;
+      self.inset.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
 define_list_shorthand! {
   /// A value for the [animation](https://drafts.csswg.org/css-animations/#animation) shorthand property.
   pub struct Animation<'i>(VendorPrefix) {
@@ -602,6 +756,7 @@ pub(crate) struct AnimationHandler<'i> {
   delays: Option<(SmallVec<[Time; 1]>, VendorPrefix)>,
   fill_modes: Option<(SmallVec<[AnimationFillMode; 1]>, VendorPrefix)>,
   timelines: Option<SmallVec<[AnimationTimeline<'i>; 1]>>,
+  compositions: Option<SmallVec<[AnimationComposition; 1]>>,
   has_any: bool,
 }
 
@@ -652,6 +807,10 @@ impl<'i> PropertyHandler<'i> for AnimationHandler<'i> {
         self.timelines = Some(val.clone());
         self.has_any = true;
       }
+      Property::AnimationComposition(val) => {
+        self.compositions = Some(val.clone());
+        self.has_any = true;
+      }
       Property::Animation(val, vp) => {
         let names = val.iter().map(|b| b.name.clone()).collect();
         maybe_flush!(names, &names, vp);
@@ -717,9 +876,9 @@ impl<'i> PropertyHandler<'i> for AnimationHandler<'i> {
         }
 
         self.flush(dest, context);
-        dest.push(Property::Unparsed(
-          val.get_prefixed(context.targets, Feature::Animation),
-        ));
+        let (unparsed, prefixed) = context.prefix_unparsed(&val, Feature::Animation);
+        dest.extend(prefixed);
+        dest.push(Property::Unparsed(unparsed));
       }
       _ => return false,
     }
@@ -749,6 +908,7 @@ impl<'i> AnimationHandler<'i> {
     let mut delays = std::mem::take(&mut self.delays);
     let mut fill_modes = std::mem::take(&mut self.fill_modes);
     let mut timelines_value = std::mem::take(&mut self.timelines);
+    let compositions = std::mem::take(&mut self.compositions);
 
     if let (
       Some((names, names_vp)),
@@ -809,7 +969,7 @@ impl<'i> AnimationHandler<'i> {
           None
         };
 
-        let animations = izip!(
+        let animations: AnimationList = izip!(
           names.drain(..),
           durations.drain(..),
           timing_functions.drain(..),
@@ -851,6 +1011,9 @@ impl<'i> AnimationHandler<'i> {
         )
         .collect();
         let prefix = context.targets.prefixes(intersection, Feature::Animation);
+        let (prefix, prefixed) =
+          context.split_prefixes(intersection, prefix, |vp| Property::Animation(animations.clone(), vp));
+        dest.extend(prefixed);
         dest.push(Property::Animation(animations, prefix));
         names_vp.remove(intersection);
         durations_vp.remove(intersection);
@@ -873,6 +1036,8 @@ impl<'i> AnimationHandler<'i> {
         if let Some((val, vp)) = $var {
           if !vp.is_empty() {
             let prefix = context.targets.prefixes(vp, Feature::$property);
+            let (prefix, prefixed) = context.split_prefixes(vp, prefix, |vp| Property::$property(val.clone(), vp));
+            dest.extend(prefixed);
             dest.push(Property::$property(val, prefix))
           }
         }
@@ -891,6 +1056,186 @@ impl<'i> AnimationHandler<'i> {
     if let Some(val) = timelines_value {
       dest.push(Property::AnimationTimeline(val));
     }
+
+    // animation-composition is not part of the animation shorthand, so it is always output
+    // separately, after the shorthand.
+    if let Some(val) = compositions {
+      dest.push(Property::AnimationComposition(val));
+    }
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct ScrollTimelineHandler<'i> {
+  names: Option<SmallVec<[TimelineName<'i>; 1]>>,
+  axes: Option<SmallVec<[ScrollAxis; 1]>>,
+  has_any: bool,
+}
+
+impl<'i> PropertyHandler<'i> for ScrollTimelineHandler<'i> {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::ScrollTimelineName(val) => {
+        self.names = Some(val.clone());
+        self.has_any = true;
+      }
+      Property::ScrollTimelineAxis(val) => {
+        self.axes = Some(val.clone());
+        self.has_any = true;
+      }
+      Property::ScrollTimeline(val) => {
+        self.names = Some(val.iter().map(|t| t.name.clone()).collect());
+        self.axes = Some(val.iter().map(|t| t.axis).collect());
+        self.has_any = true;
+      }
+      Property::Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::ScrollTimelineName | PropertyId::ScrollTimelineAxis | PropertyId::ScrollTimeline
+        ) =>
+      {
+        self.flush(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    self.flush(dest, context);
+  }
+}
+
+impl<'i> ScrollTimelineHandler<'i> {
+  fn flush(&mut self, dest: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {
+    if !self.has_any {
+      return;
+    }
+
+    self.has_any = false;
+
+    let names = std::mem::take(&mut self.names);
+    let axes = std::mem::take(&mut self.axes);
+
+    match (names, axes) {
+      (Some(names), Some(axes)) if names.len() == axes.len() => {
+        let timelines = names
+          .into_iter()
+          .zip(axes)
+          .map(|(name, axis)| ScrollTimelineDefinition { name, axis })
+          .collect();
+        dest.push(Property::ScrollTimeline(timelines));
+      }
+      (names, axes) => {
+        if let Some(names) = names {
+          dest.push(Property::ScrollTimelineName(names));
+        }
+
+        if let Some(axes) = axes {
+          dest.push(Property::ScrollTimelineAxis(axes));
+        }
+      }
+    }
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct ViewTimelineHandler<'i> {
+  names: Option<SmallVec<[TimelineName<'i>; 1]>>,
+  axes: Option<SmallVec<[ScrollAxis; 1]>>,
+  insets: Option<SmallVec<[Size2D<LengthPercentageOrAuto>; 1]>>,
+  has_any: bool,
+}
+
+impl<'i> PropertyHandler<'i> for ViewTimelineHandler<'i> {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::ViewTimelineName(val) => {
+        self.names = Some(val.clone());
+        self.has_any = true;
+      }
+      Property::ViewTimelineAxis(val) => {
+        self.axes = Some(val.clone());
+        self.has_any = true;
+      }
+      Property::ViewTimelineInset(val) => {
+        self.insets = Some(val.clone());
+        self.has_any = true;
+      }
+      Property::ViewTimeline(val) => {
+        self.names = Some(val.iter().map(|t| t.name.clone()).collect());
+        self.axes = Some(val.iter().map(|t| t.axis).collect());
+        self.insets = Some(val.iter().map(|t| t.inset.clone()).collect());
+        self.has_any = true;
+      }
+      Property::Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::ViewTimelineName
+            | PropertyId::ViewTimelineAxis
+            | PropertyId::ViewTimelineInset
+            | PropertyId::ViewTimeline
+        ) =>
+      {
+        self.flush(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    self.flush(dest, context);
+  }
+}
+
+impl<'i> ViewTimelineHandler<'i> {
+  fn flush(&mut self, dest: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {
+    if !self.has_any {
+      return;
+    }
+
+    self.has_any = false;
+
+    let names = std::mem::take(&mut self.names);
+    let axes = std::mem::take(&mut self.axes);
+    let insets = std::mem::take(&mut self.insets);
+
+    match (names, axes, insets) {
+      (Some(names), Some(axes), Some(insets)) if names.len() == axes.len() && names.len() == insets.len() => {
+        let timelines = izip!(names, axes, insets)
+          .map(|(name, axis, inset)| ViewTimelineDefinition { name, axis, inset })
+          .collect();
+        dest.push(Property::ViewTimeline(timelines));
+      }
+      (names, axes, insets) => {
+        if let Some(names) = names {
+          dest.push(Property::ViewTimelineName(names));
+        }
+
+        if let Some(axes) = axes {
+          dest.push(Property::ViewTimelineAxis(axes));
+        }
+
+        if let Some(insets) = insets {
+          dest.push(Property::ViewTimelineInset(insets));
+        }
+      }
+    }
   }
 }
 
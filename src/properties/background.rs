@@ -9,7 +9,7 @@ use crate::printer::Printer;
 use crate::properties::{Property, PropertyId, VendorPrefix};
 use crate::targets::{Browsers, Targets};
 use crate::traits::{FallbackValues, IsCompatible, Parse, PropertyHandler, Shorthand, ToCss};
-use crate::values::color::ColorFallbackKind;
+use crate::values::color::{ColorFallbackKind, GamutMapping};
 use crate::values::image::ImageFallback;
 use crate::values::{color::CssColor, image::Image, length::LengthPercentageOrAuto, position::*};
 #[cfg(feature = "visitor")]
@@ -544,10 +544,10 @@ impl<'i> ImageFallback<'i> for Background<'i> {
   }
 
   #[inline]
-  fn get_fallback(&self, kind: ColorFallbackKind) -> Self {
+  fn get_fallback(&self, kind: ColorFallbackKind, gamut_mapping: GamutMapping) -> Self {
     Background {
-      color: self.color.get_fallback(kind),
-      image: self.image.get_fallback(kind),
+      color: self.color.get_fallback_with_gamut_mapping(kind, gamut_mapping),
+      image: self.image.get_fallback_with_gamut_mapping(kind, gamut_mapping),
       ..self.clone()
     }
   }
@@ -1029,8 +1029,12 @@ impl<'i> BackgroundHandler<'i> {
         .collect();
 
         if !self.flushed_properties.intersects(BackgroundProperty::Background) {
-          for fallback in backgrounds.get_fallbacks(context.targets) {
-            push!(Background, fallback);
+          let fallbacks = backgrounds.get_fallbacks(context.targets, context.gamut_mapping);
+          for fallback in context.fallbacks(
+            || Property::Background(backgrounds.clone()),
+            fallbacks.into_iter().map(Property::Background).collect(),
+          ) {
+            dest.push(fallback);
           }
         }
 
@@ -1048,8 +1052,12 @@ impl<'i> BackgroundHandler<'i> {
 
     if let Some(mut color) = color {
       if !self.flushed_properties.contains(BackgroundProperty::BackgroundColor) {
-        for fallback in color.get_fallbacks(context.targets) {
-          push!(BackgroundColor, fallback);
+        let fallbacks = color.get_fallbacks(context.targets, context.gamut_mapping);
+        for fallback in context.fallbacks(
+          || Property::BackgroundColor(color.clone()),
+          fallbacks.into_iter().map(Property::BackgroundColor).collect(),
+        ) {
+          dest.push(fallback);
         }
       }
 
@@ -1058,8 +1066,12 @@ impl<'i> BackgroundHandler<'i> {
 
     if let Some(mut images) = images {
       if !self.flushed_properties.contains(BackgroundProperty::BackgroundImage) {
-        for fallback in images.get_fallbacks(context.targets) {
-          push!(BackgroundImage, fallback);
+        let fallbacks = images.get_fallbacks(context.targets, context.gamut_mapping);
+        for fallback in context.fallbacks(
+          || Property::BackgroundImage(images.clone()),
+          fallbacks.into_iter().map(Property::BackgroundImage).collect(),
+        ) {
+          dest.push(fallback);
         }
       }
 
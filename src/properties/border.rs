@@ -14,7 +14,7 @@ use crate::properties::{Property, PropertyId};
 use crate::targets::Browsers;
 use crate::targets::Targets;
 use crate::traits::{FallbackValues, IsCompatible, Parse, PropertyHandler, Shorthand, ToCss};
-use crate::values::color::{ColorFallbackKind, CssColor};
+use crate::values::color::{ColorFallbackKind, CssColor, GamutMapping};
 use crate::values::length::*;
 use crate::values::rect::Rect;
 use crate::values::size::Size2D;
@@ -241,10 +241,10 @@ impl<S: ToCss + Default + PartialEq, const P: u8> ToCss for GenericBorder<S, P>
 }
 
 impl<S: Clone, const P: u8> FallbackValues for GenericBorder<S, P> {
-  fn get_fallbacks(&mut self, targets: Targets) -> Vec<Self> {
+  fn get_fallbacks(&mut self, targets: Targets, gamut_mapping: GamutMapping) -> Vec<Self> {
     self
       .color
-      .get_fallbacks(targets)
+      .get_fallbacks(targets, gamut_mapping)
       .into_iter()
       .map(|color| GenericBorder {
         color,
@@ -459,7 +459,7 @@ rect_shorthand! {
 macro_rules! impl_fallbacks {
   ($t: ident $(, $name: ident)+) => {
     impl FallbackValues for $t {
-      fn get_fallbacks(&mut self, targets: Targets) -> Vec<Self> {
+      fn get_fallbacks(&mut self, targets: Targets, gamut_mapping: GamutMapping) -> Vec<Self> {
         let mut fallbacks = ColorFallbackKind::empty();
         $(
           fallbacks |= self.$name.get_necessary_fallbacks(targets);
@@ -469,7 +469,7 @@ macro_rules! impl_fallbacks {
         if fallbacks.contains(ColorFallbackKind::RGB) {
           res.push($t {
             $(
-              $name: self.$name.get_fallback(ColorFallbackKind::RGB),
+              $name: self.$name.get_fallback_with_gamut_mapping(ColorFallbackKind::RGB, gamut_mapping),
             )+
           });
         }
@@ -477,14 +477,14 @@ macro_rules! impl_fallbacks {
         if fallbacks.contains(ColorFallbackKind::P3) {
           res.push($t {
             $(
-              $name: self.$name.get_fallback(ColorFallbackKind::P3),
+              $name: self.$name.get_fallback_with_gamut_mapping(ColorFallbackKind::P3, gamut_mapping),
             )+
           });
         }
 
         if fallbacks.contains(ColorFallbackKind::LAB) {
           $(
-            self.$name = self.$name.get_fallback(ColorFallbackKind::LAB);
+            self.$name = self.$name.get_fallback_with_gamut_mapping(ColorFallbackKind::LAB, gamut_mapping);
           )+
         }
 
@@ -778,7 +778,7 @@ impl<'i> PropertyHandler<'i> for BorderHandler<'i> {
 }
 
 impl<'i> BorderHandler<'i> {
-  fn flush(&mut self, dest: &mut DeclarationList, context: &mut PropertyHandlerContext<'i, '_>) {
+  fn flush(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
     if !self.has_any {
       return;
     }
@@ -804,10 +804,11 @@ impl<'i> BorderHandler<'i> {
       ($prop: ident => $val: expr) => {{
         let mut val = $val;
         if !self.flushed_properties.contains(BorderProperty::$prop) {
-          let fallbacks = val.get_fallbacks(context.targets);
-          for fallback in fallbacks {
-            dest.push(Property::$prop(fallback))
-          }
+          let fallbacks = val.get_fallbacks(context.targets, context.gamut_mapping);
+          dest.extend(context.fallbacks(
+            || Property::$prop(val.clone()),
+            fallbacks.into_iter().map(Property::$prop).collect(),
+          ));
         }
         push!($prop, val);
       }};
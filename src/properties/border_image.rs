@@ -8,6 +8,7 @@ use crate::printer::Printer;
 use crate::properties::{Property, PropertyId, VendorPrefix};
 use crate::targets::{Browsers, Targets};
 use crate::traits::{IsCompatible, Parse, PropertyHandler, Shorthand, ToCss};
+use crate::values::color::GamutMapping;
 use crate::values::image::Image;
 use crate::values::number::CSSNumber;
 use crate::values::rect::Rect;
@@ -375,10 +376,10 @@ impl<'i> ToCss for BorderImage<'i> {
 }
 
 impl<'i> FallbackValues for BorderImage<'i> {
-  fn get_fallbacks(&mut self, targets: Targets) -> Vec<Self> {
+  fn get_fallbacks(&mut self, targets: Targets, gamut_mapping: GamutMapping) -> Vec<Self> {
     self
       .source
-      .get_fallbacks(targets)
+      .get_fallbacks(targets, gamut_mapping)
       .into_iter()
       .map(|source| BorderImage { source, ..self.clone() })
       .collect()
@@ -477,16 +478,17 @@ impl<'i> PropertyHandler<'i> for BorderImageHandler<'i> {
 
         // Even if we weren't able to parse the value (e.g. due to var() references),
         // we can still add vendor prefixes to the property itself.
-        let mut unparsed = if matches!(val.property_id, PropertyId::BorderImage(_)) {
-          val.get_prefixed(context.targets, Feature::BorderImage)
+        let (mut unparsed, prefixed) = if matches!(val.property_id, PropertyId::BorderImage(_)) {
+          context.prefix_unparsed(val, Feature::BorderImage)
         } else {
-          val.clone()
+          (val.clone(), Vec::new())
         };
 
         context.add_unparsed_fallbacks(&mut unparsed);
         self
           .flushed_properties
           .insert(BorderImageProperty::try_from(&unparsed.property_id).unwrap());
+        dest.extend(prefixed);
         dest.push(Property::Unparsed(unparsed));
       }
       _ => return false,
@@ -553,17 +555,21 @@ impl<'i> BorderImageHandler<'i> {
       if prefix.contains(VendorPrefix::None) && !border_image.slice.fill {
         prefix = context.targets.prefixes(self.vendor_prefix, Feature::BorderImage);
         if !self.flushed_properties.intersects(BorderImageProperty::BorderImage) {
-          let fallbacks = border_image.get_fallbacks(context.targets);
-          for fallback in fallbacks {
-            // Match prefix of fallback. e.g. -webkit-linear-gradient
-            // can only be used in -webkit-border-image, not -moz-border-image.
-            // However, if border-image is unprefixed, gradients can still be.
-            let mut p = fallback.source.get_vendor_prefix() & prefix;
-            if p.is_empty() {
-              p = prefix;
-            }
-            dest.push(Property::BorderImage(fallback, p));
-          }
+          let fallbacks = border_image
+            .get_fallbacks(context.targets, context.gamut_mapping)
+            .into_iter()
+            .map(|fallback| {
+              // Match prefix of fallback. e.g. -webkit-linear-gradient
+              // can only be used in -webkit-border-image, not -moz-border-image.
+              // However, if border-image is unprefixed, gradients can still be.
+              let mut p = fallback.source.get_vendor_prefix() & prefix;
+              if p.is_empty() {
+                p = prefix;
+              }
+              Property::BorderImage(fallback, p)
+            })
+            .collect();
+          dest.extend(context.fallbacks(|| Property::BorderImage(border_image.clone(), prefix), fallbacks));
         }
       }
 
@@ -572,15 +578,22 @@ impl<'i> BorderImageHandler<'i> {
         prefix = p;
       }
 
-      dest.push(Property::BorderImage(border_image, prefix));
+      let (prefix, prefixed) = context.split_prefixes(self.vendor_prefix, prefix, |vp| {
+        Property::BorderImage(border_image.clone(), vp)
+      });
+      dest.extend(prefixed);
+      if !prefix.is_empty() {
+        dest.push(Property::BorderImage(border_image, prefix));
+      }
       self.flushed_properties.insert(BorderImageProperty::BorderImage);
     } else {
       if let Some(mut source) = source {
         if !self.flushed_properties.contains(BorderImageProperty::BorderImageSource) {
-          let fallbacks = source.get_fallbacks(context.targets);
-          for fallback in fallbacks {
-            dest.push(Property::BorderImageSource(fallback));
-          }
+          let fallbacks = source.get_fallbacks(context.targets, context.gamut_mapping);
+          dest.extend(context.fallbacks(
+            || Property::BorderImageSource(source.clone()),
+            fallbacks.into_iter().map(Property::BorderImageSource).collect(),
+          ));
         }
 
         push!(BorderImageSource, source);
@@ -11,6 +11,7 @@ use crate::printer::Printer;
 use crate::properties::{Property, PropertyId, VendorPrefix};
 use crate::traits::{IsCompatible, Parse, PropertyHandler, Shorthand, ToCss, Zero};
 use crate::values::length::*;
+use crate::values::number::CSSNumber;
 use crate::values::rect::Rect;
 use crate::values::size::Size2D;
 #[cfg(feature = "visitor")]
@@ -89,6 +90,137 @@ impl ToCss for BorderRadius {
   }
 }
 
+/// A [`<corner-shape-value>`](https://drafts.csswg.org/css-borders-4/#typedef-corner-shape-value),
+/// as used in the `corner-shape` property and its longhands.
+///
+/// `superellipse()` values that are equivalent to a keyword are parsed as that keyword.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum CornerShapeValue {
+  /// A convex elliptical curve, equivalent to `superellipse(1)`.
+  #[default]
+  Round,
+  /// A concave elliptical curve, equivalent to `superellipse(-1)`.
+  Scoop,
+  /// A straight diagonal line, equivalent to `superellipse(0)`.
+  Bevel,
+  /// A concave square notch, equivalent to `superellipse(-infinity)`.
+  Notch,
+  /// A convex square, equivalent to `superellipse(infinity)`.
+  Square,
+  /// A curve between round and square, equivalent to `superellipse(2)`.
+  Squircle,
+  /// A `superellipse()` function with the given curvature.
+  Superellipse(CSSNumber),
+}
+
+impl<'i> Parse<'i> for CornerShapeValue {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    if let Ok(ident) = input.try_parse(|input| input.expect_ident_cloned()) {
+      return match_ignore_ascii_case! { &ident,
+        "round" => Ok(CornerShapeValue::Round),
+        "scoop" => Ok(CornerShapeValue::Scoop),
+        "bevel" => Ok(CornerShapeValue::Bevel),
+        "notch" => Ok(CornerShapeValue::Notch),
+        "square" => Ok(CornerShapeValue::Square),
+        "squircle" => Ok(CornerShapeValue::Squircle),
+        _ => Err(location.new_unexpected_token_error(Token::Ident(ident)))
+      };
+    }
+
+    input.expect_function_matching("superellipse")?;
+    let k = input.parse_nested_block(|input| {
+      if let Ok(ident) = input.try_parse(|input| input.expect_ident_cloned()) {
+        return match_ignore_ascii_case! { &ident,
+          "infinity" => Ok(CSSNumber::INFINITY),
+          "-infinity" => Ok(CSSNumber::NEG_INFINITY),
+          _ => Err(location.new_unexpected_token_error(Token::Ident(ident)))
+        };
+      }
+
+      let k = CSSNumber::parse(input)?;
+      if k.is_nan() {
+        return Err(location.new_custom_error(ParserError::InvalidValue));
+      }
+      Ok(k)
+    })?;
+
+    Ok(match k {
+      k if k == 1.0 => CornerShapeValue::Round,
+      k if k == -1.0 => CornerShapeValue::Scoop,
+      k if k == 0.0 => CornerShapeValue::Bevel,
+      k if k == 2.0 => CornerShapeValue::Squircle,
+      k if k == CSSNumber::INFINITY => CornerShapeValue::Square,
+      k if k == CSSNumber::NEG_INFINITY => CornerShapeValue::Notch,
+      k => CornerShapeValue::Superellipse(k),
+    })
+  }
+}
+
+impl ToCss for CornerShapeValue {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      CornerShapeValue::Round => dest.write_str("round"),
+      CornerShapeValue::Scoop => dest.write_str("scoop"),
+      CornerShapeValue::Bevel => dest.write_str("bevel"),
+      CornerShapeValue::Notch => dest.write_str("notch"),
+      CornerShapeValue::Square => dest.write_str("square"),
+      CornerShapeValue::Squircle => dest.write_str("squircle"),
+      CornerShapeValue::Superellipse(k) => {
+        dest.write_str("superellipse(")?;
+        k.to_css(dest)?;
+        dest.write_char(')')
+      }
+    }
+  }
+}
+
+define_shorthand! {
+  /// A value for the [corner-shape](https://drafts.csswg.org/css-borders-4/#propdef-corner-shape) shorthand property.
+  pub struct CornerShape {
+    /// The shape of the top left corner.
+    top_left: CornerTopLeftShape(CornerShapeValue),
+    /// The shape of the top right corner.
+    top_right: CornerTopRightShape(CornerShapeValue),
+    /// The shape of the bottom right corner.
+    bottom_right: CornerBottomRightShape(CornerShapeValue),
+    /// The shape of the bottom left corner.
+    bottom_left: CornerBottomLeftShape(CornerShapeValue),
+  }
+}
+
+impl<'i> Parse<'i> for CornerShape {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let rect: Rect<CornerShapeValue> = Rect::parse(input)?;
+    Ok(CornerShape {
+      top_left: rect.0,
+      top_right: rect.1,
+      bottom_right: rect.2,
+      bottom_left: rect.3,
+    })
+  }
+}
+
+impl ToCss for CornerShape {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    Rect::new(&self.top_left, &self.top_right, &self.bottom_right, &self.bottom_left).to_css(dest)
+  }
+}
+
 #[derive(Default, Debug)]
 pub(crate) struct BorderRadiusHandler<'i> {
   top_left: Option<(Size2D<LengthPercentage>, VendorPrefix)>,
@@ -99,6 +231,14 @@ pub(crate) struct BorderRadiusHandler<'i> {
   start_end: Option<Property<'i>>,
   end_end: Option<Property<'i>>,
   end_start: Option<Property<'i>>,
+  shape_top_left: Option<CornerShapeValue>,
+  shape_top_right: Option<CornerShapeValue>,
+  shape_bottom_right: Option<CornerShapeValue>,
+  shape_bottom_left: Option<CornerShapeValue>,
+  shape_start_start: Option<Property<'i>>,
+  shape_start_end: Option<Property<'i>>,
+  shape_end_end: Option<Property<'i>>,
+  shape_end_start: Option<Property<'i>>,
   category: PropertyCategory,
   has_any: bool,
 }
@@ -161,6 +301,18 @@ impl<'i> PropertyHandler<'i> for BorderRadiusHandler<'i> {
       }};
     }
 
+    macro_rules! shape_property {
+      ($prop: ident, $val: expr) => {{
+        if self.category != PropertyCategory::Physical {
+          self.flush(dest, context);
+        }
+
+        self.$prop = Some($val.clone());
+        self.category = PropertyCategory::Physical;
+        self.has_any = true;
+      }};
+    }
+
     match property {
       BorderTopLeftRadius(val, vp) => property!(top_left, val, vp),
       BorderTopRightRadius(val, vp) => property!(top_right, val, vp),
@@ -170,6 +322,34 @@ impl<'i> PropertyHandler<'i> for BorderRadiusHandler<'i> {
       BorderStartEndRadius(_) => logical_property!(start_end),
       BorderEndEndRadius(_) => logical_property!(end_end),
       BorderEndStartRadius(_) => logical_property!(end_start),
+      CornerTopLeftShape(val) => shape_property!(shape_top_left, val),
+      CornerTopRightShape(val) => shape_property!(shape_top_right, val),
+      CornerBottomRightShape(val) => shape_property!(shape_bottom_right, val),
+      CornerBottomLeftShape(val) => shape_property!(shape_bottom_left, val),
+      CornerStartStartShape(_) => logical_property!(shape_start_start),
+      CornerStartEndShape(_) => logical_property!(shape_start_end),
+      CornerEndEndShape(_) => logical_property!(shape_end_end),
+      CornerEndStartShape(_) => logical_property!(shape_end_start),
+      CornerShape(val) => {
+        self.shape_start_start = None;
+        self.shape_start_end = None;
+        self.shape_end_end = None;
+        self.shape_end_start = None;
+        shape_property!(shape_top_left, val.top_left);
+        shape_property!(shape_top_right, val.top_right);
+        shape_property!(shape_bottom_right, val.bottom_right);
+        shape_property!(shape_bottom_left, val.bottom_left);
+      }
+      Unparsed(val) if is_corner_shape_property(&val.property_id) => match &val.property_id {
+        PropertyId::CornerStartStartShape => logical_property!(shape_start_start),
+        PropertyId::CornerStartEndShape => logical_property!(shape_start_end),
+        PropertyId::CornerEndEndShape => logical_property!(shape_end_end),
+        PropertyId::CornerEndStartShape => logical_property!(shape_end_start),
+        _ => {
+          self.flush(dest, context);
+          dest.push(property.clone());
+        }
+      },
       BorderRadius(val, vp) => {
         self.start_start = None;
         self.start_end = None;
@@ -194,9 +374,9 @@ impl<'i> PropertyHandler<'i> for BorderRadiusHandler<'i> {
           PropertyId::BorderEndStartRadius => logical_property!(end_start),
           _ => {
             self.flush(dest, context);
-            dest.push(Property::Unparsed(
-              val.get_prefixed(context.targets, Feature::BorderRadius),
-            ));
+            let (unparsed, prefixed) = context.prefix_unparsed(val, Feature::BorderRadius);
+            dest.extend(prefixed);
+            dest.push(Property::Unparsed(unparsed));
           }
         }
       }
@@ -227,6 +407,14 @@ impl<'i> BorderRadiusHandler<'i> {
     let start_end = std::mem::take(&mut self.start_end);
     let end_end = std::mem::take(&mut self.end_end);
     let end_start = std::mem::take(&mut self.end_start);
+    let shape_top_left = std::mem::take(&mut self.shape_top_left);
+    let shape_top_right = std::mem::take(&mut self.shape_top_right);
+    let shape_bottom_right = std::mem::take(&mut self.shape_bottom_right);
+    let shape_bottom_left = std::mem::take(&mut self.shape_bottom_left);
+    let shape_start_start = std::mem::take(&mut self.shape_start_start);
+    let shape_start_end = std::mem::take(&mut self.shape_start_end);
+    let shape_end_end = std::mem::take(&mut self.shape_end_end);
+    let shape_end_start = std::mem::take(&mut self.shape_end_start);
 
     if let (
       Some((top_left, tl_prefix)),
@@ -238,15 +426,17 @@ impl<'i> BorderRadiusHandler<'i> {
       let intersection = *tl_prefix & *tr_prefix & *br_prefix & *bl_prefix;
       if !intersection.is_empty() {
         let prefix = context.targets.prefixes(intersection, Feature::BorderRadius);
-        dest.push(Property::BorderRadius(
-          BorderRadius {
-            top_left: top_left.clone(),
-            top_right: top_right.clone(),
-            bottom_right: bottom_right.clone(),
-            bottom_left: bottom_left.clone(),
-          },
-          prefix,
-        ));
+        let border_radius = BorderRadius {
+          top_left: top_left.clone(),
+          top_right: top_right.clone(),
+          bottom_right: bottom_right.clone(),
+          bottom_left: bottom_left.clone(),
+        };
+        let (prefix, prefixed) = context.split_prefixes(intersection, prefix, |vp| {
+          Property::BorderRadius(border_radius.clone(), vp)
+        });
+        dest.extend(prefixed);
+        dest.push(Property::BorderRadius(border_radius, prefix));
         tl_prefix.remove(intersection);
         tr_prefix.remove(intersection);
         br_prefix.remove(intersection);
@@ -256,9 +446,11 @@ impl<'i> BorderRadiusHandler<'i> {
 
     macro_rules! single_property {
       ($prop: ident, $key: ident) => {
-        if let Some((val, mut vp)) = $key {
+        if let Some((val, vp)) = $key {
           if !vp.is_empty() {
-            vp = context.targets.prefixes(vp, Feature::$prop);
+            let prefixes = context.targets.prefixes(vp, Feature::$prop);
+            let (vp, prefixed) = context.split_prefixes(vp, prefixes, |vp| Property::$prop(val.clone(), vp));
+            dest.extend(prefixed);
             dest.push(Property::$prop(val, vp))
           }
         }
@@ -322,6 +514,39 @@ impl<'i> BorderRadiusHandler<'i> {
       BorderBottomLeftRadius,
       BorderBottomRightRadius
     );
+
+    // Corner shapes are independent of the radii, so they are combined separately.
+    // Browsers that support corner-shape also support the logical longhands.
+    match (shape_top_left, shape_top_right, shape_bottom_right, shape_bottom_left) {
+      (Some(top_left), Some(top_right), Some(bottom_right), Some(bottom_left)) => {
+        dest.push(Property::CornerShape(CornerShape {
+          top_left,
+          top_right,
+          bottom_right,
+          bottom_left,
+        }));
+      }
+      (top_left, top_right, bottom_right, bottom_left) => {
+        if let Some(val) = top_left {
+          dest.push(Property::CornerTopLeftShape(val));
+        }
+        if let Some(val) = top_right {
+          dest.push(Property::CornerTopRightShape(val));
+        }
+        if let Some(val) = bottom_right {
+          dest.push(Property::CornerBottomRightShape(val));
+        }
+        if let Some(val) = bottom_left {
+          dest.push(Property::CornerBottomLeftShape(val));
+        }
+      }
+    }
+
+    dest.extend(
+      [shape_start_start, shape_start_end, shape_end_end, shape_end_start]
+        .into_iter()
+        .flatten(),
+    );
   }
 }
 
@@ -351,3 +576,19 @@ fn is_logical_border_radius_property(property_id: &PropertyId) -> bool {
     _ => false,
   }
 }
+
+#[inline]
+fn is_corner_shape_property(property_id: &PropertyId) -> bool {
+  matches!(
+    property_id,
+    PropertyId::CornerTopLeftShape
+      | PropertyId::CornerTopRightShape
+      | PropertyId::CornerBottomRightShape
+      | PropertyId::CornerBottomLeftShape
+      | PropertyId::CornerStartStartShape
+      | PropertyId::CornerStartEndShape
+      | PropertyId::CornerEndEndShape
+      | PropertyId::CornerEndStartShape
+      | PropertyId::CornerShape
+  )
+}
@@ -198,27 +198,36 @@ impl BoxShadowHandler {
 
     if let Some((box_shadows, prefixes)) = box_shadows {
       if !self.flushed {
+        let authored = prefixes;
         let mut prefixes = context.targets.prefixes(prefixes, Feature::BoxShadow);
         let mut fallbacks = ColorFallbackKind::empty();
         for shadow in &box_shadows {
           fallbacks |= shadow.color.get_necessary_fallbacks(context.targets);
         }
 
+        let mut fallback_decls = Vec::new();
         if fallbacks.contains(ColorFallbackKind::RGB) {
-          let rgb = box_shadows
+          let rgb: SmallVec<[BoxShadow; 1]> = box_shadows
             .iter()
             .map(|shadow| BoxShadow {
-              color: shadow.color.to_rgb().unwrap_or_else(|_| shadow.color.clone()),
+              color: shadow
+                .color
+                .to_rgb_with_gamut_mapping(context.gamut_mapping)
+                .unwrap_or_else(|_| shadow.color.clone()),
               ..shadow.clone()
             })
             .collect();
-          dest.push(Property::BoxShadow(rgb, prefixes));
-          if prefixes.contains(VendorPrefix::None) {
-            prefixes = VendorPrefix::None;
-          } else {
+          if !prefixes.contains(VendorPrefix::None) {
             // Only output RGB for prefixed property (e.g. -webkit-box-shadow)
+            dest.push(Property::BoxShadow(rgb, prefixes));
             return;
           }
+
+          let (rgb_prefixes, prefixed) =
+            context.split_prefixes(authored, prefixes, |vp| Property::BoxShadow(rgb.clone(), vp));
+          dest.extend(prefixed);
+          fallback_decls.push(Property::BoxShadow(rgb, rgb_prefixes));
+          prefixes = VendorPrefix::None;
         }
 
         if fallbacks.contains(ColorFallbackKind::P3) {
@@ -229,9 +238,11 @@ impl BoxShadowHandler {
               ..shadow.clone()
             })
             .collect();
-          dest.push(Property::BoxShadow(p3, VendorPrefix::None));
+          fallback_decls.push(Property::BoxShadow(p3, VendorPrefix::None));
         }
 
+        dest.extend(context.fallbacks(|| Property::BoxShadow(box_shadows.clone(), prefixes), fallback_decls));
+
         if fallbacks.contains(ColorFallbackKind::LAB) {
           let lab = box_shadows
             .iter()
@@ -242,6 +253,9 @@ impl BoxShadowHandler {
             .collect();
           dest.push(Property::BoxShadow(lab, VendorPrefix::None));
         } else {
+          let (prefixes, prefixed) =
+            context.split_prefixes(authored, prefixes, |vp| Property::BoxShadow(box_shadows.clone(), vp));
+          dest.extend(prefixed);
           dest.push(Property::BoxShadow(box_shadows, prefixes))
         }
       } else {
@@ -8,11 +8,11 @@ use crate::properties::PropertyId;
 use crate::rules::supports::SupportsCondition;
 use crate::stylesheet::ParserOptions;
 use crate::targets::{should_compile, Targets};
-use crate::traits::{Parse, ParseWithOptions, ToCss};
+use crate::traits::{private::TryAdd, Parse, ParseWithOptions, ToCss};
 use crate::values::angle::Angle;
 use crate::values::color::{
-  parse_hsl_hwb_components, parse_rgb_components, ColorFallbackKind, ComponentParser, CssColor, LightDarkColor,
-  HSL, RGBA, SRGB,
+  parse_hsl_hwb_components, parse_rgb_components, ColorFallbackKind, ComponentParser, CssColor, GamutMapping,
+  LightDarkColor, HSL, RGBA, SRGB,
 };
 use crate::values::ident::{CustomIdent, DashedIdent, DashedIdentReference, Ident};
 use crate::values::length::{serialize_dimension, LengthValue};
@@ -20,6 +20,7 @@ use crate::values::number::CSSInteger;
 use crate::values::percentage::Percentage;
 use crate::values::resolution::Resolution;
 use crate::values::string::CowArcStr;
+use crate::values::syntax::SyntaxString;
 use crate::values::time::Time;
 use crate::values::url::Url;
 #[cfg(feature = "visitor")]
@@ -180,14 +181,29 @@ impl<'i> UnparsedProperty<'i> {
     mut self,
     vars: &std::collections::HashMap<&str, TokenList<'i>>,
   ) -> Result<super::Property<'x>, ()> {
+    // Substitute variables in the token list.
+    self.value.substitute_variables(vars);
+    self.reparse()
+  }
+
+  /// Substitutes environment variables and re-parses the property.
+  #[cfg(feature = "substitute_variables")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "substitute_variables")))]
+  pub fn substitute_environment_variables<'x>(
+    mut self,
+    env: &std::collections::HashMap<String, TokenList<'i>>,
+  ) -> Result<super::Property<'x>, ()> {
+    self.value.substitute_environment_variables(env);
+    self.reparse()
+  }
+
+  #[cfg(feature = "substitute_variables")]
+  pub(crate) fn reparse<'x>(&self) -> Result<super::Property<'x>, ()> {
     use super::Property;
     use crate::stylesheet::PrinterOptions;
     use static_self::IntoOwned;
 
-    // Substitute variables in the token list.
-    self.value.substitute_variables(vars);
-
-    // Now stringify and re-parse the property to its fully parsed form.
+    // Stringify and re-parse the property to its fully parsed form.
     // Ideally we'd be able to reuse the tokens rather than printing, but cssparser doesn't provide a way to do that.
     let mut css = String::new();
     let mut dest = Printer::new(&mut css, PrinterOptions::default());
@@ -230,6 +246,10 @@ pub enum TokenOrValue<'i> {
   Var(Variable<'i>),
   /// A CSS environment variable reference.
   Env(EnvironmentVariable<'i>),
+  /// An attribute reference.
+  Attr(AttrFunction<'i>),
+  /// A toggle() value.
+  Toggle(ToggleFunction<'i>),
   /// A custom CSS function.
   Function(Function<'i>),
   /// A length.
@@ -415,8 +435,22 @@ impl<'i> TokenList<'i> {
             tokens.push(env);
             last_is_delim = true;
             last_is_whitespace = false;
+          } else if let Some(attr) = try_parse_attr_token(&f, input, options, depth) {
+            tokens.push(TokenOrValue::Attr(attr));
+            last_is_delim = true;
+            last_is_whitespace = false;
+          } else if let Some(toggle) = try_parse_toggle_token(&f, input, options, depth) {
+            tokens.push(TokenOrValue::Toggle(toggle));
+            last_is_delim = true;
+            last_is_whitespace = false;
           } else {
-            let arguments = input.parse_nested_block(|input| TokenList::parse(input, options, depth + 1))?;
+            let mut arguments = input.parse_nested_block(|input| TokenList::parse(input, options, depth + 1))?;
+            match_ignore_ascii_case! { &*f,
+              "min" => arguments.reduce_min_max_args(std::cmp::Ordering::Less),
+              "max" => arguments.reduce_min_max_args(std::cmp::Ordering::Greater),
+              "calc" => arguments.reduce_calc_args(),
+              _ => {}
+            }
             tokens.push(TokenOrValue::Function(Function {
               name: Ident(f),
               arguments,
@@ -506,6 +540,262 @@ impl<'i> TokenList<'i> {
   }
 }
 
+impl<'i> TokenList<'i> {
+  /// Removes constant arguments of a `min()` or `max()` function that can never win,
+  /// e.g. `min(10px, 20px, var(--x))` => `min(10px, var(--x))`.
+  fn reduce_min_max_args(&mut self, cmp: std::cmp::Ordering) {
+    // Split the arguments at top-level commas.
+    let mut args: Vec<Vec<TokenOrValue<'i>>> = vec![vec![]];
+    let mut depth = 0;
+    for token in &self.0 {
+      match token {
+        TokenOrValue::Token(Token::ParenthesisBlock | Token::SquareBracketBlock | Token::CurlyBracketBlock) => {
+          depth += 1
+        }
+        TokenOrValue::Token(Token::CloseParenthesis | Token::CloseSquareBracket | Token::CloseCurlyBracket) => {
+          depth -= 1
+        }
+        TokenOrValue::Token(Token::Comma) if depth == 0 => {
+          args.push(vec![]);
+          continue;
+        }
+        _ => {}
+      }
+      args.last_mut().unwrap().push(token.clone());
+    }
+
+    fn compare(a: &TokenOrValue, b: &TokenOrValue) -> Option<std::cmp::Ordering> {
+      match (a, b) {
+        (TokenOrValue::Length(a), TokenOrValue::Length(b)) => a.partial_cmp(b),
+        (
+          TokenOrValue::Token(Token::Number { value: a, .. }),
+          TokenOrValue::Token(Token::Number { value: b, .. }),
+        ) => a.partial_cmp(b),
+        (
+          TokenOrValue::Token(Token::Percentage { unit_value: a, .. }),
+          TokenOrValue::Token(Token::Percentage { unit_value: b, .. }),
+        ) => a.partial_cmp(b),
+        _ => None,
+      }
+    }
+
+    // Keep only the winning value among each set of comparable constants, in the position of the first one.
+    let len = args.len();
+    let mut reduced: Vec<Vec<TokenOrValue<'i>>> = Vec::with_capacity(len);
+    'outer: for arg in args {
+      if let Some(value) = constant(&arg) {
+        for r in reduced.iter_mut() {
+          if let Some(ord) = constant(r).and_then(|v| compare(value, v)) {
+            if ord == cmp {
+              *r = arg;
+            }
+            continue 'outer;
+          }
+        }
+      }
+      reduced.push(arg);
+    }
+
+    if reduced.len() == len {
+      return;
+    }
+
+    self.0.clear();
+    for (i, arg) in reduced.into_iter().enumerate() {
+      if i > 0 {
+        self.0.push(TokenOrValue::Token(Token::Comma));
+      }
+      let start = arg.iter().position(|token| !token.is_whitespace()).unwrap_or(arg.len());
+      let end = arg.iter().rposition(|token| !token.is_whitespace()).map_or(start, |i| i + 1);
+      self.0.extend(arg.into_iter().take(end).skip(start));
+    }
+  }
+
+  /// Combines the constant terms of a `calc()` sum that cannot be fully parsed,
+  /// e.g. `calc(var(--x) + 10px + 20px)` => `calc(var(--x) + 30px)`.
+  fn reduce_calc_args(&mut self) {
+    // Split the sum into terms at top-level `+` and `-` operators, along with whether each term is subtracted.
+    let mut terms: Vec<(bool, Vec<TokenOrValue<'i>>)> = vec![(false, vec![])];
+    let mut depth = 0;
+    for token in &self.0 {
+      match token {
+        TokenOrValue::Token(Token::ParenthesisBlock | Token::SquareBracketBlock | Token::CurlyBracketBlock) => {
+          depth += 1
+        }
+        TokenOrValue::Token(Token::CloseParenthesis | Token::CloseSquareBracket | Token::CloseCurlyBracket) => {
+          depth -= 1
+        }
+        TokenOrValue::Token(Token::Delim(d @ ('+' | '-'))) if depth == 0 => {
+          terms.push((*d == '-', vec![]));
+          continue;
+        }
+        _ => {}
+      }
+      terms.last_mut().unwrap().1.push(token.clone());
+    }
+
+    if terms.iter().any(|(_, term)| term.iter().all(|token| token.is_whitespace())) {
+      return;
+    }
+
+    fn negate<'i>(token: &TokenOrValue<'i>) -> TokenOrValue<'i> {
+      match token {
+        TokenOrValue::Length(v) => TokenOrValue::Length(v.clone() * -1.0),
+        TokenOrValue::Token(Token::Number { value, int_value, .. }) => TokenOrValue::Token(Token::Number {
+          has_sign: false,
+          value: -value,
+          int_value: int_value.and_then(|v| v.checked_neg()),
+        }),
+        TokenOrValue::Token(Token::Percentage {
+          unit_value, int_value, ..
+        }) => TokenOrValue::Token(Token::Percentage {
+          has_sign: false,
+          unit_value: -unit_value,
+          int_value: int_value.and_then(|v| v.checked_neg()),
+        }),
+        _ => token.clone(),
+      }
+    }
+
+    fn is_negative(token: &TokenOrValue) -> bool {
+      match token {
+        TokenOrValue::Length(v) => v.to_unit_value().0 < 0.0,
+        TokenOrValue::Token(Token::Number { value, .. }) => *value < 0.0,
+        TokenOrValue::Token(Token::Percentage { unit_value, .. }) => *unit_value < 0.0,
+        _ => false,
+      }
+    }
+
+    fn add<'i>(a: &TokenOrValue<'i>, b: &TokenOrValue<'i>) -> Option<TokenOrValue<'i>> {
+      match (a, b) {
+        (TokenOrValue::Length(a), TokenOrValue::Length(b)) => a.try_add(b).map(TokenOrValue::Length),
+        (
+          TokenOrValue::Token(Token::Number {
+            value: a,
+            int_value: ai,
+            ..
+          }),
+          TokenOrValue::Token(Token::Number {
+            value: b,
+            int_value: bi,
+            ..
+          }),
+        ) => Some(TokenOrValue::Token(Token::Number {
+          has_sign: false,
+          value: a + b,
+          int_value: ai.zip(*bi).and_then(|(a, b)| a.checked_add(b)),
+        })),
+        (
+          TokenOrValue::Token(Token::Percentage {
+            unit_value: a,
+            int_value: ai,
+            ..
+          }),
+          TokenOrValue::Token(Token::Percentage {
+            unit_value: b,
+            int_value: bi,
+            ..
+          }),
+        ) => Some(TokenOrValue::Token(Token::Percentage {
+          has_sign: false,
+          unit_value: a + b,
+          int_value: ai.zip(*bi).and_then(|(a, b)| a.checked_add(b)),
+        })),
+        _ => None,
+      }
+    }
+
+    // Add each constant to the first compatible constant, keeping it in that position.
+    // Constants are stored with their sign applied.
+    let len = terms.len();
+    let mut reduced: Vec<(bool, Vec<TokenOrValue<'i>>)> = Vec::with_capacity(len);
+    'outer: for (negative, term) in terms {
+      if let Some(value) = constant(&term) {
+        let value = if negative { negate(value) } else { value.clone() };
+        for (_, r) in reduced.iter_mut() {
+          if let Some(sum) = constant(r).and_then(|v| add(v, &value)) {
+            *r = vec![sum];
+            continue 'outer;
+          }
+        }
+        reduced.push((false, vec![value]));
+        continue;
+      }
+      reduced.push((negative, term));
+    }
+
+    if reduced.len() == len {
+      return;
+    }
+
+    self.0.clear();
+    for (i, (negative, term)) in reduced.into_iter().enumerate() {
+      let start = term.iter().position(|token| !token.is_whitespace()).unwrap_or(term.len());
+      let end = term.iter().rposition(|token| !token.is_whitespace()).map_or(start, |i| i + 1);
+      let mut term: Vec<_> = term.into_iter().take(end).skip(start).collect();
+      if i > 0 {
+        let mut negative = negative;
+        if term.len() == 1 && is_negative(&term[0]) {
+          term[0] = negate(&term[0]);
+          negative = !negative;
+        }
+        self.0.push(TokenOrValue::Token(Token::Delim(if negative { '-' } else { '+' })));
+      }
+      self.0.extend(term);
+    }
+  }
+}
+
+/// Returns the value of an argument that consists of a single constant length, number, or percentage.
+fn constant<'a, 'i>(arg: &'a [TokenOrValue<'i>]) -> Option<&'a TokenOrValue<'i>> {
+  let mut tokens = arg.iter().filter(|token| !token.is_whitespace());
+  match (tokens.next(), tokens.next()) {
+    (
+      Some(
+        token @ (TokenOrValue::Length(..)
+        | TokenOrValue::Token(Token::Number { .. })
+        | TokenOrValue::Token(Token::Percentage { .. })),
+      ),
+      None,
+    ) => Some(token),
+    _ => None,
+  }
+}
+
+#[inline]
+fn try_parse_attr_token<'i>(
+  f: &CowArcStr<'i>,
+  input: &mut Parser<'i, '_>,
+  options: &ParserOptions<'_, 'i>,
+  depth: usize,
+) -> Option<AttrFunction<'i>> {
+  if !f.eq_ignore_ascii_case("attr") {
+    return None;
+  }
+
+  // Invalid attr() references are preserved as regular functions.
+  input
+    .try_parse(|input| input.parse_nested_block(|input| AttrFunction::parse_nested(input, options, depth + 1)))
+    .ok()
+}
+
+#[inline]
+fn try_parse_toggle_token<'i>(
+  f: &CowArcStr<'i>,
+  input: &mut Parser<'i, '_>,
+  options: &ParserOptions<'_, 'i>,
+  depth: usize,
+) -> Option<ToggleFunction<'i>> {
+  if !f.eq_ignore_ascii_case("toggle") {
+    return None;
+  }
+
+  // Invalid toggle() values are preserved as regular functions.
+  input
+    .try_parse(|input| input.parse_nested_block(|input| ToggleFunction::parse_nested(input, options, depth + 1)))
+    .ok()
+}
+
 #[inline]
 fn try_parse_color_token<'i, 't>(
   f: &CowArcStr<'i>,
@@ -567,6 +857,14 @@ impl<'i> TokenList<'i> {
           env.to_css(dest, is_custom_property)?;
           self.write_whitespace_if_needed(i, dest)?
         }
+        TokenOrValue::Attr(attr) => {
+          attr.to_css(dest, is_custom_property)?;
+          self.write_whitespace_if_needed(i, dest)?
+        }
+        TokenOrValue::Toggle(toggle) => {
+          toggle.to_css(dest, is_custom_property)?;
+          self.write_whitespace_if_needed(i, dest)?
+        }
         TokenOrValue::Function(f) => {
           f.to_css(dest, is_custom_property)?;
           self.write_whitespace_if_needed(i, dest)?
@@ -1115,6 +1413,16 @@ impl<'i> TokenList<'i> {
             fallbacks |= fallback.get_necessary_fallbacks(targets);
           }
         }
+        TokenOrValue::Attr(a) => {
+          if let Some(fallback) = &a.fallback {
+            fallbacks |= fallback.get_necessary_fallbacks(targets);
+          }
+        }
+        TokenOrValue::Toggle(t) => {
+          for value in &t.values {
+            fallbacks |= value.get_necessary_fallbacks(targets);
+          }
+        }
         _ => {}
       }
     }
@@ -1122,22 +1430,30 @@ impl<'i> TokenList<'i> {
     fallbacks
   }
 
-  pub(crate) fn get_fallback(&self, kind: ColorFallbackKind) -> Self {
+  pub(crate) fn get_fallback(&self, kind: ColorFallbackKind, gamut_mapping: GamutMapping) -> Self {
     let tokens = self
       .0
       .iter()
       .map(|token| match token {
-        TokenOrValue::Color(color) => TokenOrValue::Color(color.get_fallback(kind)),
-        TokenOrValue::Function(f) => TokenOrValue::Function(f.get_fallback(kind)),
-        TokenOrValue::Var(v) => TokenOrValue::Var(v.get_fallback(kind)),
-        TokenOrValue::Env(e) => TokenOrValue::Env(e.get_fallback(kind)),
+        TokenOrValue::Color(color) => {
+          TokenOrValue::Color(color.get_fallback_with_gamut_mapping(kind, gamut_mapping))
+        }
+        TokenOrValue::Function(f) => TokenOrValue::Function(f.get_fallback(kind, gamut_mapping)),
+        TokenOrValue::Var(v) => TokenOrValue::Var(v.get_fallback(kind, gamut_mapping)),
+        TokenOrValue::Env(e) => TokenOrValue::Env(e.get_fallback(kind, gamut_mapping)),
+        TokenOrValue::Attr(a) => TokenOrValue::Attr(a.get_fallback(kind, gamut_mapping)),
+        TokenOrValue::Toggle(t) => TokenOrValue::Toggle(t.get_fallback(kind, gamut_mapping)),
         _ => token.clone(),
       })
       .collect();
     TokenList(tokens)
   }
 
-  pub(crate) fn get_fallbacks(&mut self, targets: Targets) -> Vec<(SupportsCondition<'i>, Self)> {
+  pub(crate) fn get_fallbacks(
+    &mut self,
+    targets: Targets,
+    gamut_mapping: GamutMapping,
+  ) -> Vec<(SupportsCondition<'i>, Self)> {
     // Get the full list of possible fallbacks, and remove the lowest one, which will replace
     // the original declaration. The remaining fallbacks need to be added as @supports rules.
     let mut fallbacks = self.get_necessary_fallbacks(targets);
@@ -1148,14 +1464,14 @@ impl<'i> TokenList<'i> {
     if fallbacks.contains(ColorFallbackKind::P3) {
       res.push((
         ColorFallbackKind::P3.supports_condition(),
-        self.get_fallback(ColorFallbackKind::P3),
+        self.get_fallback(ColorFallbackKind::P3, gamut_mapping),
       ));
     }
 
     if fallbacks.contains(ColorFallbackKind::LAB) {
       res.push((
         ColorFallbackKind::LAB.supports_condition(),
-        self.get_fallback(ColorFallbackKind::LAB),
+        self.get_fallback(ColorFallbackKind::LAB, gamut_mapping),
       ));
     }
 
@@ -1163,11 +1479,13 @@ impl<'i> TokenList<'i> {
       for token in self.0.iter_mut() {
         match token {
           TokenOrValue::Color(color) => {
-            *color = color.get_fallback(lowest_fallback);
+            *color = color.get_fallback_with_gamut_mapping(lowest_fallback, gamut_mapping);
           }
-          TokenOrValue::Function(f) => *f = f.get_fallback(lowest_fallback),
-          TokenOrValue::Var(v) if v.fallback.is_some() => *v = v.get_fallback(lowest_fallback),
-          TokenOrValue::Env(v) if v.fallback.is_some() => *v = v.get_fallback(lowest_fallback),
+          TokenOrValue::Function(f) => *f = f.get_fallback(lowest_fallback, gamut_mapping),
+          TokenOrValue::Var(v) if v.fallback.is_some() => *v = v.get_fallback(lowest_fallback, gamut_mapping),
+          TokenOrValue::Env(v) if v.fallback.is_some() => *v = v.get_fallback(lowest_fallback, gamut_mapping),
+          TokenOrValue::Attr(a) if a.fallback.is_some() => *a = a.get_fallback(lowest_fallback, gamut_mapping),
+          TokenOrValue::Toggle(t) => *t = t.get_fallback(lowest_fallback, gamut_mapping),
           _ => {}
         }
       }
@@ -1182,6 +1500,25 @@ impl<'i> TokenList<'i> {
   pub fn substitute_variables(&mut self, vars: &std::collections::HashMap<&str, TokenList<'i>>) {
     self.visit(&mut VarInliner { vars }).unwrap()
   }
+
+  /// Substitutes environment variables with the provided values. Author-defined
+  /// environment variables that are not provided are replaced with their fallback, if any.
+  #[cfg(feature = "substitute_variables")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "substitute_variables")))]
+  pub fn substitute_environment_variables(&mut self, env: &std::collections::HashMap<String, TokenList<'i>>) {
+    self.substitute_env(env);
+  }
+
+  /// Substitutes environment variables, and returns whether any were replaced.
+  #[cfg(feature = "substitute_variables")]
+  pub(crate) fn substitute_env(&mut self, env: &std::collections::HashMap<String, TokenList<'i>>) -> bool {
+    let mut inliner = EnvInliner {
+      env,
+      substituted: false,
+    };
+    self.visit(&mut inliner).unwrap();
+    inliner.substituted
+  }
 }
 
 #[cfg(feature = "substitute_variables")]
@@ -1226,6 +1563,67 @@ impl<'a, 'i> crate::visitor::Visitor<'i> for VarInliner<'a, 'i> {
   }
 }
 
+#[cfg(feature = "substitute_variables")]
+struct EnvInliner<'a, 'i> {
+  env: &'a std::collections::HashMap<String, TokenList<'i>>,
+  substituted: bool,
+}
+
+#[cfg(feature = "substitute_variables")]
+impl<'a, 'i> crate::visitor::Visitor<'i> for EnvInliner<'a, 'i> {
+  type Error = std::convert::Infallible;
+
+  fn visit_types(&self) -> crate::visitor::VisitTypes {
+    crate::visit_types!(TOKENS | ENVIRONMENT_VARIABLES)
+  }
+
+  fn visit_token_list(&mut self, tokens: &mut TokenList<'i>) -> Result<(), Self::Error> {
+    let mut i = 0;
+    let mut seen = std::collections::HashSet::new();
+    while i < tokens.0.len() {
+      let token = &mut tokens.0[i];
+      token.visit(self).unwrap();
+      if let TokenOrValue::Env(env) = token {
+        let name = env.name.name();
+        let value = match self.env.get(name) {
+          // Indexed values cannot be resolved from a single token list.
+          Some(value) if env.indices.is_empty() => Some(value.0.clone()),
+          Some(_) => None,
+          // UA environment variables are resolved by the browser at runtime.
+          None if matches!(env.name, EnvironmentVariableName::UA(_)) => None,
+          None => env.fallback.as_ref().map(|fallback| fallback.0.clone()),
+        };
+        if let Some(mut value) = value {
+          // Ignore circular references.
+          if seen.insert(name.to_owned()) {
+            // Whitespace following env() is removed during parsing, so add it back
+            // unless the value ends with a function, which doesn't need it.
+            if matches!(
+              value.last(),
+              Some(token) if !matches!(token, TokenOrValue::Var(..) | TokenOrValue::Env(..) | TokenOrValue::Function(..))
+            ) && matches!(
+              tokens.0.get(i + 1),
+              Some(token) if !token.is_whitespace() && !matches!(
+                token,
+                TokenOrValue::Token(Token::Comma) | TokenOrValue::Token(Token::CloseParenthesis)
+              )
+            ) {
+              value.push(TokenOrValue::Token(Token::WhiteSpace(" ".into())));
+            }
+            tokens.0.splice(i..i + 1, value);
+            self.substituted = true;
+            // Don't advance. We need to replace any environment variables in the value.
+            continue;
+          }
+        }
+      }
+      seen.clear();
+      i += 1;
+    }
+    Ok(())
+  }
+}
+
 /// A CSS variable reference.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
@@ -1271,10 +1669,13 @@ impl<'i> Variable<'i> {
     dest.write_char(')')
   }
 
-  fn get_fallback(&self, kind: ColorFallbackKind) -> Self {
+  fn get_fallback(&self, kind: ColorFallbackKind, gamut_mapping: GamutMapping) -> Self {
     Variable {
       name: self.name.clone(),
-      fallback: self.fallback.as_ref().map(|fallback| fallback.get_fallback(kind)),
+      fallback: self
+        .fallback
+        .as_ref()
+        .map(|fallback| fallback.get_fallback(kind, gamut_mapping)),
     }
   }
 }
@@ -1445,11 +1846,234 @@ impl<'i> EnvironmentVariable<'i> {
     dest.write_char(')')
   }
 
-  fn get_fallback(&self, kind: ColorFallbackKind) -> Self {
+  fn get_fallback(&self, kind: ColorFallbackKind, gamut_mapping: GamutMapping) -> Self {
     EnvironmentVariable {
       name: self.name.clone(),
       indices: self.indices.clone(),
-      fallback: self.fallback.as_ref().map(|fallback| fallback.get_fallback(kind)),
+      fallback: self
+        .fallback
+        .as_ref()
+        .map(|fallback| fallback.get_fallback(kind, gamut_mapping)),
+    }
+  }
+}
+
+/// A CSS [attr()](https://drafts.csswg.org/css-values-5/#attr-notation) reference.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit), visit(visit_attr_function, ATTR_FUNCTIONS))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct AttrFunction<'i> {
+  /// The attribute name.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: Ident<'i>,
+  /// The type that the attribute value is parsed as.
+  #[cfg_attr(feature = "visitor", skip_visit)]
+  pub attr_type: Option<AttrType<'i>>,
+  /// A fallback value in case the attribute is missing or invalid.
+  pub fallback: Option<TokenList<'i>>,
+}
+
+/// The type of an [attr()](AttrFunction) reference.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum AttrType<'i> {
+  /// A `type()` function containing a syntax, e.g. `type(<length>)`.
+  Type(SyntaxString),
+  /// The `raw-string` keyword.
+  RawString,
+  /// The `number` keyword.
+  Number,
+  /// The `%` unit.
+  Percentage,
+  /// A dimension unit, e.g. `px`.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Unit(Ident<'i>),
+}
+
+impl<'i> Parse<'i> for AttrType<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_function_matching("type")).is_ok() {
+      return input.parse_nested_block(|input| {
+        let start = input.position();
+        while input.next().is_ok() {}
+        let syntax = SyntaxString::parse_string(input.slice_from(start).trim())
+          .map_err(|_| input.new_custom_error(ParserError::InvalidValue))?;
+        Ok(AttrType::Type(syntax))
+      });
+    }
+
+    if input.try_parse(|input| input.expect_delim('%')).is_ok() {
+      return Ok(AttrType::Percentage);
+    }
+
+    let ident = input.expect_ident()?;
+    match_ignore_ascii_case! { ident,
+      "raw-string" => Ok(AttrType::RawString),
+      "number" => Ok(AttrType::Number),
+      _ => Ok(AttrType::Unit(Ident(ident.into())))
+    }
+  }
+}
+
+impl<'i> ToCss for AttrType<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      AttrType::Type(syntax) => {
+        dest.write_str("type(")?;
+        match syntax {
+          SyntaxString::Universal => dest.write_char('*')?,
+          SyntaxString::Components(components) => {
+            let mut first = true;
+            for component in components {
+              if first {
+                first = false;
+              } else {
+                dest.delim('|', true)?;
+              }
+              component.to_css(dest)?;
+            }
+          }
+        }
+        dest.write_char(')')
+      }
+      AttrType::RawString => dest.write_str("raw-string"),
+      AttrType::Number => dest.write_str("number"),
+      AttrType::Percentage => dest.write_char('%'),
+      AttrType::Unit(unit) => unit.to_css(dest),
+    }
+  }
+}
+
+impl<'i> AttrFunction<'i> {
+  pub(crate) fn parse_nested<'t>(
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions<'_, 'i>,
+    depth: usize,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let name = Ident::parse(input)?;
+    let attr_type = input.try_parse(AttrType::parse).ok();
+    let fallback = if input.try_parse(|input| input.expect_comma()).is_ok() {
+      Some(TokenList::parse(input, options, depth + 1)?)
+    } else {
+      None
+    };
+
+    Ok(AttrFunction {
+      name,
+      attr_type,
+      fallback,
+    })
+  }
+
+  pub(crate) fn to_css<W>(&self, dest: &mut Printer<W>, is_custom_property: bool) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("attr(")?;
+    self.name.to_css(dest)?;
+
+    if let Some(attr_type) = &self.attr_type {
+      dest.write_char(' ')?;
+      attr_type.to_css(dest)?;
+    }
+
+    if let Some(fallback) = &self.fallback {
+      dest.delim(',', false)?;
+      fallback.to_css(dest, is_custom_property)?;
+    }
+    dest.write_char(')')
+  }
+
+  fn get_fallback(&self, kind: ColorFallbackKind, gamut_mapping: GamutMapping) -> Self {
+    AttrFunction {
+      name: self.name.clone(),
+      attr_type: self.attr_type.clone(),
+      fallback: self
+        .fallback
+        .as_ref()
+        .map(|fallback| fallback.get_fallback(kind, gamut_mapping)),
+    }
+  }
+}
+
+/// A CSS [toggle()](https://drafts.csswg.org/css-values-5/#toggle-notation) value.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct ToggleFunction<'i> {
+  /// The values to cycle between.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub values: Vec<TokenList<'i>>,
+}
+
+impl<'i> ToggleFunction<'i> {
+  pub(crate) fn parse_nested<'t>(
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions<'_, 'i>,
+    depth: usize,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let values = input.parse_comma_separated(|input| {
+      let location = input.current_source_location();
+      let mut value = TokenList::parse(input, options, depth + 1)?;
+      let start = value.0.iter().position(|token| !token.is_whitespace());
+      let end = value.0.iter().rposition(|token| !token.is_whitespace());
+      match (start, end) {
+        // toggle() may not be nested, and each argument must be non-empty.
+        (Some(start), Some(end))
+          if !value.0[start..=end].iter().any(|t| matches!(t, TokenOrValue::Toggle(..))) =>
+        {
+          value.0.truncate(end + 1);
+          value.0.drain(..start);
+          Ok(value)
+        }
+        _ => Err(location.new_custom_error(ParserError::InvalidValue)),
+      }
+    })?;
+
+    Ok(ToggleFunction { values })
+  }
+
+  pub(crate) fn to_css<W>(&self, dest: &mut Printer<W>, is_custom_property: bool) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("toggle(")?;
+    let mut first = true;
+    for value in &self.values {
+      if first {
+        first = false;
+      } else {
+        dest.delim(',', false)?;
+      }
+      value.to_css(dest, is_custom_property)?;
+    }
+    dest.write_char(')')
+  }
+
+  fn get_fallback(&self, kind: ColorFallbackKind, gamut_mapping: GamutMapping) -> Self {
+    ToggleFunction {
+      values: self
+        .values
+        .iter()
+        .map(|value| value.get_fallback(kind, gamut_mapping))
+        .collect(),
     }
   }
 }
@@ -1480,10 +2104,10 @@ impl<'i> Function<'i> {
     dest.write_char(')')
   }
 
-  fn get_fallback(&self, kind: ColorFallbackKind) -> Self {
+  fn get_fallback(&self, kind: ColorFallbackKind, gamut_mapping: GamutMapping) -> Self {
     Function {
       name: self.name.clone(),
-      arguments: self.arguments.get_fallback(kind),
+      arguments: self.arguments.get_fallback(kind, gamut_mapping),
     }
   }
 }
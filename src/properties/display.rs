@@ -2,13 +2,15 @@
 
 use super::custom::UnparsedProperty;
 use super::{Property, PropertyId};
+use crate::compat;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::DeclarationList;
 use crate::error::{ParserError, PrinterError};
 use crate::macros::enum_property;
 use crate::prefixes::{is_flex_2009, Feature};
 use crate::printer::Printer;
-use crate::traits::{Parse, PropertyHandler, ToCss};
+use crate::targets::Browsers;
+use crate::traits::{IsCompatible, Parse, PropertyHandler, ToCss};
 use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
@@ -382,6 +384,32 @@ enum_property! {
   }
 }
 
+enum_property! {
+  /// A value for the [reading-flow](https://drafts.csswg.org/css-display-4/#reading-flow) property.
+  pub enum ReadingFlow {
+    /// Items are read in DOM order.
+    "normal": Normal,
+    /// Items are read in DOM order, adjusted by `reading-order`.
+    "source-order": SourceOrder,
+    /// Flex items are read in visual order, according to the writing mode.
+    "flex-visual": FlexVisual,
+    /// Flex items are read in the order of the flex flow.
+    "flex-flow": FlexFlow,
+    /// Grid items are read in visual order, row by row.
+    "grid-rows": GridRows,
+    /// Grid items are read in visual order, column by column.
+    "grid-columns": GridColumns,
+    /// Grid items are read in order-modified document order.
+    "grid-order": GridOrder,
+  }
+}
+
+impl IsCompatible for ReadingFlow {
+  fn is_compatible(&self, browsers: Browsers) -> bool {
+    compat::Feature::ReadingFlow.is_compatible(browsers)
+  }
+}
+
 #[derive(Default)]
 pub(crate) struct DisplayHandler<'i> {
   decls: Vec<Property<'i>>,
@@ -453,12 +481,13 @@ impl<'i> PropertyHandler<'i> for DisplayHandler<'i> {
       }) = display
       {
         let prefixes = context.targets.prefixes(VendorPrefix::None, Feature::DisplayFlex);
+        let mut prefixed = Vec::new();
 
         if let Some(targets) = context.targets.browsers {
           // Handle legacy -webkit-box/-moz-box values if needed.
           if is_flex_2009(targets) {
             if prefixes.contains(VendorPrefix::WebKit) {
-              dest.push(Property::Display(Display::Pair(DisplayPair {
+              prefixed.push(Property::Display(Display::Pair(DisplayPair {
                 inside: DisplayInside::Box(VendorPrefix::WebKit),
                 outside: outside.clone(),
                 is_list_item: false,
@@ -466,7 +495,7 @@ impl<'i> PropertyHandler<'i> for DisplayHandler<'i> {
             }
 
             if prefixes.contains(VendorPrefix::Moz) {
-              dest.push(Property::Display(Display::Pair(DisplayPair {
+              prefixed.push(Property::Display(Display::Pair(DisplayPair {
                 inside: DisplayInside::Box(VendorPrefix::Moz),
                 outside: outside.clone(),
                 is_list_item: false,
@@ -476,7 +505,7 @@ impl<'i> PropertyHandler<'i> for DisplayHandler<'i> {
         }
 
         if prefixes.contains(VendorPrefix::WebKit) {
-          dest.push(Property::Display(Display::Pair(DisplayPair {
+          prefixed.push(Property::Display(Display::Pair(DisplayPair {
             inside: DisplayInside::Flex(VendorPrefix::WebKit),
             outside: outside.clone(),
             is_list_item: false,
@@ -484,12 +513,14 @@ impl<'i> PropertyHandler<'i> for DisplayHandler<'i> {
         }
 
         if prefixes.contains(VendorPrefix::Ms) {
-          dest.push(Property::Display(Display::Pair(DisplayPair {
+          prefixed.push(Property::Display(Display::Pair(DisplayPair {
             inside: DisplayInside::Flex(VendorPrefix::Ms),
             outside: outside.clone(),
             is_list_item: false,
           })));
         }
+
+        dest.extend(context.prefixed(|| Property::Display(display.clone()), prefixed));
       }
 
       dest.push(Property::Display(display))
@@ -1,10 +1,10 @@
 //! CSS properties related to filters and effects.
 
 use crate::error::{ParserError, PrinterError};
-use crate::printer::Printer;
+use crate::printer::{Printer, PrinterOptions};
 use crate::targets::{Browsers, Targets};
 use crate::traits::{FallbackValues, IsCompatible, Parse, ToCss, Zero};
-use crate::values::color::ColorFallbackKind;
+use crate::values::color::{ColorFallbackKind, GamutMapping};
 use crate::values::{angle::Angle, color::CssColor, length::Length, percentage::NumberOrPercentage, url::Url};
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
@@ -201,9 +201,9 @@ impl<'i> ToCss for Filter<'i> {
 }
 
 impl<'i> Filter<'i> {
-  fn get_fallback(&self, kind: ColorFallbackKind) -> Self {
+  fn get_fallback(&self, kind: ColorFallbackKind, gamut_mapping: GamutMapping) -> Self {
     match self {
-      Filter::DropShadow(shadow) => Filter::DropShadow(shadow.get_fallback(kind)),
+      Filter::DropShadow(shadow) => Filter::DropShadow(shadow.get_fallback(kind, gamut_mapping)),
       _ => self.clone(),
     }
   }
@@ -300,9 +300,9 @@ impl ToCss for DropShadow {
 }
 
 impl DropShadow {
-  fn get_fallback(&self, kind: ColorFallbackKind) -> DropShadow {
+  fn get_fallback(&self, kind: ColorFallbackKind, gamut_mapping: GamutMapping) -> DropShadow {
     DropShadow {
-      color: self.color.get_fallback(kind),
+      color: self.color.get_fallback_with_gamut_mapping(kind, gamut_mapping),
       ..self.clone()
     }
   }
@@ -366,7 +366,7 @@ impl<'i> ToCss for FilterList<'i> {
 }
 
 impl<'i> FallbackValues for FilterList<'i> {
-  fn get_fallbacks(&mut self, targets: Targets) -> Vec<Self> {
+  fn get_fallbacks(&mut self, targets: Targets, gamut_mapping: GamutMapping) -> Vec<Self> {
     let mut res = Vec::new();
     let mut fallbacks = ColorFallbackKind::empty();
     if let FilterList::Filters(filters) = self {
@@ -380,7 +380,7 @@ impl<'i> FallbackValues for FilterList<'i> {
         res.push(FilterList::Filters(
           filters
             .iter()
-            .map(|filter| filter.get_fallback(ColorFallbackKind::RGB))
+            .map(|filter| filter.get_fallback(ColorFallbackKind::RGB, gamut_mapping))
             .collect(),
         ));
       }
@@ -389,14 +389,14 @@ impl<'i> FallbackValues for FilterList<'i> {
         res.push(FilterList::Filters(
           filters
             .iter()
-            .map(|filter| filter.get_fallback(ColorFallbackKind::P3))
+            .map(|filter| filter.get_fallback(ColorFallbackKind::P3, gamut_mapping))
             .collect(),
         ));
       }
 
       if fallbacks.contains(ColorFallbackKind::LAB) {
         for filter in filters.iter_mut() {
-          *filter = filter.get_fallback(ColorFallbackKind::LAB);
+          *filter = filter.get_fallback(ColorFallbackKind::LAB, gamut_mapping);
         }
       }
     }
@@ -410,3 +410,59 @@ impl IsCompatible for FilterList<'_> {
     true
   }
 }
+
+/// A value for the [zoom](https://drafts.csswg.org/css-viewport/#zoom-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum Zoom {
+  /// The `normal` keyword.
+  Normal,
+  /// A zoom factor, as a number or percentage.
+  Factor(NumberOrPercentage),
+}
+
+impl<'i> Parse<'i> for Zoom {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(Zoom::Normal);
+    }
+
+    let location = input.current_source_location();
+    let factor = NumberOrPercentage::parse(input)?;
+    let value: f32 = (&factor).into();
+    if value < 0.0 {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(Zoom::Factor(factor))
+  }
+}
+
+impl ToCss for Zoom {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      Zoom::Normal => dest.write_str("normal"),
+      Zoom::Factor(NumberOrPercentage::Number(number)) => number.to_css(dest),
+      Zoom::Factor(NumberOrPercentage::Percentage(percent)) => {
+        // Percentages are equivalent to numbers, so use whichever is shorter.
+        let number = percent.0.to_css_string(PrinterOptions::default())?;
+        let percent = percent.to_css_string(PrinterOptions::default())?;
+        if number.len() < percent.len() {
+          dest.write_str(&number)
+        } else {
+          dest.write_str(&percent)
+        }
+      }
+    }
+  }
+}
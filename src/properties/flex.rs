@@ -640,7 +640,10 @@ impl FlexHandler {
       ($prop: ident, $key: ident $(, 2012: $prop_2012: ident )? $(, 2009: $prop_2009: ident )?) => {
         if let Some((val, prefix)) = $key {
           if !prefix.is_empty() {
+            let authored = prefix;
             let mut prefix = context.targets.prefixes(prefix, Feature::$prop);
+            #[allow(unused_mut)]
+            let mut legacy = Vec::new();
             if prefix.contains(VendorPrefix::None) {
               $(
                 // 2009 spec, implemented by webkit and firefox.
@@ -654,7 +657,7 @@ impl FlexHandler {
                   }
                   if !prefixes_2009.is_empty() {
                     if let Some(v) = $prop_2009::from_standard(&val) {
-                      dest.push(Property::$prop_2009(v, prefixes_2009));
+                      legacy.push(Property::$prop_2009(v, prefixes_2009));
                     }
                   }
                 }
@@ -664,7 +667,7 @@ impl FlexHandler {
             $(
               let mut ms = true;
               if prefix.contains(VendorPrefix::Ms) {
-                dest.push(Property::$prop_2012(val.clone(), VendorPrefix::Ms));
+                legacy.push(Property::$prop_2012(val.clone(), VendorPrefix::Ms));
                 ms = false;
               }
               if !ms {
@@ -672,8 +675,12 @@ impl FlexHandler {
               }
             )?
 
+            dest.extend(context.prefixed(|| Property::$prop(val.clone(), authored), legacy));
+
             // Firefox only implemented the 2009 spec prefixed.
             prefix.remove(VendorPrefix::Moz);
+            let (prefix, prefixed) = context.split_prefixes(authored, prefix, |vp| Property::$prop(val.clone(), vp));
+            dest.extend(prefixed);
             dest.push(Property::$prop(val, prefix))
           }
         }
@@ -713,8 +720,13 @@ impl FlexHandler {
         }
         if !prefixes_2009.is_empty() {
           let (orient, dir) = direction.to_2009();
-          dest.push(Property::BoxOrient(orient, prefixes_2009));
-          dest.push(Property::BoxDirection(dir, prefixes_2009));
+          dest.extend(context.prefixed(
+            || Property::FlexDirection(direction, VendorPrefix::None),
+            vec![
+              Property::BoxOrient(orient, prefixes_2009),
+              Property::BoxDirection(dir, prefixes_2009),
+            ],
+          ));
         }
       }
     }
@@ -725,13 +737,14 @@ impl FlexHandler {
         let mut prefix = context.targets.prefixes(intersection, Feature::FlexFlow);
         // Firefox only implemented the 2009 spec prefixed.
         prefix.remove(VendorPrefix::Moz);
-        dest.push(Property::FlexFlow(
-          FlexFlow {
-            direction: *direction,
-            wrap: *wrap,
-          },
-          prefix,
-        ));
+        let flex_flow = FlexFlow {
+          direction: *direction,
+          wrap: *wrap,
+        };
+        let (prefix, prefixed) =
+          context.split_prefixes(intersection, prefix, |vp| Property::FlexFlow(flex_flow.clone(), vp));
+        dest.extend(prefixed);
+        dest.push(Property::FlexFlow(flex_flow, prefix));
         dir_prefix.remove(intersection);
         wrap_prefix.remove(intersection);
       }
@@ -751,7 +764,10 @@ impl FlexHandler {
           prefixes_2009 |= VendorPrefix::Moz;
         }
         if !prefixes_2009.is_empty() {
-          dest.push(Property::BoxFlex(grow, prefixes_2009));
+          dest.extend(context.prefixed(
+            || Property::FlexGrow(grow, VendorPrefix::None),
+            vec![Property::BoxFlex(grow, prefixes_2009)],
+          ));
         }
       }
     }
@@ -764,14 +780,15 @@ impl FlexHandler {
         let mut prefix = context.targets.prefixes(intersection, Feature::Flex);
         // Firefox only implemented the 2009 spec prefixed.
         prefix.remove(VendorPrefix::Moz);
-        dest.push(Property::Flex(
-          Flex {
-            grow: *grow,
-            shrink: *shrink,
-            basis: basis.clone(),
-          },
-          prefix,
-        ));
+        let flex = Flex {
+          grow: *grow,
+          shrink: *shrink,
+          basis: basis.clone(),
+        };
+        let (prefix, prefixed) =
+          context.split_prefixes(intersection, prefix, |vp| Property::Flex(flex.clone(), vp));
+        dest.extend(prefixed);
+        dest.push(Property::Flex(flex, prefix));
         grow_prefix.remove(intersection);
         shrink_prefix.remove(intersection);
         basis_prefix.remove(intersection);
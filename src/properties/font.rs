@@ -2,6 +2,7 @@
 
 use std::collections::HashSet;
 
+use super::custom::{CustomProperty, CustomPropertyName};
 use super::{Property, PropertyId};
 use crate::compat::Feature;
 use crate::context::PropertyHandlerContext;
@@ -9,8 +10,10 @@ use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
 use crate::macros::*;
 use crate::printer::Printer;
+use crate::stylesheet::ParserOptions;
 use crate::targets::should_compile;
-use crate::traits::{IsCompatible, Parse, PropertyHandler, Shorthand, ToCss};
+use crate::traits::{IsCompatible, Parse, ParseWithOptions, PropertyHandler, Shorthand, ToCss};
+use crate::values::ident::{CustomIdent, DashedIdentReference};
 use crate::values::length::LengthValue;
 use crate::values::number::CSSNumber;
 use crate::values::string::CowArcStr;
@@ -643,6 +646,767 @@ impl IsCompatible for FontVariantCaps {
   }
 }
 
+enum_property! {
+  /// A [`<common-lig-values>`](https://drafts.csswg.org/css-fonts-4/#common-lig-values) value,
+  /// as used in the `font-variant-ligatures` property.
+  pub enum CommonLigValues {
+    /// Common ligatures are enabled.
+    "common-ligatures": CommonLigatures,
+    /// Common ligatures are disabled.
+    "no-common-ligatures": NoCommonLigatures,
+  }
+}
+
+enum_property! {
+  /// A [`<discretionary-lig-values>`](https://drafts.csswg.org/css-fonts-4/#discretionary-lig-values) value,
+  /// as used in the `font-variant-ligatures` property.
+  pub enum DiscretionaryLigValues {
+    /// Discretionary ligatures are enabled.
+    "discretionary-ligatures": DiscretionaryLigatures,
+    /// Discretionary ligatures are disabled.
+    "no-discretionary-ligatures": NoDiscretionaryLigatures,
+  }
+}
+
+enum_property! {
+  /// A [`<historical-lig-values>`](https://drafts.csswg.org/css-fonts-4/#historical-lig-values) value,
+  /// as used in the `font-variant-ligatures` property.
+  pub enum HistoricalLigValues {
+    /// Historical ligatures are enabled.
+    "historical-ligatures": HistoricalLigatures,
+    /// Historical ligatures are disabled.
+    "no-historical-ligatures": NoHistoricalLigatures,
+  }
+}
+
+enum_property! {
+  /// A [`<contextual-alt-values>`](https://drafts.csswg.org/css-fonts-4/#contextual-alt-values) value,
+  /// as used in the `font-variant-ligatures` property.
+  pub enum ContextualAltValues {
+    /// Contextual alternates are enabled.
+    "contextual": Contextual,
+    /// Contextual alternates are disabled.
+    "no-contextual": NoContextual,
+  }
+}
+
+/// A value for the [font-variant-ligatures](https://drafts.csswg.org/css-fonts-4/#font-variant-ligatures-prop) property.
+///
+/// The `normal` keyword is represented by all fields being `None`, and `none` by all of them being disabled.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct FontVariantLigatures {
+  /// Common ligatures, e.g. "fi".
+  pub common: Option<CommonLigValues>,
+  /// Discretionary ligatures, which are specific to the font.
+  pub discretionary: Option<DiscretionaryLigValues>,
+  /// Historical ligatures.
+  pub historical: Option<HistoricalLigValues>,
+  /// Contextual alternates.
+  pub contextual: Option<ContextualAltValues>,
+}
+
+impl FontVariantLigatures {
+  const NONE: FontVariantLigatures = FontVariantLigatures {
+    common: Some(CommonLigValues::NoCommonLigatures),
+    discretionary: Some(DiscretionaryLigValues::NoDiscretionaryLigatures),
+    historical: Some(HistoricalLigValues::NoHistoricalLigatures),
+    contextual: Some(ContextualAltValues::NoContextual),
+  };
+
+  fn is_normal(&self) -> bool {
+    *self == FontVariantLigatures::default()
+  }
+
+  fn parse_component<'i>(&mut self, input: &mut Parser<'i, '_>) -> Result<(), ParseError<'i, ParserError<'i>>> {
+    if self.common.is_none() {
+      if let Ok(value) = input.try_parse(CommonLigValues::parse) {
+        self.common = Some(value);
+        return Ok(());
+      }
+    }
+    if self.discretionary.is_none() {
+      if let Ok(value) = input.try_parse(DiscretionaryLigValues::parse) {
+        self.discretionary = Some(value);
+        return Ok(());
+      }
+    }
+    if self.historical.is_none() {
+      if let Ok(value) = input.try_parse(HistoricalLigValues::parse) {
+        self.historical = Some(value);
+        return Ok(());
+      }
+    }
+    if self.contextual.is_none() {
+      if let Ok(value) = input.try_parse(ContextualAltValues::parse) {
+        self.contextual = Some(value);
+        return Ok(());
+      }
+    }
+    Err(input.new_custom_error(ParserError::InvalidValue))
+  }
+
+  fn write_components<W>(&self, dest: &mut Printer<W>, needs_space: &mut bool) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    write_component(&self.common, dest, needs_space)?;
+    write_component(&self.discretionary, dest, needs_space)?;
+    write_component(&self.historical, dest, needs_space)?;
+    write_component(&self.contextual, dest, needs_space)
+  }
+}
+
+impl<'i> Parse<'i> for FontVariantLigatures {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontVariantLigatures::default());
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(FontVariantLigatures::NONE);
+    }
+
+    let mut value = FontVariantLigatures::default();
+    value.parse_component(input)?;
+    while input.try_parse(|input| value.parse_component(input)).is_ok() {}
+    Ok(value)
+  }
+}
+
+impl ToCss for FontVariantLigatures {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.is_normal() {
+      return dest.write_str("normal");
+    }
+
+    if *self == FontVariantLigatures::NONE {
+      return dest.write_str("none");
+    }
+
+    self.write_components(dest, &mut false)
+  }
+}
+
+enum_property! {
+  /// A [`<numeric-figure-values>`](https://drafts.csswg.org/css-fonts-4/#numeric-figure-values) value,
+  /// as used in the `font-variant-numeric` property.
+  pub enum NumericFigureValues {
+    /// Lining numerals.
+    "lining-nums": LiningNums,
+    /// Old-style numerals.
+    "oldstyle-nums": OldstyleNums,
+  }
+}
+
+enum_property! {
+  /// A [`<numeric-spacing-values>`](https://drafts.csswg.org/css-fonts-4/#numeric-spacing-values) value,
+  /// as used in the `font-variant-numeric` property.
+  pub enum NumericSpacingValues {
+    /// Proportional numerals.
+    "proportional-nums": ProportionalNums,
+    /// Tabular numerals.
+    "tabular-nums": TabularNums,
+  }
+}
+
+enum_property! {
+  /// A [`<numeric-fraction-values>`](https://drafts.csswg.org/css-fonts-4/#numeric-fraction-values) value,
+  /// as used in the `font-variant-numeric` property.
+  pub enum NumericFractionValues {
+    /// Diagonal fractions.
+    "diagonal-fractions": DiagonalFractions,
+    /// Stacked fractions.
+    "stacked-fractions": StackedFractions,
+  }
+}
+
+/// A value for the [font-variant-numeric](https://drafts.csswg.org/css-fonts-4/#font-variant-numeric-prop) property.
+///
+/// The `normal` keyword is represented by the default value.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct FontVariantNumeric {
+  /// The style of numerals.
+  pub figure: Option<NumericFigureValues>,
+  /// The spacing of numerals.
+  pub spacing: Option<NumericSpacingValues>,
+  /// The style of fractions.
+  pub fraction: Option<NumericFractionValues>,
+  /// Whether ordinal forms are used.
+  pub ordinal: bool,
+  /// Whether a slashed zero is used.
+  pub slashed_zero: bool,
+}
+
+impl FontVariantNumeric {
+  fn is_normal(&self) -> bool {
+    *self == FontVariantNumeric::default()
+  }
+
+  fn parse_component<'i>(&mut self, input: &mut Parser<'i, '_>) -> Result<(), ParseError<'i, ParserError<'i>>> {
+    if self.figure.is_none() {
+      if let Ok(value) = input.try_parse(NumericFigureValues::parse) {
+        self.figure = Some(value);
+        return Ok(());
+      }
+    }
+    if self.spacing.is_none() {
+      if let Ok(value) = input.try_parse(NumericSpacingValues::parse) {
+        self.spacing = Some(value);
+        return Ok(());
+      }
+    }
+    if self.fraction.is_none() {
+      if let Ok(value) = input.try_parse(NumericFractionValues::parse) {
+        self.fraction = Some(value);
+        return Ok(());
+      }
+    }
+    if !self.ordinal && input.try_parse(|input| input.expect_ident_matching("ordinal")).is_ok() {
+      self.ordinal = true;
+      return Ok(());
+    }
+    if !self.slashed_zero && input.try_parse(|input| input.expect_ident_matching("slashed-zero")).is_ok() {
+      self.slashed_zero = true;
+      return Ok(());
+    }
+    Err(input.new_custom_error(ParserError::InvalidValue))
+  }
+
+  fn write_components<W>(&self, dest: &mut Printer<W>, needs_space: &mut bool) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    write_component(&self.figure, dest, needs_space)?;
+    write_component(&self.spacing, dest, needs_space)?;
+    write_component(&self.fraction, dest, needs_space)?;
+    write_keyword(self.ordinal, "ordinal", dest, needs_space)?;
+    write_keyword(self.slashed_zero, "slashed-zero", dest, needs_space)
+  }
+}
+
+impl<'i> Parse<'i> for FontVariantNumeric {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontVariantNumeric::default());
+    }
+
+    let mut value = FontVariantNumeric::default();
+    value.parse_component(input)?;
+    while input.try_parse(|input| value.parse_component(input)).is_ok() {}
+    Ok(value)
+  }
+}
+
+impl ToCss for FontVariantNumeric {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.is_normal() {
+      return dest.write_str("normal");
+    }
+
+    self.write_components(dest, &mut false)
+  }
+}
+
+enum_property! {
+  /// A [`<east-asian-variant-values>`](https://drafts.csswg.org/css-fonts-4/#east-asian-variant-values) value,
+  /// as used in the `font-variant-east-asian` property.
+  pub enum EastAsianVariantValues {
+    /// Glyphs from the JIS X 0208-1978 standard.
+    "jis78": Jis78,
+    /// Glyphs from the JIS X 0208-1983 standard.
+    "jis83": Jis83,
+    /// Glyphs from the JIS X 0208-1990 standard.
+    "jis90": Jis90,
+    /// Glyphs from the JIS X 0213:2004 standard.
+    "jis04": Jis04,
+    /// Simplified Chinese glyphs.
+    "simplified": Simplified,
+    /// Traditional Chinese glyphs.
+    "traditional": Traditional,
+  }
+}
+
+enum_property! {
+  /// A [`<east-asian-width-values>`](https://drafts.csswg.org/css-fonts-4/#east-asian-width-values) value,
+  /// as used in the `font-variant-east-asian` property.
+  pub enum EastAsianWidthValues {
+    /// Full-width variants.
+    "full-width": FullWidth,
+    /// Proportionally-spaced variants.
+    "proportional-width": ProportionalWidth,
+  }
+}
+
+/// A value for the [font-variant-east-asian](https://drafts.csswg.org/css-fonts-4/#font-variant-east-asian-prop) property.
+///
+/// The `normal` keyword is represented by the default value.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct FontVariantEastAsian {
+  /// The glyph variant standard.
+  pub variant: Option<EastAsianVariantValues>,
+  /// The glyph width.
+  pub width: Option<EastAsianWidthValues>,
+  /// Whether ruby variant glyphs are used.
+  pub ruby: bool,
+}
+
+impl FontVariantEastAsian {
+  fn is_normal(&self) -> bool {
+    *self == FontVariantEastAsian::default()
+  }
+
+  fn parse_component<'i>(&mut self, input: &mut Parser<'i, '_>) -> Result<(), ParseError<'i, ParserError<'i>>> {
+    if self.variant.is_none() {
+      if let Ok(value) = input.try_parse(EastAsianVariantValues::parse) {
+        self.variant = Some(value);
+        return Ok(());
+      }
+    }
+    if self.width.is_none() {
+      if let Ok(value) = input.try_parse(EastAsianWidthValues::parse) {
+        self.width = Some(value);
+        return Ok(());
+      }
+    }
+    if !self.ruby && input.try_parse(|input| input.expect_ident_matching("ruby")).is_ok() {
+      self.ruby = true;
+      return Ok(());
+    }
+    Err(input.new_custom_error(ParserError::InvalidValue))
+  }
+
+  fn write_components<W>(&self, dest: &mut Printer<W>, needs_space: &mut bool) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    write_component(&self.variant, dest, needs_space)?;
+    write_component(&self.width, dest, needs_space)?;
+    write_keyword(self.ruby, "ruby", dest, needs_space)
+  }
+}
+
+impl<'i> Parse<'i> for FontVariantEastAsian {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontVariantEastAsian::default());
+    }
+
+    let mut value = FontVariantEastAsian::default();
+    value.parse_component(input)?;
+    while input.try_parse(|input| value.parse_component(input)).is_ok() {}
+    Ok(value)
+  }
+}
+
+impl ToCss for FontVariantEastAsian {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.is_normal() {
+      return dest.write_str("normal");
+    }
+
+    self.write_components(dest, &mut false)
+  }
+}
+
+/// A value for the [font-variant-alternates](https://drafts.csswg.org/css-fonts-4/#font-variant-alternates-prop) property.
+///
+/// The `normal` keyword is represented by the default value. The names refer to
+/// feature values defined in `@font-feature-values` rules.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct FontVariantAlternates<'i> {
+  /// The `stylistic()` function.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub stylistic: Option<CustomIdent<'i>>,
+  /// Whether historical forms are used.
+  pub historical_forms: bool,
+  /// The `styleset()` function. Empty if not specified.
+  pub styleset: Vec<CustomIdent<'i>>,
+  /// The `character-variant()` function. Empty if not specified.
+  pub character_variant: Vec<CustomIdent<'i>>,
+  /// The `swash()` function.
+  pub swash: Option<CustomIdent<'i>>,
+  /// The `ornaments()` function.
+  pub ornaments: Option<CustomIdent<'i>>,
+  /// The `annotation()` function.
+  pub annotation: Option<CustomIdent<'i>>,
+}
+
+impl<'i> FontVariantAlternates<'i> {
+  fn is_normal(&self) -> bool {
+    *self == FontVariantAlternates::default()
+  }
+
+  fn parse_component<'t>(&mut self, input: &mut Parser<'i, 't>) -> Result<(), ParseError<'i, ParserError<'i>>> {
+    if !self.historical_forms && input.try_parse(|input| input.expect_ident_matching("historical-forms")).is_ok() {
+      self.historical_forms = true;
+      return Ok(());
+    }
+
+    let location = input.current_source_location();
+    let f = input.expect_function()?.clone();
+    match_ignore_ascii_case! { &f,
+      "stylistic" if self.stylistic.is_none() => {
+        self.stylistic = Some(input.parse_nested_block(CustomIdent::parse)?);
+      },
+      "styleset" if self.styleset.is_empty() => {
+        self.styleset = input.parse_nested_block(|input| input.parse_comma_separated(CustomIdent::parse))?;
+      },
+      "character-variant" if self.character_variant.is_empty() => {
+        self.character_variant = input.parse_nested_block(|input| input.parse_comma_separated(CustomIdent::parse))?;
+      },
+      "swash" if self.swash.is_none() => {
+        self.swash = Some(input.parse_nested_block(CustomIdent::parse)?);
+      },
+      "ornaments" if self.ornaments.is_none() => {
+        self.ornaments = Some(input.parse_nested_block(CustomIdent::parse)?);
+      },
+      "annotation" if self.annotation.is_none() => {
+        self.annotation = Some(input.parse_nested_block(CustomIdent::parse)?);
+      },
+      _ => return Err(location.new_unexpected_token_error(Token::Function(f.clone())))
+    }
+    Ok(())
+  }
+
+  fn write_components<W>(&self, dest: &mut Printer<W>, needs_space: &mut bool) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    fn write_function<W>(
+      name: &str,
+      idents: &[CustomIdent],
+      dest: &mut Printer<W>,
+      needs_space: &mut bool,
+    ) -> Result<(), PrinterError>
+    where
+      W: std::fmt::Write,
+    {
+      if idents.is_empty() {
+        return Ok(());
+      }
+
+      if *needs_space {
+        dest.write_char(' ')?;
+      }
+      dest.write_str(name)?;
+      dest.write_char('(')?;
+      let mut first = true;
+      for ident in idents {
+        if first {
+          first = false;
+        } else {
+          dest.delim(',', false)?;
+        }
+        // Feature value names are defined by @font-feature-values rules, which are not scoped by CSS modules.
+        ident.to_css_with_options(dest, false)?;
+      }
+      dest.write_char(')')?;
+      *needs_space = true;
+      Ok(())
+    }
+
+    write_function("stylistic", self.stylistic.as_slice(), dest, needs_space)?;
+    write_keyword(self.historical_forms, "historical-forms", dest, needs_space)?;
+    write_function("styleset", &self.styleset, dest, needs_space)?;
+    write_function("character-variant", &self.character_variant, dest, needs_space)?;
+    write_function("swash", self.swash.as_slice(), dest, needs_space)?;
+    write_function("ornaments", self.ornaments.as_slice(), dest, needs_space)?;
+    write_function("annotation", self.annotation.as_slice(), dest, needs_space)
+  }
+}
+
+impl<'i> Parse<'i> for FontVariantAlternates<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontVariantAlternates::default());
+    }
+
+    let mut value = FontVariantAlternates::default();
+    value.parse_component(input)?;
+    while input.try_parse(|input| value.parse_component(input)).is_ok() {}
+    Ok(value)
+  }
+}
+
+impl<'i> ToCss for FontVariantAlternates<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.is_normal() {
+      return dest.write_str("normal");
+    }
+
+    self.write_components(dest, &mut false)
+  }
+}
+
+enum_property! {
+  /// A value for the [font-variant-position](https://drafts.csswg.org/css-fonts-4/#font-variant-position-prop) property.
+  #[derive(Default)]
+  pub enum FontVariantPosition {
+    /// No superscript or subscript glyphs are used.
+    #[default]
+    Normal,
+    /// Subscript glyphs are used.
+    Sub,
+    /// Superscript glyphs are used.
+    Super,
+  }
+}
+
+enum_property! {
+  /// A value for the [font-variant-emoji](https://drafts.csswg.org/css-fonts-4/#font-variant-emoji-prop) property.
+  #[derive(Default)]
+  pub enum FontVariantEmoji {
+    /// The presentation is determined by the user agent.
+    #[default]
+    Normal,
+    /// Text presentation is used.
+    Text,
+    /// Emoji presentation is used.
+    Emoji,
+    /// The presentation follows the Unicode default for each character.
+    Unicode,
+  }
+}
+
+macro_rules! impl_compatible {
+  ($($t: ty),+) => {
+    $(
+      impl IsCompatible for $t {
+        fn is_compatible(&self, _browsers: crate::targets::Browsers) -> bool {
+          true
+        }
+      }
+    )+
+  };
+}
+
+impl_compatible!(
+  FontVariantLigatures,
+  FontVariantNumeric,
+  FontVariantEastAsian,
+  FontVariantAlternates<'_>,
+  FontVariantPosition,
+  FontVariantEmoji,
+  FontSizeAdjust
+);
+
+fn write_component<T: ToCss, W>(
+  value: &Option<T>,
+  dest: &mut Printer<W>,
+  needs_space: &mut bool,
+) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  if let Some(value) = value {
+    if *needs_space {
+      dest.write_char(' ')?;
+    }
+    value.to_css(dest)?;
+    *needs_space = true;
+  }
+  Ok(())
+}
+
+fn write_keyword<W>(
+  value: bool,
+  keyword: &str,
+  dest: &mut Printer<W>,
+  needs_space: &mut bool,
+) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  if value {
+    if *needs_space {
+      dest.write_char(' ')?;
+    }
+    dest.write_str(keyword)?;
+    *needs_space = true;
+  }
+  Ok(())
+}
+
+define_shorthand! {
+  /// A value for the [font-variant](https://drafts.csswg.org/css-fonts-4/#font-variant-prop) shorthand property.
+  pub struct FontVariant<'i> {
+    /// The ligatures and contextual forms.
+    ligatures: FontVariantLigatures(FontVariantLigatures),
+    /// How the text should be capitalized.
+    caps: FontVariantCaps(FontVariantCaps),
+    /// The alternate glyphs.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    alternates: FontVariantAlternates(FontVariantAlternates<'i>),
+    /// The numeric glyphs.
+    numeric: FontVariantNumeric(FontVariantNumeric),
+    /// The East Asian glyphs.
+    east_asian: FontVariantEastAsian(FontVariantEastAsian),
+    /// Superscript and subscript glyphs.
+    position: FontVariantPosition(FontVariantPosition),
+    /// The emoji presentation.
+    emoji: FontVariantEmoji(FontVariantEmoji),
+  }
+}
+
+impl<'i> FontVariant<'i> {
+  fn normal() -> Self {
+    FontVariant {
+      ligatures: FontVariantLigatures::default(),
+      caps: FontVariantCaps::default(),
+      alternates: FontVariantAlternates::default(),
+      numeric: FontVariantNumeric::default(),
+      east_asian: FontVariantEastAsian::default(),
+      position: FontVariantPosition::default(),
+      emoji: FontVariantEmoji::default(),
+    }
+  }
+}
+
+impl<'i> Parse<'i> for FontVariant<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut value = FontVariant::normal();
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(value);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      value.ligatures = FontVariantLigatures::NONE;
+      return Ok(value);
+    }
+
+    let mut caps = None;
+    let mut position = None;
+    let mut emoji = None;
+    let mut any = false;
+    loop {
+      if input.try_parse(|input| value.ligatures.parse_component(input)).is_ok()
+        || input.try_parse(|input| value.alternates.parse_component(input)).is_ok()
+        || input.try_parse(|input| value.numeric.parse_component(input)).is_ok()
+        || input.try_parse(|input| value.east_asian.parse_component(input)).is_ok()
+      {
+        any = true;
+        continue;
+      }
+
+      // The normal keyword is only valid on its own.
+      if caps.is_none() {
+        if let Ok(v) = input.try_parse(FontVariantCaps::parse) {
+          if v == FontVariantCaps::Normal {
+            return Err(input.new_custom_error(ParserError::InvalidValue));
+          }
+          caps = Some(v);
+          any = true;
+          continue;
+        }
+      }
+
+      if position.is_none() {
+        if let Ok(v) = input.try_parse(FontVariantPosition::parse) {
+          if v == FontVariantPosition::Normal {
+            return Err(input.new_custom_error(ParserError::InvalidValue));
+          }
+          position = Some(v);
+          any = true;
+          continue;
+        }
+      }
+
+      if emoji.is_none() {
+        if let Ok(v) = input.try_parse(FontVariantEmoji::parse) {
+          if v == FontVariantEmoji::Normal {
+            return Err(input.new_custom_error(ParserError::InvalidValue));
+          }
+          emoji = Some(v);
+          any = true;
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if !any {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    value.caps = caps.unwrap_or_default();
+    value.position = position.unwrap_or_default();
+    value.emoji = emoji.unwrap_or_default();
+    Ok(value)
+  }
+}
+
+impl<'i> ToCss for FontVariant<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let others_normal = self.caps == FontVariantCaps::Normal
+      && self.alternates.is_normal()
+      && self.numeric.is_normal()
+      && self.east_asian.is_normal()
+      && self.position == FontVariantPosition::Normal
+      && self.emoji == FontVariantEmoji::Normal;
+
+    if others_normal {
+      // Covers both `normal` and `none`.
+      return self.ligatures.to_css(dest);
+    }
+
+    let mut needs_space = false;
+    self.ligatures.write_components(dest, &mut needs_space)?;
+    if self.caps != FontVariantCaps::Normal {
+      write_component(&Some(self.caps), dest, &mut needs_space)?;
+    }
+    self.alternates.write_components(dest, &mut needs_space)?;
+    self.numeric.write_components(dest, &mut needs_space)?;
+    self.east_asian.write_components(dest, &mut needs_space)?;
+    if self.position != FontVariantPosition::Normal {
+      write_component(&Some(self.position), dest, &mut needs_space)?;
+    }
+    if self.emoji != FontVariantEmoji::Normal {
+      write_component(&Some(self.emoji), dest, &mut needs_space)?;
+    }
+    Ok(())
+  }
+}
+
 /// A value for the [line-height](https://www.w3.org/TR/2020/WD-css-inline-3-20200827/#propdef-line-height) property.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
@@ -767,6 +1531,171 @@ impl ToCss for VerticalAlign {
   }
 }
 
+/// A value for the [font-palette](https://drafts.csswg.org/css-fonts-4/#font-palette-prop) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum FontPalette<'i> {
+  /// The default palette of the font.
+  Normal,
+  /// The first palette in the font that is marked as applicable to a light background.
+  Light,
+  /// The first palette in the font that is marked as applicable to a dark background.
+  Dark,
+  /// A reference to a palette defined by a `@font-palette-values` rule.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Custom(DashedIdentReference<'i>),
+}
+
+impl<'i> ParseWithOptions<'i> for FontPalette<'i> {
+  fn parse_with_options<'t>(
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions<'_, 'i>,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontPalette::Normal);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("light")).is_ok() {
+      return Ok(FontPalette::Light);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("dark")).is_ok() {
+      return Ok(FontPalette::Dark);
+    }
+
+    Ok(FontPalette::Custom(DashedIdentReference::parse_with_options(
+      input, options,
+    )?))
+  }
+}
+
+impl<'i> ToCss for FontPalette<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      FontPalette::Normal => dest.write_str("normal"),
+      FontPalette::Light => dest.write_str("light"),
+      FontPalette::Dark => dest.write_str("dark"),
+      FontPalette::Custom(name) => name.to_css(dest),
+    }
+  }
+}
+
+enum_property! {
+  /// A font metric used by the [font-size-adjust](https://drafts.csswg.org/css-fonts-5/#font-size-adjust-prop) property.
+  #[derive(Default)]
+  pub enum FontMetric {
+    /// The x-height of the font.
+    #[default]
+    "ex-height": ExHeight,
+    /// The cap height of the font.
+    "cap-height": CapHeight,
+    /// The advance width of the "0" glyph.
+    "ch-width": ChWidth,
+    /// The advance width of the "水" glyph.
+    "ic-width": IcWidth,
+    /// The advance height of the "水" glyph.
+    "ic-height": IcHeight,
+  }
+}
+
+/// The aspect value of a [font-size-adjust](https://drafts.csswg.org/css-fonts-5/#font-size-adjust-prop) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum FontSizeAdjustValue {
+  /// The aspect value is computed from the primary font.
+  FromFont,
+  /// An explicit aspect value.
+  Number(CSSNumber),
+}
+
+/// A value for the [font-size-adjust](https://drafts.csswg.org/css-fonts-5/#font-size-adjust-prop) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum FontSizeAdjust {
+  /// The font size is not adjusted.
+  None,
+  /// The font size is adjusted so that the given metric is the aspect value multiplied by the font size.
+  Metric {
+    /// The font metric to normalize.
+    metric: FontMetric,
+    /// The aspect value.
+    value: FontSizeAdjustValue,
+  },
+}
+
+impl<'i> Parse<'i> for FontSizeAdjust {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(FontSizeAdjust::None);
+    }
+
+    let metric = input.try_parse(FontMetric::parse).unwrap_or_default();
+    if input.try_parse(|input| input.expect_ident_matching("from-font")).is_ok() {
+      return Ok(FontSizeAdjust::Metric {
+        metric,
+        value: FontSizeAdjustValue::FromFont,
+      });
+    }
+
+    let location = input.current_source_location();
+    let value = CSSNumber::parse(input)?;
+    if value < 0.0 {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(FontSizeAdjust::Metric {
+      metric,
+      value: FontSizeAdjustValue::Number(value),
+    })
+  }
+}
+
+impl ToCss for FontSizeAdjust {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      FontSizeAdjust::None => dest.write_str("none"),
+      FontSizeAdjust::Metric { metric, value } => {
+        if *metric != FontMetric::ExHeight {
+          metric.to_css(dest)?;
+          dest.write_char(' ')?;
+        }
+
+        match value {
+          FontSizeAdjustValue::FromFont => dest.write_str("from-font"),
+          FontSizeAdjustValue::Number(value) => value.to_css(dest),
+        }
+      }
+    }
+  }
+}
+
 define_shorthand! {
   /// A value for the [font](https://www.w3.org/TR/css-fonts-4/#font-prop) shorthand property.
   pub struct Font<'i> {
@@ -912,7 +1841,7 @@ impl<'i> ToCss for Font<'i> {
 
 property_bitflags! {
   #[derive(Default, Debug)]
-  struct FontProperty: u8 {
+  struct FontProperty: u16 {
     const FontFamily = 1 << 0;
     const FontSize = 1 << 1;
     const FontStyle = 1 << 2;
@@ -921,6 +1850,14 @@ property_bitflags! {
     const LineHeight = 1 << 5;
     const FontVariantCaps = 1 << 6;
     const Font = Self::FontFamily.bits() | Self::FontSize.bits() | Self::FontStyle.bits() | Self::FontWeight.bits() | Self::FontStretch.bits() | Self::LineHeight.bits() | Self::FontVariantCaps.bits();
+    const FontVariantLigatures = 1 << 7;
+    const FontVariantAlternates = 1 << 8;
+    const FontVariantNumeric = 1 << 9;
+    const FontVariantEastAsian = 1 << 10;
+    const FontVariantPosition = 1 << 11;
+    const FontVariantEmoji = 1 << 12;
+    const FontVariant = Self::FontVariantCaps.bits() | Self::FontVariantLigatures.bits() | Self::FontVariantAlternates.bits() | Self::FontVariantNumeric.bits() | Self::FontVariantEastAsian.bits() | Self::FontVariantPosition.bits() | Self::FontVariantEmoji.bits();
+    const FontSizeAdjust = 1 << 13;
   }
 }
 
@@ -933,6 +1870,13 @@ pub(crate) struct FontHandler<'i> {
   stretch: Option<FontStretch>,
   line_height: Option<LineHeight>,
   variant_caps: Option<FontVariantCaps>,
+  variant_ligatures: Option<FontVariantLigatures>,
+  variant_alternates: Option<FontVariantAlternates<'i>>,
+  variant_numeric: Option<FontVariantNumeric>,
+  variant_east_asian: Option<FontVariantEastAsian>,
+  variant_position: Option<FontVariantPosition>,
+  variant_emoji: Option<FontVariantEmoji>,
+  size_adjust: Option<FontSizeAdjust>,
   flushed_properties: FontProperty,
   has_any: bool,
 }
@@ -969,7 +1913,14 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
       FontWeight(val) => property!(weight, val),
       FontStretch(val) => property!(stretch, val),
       FontVariantCaps(val) => property!(variant_caps, val),
+      FontVariantLigatures(val) => property!(variant_ligatures, val),
+      FontVariantAlternates(val) => property!(variant_alternates, val),
+      FontVariantNumeric(val) => property!(variant_numeric, val),
+      FontVariantEastAsian(val) => property!(variant_east_asian, val),
+      FontVariantPosition(val) => property!(variant_position, val),
+      FontVariantEmoji(val) => property!(variant_emoji, val),
       LineHeight(val) => property!(line_height, val),
+      FontSizeAdjust(val) => property!(size_adjust, val),
       Font(val) => {
         flush!(family, &val.family);
         flush!(size, &val.size);
@@ -985,8 +1936,41 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
         self.stretch = Some(val.stretch.clone());
         self.line_height = Some(val.line_height.clone());
         self.variant_caps = Some(val.variant_caps.clone());
+        // The font shorthand also resets the other font-variant longhands and font-size-adjust.
+        self.variant_ligatures = Some(Default::default());
+        self.variant_alternates = Some(Default::default());
+        self.variant_numeric = Some(Default::default());
+        self.variant_east_asian = Some(Default::default());
+        self.variant_position = Some(Default::default());
+        self.variant_emoji = Some(Default::default());
+        self.size_adjust = Some(self::FontSizeAdjust::None);
+        self.has_any = true;
+      }
+      FontVariant(val) => {
+        flush!(variant_caps, &val.caps);
+        flush!(variant_ligatures, &val.ligatures);
+        flush!(variant_alternates, &val.alternates);
+        flush!(variant_numeric, &val.numeric);
+        flush!(variant_east_asian, &val.east_asian);
+        flush!(variant_position, &val.position);
+        flush!(variant_emoji, &val.emoji);
+        self.variant_caps = Some(val.caps);
+        self.variant_ligatures = Some(val.ligatures.clone());
+        self.variant_alternates = Some(val.alternates.clone());
+        self.variant_numeric = Some(val.numeric.clone());
+        self.variant_east_asian = Some(val.east_asian.clone());
+        self.variant_position = Some(val.position);
+        self.variant_emoji = Some(val.emoji);
         self.has_any = true;
-        // TODO: reset other properties
+      }
+      Custom(CustomProperty {
+        name: CustomPropertyName::Unknown(name),
+        ..
+      }) if self.has_font_shorthand() && is_untyped_font_reset_property(name) => {
+        // These properties are also reset by the font shorthand, but are not parsed,
+        // so flush to keep them after it.
+        self.flush(dest, context);
+        return false;
       }
       Unparsed(val) if is_font_property(&val.property_id) => {
         self.flush(dest, context);
@@ -1008,6 +1992,17 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
 }
 
 impl<'i> FontHandler<'i> {
+  /// Returns whether the pending longhands will be combined into a font shorthand.
+  fn has_font_shorthand(&self) -> bool {
+    self.family.is_some()
+      && self.size.is_some()
+      && self.style.is_some()
+      && self.weight.is_some()
+      && self.stretch.is_some()
+      && self.line_height.is_some()
+      && self.variant_caps.is_some()
+  }
+
   fn flush(&mut self, decls: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
     if !self.has_any {
       return;
@@ -1032,6 +2027,13 @@ impl<'i> FontHandler<'i> {
     let stretch = std::mem::take(&mut self.stretch);
     let line_height = std::mem::take(&mut self.line_height);
     let variant_caps = std::mem::take(&mut self.variant_caps);
+    let variant_ligatures = std::mem::take(&mut self.variant_ligatures);
+    let variant_alternates = std::mem::take(&mut self.variant_alternates);
+    let variant_numeric = std::mem::take(&mut self.variant_numeric);
+    let variant_east_asian = std::mem::take(&mut self.variant_east_asian);
+    let variant_position = std::mem::take(&mut self.variant_position);
+    let variant_emoji = std::mem::take(&mut self.variant_emoji);
+    let size_adjust = std::mem::take(&mut self.size_adjust);
 
     if let Some(family) = &mut family {
       if family.len() > 1 {
@@ -1072,6 +2074,31 @@ impl<'i> FontHandler<'i> {
       if !caps.is_css2() {
         push!(FontVariantCaps, variant_caps.unwrap());
       }
+
+      // The font shorthand resets the other font-variant longhands to normal,
+      // so they only need to be written when they have a different value.
+      macro_rules! push_variant {
+        ($prop: ident, $val: expr) => {
+          if let Some(val) = $val {
+            if val != Default::default() {
+              push!($prop, val);
+            }
+          }
+        };
+      }
+
+      push_variant!(FontVariantLigatures, variant_ligatures);
+      push_variant!(FontVariantAlternates, variant_alternates);
+      push_variant!(FontVariantNumeric, variant_numeric);
+      push_variant!(FontVariantEastAsian, variant_east_asian);
+      push_variant!(FontVariantPosition, variant_position);
+      push_variant!(FontVariantEmoji, variant_emoji);
+
+      if let Some(val) = size_adjust {
+        if val != FontSizeAdjust::None {
+          push!(FontSizeAdjust, val);
+        }
+      }
     } else {
       if let Some(val) = family {
         push!(FontFamily, val);
@@ -1085,8 +2112,70 @@ impl<'i> FontHandler<'i> {
         push!(FontStyle, val);
       }
 
-      if let Some(val) = variant_caps {
-        push!(FontVariantCaps, val);
+      match (
+        variant_caps,
+        variant_ligatures,
+        variant_alternates,
+        variant_numeric,
+        variant_east_asian,
+        variant_position,
+        variant_emoji,
+      ) {
+        (
+          Some(caps),
+          Some(ligatures),
+          Some(alternates),
+          Some(numeric),
+          Some(east_asian),
+          Some(position),
+          Some(emoji),
+        ) => {
+          push!(
+            FontVariant,
+            FontVariant {
+              caps,
+              ligatures,
+              alternates,
+              numeric,
+              east_asian,
+              position,
+              emoji,
+            }
+          );
+        }
+        (caps, ligatures, alternates, numeric, east_asian, position, emoji) => {
+          if let Some(val) = caps {
+            push!(FontVariantCaps, val);
+          }
+
+          if let Some(val) = ligatures {
+            push!(FontVariantLigatures, val);
+          }
+
+          if let Some(val) = alternates {
+            push!(FontVariantAlternates, val);
+          }
+
+          if let Some(val) = numeric {
+            push!(FontVariantNumeric, val);
+          }
+
+          if let Some(val) = east_asian {
+            push!(FontVariantEastAsian, val);
+          }
+
+          if let Some(val) = position {
+            push!(FontVariantPosition, val);
+          }
+
+          if let Some(val) = emoji {
+            push!(FontVariantEmoji, val);
+          }
+        }
+      }
+
+      if let Some(val) = size_adjust {
+        push!(FontSizeAdjust, val);
       }
 
       if let Some(val) = weight {
@@ -1104,6 +2193,216 @@ impl<'i> FontHandler<'i> {
   }
 }
 
+enum_property! {
+  /// A value for the [font-synthesis](https://drafts.csswg.org/css-fonts-4/#font-synthesis) longhand properties,
+  /// e.g. `font-synthesis-weight`.
+  pub enum FontSynthesisKeyword {
+    /// Synthesis is allowed.
+    Auto,
+    /// Synthesis is not allowed.
+    None,
+  }
+}
+
+define_shorthand! {
+  /// A value for the [font-synthesis](https://drafts.csswg.org/css-fonts-4/#font-synthesis) shorthand property.
+  pub struct FontSynthesis {
+    /// Whether bold faces may be synthesized.
+    weight: FontSynthesisWeight(FontSynthesisKeyword),
+    /// Whether oblique faces may be synthesized.
+    style: FontSynthesisStyle(FontSynthesisKeyword),
+    /// Whether small caps faces may be synthesized.
+    small_caps: FontSynthesisSmallCaps(FontSynthesisKeyword),
+    /// Whether superscript and subscript faces may be synthesized.
+    position: FontSynthesisPosition(FontSynthesisKeyword),
+  }
+}
+
+impl<'i> Parse<'i> for FontSynthesis {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut synthesis = FontSynthesis {
+      weight: FontSynthesisKeyword::None,
+      style: FontSynthesisKeyword::None,
+      small_caps: FontSynthesisKeyword::None,
+      position: FontSynthesisKeyword::None,
+    };
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(synthesis);
+    }
+
+    let mut any = false;
+    loop {
+      let location = input.current_source_location();
+      let ident = match input.try_parse(|input| input.expect_ident_cloned()) {
+        Ok(ident) => ident,
+        Err(_) => break,
+      };
+
+      let value = match_ignore_ascii_case! { &ident,
+        "weight" => &mut synthesis.weight,
+        "style" => &mut synthesis.style,
+        "small-caps" => &mut synthesis.small_caps,
+        "position" => &mut synthesis.position,
+        _ => return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
+      };
+
+      if *value == FontSynthesisKeyword::Auto {
+        return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())));
+      }
+
+      *value = FontSynthesisKeyword::Auto;
+      any = true;
+    }
+
+    if !any {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(synthesis)
+  }
+}
+
+impl ToCss for FontSynthesis {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let mut needs_space = false;
+    macro_rules! keyword {
+      ($key: ident, $name: literal) => {
+        if self.$key == FontSynthesisKeyword::Auto {
+          if needs_space {
+            dest.write_char(' ')?;
+          }
+          dest.write_str($name)?;
+          needs_space = true;
+        }
+      };
+    }
+
+    keyword!(weight, "weight");
+    keyword!(style, "style");
+    keyword!(small_caps, "small-caps");
+    keyword!(position, "position");
+
+    if !needs_space {
+      dest.write_str("none")?;
+    }
+
+    Ok(())
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct FontSynthesisHandler {
+  weight: Option<FontSynthesisKeyword>,
+  style: Option<FontSynthesisKeyword>,
+  small_caps: Option<FontSynthesisKeyword>,
+  position: Option<FontSynthesisKeyword>,
+  has_any: bool,
+}
+
+impl<'i> PropertyHandler<'i> for FontSynthesisHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::FontSynthesisWeight(val) => self.weight = Some(*val),
+      Property::FontSynthesisStyle(val) => self.style = Some(*val),
+      Property::FontSynthesisSmallCaps(val) => self.small_caps = Some(*val),
+      Property::FontSynthesisPosition(val) => self.position = Some(*val),
+      Property::FontSynthesis(val) => {
+        self.weight = Some(val.weight);
+        self.style = Some(val.style);
+        self.small_caps = Some(val.small_caps);
+        self.position = Some(val.position);
+      }
+      Property::Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::FontSynthesis
+            | PropertyId::FontSynthesisWeight
+            | PropertyId::FontSynthesisStyle
+            | PropertyId::FontSynthesisSmallCaps
+            | PropertyId::FontSynthesisPosition
+        ) =>
+      {
+        self.flush(dest, context);
+        dest.push(property.clone());
+        return true;
+      }
+      _ => return false,
+    }
+
+    self.has_any = true;
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    self.flush(dest, context);
+  }
+}
+
+impl FontSynthesisHandler {
+  fn flush<'i>(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    if !self.has_any {
+      return;
+    }
+
+    self.has_any = false;
+
+    let weight = std::mem::take(&mut self.weight);
+    let style = std::mem::take(&mut self.style);
+    let small_caps = std::mem::take(&mut self.small_caps);
+    let position = std::mem::take(&mut self.position);
+
+    // Browsers without support for the position keyword would reject the whole shorthand,
+    // so in that case it is omitted (i.e. none) and followed by the longhand instead.
+    let position_supported = context.targets.is_compatible(Feature::FontSynthesisPosition);
+    let shorthand_position = match position {
+      Some(FontSynthesisKeyword::Auto) if !position_supported => Some(FontSynthesisKeyword::None),
+      // When the targets only support the shorthand, an omitted position is reset to none,
+      // which only matters in browsers that support it.
+      None if !context.targets.is_compatible(Feature::FontSynthesisWeight) => Some(FontSynthesisKeyword::None),
+      position => position,
+    };
+
+    match (weight, style, small_caps, shorthand_position) {
+      (Some(weight), Some(style), Some(small_caps), Some(shorthand_position)) => {
+        dest.push(Property::FontSynthesis(FontSynthesis {
+          weight,
+          style,
+          small_caps,
+          position: shorthand_position,
+        }));
+        if let Some(position) = position {
+          if position != shorthand_position {
+            dest.push(Property::FontSynthesisPosition(position));
+          }
+        }
+      }
+      (weight, style, small_caps, _) => {
+        if let Some(weight) = weight {
+          dest.push(Property::FontSynthesisWeight(weight));
+        }
+        if let Some(style) = style {
+          dest.push(Property::FontSynthesisStyle(style));
+        }
+        if let Some(small_caps) = small_caps {
+          dest.push(Property::FontSynthesisSmallCaps(small_caps));
+        }
+        if let Some(position) = position {
+          dest.push(Property::FontSynthesisPosition(position));
+        }
+      }
+    }
+  }
+}
+
 const SYSTEM_UI: FontFamily = FontFamily::Generic(GenericFontFamily::SystemUI);
 
 const DEFAULT_SYSTEM_FONTS: &[&str] = &[
@@ -1151,8 +2450,26 @@ fn is_font_property(property_id: &PropertyId) -> bool {
     | PropertyId::FontWeight
     | PropertyId::FontStretch
     | PropertyId::FontVariantCaps
+    | PropertyId::FontVariantLigatures
+    | PropertyId::FontVariantAlternates
+    | PropertyId::FontVariantNumeric
+    | PropertyId::FontVariantEastAsian
+    | PropertyId::FontVariantPosition
+    | PropertyId::FontVariantEmoji
+    | PropertyId::FontVariant
+    | PropertyId::FontSizeAdjust
     | PropertyId::LineHeight
     | PropertyId::Font => true,
     _ => false,
   }
 }
+
+/// Returns whether the given property is reset by the font shorthand, but is not parsed into a typed value.
+#[inline]
+fn is_untyped_font_reset_property(name: &str) -> bool {
+  name.eq_ignore_ascii_case("font-kerning")
+    || name.eq_ignore_ascii_case("font-feature-settings")
+    || name.eq_ignore_ascii_case("font-variation-settings")
+    || name.eq_ignore_ascii_case("font-optical-sizing")
+    || name.eq_ignore_ascii_case("font-language-override")
+}
@@ -8,6 +8,7 @@ use crate::macros::{define_shorthand, enum_property, shorthand_handler, shorthan
 use crate::printer::Printer;
 use crate::targets::{Browsers, Targets};
 use crate::traits::{FallbackValues, IsCompatible, Parse, PropertyHandler, Shorthand, ToCss};
+use crate::values::color::GamutMapping;
 use crate::values::string::CSSString;
 use crate::values::{ident::CustomIdent, image::Image};
 #[cfg(feature = "visitor")]
@@ -394,10 +395,10 @@ shorthand_property! {
 }
 
 impl<'i> FallbackValues for ListStyle<'i> {
-  fn get_fallbacks(&mut self, targets: Targets) -> Vec<Self> {
+  fn get_fallbacks(&mut self, targets: Targets, gamut_mapping: GamutMapping) -> Vec<Self> {
     self
       .image
-      .get_fallbacks(targets)
+      .get_fallbacks(targets, gamut_mapping)
       .into_iter()
       .map(|image| ListStyle { image, ..self.clone() })
       .collect()
@@ -10,8 +10,9 @@ use crate::macros::{define_list_shorthand, define_shorthand, enum_property, prop
 use crate::prefixes::Feature;
 use crate::printer::Printer;
 use crate::properties::Property;
-use crate::targets::{Browsers, Targets};
+use crate::targets::{Browsers, Features, Targets};
 use crate::traits::{FallbackValues, IsCompatible, Parse, PropertyHandler, Shorthand, ToCss};
+use crate::values::color::GamutMapping;
 use crate::values::image::ImageFallback;
 use crate::values::length::LengthOrNumber;
 use crate::values::rect::Rect;
@@ -553,10 +554,10 @@ impl<'i> ToCss for MaskBorder<'i> {
 }
 
 impl<'i> FallbackValues for MaskBorder<'i> {
-  fn get_fallbacks(&mut self, targets: Targets) -> Vec<Self> {
+  fn get_fallbacks(&mut self, targets: Targets, gamut_mapping: GamutMapping) -> Vec<Self> {
     self
       .source
-      .get_fallbacks(targets)
+      .get_fallbacks(targets, gamut_mapping)
       .into_iter()
       .map(|source| MaskBorder { source, ..self.clone() })
       .collect()
@@ -721,11 +722,12 @@ impl<'i> PropertyHandler<'i> for MaskHandler<'i> {
       }
       Property::Unparsed(val) if is_mask_property(&val.property_id) => {
         self.flush(dest, context);
-        let mut unparsed = val.get_prefixed(context.targets, Feature::Mask);
+        let (mut unparsed, prefixed) = context.prefix_unparsed(val, Feature::Mask);
         context.add_unparsed_fallbacks(&mut unparsed);
         self
           .flushed_properties
           .insert(MaskProperty::try_from(&val.property_id).unwrap());
+        dest.extend(prefixed);
         dest.push(Property::Unparsed(unparsed));
       }
       Property::MaskBorderSource(val) => property!(border_source, val, &VendorPrefix::None),
@@ -858,7 +860,8 @@ impl<'i> MaskHandler<'i> {
 
         let mut prefix = context.targets.prefixes(intersection, Feature::Mask);
         if !self.flushed_properties.intersects(MaskProperty::Mask) {
-          for fallback in masks.get_fallbacks(context.targets) {
+          let mut fallbacks = Vec::new();
+          for fallback in masks.get_fallbacks(context.targets, context.gamut_mapping) {
             // Match prefix of fallback. e.g. -webkit-linear-gradient
             // can only be used in -webkit-mask-image.
             // However, if mask-image is unprefixed, gradients can still be.
@@ -870,8 +873,9 @@ impl<'i> MaskHandler<'i> {
             if p.is_empty() {
               p = prefix;
             }
-            self.flush_mask_shorthand(fallback, p, dest);
+            self.flush_mask_shorthand(fallback, p, &mut fallbacks);
           }
+          dest.extend(context.fallbacks(|| Property::Mask(masks.clone(), prefix), fallbacks));
 
           let p = masks
             .iter()
@@ -883,7 +887,20 @@ impl<'i> MaskHandler<'i> {
           }
         }
 
-        self.flush_mask_shorthand(masks, prefix, dest);
+        let (prefix, prefixed) = context.split_prefixes_with(
+          intersection,
+          prefix,
+          || Property::Mask(masks.clone(), intersection),
+          |vp| {
+            let mut prefixed = Vec::new();
+            self.flush_mask_shorthand(masks.clone(), vp, &mut prefixed);
+            prefixed
+          },
+        );
+        dest.extend(prefixed);
+        if !prefix.is_empty() {
+          self.flush_mask_shorthand(masks, prefix, dest);
+        }
         self.flushed_properties.insert(MaskProperty::Mask);
 
         images_vp.remove(intersection);
@@ -902,6 +919,8 @@ impl<'i> MaskHandler<'i> {
         if let Some((val, vp)) = $var {
           if !vp.is_empty() {
             let prefix = context.targets.prefixes(vp, Feature::$property);
+            let (prefix, prefixed) = context.split_prefixes(vp, prefix, |vp| Property::$property(val.clone(), vp));
+            dest.extend(prefixed);
             dest.push(Property::$property(val, prefix));
             self.flushed_properties.insert(MaskProperty::$property);
           }
@@ -914,7 +933,8 @@ impl<'i> MaskHandler<'i> {
         let mut prefix = vp;
         if !self.flushed_properties.contains(MaskProperty::MaskImage) {
           prefix = context.targets.prefixes(prefix, Feature::MaskImage);
-          for fallback in images.get_fallbacks(context.targets) {
+          let mut fallbacks = Vec::new();
+          for fallback in images.get_fallbacks(context.targets, context.gamut_mapping) {
             // Match prefix of fallback. e.g. -webkit-linear-gradient
             // can only be used in -webkit-mask-image.
             // However, if mask-image is unprefixed, gradients can still be.
@@ -926,8 +946,9 @@ impl<'i> MaskHandler<'i> {
             if p.is_empty() {
               p = prefix;
             }
-            dest.push(Property::MaskImage(fallback, p))
+            fallbacks.push(Property::MaskImage(fallback, p))
           }
+          dest.extend(context.fallbacks(|| Property::MaskImage(images.clone(), prefix), fallbacks));
 
           let p = images
             .iter()
@@ -939,7 +960,11 @@ impl<'i> MaskHandler<'i> {
           }
         }
 
-        dest.push(Property::MaskImage(images, prefix));
+        let (prefix, prefixed) = context.split_prefixes(vp, prefix, |vp| Property::MaskImage(images.clone(), vp));
+        dest.extend(prefixed);
+        if !prefix.is_empty() {
+          dest.push(Property::MaskImage(images, prefix));
+        }
         self.flushed_properties.insert(MaskProperty::MaskImage);
       }
     }
@@ -953,8 +978,11 @@ impl<'i> MaskHandler<'i> {
     if let Some(composites) = composites {
       let prefix = context.targets.prefixes(VendorPrefix::None, Feature::MaskComposite);
       if prefix.contains(VendorPrefix::WebKit) {
-        dest.push(Property::WebKitMaskComposite(
-          composites.iter().map(|c| (*c).into()).collect(),
+        dest.extend(context.prefixed(
+          || Property::MaskComposite(composites.clone()),
+          vec![Property::WebKitMaskComposite(
+            composites.iter().map(|c| (*c).into()).collect(),
+          )],
         ));
       }
 
@@ -965,9 +993,12 @@ impl<'i> MaskHandler<'i> {
     if let Some(modes) = modes {
       let prefix = context.targets.prefixes(VendorPrefix::None, Feature::Mask);
       if prefix.contains(VendorPrefix::WebKit) {
-        dest.push(Property::WebKitMaskSourceType(
-          modes.iter().map(|c| (*c).into()).collect(),
-          VendorPrefix::WebKit,
+        dest.extend(context.prefixed(
+          || Property::MaskMode(modes.clone()),
+          vec![Property::WebKitMaskSourceType(
+            modes.iter().map(|c| (*c).into()).collect(),
+            VendorPrefix::WebKit,
+          )],
         ));
       }
 
@@ -1034,6 +1065,29 @@ impl<'i> MaskHandler<'i> {
     let mut repeat = std::mem::take(&mut self.border_repeat);
     let mut mode = std::mem::take(&mut self.border_mode);
 
+    // When compiling for browser targets, the legacy -webkit-mask-box-image properties are converted
+    // to the standard mask-border properties. The prefixed properties are added back below if needed.
+    if context.targets.browsers.is_some() && !context.targets.exclude.contains(Features::VendorPrefixes) {
+      let unprefix = |vp: &mut VendorPrefix| {
+        let is_legacy = *vp == VendorPrefix::WebKit;
+        if is_legacy {
+          *vp = VendorPrefix::None;
+        }
+        is_legacy
+      };
+
+      let source = source.as_mut().map_or(false, |(_, vp)| unprefix(vp));
+      let slice = slice.as_mut().map_or(false, |(_, vp)| unprefix(vp));
+      let width = width.as_mut().map_or(false, |(_, vp)| unprefix(vp));
+      let outset = outset.as_mut().map_or(false, |(_, vp)| unprefix(vp));
+      let repeat = repeat.as_mut().map_or(false, |(_, vp)| unprefix(vp));
+
+      // -webkit-mask-box-image has no mode, and always uses the alpha channel.
+      if source && slice && width && outset && repeat && mode.is_none() {
+        mode = Some(MaskBorderMode::default());
+      }
+    }
+
     if let (
       Some((source, source_vp)),
       Some((slice, slice_vp)),
@@ -1056,24 +1110,25 @@ impl<'i> MaskHandler<'i> {
         let mut prefix = context.targets.prefixes(intersection, Feature::MaskBorder);
         if !self.flushed_properties.intersects(MaskProperty::MaskBorder) {
           // Get vendor prefix and color fallbacks.
-          let fallbacks = mask_border.get_fallbacks(context.targets);
-          for fallback in fallbacks {
+          let mut fallbacks = Vec::new();
+          for fallback in mask_border.get_fallbacks(context.targets, context.gamut_mapping) {
             let mut p = fallback.source.get_vendor_prefix() - VendorPrefix::None & prefix;
             if p.is_empty() {
               p = prefix;
             }
 
             if p.contains(VendorPrefix::WebKit) {
-              dest.push(Property::WebKitMaskBoxImage(
+              fallbacks.push(Property::WebKitMaskBoxImage(
                 fallback.clone().into(),
                 VendorPrefix::WebKit,
               ));
             }
 
             if p.contains(VendorPrefix::None) {
-              dest.push(Property::MaskBorder(fallback));
+              fallbacks.push(Property::MaskBorder(fallback));
             }
           }
+          dest.extend(context.fallbacks(|| Property::MaskBorder(mask_border.clone()), fallbacks));
         }
 
         let p = mask_border.source.get_vendor_prefix() - VendorPrefix::None & prefix;
@@ -1082,10 +1137,12 @@ impl<'i> MaskHandler<'i> {
         }
 
         if prefix.contains(VendorPrefix::WebKit) {
-          dest.push(Property::WebKitMaskBoxImage(
-            mask_border.clone().into(),
-            VendorPrefix::WebKit,
-          ));
+          let webkit = Property::WebKitMaskBoxImage(mask_border.clone().into(), VendorPrefix::WebKit);
+          if intersection.contains(VendorPrefix::WebKit) {
+            dest.push(webkit);
+          } else {
+            dest.extend(context.prefixed(|| Property::MaskBorder(mask_border.clone()), vec![webkit]));
+          }
         }
 
         if prefix.contains(VendorPrefix::None) {
@@ -1102,28 +1159,34 @@ impl<'i> MaskHandler<'i> {
       }
     }
 
-    if let Some((mut source, mut prefix)) = source {
-      prefix = context.targets.prefixes(prefix, Feature::MaskBorderSource);
+    if let Some((mut source, authored)) = source {
+      let prefix = context.targets.prefixes(authored, Feature::MaskBorderSource);
 
       if !self.flushed_properties.contains(MaskProperty::MaskBorderSource) {
         // Get vendor prefix and color fallbacks.
-        let fallbacks = source.get_fallbacks(context.targets);
-        for fallback in fallbacks {
+        let mut fallbacks = Vec::new();
+        for fallback in source.get_fallbacks(context.targets, context.gamut_mapping) {
           if prefix.contains(VendorPrefix::WebKit) {
-            dest.push(Property::WebKitMaskBoxImageSource(
+            fallbacks.push(Property::WebKitMaskBoxImageSource(
               fallback.clone(),
               VendorPrefix::WebKit,
             ));
           }
 
           if prefix.contains(VendorPrefix::None) {
-            dest.push(Property::MaskBorderSource(fallback));
+            fallbacks.push(Property::MaskBorderSource(fallback));
           }
         }
+        dest.extend(context.fallbacks(|| Property::MaskBorderSource(source.clone()), fallbacks));
       }
 
       if prefix.contains(VendorPrefix::WebKit) {
-        dest.push(Property::WebKitMaskBoxImageSource(source.clone(), VendorPrefix::WebKit));
+        let webkit = Property::WebKitMaskBoxImageSource(source.clone(), VendorPrefix::WebKit);
+        if authored.contains(VendorPrefix::WebKit) {
+          dest.push(webkit);
+        } else {
+          dest.extend(context.prefixed(|| Property::MaskBorderSource(source.clone()), vec![webkit]));
+        }
       }
 
       if prefix.contains(VendorPrefix::None) {
@@ -1134,10 +1197,15 @@ impl<'i> MaskHandler<'i> {
 
     macro_rules! prop {
       ($val: expr, $prop: ident, $webkit: ident) => {
-        if let Some((val, mut prefix)) = $val {
-          prefix = context.targets.prefixes(prefix, Feature::$prop);
+        if let Some((val, authored)) = $val {
+          let prefix = context.targets.prefixes(authored, Feature::$prop);
           if prefix.contains(VendorPrefix::WebKit) {
-            dest.push(Property::$webkit(val.clone(), VendorPrefix::WebKit));
+            let webkit = Property::$webkit(val.clone(), VendorPrefix::WebKit);
+            if authored.contains(VendorPrefix::WebKit) {
+              dest.push(webkit);
+            } else {
+              dest.extend(context.prefixed(|| Property::$prop(val.clone()), vec![webkit]));
+            }
           }
 
           if prefix.contains(VendorPrefix::None) {
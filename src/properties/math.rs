@@ -0,0 +1,80 @@
+//! CSS properties related to MathML.
+
+use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
+use crate::printer::Printer;
+use crate::traits::{Parse, ToCss};
+use crate::values::number::CSSInteger;
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+/// A value for the [math-depth](https://w3c.github.io/mathml-core/#the-math-script-level-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum MathDepth {
+  /// Increments the inherited math depth by one when `math-style` is `compact`.
+  AutoAdd,
+  /// Adds the given value to the inherited math depth.
+  Add(CSSInteger),
+  /// Sets the math depth to the given value.
+  Integer(CSSInteger),
+}
+
+impl<'i> Parse<'i> for MathDepth {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto-add")).is_ok() {
+      return Ok(MathDepth::AutoAdd);
+    }
+
+    if input.try_parse(|input| input.expect_function_matching("add")).is_ok() {
+      return input.parse_nested_block(|input| Ok(MathDepth::Add(CSSInteger::parse(input)?)));
+    }
+
+    Ok(MathDepth::Integer(CSSInteger::parse(input)?))
+  }
+}
+
+impl ToCss for MathDepth {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      MathDepth::AutoAdd => dest.write_str("auto-add"),
+      MathDepth::Add(value) => {
+        dest.write_str("add(")?;
+        value.to_css(dest)?;
+        dest.write_char(')')
+      }
+      MathDepth::Integer(value) => value.to_css(dest),
+    }
+  }
+}
+
+enum_property! {
+  /// A value for the [math-style](https://w3c.github.io/mathml-core/#the-math-style-property) property.
+  pub enum MathStyle {
+    /// The initial value, used for display mathematics.
+    Normal,
+    /// Reduces the height of formulas, e.g. for inline mathematics.
+    Compact,
+  }
+}
+
+enum_property! {
+  /// A value for the [math-shift](https://w3c.github.io/mathml-core/#the-math-shift) property.
+  pub enum MathShift {
+    /// The initial value, using the normal superscript shift.
+    Normal,
+    /// Uses the reduced superscript shift, e.g. for cramped styles.
+    Compact,
+  }
+}
@@ -91,6 +91,7 @@
 #![deny(missing_docs)]
 
 pub mod align;
+pub mod anchor;
 pub mod animation;
 pub mod background;
 pub mod border;
@@ -109,16 +110,20 @@ pub mod grid;
 pub mod list;
 pub(crate) mod margin_padding;
 pub mod masking;
+pub mod math;
+pub mod motion;
 pub mod outline;
 pub mod overflow;
 pub mod position;
 pub(crate) mod prefix_handler;
+pub mod scroll;
 pub mod size;
 pub mod svg;
 pub mod text;
 pub mod transform;
 pub mod transition;
 pub mod ui;
+pub mod view_transition;
 
 use crate::declaration::DeclarationBlock;
 use crate::error::{ParserError, PrinterError};
@@ -128,18 +133,19 @@ use crate::parser::starts_with_ignore_ascii_case;
 use crate::parser::ParserOptions;
 use crate::prefixes::Feature;
 use crate::printer::{Printer, PrinterOptions};
-use crate::targets::Targets;
-use crate::traits::{Parse, ParseWithOptions, Shorthand, ToCss};
+use crate::targets::{Browsers, Targets};
+use crate::traits::{IsCompatible, Parse, ParseWithOptions, Shorthand, ToCss};
 use crate::values::number::{CSSInteger, CSSNumber};
 use crate::values::string::CowArcStr;
 use crate::values::{
-  alpha::*, color::*, easing::EasingFunction, ident::CustomIdent, ident::DashedIdentReference, image::*,
-  length::*, position::*, rect::*, shape::FillRule, size::Size2D, time::Time,
+  alpha::*, color::*, easing::EasingFunction, image::*, length::*, position::*, rect::*, shape::FillRule,
+  size::Size2D, time::Time,
 };
 use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use align::*;
+use anchor::*;
 use animation::*;
 use background::*;
 use border::*;
@@ -159,8 +165,11 @@ use grid::*;
 use list::*;
 use margin_padding::*;
 use masking::*;
+use math::*;
+use motion::*;
 use outline::*;
 use overflow::*;
+use scroll::*;
 use size::*;
 use smallvec::{smallvec, SmallVec};
 #[cfg(feature = "into_owned")]
@@ -170,6 +179,7 @@ use text::*;
 use transform::*;
 use transition::*;
 use ui::*;
+use view_transition::*;
 
 macro_rules! define_properties {
   (
@@ -1217,6 +1227,10 @@ define_properties! {
   "display": Display(Display),
   "visibility": Visibility(Visibility),
 
+  // https://drafts.csswg.org/css-display-4/
+  "reading-flow": ReadingFlow(ReadingFlow),
+  "reading-order": ReadingOrder(CSSInteger),
+
   "width": Width(Size) [logical_group: Size, category: Physical],
   "height": Height(Size) [logical_group: Size, category: Physical],
   "min-width": MinWidth(Size) [logical_group: MinSize, category: Physical],
@@ -1231,11 +1245,22 @@ define_properties! {
   "max-inline-size": MaxInlineSize(MaxSize) [logical_group: MaxSize, category: Logical],
   "box-sizing": BoxSizing(BoxSizing, VendorPrefix) / WebKit / Moz,
   "aspect-ratio": AspectRatio(AspectRatio),
+  "contain-intrinsic-width": ContainIntrinsicWidth(ContainIntrinsicLength),
+  "contain-intrinsic-height": ContainIntrinsicHeight(ContainIntrinsicLength),
+  "contain-intrinsic-block-size": ContainIntrinsicBlockSize(ContainIntrinsicLength),
+  "contain-intrinsic-inline-size": ContainIntrinsicInlineSize(ContainIntrinsicLength),
+  "contain-intrinsic-size": ContainIntrinsicSize(ContainIntrinsicSize) shorthand: true,
 
   "overflow": Overflow(Overflow) shorthand: true,
   "overflow-x": OverflowX(OverflowKeyword),
   "overflow-y": OverflowY(OverflowKeyword),
+  "overscroll-behavior": OverscrollBehavior(OverscrollBehavior) shorthand: true,
+  "overscroll-behavior-x": OverscrollBehaviorX(OverscrollBehaviorKeyword),
+  "overscroll-behavior-y": OverscrollBehaviorY(OverscrollBehaviorKeyword),
   "text-overflow": TextOverflow(TextOverflow, VendorPrefix) / O,
+  "line-clamp": LineClamp(LineClamp<'i>, VendorPrefix) / WebKit,
+  "continue": Continue(Continue),
+  "overflow-clip-margin": OverflowClipMargin(OverflowClipMargin),
 
   // https://www.w3.org/TR/2020/WD-css-position-3-20200519
   "position": Position(position::Position),
@@ -1251,6 +1276,15 @@ define_properties! {
   "inset-inline": InsetInline(InsetInline) shorthand: true,
   "inset": Inset(Inset) shorthand: true,
 
+  // https://drafts.csswg.org/css-anchor-position-1/
+  "anchor-name": AnchorName(AnchorName<'i>),
+  "position-anchor": PositionAnchor(PositionAnchor<'i>),
+  "position-area": PositionArea(PositionArea),
+  "inset-area": InsetArea(PositionArea),
+  "position-try-order": PositionTryOrder(PositionTryOrder),
+  "position-try-fallbacks": PositionTryFallbacks(PositionTryFallbacks<'i>),
+  "position-try": PositionTry(PositionTry<'i>) shorthand: true,
+
   "border-spacing": BorderSpacing(Size2D<Length>),
 
   "border-top-color": BorderTopColor(CssColor) [logical_group: BorderColor, category: Physical],
@@ -1290,6 +1324,17 @@ define_properties! {
   "border-end-end-radius": BorderEndEndRadius(Size2D<LengthPercentage>) [logical_group: BorderRadius, category: Logical],
   "border-radius": BorderRadius(BorderRadius, VendorPrefix) / WebKit / Moz shorthand: true,
 
+  // https://drafts.csswg.org/css-borders-4/#corner-shaping
+  "corner-top-left-shape": CornerTopLeftShape(CornerShapeValue) [logical_group: CornerShape, category: Physical],
+  "corner-top-right-shape": CornerTopRightShape(CornerShapeValue) [logical_group: CornerShape, category: Physical],
+  "corner-bottom-right-shape": CornerBottomRightShape(CornerShapeValue) [logical_group: CornerShape, category: Physical],
+  "corner-bottom-left-shape": CornerBottomLeftShape(CornerShapeValue) [logical_group: CornerShape, category: Physical],
+  "corner-start-start-shape": CornerStartStartShape(CornerShapeValue) [logical_group: CornerShape, category: Logical],
+  "corner-start-end-shape": CornerStartEndShape(CornerShapeValue) [logical_group: CornerShape, category: Logical],
+  "corner-end-end-shape": CornerEndEndShape(CornerShapeValue) [logical_group: CornerShape, category: Logical],
+  "corner-end-start-shape": CornerEndStartShape(CornerShapeValue) [logical_group: CornerShape, category: Logical],
+  "corner-shape": CornerShape(CornerShape) shorthand: true,
+
   "border-image-source": BorderImageSource(Image<'i>),
   "border-image-outset": BorderImageOutset(Rect<LengthOrNumber>),
   "border-image-repeat": BorderImageRepeat(BorderImageRepeat),
@@ -1466,21 +1511,47 @@ define_properties! {
   "scroll-padding-inline": ScrollPaddingInline(ScrollPaddingInline) shorthand: true,
   "scroll-padding": ScrollPadding(ScrollPadding) shorthand: true,
 
+  "scroll-start-block": ScrollStartBlock(ScrollStartValue),
+  "scroll-start-inline": ScrollStartInline(ScrollStartValue),
+  "scroll-start-x": ScrollStartX(ScrollStartValue),
+  "scroll-start-y": ScrollStartY(ScrollStartValue),
+  "scroll-start": ScrollStart(ScrollStart) shorthand: true,
+  "scroll-start-target-block": ScrollStartTargetBlock(ScrollStartTargetValue),
+  "scroll-start-target-inline": ScrollStartTargetInline(ScrollStartTargetValue),
+  "scroll-start-target-x": ScrollStartTargetX(ScrollStartTargetValue),
+  "scroll-start-target-y": ScrollStartTargetY(ScrollStartTargetValue),
+  "scroll-start-target": ScrollStartTarget(ScrollStartTarget) shorthand: true,
+  "scroll-initial-target": ScrollInitialTarget(ScrollInitialTarget),
+
   "font-weight": FontWeight(FontWeight),
   "font-size": FontSize(FontSize),
   "font-stretch": FontStretch(FontStretch),
   "font-family": FontFamily(Vec<FontFamily<'i>>),
   "font-style": FontStyle(FontStyle),
   "font-variant-caps": FontVariantCaps(FontVariantCaps),
+  "font-variant-ligatures": FontVariantLigatures(FontVariantLigatures),
+  "font-variant-alternates": FontVariantAlternates(FontVariantAlternates<'i>),
+  "font-variant-numeric": FontVariantNumeric(FontVariantNumeric),
+  "font-variant-east-asian": FontVariantEastAsian(FontVariantEastAsian),
+  "font-variant-position": FontVariantPosition(FontVariantPosition),
+  "font-variant-emoji": FontVariantEmoji(FontVariantEmoji),
+  "font-variant": FontVariant(FontVariant<'i>) shorthand: true,
   "line-height": LineHeight(LineHeight),
   "font": Font(Font<'i>) shorthand: true,
   "vertical-align": VerticalAlign(VerticalAlign),
-  "font-palette": FontPalette(DashedIdentReference<'i>),
+  "font-palette": FontPalette(FontPalette<'i>),
+  "font-size-adjust": FontSizeAdjust(FontSizeAdjust),
+  "font-synthesis-weight": FontSynthesisWeight(FontSynthesisKeyword),
+  "font-synthesis-style": FontSynthesisStyle(FontSynthesisKeyword),
+  "font-synthesis-small-caps": FontSynthesisSmallCaps(FontSynthesisKeyword),
+  "font-synthesis-position": FontSynthesisPosition(FontSynthesisKeyword),
+  "font-synthesis": FontSynthesis(FontSynthesis) shorthand: true,
 
   "transition-property": TransitionProperty(SmallVec<[PropertyId<'i>; 1]>, VendorPrefix) / WebKit / Moz / Ms,
   "transition-duration": TransitionDuration(SmallVec<[Time; 1]>, VendorPrefix) / WebKit / Moz / Ms,
   "transition-delay": TransitionDelay(SmallVec<[Time; 1]>, VendorPrefix) / WebKit / Moz / Ms,
   "transition-timing-function": TransitionTimingFunction(SmallVec<[EasingFunction; 1]>, VendorPrefix) / WebKit / Moz / Ms,
+  "transition-behavior": TransitionBehavior(SmallVec<[TransitionBehavior; 1]>),
   "transition": Transition(SmallVec<[Transition<'i>; 1]>, VendorPrefix) / WebKit / Moz / Ms shorthand: true,
 
   "animation-name": AnimationName(AnimationNameList<'i>, VendorPrefix) / WebKit / Moz / O,
@@ -1495,6 +1566,15 @@ define_properties! {
   "animation-timeline": AnimationTimeline(SmallVec<[AnimationTimeline<'i>; 1]>),
   "animation": Animation(AnimationList<'i>, VendorPrefix) / WebKit / Moz / O shorthand: true,
 
+  // https://drafts.csswg.org/scroll-animations-1/
+  "scroll-timeline-name": ScrollTimelineName(SmallVec<[TimelineName<'i>; 1]>),
+  "scroll-timeline-axis": ScrollTimelineAxis(SmallVec<[ScrollAxis; 1]>),
+  "scroll-timeline": ScrollTimeline(SmallVec<[ScrollTimelineDefinition<'i>; 1]>) shorthand: true,
+  "view-timeline-name": ViewTimelineName(SmallVec<[TimelineName<'i>; 1]>),
+  "view-timeline-axis": ViewTimelineAxis(SmallVec<[ScrollAxis; 1]>),
+  "view-timeline-inset": ViewTimelineInset(SmallVec<[Size2D<LengthPercentageOrAuto>; 1]>),
+  "view-timeline": ViewTimeline(SmallVec<[ViewTimelineDefinition<'i>; 1]>) shorthand: true,
+
   // https://drafts.csswg.org/css-transforms-2/
   "transform": Transform(TransformList, VendorPrefix) / WebKit / Moz / Ms / O,
   "transform-origin": TransformOrigin(Position, VendorPrefix) / WebKit / Moz / Ms / O, // TODO: handle z offset syntax
@@ -1507,9 +1587,19 @@ define_properties! {
   "rotate": Rotate(Rotate),
   "scale": Scale(Scale),
 
+  // https://drafts.fxtf.org/motion-1/
+  "offset-path": OffsetPath(OffsetPath<'i>),
+  "offset-distance": OffsetDistance(LengthPercentage),
+  "offset-rotate": OffsetRotate(OffsetRotate),
+  "offset-anchor": OffsetAnchor(OffsetAnchor),
+  "offset-position": OffsetPosition(OffsetPosition),
+  "offset": Offset(Offset<'i>) shorthand: true,
+
   // https://www.w3.org/TR/2021/CRD-css-text-3-20210422
   "text-transform": TextTransform(TextTransform),
-  "white-space": WhiteSpace(WhiteSpace),
+  "white-space-collapse": WhiteSpaceCollapse(WhiteSpaceCollapse),
+  "text-wrap-mode": TextWrapMode(TextWrapMode),
+  "white-space": WhiteSpace(WhiteSpace) shorthand: true,
   "tab-size": TabSize(LengthOrNumber, VendorPrefix) / Moz / O,
   "word-break": WordBreak(WordBreak),
   "line-break": LineBreak(LineBreak),
@@ -1523,6 +1613,11 @@ define_properties! {
   "letter-spacing": LetterSpacing(Spacing),
   "text-indent": TextIndent(TextIndent),
 
+  // https://drafts.csswg.org/css-text-4/
+  "text-spacing-trim": TextSpacingTrim(TextSpacingTrim),
+  "text-autospace": TextAutospace(TextAutospace),
+  "text-spacing": TextSpacing(TextSpacing) shorthand: true,
+
   // https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506
   "text-decoration-line": TextDecorationLine(TextDecorationLine, VendorPrefix) / WebKit / Moz,
   "text-decoration-style": TextDecorationStyle(TextDecorationStyle, VendorPrefix) / WebKit / Moz,
@@ -1536,6 +1631,13 @@ define_properties! {
   "text-emphasis-position": TextEmphasisPosition(TextEmphasisPosition, VendorPrefix) / WebKit,
   "text-shadow": TextShadow(SmallVec<[TextShadow; 1]>),
 
+  // https://drafts.csswg.org/css-inline-3/
+  "text-box-trim": TextBoxTrim(TextBoxTrim),
+  "text-box-edge": TextBoxEdge(TextBoxEdge),
+  "text-box": TextBox(TextBox) shorthand: true,
+  "initial-letter": InitialLetter(InitialLetter, VendorPrefix) / WebKit,
+  "initial-letter-align": InitialLetterAlign(InitialLetterAlign),
+
   // https://w3c.github.io/csswg-drafts/css-size-adjust/
   "text-size-adjust": TextSizeAdjust(TextSizeAdjust, VendorPrefix) / WebKit / Moz / Ms,
 
@@ -1555,6 +1657,16 @@ define_properties! {
   "user-select": UserSelect(UserSelect, VendorPrefix) / WebKit / Moz / Ms,
   "accent-color": AccentColor(ColorOrAuto),
   "appearance": Appearance(Appearance<'i>, VendorPrefix) / WebKit / Moz / Ms,
+  "field-sizing": FieldSizing(FieldSizing),
+
+  // https://drafts.csswg.org/css-scrollbars-1/
+  "scrollbar-width": ScrollbarWidth(ScrollbarWidth),
+  "scrollbar-color": ScrollbarColor(ScrollbarColor),
+
+  // Nonstandard properties used by mobile WebKit and Blink browsers.
+  "tap-highlight-color": WebKitTapHighlightColor(CssColor, VendorPrefix) / WebKit unprefixed: false,
+  "touch-callout": WebKitTouchCallout(WebKitTouchCallout, VendorPrefix) / WebKit unprefixed: false,
+  "overflow-scrolling": WebKitOverflowScrolling(WebKitOverflowScrolling, VendorPrefix) / WebKit unprefixed: false,
 
   // https://www.w3.org/TR/2020/WD-css-lists-3-20201117
   "list-style-type": ListStyleType(ListStyleType<'i>),
@@ -1581,13 +1693,25 @@ define_properties! {
   "marker-start": MarkerStart(Marker<'i>),
   "marker-mid": MarkerMid(Marker<'i>),
   "marker-end": MarkerEnd(Marker<'i>),
-  "marker": Marker(Marker<'i>),
+  "marker": Marker(Marker<'i>) shorthand: true,
   "color-interpolation": ColorInterpolation(ColorInterpolation),
   "color-interpolation-filters": ColorInterpolationFilters(ColorInterpolation),
   "color-rendering": ColorRendering(ColorRendering),
   "shape-rendering": ShapeRendering(ShapeRendering),
   "text-rendering": TextRendering(TextRendering),
   "image-rendering": ImageRendering(ImageRendering),
+  "vector-effect": VectorEffect(VectorEffect),
+  "dominant-baseline": DominantBaseline(DominantBaseline),
+
+  // https://www.w3.org/TR/SVG2/geometry.html
+  "d": D(PathData),
+  "cx": Cx(LengthPercentage),
+  "cy": Cy(LengthPercentage),
+  "r": R(LengthPercentage),
+  "rx": Rx(LengthPercentageOrAuto),
+  "ry": Ry(LengthPercentageOrAuto),
+  "x": X(LengthPercentage),
+  "y": Y(LengthPercentage),
 
   // https://www.w3.org/TR/css-masking-1/
   "clip-path": ClipPath(ClipPath<'i>, VendorPrefix) / WebKit,
@@ -1626,19 +1750,204 @@ define_properties! {
   "filter": Filter(FilterList<'i>, VendorPrefix) / WebKit,
   "backdrop-filter": BackdropFilter(FilterList<'i>, VendorPrefix) / WebKit,
 
+  // https://drafts.csswg.org/css-viewport/
+  "zoom": Zoom(Zoom),
+
   // https://drafts.csswg.org/css2/
   "z-index": ZIndex(position::ZIndex),
 
+  // https://drafts.csswg.org/css-position-4/
+  "overlay": Overlay(position::Overlay),
+
   // https://drafts.csswg.org/css-contain-3/
   "container-type": ContainerType(ContainerType),
   "container-name": ContainerName(ContainerNameList<'i>),
   "container": Container(Container<'i>) shorthand: true,
 
-  // https://w3c.github.io/csswg-drafts/css-view-transitions-1/
-  "view-transition-name": ViewTransitionName(CustomIdent<'i>),
+  // https://drafts.csswg.org/css-view-transitions-2/
+  "view-transition-name": ViewTransitionName(ViewTransitionName<'i>),
+  "view-transition-class": ViewTransitionClass(ViewTransitionClass<'i>),
 
   // https://drafts.csswg.org/css-color-adjust/
   "color-scheme": ColorScheme(ColorScheme),
+
+  // https://w3c.github.io/mathml-core/#new-css-properties
+  "math-depth": MathDepth(MathDepth),
+  "math-style": MathStyle(MathStyle),
+  "math-shift": MathShift(MathShift),
+}
+
+impl<'i> Property<'i> {
+  /// Returns whether the property value is compatible with all of the given browsers,
+  /// or `None` if there is no compatibility data for the property.
+  ///
+  /// This combines the compatibility data for the property itself, if any, with the
+  /// compatibility data for its value.
+  pub fn is_compatible(&self, browsers: Browsers) -> Option<bool> {
+    let feature = self.compat_feature().map(|feature| feature.is_compatible(browsers));
+    let value = self.is_value_compatible(browsers);
+    match (feature, value) {
+      (Some(feature), Some(value)) => Some(feature && value),
+      (feature, value) => feature.or(value),
+    }
+  }
+
+  /// Returns the compatibility feature for the property itself, if any.
+  fn compat_feature(&self) -> Option<crate::compat::Feature> {
+    use crate::compat::Feature;
+    use Property::*;
+
+    let feature = match self {
+      BorderBlockStartColor(_)
+      | BorderBlockEndColor(_)
+      | BorderInlineStartColor(_)
+      | BorderInlineEndColor(_)
+      | BorderBlockStartStyle(_)
+      | BorderBlockEndStyle(_)
+      | BorderInlineStartStyle(_)
+      | BorderInlineEndStyle(_)
+      | BorderBlockStartWidth(_)
+      | BorderBlockEndWidth(_)
+      | BorderInlineStartWidth(_)
+      | BorderInlineEndWidth(_)
+      | BorderBlockStart(_)
+      | BorderBlockEnd(_)
+      | BorderInlineStart(_)
+      | BorderInlineEnd(_) => Feature::LogicalBorders,
+      BorderBlockColor(_) | BorderBlockStyle(_) | BorderBlockWidth(_) | BorderInlineColor(_)
+      | BorderInlineStyle(_) | BorderInlineWidth(_) | BorderBlock(_) | BorderInline(_) => {
+        Feature::LogicalBorderShorthand
+      }
+      BorderStartStartRadius(_) | BorderStartEndRadius(_) | BorderEndStartRadius(_) | BorderEndEndRadius(_) => {
+        Feature::LogicalBorderRadius
+      }
+      BlockSize(_) | InlineSize(_) | MinBlockSize(_) | MinInlineSize(_) | MaxBlockSize(_) | MaxInlineSize(_) => {
+        Feature::LogicalSize
+      }
+      InsetBlockStart(_) | InsetBlockEnd(_) | InsetInlineStart(_) | InsetInlineEnd(_) | InsetBlock(_)
+      | InsetInline(_) => Feature::LogicalInset,
+      MarginBlockStart(_) | MarginBlockEnd(_) | MarginInlineStart(_) | MarginInlineEnd(_) => {
+        Feature::LogicalMargin
+      }
+      MarginBlock(_) | MarginInline(_) => Feature::LogicalMarginShorthand,
+      PaddingBlockStart(_) | PaddingBlockEnd(_) | PaddingInlineStart(_) | PaddingInlineEnd(_) => {
+        Feature::LogicalPadding
+      }
+      PaddingBlock(_) | PaddingInline(_) => Feature::LogicalPaddingShorthand,
+      PlaceContent(_) => Feature::PlaceContent,
+      PlaceItems(_) => Feature::PlaceItems,
+      PlaceSelf(_) => Feature::PlaceSelf,
+      Overflow(overflow) if overflow.x != overflow.y => Feature::OverflowShorthand,
+      OverscrollBehavior(_) => Feature::OverscrollBehaviorShorthand,
+      Translate(_) | Rotate(_) | Scale(_) => Feature::IndividualTransforms,
+      TransitionBehavior(_) => Feature::TransitionBehavior,
+      ReadingFlow(_) => Feature::ReadingFlow,
+      ReadingOrder(_) => Feature::ReadingOrder,
+      TextSpacingTrim(_) => Feature::TextSpacingTrim,
+      TextAutospace(_) => Feature::TextAutospace,
+      ScrollbarWidth(_) => Feature::ScrollbarWidth,
+      ScrollbarColor(_) => Feature::ScrollbarColor,
+      FontSynthesisWeight(_) => Feature::FontSynthesisWeight,
+      FontSynthesisStyle(_) => Feature::FontSynthesisStyle,
+      FontSynthesisSmallCaps(_) => Feature::FontSynthesisSmallCaps,
+      FontSynthesisPosition(_) => Feature::FontSynthesisPosition,
+      Zoom(_) => Feature::Zoom,
+      LineClamp(_, prefix) if *prefix == VendorPrefix::None => Feature::LineClamp,
+      WebKitTapHighlightColor(..) => Feature::WebkitTapHighlightColor,
+      WebKitTouchCallout(..) => Feature::WebkitTouchCallout,
+      WebKitOverflowScrolling(..) => Feature::WebkitOverflowScrolling,
+      _ => return None,
+    };
+
+    Some(feature)
+  }
+
+  /// Returns whether the value of the property is compatible with all of the given browsers,
+  /// or `None` if there is no compatibility data for the value.
+  fn is_value_compatible(&self, browsers: Browsers) -> Option<bool> {
+    use Property::*;
+
+    let res = match self {
+      BackgroundColor(v)
+      | Color(v)
+      | BorderTopColor(v)
+      | BorderBottomColor(v)
+      | BorderLeftColor(v)
+      | BorderRightColor(v)
+      | BorderBlockStartColor(v)
+      | BorderBlockEndColor(v)
+      | BorderInlineStartColor(v)
+      | BorderInlineEndColor(v)
+      | OutlineColor(v)
+      | TextDecorationColor(v, _)
+      | TextEmphasisColor(v, _)
+      | WebKitTapHighlightColor(v, _) => v.is_compatible(browsers),
+      BackgroundImage(v) => v.is_compatible(browsers),
+      BackgroundSize(v) => v.is_compatible(browsers),
+      BackgroundRepeat(v) => v.is_compatible(browsers),
+      BoxShadow(v, _) => v.is_compatible(browsers),
+      TextShadow(v) => v.is_compatible(browsers),
+      Width(v) | Height(v) | MinWidth(v) | MinHeight(v) => v.is_compatible(browsers),
+      BlockSize(v) | InlineSize(v) | MinBlockSize(v) | MinInlineSize(v) => v.is_compatible(browsers),
+      MaxWidth(v) | MaxHeight(v) | MaxBlockSize(v) | MaxInlineSize(v) => v.is_compatible(browsers),
+      Top(v) | Bottom(v) | Left(v) | Right(v) => v.is_compatible(browsers),
+      InsetBlockStart(v) | InsetBlockEnd(v) | InsetInlineStart(v) | InsetInlineEnd(v) => v.is_compatible(browsers),
+      MarginTop(v) | MarginBottom(v) | MarginLeft(v) | MarginRight(v) => v.is_compatible(browsers),
+      MarginBlockStart(v) | MarginBlockEnd(v) | MarginInlineStart(v) | MarginInlineEnd(v) => {
+        v.is_compatible(browsers)
+      }
+      PaddingTop(v) | PaddingBottom(v) | PaddingLeft(v) | PaddingRight(v) => v.is_compatible(browsers),
+      PaddingBlockStart(v) | PaddingBlockEnd(v) | PaddingInlineStart(v) | PaddingInlineEnd(v) => {
+        v.is_compatible(browsers)
+      }
+      FlexBasis(v, _) => v.is_compatible(browsers),
+      BorderSpacing(v) => v.is_compatible(browsers),
+      BorderTopStyle(v) | BorderBottomStyle(v) | BorderLeftStyle(v) | BorderRightStyle(v) => {
+        v.is_compatible(browsers)
+      }
+      BorderTopWidth(v) | BorderBottomWidth(v) | BorderLeftWidth(v) | BorderRightWidth(v) | OutlineWidth(v) => {
+        v.is_compatible(browsers)
+      }
+      BorderTopLeftRadius(v, _)
+      | BorderTopRightRadius(v, _)
+      | BorderBottomLeftRadius(v, _)
+      | BorderBottomRightRadius(v, _) => v.is_compatible(browsers),
+      BorderStartStartRadius(v) | BorderStartEndRadius(v) | BorderEndStartRadius(v) | BorderEndEndRadius(v) => {
+        v.is_compatible(browsers)
+      }
+      BorderImageSource(v) | ListStyleImage(v) => v.is_compatible(browsers),
+      BorderImageOutset(v) => v.is_compatible(browsers),
+      BorderImageRepeat(v) => v.is_compatible(browsers),
+      BorderImageWidth(v) => v.is_compatible(browsers),
+      BorderImageSlice(v) => v.is_compatible(browsers),
+      OutlineStyle(v) => v.is_compatible(browsers),
+      FontWeight(v) => v.is_compatible(browsers),
+      FontSize(v) => v.is_compatible(browsers),
+      FontStretch(v) => v.is_compatible(browsers),
+      FontFamily(v) => v.is_compatible(browsers),
+      FontStyle(v) => v.is_compatible(browsers),
+      FontVariantCaps(v) => v.is_compatible(browsers),
+      LineHeight(v) => v.is_compatible(browsers),
+      TransformOrigin(v, _) | PerspectiveOrigin(v, _) => v.is_compatible(browsers),
+      TabSize(v, _) => v.is_compatible(browsers),
+      CaretColor(v) | AccentColor(v) => v.is_compatible(browsers),
+      Caret(v) => v.is_compatible(browsers),
+      ListStyleType(v) => v.is_compatible(browsers),
+      ListStylePosition(v) => v.is_compatible(browsers),
+      Fill(v) | Stroke(v) => v.is_compatible(browsers),
+      StrokeWidth(v) | StrokeDashoffset(v) => v.is_compatible(browsers),
+      Filter(v, _) | BackdropFilter(v, _) => v.is_compatible(browsers),
+      ContainerType(v) => v.is_compatible(browsers),
+      ContainerName(v) => v.is_compatible(browsers),
+      ReadingFlow(v) => v.is_compatible(browsers),
+      TextSpacingTrim(v) => v.is_compatible(browsers),
+      TextAutospace(v) => v.is_compatible(browsers),
+      ScrollbarColor(v) => v.is_compatible(browsers),
+      _ => return None,
+    };
+
+    Some(res)
+  }
 }
 
 impl<'i, T: smallvec::Array<Item = V>, V: Parse<'i>> Parse<'i> for SmallVec<T> {
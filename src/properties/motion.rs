@@ -0,0 +1,570 @@
+//! CSS properties related to motion paths.
+
+use super::{Property, PropertyId};
+use crate::context::PropertyHandlerContext;
+use crate::declaration::{DeclarationBlock, DeclarationList};
+use crate::error::{ParserError, PrinterError};
+use crate::macros::{define_shorthand, enum_property, shorthand_handler};
+use crate::printer::Printer;
+use crate::properties::masking::GeometryBox;
+use crate::properties::svg::SVGPath;
+use crate::targets::Browsers;
+use crate::traits::{IsCompatible, Parse, PropertyHandler, Shorthand, ToCss, Zero};
+use crate::values::angle::Angle;
+use crate::values::length::LengthPercentage;
+use crate::values::{position::Position, shape::BasicShape, url::Url};
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+enum_property! {
+  /// A [`<ray-size>`](https://drafts.fxtf.org/motion-1/#typedef-ray-size) value,
+  /// which determines the length of a `ray()` path.
+  pub enum RaySize {
+    /// The distance to the closest side of the containing block.
+    "closest-side": ClosestSide,
+    /// The distance to the closest corner of the containing block.
+    "closest-corner": ClosestCorner,
+    /// The distance to the farthest side of the containing block.
+    "farthest-side": FarthestSide,
+    /// The distance to the farthest corner of the containing block.
+    "farthest-corner": FarthestCorner,
+    /// The distance to the point where the ray intersects the containing block.
+    "sides": Sides,
+  }
+}
+
+/// A [`ray()`](https://drafts.fxtf.org/motion-1/#ray-function) function, which defines
+/// an offset path as a line segment starting at the offset position.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct Ray {
+  /// The angle of the ray, measured clockwise from the positive y-axis.
+  pub angle: Angle,
+  /// The length of the ray.
+  pub size: RaySize,
+  /// Whether the ray is shortened so that the element stays within the containing block.
+  pub contain: bool,
+  /// The starting point of the ray. If not specified, the `offset-position` is used.
+  pub position: Option<Position>,
+}
+
+impl<'i> Parse<'i> for Ray {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    input.expect_function_matching("ray")?;
+    input.parse_nested_block(|input| {
+      let mut angle = None;
+      let mut size = None;
+      let mut contain = false;
+      let mut position = None;
+
+      loop {
+        if angle.is_none() {
+          if let Ok(value) = input.try_parse(Angle::parse_with_unitless_zero) {
+            angle = Some(value);
+            continue;
+          }
+        }
+
+        if size.is_none() {
+          if let Ok(value) = input.try_parse(RaySize::parse) {
+            size = Some(value);
+            continue;
+          }
+        }
+
+        if !contain && input.try_parse(|input| input.expect_ident_matching("contain")).is_ok() {
+          contain = true;
+          continue;
+        }
+
+        if position.is_none() && input.try_parse(|input| input.expect_ident_matching("at")).is_ok() {
+          position = Some(Position::parse(input)?);
+          continue;
+        }
+
+        break;
+      }
+
+      let location = input.current_source_location();
+      let Some(angle) = angle else {
+        return Err(location.new_custom_error(ParserError::InvalidValue));
+      };
+
+      Ok(Ray {
+        angle,
+        size: size.unwrap_or(RaySize::ClosestSide),
+        contain,
+        position,
+      })
+    })
+  }
+}
+
+impl ToCss for Ray {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("ray(")?;
+    self.angle.to_css_with_unitless_zero(dest)?;
+    if self.size != RaySize::ClosestSide {
+      dest.write_char(' ')?;
+      self.size.to_css(dest)?;
+    }
+    if self.contain {
+      dest.write_str(" contain")?;
+    }
+    if let Some(position) = &self.position {
+      dest.write_str(" at ")?;
+      position.to_css(dest)?;
+    }
+    dest.write_char(')')
+  }
+}
+
+/// A shape used as an [offset path](https://drafts.fxtf.org/motion-1/#typedef-offset-path).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum OffsetPathShape {
+  /// A `ray()` function.
+  Ray(Ray),
+  /// A `path()` function.
+  Path(SVGPath),
+  /// A basic shape.
+  BasicShape(BasicShape),
+}
+
+impl<'i> Parse<'i> for OffsetPathShape {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(ray) = input.try_parse(Ray::parse) {
+      return Ok(OffsetPathShape::Ray(ray));
+    }
+
+    if let Ok(path) = input.try_parse(SVGPath::parse) {
+      return Ok(OffsetPathShape::Path(path));
+    }
+
+    Ok(OffsetPathShape::BasicShape(BasicShape::parse(input)?))
+  }
+}
+
+impl ToCss for OffsetPathShape {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      OffsetPathShape::Ray(ray) => ray.to_css(dest),
+      OffsetPathShape::Path(path) => path.to_css(dest),
+      OffsetPathShape::BasicShape(shape) => shape.to_css(dest),
+    }
+  }
+}
+
+/// A value for the [offset-path](https://drafts.fxtf.org/motion-1/#offset-path-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum OffsetPath<'i> {
+  /// The element is not positioned along a path.
+  None,
+  /// A url reference to an SVG shape element.
+  #[cfg_attr(feature = "serde", serde(borrow, with = "crate::serialization::ValueWrapper::<Url>"))]
+  Url(Url<'i>),
+  /// A shape, positioned according to the reference box.
+  #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+  Shape {
+    /// The shape of the path.
+    shape: Box<OffsetPathShape>,
+    /// A reference box that the shape is positioned according to.
+    reference_box: GeometryBox,
+  },
+  /// The path is the edge of the reference box.
+  #[cfg_attr(feature = "serde", serde(with = "crate::serialization::ValueWrapper::<GeometryBox>"))]
+  Box(GeometryBox),
+}
+
+impl<'i> Parse<'i> for OffsetPath<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(OffsetPath::None);
+    }
+
+    if let Ok(url) = input.try_parse(Url::parse) {
+      return Ok(OffsetPath::Url(url));
+    }
+
+    if let Ok(shape) = input.try_parse(OffsetPathShape::parse) {
+      let b = input.try_parse(GeometryBox::parse).unwrap_or_default();
+      return Ok(OffsetPath::Shape {
+        shape: Box::new(shape),
+        reference_box: b,
+      });
+    }
+
+    let b = GeometryBox::parse(input)?;
+    if let Ok(shape) = input.try_parse(OffsetPathShape::parse) {
+      return Ok(OffsetPath::Shape {
+        shape: Box::new(shape),
+        reference_box: b,
+      });
+    }
+    Ok(OffsetPath::Box(b))
+  }
+}
+
+impl<'i> ToCss for OffsetPath<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      OffsetPath::None => dest.write_str("none"),
+      OffsetPath::Url(url) => url.to_css(dest),
+      OffsetPath::Shape {
+        shape,
+        reference_box: b,
+      } => {
+        shape.to_css(dest)?;
+        if *b != GeometryBox::default() {
+          dest.write_char(' ')?;
+          b.to_css(dest)?;
+        }
+        Ok(())
+      }
+      OffsetPath::Box(b) => b.to_css(dest),
+    }
+  }
+}
+
+impl IsCompatible for OffsetPath<'_> {
+  fn is_compatible(&self, _browsers: Browsers) -> bool {
+    true
+  }
+}
+
+/// A value for the [offset-rotate](https://drafts.fxtf.org/motion-1/#offset-rotate-property) property.
+///
+/// The `reverse` keyword is represented as `auto` with an additional 180 degree rotation.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct OffsetRotate {
+  /// Whether the element is rotated by the direction of the offset path.
+  pub auto: bool,
+  /// A fixed rotation, added to the direction of the path if `auto` is set.
+  pub angle: Angle,
+}
+
+impl Default for OffsetRotate {
+  fn default() -> OffsetRotate {
+    OffsetRotate {
+      auto: true,
+      angle: Angle::Deg(0.0),
+    }
+  }
+}
+
+impl<'i> Parse<'i> for OffsetRotate {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut keyword = None;
+    let mut angle = None;
+
+    loop {
+      if keyword.is_none() {
+        if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+          keyword = Some(Angle::Deg(0.0));
+          continue;
+        }
+
+        if input.try_parse(|input| input.expect_ident_matching("reverse")).is_ok() {
+          keyword = Some(Angle::Deg(180.0));
+          continue;
+        }
+      }
+
+      if angle.is_none() {
+        if let Ok(value) = input.try_parse(Angle::parse_with_unitless_zero) {
+          angle = Some(value);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    match (keyword, angle) {
+      (Some(keyword), Some(angle)) => Ok(OffsetRotate {
+        auto: true,
+        angle: Angle::Deg(keyword.to_degrees() + angle.to_degrees()),
+      }),
+      (Some(keyword), None) => Ok(OffsetRotate {
+        auto: true,
+        angle: keyword,
+      }),
+      (None, Some(angle)) => Ok(OffsetRotate { auto: false, angle }),
+      (None, None) => Err(input.new_custom_error(ParserError::InvalidValue)),
+    }
+  }
+}
+
+impl ToCss for OffsetRotate {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if !self.auto {
+      return self.angle.to_css_with_unitless_zero(dest);
+    }
+
+    if self.angle.to_degrees() == 180.0 {
+      return dest.write_str("reverse");
+    }
+
+    dest.write_str("auto")?;
+    if !self.angle.is_zero() {
+      dest.write_char(' ')?;
+      self.angle.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+impl IsCompatible for OffsetRotate {
+  fn is_compatible(&self, _browsers: Browsers) -> bool {
+    true
+  }
+}
+
+/// A value for the [offset-position](https://drafts.fxtf.org/motion-1/#offset-position-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum OffsetPosition {
+  /// The element does not have an offset starting position.
+  Normal,
+  /// The offset starting position is the element's own position.
+  Auto,
+  /// An explicit offset starting position.
+  Position(Position),
+}
+
+impl<'i> Parse<'i> for OffsetPosition {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(OffsetPosition::Normal);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(OffsetPosition::Auto);
+    }
+
+    Ok(OffsetPosition::Position(Position::parse(input)?))
+  }
+}
+
+impl ToCss for OffsetPosition {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      OffsetPosition::Normal => dest.write_str("normal"),
+      OffsetPosition::Auto => dest.write_str("auto"),
+      OffsetPosition::Position(position) => position.to_css(dest),
+    }
+  }
+}
+
+impl IsCompatible for OffsetPosition {
+  fn is_compatible(&self, browsers: Browsers) -> bool {
+    match self {
+      OffsetPosition::Position(position) => position.is_compatible(browsers),
+      _ => true,
+    }
+  }
+}
+
+/// A value for the [offset-anchor](https://drafts.fxtf.org/motion-1/#offset-anchor-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum OffsetAnchor {
+  /// The anchor point is the element's transform origin, or its offset position if `offset-path` is `none`.
+  Auto,
+  /// An explicit anchor point.
+  Position(Position),
+}
+
+impl<'i> Parse<'i> for OffsetAnchor {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(OffsetAnchor::Auto);
+    }
+
+    Ok(OffsetAnchor::Position(Position::parse(input)?))
+  }
+}
+
+impl ToCss for OffsetAnchor {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      OffsetAnchor::Auto => dest.write_str("auto"),
+      OffsetAnchor::Position(position) => position.to_css(dest),
+    }
+  }
+}
+
+impl IsCompatible for OffsetAnchor {
+  fn is_compatible(&self, browsers: Browsers) -> bool {
+    match self {
+      OffsetAnchor::Position(position) => position.is_compatible(browsers),
+      OffsetAnchor::Auto => true,
+    }
+  }
+}
+
+define_shorthand! {
+  /// A value for the [offset](https://drafts.fxtf.org/motion-1/#offset-shorthand) shorthand property.
+  pub struct Offset<'i> {
+    /// The offset starting position.
+    position: OffsetPosition(OffsetPosition),
+    /// The offset path.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    path: OffsetPath(OffsetPath<'i>),
+    /// The distance along the offset path.
+    distance: OffsetDistance(LengthPercentage),
+    /// The rotation of the element along the offset path.
+    rotate: OffsetRotate(OffsetRotate),
+    /// The anchor point of the element.
+    anchor: OffsetAnchor(OffsetAnchor),
+  }
+}
+
+impl<'i> Parse<'i> for Offset<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let position = input.try_parse(OffsetPosition::parse).ok();
+    let path = input.try_parse(OffsetPath::parse).ok();
+
+    let mut distance = None;
+    let mut rotate = None;
+    if path.is_some() {
+      loop {
+        if distance.is_none() {
+          if let Ok(value) = input.try_parse(LengthPercentage::parse) {
+            distance = Some(value);
+            continue;
+          }
+        }
+
+        if rotate.is_none() {
+          if let Ok(value) = input.try_parse(OffsetRotate::parse) {
+            rotate = Some(value);
+            continue;
+          }
+        }
+
+        break;
+      }
+    }
+
+    if position.is_none() && path.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    let anchor = if input.try_parse(|input| input.expect_delim('/')).is_ok() {
+      OffsetAnchor::parse(input)?
+    } else {
+      OffsetAnchor::Auto
+    };
+
+    Ok(Offset {
+      position: position.unwrap_or(OffsetPosition::Normal),
+      path: path.unwrap_or(OffsetPath::None),
+      distance: distance.unwrap_or(LengthPercentage::zero()),
+      rotate: rotate.unwrap_or_default(),
+      anchor,
+    })
+  }
+}
+
+impl<'i> ToCss for Offset<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let has_position = self.position != OffsetPosition::Normal;
+    let has_distance = !self.distance.is_zero();
+    let has_rotate = self.rotate != OffsetRotate::default();
+
+    if has_position {
+      self.position.to_css(dest)?;
+    }
+
+    if !has_position || self.path != OffsetPath::None || has_distance || has_rotate {
+      if has_position {
+        dest.write_char(' ')?;
+      }
+      self.path.to_css(dest)?;
+
+      if has_distance {
+        dest.write_char(' ')?;
+        self.distance.to_css(dest)?;
+      }
+
+      if has_rotate {
+        dest.write_char(' ')?;
+        self.rotate.to_css(dest)?;
+      }
+    }
+
+    if self.anchor != OffsetAnchor::Auto {
+      dest.delim('/', true)?;
+      self.anchor.to_css(dest)?;
+    }
+
+    Ok(())
+  }
+}
+
+shorthand_handler!(OffsetHandler -> Offset<'i> {
+  position: OffsetPosition(OffsetPosition),
+  path: OffsetPath(OffsetPath<'i>),
+  distance: OffsetDistance(LengthPercentage),
+  rotate: OffsetRotate(OffsetRotate),
+  anchor: OffsetAnchor(OffsetAnchor),
+});
@@ -1,5 +1,7 @@
 //! CSS properties related to overflow.
 
+use super::display::{Display, DisplayInside, DisplayOutside, DisplayPair};
+use super::flex::BoxOrient;
 use super::{Property, PropertyId};
 use crate::compat::Feature;
 use crate::context::PropertyHandlerContext;
@@ -7,7 +9,12 @@ use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
 use crate::macros::{define_shorthand, enum_property};
 use crate::printer::Printer;
-use crate::traits::{Parse, PropertyHandler, Shorthand, ToCss};
+use crate::targets::should_compile;
+use crate::traits::{Parse, PropertyHandler, Shorthand, ToCss, TrySign, Zero};
+use crate::values::length::Length;
+use crate::values::number::CSSInteger;
+use crate::values::string::CSSString;
+use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use cssparser::*;
@@ -71,6 +78,51 @@ enum_property! {
   }
 }
 
+enum_property! {
+  /// An [overscroll-behavior](https://drafts.csswg.org/css-overscroll-1/#overscroll-behavior-properties) keyword
+  /// as used in the `overscroll-behavior-x`, `overscroll-behavior-y`, and `overscroll-behavior` properties.
+  pub enum OverscrollBehaviorKeyword {
+    /// Scroll chaining and boundary default actions are allowed.
+    Auto,
+    /// Scroll chaining to ancestor scroll containers is prevented.
+    Contain,
+    /// Scroll chaining and overscroll affordances are prevented.
+    None,
+  }
+}
+
+define_shorthand! {
+  /// A value for the [overscroll-behavior](https://drafts.csswg.org/css-overscroll-1/#overscroll-behavior-properties) shorthand property.
+  pub struct OverscrollBehavior {
+    /// The overscroll behavior for the x direction.
+    x: OverscrollBehaviorX(OverscrollBehaviorKeyword),
+    /// The overscroll behavior for the y direction.
+    y: OverscrollBehaviorY(OverscrollBehaviorKeyword),
+  }
+}
+
+impl<'i> Parse<'i> for OverscrollBehavior {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let x = OverscrollBehaviorKeyword::parse(input)?;
+    let y = input.try_parse(OverscrollBehaviorKeyword::parse).unwrap_or(x);
+    Ok(OverscrollBehavior { x, y })
+  }
+}
+
+impl ToCss for OverscrollBehavior {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.x.to_css(dest)?;
+    if self.y != self.x {
+      dest.write_char(' ')?;
+      self.y.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
 #[derive(Default)]
 pub(crate) struct OverflowHandler {
   x: Option<OverflowKeyword>,
@@ -134,3 +186,387 @@ impl<'i> PropertyHandler<'i> for OverflowHandler {
     }
   }
 }
+
+#[derive(Default)]
+pub(crate) struct OverscrollBehaviorHandler {
+  x: Option<OverscrollBehaviorKeyword>,
+  y: Option<OverscrollBehaviorKeyword>,
+}
+
+impl<'i> PropertyHandler<'i> for OverscrollBehaviorHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    use Property::*;
+
+    match property {
+      OverscrollBehaviorX(val) => self.x = Some(*val),
+      OverscrollBehaviorY(val) => self.y = Some(*val),
+      OverscrollBehavior(val) => {
+        self.x = Some(val.x);
+        self.y = Some(val.y);
+      }
+      Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::OverscrollBehaviorX | PropertyId::OverscrollBehaviorY | PropertyId::OverscrollBehavior
+        ) =>
+      {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList, context: &mut PropertyHandlerContext<'i, '_>) {
+    if self.x.is_none() && self.y.is_none() {
+      return;
+    }
+
+    let x = std::mem::take(&mut self.x);
+    let y = std::mem::take(&mut self.y);
+
+    match (x, y) {
+      // Only use the shorthand if it is supported by all targets.
+      // Otherwise, fall back to the longhands, which have wider support.
+      (Some(x), Some(y)) if context.targets.is_compatible(Feature::OverscrollBehaviorShorthand) => {
+        dest.push(Property::OverscrollBehavior(OverscrollBehavior { x, y }))
+      }
+      _ => {
+        if let Some(x) = x {
+          dest.push(Property::OverscrollBehaviorX(x))
+        }
+
+        if let Some(y) = y {
+          dest.push(Property::OverscrollBehaviorY(y))
+        }
+      }
+    }
+  }
+}
+
+/// A value for the [block-ellipsis](https://drafts.csswg.org/css-overflow-4/#block-ellipsis) component
+/// of the `line-clamp` property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum BlockEllipsis<'i> {
+  /// An ellipsis character is inserted.
+  Auto,
+  /// No ellipsis is inserted.
+  NoEllipsis,
+  /// The given string is inserted.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  String(CSSString<'i>),
+}
+
+impl<'i> Parse<'i> for BlockEllipsis<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(s) = input.try_parse(CSSString::parse) {
+      return Ok(BlockEllipsis::String(s));
+    }
+
+    let location = input.current_source_location();
+    let ident = input.expect_ident()?;
+    match_ignore_ascii_case! { ident,
+      "auto" => Ok(BlockEllipsis::Auto),
+      "no-ellipsis" => Ok(BlockEllipsis::NoEllipsis),
+      _ => Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
+    }
+  }
+}
+
+impl ToCss for BlockEllipsis<'_> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      BlockEllipsis::Auto => dest.write_str("auto"),
+      BlockEllipsis::NoEllipsis => dest.write_str("no-ellipsis"),
+      BlockEllipsis::String(s) => s.to_css(dest),
+    }
+  }
+}
+
+/// A value for the [line-clamp](https://drafts.csswg.org/css-overflow-4/#line-clamp) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum LineClamp<'i> {
+  /// Content is not clamped.
+  None,
+  /// Content is clamped after the given number of lines.
+  #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+  Lines {
+    /// The maximum number of lines.
+    lines: CSSInteger,
+    /// The ellipsis inserted at the end of the last line.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    block_ellipsis: BlockEllipsis<'i>,
+  },
+}
+
+impl<'i> Parse<'i> for LineClamp<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(LineClamp::None);
+    }
+
+    let mut lines = None;
+    let mut block_ellipsis = None;
+    loop {
+      if lines.is_none() {
+        let location = input.current_source_location();
+        if let Ok(value) = input.try_parse(CSSInteger::parse) {
+          if value < 1 {
+            return Err(location.new_custom_error(ParserError::InvalidValue));
+          }
+          lines = Some(value);
+          continue;
+        }
+      }
+
+      if block_ellipsis.is_none() {
+        if let Ok(value) = input.try_parse(BlockEllipsis::parse) {
+          block_ellipsis = Some(value);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    match lines {
+      Some(lines) => Ok(LineClamp::Lines {
+        lines,
+        block_ellipsis: block_ellipsis.unwrap_or(BlockEllipsis::Auto),
+      }),
+      None => Err(input.new_custom_error(ParserError::InvalidDeclaration)),
+    }
+  }
+}
+
+impl ToCss for LineClamp<'_> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      LineClamp::None => dest.write_str("none"),
+      LineClamp::Lines { lines, block_ellipsis } => {
+        lines.to_css(dest)?;
+        if *block_ellipsis != BlockEllipsis::Auto {
+          dest.write_char(' ')?;
+          block_ellipsis.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+enum_property! {
+  /// A value for the [continue](https://drafts.csswg.org/css-overflow-4/#continue) property.
+  pub enum Continue {
+    /// The box continues normally.
+    Auto,
+    /// Content after the region break is discarded.
+    Discard,
+    /// Content after the region break is collapsed.
+    Collapse,
+  }
+}
+
+enum_property! {
+  /// A [`<visual-box>`](https://drafts.csswg.org/css-box-4/#typedef-visual-box) value,
+  /// as used in the `overflow-clip-margin` property.
+  #[derive(Default)]
+  pub enum VisualBox {
+    /// The content box.
+    "content-box": ContentBox,
+    /// The padding box.
+    #[default]
+    "padding-box": PaddingBox,
+    /// The border box.
+    "border-box": BorderBox,
+  }
+}
+
+/// A value for the [overflow-clip-margin](https://drafts.csswg.org/css-overflow-4/#overflow-clip-margin) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct OverflowClipMargin {
+  /// The box edge from which the overflow clip edge is drawn.
+  pub visual_box: VisualBox,
+  /// How far the overflow clip edge is extended outward from the box edge.
+  pub length: Length,
+}
+
+impl<'i> Parse<'i> for OverflowClipMargin {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut visual_box = None;
+    let mut length = None;
+    loop {
+      if visual_box.is_none() {
+        if let Ok(value) = input.try_parse(VisualBox::parse) {
+          visual_box = Some(value);
+          continue;
+        }
+      }
+
+      if length.is_none() {
+        let location = input.current_source_location();
+        if let Ok(value) = input.try_parse(Length::parse) {
+          if value.is_sign_negative() {
+            return Err(location.new_custom_error(ParserError::InvalidValue));
+          }
+          length = Some(value);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if visual_box.is_none() && length.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(OverflowClipMargin {
+      visual_box: visual_box.unwrap_or_default(),
+      length: length.unwrap_or_else(Length::zero),
+    })
+  }
+}
+
+impl ToCss for OverflowClipMargin {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.visual_box != VisualBox::PaddingBox {
+      self.visual_box.to_css(dest)?;
+      if self.length.is_zero() {
+        return Ok(());
+      }
+      dest.write_char(' ')?;
+    }
+
+    self.length.to_css(dest)
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct LineClampHandler<'i> {
+  line_clamp: Option<(LineClamp<'i>, VendorPrefix)>,
+}
+
+impl<'i> PropertyHandler<'i> for LineClampHandler<'i> {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::LineClamp(val, vp) => {
+        // If two vendor prefixes have different values, we need to flush what we have immediately to preserve order.
+        if let Some((cur, prefixes)) = &self.line_clamp {
+          if cur != val && !prefixes.contains(*vp) {
+            self.finalize(dest, context);
+          }
+        }
+
+        if let Some((cur, prefixes)) = &mut self.line_clamp {
+          *cur = val.clone();
+          *prefixes |= *vp;
+        } else {
+          self.line_clamp = Some((val.clone(), *vp));
+        }
+      }
+      Property::Unparsed(val) if matches!(val.property_id, PropertyId::LineClamp(_)) => {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    let (val, mut prefix) = match std::mem::take(&mut self.line_clamp) {
+      Some(line_clamp) => line_clamp,
+      None => return,
+    };
+
+    if prefix.contains(VendorPrefix::None) && should_compile!(context.targets, LineClamp) {
+      // The legacy -webkit-line-clamp property only works in combination with
+      // `display: -webkit-box` and `-webkit-box-orient: vertical`.
+      match &val {
+        LineClamp::Lines {
+          block_ellipsis: BlockEllipsis::Auto,
+          ..
+        } => {
+          dest.push(Property::Display(Display::Pair(DisplayPair {
+            outside: DisplayOutside::Block,
+            inside: DisplayInside::Box(VendorPrefix::WebKit),
+            is_list_item: false,
+          })));
+          dest.push(Property::BoxOrient(BoxOrient::Vertical, VendorPrefix::WebKit));
+          prefix |= VendorPrefix::WebKit;
+        }
+        LineClamp::None => prefix |= VendorPrefix::WebKit,
+        _ => {}
+      }
+    } else if prefix.contains(VendorPrefix::WebKit)
+      && context.targets.browsers.is_some()
+      && !should_compile!(context.targets, LineClamp)
+    {
+      // If all targets support the standard property, the legacy -webkit-box
+      // declarations used alongside -webkit-line-clamp are no longer needed.
+      let is_box = |property: &Property| {
+        matches!(
+          property,
+          Property::Display(Display::Pair(DisplayPair {
+            inside: DisplayInside::Box(VendorPrefix::WebKit),
+            ..
+          }))
+        )
+      };
+      let is_orient = |property: &Property| matches!(property, Property::BoxOrient(BoxOrient::Vertical, prefix) if prefix.contains(VendorPrefix::WebKit));
+      if dest.iter().any(is_box) && dest.iter().any(is_orient) {
+        dest.retain(|property| !is_box(property) && !is_orient(property));
+        prefix = VendorPrefix::None;
+      }
+    }
+
+    dest.push(Property::LineClamp(val, prefix));
+  }
+}
@@ -4,6 +4,7 @@ use super::Property;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::DeclarationList;
 use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
 use crate::prefixes::Feature;
 use crate::printer::Printer;
 use crate::traits::{Parse, PropertyHandler, ToCss};
@@ -91,7 +92,7 @@ pub enum ZIndex {
 
 impl<'i> Parse<'i> for ZIndex {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
-    if let Ok(value) = input.expect_integer() {
+    if let Ok(value) = input.try_parse(CSSInteger::parse) {
       return Ok(ZIndex::Integer(value));
     }
 
@@ -112,6 +113,18 @@ impl ToCss for ZIndex {
   }
 }
 
+enum_property! {
+  /// A value for the [overlay](https://drafts.csswg.org/css-position-4/#overlay) property.
+  ///
+  /// This is set by the user agent for elements in the top layer, and can only be transitioned by authors.
+  pub enum Overlay {
+    /// The element is not rendered in the top layer.
+    None,
+    /// The element is rendered in the top layer if it is in the top layer.
+    Auto,
+  }
+}
+
 #[derive(Default)]
 pub(crate) struct PositionHandler {
   position: Option<Position>,
@@ -15,10 +15,11 @@ macro_rules! define_prefixes {
       $(
         $name: Option<usize>,
       )+
+      prefixed: Vec<(usize, VendorPrefix)>,
     }
 
     impl<'i> PropertyHandler<'i> for PrefixHandler {
-      fn handle_property(&mut self, property: &Property<'i>, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext) -> bool {
+      fn handle_property(&mut self, property: &Property<'i>, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) -> bool {
         match property {
           $(
             Property::$name(val, prefix) => {
@@ -31,6 +32,9 @@ macro_rules! define_prefixes {
                       *cur = val.clone();
                       *prefixes |= *prefix;
                       *prefixes = context.targets.prefixes(*prefixes, Feature::$name);
+                      if let Some((_, authored)) = self.prefixed.iter_mut().find(|(index, _)| *index == i) {
+                        *authored |= *prefix;
+                      }
                       return true
                     }
                   }
@@ -42,6 +46,9 @@ macro_rules! define_prefixes {
 
               // Store the index of the property, so we can update it later.
               self.$name = Some(dest.len());
+              if context.fallback_control.is_some() {
+                self.prefixed.push((dest.len(), *prefix));
+              }
               dest.push(Property::$name(val.clone(), prefixes))
             }
           )+
@@ -51,7 +58,17 @@ macro_rules! define_prefixes {
         true
       }
 
-      fn finalize(&mut self, _: &mut DeclarationList, _: &mut PropertyHandlerContext) {}
+      fn finalize(&mut self, _: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {
+        $(
+          self.$name = None;
+        )+
+      }
+    }
+
+    impl PrefixHandler {
+      pub(crate) fn take_prefixed(&mut self) -> Vec<(usize, VendorPrefix)> {
+        std::mem::take(&mut self.prefixed)
+      }
     }
   };
 }
@@ -73,6 +90,7 @@ define_prefixes! {
   ClipPath,
   BoxDecorationBreak,
   TextSizeAdjust,
+  InitialLetter,
 }
 
 macro_rules! define_fallbacks {
@@ -83,8 +101,9 @@ macro_rules! define_fallbacks {
       #[derive(Default)]
       pub(crate) struct FallbackHandler {
         $(
-          [<$name:snake>]: Option<usize>
-        ),+
+          [<$name:snake>]: Option<usize>,
+        )+
+        prefixed: Vec<(usize, VendorPrefix)>,
       }
     }
 
@@ -94,16 +113,28 @@ macro_rules! define_fallbacks {
           $(
             Property::$name(val $(, mut $p)?) => {
               let mut val = val.clone();
+              macro_rules! authored {
+                ($vp: ident) => {
+                  $vp
+                };
+                () => {
+                  VendorPrefix::None
+                };
+              }
+
+              let authored = authored!($($p)?);
               $(
                 $p = context.targets.prefixes($p, Feature::$name);
               )?
               if paste::paste! { self.[<$name:snake>] }.is_none() {
-                let fallbacks = val.get_fallbacks(context.targets);
+                let fallbacks = val.get_fallbacks(context.targets, context.gamut_mapping);
+                let fallbacks = context.fallbacks(
+                  || Property::$name(val.clone() $(, $p)?),
+                  fallbacks.into_iter().map(|fallback| Property::$name(fallback $(, $p)?)).collect()
+                );
                 #[allow(unused_variables)]
                 let has_fallbacks = !fallbacks.is_empty();
-                for fallback in fallbacks {
-                  dest.push(Property::$name(fallback $(, $p)?))
-                }
+                dest.extend(fallbacks);
 
                 $(
                   if has_fallbacks && $p.contains(VendorPrefix::None) {
@@ -114,8 +145,10 @@ macro_rules! define_fallbacks {
 
               if paste::paste! { self.[<$name:snake>] }.is_none() || matches!(context.targets.browsers, Some(targets) if !val.is_compatible(targets)) {
                 paste::paste! { self.[<$name:snake>] = Some(dest.len()) };
+                self.track_prefixes(dest.len(), authored, context);
                 dest.push(Property::$name(val $(, $p)?));
               } else if let Some(index) = paste::paste! { self.[<$name:snake>] } {
+                self.track_prefixes(index, authored, context);
                 dest[index] = Property::$name(val $(, $p)?);
               }
             }
@@ -146,12 +179,16 @@ macro_rules! define_fallbacks {
 
             // Unparsed properties are always "valid", meaning they always override previous declarations.
             context.add_unparsed_fallbacks(&mut unparsed);
-            if let Some(index) = *index {
+            let authored = val.property_id.prefix().or_none();
+            let index = if let Some(index) = *index {
               dest[index] = Property::Unparsed(unparsed);
+              index
             } else {
               *index = Some(dest.len());
               dest.push(Property::Unparsed(unparsed));
-            }
+              dest.len() - 1
+            };
+            self.track_prefixes(index, authored, context);
           }
           _ => return false
         }
@@ -159,12 +196,32 @@ macro_rules! define_fallbacks {
         true
       }
 
-      fn finalize(&mut self, _: &mut DeclarationList, _: &mut PropertyHandlerContext) {
+      fn finalize(&mut self, _: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {
         $(
           paste::paste! { self.[<$name:snake>] = None };
         )+
       }
     }
+
+    impl FallbackHandler {
+      /// Records the authored vendor prefixes of a declaration, so the prefixes added by the targets
+      /// can be split into separate declarations in finalize when a fallback control hook is present.
+      fn track_prefixes(&mut self, index: usize, authored: VendorPrefix, context: &PropertyHandlerContext) {
+        if context.fallback_control.is_none() {
+          return;
+        }
+
+        if let Some(entry) = self.prefixed.iter_mut().find(|(i, _)| *i == index) {
+          entry.1 = authored;
+        } else {
+          self.prefixed.push((index, authored));
+        }
+      }
+
+      pub(crate) fn take_prefixed(&mut self) -> Vec<(usize, VendorPrefix)> {
+        std::mem::take(&mut self.prefixed)
+      }
+    }
   };
 }
 
@@ -175,6 +232,40 @@ define_fallbacks! {
   BackdropFilter(prefix),
   Fill,
   Stroke,
-  CaretColor,
-  Caret,
+}
+
+/// Splits the vendor prefixes added by the targets into separate declarations, so they can be
+/// suppressed or replaced by the fallback control hook. This runs once all declarations have been
+/// merged, given the indices of the declarations and the prefixes they were authored with.
+pub(crate) fn split_prefixed<'i>(
+  dest: &mut DeclarationList<'i>,
+  mut prefixed: Vec<(usize, VendorPrefix)>,
+  context: &PropertyHandlerContext<'i, '_>,
+) {
+  // Insert from the end so the remaining indices stay valid.
+  prefixed.sort_by(|a, b| b.0.cmp(&a.0));
+  for (index, authored) in prefixed {
+    let decl = &dest[index];
+    let expanded = decl.property_id().prefix().or_none();
+    if (expanded - authored).is_empty() {
+      continue;
+    }
+
+    let with_prefix = |prefix: VendorPrefix| match decl {
+      Property::Unparsed(unparsed) => {
+        Property::Unparsed(unparsed.with_property_id(unparsed.property_id.with_prefix(prefix)))
+      }
+      decl => {
+        let mut decl = decl.clone();
+        decl.set_prefix(prefix);
+        decl
+      }
+    };
+
+    let (prefix, mut decls) = context.split_prefixes(authored, expanded, with_prefix);
+    if !prefix.is_empty() {
+      decls.push(with_prefix(prefix));
+    }
+    dest.splice(index..=index, decls);
+  }
 }
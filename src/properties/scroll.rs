@@ -0,0 +1,174 @@
+//! CSS properties related to scrolling.
+
+use super::{Property, PropertyId};
+use crate::declaration::DeclarationBlock;
+use crate::error::{ParserError, PrinterError};
+use crate::macros::{define_shorthand, enum_property};
+use crate::printer::Printer;
+use crate::traits::{Parse, Shorthand, ToCss, Zero};
+use crate::values::length::LengthPercentage;
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+enum_property! {
+  /// A keyword for the [scroll-start](https://drafts.csswg.org/css-scroll-snap-2/#scroll-start) properties.
+  pub enum ScrollStartKeyword {
+    /// The initial scroll position is determined by the user agent.
+    Auto,
+    /// Scrolls to the start edge of the scroll container.
+    Start,
+    /// Scrolls to the end edge of the scroll container.
+    End,
+    /// Scrolls to the center of the scroll container.
+    Center,
+    /// Scrolls to the left edge of the scroll container.
+    Left,
+    /// Scrolls to the right edge of the scroll container.
+    Right,
+    /// Scrolls to the top edge of the scroll container.
+    Top,
+    /// Scrolls to the bottom edge of the scroll container.
+    Bottom,
+  }
+}
+
+/// A value for the [scroll-start](https://drafts.csswg.org/css-scroll-snap-2/#scroll-start) longhand properties,
+/// i.e. `scroll-start-block`, `scroll-start-inline`, `scroll-start-x`, and `scroll-start-y`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum ScrollStartValue {
+  /// A scroll position keyword.
+  Keyword(ScrollStartKeyword),
+  /// An offset from the start edge of the scroll container.
+  LengthPercentage(LengthPercentage),
+}
+
+impl Default for ScrollStartValue {
+  fn default() -> ScrollStartValue {
+    ScrollStartValue::Keyword(ScrollStartKeyword::Start)
+  }
+}
+
+impl<'i> Parse<'i> for ScrollStartValue {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(keyword) = input.try_parse(ScrollStartKeyword::parse) {
+      return Ok(ScrollStartValue::Keyword(keyword));
+    }
+
+    let location = input.current_source_location();
+    let value = LengthPercentage::parse(input)?;
+    if matches!(
+      value.partial_cmp(&LengthPercentage::zero()),
+      Some(std::cmp::Ordering::Less)
+    ) {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(ScrollStartValue::LengthPercentage(value))
+  }
+}
+
+impl ToCss for ScrollStartValue {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ScrollStartValue::Keyword(keyword) => keyword.to_css(dest),
+      ScrollStartValue::LengthPercentage(value) => value.to_css(dest),
+    }
+  }
+}
+
+define_shorthand! {
+  /// A value for the [scroll-start](https://drafts.csswg.org/css-scroll-snap-2/#scroll-start) shorthand property.
+  pub struct ScrollStart {
+    /// The initial scroll position in the block axis.
+    block: ScrollStartBlock(ScrollStartValue),
+    /// The initial scroll position in the inline axis.
+    inline: ScrollStartInline(ScrollStartValue),
+  }
+}
+
+impl<'i> Parse<'i> for ScrollStart {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let block = ScrollStartValue::parse(input)?;
+    let inline = input.try_parse(ScrollStartValue::parse).unwrap_or_default();
+    Ok(ScrollStart { block, inline })
+  }
+}
+
+impl ToCss for ScrollStart {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.block.to_css(dest)?;
+    if self.inline != ScrollStartValue::default() {
+      dest.write_char(' ')?;
+      self.inline.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+enum_property! {
+  /// A value for the [scroll-start-target](https://drafts.csswg.org/css-scroll-snap-2/#scroll-start-target) longhand properties,
+  /// i.e. `scroll-start-target-block`, `scroll-start-target-inline`, `scroll-start-target-x`, and `scroll-start-target-y`.
+  pub enum ScrollStartTargetValue {
+    /// The element does not determine the initial scroll position of its scroll container.
+    None,
+    /// The element's scroll container is initially scrolled to the element.
+    Auto,
+  }
+}
+
+define_shorthand! {
+  /// A value for the [scroll-start-target](https://drafts.csswg.org/css-scroll-snap-2/#scroll-start-target) shorthand property.
+  pub struct ScrollStartTarget {
+    /// Whether the element is a scroll start target in the block axis.
+    block: ScrollStartTargetBlock(ScrollStartTargetValue),
+    /// Whether the element is a scroll start target in the inline axis.
+    inline: ScrollStartTargetInline(ScrollStartTargetValue),
+  }
+}
+
+impl<'i> Parse<'i> for ScrollStartTarget {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let block = ScrollStartTargetValue::parse(input)?;
+    let inline = input.try_parse(ScrollStartTargetValue::parse).unwrap_or(block);
+    Ok(ScrollStartTarget { block, inline })
+  }
+}
+
+impl ToCss for ScrollStartTarget {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.block.to_css(dest)?;
+    if self.inline != self.block {
+      dest.write_char(' ')?;
+      self.inline.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+enum_property! {
+  /// A value for the [scroll-initial-target](https://drafts.csswg.org/css-scroll-snap-2/#scroll-initial-target) property.
+  pub enum ScrollInitialTarget {
+    /// The element is not an initial scroll target.
+    None,
+    /// The element's nearest scroll container is initially scrolled to the element.
+    Nearest,
+  }
+}
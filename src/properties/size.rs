@@ -2,14 +2,14 @@
 
 use crate::compat::Feature;
 use crate::context::PropertyHandlerContext;
-use crate::declaration::DeclarationList;
+use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
 use crate::logical::PropertyCategory;
-use crate::macros::{enum_property, property_bitflags};
+use crate::macros::{define_shorthand, enum_property, property_bitflags};
 use crate::printer::Printer;
 use crate::properties::{Property, PropertyId};
-use crate::traits::{IsCompatible, Parse, PropertyHandler, ToCss};
-use crate::values::length::LengthPercentage;
+use crate::traits::{IsCompatible, Parse, PropertyHandler, Shorthand, ToCss, TrySign};
+use crate::values::length::{Length, LengthPercentage};
 use crate::values::ratio::Ratio;
 use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
@@ -358,6 +358,185 @@ impl ToCss for AspectRatio {
   }
 }
 
+/// A value for the [contain-intrinsic-*](https://drafts.csswg.org/css-sizing-4/#intrinsic-size-override)
+/// longhand properties, i.e. `contain-intrinsic-width` and `contain-intrinsic-height`.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct ContainIntrinsicLength {
+  /// The `auto` keyword, which uses the last remembered size of the element if any.
+  pub auto: bool,
+  /// The explicit intrinsic size, or `None` for the `none` keyword.
+  pub length: Option<Length>,
+}
+
+impl<'i> Parse<'i> for ContainIntrinsicLength {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let auto = input.try_parse(|input| input.expect_ident_matching("auto")).is_ok();
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(ContainIntrinsicLength { auto, length: None });
+    }
+
+    let location = input.current_source_location();
+    let length = Length::parse(input)?;
+    if length.is_sign_negative() {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(ContainIntrinsicLength {
+      auto,
+      length: Some(length),
+    })
+  }
+}
+
+impl ToCss for ContainIntrinsicLength {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.auto {
+      dest.write_str("auto ")?;
+    }
+
+    match &self.length {
+      Some(length) => length.to_css(dest),
+      None => dest.write_str("none"),
+    }
+  }
+}
+
+define_shorthand! {
+  /// A value for the [contain-intrinsic-size](https://drafts.csswg.org/css-sizing-4/#propdef-contain-intrinsic-size) shorthand property.
+  pub struct ContainIntrinsicSize {
+    /// The intrinsic width.
+    width: ContainIntrinsicWidth(ContainIntrinsicLength),
+    /// The intrinsic height.
+    height: ContainIntrinsicHeight(ContainIntrinsicLength),
+  }
+}
+
+impl<'i> Parse<'i> for ContainIntrinsicSize {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let width = ContainIntrinsicLength::parse(input)?;
+    let height = input.try_parse(ContainIntrinsicLength::parse).unwrap_or_else(|_| width.clone());
+    Ok(ContainIntrinsicSize { width, height })
+  }
+}
+
+impl ToCss for ContainIntrinsicSize {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.width.to_css(dest)?;
+    if self.height != self.width {
+      dest.write_char(' ')?;
+      self.height.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct ContainIntrinsicSizeHandler {
+  width: Option<ContainIntrinsicLength>,
+  height: Option<ContainIntrinsicLength>,
+  block_size: Option<ContainIntrinsicLength>,
+  inline_size: Option<ContainIntrinsicLength>,
+  has_any: bool,
+  category: PropertyCategory,
+}
+
+impl<'i> PropertyHandler<'i> for ContainIntrinsicSizeHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    _: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    macro_rules! property {
+      ($prop: ident, $val: expr, $category: ident) => {{
+        // Preserve the previous values if the category changes between logical and physical.
+        if PropertyCategory::$category != self.category {
+          self.flush(dest);
+        }
+
+        self.$prop = Some($val.clone());
+        self.category = PropertyCategory::$category;
+        self.has_any = true;
+      }};
+    }
+
+    match property {
+      Property::ContainIntrinsicWidth(val) => property!(width, val, Physical),
+      Property::ContainIntrinsicHeight(val) => property!(height, val, Physical),
+      Property::ContainIntrinsicBlockSize(val) => property!(block_size, val, Logical),
+      Property::ContainIntrinsicInlineSize(val) => property!(inline_size, val, Logical),
+      Property::ContainIntrinsicSize(val) => {
+        property!(width, val.width, Physical);
+        property!(height, val.height, Physical);
+      }
+      Property::Unparsed(unparsed)
+        if matches!(
+          unparsed.property_id,
+          PropertyId::ContainIntrinsicWidth
+            | PropertyId::ContainIntrinsicHeight
+            | PropertyId::ContainIntrinsicSize
+            | PropertyId::ContainIntrinsicBlockSize
+            | PropertyId::ContainIntrinsicInlineSize
+        ) =>
+      {
+        self.flush(dest);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList, _: &mut PropertyHandlerContext<'i, '_>) {
+    self.flush(dest);
+  }
+}
+
+impl ContainIntrinsicSizeHandler {
+  fn flush(&mut self, dest: &mut DeclarationList) {
+    if !self.has_any {
+      return;
+    }
+
+    self.has_any = false;
+
+    let width = std::mem::take(&mut self.width);
+    let height = std::mem::take(&mut self.height);
+    match (width, height) {
+      (Some(width), Some(height)) => {
+        dest.push(Property::ContainIntrinsicSize(ContainIntrinsicSize { width, height }));
+      }
+      (width, height) => {
+        if let Some(width) = width {
+          dest.push(Property::ContainIntrinsicWidth(width));
+        }
+        if let Some(height) = height {
+          dest.push(Property::ContainIntrinsicHeight(height));
+        }
+      }
+    }
+
+    if let Some(block_size) = std::mem::take(&mut self.block_size) {
+      dest.push(Property::ContainIntrinsicBlockSize(block_size));
+    }
+
+    if let Some(inline_size) = std::mem::take(&mut self.inline_size) {
+      dest.push(Property::ContainIntrinsicInlineSize(inline_size));
+    }
+  }
+}
+
 property_bitflags! {
   #[derive(Default)]
   struct SizeProperty: u16 {
@@ -476,14 +655,14 @@ impl<'i> PropertyHandler<'i> for SizeHandler {
     true
   }
 
-  fn finalize(&mut self, dest: &mut DeclarationList, context: &mut PropertyHandlerContext<'i, '_>) {
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
     self.flush(dest, context);
     self.flushed_properties = SizeProperty::empty();
   }
 }
 
 impl SizeHandler {
-  fn flush<'i>(&mut self, dest: &mut DeclarationList, context: &mut PropertyHandlerContext<'i, '_>) {
+  fn flush<'i>(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
     if !self.has_any {
       return;
     }
@@ -492,13 +671,12 @@ impl SizeHandler {
     let logical_supported = !context.should_compile_logical(Feature::LogicalSize);
 
     macro_rules! prefix {
-      ($prop: ident, $size: ident, $feature: ident) => {
+      ($prop: ident, $size: ident, $feature: ident, $val: ident) => {
         if !self.flushed_properties.contains(SizeProperty::$prop) {
           let prefixes =
             context.targets.prefixes(VendorPrefix::None, crate::prefixes::Feature::$feature) - VendorPrefix::None;
-          for prefix in prefixes {
-            dest.push(Property::$prop($size::$feature(prefix)));
-          }
+          let prefixed = prefixes.iter().map(|prefix| Property::$prop($size::$feature(prefix))).collect();
+          dest.extend(context.prefixed(|| Property::$prop($val.clone()), prefixed));
         }
       };
     }
@@ -507,10 +685,10 @@ impl SizeHandler {
       ($prop: ident, $val: ident, $size: ident) => {{
         if let Some(val) = std::mem::take(&mut self.$val) {
           match val {
-            $size::Stretch(VendorPrefix::None) => prefix!($prop, $size, Stretch),
-            $size::MinContent(VendorPrefix::None) => prefix!($prop, $size, MinContent),
-            $size::MaxContent(VendorPrefix::None) => prefix!($prop, $size, MaxContent),
-            $size::FitContent(VendorPrefix::None) => prefix!($prop, $size, FitContent),
+            $size::Stretch(VendorPrefix::None) => prefix!($prop, $size, Stretch, val),
+            $size::MinContent(VendorPrefix::None) => prefix!($prop, $size, MinContent, val),
+            $size::MaxContent(VendorPrefix::None) => prefix!($prop, $size, MaxContent, val),
+            $size::FitContent(VendorPrefix::None) => prefix!($prop, $size, FitContent, val),
             _ => {}
           }
           dest.push(Property::$prop(val.clone()));
@@ -1,12 +1,22 @@
 //! CSS properties used in SVG.
 
+use super::{Property, PropertyId};
+use crate::context::PropertyHandlerContext;
+use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
 use crate::macros::enum_property;
-use crate::printer::Printer;
+use crate::printer::{Printer, PrinterOptions};
 use crate::targets::{Browsers, Targets};
-use crate::traits::{FallbackValues, IsCompatible, Parse, ToCss};
-use crate::values::length::LengthPercentage;
-use crate::values::{color::CssColor, url::Url};
+use crate::traits::{FallbackValues, IsCompatible, Parse, PropertyHandler, Shorthand, ToCss};
+use crate::values::alpha::AlphaValue;
+use crate::values::length::{LengthPercentage, LengthPercentageOrAuto};
+use crate::values::number::CSSNumber;
+use crate::values::shape::FillRule;
+use crate::values::{
+  color::{CssColor, GamutMapping},
+  url::Url,
+};
+use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use cssparser::*;
@@ -130,10 +140,10 @@ impl ToCss for SVGPaintFallback {
 }
 
 impl<'i> FallbackValues for SVGPaint<'i> {
-  fn get_fallbacks(&mut self, targets: Targets) -> Vec<Self> {
+  fn get_fallbacks(&mut self, targets: Targets, gamut_mapping: GamutMapping) -> Vec<Self> {
     match self {
       SVGPaint::Color(color) => color
-        .get_fallbacks(targets)
+        .get_fallbacks(targets, gamut_mapping)
         .into_iter()
         .map(|color| SVGPaint::Color(color))
         .collect(),
@@ -141,7 +151,7 @@ impl<'i> FallbackValues for SVGPaint<'i> {
         url,
         fallback: Some(SVGPaintFallback::Color(color)),
       } => color
-        .get_fallbacks(targets)
+        .get_fallbacks(targets, gamut_mapping)
         .into_iter()
         .map(|color| SVGPaint::Url {
           url: url.clone(),
@@ -295,8 +305,480 @@ impl<'i> ToCss for Marker<'i> {
   {
     match self {
       Marker::None => dest.write_str("none"),
-      Marker::Url(url) => url.to_css(dest),
+      Marker::Url(url) => {
+        // When CSS modules are enabled, references to a local `<marker>` element are
+        // scoped in the same way as id selectors, so `url(#arrow)` matches `#arrow`.
+        if dest.css_module.is_some() {
+          if let Some(id) = url.url.strip_prefix('#').filter(|id| is_local_ident(id)) {
+            dest.write_str("url(#")?;
+            dest.write_ident(id, true)?;
+            return dest.write_char(')');
+          }
+        }
+
+        url.to_css(dest)
+      }
+    }
+  }
+}
+
+fn is_local_ident(id: &str) -> bool {
+  let mut chars = id.chars();
+  match chars.next() {
+    Some(c) if c.is_ascii_alphabetic() || c == '_' || !c.is_ascii() => {}
+    _ => return false,
+  }
+  chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || !c.is_ascii())
+}
+
+impl<'i> Shorthand<'i> for Marker<'i> {
+  fn from_longhands(decls: &DeclarationBlock<'i>, _: VendorPrefix) -> Option<(Self, bool)> {
+    let mut start = None;
+    let mut mid = None;
+    let mut end = None;
+    let mut count = 0;
+    let mut important_count = 0;
+    for (property, important) in decls.iter() {
+      match property {
+        Property::MarkerStart(value) => start = Some(value),
+        Property::MarkerMid(value) => mid = Some(value),
+        Property::MarkerEnd(value) => end = Some(value),
+        Property::Marker(value) => {
+          start = Some(value);
+          mid = Some(value);
+          end = Some(value);
+        }
+        _ => continue,
+      }
+
+      count += 1;
+      if important {
+        important_count += 1;
+      }
+    }
+
+    // !important flags must match to produce a shorthand.
+    if important_count > 0 && important_count != count {
+      return None;
+    }
+
+    match (start, mid, end) {
+      (Some(start), Some(mid), Some(end)) if start == mid && mid == end => {
+        Some((start.clone(), important_count > 0))
+      }
+      _ => None,
+    }
+  }
+
+  fn longhands(_: VendorPrefix) -> Vec<PropertyId<'static>> {
+    vec![PropertyId::MarkerStart, PropertyId::MarkerMid, PropertyId::MarkerEnd]
+  }
+
+  fn longhand(&self, property_id: &PropertyId) -> Option<Property<'i>> {
+    match property_id {
+      PropertyId::MarkerStart => Some(Property::MarkerStart(self.clone())),
+      PropertyId::MarkerMid => Some(Property::MarkerMid(self.clone())),
+      PropertyId::MarkerEnd => Some(Property::MarkerEnd(self.clone())),
+      _ => None,
+    }
+  }
+
+  fn set_longhand(&mut self, _: &Property<'i>) -> Result<(), ()> {
+    // A single longhand cannot be updated without affecting the others.
+    Err(())
+  }
+}
+
+/// A value for the [d](https://svgwg.org/svg2-draft/paths.html#TheDProperty) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum PathData {
+  /// No path is drawn.
+  None,
+  /// A `path()` function containing SVG path data.
+  Path(SVGPath),
+}
+
+impl<'i> Parse<'i> for PathData {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(PathData::None);
+    }
+
+    Ok(PathData::Path(SVGPath::parse(input)?))
+  }
+}
+
+impl ToCss for PathData {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      PathData::None => dest.write_str("none"),
+      PathData::Path(path) => path.to_css(dest),
+    }
+  }
+}
+
+/// A [path()](https://drafts.csswg.org/css-shapes-1/#funcdef-basic-shape-path) function
+/// containing [SVG path data](https://svgwg.org/svg2-draft/paths.html#PathData).
+///
+/// Consecutive segments with the same command are merged, and the path data is
+/// serialized in its most compact form.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct SVGPath {
+  /// The segments of the path.
+  pub segments: Vec<PathSegment>,
+}
+
+/// A segment of an [SVGPath](SVGPath), consisting of a command and its arguments.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct PathSegment {
+  /// The path command.
+  pub command: PathCommand,
+  /// Whether the coordinates are relative to the current point.
+  pub relative: bool,
+  /// The arguments of the command. This may contain multiple sets of arguments
+  /// if the command is repeated.
+  pub values: Vec<CSSNumber>,
+}
+
+/// An [SVG path command](https://svgwg.org/svg2-draft/paths.html#PathData).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum PathCommand {
+  /// The `M` command.
+  MoveTo,
+  /// The `Z` command.
+  ClosePath,
+  /// The `L` command.
+  LineTo,
+  /// The `H` command.
+  HorizontalLineTo,
+  /// The `V` command.
+  VerticalLineTo,
+  /// The `C` command.
+  CurveTo,
+  /// The `S` command.
+  SmoothCurveTo,
+  /// The `Q` command.
+  QuadraticCurveTo,
+  /// The `T` command.
+  SmoothQuadraticCurveTo,
+  /// The `A` command.
+  Arc,
+}
+
+impl PathCommand {
+  fn from_char(c: char) -> Option<(PathCommand, bool)> {
+    let command = match c.to_ascii_uppercase() {
+      'M' => PathCommand::MoveTo,
+      'Z' => PathCommand::ClosePath,
+      'L' => PathCommand::LineTo,
+      'H' => PathCommand::HorizontalLineTo,
+      'V' => PathCommand::VerticalLineTo,
+      'C' => PathCommand::CurveTo,
+      'S' => PathCommand::SmoothCurveTo,
+      'Q' => PathCommand::QuadraticCurveTo,
+      'T' => PathCommand::SmoothQuadraticCurveTo,
+      'A' => PathCommand::Arc,
+      _ => return None,
+    };
+    Some((command, c.is_ascii_lowercase()))
+  }
+
+  fn to_char(self, relative: bool) -> char {
+    let c = match self {
+      PathCommand::MoveTo => 'M',
+      PathCommand::ClosePath => 'Z',
+      PathCommand::LineTo => 'L',
+      PathCommand::HorizontalLineTo => 'H',
+      PathCommand::VerticalLineTo => 'V',
+      PathCommand::CurveTo => 'C',
+      PathCommand::SmoothCurveTo => 'S',
+      PathCommand::QuadraticCurveTo => 'Q',
+      PathCommand::SmoothQuadraticCurveTo => 'T',
+      PathCommand::Arc => 'A',
+    };
+    if relative {
+      c.to_ascii_lowercase()
+    } else {
+      c
+    }
+  }
+
+  /// Returns the number of arguments the command takes.
+  fn arity(&self) -> usize {
+    match self {
+      PathCommand::ClosePath => 0,
+      PathCommand::HorizontalLineTo | PathCommand::VerticalLineTo => 1,
+      PathCommand::MoveTo | PathCommand::LineTo | PathCommand::SmoothQuadraticCurveTo => 2,
+      PathCommand::SmoothCurveTo | PathCommand::QuadraticCurveTo => 4,
+      PathCommand::CurveTo => 6,
+      PathCommand::Arc => 7,
+    }
+  }
+}
+
+impl<'i> Parse<'i> for SVGPath {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    input.expect_function_matching("path")?;
+    input.parse_nested_block(|input| {
+      let location = input.current_source_location();
+      let data = input.expect_string()?;
+      SVGPath::parse_data(data.as_ref()).ok_or_else(|| location.new_custom_error(ParserError::InvalidValue))
+    })
+  }
+}
+
+impl SVGPath {
+  /// Parses SVG path data, e.g. the `d` attribute of a `<path>` element.
+  pub fn parse_data(data: &str) -> Option<SVGPath> {
+    let mut parser = PathDataParser {
+      data: data.as_bytes(),
+      pos: 0,
+    };
+    let mut segments: Vec<PathSegment> = Vec::new();
+
+    parser.skip_whitespace();
+    while let Some(c) = parser.peek() {
+      let (command, relative) = PathCommand::from_char(c as char)?;
+      if segments.is_empty() && command != PathCommand::MoveTo {
+        return None;
+      }
+      parser.pos += 1;
+
+      let arity = command.arity();
+      let mut values = Vec::new();
+      loop {
+        parser.skip_whitespace();
+        if arity == 0 || !parser.at_number() {
+          break;
+        }
+
+        for i in 0..arity {
+          if i > 0 {
+            parser.skip_separator();
+          }
+          // The large-arc and sweep flags of an arc may be written without separators.
+          let value = if command == PathCommand::Arc && (i == 3 || i == 4) {
+            parser.parse_flag()?
+          } else {
+            parser.parse_number()?
+          };
+          values.push(value);
+        }
+
+        parser.skip_separator();
+      }
+
+      if values.is_empty() && arity > 0 {
+        return None;
+      }
+
+      // Merge with the previous segment if the command is repeated. A line after a move
+      // can also be merged, because extra coordinates after a move are treated as lines.
+      match segments.last_mut() {
+        Some(prev)
+          if prev.relative == relative
+            && command != PathCommand::ClosePath
+            && (prev.command == command && command != PathCommand::MoveTo
+              || prev.command == PathCommand::MoveTo && command == PathCommand::LineTo) =>
+        {
+          prev.values.extend(values);
+        }
+        _ => segments.push(PathSegment {
+          command,
+          relative,
+          values,
+        }),
+      }
+    }
+
+    Some(SVGPath { segments })
+  }
+
+  fn write_data(&self, dest: &mut String) -> Result<(), PrinterError> {
+    for segment in &self.segments {
+      dest.push(segment.command.to_char(segment.relative));
+      let arity = segment.command.arity();
+      let mut prev = String::new();
+      for (i, value) in segment.values.iter().enumerate() {
+        let mut s = String::new();
+        if segment.command == PathCommand::Arc && (i % arity == 3 || i % arity == 4) {
+          s.push(if *value == 0.0 { '0' } else { '1' });
+        } else {
+          number_to_string(*value, &mut s)?;
+        }
+
+        // Separators can be omitted before a minus sign, or before a decimal point
+        // if the previous number already contains one.
+        if i > 0 && !s.starts_with('-') && !(s.starts_with('.') && prev.contains('.') && !prev.contains('e')) {
+          dest.push(' ');
+        }
+        dest.push_str(&s);
+        prev = s;
+      }
+    }
+    Ok(())
+  }
+}
+
+impl ToCss for SVGPath {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let mut data = String::new();
+    self.write_data(&mut data)?;
+    dest.write_str("path(")?;
+    serialize_string(&data, dest)?;
+    dest.write_char(')')
+  }
+}
+
+fn number_to_string(value: CSSNumber, dest: &mut String) -> Result<(), PrinterError> {
+  let mut printer = Printer::new(dest, PrinterOptions::default());
+  value.to_css(&mut printer)
+}
+
+struct PathDataParser<'a> {
+  data: &'a [u8],
+  pos: usize,
+}
+
+impl<'a> PathDataParser<'a> {
+  fn peek(&self) -> Option<u8> {
+    self.data.get(self.pos).copied()
+  }
+
+  fn skip_whitespace(&mut self) {
+    while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r' | b'\x0c')) {
+      self.pos += 1;
+    }
+  }
+
+  fn skip_separator(&mut self) {
+    self.skip_whitespace();
+    if self.peek() == Some(b',') {
+      self.pos += 1;
+      self.skip_whitespace();
+    }
+  }
+
+  fn at_number(&self) -> bool {
+    matches!(self.peek(), Some(b'0'..=b'9' | b'.' | b'-' | b'+'))
+  }
+
+  fn parse_flag(&mut self) -> Option<CSSNumber> {
+    let value = match self.peek()? {
+      b'0' => 0.0,
+      b'1' => 1.0,
+      _ => return None,
+    };
+    self.pos += 1;
+    Some(value)
+  }
+
+  fn parse_number(&mut self) -> Option<CSSNumber> {
+    let start = self.pos;
+    if matches!(self.peek(), Some(b'-' | b'+')) {
+      self.pos += 1;
+    }
+
+    let mut has_digits = self.skip_digits();
+    if self.peek() == Some(b'.') {
+      self.pos += 1;
+      has_digits |= self.skip_digits();
+    }
+
+    if !has_digits {
+      return None;
+    }
+
+    if matches!(self.peek(), Some(b'e' | b'E')) {
+      let mantissa_end = self.pos;
+      self.pos += 1;
+      if matches!(self.peek(), Some(b'-' | b'+')) {
+        self.pos += 1;
+      }
+      if !self.skip_digits() {
+        self.pos = mantissa_end;
+      }
+    }
+
+    let value: CSSNumber = std::str::from_utf8(&self.data[start..self.pos]).ok()?.parse().ok()?;
+    value.is_finite().then_some(value)
+  }
+
+  fn skip_digits(&mut self) -> bool {
+    let start = self.pos;
+    while matches!(self.peek(), Some(b'0'..=b'9')) {
+      self.pos += 1;
     }
+    self.pos > start
+  }
+}
+enum_property! {
+  /// A value for the [vector-effect](https://www.w3.org/TR/SVG2/coords.html#VectorEffects) property.
+  pub enum VectorEffect {
+    /// No vector effect is applied.
+    "none": None,
+    /// The stroke width is not affected by transformations.
+    "non-scaling-stroke": NonScalingStroke,
+    /// The scale of the user coordinate system is not affected by transformations.
+    "non-scaling-size": NonScalingSize,
+    /// The rotation and skew of the user coordinate system is not affected by transformations.
+    "non-rotation": NonRotation,
+    /// The position of the user coordinate system is not affected by transformations.
+    "fixed-position": FixedPosition,
+  }
+}
+
+enum_property! {
+  /// A value for the [dominant-baseline](https://drafts.csswg.org/css-inline-3/#dominant-baseline-property) property.
+  pub enum DominantBaseline {
+    /// The baseline is chosen based on the writing mode.
+    "auto": Auto,
+    /// The bottom of the em box.
+    "text-bottom": TextBottom,
+    /// The alphabetic baseline.
+    "alphabetic": Alphabetic,
+    /// The ideographic-under baseline.
+    "ideographic": Ideographic,
+    /// Halfway between the alphabetic baseline and the x-height.
+    "middle": Middle,
+    /// The central baseline.
+    "central": Central,
+    /// The math baseline.
+    "mathematical": Mathematical,
+    /// The hanging baseline.
+    "hanging": Hanging,
+    /// The top of the em box.
+    "text-top": TextTop,
   }
 }
 
@@ -363,3 +845,227 @@ enum_property! {
     OptimizeQuality,
   }
 }
+
+#[derive(Default)]
+pub(crate) struct MarkerHandler<'i> {
+  start: Option<Marker<'i>>,
+  mid: Option<Marker<'i>>,
+  end: Option<Marker<'i>>,
+}
+
+impl<'i> PropertyHandler<'i> for MarkerHandler<'i> {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::MarkerStart(val) => self.start = Some(val.clone()),
+      Property::MarkerMid(val) => self.mid = Some(val.clone()),
+      Property::MarkerEnd(val) => self.end = Some(val.clone()),
+      Property::Marker(val) => {
+        self.start = Some(val.clone());
+        self.mid = Some(val.clone());
+        self.end = Some(val.clone());
+      }
+      Property::Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::MarkerStart | PropertyId::MarkerMid | PropertyId::MarkerEnd | PropertyId::Marker
+        ) =>
+      {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {
+    let start = std::mem::take(&mut self.start);
+    let mid = std::mem::take(&mut self.mid);
+    let end = std::mem::take(&mut self.end);
+
+    match (start, mid, end) {
+      (Some(start), Some(mid), Some(end)) if start == mid && mid == end => dest.push(Property::Marker(start)),
+      (start, mid, end) => {
+        if let Some(start) = start {
+          dest.push(Property::MarkerStart(start));
+        }
+        if let Some(mid) = mid {
+          dest.push(Property::MarkerMid(mid));
+        }
+        if let Some(end) = end {
+          dest.push(Property::MarkerEnd(end));
+        }
+      }
+    }
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct SVGHandler {
+  fill_rule: Option<FillRule>,
+  fill_opacity: Option<AlphaValue>,
+  stroke_opacity: Option<AlphaValue>,
+  stroke_width: Option<LengthPercentage>,
+  stroke_linecap: Option<StrokeLinecap>,
+  stroke_linejoin: Option<StrokeLinejoin>,
+  stroke_miterlimit: Option<CSSNumber>,
+  stroke_dasharray: Option<StrokeDasharray>,
+  stroke_dashoffset: Option<LengthPercentage>,
+  color_interpolation: Option<ColorInterpolation>,
+  color_interpolation_filters: Option<ColorInterpolation>,
+  color_rendering: Option<ColorRendering>,
+  shape_rendering: Option<ShapeRendering>,
+  text_rendering: Option<TextRendering>,
+  image_rendering: Option<ImageRendering>,
+  vector_effect: Option<VectorEffect>,
+  d: Option<PathData>,
+  cx: Option<LengthPercentage>,
+  cy: Option<LengthPercentage>,
+  r: Option<LengthPercentage>,
+  rx: Option<LengthPercentageOrAuto>,
+  ry: Option<LengthPercentageOrAuto>,
+  x: Option<LengthPercentage>,
+  y: Option<LengthPercentage>,
+  has_any: bool,
+}
+
+impl<'i> PropertyHandler<'i> for SVGHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    macro_rules! property {
+      ($prop: ident, $val: expr) => {{
+        self.$prop = Some($val.clone());
+        self.has_any = true;
+      }};
+      ($prop: ident, $val: expr, compat) => {{
+        // Preserve the previous value as a fallback if the new one isn't supported by all targets.
+        if self.$prop.is_some() && matches!(context.targets.browsers, Some(targets) if !$val.is_compatible(targets)) {
+          self.flush(dest);
+        }
+        property!($prop, $val);
+      }};
+    }
+
+    match property {
+      Property::FillRule(val) => property!(fill_rule, val),
+      Property::FillOpacity(val) => property!(fill_opacity, val),
+      Property::StrokeOpacity(val) => property!(stroke_opacity, val),
+      Property::StrokeWidth(val) => property!(stroke_width, val, compat),
+      Property::StrokeLinecap(val) => property!(stroke_linecap, val),
+      Property::StrokeLinejoin(val) => property!(stroke_linejoin, val),
+      Property::StrokeMiterlimit(val) => property!(stroke_miterlimit, val),
+      Property::StrokeDasharray(val) => property!(stroke_dasharray, val),
+      Property::StrokeDashoffset(val) => property!(stroke_dashoffset, val, compat),
+      Property::ColorInterpolation(val) => property!(color_interpolation, val),
+      Property::ColorInterpolationFilters(val) => property!(color_interpolation_filters, val),
+      Property::ColorRendering(val) => property!(color_rendering, val),
+      Property::ShapeRendering(val) => property!(shape_rendering, val),
+      Property::TextRendering(val) => property!(text_rendering, val),
+      Property::ImageRendering(val) => property!(image_rendering, val),
+      Property::VectorEffect(val) => property!(vector_effect, val),
+      Property::D(val) => property!(d, val),
+      Property::Cx(val) => property!(cx, val, compat),
+      Property::Cy(val) => property!(cy, val, compat),
+      Property::R(val) => property!(r, val, compat),
+      Property::Rx(val) => property!(rx, val, compat),
+      Property::Ry(val) => property!(ry, val, compat),
+      Property::X(val) => property!(x, val, compat),
+      Property::Y(val) => property!(y, val, compat),
+      Property::Unparsed(val) if is_svg_property(&val.property_id) => {
+        self.flush(dest);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {
+    self.flush(dest);
+  }
+}
+
+impl SVGHandler {
+  fn flush(&mut self, dest: &mut DeclarationList) {
+    if !self.has_any {
+      return;
+    }
+
+    self.has_any = false;
+
+    macro_rules! flush {
+      ($prop: ident, $property: ident) => {
+        if let Some(val) = std::mem::take(&mut self.$prop) {
+          dest.push(Property::$property(val));
+        }
+      };
+    }
+
+    flush!(fill_rule, FillRule);
+    flush!(fill_opacity, FillOpacity);
+    flush!(stroke_opacity, StrokeOpacity);
+    flush!(stroke_width, StrokeWidth);
+    flush!(stroke_linecap, StrokeLinecap);
+    flush!(stroke_linejoin, StrokeLinejoin);
+    flush!(stroke_miterlimit, StrokeMiterlimit);
+    flush!(stroke_dasharray, StrokeDasharray);
+    flush!(stroke_dashoffset, StrokeDashoffset);
+    flush!(color_interpolation, ColorInterpolation);
+    flush!(color_interpolation_filters, ColorInterpolationFilters);
+    flush!(color_rendering, ColorRendering);
+    flush!(shape_rendering, ShapeRendering);
+    flush!(text_rendering, TextRendering);
+    flush!(image_rendering, ImageRendering);
+    flush!(vector_effect, VectorEffect);
+    flush!(d, D);
+    flush!(cx, Cx);
+    flush!(cy, Cy);
+    flush!(r, R);
+    flush!(rx, Rx);
+    flush!(ry, Ry);
+    flush!(x, X);
+    flush!(y, Y);
+  }
+}
+
+#[inline]
+fn is_svg_property(property_id: &PropertyId) -> bool {
+  matches!(
+    property_id,
+    PropertyId::FillRule
+      | PropertyId::FillOpacity
+      | PropertyId::StrokeOpacity
+      | PropertyId::StrokeWidth
+      | PropertyId::StrokeLinecap
+      | PropertyId::StrokeLinejoin
+      | PropertyId::StrokeMiterlimit
+      | PropertyId::StrokeDasharray
+      | PropertyId::StrokeDashoffset
+      | PropertyId::ColorInterpolation
+      | PropertyId::ColorInterpolationFilters
+      | PropertyId::ColorRendering
+      | PropertyId::ShapeRendering
+      | PropertyId::TextRendering
+      | PropertyId::ImageRendering
+      | PropertyId::VectorEffect
+      | PropertyId::D
+      | PropertyId::Cx
+      | PropertyId::Cy
+      | PropertyId::R
+      | PropertyId::Rx
+      | PropertyId::Ry
+      | PropertyId::X
+      | PropertyId::Y
+  )
+}
@@ -2,19 +2,21 @@
 
 #![allow(non_upper_case_globals)]
 
+use super::custom::{CustomProperty, CustomPropertyName};
 use super::{Property, PropertyId};
 use crate::compat;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
-use crate::macros::{define_shorthand, enum_property};
+use crate::macros::{define_shorthand, enum_property, shorthand_handler};
 use crate::prefixes::Feature;
 use crate::printer::Printer;
 use crate::targets::{should_compile, Browsers, Targets};
 use crate::traits::{FallbackValues, IsCompatible, Parse, PropertyHandler, Shorthand, ToCss, Zero};
 use crate::values::calc::{Calc, MathFunction};
-use crate::values::color::{ColorFallbackKind, CssColor};
+use crate::values::color::{ColorFallbackKind, CssColor, GamutMapping};
 use crate::values::length::{Length, LengthPercentage, LengthValue};
+use crate::values::number::{CSSInteger, CSSNumber};
 use crate::values::percentage::Percentage;
 use crate::values::string::CSSString;
 use crate::vendor_prefix::VendorPrefix;
@@ -217,20 +219,202 @@ impl ToCss for TextTransform {
 }
 
 enum_property! {
-  /// A value for the [white-space](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#white-space-property) property.
-  pub enum WhiteSpace {
+  /// A value for the [white-space-collapse](https://drafts.csswg.org/css-text-4/#white-space-collapsing) property.
+  #[derive(Default)]
+  pub enum WhiteSpaceCollapse {
     /// Sequences of white space are collapsed into a single character.
-    "normal": Normal,
-    /// White space is not collapsed.
-    "pre": Pre,
-    /// White space is collapsed, but no line wrapping occurs.
-    "nowrap": NoWrap,
-    /// White space is preserved, but line wrapping occurs.
-    "pre-wrap": PreWrap,
-    /// Like pre-wrap, but with different line breaking rules.
+    #[default]
+    "collapse": Collapse,
+    /// White space is removed.
+    "discard": Discard,
+    /// White space is preserved.
+    "preserve": Preserve,
+    /// Segment breaks are preserved, but other white space is collapsed.
+    "preserve-breaks": PreserveBreaks,
+    /// White space is preserved, but segment breaks are collapsed.
+    "preserve-spaces": PreserveSpaces,
+    /// Like preserve, but preserved white space takes up space and allows wrapping.
     "break-spaces": BreakSpaces,
-    /// White space is collapsed, but with different line breaking rules.
-    "pre-line": PreLine,
+  }
+}
+
+enum_property! {
+  /// A value for the [text-wrap-mode](https://drafts.csswg.org/css-text-4/#text-wrap-mode) property.
+  #[derive(Default)]
+  pub enum TextWrapMode {
+    /// Lines may break at allowed soft wrap opportunities.
+    #[default]
+    "wrap": Wrap,
+    /// Lines do not break.
+    "nowrap": NoWrap,
+  }
+}
+
+define_shorthand! {
+  /// A value for the [white-space](https://drafts.csswg.org/css-text-4/#white-space-property) shorthand property.
+  pub struct WhiteSpace {
+    /// How white space is collapsed.
+    collapse: WhiteSpaceCollapse(WhiteSpaceCollapse),
+    /// Whether lines may wrap.
+    wrap_mode: TextWrapMode(TextWrapMode),
+  }
+}
+
+impl<'i> Parse<'i> for WhiteSpace {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(value) = input.try_parse(parse_legacy_white_space) {
+      return Ok(value);
+    }
+
+    let mut collapse = None;
+    let mut wrap_mode = None;
+    loop {
+      if collapse.is_none() {
+        if let Ok(value) = input.try_parse(WhiteSpaceCollapse::parse) {
+          collapse = Some(value);
+          continue;
+        }
+      }
+
+      if wrap_mode.is_none() {
+        if let Ok(value) = input.try_parse(TextWrapMode::parse) {
+          wrap_mode = Some(value);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if collapse.is_none() && wrap_mode.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(WhiteSpace {
+      collapse: collapse.unwrap_or_default(),
+      wrap_mode: wrap_mode.unwrap_or_default(),
+    })
+  }
+}
+
+/// Parses the legacy `white-space` keywords that do not correspond to a single longhand value.
+fn parse_legacy_white_space<'i>(
+  input: &mut Parser<'i, '_>,
+) -> Result<WhiteSpace, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  let ident = input.expect_ident()?;
+  let (collapse, wrap_mode) = match_ignore_ascii_case! { ident,
+    "normal" => (WhiteSpaceCollapse::Collapse, TextWrapMode::Wrap),
+    "pre" => (WhiteSpaceCollapse::Preserve, TextWrapMode::NoWrap),
+    "pre-wrap" => (WhiteSpaceCollapse::Preserve, TextWrapMode::Wrap),
+    "pre-line" => (WhiteSpaceCollapse::PreserveBreaks, TextWrapMode::Wrap),
+    _ => return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
+  };
+  Ok(WhiteSpace { collapse, wrap_mode })
+}
+
+impl ToCss for WhiteSpace {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    // Prefer the legacy keywords, which are supported in all browsers.
+    match (&self.collapse, &self.wrap_mode) {
+      (WhiteSpaceCollapse::Collapse, TextWrapMode::Wrap) => dest.write_str("normal"),
+      (WhiteSpaceCollapse::Collapse, TextWrapMode::NoWrap) => dest.write_str("nowrap"),
+      (WhiteSpaceCollapse::Preserve, TextWrapMode::NoWrap) => dest.write_str("pre"),
+      (WhiteSpaceCollapse::Preserve, TextWrapMode::Wrap) => dest.write_str("pre-wrap"),
+      (WhiteSpaceCollapse::PreserveBreaks, TextWrapMode::Wrap) => dest.write_str("pre-line"),
+      (collapse, TextWrapMode::Wrap) => collapse.to_css(dest),
+      (collapse, TextWrapMode::NoWrap) => {
+        collapse.to_css(dest)?;
+        dest.write_str(" nowrap")
+      }
+    }
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct WhiteSpaceHandler {
+  collapse: Option<WhiteSpaceCollapse>,
+  wrap_mode: Option<TextWrapMode>,
+  has_any: bool,
+}
+
+impl<'i> PropertyHandler<'i> for WhiteSpaceHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::WhiteSpaceCollapse(val) => {
+        self.collapse = Some(*val);
+        self.has_any = true;
+      }
+      Property::TextWrapMode(val) => {
+        self.wrap_mode = Some(*val);
+        self.has_any = true;
+      }
+      Property::WhiteSpace(val) => {
+        self.collapse = Some(val.collapse);
+        self.wrap_mode = Some(val.wrap_mode);
+        self.has_any = true;
+      }
+      Property::Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::WhiteSpace | PropertyId::WhiteSpaceCollapse | PropertyId::TextWrapMode
+        ) =>
+      {
+        self.flush(dest);
+        let mut unparsed = val.clone();
+        context.add_unparsed_fallbacks(&mut unparsed);
+        dest.push(Property::Unparsed(unparsed));
+      }
+      Property::Custom(CustomProperty {
+        name: CustomPropertyName::Unknown(name),
+        ..
+      }) if name.eq_ignore_ascii_case("text-wrap") => {
+        // The text-wrap shorthand also sets text-wrap-mode, so flush to preserve the order.
+        self.flush(dest);
+        return false;
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {
+    self.flush(dest);
+  }
+}
+
+impl WhiteSpaceHandler {
+  fn flush(&mut self, dest: &mut DeclarationList) {
+    if !self.has_any {
+      return;
+    }
+
+    self.has_any = false;
+
+    let collapse = std::mem::take(&mut self.collapse);
+    let wrap_mode = std::mem::take(&mut self.wrap_mode);
+    match (collapse, wrap_mode) {
+      // The shorthand is serialized as a legacy keyword where possible, so combining
+      // the longhands also allows older browsers to understand them.
+      (Some(collapse), Some(wrap_mode)) => dest.push(Property::WhiteSpace(WhiteSpace { collapse, wrap_mode })),
+      (collapse, wrap_mode) => {
+        if let Some(collapse) = collapse {
+          dest.push(Property::WhiteSpaceCollapse(collapse));
+        }
+        if let Some(wrap_mode) = wrap_mode {
+          dest.push(Property::TextWrapMode(wrap_mode));
+        }
+      }
+    }
   }
 }
 
@@ -465,6 +649,420 @@ impl ToCss for TextIndent {
   }
 }
 
+bitflags! {
+  /// The character class boundaries at which extra spacing is inserted by the
+  /// [text-autospace](https://drafts.csswg.org/css-text-4/#text-autospace-property) property.
+  #[cfg_attr(feature = "visitor", derive(Visit))]
+  #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(from = "SerializedAutospaceBoundaries", into = "SerializedAutospaceBoundaries"))]
+  #[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+  #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+  pub struct AutospaceBoundaries: u8 {
+    /// Space is inserted between ideographs and non-ideographic letters.
+    const IdeographAlpha   = 0b001;
+    /// Space is inserted between ideographs and non-ideographic numerals.
+    const IdeographNumeric = 0b010;
+    /// Space is inserted around punctuation as required by the content language.
+    const Punctuation      = 0b100;
+  }
+}
+
+impl ToCss for AutospaceBoundaries {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let mut needs_space = false;
+    macro_rules! val {
+      ($val: ident, $str: expr) => {
+        #[allow(unused_assignments)]
+        if self.contains(AutospaceBoundaries::$val) {
+          if needs_space {
+            dest.write_char(' ')?;
+          }
+          dest.write_str($str)?;
+          needs_space = true;
+        }
+      };
+    }
+
+    val!(IdeographAlpha, "ideograph-alpha");
+    val!(IdeographNumeric, "ideograph-numeric");
+    val!(Punctuation, "punctuation");
+    Ok(())
+  }
+}
+
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+struct SerializedAutospaceBoundaries {
+  ideograph_alpha: bool,
+  ideograph_numeric: bool,
+  punctuation: bool,
+}
+
+impl From<AutospaceBoundaries> for SerializedAutospaceBoundaries {
+  fn from(b: AutospaceBoundaries) -> Self {
+    Self {
+      ideograph_alpha: b.contains(AutospaceBoundaries::IdeographAlpha),
+      ideograph_numeric: b.contains(AutospaceBoundaries::IdeographNumeric),
+      punctuation: b.contains(AutospaceBoundaries::Punctuation),
+    }
+  }
+}
+
+impl From<SerializedAutospaceBoundaries> for AutospaceBoundaries {
+  fn from(s: SerializedAutospaceBoundaries) -> AutospaceBoundaries {
+    let mut b = AutospaceBoundaries::empty();
+    b.set(AutospaceBoundaries::IdeographAlpha, s.ideograph_alpha);
+    b.set(AutospaceBoundaries::IdeographNumeric, s.ideograph_numeric);
+    b.set(AutospaceBoundaries::Punctuation, s.punctuation);
+    b
+  }
+}
+
+#[cfg(feature = "jsonschema")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jsonschema")))]
+impl<'a> schemars::JsonSchema for AutospaceBoundaries {
+  fn is_referenceable() -> bool {
+    true
+  }
+
+  fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    SerializedAutospaceBoundaries::json_schema(gen)
+  }
+
+  fn schema_name() -> String {
+    "AutospaceBoundaries".into()
+  }
+}
+
+enum_property! {
+  /// Defines how autospacing interacts with existing spaces in the
+  /// [text-autospace](https://drafts.csswg.org/css-text-4/#text-autospace-property) property.
+  pub enum AutospaceMode {
+    /// Spacing is only inserted where there is no existing space.
+    Insert,
+    /// Existing spaces at the boundary are replaced by autospacing.
+    Replace,
+  }
+}
+
+/// A value for the [text-autospace](https://drafts.csswg.org/css-text-4/#text-autospace-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum TextAutospace {
+  /// Equivalent to `ideograph-alpha ideograph-numeric`.
+  Normal,
+  /// The user agent chooses a set of boundaries appropriate for the content language.
+  Auto,
+  /// No extra space is created.
+  NoAutospace,
+  /// Extra space is created at the given boundaries.
+  Autospace {
+    /// The boundaries at which spacing is inserted.
+    boundaries: AutospaceBoundaries,
+    /// How spacing interacts with existing spaces.
+    mode: AutospaceMode,
+  },
+}
+
+impl<'i> Parse<'i> for TextAutospace {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let mut boundaries = AutospaceBoundaries::empty();
+    let mut mode = None;
+    loop {
+      let state = input.state();
+      let ident = match input.expect_ident_cloned() {
+        Ok(ident) => ident,
+        Err(_) => {
+          input.reset(&state);
+          break;
+        }
+      };
+
+      let flag = match_ignore_ascii_case! { &ident,
+        "normal" if boundaries.is_empty() && mode.is_none() => return Ok(TextAutospace::Normal),
+        "auto" if boundaries.is_empty() && mode.is_none() => return Ok(TextAutospace::Auto),
+        "no-autospace" if boundaries.is_empty() && mode.is_none() => return Ok(TextAutospace::NoAutospace),
+        "ideograph-alpha" => AutospaceBoundaries::IdeographAlpha,
+        "ideograph-numeric" => AutospaceBoundaries::IdeographNumeric,
+        "punctuation" => AutospaceBoundaries::Punctuation,
+        "insert" | "replace" if mode.is_none() => {
+          mode = Some(if ident.eq_ignore_ascii_case("insert") {
+            AutospaceMode::Insert
+          } else {
+            AutospaceMode::Replace
+          });
+          continue;
+        },
+        _ => {
+          // Leave unknown keywords for the caller, e.g. the text-spacing shorthand.
+          input.reset(&state);
+          break;
+        }
+      };
+
+      if boundaries.contains(flag) {
+        return Err(location.new_custom_error(ParserError::InvalidValue));
+      }
+      boundaries |= flag;
+    }
+
+    if boundaries.is_empty() && mode.is_none() {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+
+    let mode = mode.unwrap_or(AutospaceMode::Insert);
+    if boundaries == AutospaceBoundaries::IdeographAlpha | AutospaceBoundaries::IdeographNumeric
+      && mode == AutospaceMode::Insert
+    {
+      return Ok(TextAutospace::Normal);
+    }
+
+    Ok(TextAutospace::Autospace { boundaries, mode })
+  }
+}
+
+impl ToCss for TextAutospace {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      TextAutospace::Normal => dest.write_str("normal"),
+      TextAutospace::Auto => dest.write_str("auto"),
+      TextAutospace::NoAutospace => dest.write_str("no-autospace"),
+      TextAutospace::Autospace { boundaries, mode } => {
+        boundaries.to_css(dest)?;
+        if *mode != AutospaceMode::Insert || boundaries.is_empty() {
+          if !boundaries.is_empty() {
+            dest.write_char(' ')?;
+          }
+          mode.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+impl IsCompatible for TextAutospace {
+  fn is_compatible(&self, browsers: Browsers) -> bool {
+    compat::Feature::TextAutospace.is_compatible(browsers)
+  }
+}
+
+enum_property! {
+  /// A value for the [text-spacing-trim](https://drafts.csswg.org/css-text-4/#text-spacing-trim-property) property.
+  #[derive(Default)]
+  pub enum TextSpacingTrim {
+    /// Fullwidth opening punctuation is trimmed at the start of each line, and adjacent punctuation is trimmed.
+    #[default]
+    "normal": Normal,
+    /// Fullwidth punctuation is not trimmed.
+    "space-all": SpaceAll,
+    /// Like normal, but fullwidth opening punctuation is not trimmed at the start of the first line.
+    "space-first": SpaceFirst,
+    /// Fullwidth opening punctuation is trimmed at the start of each line.
+    "trim-start": TrimStart,
+    /// Fullwidth punctuation is trimmed at the start and end of each line.
+    "trim-both": TrimBoth,
+    /// All fullwidth punctuation is trimmed.
+    "trim-all": TrimAll,
+    /// The user agent chooses a set of trimming rules.
+    "auto": Auto,
+  }
+}
+
+impl IsCompatible for TextSpacingTrim {
+  fn is_compatible(&self, browsers: Browsers) -> bool {
+    compat::Feature::TextSpacingTrim.is_compatible(browsers)
+  }
+}
+
+define_shorthand! {
+  /// A value for the [text-spacing](https://drafts.csswg.org/css-text-4/#text-spacing-property) shorthand property.
+  pub struct TextSpacing {
+    /// How fullwidth punctuation is trimmed.
+    spacing_trim: TextSpacingTrim(TextSpacingTrim),
+    /// Where extra space is inserted between characters.
+    autospace: TextAutospace(TextAutospace),
+  }
+}
+
+impl<'i> Parse<'i> for TextSpacing {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(TextSpacing {
+        spacing_trim: TextSpacingTrim::SpaceAll,
+        autospace: TextAutospace::NoAutospace,
+      });
+    }
+
+    let auto = input.try_parse(|input| {
+      input.expect_ident_matching("auto")?;
+      input.expect_exhausted()
+    });
+    if auto.is_ok() {
+      return Ok(TextSpacing {
+        spacing_trim: TextSpacingTrim::Auto,
+        autospace: TextAutospace::Auto,
+      });
+    }
+
+    let mut spacing_trim = None;
+    let mut autospace = None;
+    loop {
+      if spacing_trim.is_none() {
+        if let Ok(value) = input.try_parse(TextSpacingTrim::parse) {
+          spacing_trim = Some(value);
+          continue;
+        }
+      }
+
+      if autospace.is_none() {
+        if let Ok(value) = input.try_parse(TextAutospace::parse) {
+          autospace = Some(value);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if spacing_trim.is_none() && autospace.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(TextSpacing {
+      spacing_trim: spacing_trim.unwrap_or_default(),
+      autospace: autospace.unwrap_or(TextAutospace::Normal),
+    })
+  }
+}
+
+impl ToCss for TextSpacing {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match (&self.spacing_trim, &self.autospace) {
+      (TextSpacingTrim::SpaceAll, TextAutospace::NoAutospace) => dest.write_str("none"),
+      (TextSpacingTrim::Auto, TextAutospace::Auto) => dest.write_str("auto"),
+      // A single `auto` keyword would set both longhands, so the other value must be kept.
+      (TextSpacingTrim::Auto, _) | (_, TextAutospace::Auto) => {
+        self.spacing_trim.to_css(dest)?;
+        dest.write_char(' ')?;
+        self.autospace.to_css(dest)
+      }
+      (spacing_trim, TextAutospace::Normal) => spacing_trim.to_css(dest),
+      (TextSpacingTrim::Normal, autospace) => autospace.to_css(dest),
+      (spacing_trim, autospace) => {
+        spacing_trim.to_css(dest)?;
+        dest.write_char(' ')?;
+        autospace.to_css(dest)
+      }
+    }
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct TextSpacingHandler {
+  spacing_trim: Option<TextSpacingTrim>,
+  autospace: Option<TextAutospace>,
+  has_any: bool,
+}
+
+impl<'i> PropertyHandler<'i> for TextSpacingHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    macro_rules! property {
+      ($key: ident, $val: expr) => {{
+        // Keep the previous value as a fallback if the new one is not supported by all targets.
+        if self.$key.is_some() && matches!(context.targets.browsers, Some(targets) if !$val.is_compatible(targets)) {
+          self.flush(dest, context);
+        }
+        self.$key = Some($val.clone());
+        self.has_any = true;
+      }};
+    }
+
+    match property {
+      Property::TextSpacingTrim(val) => property!(spacing_trim, val),
+      Property::TextAutospace(val) => property!(autospace, val),
+      Property::TextSpacing(val) => {
+        property!(spacing_trim, &val.spacing_trim);
+        property!(autospace, &val.autospace);
+      }
+      Property::Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::TextSpacingTrim | PropertyId::TextAutospace | PropertyId::TextSpacing
+        ) =>
+      {
+        self.flush(dest, context);
+        let mut unparsed = val.clone();
+        context.add_unparsed_fallbacks(&mut unparsed);
+        dest.push(Property::Unparsed(unparsed));
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    self.flush(dest, context);
+  }
+}
+
+impl TextSpacingHandler {
+  fn flush(&mut self, dest: &mut DeclarationList, context: &mut PropertyHandlerContext) {
+    if !self.has_any {
+      return;
+    }
+
+    self.has_any = false;
+
+    let spacing_trim = std::mem::take(&mut self.spacing_trim);
+    let autospace = std::mem::take(&mut self.autospace);
+    match (spacing_trim, autospace) {
+      // The text-spacing shorthand is not yet supported by any browser, so only
+      // combine the longhands when no browser targets are specified.
+      (Some(spacing_trim), Some(autospace)) if context.targets.browsers.is_none() => {
+        dest.push(Property::TextSpacing(TextSpacing {
+          spacing_trim,
+          autospace,
+        }))
+      }
+      (spacing_trim, autospace) => {
+        if let Some(spacing_trim) = spacing_trim {
+          dest.push(Property::TextSpacingTrim(spacing_trim));
+        }
+        if let Some(autospace) = autospace {
+          dest.push(Property::TextAutospace(autospace));
+        }
+      }
+    }
+  }
+}
+
 /// A value for the [text-size-adjust](https://w3c.github.io/csswg-drafts/css-size-adjust/#adjustment-control) property.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
@@ -887,10 +1485,10 @@ impl ToCss for TextDecoration {
 }
 
 impl FallbackValues for TextDecoration {
-  fn get_fallbacks(&mut self, targets: Targets) -> Vec<Self> {
+  fn get_fallbacks(&mut self, targets: Targets, gamut_mapping: GamutMapping) -> Vec<Self> {
     self
       .color
-      .get_fallbacks(targets)
+      .get_fallbacks(targets, gamut_mapping)
       .into_iter()
       .map(|color| TextDecoration { color, ..self.clone() })
       .collect()
@@ -1083,10 +1681,10 @@ impl<'i> ToCss for TextEmphasis<'i> {
 }
 
 impl<'i> FallbackValues for TextEmphasis<'i> {
-  fn get_fallbacks(&mut self, targets: Targets) -> Vec<Self> {
+  fn get_fallbacks(&mut self, targets: Targets, gamut_mapping: GamutMapping) -> Vec<Self> {
     self
       .color
-      .get_fallbacks(targets)
+      .get_fallbacks(targets, gamut_mapping)
       .into_iter()
       .map(|color| TextEmphasis { color, ..self.clone() })
       .collect()
@@ -1273,14 +1871,16 @@ impl<'i> PropertyHandler<'i> for TextDecorationHandler<'i> {
       }
       Unparsed(val) if is_text_decoration_property(&val.property_id) => {
         self.finalize(dest, context);
-        let mut unparsed = val.get_prefixed(context.targets, Feature::TextDecoration);
+        let (mut unparsed, prefixed) = context.prefix_unparsed(val, Feature::TextDecoration);
         context.add_unparsed_fallbacks(&mut unparsed);
+        dest.extend(prefixed);
         dest.push(Property::Unparsed(unparsed))
       }
       Unparsed(val) if is_text_emphasis_property(&val.property_id) => {
         self.finalize(dest, context);
-        let mut unparsed = val.get_prefixed(context.targets, Feature::TextEmphasis);
+        let (mut unparsed, prefixed) = context.prefix_unparsed(val, Feature::TextEmphasis);
         context.add_unparsed_fallbacks(&mut unparsed);
+        dest.extend(prefixed);
         dest.push(Property::Unparsed(unparsed))
       }
       _ => return false,
@@ -1330,12 +1930,22 @@ impl<'i> PropertyHandler<'i> for TextDecorationHandler<'i> {
         {
           prefix = context.targets.prefixes(VendorPrefix::None, Feature::TextDecoration);
 
-          let fallbacks = decoration.get_fallbacks(context.targets);
-          for fallback in fallbacks {
-            dest.push(Property::TextDecoration(fallback, prefix))
-          }
+          let fallbacks = decoration.get_fallbacks(context.targets, context.gamut_mapping);
+          dest.extend(
+            context.fallbacks(
+              || Property::TextDecoration(decoration.clone(), prefix),
+              fallbacks
+                .into_iter()
+                .map(|fallback| Property::TextDecoration(fallback, prefix))
+                .collect(),
+            ),
+          );
         }
 
+        let (prefix, prefixed) = context.split_prefixes(intersection, prefix, |vp| {
+          Property::TextDecoration(decoration.clone(), vp)
+        });
+        dest.extend(prefixed);
         dest.push(Property::TextDecoration(decoration, prefix));
         line_vp.remove(intersection);
         style_vp.remove(intersection);
@@ -1352,11 +1962,19 @@ impl<'i> PropertyHandler<'i> for TextDecorationHandler<'i> {
           if !vp.is_empty() {
             let prefix = context.targets.prefixes(vp, Feature::$prop);
             if prefix.contains(VendorPrefix::None) {
-              let fallbacks = val.get_fallbacks(context.targets);
-              for fallback in fallbacks {
-                dest.push(Property::$prop(fallback, prefix))
-              }
+              let fallbacks = val.get_fallbacks(context.targets, context.gamut_mapping);
+              dest.extend(
+                context.fallbacks(
+                  || Property::$prop(val.clone(), prefix),
+                  fallbacks
+                    .into_iter()
+                    .map(|fallback| Property::$prop(fallback, prefix))
+                    .collect(),
+                ),
+              );
             }
+            let (prefix, prefixed) = context.split_prefixes(vp, prefix, |vp| Property::$prop(val.clone(), vp));
+            dest.extend(prefixed);
             dest.push(Property::$prop(val, prefix))
           }
         }
@@ -1368,6 +1986,8 @@ impl<'i> PropertyHandler<'i> for TextDecorationHandler<'i> {
         if let Some((val, vp)) = $key {
           if !vp.is_empty() {
             let prefix = context.targets.prefixes(vp, Feature::$prop);
+            let (prefix, prefixed) = context.split_prefixes(vp, prefix, |vp| Property::$prop(val.clone(), vp));
+            dest.extend(prefixed);
             dest.push(Property::$prop(val, prefix))
           }
         }
@@ -1406,12 +2026,21 @@ impl<'i> PropertyHandler<'i> for TextDecorationHandler<'i> {
         };
 
         if prefix.contains(VendorPrefix::None) {
-          let fallbacks = emphasis.get_fallbacks(context.targets);
-          for fallback in fallbacks {
-            dest.push(Property::TextEmphasis(fallback, prefix))
-          }
+          let fallbacks = emphasis.get_fallbacks(context.targets, context.gamut_mapping);
+          dest.extend(
+            context.fallbacks(
+              || Property::TextEmphasis(emphasis.clone(), prefix),
+              fallbacks
+                .into_iter()
+                .map(|fallback| Property::TextEmphasis(fallback, prefix))
+                .collect(),
+            ),
+          );
         }
 
+        let (prefix, prefixed) =
+          context.split_prefixes(intersection, prefix, |vp| Property::TextEmphasis(emphasis.clone(), vp));
+        dest.extend(prefixed);
         dest.push(Property::TextEmphasis(emphasis, prefix));
         style_vp.remove(intersection);
         color_vp.remove(intersection);
@@ -1428,6 +2057,9 @@ impl<'i> PropertyHandler<'i> for TextDecorationHandler<'i> {
         if pos.horizontal != TextEmphasisPositionHorizontal::Right {
           prefix = VendorPrefix::None;
         }
+        let (prefix, prefixed) =
+          context.split_prefixes(vp, prefix, |vp| Property::TextEmphasisPosition(pos.clone(), vp));
+        dest.extend(prefixed);
         dest.push(Property::TextEmphasisPosition(pos, prefix))
       }
     }
@@ -1561,7 +2193,7 @@ fn is_text_emphasis_property(property_id: &PropertyId) -> bool {
 }
 
 impl FallbackValues for SmallVec<[TextShadow; 1]> {
-  fn get_fallbacks(&mut self, targets: Targets) -> Vec<Self> {
+  fn get_fallbacks(&mut self, targets: Targets, gamut_mapping: GamutMapping) -> Vec<Self> {
     let mut fallbacks = ColorFallbackKind::empty();
     for shadow in self.iter() {
       fallbacks |= shadow.color.get_necessary_fallbacks(targets);
@@ -1572,7 +2204,7 @@ impl FallbackValues for SmallVec<[TextShadow; 1]> {
       let rgb = self
         .iter()
         .map(|shadow| TextShadow {
-          color: shadow.color.to_rgb().unwrap(),
+          color: shadow.color.to_rgb_with_gamut_mapping(gamut_mapping).unwrap(),
           ..shadow.clone()
         })
         .collect();
@@ -1627,3 +2259,395 @@ enum_property! {
     "plaintext": Plaintext,
   }
 }
+
+enum_property! {
+  /// A value for the [text-box-trim](https://drafts.csswg.org/css-inline-3/#text-box-trim) property.
+  #[derive(Default)]
+  pub enum TextBoxTrim {
+    /// No effect.
+    #[default]
+    "none": None,
+    /// The block-start side of the first formatted line is trimmed.
+    "trim-start": TrimStart,
+    /// The block-end side of the last formatted line is trimmed.
+    "trim-end": TrimEnd,
+    /// Both the start and end sides are trimmed.
+    "trim-both": TrimBoth,
+  }
+}
+
+impl IsCompatible for TextBoxTrim {
+  fn is_compatible(&self, _browsers: Browsers) -> bool {
+    true
+  }
+}
+
+enum_property! {
+  /// A metric used for the over (block-start) edge in a [`<text-edge>`](https://drafts.csswg.org/css-inline-3/#typedef-text-edge) value.
+  pub enum TextEdgeOver {
+    /// The text-over baseline.
+    "text": Text,
+    /// The ideographic-over baseline.
+    "ideographic": Ideographic,
+    /// The ideographic-ink-over baseline.
+    "ideographic-ink": IdeographicInk,
+    /// The cap-height baseline.
+    "cap": Cap,
+    /// The x-height baseline.
+    "ex": Ex,
+  }
+}
+
+enum_property! {
+  /// A metric used for the under (block-end) edge in a [`<text-edge>`](https://drafts.csswg.org/css-inline-3/#typedef-text-edge) value.
+  pub enum TextEdgeUnder {
+    /// The text-under baseline.
+    "text": Text,
+    /// The ideographic-under baseline.
+    "ideographic": Ideographic,
+    /// The ideographic-ink-under baseline.
+    "ideographic-ink": IdeographicInk,
+    /// The alphabetic baseline.
+    "alphabetic": Alphabetic,
+  }
+}
+
+impl TextEdgeOver {
+  /// Returns the under edge implied when only the over edge is specified.
+  fn implied_under(&self) -> TextEdgeUnder {
+    match self {
+      TextEdgeOver::Ideographic => TextEdgeUnder::Ideographic,
+      TextEdgeOver::IdeographicInk => TextEdgeUnder::IdeographicInk,
+      TextEdgeOver::Text | TextEdgeOver::Cap | TextEdgeOver::Ex => TextEdgeUnder::Text,
+    }
+  }
+}
+
+/// A [`<text-edge>`](https://drafts.csswg.org/css-inline-3/#typedef-text-edge) value,
+/// as used in the `text-box-edge` property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct TextEdge {
+  /// The metric used for the over edge.
+  pub over: TextEdgeOver,
+  /// The metric used for the under edge.
+  pub under: TextEdgeUnder,
+}
+
+impl<'i> Parse<'i> for TextEdge {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let over = TextEdgeOver::parse(input)?;
+    let under = input.try_parse(TextEdgeUnder::parse).unwrap_or_else(|_| over.implied_under());
+    Ok(TextEdge { over, under })
+  }
+}
+
+impl ToCss for TextEdge {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.over.to_css(dest)?;
+    if self.under != self.over.implied_under() {
+      dest.write_char(' ')?;
+      self.under.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+/// A value for the [text-box-edge](https://drafts.csswg.org/css-inline-3/#text-box-edge) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum TextBoxEdge {
+  /// The value of `line-fit-edge` is used.
+  #[default]
+  Auto,
+  /// The given text metrics are used.
+  Edge(TextEdge),
+}
+
+impl<'i> Parse<'i> for TextBoxEdge {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(TextBoxEdge::Auto);
+    }
+
+    Ok(TextBoxEdge::Edge(TextEdge::parse(input)?))
+  }
+}
+
+impl ToCss for TextBoxEdge {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      TextBoxEdge::Auto => dest.write_str("auto"),
+      TextBoxEdge::Edge(edge) => edge.to_css(dest),
+    }
+  }
+}
+
+impl IsCompatible for TextBoxEdge {
+  fn is_compatible(&self, _browsers: Browsers) -> bool {
+    true
+  }
+}
+
+define_shorthand! {
+  /// A value for the [text-box](https://drafts.csswg.org/css-inline-3/#text-box-shorthand) shorthand property.
+  pub struct TextBox {
+    /// The sides to trim.
+    trim: TextBoxTrim(TextBoxTrim),
+    /// The text metrics used to trim.
+    edge: TextBoxEdge(TextBoxEdge),
+  }
+}
+
+impl<'i> Parse<'i> for TextBox {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(TextBox {
+        trim: TextBoxTrim::None,
+        edge: TextBoxEdge::Auto,
+      });
+    }
+
+    let mut trim = None;
+    let mut edge = None;
+    loop {
+      if trim.is_none() {
+        if let Ok(value) = input.try_parse(TextBoxTrim::parse) {
+          trim = Some(value);
+          continue;
+        }
+      }
+
+      if edge.is_none() {
+        if let Ok(value) = input.try_parse(TextBoxEdge::parse) {
+          edge = Some(value);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if trim.is_none() && edge.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    // Unlike most shorthands, an omitted text-box-trim value is `trim-both` rather than the initial value.
+    Ok(TextBox {
+      trim: trim.unwrap_or(TextBoxTrim::TrimBoth),
+      edge: edge.unwrap_or_default(),
+    })
+  }
+}
+
+impl ToCss for TextBox {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match (&self.trim, &self.edge) {
+      (TextBoxTrim::None, TextBoxEdge::Auto) => dest.write_str("normal"),
+      (TextBoxTrim::TrimBoth, TextBoxEdge::Edge(edge)) => edge.to_css(dest),
+      (trim, TextBoxEdge::Auto) => trim.to_css(dest),
+      (trim, edge) => {
+        trim.to_css(dest)?;
+        dest.write_char(' ')?;
+        edge.to_css(dest)
+      }
+    }
+  }
+}
+
+shorthand_handler!(TextBoxHandler -> TextBox {
+  trim: TextBoxTrim(TextBoxTrim),
+  edge: TextBoxEdge(TextBoxEdge),
+});
+
+/// A value for the [initial-letter](https://drafts.csswg.org/css-inline-3/#sizing-drop-initials) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum InitialLetter {
+  /// No special initial-letter effect.
+  Normal,
+  /// The initial letter is sized and sunk by the given amounts.
+  Letter {
+    /// The size of the initial letter, in lines.
+    size: CSSNumber,
+    /// How far the initial letter sinks.
+    sink: InitialLetterSink,
+  },
+}
+
+/// The sink of an [initial-letter](https://drafts.csswg.org/css-inline-3/#sizing-drop-initials) value.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum InitialLetterSink {
+  /// The sink is equal to the size, floored.
+  Drop,
+  /// The initial letter is raised, with a sink of 1.
+  Raise,
+  /// An explicit number of lines to sink.
+  Integer(CSSInteger),
+}
+
+impl<'i> Parse<'i> for InitialLetter {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(InitialLetter::Normal);
+    }
+
+    let mut sink = input.try_parse(parse_initial_letter_keyword).ok();
+
+    let location = input.current_source_location();
+    let size = CSSNumber::parse(input)?;
+    if size < 1.0 {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+
+    if sink.is_none() {
+      let location = input.current_source_location();
+      if let Ok(integer) = input.try_parse(CSSInteger::parse) {
+        if integer < 1 {
+          return Err(location.new_custom_error(ParserError::InvalidValue));
+        }
+        sink = Some(InitialLetterSink::Integer(integer));
+      } else if let Ok(keyword) = input.try_parse(parse_initial_letter_keyword) {
+        sink = Some(keyword);
+      }
+    }
+
+    Ok(InitialLetter::Letter {
+      size,
+      sink: sink.unwrap_or(InitialLetterSink::Drop),
+    })
+  }
+}
+
+fn parse_initial_letter_keyword<'i>(
+  input: &mut Parser<'i, '_>,
+) -> Result<InitialLetterSink, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  let ident = input.expect_ident()?;
+  match_ignore_ascii_case! { ident,
+    "drop" => Ok(InitialLetterSink::Drop),
+    "raise" => Ok(InitialLetterSink::Raise),
+    _ => Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
+  }
+}
+
+impl ToCss for InitialLetter {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      InitialLetter::Normal => dest.write_str("normal"),
+      InitialLetter::Letter { size, sink } => {
+        size.to_css(dest)?;
+        match sink {
+          InitialLetterSink::Drop => Ok(()),
+          // An integer sink equal to the floored size is equivalent to `drop`.
+          InitialLetterSink::Integer(integer) if *integer == size.floor() as CSSInteger => Ok(()),
+          InitialLetterSink::Integer(integer) => {
+            dest.write_char(' ')?;
+            integer.to_css(dest)
+          }
+          InitialLetterSink::Raise => dest.write_str(" raise"),
+        }
+      }
+    }
+  }
+}
+
+enum_property! {
+  /// An alignment keyword for the [initial-letter-align](https://drafts.csswg.org/css-inline-3/#aligning-initial-letter) property.
+  pub enum InitialLetterAlignKeyword {
+    /// Use the alphabetic baseline and cap-height.
+    Alphabetic,
+    /// Use the ideographic character face bottom and top edges.
+    Ideographic,
+    /// Use the hanging baseline and the alphabetic baseline.
+    Hanging,
+    /// Use the over and under edges of the line box.
+    Leading,
+  }
+}
+
+/// A value for the [initial-letter-align](https://drafts.csswg.org/css-inline-3/#aligning-initial-letter) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct InitialLetterAlign {
+  /// Whether the border box of the initial letter is used for alignment rather than its glyph outline.
+  pub border_box: bool,
+  /// The alignment points to use.
+  pub alignment: Option<InitialLetterAlignKeyword>,
+}
+
+impl<'i> Parse<'i> for InitialLetterAlign {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let border_box = input.try_parse(|input| input.expect_ident_matching("border-box")).is_ok();
+    let alignment = input.try_parse(InitialLetterAlignKeyword::parse).ok();
+    if !border_box && alignment.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(InitialLetterAlign { border_box, alignment })
+  }
+}
+
+impl ToCss for InitialLetterAlign {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.border_box {
+      dest.write_str("border-box")?;
+    }
+
+    if let Some(alignment) = &self.alignment {
+      if self.border_box {
+        dest.write_char(' ')?;
+      }
+      alignment.to_css(dest)?;
+    }
+
+    Ok(())
+  }
+}
@@ -65,6 +65,7 @@ impl ToCss for TransformList {
         &mut base,
         PrinterOptions {
           minify: true,
+          precision: dest.precision,
           ..PrinterOptions::default()
         },
       ))?;
@@ -1532,6 +1533,7 @@ impl Translate {
       Translate::None => {
         Transform::Translate3d(LengthPercentage::zero(), LengthPercentage::zero(), Length::zero())
       }
+      Translate::XYZ { x, y, z } if z.is_zero() => Transform::Translate(x.clone(), y.clone()),
       Translate::XYZ { x, y, z } => Transform::Translate3d(x.clone(), y.clone(), z.clone()),
     }
   }
@@ -1620,6 +1622,9 @@ impl ToCss for Rotate {
 impl Rotate {
   /// Converts the rotation to a transform function.
   pub fn to_transform(&self) -> Transform {
+    if self.x == 0.0 && self.y == 0.0 && self.z == 1.0 {
+      return Transform::Rotate(self.angle.clone());
+    }
     Transform::Rotate3d(self.x, self.y, self.z, self.angle.clone())
   }
 }
@@ -1708,6 +1713,7 @@ impl Scale {
         NumberOrPercentage::Number(1.0),
         NumberOrPercentage::Number(1.0),
       ),
+      Scale::XYZ { x, y, z } if *z == NumberOrPercentage::Number(1.0) => Transform::Scale(x.clone(), y.clone()),
       Scale::XYZ { x, y, z } => Transform::Scale3d(x.clone(), y.clone(), z.clone()),
     }
   }
@@ -1731,17 +1737,6 @@ impl<'i> PropertyHandler<'i> for TransformHandler {
   ) -> bool {
     use Property::*;
 
-    macro_rules! individual_property {
-      ($prop: ident, $val: ident) => {
-        if let Some((transform, _)) = &mut self.transform {
-          transform.0.push($val.to_transform())
-        } else {
-          self.$prop = Some($val.clone());
-          self.has_any = true;
-        }
-      };
-    }
-
     match property {
       Transform(val, vp) => {
         // If two vendor prefixes for the same property have different
@@ -1760,14 +1755,21 @@ impl<'i> PropertyHandler<'i> for TransformHandler {
           self.transform = Some((val.clone(), *vp));
           self.has_any = true;
         }
-
-        self.translate = None;
-        self.rotate = None;
-        self.scale = None;
       }
-      Translate(val) => individual_property!(translate, val),
-      Rotate(val) => individual_property!(rotate, val),
-      Scale(val) => individual_property!(scale, val),
+      // The individual transform properties are independent of `transform`,
+      // so they do not override it or each other.
+      Translate(val) => {
+        self.translate = Some(val.clone());
+        self.has_any = true;
+      }
+      Rotate(val) => {
+        self.rotate = Some(val.clone());
+        self.has_any = true;
+      }
+      Scale(val) => {
+        self.scale = Some(val.clone());
+        self.has_any = true;
+      }
       Unparsed(val)
         if matches!(
           val.property_id,
@@ -1776,7 +1778,9 @@ impl<'i> PropertyHandler<'i> for TransformHandler {
       {
         self.flush(dest, context);
         let prop = if matches!(val.property_id, PropertyId::Transform(_)) {
-          Property::Unparsed(val.get_prefixed(context.targets, Feature::Transform))
+          let (unparsed, prefixed) = context.prefix_unparsed(val, Feature::Transform);
+          dest.extend(prefixed);
+          Property::Unparsed(unparsed)
         } else {
           property.clone()
         };
@@ -1801,13 +1805,50 @@ impl TransformHandler {
 
     self.has_any = false;
 
-    let transform = std::mem::take(&mut self.transform);
-    let translate = std::mem::take(&mut self.translate);
-    let rotate = std::mem::take(&mut self.rotate);
-    let scale = std::mem::take(&mut self.scale);
+    let mut transform = std::mem::take(&mut self.transform);
+    let mut translate = std::mem::take(&mut self.translate);
+    let mut rotate = std::mem::take(&mut self.rotate);
+    let mut scale = std::mem::take(&mut self.scale);
+
+    // If some targets do not support the individual transform properties, fold them into
+    // the transform list. They are applied before the transform functions, in the order
+    // translate, rotate, then scale.
+    let has_individual = translate.is_some() || rotate.is_some() || scale.is_some();
+    if has_individual && !context.targets.is_compatible(crate::compat::Feature::IndividualTransforms) {
+      let is_identity = matches!(translate, None | Some(Translate::None))
+        && rotate.as_ref().map_or(true, |rotate| rotate.angle.is_zero())
+        && matches!(scale, None | Some(Scale::None));
+
+      // Setting only `none` values has no effect in browsers that lack support, so
+      // avoid introducing a `transform` declaration that did not exist before.
+      if transform.is_some() || !is_identity {
+        let mut list = Vec::new();
+        if let Some(translate) = translate.take().filter(|t| *t != Translate::None) {
+          list.push(translate.to_transform());
+        }
+        if let Some(rotate) = rotate.take().filter(|r| !r.angle.is_zero()) {
+          list.push(rotate.to_transform());
+        }
+        if let Some(scale) = scale.take().filter(|s| *s != Scale::None) {
+          list.push(scale.to_transform());
+        }
+
+        let (list, prefix) = match transform.take() {
+          Some((transform, prefix)) => {
+            list.extend(transform.0);
+            (list, prefix)
+          }
+          None => (list, VendorPrefix::None),
+        };
+        transform = Some((TransformList(list), prefix));
+      }
+    }
 
     if let Some((transform, prefix)) = transform {
-      let prefix = context.targets.prefixes(prefix, Feature::Transform);
+      let prefixes = context.targets.prefixes(prefix, Feature::Transform);
+      let (prefix, prefixed) =
+        context.split_prefixes(prefix, prefixes, |vp| Property::Transform(transform.clone(), vp));
+      dest.extend(prefixed);
       dest.push(Property::Transform(transform, prefix))
     }
 
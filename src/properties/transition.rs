@@ -5,7 +5,7 @@ use crate::compat;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
-use crate::macros::define_list_shorthand;
+use crate::macros::{define_list_shorthand, enum_property};
 use crate::prefixes::Feature;
 use crate::printer::Printer;
 use crate::properties::masking::get_webkit_mask_property;
@@ -18,6 +18,16 @@ use cssparser::*;
 use itertools::izip;
 use smallvec::SmallVec;
 
+enum_property! {
+  /// A value for the [transition-behavior](https://drafts.csswg.org/css-transitions-2/#transition-behavior-property) property.
+  pub enum TransitionBehavior {
+    /// Transitions will not be started for discretely animated properties.
+    "normal": Normal,
+    /// Transitions will be started for discretely animated properties.
+    "allow-discrete": AllowDiscrete,
+  }
+}
+
 define_list_shorthand! {
   /// A value for the [transition](https://www.w3.org/TR/2018/WD-css-transitions-1-20181011/#transition-shorthand-property) property.
   pub struct Transition<'i>(VendorPrefix) {
@@ -30,6 +40,8 @@ define_list_shorthand! {
     delay: TransitionDelay(Time, VendorPrefix),
     /// The easing function for the transition.
     timing_function: TransitionTimingFunction(EasingFunction, VendorPrefix),
+    /// Whether the transition applies to discretely animated properties.
+    behavior: TransitionBehavior(TransitionBehavior),
   }
 }
 
@@ -39,6 +51,7 @@ impl<'i> Parse<'i> for Transition<'i> {
     let mut duration = None;
     let mut delay = None;
     let mut timing_function = None;
+    let mut behavior = None;
 
     loop {
       if duration.is_none() {
@@ -62,6 +75,14 @@ impl<'i> Parse<'i> for Transition<'i> {
         }
       }
 
+      // Parse the behavior before the property, since any identifier is a valid property name.
+      if behavior.is_none() {
+        if let Ok(value) = input.try_parse(TransitionBehavior::parse) {
+          behavior = Some(value);
+          continue;
+        }
+      }
+
       if property.is_none() {
         if let Ok(value) = input.try_parse(PropertyId::parse) {
           property = Some(value);
@@ -77,6 +98,7 @@ impl<'i> Parse<'i> for Transition<'i> {
       duration: duration.unwrap_or(Time::Seconds(0.0)),
       delay: delay.unwrap_or(Time::Seconds(0.0)),
       timing_function: timing_function.unwrap_or(EasingFunction::Ease),
+      behavior: behavior.unwrap_or(TransitionBehavior::Normal),
     })
   }
 }
@@ -102,6 +124,11 @@ impl<'i> ToCss for Transition<'i> {
       self.delay.to_css(dest)?;
     }
 
+    if self.behavior != TransitionBehavior::Normal {
+      dest.write_char(' ')?;
+      self.behavior.to_css(dest)?;
+    }
+
     Ok(())
   }
 }
@@ -112,6 +139,8 @@ pub(crate) struct TransitionHandler<'i> {
   durations: Option<(SmallVec<[Time; 1]>, VendorPrefix)>,
   delays: Option<(SmallVec<[Time; 1]>, VendorPrefix)>,
   timing_functions: Option<(SmallVec<[EasingFunction; 1]>, VendorPrefix)>,
+  behaviors: Option<SmallVec<[TransitionBehavior; 1]>>,
+  authored_prefixes: VendorPrefix,
   has_any: bool,
 }
 
@@ -139,6 +168,7 @@ impl<'i> PropertyHandler<'i> for TransitionHandler<'i> {
     macro_rules! property {
       ($feature: ident, $prop: ident, $val: expr, $vp: ident) => {{
         maybe_flush!($prop, $val, $vp);
+        self.authored_prefixes |= *$vp;
 
         // Otherwise, update the value and add the prefix.
         if let Some((val, prefixes)) = &mut self.$prop {
@@ -158,6 +188,10 @@ impl<'i> PropertyHandler<'i> for TransitionHandler<'i> {
       TransitionDuration(val, vp) => property!(TransitionDuration, durations, val, vp),
       TransitionDelay(val, vp) => property!(TransitionDelay, delays, val, vp),
       TransitionTimingFunction(val, vp) => property!(TransitionTimingFunction, timing_functions, val, vp),
+      TransitionBehavior(val) => {
+        self.behaviors = Some(val.clone());
+        self.has_any = true;
+      }
       Transition(val, vp) => {
         let properties: SmallVec<[PropertyId; 1]> = val.iter().map(|b| b.property.clone()).collect();
         maybe_flush!(properties, &properties, vp);
@@ -172,6 +206,8 @@ impl<'i> PropertyHandler<'i> for TransitionHandler<'i> {
           val.iter().map(|b| b.timing_function.clone()).collect();
         maybe_flush!(timing_functions, &timing_functions, vp);
 
+        self.behaviors = Some(val.iter().map(|b| b.behavior).collect());
+
         property!(TransitionProperty, properties, &properties, vp);
         property!(TransitionDuration, durations, &durations, vp);
         property!(TransitionDelay, delays, &delays, vp);
@@ -179,9 +215,9 @@ impl<'i> PropertyHandler<'i> for TransitionHandler<'i> {
       }
       Unparsed(val) if is_transition_property(&val.property_id) => {
         self.flush(dest, context);
-        dest.push(Property::Unparsed(
-          val.get_prefixed(context.targets, Feature::Transition),
-        ));
+        let (unparsed, prefixed) = context.prefix_unparsed(val, Feature::Transition);
+        dest.extend(prefixed);
+        dest.push(Property::Unparsed(unparsed));
       }
       _ => return false,
     }
@@ -206,6 +242,21 @@ impl<'i> TransitionHandler<'i> {
     let mut durations = std::mem::take(&mut self.durations);
     let mut delays = std::mem::take(&mut self.delays);
     let mut timing_functions = std::mem::take(&mut self.timing_functions);
+    let mut behaviors = std::mem::take(&mut self.behaviors);
+    let authored_prefixes = self.authored_prefixes;
+
+    // Prefixes that were not authored were added for the targets, and are passed through the fallback control hook.
+    macro_rules! push_prefixed {
+      ($prop: ident, $val: expr, $prefix: expr) => {{
+        let (prefix, prefixed) = context.split_prefixes($prefix & authored_prefixes, $prefix, |vp| {
+          Property::$prop($val.clone(), vp)
+        });
+        dest.extend(prefixed);
+        if !prefix.is_empty() {
+          dest.push(Property::$prop($val, prefix));
+        }
+      }};
+    }
 
     let rtl_properties = if let Some((properties, _)) = &mut properties {
       expand_properties(properties, context)
@@ -225,6 +276,24 @@ impl<'i> TransitionHandler<'i> {
       // prefixes will be handled by outputting individual properties below.
       let intersection = *property_prefixes & *duration_prefixes & *delay_prefixes & *timing_prefixes;
       if !intersection.is_empty() {
+        // transition-behavior is only supported in the unprefixed shorthand by newer browsers.
+        // Otherwise, output it as a separate longhand after the shorthand.
+        let behavior_property = match &behaviors {
+          Some(behaviors)
+            if behaviors.iter().any(|b| *b != TransitionBehavior::Normal)
+              && (intersection != VendorPrefix::None
+                || !context.targets.is_compatible(compat::Feature::TransitionBehavior)) =>
+          {
+            Some(Property::TransitionBehavior(behaviors.clone()))
+          }
+          _ => None,
+        };
+
+        let shorthand_behaviors = match &behaviors {
+          Some(behaviors) if behavior_property.is_none() => behaviors.as_slice(),
+          _ => &[],
+        };
+
         macro_rules! get_transitions {
           ($properties: ident) => {{
             // transition-property determines the number of transitions. The values of other
@@ -233,15 +302,18 @@ impl<'i> TransitionHandler<'i> {
             let mut durations_iter = durations.iter().cycle().cloned();
             let mut delays_iter = delays.iter().cycle().cloned();
             let mut timing_iter = timing_functions.iter().cycle().cloned();
+            let mut behaviors_iter = shorthand_behaviors.iter().cycle().cloned();
             for property_id in $properties {
               let duration = durations_iter.next().unwrap_or(Time::Seconds(0.0));
               let delay = delays_iter.next().unwrap_or(Time::Seconds(0.0));
               let timing_function = timing_iter.next().unwrap_or(EasingFunction::Ease);
+              let behavior = behaviors_iter.next().unwrap_or(TransitionBehavior::Normal);
               let transition = Transition {
                 property: property_id.clone(),
                 duration,
                 delay,
                 timing_function,
+                behavior,
               };
 
               // Expand vendor prefixes into multiple transitions.
@@ -264,13 +336,18 @@ impl<'i> TransitionHandler<'i> {
             Property::Transition(rtl_transitions, intersection),
           );
         } else {
-          dest.push(Property::Transition(transitions.clone(), intersection));
+          push_prefixed!(Transition, transitions, intersection);
         }
 
         property_prefixes.remove(intersection);
         duration_prefixes.remove(intersection);
         delay_prefixes.remove(intersection);
         timing_prefixes.remove(intersection);
+
+        if let Some(p) = behavior_property {
+          dest.push(p);
+        }
+        behaviors = None;
       }
     }
 
@@ -282,29 +359,33 @@ impl<'i> TransitionHandler<'i> {
             Property::TransitionProperty(rtl_properties, prefix),
           );
         } else {
-          dest.push(Property::TransitionProperty(properties, prefix));
+          push_prefixed!(TransitionProperty, properties, prefix);
         }
       }
     }
 
     if let Some((durations, prefix)) = durations {
       if !prefix.is_empty() {
-        dest.push(Property::TransitionDuration(durations, prefix));
+        push_prefixed!(TransitionDuration, durations, prefix);
       }
     }
 
     if let Some((delays, prefix)) = delays {
       if !prefix.is_empty() {
-        dest.push(Property::TransitionDelay(delays, prefix));
+        push_prefixed!(TransitionDelay, delays, prefix);
       }
     }
 
     if let Some((timing_functions, prefix)) = timing_functions {
       if !prefix.is_empty() {
-        dest.push(Property::TransitionTimingFunction(timing_functions, prefix));
+        push_prefixed!(TransitionTimingFunction, timing_functions, prefix);
       }
     }
 
+    if let Some(behaviors) = behaviors {
+      dest.push(Property::TransitionBehavior(behaviors));
+    }
+
     self.reset();
   }
 
@@ -313,6 +394,8 @@ impl<'i> TransitionHandler<'i> {
     self.durations = None;
     self.delays = None;
     self.timing_functions = None;
+    self.behaviors = None;
+    self.authored_prefixes = VendorPrefix::empty();
   }
 }
 
@@ -323,6 +406,7 @@ fn is_transition_property(property_id: &PropertyId) -> bool {
     | PropertyId::TransitionDuration(_)
     | PropertyId::TransitionDelay(_)
     | PropertyId::TransitionTimingFunction(_)
+    | PropertyId::TransitionBehavior
     | PropertyId::Transition(_) => true,
     _ => false,
   }
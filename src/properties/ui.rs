@@ -1,15 +1,17 @@
 //! CSS properties related to user interface.
 
 use crate::compat::Feature;
-use crate::context::PropertyHandlerContext;
+use crate::context::{DeclarationContext, PropertyHandlerContext};
 use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
-use crate::macros::{define_shorthand, enum_property, shorthand_property};
+use crate::macros::{define_shorthand, enum_property, shorthand_handler, shorthand_property};
 use crate::printer::Printer;
 use crate::properties::{Property, PropertyId};
-use crate::targets::{Browsers, Targets};
+use crate::selector::WebKitScrollbarPseudoElement;
+use crate::targets::{Browsers, Features, Targets};
 use crate::traits::{FallbackValues, IsCompatible, Parse, PropertyHandler, Shorthand, ToCss};
-use crate::values::color::CssColor;
+use crate::values::color::{CssColor, GamutMapping};
+use crate::values::length::LengthPercentage;
 use crate::values::number::CSSNumber;
 use crate::values::string::CowArcStr;
 use crate::values::url::Url;
@@ -20,6 +22,8 @@ use cssparser::*;
 use smallvec::SmallVec;
 
 use super::custom::Token;
+use super::display::{Display, DisplayKeyword};
+use super::size::Size;
 use super::{CustomProperty, CustomPropertyName, TokenList, TokenOrValue};
 
 enum_property! {
@@ -225,10 +229,10 @@ impl ToCss for ColorOrAuto {
 }
 
 impl FallbackValues for ColorOrAuto {
-  fn get_fallbacks(&mut self, targets: Targets) -> Vec<Self> {
+  fn get_fallbacks(&mut self, targets: Targets, gamut_mapping: GamutMapping) -> Vec<Self> {
     match self {
       ColorOrAuto::Color(color) => color
-        .get_fallbacks(targets)
+        .get_fallbacks(targets, gamut_mapping)
         .into_iter()
         .map(|color| ColorOrAuto::Color(color))
         .collect(),
@@ -266,6 +270,12 @@ impl Default for CaretShape {
   }
 }
 
+impl IsCompatible for CaretShape {
+  fn is_compatible(&self, _browsers: Browsers) -> bool {
+    true
+  }
+}
+
 shorthand_property! {
   /// A value for the [caret](https://www.w3.org/TR/2021/WD-css-ui-4-20210316/#caret) shorthand property.
   pub struct Caret {
@@ -277,10 +287,10 @@ shorthand_property! {
 }
 
 impl FallbackValues for Caret {
-  fn get_fallbacks(&mut self, targets: Targets) -> Vec<Self> {
+  fn get_fallbacks(&mut self, targets: Targets, gamut_mapping: GamutMapping) -> Vec<Self> {
     self
       .color
-      .get_fallbacks(targets)
+      .get_fallbacks(targets, gamut_mapping)
       .into_iter()
       .map(|color| Caret {
         color,
@@ -296,6 +306,11 @@ impl IsCompatible for Caret {
   }
 }
 
+shorthand_handler!(CaretHandler -> Caret fallbacks: true feature: CaretShorthand {
+  color: CaretColor(ColorOrAuto, fallback: true),
+  shape: CaretShape(CaretShape),
+});
+
 enum_property! {
   /// A value for the [user-select](https://www.w3.org/TR/2021/WD-css-ui-4-20210316/#content-selection) property.
   pub enum UserSelect {
@@ -312,6 +327,16 @@ enum_property! {
   }
 }
 
+enum_property! {
+  /// A value for the [field-sizing](https://drafts.csswg.org/css-ui-4/#field-sizing) property.
+  pub enum FieldSizing {
+    /// The element is sized to fit its contents.
+    Content,
+    /// The element has a fixed default size, regardless of its contents.
+    Fixed,
+  }
+}
+
 /// A value for the [appearance](https://www.w3.org/TR/2021/WD-css-ui-4-20210316/#appearance-switching) property.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
@@ -559,6 +584,26 @@ impl<'a> schemars::JsonSchema for ColorScheme {
   }
 }
 
+enum_property! {
+  /// A value for the nonstandard [-webkit-touch-callout](https://developer.mozilla.org/en-US/docs/Web/CSS/-webkit-touch-callout) property.
+  pub enum WebKitTouchCallout {
+    /// The default callout is displayed when the target is touched and held.
+    Default,
+    /// The callout is disabled.
+    None,
+  }
+}
+
+enum_property! {
+  /// A value for the nonstandard [-webkit-overflow-scrolling](https://developer.mozilla.org/en-US/docs/Web/CSS/-webkit-overflow-scrolling) property.
+  pub enum WebKitOverflowScrolling {
+    /// Regular scrolling, where the content immediately stops scrolling when the touch is removed.
+    Auto,
+    /// Momentum-based scrolling, where the content continues to scroll after the touch is removed.
+    Touch,
+  }
+}
+
 #[derive(Default)]
 pub(crate) struct ColorSchemeHandler;
 
@@ -595,6 +640,183 @@ impl<'i> PropertyHandler<'i> for ColorSchemeHandler {
   fn finalize(&mut self, _: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {}
 }
 
+enum_property! {
+  /// A value for the [scrollbar-width](https://drafts.csswg.org/css-scrollbars-1/#scrollbar-width) property.
+  pub enum ScrollbarWidth {
+    /// The default scrollbar width for the platform.
+    Auto,
+    /// A thin scrollbar, if available on the platform.
+    Thin,
+    /// The scrollbar is not displayed, but the element remains scrollable.
+    None,
+  }
+}
+
+/// A value for the [scrollbar-color](https://drafts.csswg.org/css-scrollbars-1/#scrollbar-color) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum ScrollbarColor {
+  /// The default scrollbar colors for the platform.
+  Auto,
+  /// Explicit scrollbar colors.
+  Colors {
+    /// The color of the scrollbar thumb.
+    thumb: CssColor,
+    /// The color of the scrollbar track.
+    track: CssColor,
+  },
+}
+
+impl<'i> Parse<'i> for ScrollbarColor {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(ScrollbarColor::Auto);
+    }
+
+    let thumb = CssColor::parse(input)?;
+    let track = CssColor::parse(input)?;
+    Ok(ScrollbarColor::Colors { thumb, track })
+  }
+}
+
+impl ToCss for ScrollbarColor {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ScrollbarColor::Auto => dest.write_str("auto"),
+      ScrollbarColor::Colors { thumb, track } => {
+        thumb.to_css(dest)?;
+        dest.write_char(' ')?;
+        track.to_css(dest)
+      }
+    }
+  }
+}
+
+impl IsCompatible for ScrollbarColor {
+  fn is_compatible(&self, browsers: Browsers) -> bool {
+    match self {
+      ScrollbarColor::Auto => Feature::ScrollbarColor.is_compatible(browsers),
+      ScrollbarColor::Colors { thumb, track } => {
+        Feature::ScrollbarColor.is_compatible(browsers)
+          && thumb.is_compatible(browsers)
+          && track.is_compatible(browsers)
+      }
+    }
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct ScrollbarHandler;
+
+impl<'i> PropertyHandler<'i> for ScrollbarHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    // Generating ::-webkit-scrollbar rules changes how scrollbars look in browsers that
+    // don't support the standard properties, so this is opt-in via the WebKitScrollbar feature.
+    let should_compile = |feature: Feature| {
+      context.context == DeclarationContext::StyleRule
+        && context.targets.include.contains(Features::WebKitScrollbar)
+        && !context
+          .targets
+          .browsers
+          .map_or(false, |browsers| feature.is_compatible(browsers))
+    };
+
+    match property {
+      Property::ScrollbarWidth(width) => {
+        if should_compile(Feature::ScrollbarWidth) {
+          match width {
+            ScrollbarWidth::Thin => {
+              let size = Size::LengthPercentage(LengthPercentage::px(8.0));
+              context
+                .add_webkit_scrollbar_rule(WebKitScrollbarPseudoElement::Scrollbar, Property::Width(size.clone()));
+              context.add_webkit_scrollbar_rule(WebKitScrollbarPseudoElement::Scrollbar, Property::Height(size));
+            }
+            ScrollbarWidth::None => context.add_webkit_scrollbar_rule(
+              WebKitScrollbarPseudoElement::Scrollbar,
+              Property::Display(Display::Keyword(DisplayKeyword::None)),
+            ),
+            ScrollbarWidth::Auto => {}
+          }
+        }
+      }
+      Property::ScrollbarColor(color) => {
+        if let ScrollbarColor::Colors { thumb, track } = color {
+          if should_compile(Feature::ScrollbarColor) {
+            context.add_webkit_scrollbar_rule(
+              WebKitScrollbarPseudoElement::Thumb,
+              Property::BackgroundColor(thumb.clone()),
+            );
+            context.add_webkit_scrollbar_rule(
+              WebKitScrollbarPseudoElement::Track,
+              Property::BackgroundColor(track.clone()),
+            );
+          }
+        }
+      }
+      _ => return false,
+    }
+
+    // Keep the standard property for browsers that support it.
+    dest.push(property.clone());
+    true
+  }
+
+  fn finalize(&mut self, _: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {}
+}
+
+#[derive(Default)]
+pub(crate) struct WebKitMobileHandler;
+
+impl<'i> PropertyHandler<'i> for WebKitMobileHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    _: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    let feature = match property {
+      Property::WebKitTapHighlightColor(..) => Feature::WebkitTapHighlightColor,
+      Property::WebKitTouchCallout(..) => Feature::WebkitTouchCallout,
+      Property::WebKitOverflowScrolling(..) => Feature::WebkitOverflowScrolling,
+      Property::Unparsed(val) => match val.property_id {
+        PropertyId::WebKitTapHighlightColor(_) => Feature::WebkitTapHighlightColor,
+        PropertyId::WebKitTouchCallout(_) => Feature::WebkitTouchCallout,
+        PropertyId::WebKitOverflowScrolling(_) => Feature::WebkitOverflowScrolling,
+        _ => return false,
+      },
+      _ => return false,
+    };
+
+    // These properties are only supported by a few mobile browsers.
+    // Drop them entirely if none of the targets support them.
+    if let Some(browsers) = context.targets.browsers {
+      let browsers = browsers.split();
+      if !browsers.is_empty() && !browsers.iter().any(|browsers| feature.is_compatible(*browsers)) {
+        return true;
+      }
+    }
+
+    false
+  }
+
+  fn finalize(&mut self, _: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {}
+}
+
 #[inline]
 fn define_var<'i>(name: &'static str, value: Token<'static>) -> Property<'i> {
   Property::Custom(CustomProperty {
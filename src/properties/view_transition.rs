@@ -0,0 +1,133 @@
+//! CSS properties related to view transitions.
+
+use crate::error::{ParserError, PrinterError};
+use crate::printer::Printer;
+use crate::traits::{Parse, ToCss};
+use crate::values::ident::CustomIdent;
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+use smallvec::SmallVec;
+
+/// A value for the [view-transition-name](https://drafts.csswg.org/css-view-transitions-1/#view-transition-name-prop) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum ViewTransitionName<'i> {
+  /// The element will not participate independently in a view transition.
+  None,
+  /// The user agent automatically generates a name for the element.
+  Auto,
+  /// The user agent generates a name for the element based on its identity.
+  MatchElement,
+  /// A custom name.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Custom(CustomIdent<'i>),
+}
+
+impl<'i> Parse<'i> for ViewTransitionName<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(ViewTransitionName::None);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(ViewTransitionName::Auto);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("match-element")).is_ok() {
+      return Ok(ViewTransitionName::MatchElement);
+    }
+
+    Ok(ViewTransitionName::Custom(CustomIdent::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for ViewTransitionName<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ViewTransitionName::None => dest.write_str("none"),
+      ViewTransitionName::Auto => dest.write_str("auto"),
+      ViewTransitionName::MatchElement => dest.write_str("match-element"),
+      ViewTransitionName::Custom(name) => name.to_css(dest),
+    }
+  }
+}
+
+/// A value for the [view-transition-class](https://drafts.csswg.org/css-view-transitions-2/#view-transition-class-prop) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum ViewTransitionClass<'i> {
+  /// The element has no view transition classes.
+  None,
+  /// A list of view transition classes.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Classes(SmallVec<[CustomIdent<'i>; 1]>),
+}
+
+impl<'i> Parse<'i> for ViewTransitionClass<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(ViewTransitionClass::None);
+    }
+
+    let mut classes = SmallVec::new();
+    while let Ok(class) = input.try_parse(parse_class) {
+      classes.push(class);
+    }
+
+    if classes.is_empty() {
+      Err(input.new_error_for_next_token())
+    } else {
+      Ok(ViewTransitionClass::Classes(classes))
+    }
+  }
+}
+
+fn parse_class<'i>(input: &mut Parser<'i, '_>) -> Result<CustomIdent<'i>, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  let class = CustomIdent::parse(input)?;
+  if class.0.eq_ignore_ascii_case("none") {
+    return Err(location.new_unexpected_token_error(Token::Ident("none".into())));
+  }
+  Ok(class)
+}
+
+impl<'i> ToCss for ViewTransitionClass<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ViewTransitionClass::None => dest.write_str("none"),
+      ViewTransitionClass::Classes(classes) => {
+        let mut first = true;
+        for class in classes {
+          if first {
+            first = false;
+          } else {
+            dest.write_char(' ')?;
+          }
+          class.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
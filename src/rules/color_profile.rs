@@ -0,0 +1,269 @@
+//! The `@color-profile` rule.
+
+use super::{remove_duplicate_descriptors, Location};
+use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
+use crate::printer::Printer;
+use crate::properties::custom::CustomProperty;
+use crate::stylesheet::ParserOptions;
+use crate::traits::{Parse, ToCss};
+use crate::values::ident::{DashedIdent, Ident};
+use crate::values::url::Url;
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+/// A [@color-profile](https://drafts.csswg.org/css-color-5/#at-profile) rule.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct ColorProfileRule<'i> {
+  /// The name of the color profile.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: ColorProfileName<'i>,
+  /// Declarations in the `@color-profile` rule.
+  pub properties: Vec<ColorProfileProperty<'i>>,
+  /// The location of the rule in the source file.
+  #[cfg_attr(feature = "visitor", skip_visit)]
+  pub loc: Location,
+}
+
+/// The name of an `@color-profile` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum ColorProfileName<'i> {
+  /// A custom color profile, referenced by the `color()` function.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Dashed(DashedIdent<'i>),
+  /// The `device-cmyk` profile, used by the `device-cmyk()` function.
+  DeviceCmyk,
+}
+
+impl<'i> Parse<'i> for ColorProfileName<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("device-cmyk")).is_ok() {
+      return Ok(ColorProfileName::DeviceCmyk);
+    }
+
+    Ok(ColorProfileName::Dashed(DashedIdent::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for ColorProfileName<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ColorProfileName::Dashed(name) => name.to_css(dest),
+      ColorProfileName::DeviceCmyk => dest.write_str("device-cmyk"),
+    }
+  }
+}
+
+/// A property within a `@color-profile` rule.
+///
+///  See [ColorProfileRule](ColorProfileRule).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum ColorProfileProperty<'i> {
+  /// The `src` property.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Src(Url<'i>),
+  /// The `rendering-intent` property.
+  RenderingIntent(RenderingIntent),
+  /// The `components` property.
+  Components(Vec<Ident<'i>>),
+  /// An unknown or unsupported property.
+  Custom(CustomProperty<'i>),
+}
+
+enum_property! {
+  /// A value for the [rendering-intent](https://drafts.csswg.org/css-color-5/#descdef-color-profile-rendering-intent)
+  /// property in a `@color-profile` rule.
+  pub enum RenderingIntent {
+    /// Colors are mapped relative to the media white point.
+    RelativeColorimetric,
+    /// Colors are mapped without adapting to the media white point.
+    AbsoluteColorimetric,
+    /// Colors are compressed to fit the destination gamut while preserving their relationships.
+    Perceptual,
+    /// Saturation is preserved at the expense of hue and lightness.
+    Saturation,
+  }
+}
+
+impl<'i> ColorProfileProperty<'i> {
+  fn name(&self) -> &str {
+    match self {
+      ColorProfileProperty::Src(..) => "src",
+      ColorProfileProperty::RenderingIntent(..) => "rendering-intent",
+      ColorProfileProperty::Components(..) => "components",
+      ColorProfileProperty::Custom(custom) => custom.name.as_ref(),
+    }
+  }
+}
+
+pub(crate) struct ColorProfileDeclarationParser;
+
+impl<'i> cssparser::DeclarationParser<'i> for ColorProfileDeclarationParser {
+  type Declaration = ColorProfileProperty<'i>;
+  type Error = ParserError<'i>;
+
+  fn parse_value<'t>(
+    &mut self,
+    name: CowRcStr<'i>,
+    input: &mut cssparser::Parser<'i, 't>,
+  ) -> Result<Self::Declaration, cssparser::ParseError<'i, Self::Error>> {
+    let state = input.state();
+    match_ignore_ascii_case! { &name,
+      "src" => {
+        // https://drafts.csswg.org/css-color-5/#descdef-color-profile-src
+        if let Ok(url) = Url::parse(input) {
+          return Ok(ColorProfileProperty::Src(url))
+        }
+      },
+      "rendering-intent" => {
+        // https://drafts.csswg.org/css-color-5/#descdef-color-profile-rendering-intent
+        if let Ok(intent) = RenderingIntent::parse(input) {
+          return Ok(ColorProfileProperty::RenderingIntent(intent))
+        }
+      },
+      "components" => {
+        // https://drafts.csswg.org/css-color-5/#descdef-color-profile-components
+        if let Ok(components) = input.parse_comma_separated(Ident::parse) {
+          return Ok(ColorProfileProperty::Components(components))
+        }
+      },
+      _ => return Err(input.new_custom_error(ParserError::InvalidDeclaration))
+    }
+
+    input.reset(&state);
+    Ok(ColorProfileProperty::Custom(CustomProperty::parse(
+      name.into(),
+      input,
+      &ParserOptions::default(),
+    )?))
+  }
+}
+
+/// Default methods reject all at rules.
+impl<'i> AtRuleParser<'i> for ColorProfileDeclarationParser {
+  type Prelude = ();
+  type AtRule = ColorProfileProperty<'i>;
+  type Error = ParserError<'i>;
+}
+
+impl<'i> QualifiedRuleParser<'i> for ColorProfileDeclarationParser {
+  type Prelude = ();
+  type QualifiedRule = ColorProfileProperty<'i>;
+  type Error = ParserError<'i>;
+}
+
+impl<'i> RuleBodyItemParser<'i, ColorProfileProperty<'i>, ParserError<'i>> for ColorProfileDeclarationParser {
+  fn parse_qualified(&self) -> bool {
+    false
+  }
+
+  fn parse_declarations(&self) -> bool {
+    true
+  }
+}
+
+impl<'i> ColorProfileRule<'i> {
+  pub(crate) fn parse<'t>(
+    name: ColorProfileName<'i>,
+    input: &mut Parser<'i, 't>,
+    loc: Location,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut decl_parser = ColorProfileDeclarationParser;
+    let parser = RuleBodyParser::new(input, &mut decl_parser);
+    let properties = parser.filter_map(Result::ok).collect();
+    Ok(ColorProfileRule { name, properties, loc })
+  }
+
+  pub(crate) fn minify(&mut self) {
+    remove_duplicate_descriptors(&mut self.properties, ColorProfileProperty::name);
+  }
+}
+
+impl<'i> ToCss for ColorProfileRule<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    #[cfg(feature = "sourcemap")]
+    dest.add_mapping(self.loc);
+    dest.write_str("@color-profile ")?;
+    self.name.to_css(dest)?;
+    dest.whitespace()?;
+    dest.write_char('{')?;
+    dest.indent();
+    let len = self.properties.len();
+    for (i, prop) in self.properties.iter().enumerate() {
+      dest.newline()?;
+      prop.to_css(dest)?;
+      if i != len - 1 || !dest.minify {
+        dest.write_char(';')?;
+      }
+    }
+    dest.dedent();
+    dest.newline()?;
+    dest.write_char('}')
+  }
+}
+
+impl<'i> ToCss for ColorProfileProperty<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    macro_rules! property {
+      ($prop: literal, $value: expr) => {{
+        dest.write_str($prop)?;
+        dest.delim(':', false)?;
+        $value.to_css(dest)
+      }};
+    }
+
+    match self {
+      ColorProfileProperty::Src(url) => property!("src", url),
+      ColorProfileProperty::RenderingIntent(intent) => property!("rendering-intent", intent),
+      ColorProfileProperty::Components(components) => {
+        dest.write_str("components")?;
+        dest.delim(':', false)?;
+        let mut first = true;
+        for component in components {
+          if first {
+            first = false;
+          } else {
+            dest.delim(',', false)?;
+          }
+          component.to_css(dest)?;
+        }
+        Ok(())
+      }
+      ColorProfileProperty::Custom(custom) => {
+        dest.write_str(custom.name.as_ref())?;
+        dest.delim(':', false)?;
+        custom.value.to_css(dest, true)
+      }
+    }
+  }
+}
@@ -196,6 +196,104 @@ impl<'i> QueryCondition<'i> for StyleQuery<'i> {
   }
 }
 
+impl<'i> ContainerCondition<'i> {
+  /// Simplifies the boolean structure of the condition, removing double negations and
+  /// duplicate conditions, and flattening nested operations. Operations consisting only
+  /// of style queries are combined into a single `style()` function.
+  fn simplify(self) -> Self {
+    match self {
+      ContainerCondition::Not(condition) => match condition.simplify() {
+        ContainerCondition::Not(condition) => *condition,
+        condition => ContainerCondition::Not(Box::new(condition)),
+      },
+      ContainerCondition::Operation { operator, conditions } => {
+        let mut res = Vec::with_capacity(conditions.len());
+        for condition in conditions {
+          match condition.simplify() {
+            ContainerCondition::Operation {
+              operator: op,
+              conditions,
+            } if op == operator => {
+              for condition in conditions {
+                push_unique(&mut res, condition);
+              }
+            }
+            condition => push_unique(&mut res, condition),
+          }
+        }
+
+        if res.len() == 1 {
+          return res.pop().unwrap();
+        }
+
+        // e.g. `style(--a: 1) and style(--b: 2)` -> `style((--a: 1) and (--b: 2))`
+        if res.iter().all(|condition| matches!(condition, ContainerCondition::Style(_))) {
+          let conditions = res
+            .into_iter()
+            .filter_map(|condition| match condition {
+              ContainerCondition::Style(query) => Some(query),
+              _ => None,
+            })
+            .collect();
+          return ContainerCondition::Style(StyleQuery::Operation { operator, conditions }.simplify());
+        }
+
+        ContainerCondition::Operation {
+          operator,
+          conditions: res,
+        }
+      }
+      ContainerCondition::Style(query) => ContainerCondition::Style(query.simplify()),
+      condition => condition,
+    }
+  }
+}
+
+impl<'i> StyleQuery<'i> {
+  /// Simplifies the boolean structure of the query, removing double negations and
+  /// duplicate conditions, and flattening nested operations.
+  fn simplify(self) -> Self {
+    match self {
+      StyleQuery::Not(query) => match query.simplify() {
+        StyleQuery::Not(query) => *query,
+        query => StyleQuery::Not(Box::new(query)),
+      },
+      StyleQuery::Operation { operator, conditions } => {
+        let mut res = Vec::with_capacity(conditions.len());
+        for query in conditions {
+          match query.simplify() {
+            StyleQuery::Operation {
+              operator: op,
+              conditions,
+            } if op == operator => {
+              for query in conditions {
+                push_unique(&mut res, query);
+              }
+            }
+            query => push_unique(&mut res, query),
+          }
+        }
+
+        if res.len() == 1 {
+          return res.pop().unwrap();
+        }
+
+        StyleQuery::Operation {
+          operator,
+          conditions: res,
+        }
+      }
+      query => query,
+    }
+  }
+}
+
+fn push_unique<T: PartialEq>(conditions: &mut Vec<T>, condition: T) {
+  if !conditions.contains(&condition) {
+    conditions.push(condition);
+  }
+}
+
 impl<'i> Parse<'i> for ContainerCondition<'i> {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     parse_query_condition(input, QueryConditionFlags::ALLOW_OR | QueryConditionFlags::ALLOW_STYLE)
@@ -278,6 +376,14 @@ impl<'i, T: Clone> ContainerRule<'i, T> {
     context: &mut MinifyContext<'_, 'i>,
     parent_is_unused: bool,
   ) -> Result<bool, MinifyError> {
+    let condition = std::mem::replace(
+      &mut self.condition,
+      ContainerCondition::Operation {
+        operator: Operator::And,
+        conditions: Vec::new(),
+      },
+    );
+    self.condition = condition.simplify();
     self.rules.minify(context, parent_is_unused)?;
     Ok(self.rules.0.is_empty())
   }
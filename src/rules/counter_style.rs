@@ -1,13 +1,19 @@
 //! The `@counter-style` rule.
 
-use super::Location;
-use crate::declaration::DeclarationBlock;
-use crate::error::PrinterError;
+use super::{remove_duplicate_descriptors, Location};
+use crate::error::{ParserError, PrinterError};
 use crate::printer::Printer;
-use crate::traits::ToCss;
+use crate::properties::custom::CustomProperty;
+use crate::properties::list::CounterStyle;
+use crate::stylesheet::ParserOptions;
+use crate::traits::{Parse, ToCss};
 use crate::values::ident::CustomIdent;
+use crate::values::image::Image;
+use crate::values::number::CSSInteger;
+use crate::values::string::CSSString;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
+use cssparser::*;
 
 /// A [@counter-style](https://drafts.csswg.org/css-counter-styles/#the-counter-style-rule) rule.
 #[derive(Debug, PartialEq, Clone)]
@@ -19,14 +25,751 @@ pub struct CounterStyleRule<'i> {
   /// The name of the counter style to declare.
   #[cfg_attr(feature = "serde", serde(borrow))]
   pub name: CustomIdent<'i>,
-  // TODO: eventually parse these properties
   /// Declarations in the `@counter-style` rule.
-  pub declarations: DeclarationBlock<'i>,
+  pub properties: Vec<CounterStyleProperty<'i>>,
   /// The location of the rule in the source file.
   #[cfg_attr(feature = "visitor", skip_visit)]
   pub loc: Location,
 }
 
+/// A property within a `@counter-style` rule.
+///
+///  See [CounterStyleRule](CounterStyleRule).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum CounterStyleProperty<'i> {
+  /// The `system` property.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  System(CounterSystem<'i>),
+  /// The `negative` property.
+  Negative(CounterNegative<'i>),
+  /// The `prefix` property.
+  Prefix(CounterSymbol<'i>),
+  /// The `suffix` property.
+  Suffix(CounterSymbol<'i>),
+  /// The `range` property.
+  Range(CounterRange),
+  /// The `pad` property.
+  Pad(CounterPad<'i>),
+  /// The `fallback` property.
+  Fallback(CounterStyle<'i>),
+  /// The `symbols` property.
+  Symbols(Vec<CounterSymbol<'i>>),
+  /// The `additive-symbols` property.
+  AdditiveSymbols(Vec<AdditiveSymbol<'i>>),
+  /// The `speak-as` property.
+  SpeakAs(SpeakAs<'i>),
+  /// An unknown or unsupported property.
+  Custom(CustomProperty<'i>),
+}
+
+/// A value for the [system](https://drafts.csswg.org/css-counter-styles/#counter-style-system)
+/// property in a `@counter-style` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum CounterSystem<'i> {
+  /// Cycles repeatedly through the provided symbols.
+  Cyclic,
+  /// Interprets the symbols as digits of a place-value numbering system.
+  Numeric,
+  /// Interprets the symbols as digits of an alphabetic numbering system.
+  Alphabetic,
+  /// Cycles repeatedly through the symbols, doubling them on each pass.
+  Symbolic,
+  /// Represents counter values using a sum of weighted symbols.
+  Additive,
+  /// Runs through the symbols once, with an optional first symbol value.
+  Fixed(Option<CSSInteger>),
+  /// Uses the algorithm of another counter style.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Extends(CounterStyle<'i>),
+}
+
+/// A [`<symbol>`](https://drafts.csswg.org/css-counter-styles/#typedef-symbol) value,
+/// as used in a `@counter-style` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum CounterSymbol<'i> {
+  /// A string.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  String(CSSString<'i>),
+  /// An identifier.
+  Ident(CustomIdent<'i>),
+  /// An image.
+  Image(Image<'i>),
+}
+
+/// A value for the [negative](https://drafts.csswg.org/css-counter-styles/#counter-style-negative)
+/// property in a `@counter-style` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct CounterNegative<'i> {
+  /// The symbol prepended to the representation of a negative counter value.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub prefix: CounterSymbol<'i>,
+  /// The symbol appended to the representation of a negative counter value.
+  pub suffix: Option<CounterSymbol<'i>>,
+}
+
+/// A value for the [range](https://drafts.csswg.org/css-counter-styles/#counter-style-range)
+/// property in a `@counter-style` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum CounterRange {
+  /// The range depends on the counter system.
+  Auto,
+  /// A list of ranges.
+  Ranges(Vec<CounterRangeItem>),
+}
+
+/// A single range within the [range](https://drafts.csswg.org/css-counter-styles/#counter-style-range) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct CounterRangeItem {
+  /// The lower bound of the range. `infinite` represents negative infinity.
+  pub start: CounterRangeBound,
+  /// The upper bound of the range. `infinite` represents positive infinity.
+  pub end: CounterRangeBound,
+}
+
+/// A bound within a [CounterRangeItem](CounterRangeItem).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum CounterRangeBound {
+  /// An infinite bound.
+  Infinite,
+  /// An integer bound.
+  Integer(CSSInteger),
+}
+
+/// A value for the [pad](https://drafts.csswg.org/css-counter-styles/#counter-style-pad)
+/// property in a `@counter-style` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct CounterPad<'i> {
+  /// The minimum length of the marker representation.
+  pub length: CSSInteger,
+  /// The symbol used for padding.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub symbol: CounterSymbol<'i>,
+}
+
+/// A weighted symbol within the [additive-symbols](https://drafts.csswg.org/css-counter-styles/#counter-style-symbols)
+/// property in a `@counter-style` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct AdditiveSymbol<'i> {
+  /// The weight of the symbol.
+  pub weight: CSSInteger,
+  /// The symbol.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub symbol: CounterSymbol<'i>,
+}
+
+/// A value for the [speak-as](https://drafts.csswg.org/css-counter-styles/#counter-style-speak-as)
+/// property in a `@counter-style` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum SpeakAs<'i> {
+  /// The speech synthesizer decides based on the counter system.
+  Auto,
+  /// A short, non-verbal sound is read.
+  Bullets,
+  /// The numeric value of the counter is read.
+  Numbers,
+  /// The generated counter representation is read as words.
+  Words,
+  /// The generated counter representation is spelled out letter by letter.
+  SpellOut,
+  /// The counter is read in the same way as another counter style.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  CounterStyle(CounterStyle<'i>),
+}
+
+/// Parses a [`<counter-style-name>`](https://drafts.csswg.org/css-counter-styles/#typedef-counter-style-name).
+fn parse_counter_style_name<'i>(
+  input: &mut Parser<'i, '_>,
+) -> Result<CounterStyle<'i>, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  match CounterStyle::parse(input)? {
+    CounterStyle::Symbols { .. } => Err(location.new_custom_error(ParserError::InvalidValue)),
+    CounterStyle::Name(name) if name.0.eq_ignore_ascii_case("none") => {
+      Err(location.new_unexpected_token_error(Token::Ident("none".into())))
+    }
+    name => Ok(name),
+  }
+}
+
+fn parse_non_negative_integer<'i>(
+  input: &mut Parser<'i, '_>,
+) -> Result<CSSInteger, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  let value = CSSInteger::parse(input)?;
+  if value.is_negative() {
+    return Err(location.new_custom_error(ParserError::InvalidValue));
+  }
+  Ok(value)
+}
+
+impl<'i> Parse<'i> for CounterSystem<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let ident = input.expect_ident()?;
+    match_ignore_ascii_case! { ident,
+      "cyclic" => Ok(CounterSystem::Cyclic),
+      "numeric" => Ok(CounterSystem::Numeric),
+      "alphabetic" => Ok(CounterSystem::Alphabetic),
+      "symbolic" => Ok(CounterSystem::Symbolic),
+      "additive" => Ok(CounterSystem::Additive),
+      "fixed" => Ok(CounterSystem::Fixed(input.try_parse(CSSInteger::parse).ok())),
+      "extends" => Ok(CounterSystem::Extends(parse_counter_style_name(input)?)),
+      _ => Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
+    }
+  }
+}
+
+impl<'i> ToCss for CounterSystem<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      CounterSystem::Cyclic => dest.write_str("cyclic"),
+      CounterSystem::Numeric => dest.write_str("numeric"),
+      CounterSystem::Alphabetic => dest.write_str("alphabetic"),
+      CounterSystem::Symbolic => dest.write_str("symbolic"),
+      CounterSystem::Additive => dest.write_str("additive"),
+      CounterSystem::Fixed(first) => {
+        dest.write_str("fixed")?;
+        if let Some(first) = first {
+          dest.write_char(' ')?;
+          first.to_css(dest)?;
+        }
+        Ok(())
+      }
+      CounterSystem::Extends(name) => {
+        dest.write_str("extends ")?;
+        name.to_css(dest)
+      }
+    }
+  }
+}
+
+impl<'i> Parse<'i> for CounterSymbol<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(s) = input.try_parse(CSSString::parse) {
+      return Ok(CounterSymbol::String(s));
+    }
+
+    if let Ok(ident) = input.try_parse(CustomIdent::parse) {
+      return Ok(CounterSymbol::Ident(ident));
+    }
+
+    Ok(CounterSymbol::Image(Image::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for CounterSymbol<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      CounterSymbol::String(s) => s.to_css(dest),
+      // Symbols are literal text, so they are never scoped by CSS modules.
+      CounterSymbol::Ident(ident) => ident.to_css_with_options(dest, false),
+      CounterSymbol::Image(image) => image.to_css(dest),
+    }
+  }
+}
+
+impl<'i> CounterSymbol<'i> {
+  /// Converts a string symbol to an identifier if it can be written without quotes or escapes.
+  fn minify(&mut self) {
+    if let CounterSymbol::String(s) = self {
+      let is_ident = {
+        let mut input = ParserInput::new(&s.0);
+        let mut parser = Parser::new(&mut input);
+        let valid = match parser.next_including_whitespace() {
+          Ok(Token::Ident(ident)) if ident.as_ref() == s.0.as_ref() => {
+            match_ignore_ascii_case! { ident,
+              "initial" | "inherit" | "unset" | "default" | "revert" | "revert-layer" => false,
+              _ => true
+            }
+          }
+          _ => false,
+        };
+        valid && parser.is_exhausted()
+      };
+
+      if is_ident {
+        *self = CounterSymbol::Ident(CustomIdent(s.0.clone()));
+      }
+    }
+  }
+}
+
+impl<'i> Parse<'i> for CounterNegative<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let prefix = CounterSymbol::parse(input)?;
+    let suffix = input.try_parse(CounterSymbol::parse).ok();
+    Ok(CounterNegative { prefix, suffix })
+  }
+}
+
+impl<'i> ToCss for CounterNegative<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match &self.suffix {
+      Some(suffix) => write_symbols(dest, [&self.prefix, suffix]),
+      None => self.prefix.to_css(dest),
+    }
+  }
+}
+
+/// Writes a space separated list of symbols. When minifying, whitespace is omitted next to strings.
+fn write_symbols<'a, 'i: 'a, W, I>(dest: &mut Printer<W>, symbols: I) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+  I: IntoIterator<Item = &'a CounterSymbol<'i>>,
+{
+  let mut prev: Option<&CounterSymbol> = None;
+  for symbol in symbols {
+    if let Some(prev) = prev {
+      let is_string = |s: &CounterSymbol| matches!(s, CounterSymbol::String(..));
+      if !dest.minify || !(is_string(prev) || is_string(symbol)) {
+        dest.write_char(' ')?;
+      }
+    }
+    symbol.to_css(dest)?;
+    prev = Some(symbol);
+  }
+  Ok(())
+}
+
+impl<'i> Parse<'i> for CounterRange {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(CounterRange::Auto);
+    }
+
+    let ranges = input.parse_comma_separated(CounterRangeItem::parse)?;
+    Ok(CounterRange::Ranges(ranges))
+  }
+}
+
+impl ToCss for CounterRange {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      CounterRange::Auto => dest.write_str("auto"),
+      CounterRange::Ranges(ranges) => {
+        let mut first = true;
+        for range in ranges {
+          if first {
+            first = false;
+          } else {
+            dest.delim(',', false)?;
+          }
+          range.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+impl<'i> Parse<'i> for CounterRangeItem {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let start = CounterRangeBound::parse(input)?;
+    let end = CounterRangeBound::parse(input)?;
+
+    // The lower bound must not be greater than the upper bound.
+    if let (CounterRangeBound::Integer(start), CounterRangeBound::Integer(end)) = (&start, &end) {
+      if start > end {
+        return Err(location.new_custom_error(ParserError::InvalidValue));
+      }
+    }
+
+    Ok(CounterRangeItem { start, end })
+  }
+}
+
+impl ToCss for CounterRangeItem {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.start.to_css(dest)?;
+    dest.write_char(' ')?;
+    self.end.to_css(dest)
+  }
+}
+
+impl<'i> Parse<'i> for CounterRangeBound {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("infinite")).is_ok() {
+      return Ok(CounterRangeBound::Infinite);
+    }
+
+    Ok(CounterRangeBound::Integer(CSSInteger::parse(input)?))
+  }
+}
+
+impl ToCss for CounterRangeBound {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      CounterRangeBound::Infinite => dest.write_str("infinite"),
+      CounterRangeBound::Integer(i) => i.to_css(dest),
+    }
+  }
+}
+
+impl<'i> Parse<'i> for CounterPad<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let (length, symbol) = parse_weighted_symbol(input)?;
+    Ok(CounterPad { length, symbol })
+  }
+}
+
+impl<'i> ToCss for CounterPad<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.length.to_css(dest)?;
+    dest.write_char(' ')?;
+    self.symbol.to_css(dest)
+  }
+}
+
+impl<'i> Parse<'i> for AdditiveSymbol<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let (weight, symbol) = parse_weighted_symbol(input)?;
+    Ok(AdditiveSymbol { weight, symbol })
+  }
+}
+
+impl<'i> ToCss for AdditiveSymbol<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.weight.to_css(dest)?;
+    dest.write_char(' ')?;
+    self.symbol.to_css(dest)
+  }
+}
+
+/// Parses `<integer [0,∞]> && <symbol>`.
+fn parse_weighted_symbol<'i>(
+  input: &mut Parser<'i, '_>,
+) -> Result<(CSSInteger, CounterSymbol<'i>), ParseError<'i, ParserError<'i>>> {
+  if let Ok(weight) = input.try_parse(parse_non_negative_integer) {
+    let symbol = CounterSymbol::parse(input)?;
+    return Ok((weight, symbol));
+  }
+
+  let symbol = CounterSymbol::parse(input)?;
+  let weight = parse_non_negative_integer(input)?;
+  Ok((weight, symbol))
+}
+
+fn parse_additive_symbols<'i>(
+  input: &mut Parser<'i, '_>,
+) -> Result<Vec<AdditiveSymbol<'i>>, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  let symbols = input.parse_comma_separated(AdditiveSymbol::parse)?;
+
+  // Weights must be in strictly descending order.
+  if symbols.windows(2).any(|w| w[0].weight <= w[1].weight) {
+    return Err(location.new_custom_error(ParserError::InvalidValue));
+  }
+
+  Ok(symbols)
+}
+
+impl<'i> Parse<'i> for SpeakAs<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(speak_as) = input.try_parse(parse_speak_as_keyword) {
+      return Ok(speak_as);
+    }
+
+    Ok(SpeakAs::CounterStyle(parse_counter_style_name(input)?))
+  }
+}
+
+fn parse_speak_as_keyword<'i>(input: &mut Parser<'i, '_>) -> Result<SpeakAs<'i>, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  let ident = input.expect_ident()?;
+  match_ignore_ascii_case! { ident,
+    "auto" => Ok(SpeakAs::Auto),
+    "bullets" => Ok(SpeakAs::Bullets),
+    "numbers" => Ok(SpeakAs::Numbers),
+    "words" => Ok(SpeakAs::Words),
+    "spell-out" => Ok(SpeakAs::SpellOut),
+    _ => Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
+  }
+}
+
+impl<'i> ToCss for SpeakAs<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      SpeakAs::Auto => dest.write_str("auto"),
+      SpeakAs::Bullets => dest.write_str("bullets"),
+      SpeakAs::Numbers => dest.write_str("numbers"),
+      SpeakAs::Words => dest.write_str("words"),
+      SpeakAs::SpellOut => dest.write_str("spell-out"),
+      SpeakAs::CounterStyle(name) => name.to_css(dest),
+    }
+  }
+}
+
+impl<'i> CounterStyleProperty<'i> {
+  fn name(&self) -> &str {
+    match self {
+      CounterStyleProperty::System(..) => "system",
+      CounterStyleProperty::Negative(..) => "negative",
+      CounterStyleProperty::Prefix(..) => "prefix",
+      CounterStyleProperty::Suffix(..) => "suffix",
+      CounterStyleProperty::Range(..) => "range",
+      CounterStyleProperty::Pad(..) => "pad",
+      CounterStyleProperty::Fallback(..) => "fallback",
+      CounterStyleProperty::Symbols(..) => "symbols",
+      CounterStyleProperty::AdditiveSymbols(..) => "additive-symbols",
+      CounterStyleProperty::SpeakAs(..) => "speak-as",
+      CounterStyleProperty::Custom(custom) => custom.name.as_ref(),
+    }
+  }
+}
+
+pub(crate) struct CounterStyleDeclarationParser;
+
+impl<'i> cssparser::DeclarationParser<'i> for CounterStyleDeclarationParser {
+  type Declaration = CounterStyleProperty<'i>;
+  type Error = ParserError<'i>;
+
+  fn parse_value<'t>(
+    &mut self,
+    name: CowRcStr<'i>,
+    input: &mut cssparser::Parser<'i, 't>,
+  ) -> Result<Self::Declaration, cssparser::ParseError<'i, Self::Error>> {
+    macro_rules! property {
+      ($property: ident, $parse: expr) => {
+        if let Ok(value) = input.try_parse(|input| {
+          let value = ($parse)(input)?;
+          input.expect_exhausted()?;
+          Ok::<_, ParseError<'i, ParserError<'i>>>(value)
+        }) {
+          return Ok(CounterStyleProperty::$property(value));
+        }
+      };
+    }
+
+    match_ignore_ascii_case! { &name,
+      // https://drafts.csswg.org/css-counter-styles/#counter-style-system
+      "system" => property!(System, CounterSystem::parse),
+      // https://drafts.csswg.org/css-counter-styles/#counter-style-negative
+      "negative" => property!(Negative, CounterNegative::parse),
+      // https://drafts.csswg.org/css-counter-styles/#counter-style-prefix
+      "prefix" => property!(Prefix, CounterSymbol::parse),
+      // https://drafts.csswg.org/css-counter-styles/#counter-style-suffix
+      "suffix" => property!(Suffix, CounterSymbol::parse),
+      // https://drafts.csswg.org/css-counter-styles/#counter-style-range
+      "range" => property!(Range, CounterRange::parse),
+      // https://drafts.csswg.org/css-counter-styles/#counter-style-pad
+      "pad" => property!(Pad, CounterPad::parse),
+      // https://drafts.csswg.org/css-counter-styles/#counter-style-fallback
+      "fallback" => property!(Fallback, parse_counter_style_name),
+      // https://drafts.csswg.org/css-counter-styles/#counter-style-symbols
+      "symbols" => property!(Symbols, |input: &mut Parser<'i, '_>| {
+        let mut symbols = vec![CounterSymbol::parse(input)?];
+        while let Ok(symbol) = input.try_parse(CounterSymbol::parse) {
+          symbols.push(symbol);
+        }
+        Ok::<_, ParseError<'i, ParserError<'i>>>(symbols)
+      }),
+      // https://drafts.csswg.org/css-counter-styles/#counter-style-symbols
+      "additive-symbols" => property!(AdditiveSymbols, parse_additive_symbols),
+      // https://drafts.csswg.org/css-counter-styles/#counter-style-speak-as
+      "speak-as" => property!(SpeakAs, SpeakAs::parse),
+      _ => return Err(input.new_custom_error(ParserError::InvalidDeclaration))
+    }
+
+    Ok(CounterStyleProperty::Custom(CustomProperty::parse(
+      name.into(),
+      input,
+      &ParserOptions::default(),
+    )?))
+  }
+}
+
+/// Default methods reject all at rules.
+impl<'i> AtRuleParser<'i> for CounterStyleDeclarationParser {
+  type Prelude = ();
+  type AtRule = CounterStyleProperty<'i>;
+  type Error = ParserError<'i>;
+}
+
+impl<'i> QualifiedRuleParser<'i> for CounterStyleDeclarationParser {
+  type Prelude = ();
+  type QualifiedRule = CounterStyleProperty<'i>;
+  type Error = ParserError<'i>;
+}
+
+impl<'i> RuleBodyItemParser<'i, CounterStyleProperty<'i>, ParserError<'i>> for CounterStyleDeclarationParser {
+  fn parse_qualified(&self) -> bool {
+    false
+  }
+
+  fn parse_declarations(&self) -> bool {
+    true
+  }
+}
+
+impl<'i> CounterStyleRule<'i> {
+  pub(crate) fn parse<'t>(
+    name: CustomIdent<'i>,
+    input: &mut Parser<'i, 't>,
+    loc: Location,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let mut decl_parser = CounterStyleDeclarationParser;
+    let parser = RuleBodyParser::new(input, &mut decl_parser);
+    let properties = parser.filter_map(Result::ok).collect();
+    let rule = CounterStyleRule { name, properties, loc };
+
+    if !rule.is_valid() {
+      return Err(location.new_custom_error(ParserError::AtRuleBodyInvalid));
+    }
+
+    Ok(rule)
+  }
+
+  fn system(&self) -> Option<&CounterSystem<'i>> {
+    self.properties.iter().rev().find_map(|p| match p {
+      CounterStyleProperty::System(system) => Some(system),
+      _ => None,
+    })
+  }
+
+  /// Returns whether the rule provides the symbols required by its counter system.
+  /// https://drafts.csswg.org/css-counter-styles/#counter-style-symbols
+  fn is_valid(&self) -> bool {
+    let symbols = self.properties.iter().rev().find_map(|p| match p {
+      CounterStyleProperty::Symbols(symbols) => Some(symbols.len()),
+      _ => None,
+    });
+    let additive_symbols = self.properties.iter().rev().find_map(|p| match p {
+      CounterStyleProperty::AdditiveSymbols(symbols) => Some(symbols.len()),
+      _ => None,
+    });
+
+    match self.system() {
+      None | Some(CounterSystem::Cyclic | CounterSystem::Symbolic | CounterSystem::Fixed(..)) => {
+        symbols.unwrap_or(0) >= 1
+      }
+      Some(CounterSystem::Alphabetic | CounterSystem::Numeric) => symbols.unwrap_or(0) >= 2,
+      Some(CounterSystem::Additive) => additive_symbols.unwrap_or(0) >= 1,
+      Some(CounterSystem::Extends(..)) => symbols.is_none() && additive_symbols.is_none(),
+    }
+  }
+
+  pub(crate) fn minify(&mut self) {
+    remove_duplicate_descriptors(&mut self.properties, CounterStyleProperty::name);
+
+    // Remove symbols that are not used by the counter system.
+    let is_additive = matches!(self.system(), Some(CounterSystem::Additive));
+    self.properties.retain(|p| match p {
+      CounterStyleProperty::Symbols(..) => !is_additive,
+      CounterStyleProperty::AdditiveSymbols(..) => is_additive,
+      _ => true,
+    });
+
+    for property in &mut self.properties {
+      match property {
+        CounterStyleProperty::System(CounterSystem::Fixed(first)) if *first == Some(1) => *first = None,
+        CounterStyleProperty::Negative(negative) => {
+          negative.prefix.minify();
+          if let Some(suffix) = &mut negative.suffix {
+            suffix.minify();
+          }
+        }
+        CounterStyleProperty::Prefix(symbol) | CounterStyleProperty::Suffix(symbol) => symbol.minify(),
+        CounterStyleProperty::Pad(pad) => pad.symbol.minify(),
+        CounterStyleProperty::Symbols(symbols) => symbols.iter_mut().for_each(CounterSymbol::minify),
+        CounterStyleProperty::AdditiveSymbols(symbols) => symbols.iter_mut().for_each(|s| s.symbol.minify()),
+        _ => {}
+      }
+    }
+  }
+}
+
 impl<'i> ToCss for CounterStyleRule<'i> {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
@@ -36,6 +779,69 @@ impl<'i> ToCss for CounterStyleRule<'i> {
     dest.add_mapping(self.loc);
     dest.write_str("@counter-style ")?;
     self.name.to_css(dest)?;
-    self.declarations.to_css_block(dest)
+    dest.whitespace()?;
+    dest.write_char('{')?;
+    dest.indent();
+    let len = self.properties.len();
+    for (i, prop) in self.properties.iter().enumerate() {
+      dest.newline()?;
+      prop.to_css(dest)?;
+      if i != len - 1 || !dest.minify {
+        dest.write_char(';')?;
+      }
+    }
+    dest.dedent();
+    dest.newline()?;
+    dest.write_char('}')
+  }
+}
+
+impl<'i> ToCss for CounterStyleProperty<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    macro_rules! property {
+      ($prop: literal, $value: expr) => {{
+        dest.write_str($prop)?;
+        dest.delim(':', false)?;
+        $value.to_css(dest)
+      }};
+    }
+
+    match self {
+      CounterStyleProperty::System(system) => property!("system", system),
+      CounterStyleProperty::Negative(negative) => property!("negative", negative),
+      CounterStyleProperty::Prefix(prefix) => property!("prefix", prefix),
+      CounterStyleProperty::Suffix(suffix) => property!("suffix", suffix),
+      CounterStyleProperty::Range(range) => property!("range", range),
+      CounterStyleProperty::Pad(pad) => property!("pad", pad),
+      CounterStyleProperty::Fallback(fallback) => property!("fallback", fallback),
+      CounterStyleProperty::Symbols(symbols) => {
+        dest.write_str("symbols")?;
+        dest.delim(':', false)?;
+        write_symbols(dest, symbols)
+      }
+      CounterStyleProperty::AdditiveSymbols(symbols) => {
+        dest.write_str("additive-symbols")?;
+        dest.delim(':', false)?;
+        let mut first = true;
+        for symbol in symbols {
+          if first {
+            first = false;
+          } else {
+            dest.delim(',', false)?;
+          }
+          symbol.to_css(dest)?;
+        }
+        Ok(())
+      }
+      CounterStyleProperty::SpeakAs(speak_as) => property!("speak-as", speak_as),
+      CounterStyleProperty::Custom(custom) => {
+        dest.write_str(custom.name.as_ref())?;
+        dest.delim(':', false)?;
+        custom.value.to_css(dest, true)
+      }
+    }
   }
 }
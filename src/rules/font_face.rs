@@ -1,12 +1,17 @@
 //! The `@font-face` rule.
 
-use super::Location;
+use super::mixin::applied_mixins_are_defined;
+use super::{CssRule, CssRuleList, Location};
+use crate::compat::Feature;
+use crate::declaration::DeclarationBlock;
 use crate::error::{ParserError, PrinterError};
-use crate::macros::enum_property;
-use crate::printer::Printer;
+use crate::macros::{enum_property, phase};
+use crate::printer::{Printer, PrinterOptions};
 use crate::properties::custom::CustomProperty;
 use crate::properties::font::{FontFamily, FontStretch, FontStyle as FontStyleProperty, FontWeight};
+use crate::properties::{Property, PropertyId};
 use crate::stylesheet::ParserOptions;
+use crate::targets::{Browsers, Targets};
 use crate::traits::{Parse, ToCss};
 use crate::values::angle::Angle;
 use crate::values::size::Size2D;
@@ -15,6 +20,7 @@ use crate::values::url::Url;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use cssparser::*;
+use std::collections::HashSet;
 use std::fmt::Write;
 
 /// A [@font-face](https://drafts.csswg.org/css-fonts/#font-face-rule) rule.
@@ -32,6 +38,247 @@ pub struct FontFaceRule<'i> {
   pub loc: Location,
 }
 
+impl<'i> FontFaceRule<'i> {
+  pub(crate) fn minify(&mut self, targets: &Targets) {
+    for property in &mut self.properties {
+      if let FontFaceProperty::Source(sources) = property {
+        minify_sources(sources, targets);
+      }
+    }
+  }
+}
+
+fn minify_sources(sources: &mut Vec<Source>, targets: &Targets) {
+  if let Some(browsers) = targets.browsers {
+    let browsers = browsers.split();
+
+    // Remove sources in formats that none of the targets can use,
+    // unless that would leave no sources at all.
+    if sources.iter().any(|source| source.is_supported_by_any(&browsers)) {
+      sources.retain(|source| source.is_supported_by_any(&browsers));
+    }
+
+    // Browsers use the first source they support, so order sources by preference.
+    // Internet Explorer < 9 requires the EOT source to come first, so don't reorder these.
+    if !sources
+      .iter()
+      .any(|source| source.format() == Some(&FontFormat::EmbeddedOpenType))
+    {
+      // Authors may deliberately order local() sources, so only reorder the url() sources between them.
+      for urls in sources.split_mut(|source| matches!(source, Source::Local(_))) {
+        urls.sort_by_key(|source| source.preference());
+      }
+    }
+
+    // If all targets support a format, the hint is not needed to skip the source.
+    for source in sources.iter_mut() {
+      if let Source::Url(url) = source {
+        if let Some(feature) = url.format.as_ref().and_then(|format| format.compat_feature()) {
+          if browsers.iter().all(|browsers| feature.is_compatible(*browsers)) {
+            url.format = None;
+          }
+        }
+      }
+    }
+  }
+
+  for source in sources.iter_mut() {
+    if let Source::Url(url) = source {
+      let mut techs = Vec::with_capacity(url.tech.len());
+      for tech in url.tech.drain(..) {
+        if !techs.contains(&tech) {
+          techs.push(tech);
+        }
+      }
+      url.tech = techs;
+    }
+  }
+
+  let mut unique = Vec::with_capacity(sources.len());
+  for source in sources.drain(..) {
+    if !unique.contains(&source) {
+      unique.push(source);
+    }
+  }
+  *sources = unique;
+}
+
+/// Removes `@font-face` rules that are identical to a later rule in the same rule list.
+/// When multiple rules match a font, the last one is used, so the later rule is kept.
+pub(crate) fn deduplicate_font_faces<R>(rules: &mut CssRuleList<R>) {
+  phase!("minify: deduplicate font faces");
+  // Rules are compared by their minified output, which does not include their location.
+  let mut seen = HashSet::new();
+  let mut duplicates = HashSet::new();
+  for (index, rule) in rules.0.iter().enumerate().rev() {
+    if let CssRule::FontFace(font_face) = rule {
+      let Ok(css) = font_face.to_css_string(PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      }) else {
+        continue;
+      };
+
+      if !seen.insert(css) {
+        duplicates.insert(index);
+      }
+    }
+  }
+
+  let mut index = 0;
+  rules.0.retain(|_| {
+    index += 1;
+    !duplicates.contains(&(index - 1))
+  });
+
+  for rule in &mut rules.0 {
+    match rule {
+      CssRule::Media(media) => deduplicate_font_faces(&mut media.rules),
+      CssRule::Supports(supports) => deduplicate_font_faces(&mut supports.rules),
+      CssRule::Container(container) => deduplicate_font_faces(&mut container.rules),
+      CssRule::LayerBlock(layer) => deduplicate_font_faces(&mut layer.rules),
+      CssRule::MozDocument(document) => deduplicate_font_faces(&mut document.rules),
+      CssRule::When(when) => {
+        deduplicate_font_faces(&mut when.rules);
+        for rule in &mut when.else_rules {
+          deduplicate_font_faces(&mut rule.rules);
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+/// Removes `@font-face` rules whose `font-family` is not referenced by any `font-family` or `font`
+/// declaration in the given rules, or included in the given list of used families. If any family
+/// cannot be determined statically, e.g. because it is defined using `var()`, no rules are removed.
+pub(crate) fn remove_unused_font_faces<R>(rules: &mut CssRuleList<R>, used_font_families: &HashSet<String>) {
+  phase!("minify: remove unused font faces");
+  let mut families: HashSet<String> = used_font_families.iter().map(|family| family.to_lowercase()).collect();
+  if collect_font_families(rules, &mut families) && applied_mixins_are_defined(rules) {
+    retain_font_faces(rules, &families);
+  }
+}
+
+fn collect_font_families<R>(rules: &CssRuleList<R>, families: &mut HashSet<String>) -> bool {
+  rules.0.iter().all(|rule| match rule {
+    CssRule::Style(style) => {
+      collect_declaration_font_families(&style.declarations, families)
+        && collect_font_families(&style.rules, families)
+    }
+    CssRule::Nesting(nesting) => {
+      collect_declaration_font_families(&nesting.style.declarations, families)
+        && collect_font_families(&nesting.style.rules, families)
+    }
+    CssRule::Media(media) => collect_font_families(&media.rules, families),
+    CssRule::Supports(supports) => collect_font_families(&supports.rules, families),
+    CssRule::Container(container) => collect_font_families(&container.rules, families),
+    CssRule::LayerBlock(layer) => collect_font_families(&layer.rules, families),
+    CssRule::MozDocument(document) => collect_font_families(&document.rules, families),
+    CssRule::Scope(scope) => collect_font_families(&scope.rules, families),
+    CssRule::StartingStyle(starting_style) => collect_font_families(&starting_style.rules, families),
+    CssRule::When(when) => {
+      collect_font_families(&when.rules, families)
+        && when.else_rules.iter().all(|rule| collect_font_families(&rule.rules, families))
+    }
+    CssRule::Mixin(mixin) => {
+      collect_declaration_font_families(&mixin.declarations, families)
+        && collect_font_families(&mixin.rules, families)
+    }
+    CssRule::Keyframes(keyframes) => keyframes
+      .keyframes
+      .iter()
+      .all(|keyframe| collect_declaration_font_families(&keyframe.declarations, families)),
+    CssRule::Page(page) => {
+      collect_declaration_font_families(&page.declarations, families)
+        && page
+          .rules
+          .iter()
+          .all(|rule| collect_declaration_font_families(&rule.declarations, families))
+    }
+    CssRule::Viewport(viewport) => collect_declaration_font_families(&viewport.declarations, families),
+    CssRule::PositionTry(position_try) => collect_declaration_font_families(&position_try.declarations, families),
+    // The bodies of applied mixins are checked where they are defined.
+    CssRule::Apply(_) => true,
+    // These rules define fonts or other named values, but do not use font families themselves.
+    CssRule::FontFace(_)
+    | CssRule::FontPaletteValues(_)
+    | CssRule::ColorProfile(_)
+    | CssRule::CounterStyle(_)
+    | CssRule::Property(_)
+    | CssRule::ViewTransition(_)
+    | CssRule::Function(_)
+    | CssRule::Namespace(_)
+    | CssRule::CustomMedia(_)
+    | CssRule::LayerStatement(_)
+    | CssRule::Ignored => true,
+    // Imported style sheets, unknown and custom at-rules may contain declarations we cannot see.
+    CssRule::Import(_) | CssRule::Unknown(_) | CssRule::Custom(_) => false,
+  })
+}
+
+fn collect_declaration_font_families(declarations: &DeclarationBlock, families: &mut HashSet<String>) -> bool {
+  let mut add = |list: &[FontFamily]| {
+    families.extend(list.iter().filter_map(|family| match family {
+      FontFamily::FamilyName(name) => Some(name.to_lowercase()),
+      FontFamily::Generic(_) => None,
+    }))
+  };
+
+  for (property, _) in declarations.iter() {
+    match property {
+      Property::FontFamily(list) => add(list),
+      Property::Font(font) => add(&font.family),
+      Property::Unparsed(unparsed)
+        if matches!(unparsed.property_id, PropertyId::FontFamily | PropertyId::Font) =>
+      {
+        return false
+      }
+      _ => {}
+    }
+  }
+
+  true
+}
+
+fn retain_font_faces<R>(rules: &mut CssRuleList<R>, families: &HashSet<String>) {
+  rules.0.retain_mut(|rule| match rule {
+    // Rules without a family name are invalid, so they are left as is.
+    CssRule::FontFace(font_face) => font_face.properties.iter().all(|property| match property {
+      FontFaceProperty::FontFamily(FontFamily::FamilyName(name)) => families.contains(&name.to_lowercase()),
+      _ => true,
+    }),
+    CssRule::Media(media) => {
+      retain_font_faces(&mut media.rules, families);
+      true
+    }
+    CssRule::Supports(supports) => {
+      retain_font_faces(&mut supports.rules, families);
+      true
+    }
+    CssRule::Container(container) => {
+      retain_font_faces(&mut container.rules, families);
+      true
+    }
+    CssRule::LayerBlock(layer) => {
+      retain_font_faces(&mut layer.rules, families);
+      true
+    }
+    CssRule::MozDocument(document) => {
+      retain_font_faces(&mut document.rules, families);
+      true
+    }
+    CssRule::When(when) => {
+      retain_font_faces(&mut when.rules, families);
+      for rule in &mut when.else_rules {
+        retain_font_faces(&mut rule.rules, families);
+      }
+      true
+    }
+    _ => true,
+  });
+}
+
 /// A property within an `@font-face` rule.
 ///
 /// See [FontFaceRule](FontFaceRule).
@@ -81,6 +328,37 @@ pub enum Source<'i> {
   Local(FontFamily<'i>),
 }
 
+impl<'i> Source<'i> {
+  fn format(&self) -> Option<&FontFormat<'i>> {
+    match self {
+      Source::Url(url) => url.format.as_ref(),
+      Source::Local(_) => None,
+    }
+  }
+
+  fn is_supported_by_any(&self, browsers: &[Browsers]) -> bool {
+    match self.format().and_then(|format| format.compat_feature()) {
+      Some(feature) => browsers.iter().any(|browsers| feature.is_compatible(*browsers)),
+      None => true,
+    }
+  }
+
+  /// Returns the sort order of a url() source. Smaller and more widely
+  /// supported formats come first.
+  fn preference(&self) -> u8 {
+    match self {
+      Source::Local(_) => 0,
+      Source::Url(url) => match url.format {
+        Some(FontFormat::WOFF2) => 1,
+        Some(FontFormat::WOFF) => 2,
+        Some(FontFormat::EmbeddedOpenType) => 4,
+        Some(FontFormat::SVG) => 5,
+        _ => 3,
+      },
+    }
+  }
+}
+
 impl<'i> Parse<'i> for Source<'i> {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     match input.try_parse(UrlSource::parse) {
@@ -210,6 +488,18 @@ pub enum FontFormat<'i> {
   String(CowArcStr<'i>),
 }
 
+impl<'i> FontFormat<'i> {
+  pub(crate) fn compat_feature(&self) -> Option<Feature> {
+    match self {
+      FontFormat::WOFF => Some(Feature::FontFormatWoff),
+      FontFormat::WOFF2 => Some(Feature::FontFormatWoff2),
+      FontFormat::EmbeddedOpenType => Some(Feature::FontFormatEmbeddedOpenType),
+      FontFormat::SVG => Some(Feature::FontFormatSvg),
+      _ => None,
+    }
+  }
+}
+
 impl<'i> Parse<'i> for FontFormat<'i> {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     let s = input.expect_ident_or_string()?;
@@ -231,8 +521,18 @@ impl<'i> ToCss for FontFormat<'i> {
   where
     W: std::fmt::Write,
   {
+    // Browser support for keywords rather than strings is very limited.
+    // https://developer.mozilla.org/en-US/docs/Web/CSS/@font-face/src
+    serialize_string(self.as_str(), dest)?;
+    Ok(())
+  }
+}
+
+impl<'i> FontFormat<'i> {
+  /// Returns the font format as a string.
+  pub fn as_str(&self) -> &str {
     use FontFormat::*;
-    let s = match self {
+    match self {
       WOFF => "woff",
       WOFF2 => "woff2",
       TrueType => "truetype",
@@ -241,11 +541,7 @@ impl<'i> ToCss for FontFormat<'i> {
       Collection => "collection",
       SVG => "svg",
       String(s) => &s,
-    };
-    // Browser support for keywords rather than strings is very limited.
-    // https://developer.mozilla.org/en-US/docs/Web/CSS/@font-face/src
-    serialize_string(&s, dest)?;
-    Ok(())
+    }
   }
 }
 
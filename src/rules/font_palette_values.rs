@@ -9,7 +9,7 @@ use crate::properties::font::FontFamily;
 use crate::stylesheet::ParserOptions;
 use crate::targets::Targets;
 use crate::traits::{Parse, ToCss};
-use crate::values::color::{ColorFallbackKind, CssColor};
+use crate::values::color::{ColorFallbackKind, CssColor, GamutMapping};
 use crate::values::ident::DashedIdent;
 use crate::values::number::CSSInteger;
 #[cfg(feature = "visitor")]
@@ -86,9 +86,9 @@ pub enum BasePalette {
 #[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
 pub struct OverrideColors {
   /// The index of the color within the palette to override.
-  index: u16,
+  pub index: u16,
   /// The replacement color.
-  color: CssColor,
+  pub color: CssColor,
 }
 
 pub(crate) struct FontPaletteValuesDeclarationParser;
@@ -244,10 +244,10 @@ impl ToCss for OverrideColors {
 }
 
 impl OverrideColors {
-  fn get_fallback(&self, kind: ColorFallbackKind) -> OverrideColors {
+  fn get_fallback(&self, kind: ColorFallbackKind, gamut_mapping: GamutMapping) -> OverrideColors {
     OverrideColors {
       index: self.index,
-      color: self.color.get_fallback(kind),
+      color: self.color.get_fallback_with_gamut_mapping(kind, gamut_mapping),
     }
   }
 }
@@ -266,18 +266,27 @@ impl<'i> FontPaletteValuesRule<'i> {
 
           if fallbacks.contains(ColorFallbackKind::RGB) {
             properties.push(FontPaletteValuesProperty::OverrideColors(
-              override_colors.iter().map(|o| o.get_fallback(ColorFallbackKind::RGB)).collect(),
+              override_colors
+                .iter()
+                .map(|o| o.get_fallback(ColorFallbackKind::RGB, context.handler_context.gamut_mapping))
+                .collect(),
             ));
           }
 
           if fallbacks.contains(ColorFallbackKind::P3) {
             properties.push(FontPaletteValuesProperty::OverrideColors(
-              override_colors.iter().map(|o| o.get_fallback(ColorFallbackKind::P3)).collect(),
+              override_colors
+                .iter()
+                .map(|o| o.get_fallback(ColorFallbackKind::P3, context.handler_context.gamut_mapping))
+                .collect(),
             ));
           }
 
           let override_colors = if fallbacks.contains(ColorFallbackKind::LAB) {
-            override_colors.iter().map(|o| o.get_fallback(ColorFallbackKind::P3)).collect()
+            override_colors
+              .iter()
+              .map(|o| o.get_fallback(ColorFallbackKind::P3, context.handler_context.gamut_mapping))
+              .collect()
           } else {
             override_colors.clone()
           };
@@ -291,7 +300,7 @@ impl<'i> FontPaletteValuesRule<'i> {
     self.properties = properties;
   }
 
-  pub(crate) fn get_fallbacks<T>(&mut self, targets: Targets) -> Vec<CssRule<'i, T>> {
+  pub(crate) fn get_fallbacks<T>(&mut self, targets: Targets, gamut_mapping: GamutMapping) -> Vec<CssRule<'i, T>> {
     // Get fallbacks for unparsed properties. These will generate @supports rules
     // containing duplicate @font-palette-values rules.
     let mut fallbacks = ColorFallbackKind::empty();
@@ -309,20 +318,20 @@ impl<'i> FontPaletteValuesRule<'i> {
     fallbacks.remove(lowest_fallback);
 
     if fallbacks.contains(ColorFallbackKind::P3) {
-      res.push(self.get_fallback(ColorFallbackKind::P3));
+      res.push(self.get_fallback(ColorFallbackKind::P3, gamut_mapping));
     }
 
     if fallbacks.contains(ColorFallbackKind::LAB)
       || (!lowest_fallback.is_empty() && lowest_fallback != ColorFallbackKind::LAB)
     {
-      res.push(self.get_fallback(ColorFallbackKind::LAB));
+      res.push(self.get_fallback(ColorFallbackKind::LAB, gamut_mapping));
     }
 
     if !lowest_fallback.is_empty() {
       for property in &mut self.properties {
         match property {
           FontPaletteValuesProperty::Custom(CustomProperty { value, .. }) => {
-            *value = value.get_fallback(lowest_fallback);
+            *value = value.get_fallback(lowest_fallback, gamut_mapping);
           }
           _ => {}
         }
@@ -332,14 +341,14 @@ impl<'i> FontPaletteValuesRule<'i> {
     res
   }
 
-  fn get_fallback<T>(&self, kind: ColorFallbackKind) -> CssRule<'i, T> {
+  fn get_fallback<T>(&self, kind: ColorFallbackKind, gamut_mapping: GamutMapping) -> CssRule<'i, T> {
     let properties = self
       .properties
       .iter()
       .map(|property| match property {
         FontPaletteValuesProperty::Custom(custom) => FontPaletteValuesProperty::Custom(CustomProperty {
           name: custom.name.clone(),
-          value: custom.value.get_fallback(kind),
+          value: custom.value.get_fallback(kind, gamut_mapping),
         }),
         _ => property.clone(),
       })
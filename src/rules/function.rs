@@ -0,0 +1,315 @@
+//! The `@function` rule.
+
+use super::Location;
+use crate::declaration::DeclarationBlock;
+use crate::error::{ParserError, PrinterError};
+use crate::printer::Printer;
+use crate::properties::custom::{CustomProperty, TokenList};
+use crate::properties::Property;
+use crate::stylesheet::ParserOptions;
+use crate::traits::{Parse, ToCss};
+use crate::values::ident::DashedIdent;
+use crate::values::syntax::SyntaxString;
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+/// A [@function](https://drafts.csswg.org/css-mixins-1/#function-rule) rule.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct FunctionRule<'i> {
+  /// The name of the custom function.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: DashedIdent<'i>,
+  /// The parameters accepted by the function.
+  pub parameters: Vec<FunctionParameter<'i>>,
+  /// The type returned by the function, if specified.
+  #[cfg_attr(feature = "visitor", skip_visit)]
+  pub returns: Option<SyntaxString>,
+  /// The declarations within the `@function` rule, such as `result` and local variables.
+  /// These are preserved as raw tokens, since they may reference the function's parameters.
+  pub declarations: DeclarationBlock<'i>,
+  /// The location of the rule in the source file.
+  #[cfg_attr(feature = "visitor", skip_visit)]
+  pub loc: Location,
+}
+
+/// A [function parameter](https://drafts.csswg.org/css-mixins-1/#typedef-function-parameter),
+/// as used in the `@function` and `@mixin` rules.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct FunctionParameter<'i> {
+  /// The name of the parameter.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: DashedIdent<'i>,
+  /// The type of the parameter, if specified.
+  #[cfg_attr(feature = "visitor", skip_visit)]
+  pub syntax: Option<SyntaxString>,
+  /// The default value of the parameter, if specified.
+  pub default_value: Option<TokenList<'i>>,
+}
+
+impl<'i> FunctionParameter<'i> {
+  /// Parses a comma separated list of parameters, e.g. within the parentheses of a `@function` prelude.
+  pub(crate) fn parse_list<'t>(
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions<'_, 'i>,
+  ) -> Result<Vec<Self>, ParseError<'i, ParserError<'i>>> {
+    if input.is_exhausted() {
+      return Ok(Vec::new());
+    }
+
+    input.parse_comma_separated(|input| FunctionParameter::parse(input, options))
+  }
+
+  fn parse<'t>(
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions<'_, 'i>,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let name = DashedIdent::parse(input)?;
+    let syntax = input.try_parse(parse_css_type).ok();
+    let default_value = if input.try_parse(|input| input.expect_colon()).is_ok() {
+      Some(TokenList::parse(input, options, 0)?)
+    } else {
+      None
+    };
+    input.expect_exhausted()?;
+    Ok(FunctionParameter {
+      name,
+      syntax,
+      default_value,
+    })
+  }
+}
+
+/// Parses a [`<css-type>`](https://drafts.csswg.org/css-mixins-1/#typedef-css-type), i.e. either a
+/// single syntax component such as `<length>`, or a `type()` function containing a full syntax.
+fn parse_css_type<'i>(input: &mut Parser<'i, '_>) -> Result<SyntaxString, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  if input.try_parse(|input| input.expect_function_matching("type")).is_ok() {
+    return input.parse_nested_block(|input| {
+      let start = input.position();
+      while input.next().is_ok() {}
+      SyntaxString::parse_string(input.slice_from(start))
+        .map_err(|_| location.new_custom_error(ParserError::InvalidValue))
+    });
+  }
+
+  let start = input.position();
+  if input.try_parse(|input| input.expect_delim('*')).is_err() {
+    if input.try_parse(|input| input.expect_delim('<')).is_ok() {
+      input.expect_ident()?;
+      input.expect_delim('>')?;
+    } else {
+      input.expect_ident()?;
+    }
+
+    // A multiplier may not include whitespace before it.
+    let state = input.state();
+    match input.next_including_whitespace() {
+      Ok(Token::Delim('+')) | Ok(Token::Delim('#')) => {}
+      _ => input.reset(&state),
+    }
+  }
+
+  SyntaxString::parse_string(input.slice_from(start))
+    .map_err(|_| location.new_custom_error(ParserError::InvalidValue))
+}
+
+/// Serializes a [`<css-type>`](https://drafts.csswg.org/css-mixins-1/#typedef-css-type).
+/// Unlike in `@property` rules, the syntax is not quoted.
+fn write_css_type<W>(syntax: &SyntaxString, dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  match syntax {
+    SyntaxString::Universal => dest.write_char('*'),
+    SyntaxString::Components(components) if components.len() == 1 => components[0].to_css(dest),
+    SyntaxString::Components(components) => {
+      dest.write_str("type(")?;
+      let mut first = true;
+      for component in components {
+        if first {
+          first = false;
+        } else {
+          dest.delim('|', true)?;
+        }
+        component.to_css(dest)?;
+      }
+      dest.write_char(')')
+    }
+  }
+}
+
+/// Serializes a list of function parameters, including the surrounding parentheses.
+pub(crate) fn write_parameters<W>(
+  parameters: &[FunctionParameter],
+  dest: &mut Printer<W>,
+) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  dest.write_char('(')?;
+  let mut first = true;
+  for parameter in parameters {
+    if first {
+      first = false;
+    } else {
+      dest.delim(',', false)?;
+    }
+    parameter.name.to_css(dest)?;
+    if let Some(syntax) = &parameter.syntax {
+      dest.write_char(' ')?;
+      write_css_type(syntax, dest)?;
+    }
+    if let Some(default_value) = &parameter.default_value {
+      dest.delim(':', false)?;
+      default_value.to_css(dest, true)?;
+    }
+  }
+  dest.write_char(')')
+}
+
+impl<'i> FunctionRule<'i> {
+  pub(crate) fn parse_prelude<'t>(
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions<'_, 'i>,
+  ) -> Result<(DashedIdent<'i>, Vec<FunctionParameter<'i>>, Option<SyntaxString>), ParseError<'i, ParserError<'i>>>
+  {
+    // The name and opening parenthesis form a single function token, e.g. `--foo(`.
+    let location = input.current_source_location();
+    let name = input.expect_function()?.clone();
+    if !name.starts_with("--") {
+      return Err(location.new_unexpected_token_error(Token::Function(name)));
+    }
+
+    let parameters = input.parse_nested_block(|input| FunctionParameter::parse_list(input, options))?;
+    let returns = if input.try_parse(|input| input.expect_ident_matching("returns")).is_ok() {
+      Some(parse_css_type(input)?)
+    } else {
+      None
+    };
+
+    Ok((DashedIdent(name.into()), parameters, returns))
+  }
+
+  pub(crate) fn parse<'t>(
+    name: DashedIdent<'i>,
+    parameters: Vec<FunctionParameter<'i>>,
+    returns: Option<SyntaxString>,
+    input: &mut Parser<'i, 't>,
+    loc: Location,
+    options: &ParserOptions<'_, 'i>,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut declarations = DeclarationBlock::new();
+    let mut decl_parser = FunctionDeclarationParser {
+      declarations: &mut declarations,
+      options,
+    };
+    let parser = RuleBodyParser::new(input, &mut decl_parser);
+
+    for decl in parser {
+      if let Err((err, _)) = decl {
+        if options.error_recovery {
+          options.warn(err);
+          continue;
+        }
+        return Err(err);
+      }
+    }
+
+    Ok(FunctionRule {
+      name,
+      parameters,
+      returns,
+      declarations,
+      loc,
+    })
+  }
+}
+
+impl<'i> ToCss for FunctionRule<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    #[cfg(feature = "sourcemap")]
+    dest.add_mapping(self.loc);
+    dest.write_str("@function ")?;
+    // Function names are referenced by function tokens within declaration values,
+    // which are not scoped by CSS modules, so neither is the name here.
+    dest.write_ident(&self.name.0, false)?;
+    write_parameters(&self.parameters, dest)?;
+    if let Some(returns) = &self.returns {
+      dest.write_str(" returns ")?;
+      write_css_type(returns, dest)?;
+    }
+    self.declarations.to_css_block(dest)
+  }
+}
+
+struct FunctionDeclarationParser<'a, 'o, 'i> {
+  declarations: &'a mut DeclarationBlock<'i>,
+  options: &'a ParserOptions<'o, 'i>,
+}
+
+impl<'a, 'o, 'i> cssparser::DeclarationParser<'i> for FunctionDeclarationParser<'a, 'o, 'i> {
+  type Declaration = ();
+  type Error = ParserError<'i>;
+
+  fn parse_value<'t>(
+    &mut self,
+    name: CowRcStr<'i>,
+    input: &mut cssparser::Parser<'i, 't>,
+  ) -> Result<Self::Declaration, cssparser::ParseError<'i, Self::Error>> {
+    // Descriptors within @function are evaluated when the function is called, so they are
+    // always kept as unparsed tokens rather than being parsed as typed properties.
+    let property = CustomProperty::parse(name.into(), input, self.options)?;
+    let important = input
+      .try_parse(|input| {
+        input.expect_delim('!')?;
+        input.expect_ident_matching("important")
+      })
+      .is_ok();
+    input.expect_exhausted()?;
+    if important {
+      self.declarations.important_declarations.push(Property::Custom(property));
+    } else {
+      self.declarations.declarations.push(Property::Custom(property));
+    }
+    Ok(())
+  }
+}
+
+/// Default methods reject all at rules.
+impl<'a, 'o, 'i> AtRuleParser<'i> for FunctionDeclarationParser<'a, 'o, 'i> {
+  type Prelude = ();
+  type AtRule = ();
+  type Error = ParserError<'i>;
+}
+
+impl<'a, 'o, 'i> QualifiedRuleParser<'i> for FunctionDeclarationParser<'a, 'o, 'i> {
+  type Prelude = ();
+  type QualifiedRule = ();
+  type Error = ParserError<'i>;
+}
+
+impl<'a, 'o, 'i> RuleBodyItemParser<'i, (), ParserError<'i>> for FunctionDeclarationParser<'a, 'o, 'i> {
+  fn parse_qualified(&self) -> bool {
+    false
+  }
+
+  fn parse_declarations(&self) -> bool {
+    true
+  }
+}
@@ -1,18 +1,21 @@
 //! The `@keyframes` rule.
 
+use super::mixin::applied_mixins_are_defined;
 use super::supports::SupportsRule;
 use super::MinifyContext;
 use super::{CssRule, CssRuleList, Location};
 use crate::context::DeclarationContext;
 use crate::declaration::DeclarationBlock;
 use crate::error::{ParserError, PrinterError};
+use crate::macros::phase;
 use crate::parser::ParserOptions;
-use crate::printer::Printer;
+use crate::printer::{Printer, PrinterOptions};
+use crate::properties::animation::{AnimationName, TimelineRangeName};
 use crate::properties::custom::{CustomProperty, UnparsedProperty};
-use crate::properties::Property;
+use crate::properties::{Property, PropertyId};
 use crate::targets::Targets;
 use crate::traits::{Parse, ToCss};
-use crate::values::color::ColorFallbackKind;
+use crate::values::color::{ColorFallbackKind, GamutMapping};
 use crate::values::ident::CustomIdent;
 use crate::values::percentage::Percentage;
 use crate::values::string::CowArcStr;
@@ -20,6 +23,7 @@ use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use cssparser::*;
+use std::collections::{HashMap, HashSet};
 
 /// A [@keyframes](https://drafts.csswg.org/css-animations/#keyframes) rule.
 #[derive(Debug, PartialEq, Clone)]
@@ -128,7 +132,11 @@ impl<'i> KeyframesRule<'i> {
     context.handler_context.context = DeclarationContext::None;
   }
 
-  pub(crate) fn get_fallbacks<T>(&mut self, targets: &Targets) -> Vec<CssRule<'i, T>> {
+  pub(crate) fn get_fallbacks<T>(
+    &mut self,
+    targets: &Targets,
+    gamut_mapping: GamutMapping,
+  ) -> Vec<CssRule<'i, T>> {
     let mut fallbacks = ColorFallbackKind::empty();
     for keyframe in &self.keyframes {
       for property in &keyframe.declarations.declarations {
@@ -146,13 +154,13 @@ impl<'i> KeyframesRule<'i> {
     fallbacks.remove(lowest_fallback);
 
     if fallbacks.contains(ColorFallbackKind::P3) {
-      res.push(self.get_fallback(ColorFallbackKind::P3));
+      res.push(self.get_fallback(ColorFallbackKind::P3, gamut_mapping));
     }
 
     if fallbacks.contains(ColorFallbackKind::LAB)
       || (!lowest_fallback.is_empty() && lowest_fallback != ColorFallbackKind::LAB)
     {
-      res.push(self.get_fallback(ColorFallbackKind::LAB));
+      res.push(self.get_fallback(ColorFallbackKind::LAB, gamut_mapping));
     }
 
     if !lowest_fallback.is_empty() {
@@ -161,7 +169,7 @@ impl<'i> KeyframesRule<'i> {
           match property {
             Property::Custom(CustomProperty { value, .. })
             | Property::Unparsed(UnparsedProperty { value, .. }) => {
-              *value = value.get_fallback(lowest_fallback);
+              *value = value.get_fallback(lowest_fallback, gamut_mapping);
             }
             _ => {}
           }
@@ -172,7 +180,7 @@ impl<'i> KeyframesRule<'i> {
     res
   }
 
-  fn get_fallback<T>(&self, kind: ColorFallbackKind) -> CssRule<'i, T> {
+  fn get_fallback<T>(&self, kind: ColorFallbackKind, gamut_mapping: GamutMapping) -> CssRule<'i, T> {
     let keyframes = self
       .keyframes
       .iter()
@@ -187,11 +195,11 @@ impl<'i> KeyframesRule<'i> {
             .map(|property| match property {
               Property::Custom(custom) => Property::Custom(CustomProperty {
                 name: custom.name.clone(),
-                value: custom.value.get_fallback(kind),
+                value: custom.value.get_fallback(kind, gamut_mapping),
               }),
               Property::Unparsed(unparsed) => Property::Unparsed(UnparsedProperty {
                 property_id: unparsed.property_id.clone(),
-                value: unparsed.value.get_fallback(kind),
+                value: unparsed.value.get_fallback(kind, gamut_mapping),
               }),
               _ => property.clone(),
             })
@@ -283,6 +291,41 @@ pub enum KeyframeSelector {
   From,
   /// The `to` keyword. Equivalent to 100%.
   To,
+  /// A [named timeline range selector](https://drafts.csswg.org/scroll-animations-1/#named-range-keyframes).
+  TimelineRangePercentage(TimelineRangePercentage),
+}
+
+/// A [named timeline range selector](https://drafts.csswg.org/scroll-animations-1/#named-range-keyframes)
+/// within an `@keyframes` rule, e.g. `entry 25%`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct TimelineRangePercentage {
+  /// The name of the timeline range.
+  pub name: TimelineRangeName,
+  /// The percentage progress within the timeline range.
+  pub percentage: Percentage,
+}
+
+impl<'i> Parse<'i> for TimelineRangePercentage {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let name = TimelineRangeName::parse(input)?;
+    let percentage = Percentage::parse(input)?;
+    Ok(TimelineRangePercentage { name, percentage })
+  }
+}
+
+impl ToCss for TimelineRangePercentage {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.name.to_css(dest)?;
+    dest.write_char(' ')?;
+    self.percentage.to_css(dest)
+  }
 }
 
 impl<'i> Parse<'i> for KeyframeSelector {
@@ -291,6 +334,10 @@ impl<'i> Parse<'i> for KeyframeSelector {
       return Ok(KeyframeSelector::Percentage(val));
     }
 
+    if let Ok(val) = input.try_parse(TimelineRangePercentage::parse) {
+      return Ok(KeyframeSelector::TimelineRangePercentage(val));
+    }
+
     let location = input.current_source_location();
     let ident = input.expect_ident()?;
     match_ignore_ascii_case! { &*ident,
@@ -324,6 +371,7 @@ impl ToCss for KeyframeSelector {
         }
       }
       KeyframeSelector::To => dest.write_str("to"),
+      KeyframeSelector::TimelineRangePercentage(range) => range.to_css(dest),
     }
   }
 }
@@ -411,3 +459,289 @@ impl<'i> RuleBodyItemParser<'i, Keyframe<'i>, ParserError<'i>> for KeyframeListP
     false
   }
 }
+
+/// Removes `@keyframes` rules whose names are not referenced by any `animation-name` or `animation`
+/// declaration in the given rules. If any animation name cannot be determined statically, e.g. because
+/// it is defined using `var()`, no rules are removed.
+pub(crate) fn remove_unused_keyframes<R>(rules: &mut CssRuleList<R>) {
+  phase!("minify: remove unused keyframes");
+  if let Some(names) = referenced_animation_names(rules) {
+    retain_keyframes(rules, &|name: &str| names.contains(name));
+  }
+}
+
+/// Returns the animation names referenced by `animation-name` and `animation` declarations in the
+/// given rules, or `None` if any of them cannot be determined statically.
+pub(crate) fn referenced_animation_names<R>(rules: &CssRuleList<R>) -> Option<HashSet<String>> {
+  let mut names = HashSet::new();
+  if collect_animation_names(rules, &mut names) && applied_mixins_are_defined(rules) {
+    Some(names)
+  } else {
+    None
+  }
+}
+
+/// Removes `@keyframes` rules that were referenced before style rules were purged, but are no
+/// longer referenced by any remaining rule. Keyframes that were never referenced are kept.
+pub(crate) fn remove_purged_keyframes<R>(rules: &mut CssRuleList<R>, referenced: &HashSet<String>) {
+  phase!("minify: remove purged keyframes");
+  if let Some(names) = referenced_animation_names(rules) {
+    retain_keyframes(rules, &|name: &str| names.contains(name) || !referenced.contains(name));
+  }
+}
+
+fn collect_animation_names<R>(rules: &CssRuleList<R>, names: &mut HashSet<String>) -> bool {
+  rules.0.iter().all(|rule| match rule {
+    CssRule::Style(style) => {
+      collect_declaration_animation_names(&style.declarations, names)
+        && collect_animation_names(&style.rules, names)
+    }
+    CssRule::Nesting(nesting) => {
+      collect_declaration_animation_names(&nesting.style.declarations, names)
+        && collect_animation_names(&nesting.style.rules, names)
+    }
+    CssRule::Media(media) => collect_animation_names(&media.rules, names),
+    CssRule::Supports(supports) => collect_animation_names(&supports.rules, names),
+    CssRule::Container(container) => collect_animation_names(&container.rules, names),
+    CssRule::LayerBlock(layer) => collect_animation_names(&layer.rules, names),
+    CssRule::MozDocument(document) => collect_animation_names(&document.rules, names),
+    CssRule::Scope(scope) => collect_animation_names(&scope.rules, names),
+    CssRule::StartingStyle(starting_style) => collect_animation_names(&starting_style.rules, names),
+    CssRule::When(when) => {
+      collect_animation_names(&when.rules, names)
+        && when.else_rules.iter().all(|rule| collect_animation_names(&rule.rules, names))
+    }
+    CssRule::Mixin(mixin) => {
+      collect_declaration_animation_names(&mixin.declarations, names)
+        && collect_animation_names(&mixin.rules, names)
+    }
+    // Unknown and custom at-rules may contain declarations we cannot see.
+    CssRule::Unknown(_) | CssRule::Custom(_) => false,
+    _ => true,
+  })
+}
+
+fn collect_declaration_animation_names(declarations: &DeclarationBlock, names: &mut HashSet<String>) -> bool {
+  for list in [&declarations.declarations, &declarations.important_declarations] {
+    // Only the last animation name declaration for each vendor prefix applies.
+    let mut last = HashMap::new();
+    for property in list {
+      match property {
+        Property::AnimationName(_, vp) | Property::Animation(_, vp) => {
+          last.insert(*vp, property);
+        }
+        Property::Unparsed(unparsed) => match unparsed.property_id {
+          PropertyId::AnimationName(vp) | PropertyId::Animation(vp) => {
+            last.insert(vp, property);
+          }
+          _ => {}
+        },
+        _ => {}
+      }
+    }
+
+    for property in last.into_values() {
+      match property {
+        Property::AnimationName(list, _) => names.extend(list.iter().filter_map(animation_name)),
+        Property::Animation(list, _) => names.extend(list.iter().filter_map(|a| animation_name(&a.name))),
+        _ => return false,
+      }
+    }
+  }
+
+  true
+}
+
+fn animation_name(name: &AnimationName) -> Option<String> {
+  match name {
+    AnimationName::None => None,
+    AnimationName::Ident(CustomIdent(name)) | AnimationName::String(name) => Some(name.to_string()),
+  }
+}
+
+fn retain_keyframes<R, F: Fn(&str) -> bool>(rules: &mut CssRuleList<R>, keep: &F) {
+  rules.0.retain_mut(|rule| match rule {
+    CssRule::Keyframes(keyframes) => match &keyframes.name {
+      KeyframesName::Ident(CustomIdent(name)) | KeyframesName::Custom(name) => keep(name.as_ref()),
+    },
+    CssRule::Media(media) => {
+      retain_keyframes(&mut media.rules, keep);
+      true
+    }
+    CssRule::Supports(supports) => {
+      retain_keyframes(&mut supports.rules, keep);
+      true
+    }
+    CssRule::Container(container) => {
+      retain_keyframes(&mut container.rules, keep);
+      true
+    }
+    CssRule::LayerBlock(layer) => {
+      retain_keyframes(&mut layer.rules, keep);
+      true
+    }
+    CssRule::MozDocument(document) => {
+      retain_keyframes(&mut document.rules, keep);
+      true
+    }
+    CssRule::Scope(scope) => {
+      retain_keyframes(&mut scope.rules, keep);
+      true
+    }
+    CssRule::StartingStyle(starting_style) => {
+      retain_keyframes(&mut starting_style.rules, keep);
+      true
+    }
+    CssRule::When(when) => {
+      retain_keyframes(&mut when.rules, keep);
+      for rule in &mut when.else_rules {
+        retain_keyframes(&mut rule.rules, keep);
+      }
+      true
+    }
+    _ => true,
+  });
+}
+
+/// Removes top-level `@keyframes` rules with the same body as an earlier rule, and rewrites
+/// `animation-name` and `animation` declarations that reference them to use the name of the
+/// earlier rule instead. If any animation name cannot be determined statically, or a name is
+/// defined by more than one rule, the affected rules are left untouched.
+pub(crate) fn deduplicate_keyframes<R>(rules: &mut CssRuleList<R>) {
+  phase!("minify: deduplicate keyframes");
+  // References cannot be rewritten if some of them are dynamic, e.g. `var()`.
+  if !collect_animation_names(rules, &mut HashSet::new()) || !applied_mixins_are_defined(rules) {
+    return;
+  }
+
+  let mut definitions = HashMap::new();
+  count_keyframes(rules, &mut definitions);
+
+  // Rules are compared by their minified output, e.g. so `from` and `0%` are equivalent.
+  let mut survivors = HashMap::new();
+  let mut renames = HashMap::new();
+  for rule in &rules.0 {
+    if let CssRule::Keyframes(keyframes) = rule {
+      let name = keyframes_name(&keyframes.name);
+      if definitions.get(name) != Some(&1) {
+        continue;
+      }
+
+      let mut body = String::new();
+      for keyframe in &keyframes.keyframes {
+        let Ok(css) = keyframe.to_css_string(PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        }) else {
+          return;
+        };
+        body.push_str(&css);
+      }
+
+      if let Some(survivor) = survivors.get(&(keyframes.vendor_prefix, body.clone())) {
+        let replacement = match survivor {
+          KeyframesName::Ident(ident) => AnimationName::Ident(ident.clone()),
+          KeyframesName::Custom(name) => AnimationName::String(name.clone()),
+        };
+        renames.insert(name.to_string(), replacement);
+      } else {
+        survivors.insert((keyframes.vendor_prefix, body), keyframes.name.clone());
+      }
+    }
+  }
+
+  if renames.is_empty() {
+    return;
+  }
+
+  rules.0.retain(|rule| match rule {
+    CssRule::Keyframes(keyframes) => !renames.contains_key(keyframes_name(&keyframes.name)),
+    _ => true,
+  });
+  rename_animations(rules, &renames);
+}
+
+fn keyframes_name<'a>(name: &'a KeyframesName) -> &'a str {
+  match name {
+    KeyframesName::Ident(CustomIdent(name)) | KeyframesName::Custom(name) => name.as_ref(),
+  }
+}
+
+fn count_keyframes<'a, R>(rules: &'a CssRuleList<R>, definitions: &mut HashMap<&'a str, usize>) {
+  for rule in &rules.0 {
+    match rule {
+      CssRule::Keyframes(keyframes) => *definitions.entry(keyframes_name(&keyframes.name)).or_default() += 1,
+      CssRule::Media(media) => count_keyframes(&media.rules, definitions),
+      CssRule::Supports(supports) => count_keyframes(&supports.rules, definitions),
+      CssRule::Container(container) => count_keyframes(&container.rules, definitions),
+      CssRule::LayerBlock(layer) => count_keyframes(&layer.rules, definitions),
+      CssRule::MozDocument(document) => count_keyframes(&document.rules, definitions),
+      CssRule::Scope(scope) => count_keyframes(&scope.rules, definitions),
+      CssRule::StartingStyle(starting_style) => count_keyframes(&starting_style.rules, definitions),
+      CssRule::When(when) => {
+        count_keyframes(&when.rules, definitions);
+        for rule in &when.else_rules {
+          count_keyframes(&rule.rules, definitions);
+        }
+      }
+      CssRule::Mixin(mixin) => count_keyframes(&mixin.rules, definitions),
+      _ => {}
+    }
+  }
+}
+
+fn rename_animations<'i, R>(rules: &mut CssRuleList<'i, R>, renames: &HashMap<String, AnimationName<'i>>) {
+  for rule in &mut rules.0 {
+    match rule {
+      CssRule::Style(style) => {
+        rename_declaration_animations(&mut style.declarations, renames);
+        rename_animations(&mut style.rules, renames);
+      }
+      CssRule::Nesting(nesting) => {
+        rename_declaration_animations(&mut nesting.style.declarations, renames);
+        rename_animations(&mut nesting.style.rules, renames);
+      }
+      CssRule::Media(media) => rename_animations(&mut media.rules, renames),
+      CssRule::Supports(supports) => rename_animations(&mut supports.rules, renames),
+      CssRule::Container(container) => rename_animations(&mut container.rules, renames),
+      CssRule::LayerBlock(layer) => rename_animations(&mut layer.rules, renames),
+      CssRule::MozDocument(document) => rename_animations(&mut document.rules, renames),
+      CssRule::Scope(scope) => rename_animations(&mut scope.rules, renames),
+      CssRule::StartingStyle(starting_style) => rename_animations(&mut starting_style.rules, renames),
+      CssRule::When(when) => {
+        rename_animations(&mut when.rules, renames);
+        for rule in &mut when.else_rules {
+          rename_animations(&mut rule.rules, renames);
+        }
+      }
+      CssRule::Mixin(mixin) => {
+        rename_declaration_animations(&mut mixin.declarations, renames);
+        rename_animations(&mut mixin.rules, renames);
+      }
+      _ => {}
+    }
+  }
+}
+
+fn rename_declaration_animations<'i>(
+  declarations: &mut DeclarationBlock<'i>,
+  renames: &HashMap<String, AnimationName<'i>>,
+) {
+  let rename = |name: &mut AnimationName<'i>| {
+    if let Some(replacement) = animation_name(name).and_then(|name| renames.get(&name)) {
+      *name = replacement.clone();
+    }
+  };
+
+  for property in declarations
+    .declarations
+    .iter_mut()
+    .chain(declarations.important_declarations.iter_mut())
+  {
+    match property {
+      Property::AnimationName(names, _) => names.iter_mut().for_each(rename),
+      Property::Animation(animations, _) => animations.iter_mut().for_each(|a| rename(&mut a.name)),
+      _ => {}
+    }
+  }
+}
@@ -1,7 +1,7 @@
 //! The `@media` rule.
 
 use super::Location;
-use super::{CssRuleList, MinifyContext};
+use super::{CssRule, CssRuleList, MinifyContext};
 use crate::error::{MinifyError, PrinterError};
 use crate::media_query::MediaList;
 use crate::parser::DefaultAtRule;
@@ -40,6 +40,23 @@ impl<'i, T: Clone> MediaRule<'i, T> {
     }
 
     self.query.transform_resolution(*context.targets);
+
+    // Nested `@media` rules with the same query always match, so hoist their rules.
+    if self
+      .rules
+      .0
+      .iter()
+      .any(|rule| matches!(rule, CssRule::Media(media) if media.query == self.query))
+    {
+      let rules = std::mem::take(&mut self.rules.0);
+      for rule in rules {
+        match rule {
+          CssRule::Media(media) if media.query == self.query => self.rules.0.extend(media.rules.0),
+          rule => self.rules.0.push(rule),
+        }
+      }
+    }
+
     Ok(self.rules.0.is_empty() || self.query.never_matches())
   }
 }
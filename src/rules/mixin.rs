@@ -0,0 +1,211 @@
+//! The `@mixin` and `@apply` rules.
+
+use super::function::{write_parameters, FunctionParameter};
+use super::Location;
+use super::{CssRule, CssRuleList, MinifyContext};
+use crate::declaration::DeclarationBlock;
+use crate::error::{MinifyError, ParserError, PrinterError};
+use crate::parser::DefaultAtRule;
+use crate::printer::Printer;
+use crate::properties::custom::TokenList;
+use crate::stylesheet::ParserOptions;
+use crate::traits::ToCss;
+use crate::values::ident::DashedIdent;
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+use std::collections::HashSet;
+
+/// A [@mixin](https://drafts.csswg.org/css-mixins-1/#mixin-rule) rule.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct MixinRule<'i, R = DefaultAtRule> {
+  /// The name of the mixin.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: DashedIdent<'i>,
+  /// The parameters accepted by the mixin, if it was declared with parentheses.
+  pub parameters: Option<Vec<FunctionParameter<'i>>>,
+  /// The declarations within the `@mixin` rule.
+  pub declarations: DeclarationBlock<'i>,
+  /// Nested rules within the `@mixin` rule.
+  pub rules: CssRuleList<'i, R>,
+  /// The location of the rule in the source file.
+  #[cfg_attr(feature = "visitor", skip_visit)]
+  pub loc: Location,
+}
+
+impl<'i, T: Clone> MixinRule<'i, T> {
+  pub(crate) fn minify(
+    &mut self,
+    context: &mut MinifyContext<'_, 'i>,
+    parent_is_unused: bool,
+  ) -> Result<(), MinifyError> {
+    self
+      .declarations
+      .minify(context.handler, context.important_handler, &mut context.handler_context);
+    self.rules.minify(context, parent_is_unused)
+  }
+}
+
+impl<'i, T: ToCss> ToCss for MixinRule<'i, T> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    #[cfg(feature = "sourcemap")]
+    dest.add_mapping(self.loc);
+    dest.write_str("@mixin ")?;
+    // Mixin names are only referenced by @apply rules, which are not scoped by CSS modules.
+    dest.write_ident(&self.name.0, false)?;
+    if let Some(parameters) = &self.parameters {
+      write_parameters(parameters, dest)?;
+    }
+    dest.whitespace()?;
+    dest.write_char('{')?;
+    dest.indent();
+
+    let len = self.declarations.len();
+    let mut i = 0;
+    macro_rules! write {
+      ($decls: ident, $important: literal) => {
+        for decl in &self.declarations.$decls {
+          dest.newline()?;
+          decl.to_css(dest, $important)?;
+          if i != len - 1 || !dest.minify || !self.rules.0.is_empty() {
+            dest.write_char(';')?;
+          }
+          i += 1;
+        }
+      };
+    }
+
+    write!(declarations, false);
+    write!(important_declarations, true);
+
+    if !self.rules.0.is_empty() {
+      if !dest.minify && len > 0 {
+        dest.write_char('\n')?;
+      }
+      dest.newline()?;
+      self.rules.to_css(dest)?;
+    }
+
+    dest.dedent();
+    dest.newline()?;
+    dest.write_char('}')
+  }
+}
+
+/// An [@apply](https://drafts.csswg.org/css-mixins-1/#apply-rule) rule.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct ApplyRule<'i> {
+  /// The name of the mixin to apply.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: DashedIdent<'i>,
+  /// The arguments passed to the mixin, if it was applied with parentheses.
+  pub arguments: Option<Vec<TokenList<'i>>>,
+  /// The location of the rule in the source file.
+  #[cfg_attr(feature = "visitor", skip_visit)]
+  pub loc: Location,
+}
+
+impl<'i> ApplyRule<'i> {
+  pub(crate) fn parse_prelude<'t>(
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions<'_, 'i>,
+  ) -> Result<(DashedIdent<'i>, Option<Vec<TokenList<'i>>>), ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let (name, arguments) = match input.next()?.clone() {
+      Token::Ident(name) if name.starts_with("--") => (name, None),
+      Token::Function(name) if name.starts_with("--") => {
+        let arguments = input.parse_nested_block(|input| {
+          if input.is_exhausted() {
+            return Ok(Vec::new());
+          }
+          input.parse_comma_separated(|input| TokenList::parse(input, options, 0))
+        })?;
+        (name, Some(arguments))
+      }
+      t => return Err(location.new_unexpected_token_error(t)),
+    };
+
+    input.expect_exhausted()?;
+    Ok((DashedIdent(name.into()), arguments))
+  }
+}
+
+impl<'i> ToCss for ApplyRule<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    #[cfg(feature = "sourcemap")]
+    dest.add_mapping(self.loc);
+    dest.write_str("@apply ")?;
+    dest.write_ident(&self.name.0, false)?;
+    if let Some(arguments) = &self.arguments {
+      dest.write_char('(')?;
+      let mut first = true;
+      for argument in arguments {
+        if first {
+          first = false;
+        } else {
+          dest.delim(',', false)?;
+        }
+        argument.to_css(dest, true)?;
+      }
+      dest.write_char(')')?;
+    }
+    dest.write_char(';')
+  }
+}
+
+/// Returns whether every `@apply` rule in the given rules refers to a `@mixin` rule that is also
+/// defined within them, i.e. whether the bodies of all applied mixins can be inspected.
+pub(crate) fn applied_mixins_are_defined<R>(rules: &CssRuleList<R>) -> bool {
+  let mut defined = HashSet::new();
+  let mut applied = HashSet::new();
+  collect_mixin_names(rules, &mut defined, &mut applied);
+  applied.is_subset(&defined)
+}
+
+fn collect_mixin_names<'a, R>(
+  rules: &'a CssRuleList<R>,
+  defined: &mut HashSet<&'a str>,
+  applied: &mut HashSet<&'a str>,
+) {
+  for rule in &rules.0 {
+    match rule {
+      CssRule::Mixin(mixin) => {
+        defined.insert(mixin.name.0.as_ref());
+        collect_mixin_names(&mixin.rules, defined, applied);
+      }
+      CssRule::Apply(apply) => {
+        applied.insert(apply.name.0.as_ref());
+      }
+      CssRule::Style(style) => collect_mixin_names(&style.rules, defined, applied),
+      CssRule::Nesting(nesting) => collect_mixin_names(&nesting.style.rules, defined, applied),
+      CssRule::Media(media) => collect_mixin_names(&media.rules, defined, applied),
+      CssRule::Supports(supports) => collect_mixin_names(&supports.rules, defined, applied),
+      CssRule::Container(container) => collect_mixin_names(&container.rules, defined, applied),
+      CssRule::LayerBlock(layer) => collect_mixin_names(&layer.rules, defined, applied),
+      CssRule::MozDocument(document) => collect_mixin_names(&document.rules, defined, applied),
+      CssRule::Scope(scope) => collect_mixin_names(&scope.rules, defined, applied),
+      CssRule::StartingStyle(starting_style) => collect_mixin_names(&starting_style.rules, defined, applied),
+      CssRule::When(when) => {
+        collect_mixin_names(&when.rules, defined, applied);
+        for rule in &when.else_rules {
+          collect_mixin_names(&rule.rules, defined, applied);
+        }
+      }
+      _ => {}
+    }
+  }
+}
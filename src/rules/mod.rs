@@ -36,26 +36,32 @@
 
 #![deny(missing_docs)]
 
+pub mod color_profile;
 pub mod container;
 pub mod counter_style;
 pub mod custom_media;
 pub mod document;
 pub mod font_face;
 pub mod font_palette_values;
+pub mod function;
 pub mod import;
 pub mod keyframes;
 pub mod layer;
 pub mod media;
+pub mod mixin;
 pub mod namespace;
 pub mod nesting;
 pub mod page;
+pub mod position_try;
 pub mod property;
 pub mod scope;
 pub mod starting_style;
 pub mod style;
 pub mod supports;
 pub mod unknown;
+pub mod view_transition;
 pub mod viewport;
+pub mod when;
 
 use self::font_palette_values::FontPaletteValuesRule;
 use self::layer::{LayerBlockRule, LayerStatementRule};
@@ -63,32 +69,41 @@ use self::property::PropertyRule;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::{DeclarationBlock, DeclarationHandler};
 use crate::dependencies::{Dependency, ImportDependency};
-use crate::error::{MinifyError, ParserError, PrinterError, PrinterErrorKind};
+use crate::error::{ErrorWithLocation, MinifyError, ParserError, PrinterError, PrinterErrorKind};
+use crate::macros::phase;
 use crate::parser::{parse_rule_list, parse_style_block, DefaultAtRule, DefaultAtRuleParser, TopLevelRuleParser};
 use crate::prefixes::Feature;
-use crate::printer::Printer;
+use crate::printer::{Printer, PrinterOptions};
+use crate::properties::{
+  custom::{CustomProperty, CustomPropertyName},
+  Property, PropertyId,
+};
 use crate::rules::keyframes::KeyframesName;
-use crate::selector::{is_compatible, is_equivalent, Component, Selector, SelectorList};
-use crate::stylesheet::ParserOptions;
+use crate::selector::{is_canonically_equal, is_compatible, is_equivalent, Component, Selector, SelectorList};
+use crate::stylesheet::{DirStrategy, MergeRules, ParserOptions, PurgeOptions};
 use crate::targets::Targets;
 use crate::traits::{AtRuleParser, ToCss};
 use crate::values::string::CowArcStr;
 use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
 use crate::visitor::{Visit, VisitTypes, Visitor};
+use color_profile::{ColorProfileName, ColorProfileRule};
 use container::ContainerRule;
 use counter_style::CounterStyleRule;
 use cssparser::{parse_one_rule, ParseError, Parser, ParserInput};
 use custom_media::CustomMediaRule;
 use document::MozDocumentRule;
 use font_face::FontFaceRule;
+use function::FunctionRule;
 use import::ImportRule;
 use itertools::Itertools;
 use keyframes::KeyframesRule;
 use media::MediaRule;
+use mixin::{ApplyRule, MixinRule};
 use namespace::NamespaceRule;
 use nesting::NestingRule;
 use page::PageRule;
+use position_try::PositionTryRule;
 use scope::ScopeRule;
 use smallvec::{smallvec, SmallVec};
 use starting_style::StartingStyleRule;
@@ -97,7 +112,9 @@ use std::hash::{BuildHasherDefault, Hasher};
 use style::StyleRule;
 use supports::SupportsRule;
 use unknown::UnknownAtRule;
+use view_transition::ViewTransitionRule;
 use viewport::ViewportRule;
+use when::WhenRule;
 
 #[derive(Clone)]
 pub(crate) struct StyleContext<'a, 'i> {
@@ -146,6 +163,8 @@ pub enum CssRule<'i, R = DefaultAtRule> {
   FontFace(FontFaceRule<'i>),
   /// A `@font-palette-values` rule.
   FontPaletteValues(FontPaletteValuesRule<'i>),
+  /// A `@color-profile` rule.
+  ColorProfile(ColorProfileRule<'i>),
   /// A `@page` rule.
   Page(PageRule<'i>),
   /// A `@supports` rule.
@@ -174,6 +193,18 @@ pub enum CssRule<'i, R = DefaultAtRule> {
   Scope(ScopeRule<'i, R>),
   /// A `@starting-style` rule.
   StartingStyle(StartingStyleRule<'i, R>),
+  /// A `@position-try` rule.
+  PositionTry(PositionTryRule<'i>),
+  /// A `@view-transition` rule.
+  ViewTransition(ViewTransitionRule<'i>),
+  /// A `@when` rule, along with its chained `@else` rules.
+  When(WhenRule<'i, R>),
+  /// A `@function` rule.
+  Function(FunctionRule<'i>),
+  /// A `@mixin` rule.
+  Mixin(MixinRule<'i, R>),
+  /// An `@apply` rule.
+  Apply(ApplyRule<'i>),
   /// A placeholder for a rule that was removed.
   Ignored,
   /// An unknown at-rule.
@@ -262,6 +293,10 @@ impl<'i, 'de: 'i, R: serde::Deserialize<'de>> serde::Deserialize<'de> for CssRul
         let rule = FontPaletteValuesRule::deserialize(deserializer)?;
         Ok(CssRule::FontPaletteValues(rule))
       }
+      "color-profile" => {
+        let rule = ColorProfileRule::deserialize(deserializer)?;
+        Ok(CssRule::ColorProfile(rule))
+      }
       "page" => {
         let rule = PageRule::deserialize(deserializer)?;
         Ok(CssRule::Page(rule))
@@ -318,6 +353,30 @@ impl<'i, 'de: 'i, R: serde::Deserialize<'de>> serde::Deserialize<'de> for CssRul
         let rule = StartingStyleRule::deserialize(deserializer)?;
         Ok(CssRule::StartingStyle(rule))
       }
+      "position-try" => {
+        let rule = PositionTryRule::deserialize(deserializer)?;
+        Ok(CssRule::PositionTry(rule))
+      }
+      "view-transition" => {
+        let rule = ViewTransitionRule::deserialize(deserializer)?;
+        Ok(CssRule::ViewTransition(rule))
+      }
+      "when" => {
+        let rule = WhenRule::deserialize(deserializer)?;
+        Ok(CssRule::When(rule))
+      }
+      "function" => {
+        let rule = FunctionRule::deserialize(deserializer)?;
+        Ok(CssRule::Function(rule))
+      }
+      "mixin" => {
+        let rule = MixinRule::deserialize(deserializer)?;
+        Ok(CssRule::Mixin(rule))
+      }
+      "apply" => {
+        let rule = ApplyRule::deserialize(deserializer)?;
+        Ok(CssRule::Apply(rule))
+      }
       "ignored" => Ok(CssRule::Ignored),
       "unknown" => {
         let rule = UnknownAtRule::deserialize(deserializer)?;
@@ -344,6 +403,7 @@ impl<'a, 'i, T: ToCss> ToCss for CssRule<'i, T> {
       CssRule::Keyframes(keyframes) => keyframes.to_css(dest),
       CssRule::FontFace(font_face) => font_face.to_css(dest),
       CssRule::FontPaletteValues(f) => f.to_css(dest),
+      CssRule::ColorProfile(rule) => rule.to_css(dest),
       CssRule::Page(font_face) => font_face.to_css(dest),
       CssRule::Supports(supports) => supports.to_css(dest),
       CssRule::CounterStyle(counter_style) => counter_style.to_css(dest),
@@ -356,6 +416,12 @@ impl<'a, 'i, T: ToCss> ToCss for CssRule<'i, T> {
       CssRule::LayerBlock(layer) => layer.to_css(dest),
       CssRule::Property(property) => property.to_css(dest),
       CssRule::StartingStyle(rule) => rule.to_css(dest),
+      CssRule::PositionTry(rule) => rule.to_css(dest),
+      CssRule::ViewTransition(rule) => rule.to_css(dest),
+      CssRule::When(rule) => rule.to_css(dest),
+      CssRule::Function(rule) => rule.to_css(dest),
+      CssRule::Mixin(rule) => rule.to_css(dest),
+      CssRule::Apply(rule) => rule.to_css(dest),
       CssRule::Container(container) => container.to_css(dest),
       CssRule::Scope(scope) => scope.to_css(dest),
       CssRule::Unknown(unknown) => unknown.to_css(dest),
@@ -488,6 +554,12 @@ pub(crate) struct MinifyContext<'a, 'i> {
   pub unused_symbols: &'a HashSet<String>,
   pub custom_media: Option<HashMap<CowArcStr<'i>, CustomMediaRule<'i>>>,
   pub css_modules: bool,
+  pub expand_is_selectors: Option<usize>,
+  pub dir_strategy: DirStrategy,
+  pub purge: Option<&'a PurgeOptions>,
+  pub merge_rules: MergeRules,
+  pub remove_overridden_declarations: bool,
+  pub warnings: Vec<ErrorWithLocation<ParserError<'i>>>,
 }
 
 impl<'i, T: Clone> CssRuleList<'i, T> {
@@ -496,9 +568,11 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
     context: &mut MinifyContext<'_, 'i>,
     parent_is_unused: bool,
   ) -> Result<(), MinifyError> {
+    phase!("minify: rules");
     let mut keyframe_rules = HashMap::new();
     let mut layer_rules = HashMap::new();
     let mut property_rules = HashMap::new();
+    let mut position_try_rules = HashMap::new();
     let mut style_rules =
       HashMap::with_capacity_and_hasher(self.0.len(), BuildHasherDefault::<PrecomputedHasher>::default());
     let mut rules = Vec::new();
@@ -539,7 +613,7 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
           set_prefix!(keyframes);
           keyframe_rules.insert(keyframes.name.clone(), rules.len());
 
-          let fallbacks = keyframes.get_fallbacks(context.targets);
+          let fallbacks = keyframes.get_fallbacks(context.targets, context.handler_context.gamut_mapping);
           rules.push(rule);
           rules.extend(fallbacks);
           continue;
@@ -576,6 +650,15 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
             continue;
           }
         }
+        CssRule::When(when) => {
+          if let Some(replacement) = when.minify(context, parent_is_unused)? {
+            rules.extend(replacement.0);
+            continue;
+          }
+        }
+        CssRule::Mixin(mixin) => {
+          mixin.minify(context, parent_is_unused)?;
+        }
         CssRule::Container(container) => {
           if let Some(CssRule::Container(last_rule)) = rules.last_mut() {
             if last_rule.name == container.name && last_rule.condition == container.condition {
@@ -741,6 +824,7 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
             // the same selectors and defines the same properties. If so, remove the
             // earlier rule because this one completely overrides it.
             if has_no_rules {
+              phase!("minify: deduplicate rules");
               // SAFETY: StyleRuleKeys never live beyond this method.
               let key = StyleRuleKey::new(unsafe { &*(&rules as *const _) }, idx);
               if idx > 0 {
@@ -788,6 +872,7 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
           if context.unused_symbols.contains(counter_style.name.0.as_ref()) {
             continue;
           }
+          counter_style.minify();
         }
         CssRule::Scope(scope) => scope.minify(context)?,
         CssRule::Nesting(nesting) => {
@@ -796,10 +881,17 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
           }
         }
         CssRule::StartingStyle(rule) => {
+          if let Some(CssRule::StartingStyle(last_rule)) = rules.last_mut() {
+            last_rule.rules.0.append(&mut rule.rules.0);
+            last_rule.minify(context, parent_is_unused)?;
+            continue;
+          }
+
           if rule.minify(context, parent_is_unused)? {
             continue;
           }
         }
+        CssRule::FontFace(font_face) => font_face.minify(context.targets),
         CssRule::FontPaletteValues(f) => {
           if context.unused_symbols.contains(f.name.0.as_ref()) {
             continue;
@@ -807,11 +899,20 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
 
           f.minify(context, parent_is_unused);
 
-          let fallbacks = f.get_fallbacks(*context.targets);
+          let fallbacks = f.get_fallbacks(*context.targets, context.handler_context.gamut_mapping);
           rules.push(rule);
           rules.extend(fallbacks);
           continue;
         }
+        CssRule::ColorProfile(color_profile) => {
+          if let ColorProfileName::Dashed(name) = &color_profile.name {
+            if context.unused_symbols.contains(name.0.as_ref()) {
+              continue;
+            }
+          }
+
+          color_profile.minify();
+        }
         CssRule::Property(property) => {
           if context.unused_symbols.contains(property.name.0.as_ref()) {
             continue;
@@ -824,6 +925,30 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
             property_rules.insert(property.name.clone(), rules.len());
           }
         }
+        CssRule::ViewTransition(view_transition) => {
+          view_transition.minify();
+
+          // Adjacent @view-transition rules can be merged, with later properties overriding earlier ones.
+          if let Some(CssRule::ViewTransition(last_rule)) = rules.last_mut() {
+            last_rule.merge(view_transition);
+            continue;
+          }
+        }
+        CssRule::PositionTry(position_try) => {
+          if context.unused_symbols.contains(position_try.name.0.as_ref()) {
+            continue;
+          }
+
+          position_try.minify(context);
+
+          // If multiple @position-try rules have the same name, the last one wins.
+          if let Some(index) = position_try_rules.get(&position_try.name) {
+            rules[*index] = rule;
+            continue;
+          } else {
+            position_try_rules.insert(position_try.name.clone(), rules.len());
+          }
+        }
         _ => {}
       }
 
@@ -833,6 +958,7 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
     // Optimize @layer rules. Combine subsequent empty layer blocks into a single @layer statement
     // so that layers are declared in the correct order.
     if !layer_rules.is_empty() {
+      phase!("minify: merge layers");
       let mut declared_layers = HashSet::new();
       let mut layer_statement = None;
       for index in 0..rules.len() {
@@ -886,18 +1012,430 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
       }
     }
 
+    if context.merge_rules >= MergeRules::Restructure {
+      merge_media_rules(&mut rules, context, parent_is_unused)?;
+      restructure_style_rules(&mut rules, context);
+    }
+
+    if context.remove_overridden_declarations {
+      remove_overridden_declarations(&mut rules, context);
+    }
+
+    if context.merge_rules >= MergeRules::Aggressive {
+      hoist_shared_declarations(&mut rules, context);
+    }
+
     self.0 = rules;
     Ok(())
   }
 }
 
+/// Merges style rules into an earlier, non-adjacent style rule with equivalent selectors or identical
+/// declarations. This is only done when none of the rules in between declare properties that overlap
+/// with the moved declarations, so that the cascade is unchanged.
+fn restructure_style_rules<'i, T>(rules: &mut [CssRule<'i, T>], context: &mut MinifyContext<'_, 'i>) {
+  for index in 1..rules.len() {
+    let (before, after) = rules.split_at_mut(index);
+    let style = match &mut after[0] {
+      CssRule::Style(style) if style.rules.0.is_empty() => style,
+      _ => continue,
+    };
+
+    let property_ids: Vec<_> = style.declarations.iter().map(|(property, _)| property.property_id()).collect();
+    let mut merged = false;
+    for rule in before.iter_mut().rev() {
+      match rule {
+        CssRule::Ignored => continue,
+        CssRule::Style(prev) => {
+          if merge_style_rules(style, prev, context) {
+            merged = true;
+            break;
+          }
+
+          // Nested rules may declare any property, so don't move declarations across them.
+          if !prev.rules.0.is_empty()
+            || prev
+              .declarations
+              .iter()
+              .any(|(property, _)| property_ids.iter().any(|id| properties_overlap(&property.property_id(), id)))
+          {
+            break;
+          }
+        }
+        _ => break,
+      }
+    }
+
+    if merged {
+      after[0] = CssRule::Ignored;
+    }
+  }
+}
+
+/// Merges `@media` rules into an earlier, non-adjacent `@media` rule with the same query. This is only
+/// done when none of the rules in between declare properties that overlap with the moved declarations.
+fn merge_media_rules<'i, T: Clone>(
+  rules: &mut [CssRule<'i, T>],
+  context: &mut MinifyContext<'_, 'i>,
+  parent_is_unused: bool,
+) -> Result<(), MinifyError> {
+  for index in 1..rules.len() {
+    let (before, after) = rules.split_at_mut(index);
+    let media = match &mut after[0] {
+      CssRule::Media(media) => media,
+      _ => continue,
+    };
+
+    let mut property_ids = Vec::new();
+    if !collect_property_ids(&media.rules.0, &mut property_ids) {
+      continue;
+    }
+
+    let mut merged = false;
+    for rule in before.iter_mut().rev() {
+      match rule {
+        CssRule::Ignored => continue,
+        CssRule::Media(prev) if prev.query == media.query => {
+          prev.rules.0.append(&mut media.rules.0);
+          prev.minify(context, parent_is_unused)?;
+          merged = true;
+          break;
+        }
+        _ => {
+          let mut ids = Vec::new();
+          if !collect_property_ids(std::slice::from_ref(rule), &mut ids)
+            || ids.iter().any(|a| property_ids.iter().any(|b| properties_overlap(a, b)))
+          {
+            break;
+          }
+        }
+      }
+    }
+
+    if merged {
+      after[0] = CssRule::Ignored;
+    }
+  }
+
+  Ok(())
+}
+
+/// Collects the ids of all properties declared by the given rules, including nested rules.
+/// Returns false if any of the rules cannot be reordered with respect to other rules.
+fn collect_property_ids<'i, T>(rules: &[CssRule<'i, T>], ids: &mut Vec<PropertyId<'i>>) -> bool {
+  rules.iter().all(|rule| match rule {
+    CssRule::Style(style) => {
+      ids.extend(style.declarations.iter().map(|(property, _)| property.property_id()));
+      collect_property_ids(&style.rules.0, ids)
+    }
+    CssRule::Media(media) => collect_property_ids(&media.rules.0, ids),
+    CssRule::Supports(supports) => collect_property_ids(&supports.rules.0, ids),
+    CssRule::Container(container) => collect_property_ids(&container.rules.0, ids),
+    CssRule::StartingStyle(starting_style) => collect_property_ids(&starting_style.rules.0, ids),
+    CssRule::Ignored => true,
+    _ => false,
+  })
+}
+
+/// Returns whether the two properties may set the same longhand, including the logical
+/// and physical properties that map to each other.
+fn properties_overlap(a: &PropertyId, b: &PropertyId) -> bool {
+  fn is_opaque(id: &PropertyId) -> bool {
+    // Unknown properties may be shorthands of any other property.
+    matches!(id, PropertyId::All | PropertyId::Custom(CustomPropertyName::Unknown(_)))
+  }
+
+  fn for_each_longhand<F: FnMut(&PropertyId)>(id: &PropertyId, f: &mut F) {
+    match id.longhands() {
+      Some(longhands) => {
+        for longhand in &longhands {
+          for_each_longhand(longhand, f);
+        }
+      }
+      None => f(id),
+    }
+  }
+
+  if is_opaque(a) || is_opaque(b) {
+    return true;
+  }
+
+  let mut overlap = false;
+  for_each_longhand(a, &mut |a: &PropertyId| {
+    for_each_longhand(b, &mut |b: &PropertyId| {
+      if a.name() == b.name() || (a.logical_group().is_some() && a.logical_group() == b.logical_group()) {
+        overlap = true;
+      }
+    })
+  });
+  overlap
+}
+
+/// Removes declarations that are always overridden by a later style rule in the same rule list, and
+/// therefore in the same `@media`, `@supports`, and `@layer` context. A declaration is overridden when
+/// each selector of its rule is also a selector of the later rule, which declares the same property
+/// (or a shorthand of it) with equal or higher importance. Rules that become empty are removed.
+fn remove_overridden_declarations<'i, T>(rules: &mut [CssRule<'i, T>], context: &MinifyContext<'_, 'i>) {
+  for index in 0..rules.len() {
+    let (before, after) = rules.split_at_mut(index + 1);
+    let style = match &mut before[index] {
+      CssRule::Style(style) => style,
+      _ => continue,
+    };
+
+    for rule in after.iter() {
+      let later = match rule {
+        CssRule::Style(later) => later,
+        _ => continue,
+      };
+
+      // If any selector of the later rule is unsupported, the whole rule is dropped by the browser.
+      // Don't remove declarations if this is a CSS module and the later rule came from a different file.
+      if later.vendor_prefix != style.vendor_prefix
+        || (context.css_modules && later.loc.source_index != style.loc.source_index)
+        || !later.is_compatible(*context.targets)
+        || !style.selectors.0.iter().all(|selector| {
+          later
+            .selectors
+            .0
+            .iter()
+            .any(|other| is_canonically_equal(std::slice::from_ref(selector), std::slice::from_ref(other)))
+        })
+      {
+        continue;
+      }
+
+      let is_overridden = |property: &Property<'i>, important: bool| {
+        later.declarations.iter().any(|(later_property, later_important)| {
+          (later_important || !important) && overrides_property(later_property, property, context.targets)
+        })
+      };
+      style
+        .declarations
+        .declarations
+        .retain(|property| !is_overridden(property, false));
+      style
+        .declarations
+        .important_declarations
+        .retain(|property| !is_overridden(property, true));
+    }
+
+    if style.declarations.is_empty() && style.rules.0.is_empty() {
+      before[index] = CssRule::Ignored;
+    }
+  }
+}
+
+/// Factors declarations that are shared by multiple style rules out into a new rule with the combined
+/// selectors, inserted before the first of them, when this reduces the size of the output. A declaration
+/// is only moved when it does not overlap with other declarations in its rule, or with any of the rules
+/// it is moved across, so that the cascade is unchanged.
+fn hoist_shared_declarations<'i, T>(rules: &mut Vec<CssRule<'i, T>>, context: &MinifyContext<'_, 'i>) {
+  let mut index = 0;
+  while index < rules.len() {
+    if let Some((property, important, candidates)) = find_shared_declaration(rules, index, context) {
+      let mut selectors = SmallVec::new();
+      let mut vendor_prefix = VendorPrefix::empty();
+      let mut loc = None;
+      for i in candidates {
+        if let CssRule::Style(style) = &mut rules[i] {
+          selectors.extend(style.selectors.0.iter().cloned());
+          vendor_prefix = style.vendor_prefix;
+          loc.get_or_insert(style.loc);
+          if important {
+            style.declarations.important_declarations.retain(|p| *p != property);
+          } else {
+            style.declarations.declarations.retain(|p| *p != property);
+          }
+
+          if style.is_empty() {
+            rules[i] = CssRule::Ignored;
+          }
+        }
+      }
+
+      let mut declarations = DeclarationBlock::default();
+      if important {
+        declarations.important_declarations.push(property);
+      } else {
+        declarations.declarations.push(property);
+      }
+
+      // The rule at the current index is revisited after the new rule, since it may share other declarations.
+      rules.insert(
+        index,
+        CssRule::Style(StyleRule {
+          selectors: SelectorList::new(selectors),
+          vendor_prefix,
+          declarations,
+          rules: CssRuleList(vec![]),
+          loc: loc.unwrap(),
+        }),
+      );
+    }
+
+    index += 1;
+  }
+}
+
+/// Finds a declaration of the style rule at the given index that can be moved into a new rule shared with
+/// later style rules. Returns the declaration, whether it is important, and the indices of the rules.
+fn find_shared_declaration<'i, T>(
+  rules: &[CssRule<'i, T>],
+  index: usize,
+  context: &MinifyContext<'_, 'i>,
+) -> Option<(Property<'i>, bool, Vec<usize>)> {
+  let style = match &rules[index] {
+    CssRule::Style(style) if style.rules.0.is_empty() && style.is_compatible(*context.targets) => style,
+    _ => return None,
+  };
+
+  // Returns whether the rule declares another property that overlaps with the declaration.
+  let overlaps_other = |rule: &StyleRule<'i, T>, property: &Property<'i>, important: bool, id: &PropertyId<'i>| {
+    rule.declarations.iter().any(|(p, is_important)| {
+      !(p == property && is_important == important) && properties_overlap(&p.property_id(), id)
+    })
+  };
+
+  for (property, important) in style.declarations.iter() {
+    let id = property.property_id();
+    if overlaps_other(style, property, important, &id) {
+      continue;
+    }
+
+    let mut candidates = vec![index];
+    for (i, rule) in rules.iter().enumerate().skip(index + 1) {
+      let other = match rule {
+        CssRule::Ignored => continue,
+        CssRule::Style(other) => other,
+        _ => break,
+      };
+
+      if other.rules.0.is_empty()
+        && other.vendor_prefix == style.vendor_prefix
+        && (!context.css_modules || other.loc.source_index == style.loc.source_index)
+        && other.is_compatible(*context.targets)
+        && other
+          .declarations
+          .iter()
+          .any(|(p, is_important)| p == property && is_important == important)
+        && !overlaps_other(other, property, important, &id)
+      {
+        candidates.push(i);
+        continue;
+      }
+
+      // Nested rules may declare any property, so don't move declarations across them.
+      if !other.rules.0.is_empty()
+        || other
+          .declarations
+          .iter()
+          .any(|(p, _)| properties_overlap(&p.property_id(), &id))
+      {
+        break;
+      }
+    }
+
+    if candidates.len() > 1 && is_hoisting_smaller(rules, &candidates, property, important) {
+      return Some((property.clone(), important, candidates));
+    }
+  }
+
+  None
+}
+
+/// Returns whether moving the declaration out of the given style rules into a new rule
+/// with their combined selectors reduces the size of the minified output.
+fn is_hoisting_smaller<'i, T>(
+  rules: &[CssRule<'i, T>],
+  candidates: &[usize],
+  property: &Property<'i>,
+  important: bool,
+) -> bool {
+  let options = || PrinterOptions {
+    minify: true,
+    ..PrinterOptions::default()
+  };
+
+  let declaration_len = match property.to_css_string(important, options()) {
+    Ok(s) => s.len(),
+    Err(_) => return false,
+  };
+
+  // The new rule contains the combined selectors separated by commas, and the declaration.
+  let mut cost = candidates.len() - 1 + 2 + declaration_len;
+  let mut saved = 0;
+  for &i in candidates {
+    if let CssRule::Style(style) = &rules[i] {
+      let selectors_len = match style.selectors.to_css_string(options()) {
+        Ok(s) => s.len(),
+        Err(_) => return false,
+      };
+      cost += selectors_len;
+
+      // Rules that only contain the declaration are removed entirely.
+      saved += if style.declarations.len() == 1 {
+        selectors_len + 2 + declaration_len
+      } else {
+        declaration_len + 1
+      };
+    }
+  }
+
+  cost < saved
+}
+
+/// Returns whether the later property always overrides the earlier one when both apply to an element.
+fn overrides_property(later: &Property, earlier: &Property, targets: &Targets) -> bool {
+  // The earlier declaration may be a fallback for browsers that do not support the later value.
+  // Unparsed values and unknown properties may be unsupported, so they are never considered overriding.
+  if matches!(
+    later,
+    Property::Unparsed(_)
+      | Property::Custom(CustomProperty {
+        name: CustomPropertyName::Unknown(_),
+        ..
+      })
+  ) {
+    return false;
+  }
+
+  if let Some(browsers) = targets.browsers {
+    if later.is_compatible(browsers) == Some(false) {
+      return false;
+    }
+  }
+
+  fn contains_longhand(shorthand: &PropertyId, id: &PropertyId) -> bool {
+    shorthand == id
+      || shorthand.longhands().map_or(false, |longhands| {
+        longhands.iter().any(|longhand| contains_longhand(longhand, id))
+      })
+  }
+
+  contains_longhand(&later.property_id(), &earlier.property_id())
+}
+
+/// Removes all but the last descriptor with each name, e.g. in an `@counter-style` rule,
+/// since only the last one applies. The remaining descriptors keep their relative order.
+pub(crate) fn remove_duplicate_descriptors<T>(descriptors: &mut Vec<T>, name: impl Fn(&T) -> &str) {
+  let mut result: Vec<T> = Vec::with_capacity(descriptors.len());
+  for descriptor in descriptors.drain(..) {
+    result.retain(|d| name(d) != name(&descriptor));
+    result.push(descriptor);
+  }
+  *descriptors = result;
+}
+
 fn merge_style_rules<'i, T>(
   style: &mut StyleRule<'i, T>,
   last_style_rule: &mut StyleRule<'i, T>,
   context: &mut MinifyContext<'_, 'i>,
 ) -> bool {
+  phase!("minify: merge rules");
   // Merge declarations if the selectors are equivalent, and both are compatible with all targets.
-  if style.selectors == last_style_rule.selectors
+  // Selectors that only differ in order, e.g. `.a.b` and `.b.a`, are considered equivalent.
+  if is_canonically_equal(&style.selectors.0, &last_style_rule.selectors.0)
     && style.is_compatible(*context.targets)
     && last_style_rule.is_compatible(*context.targets)
     && style.rules.0.is_empty()
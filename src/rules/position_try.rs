@@ -0,0 +1,178 @@
+//! The `@position-try` rule.
+
+use super::{Location, MinifyContext};
+use crate::context::DeclarationContext;
+use crate::declaration::{parse_declaration, DeclarationBlock};
+use crate::error::{ParserError, PrinterError};
+use crate::logical::LogicalGroup;
+use crate::printer::Printer;
+use crate::properties::PropertyId;
+use crate::stylesheet::ParserOptions;
+use crate::traits::ToCss;
+use crate::values::ident::DashedIdent;
+use crate::values::string::CowArcStr;
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+/// A [@position-try](https://drafts.csswg.org/css-anchor-position-1/#fallback-rule) rule.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct PositionTryRule<'i> {
+  /// The name of the position try option.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: DashedIdent<'i>,
+  /// The declarations within the `@position-try` rule.
+  pub declarations: DeclarationBlock<'i>,
+  /// The location of the rule in the source file.
+  #[cfg_attr(feature = "visitor", skip_visit)]
+  pub loc: Location,
+}
+
+impl<'i> PositionTryRule<'i> {
+  pub(crate) fn parse<'t, 'o>(
+    name: DashedIdent<'i>,
+    input: &mut Parser<'i, 't>,
+    loc: Location,
+    options: &ParserOptions<'o, 'i>,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut declarations = DeclarationBlock::new();
+    let mut decl_parser = PositionTryDeclarationParser {
+      declarations: &mut declarations,
+      options,
+    };
+    let parser = RuleBodyParser::new(input, &mut decl_parser);
+
+    for decl in parser {
+      if let Err((err, _)) = decl {
+        if options.error_recovery {
+          options.warn(err);
+          continue;
+        }
+        return Err(err);
+      }
+    }
+
+    Ok(PositionTryRule {
+      name,
+      declarations,
+      loc,
+    })
+  }
+
+  pub(crate) fn minify(&mut self, context: &mut MinifyContext<'_, 'i>) {
+    context.handler_context.context = DeclarationContext::PositionTry;
+    self
+      .declarations
+      .minify(context.handler, context.important_handler, &mut context.handler_context);
+    context.handler_context.context = DeclarationContext::None;
+  }
+}
+
+impl<'i> ToCss for PositionTryRule<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    #[cfg(feature = "sourcemap")]
+    dest.add_mapping(self.loc);
+    dest.write_str("@position-try ")?;
+    self.name.to_css(dest)?;
+    self.declarations.to_css_block(dest)
+  }
+}
+
+/// Returns whether a property is accepted within a `@position-try` rule.
+/// https://drafts.csswg.org/css-anchor-position-1/#accepted-position-try-properties
+fn is_accepted_property(property_id: &PropertyId) -> bool {
+  if matches!(
+    property_id.logical_group(),
+    Some(
+      LogicalGroup::Inset
+        | LogicalGroup::Margin
+        | LogicalGroup::Size
+        | LogicalGroup::MinSize
+        | LogicalGroup::MaxSize
+    )
+  ) {
+    return true;
+  }
+
+  matches!(
+    property_id,
+    PropertyId::Inset
+      | PropertyId::InsetBlock
+      | PropertyId::InsetInline
+      | PropertyId::Margin
+      | PropertyId::MarginBlock
+      | PropertyId::MarginInline
+      | PropertyId::AlignSelf(_)
+      | PropertyId::JustifySelf
+      | PropertyId::PlaceSelf
+      | PropertyId::PositionAnchor
+      | PropertyId::PositionArea
+      | PropertyId::InsetArea
+  )
+}
+
+struct PositionTryDeclarationParser<'a, 'o, 'i> {
+  declarations: &'a mut DeclarationBlock<'i>,
+  options: &'a ParserOptions<'o, 'i>,
+}
+
+impl<'a, 'o, 'i> cssparser::DeclarationParser<'i> for PositionTryDeclarationParser<'a, 'o, 'i> {
+  type Declaration = ();
+  type Error = ParserError<'i>;
+
+  fn parse_value<'t>(
+    &mut self,
+    name: CowRcStr<'i>,
+    input: &mut cssparser::Parser<'i, 't>,
+  ) -> Result<Self::Declaration, cssparser::ParseError<'i, Self::Error>> {
+    if !is_accepted_property(&PropertyId::from(CowArcStr::from(name.clone()))) {
+      return Err(input.new_custom_error(ParserError::DisallowedProperty(name.into())));
+    }
+
+    // Declarations marked as !important are invalid within @position-try.
+    let important_len = self.declarations.important_declarations.len();
+    parse_declaration(
+      name,
+      input,
+      &mut self.declarations.declarations,
+      &mut self.declarations.important_declarations,
+      self.options,
+    )?;
+    if self.declarations.important_declarations.len() > important_len {
+      self.declarations.important_declarations.pop();
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(())
+  }
+}
+
+/// Default methods reject all at rules.
+impl<'a, 'o, 'i> AtRuleParser<'i> for PositionTryDeclarationParser<'a, 'o, 'i> {
+  type Prelude = ();
+  type AtRule = ();
+  type Error = ParserError<'i>;
+}
+
+impl<'a, 'o, 'i> QualifiedRuleParser<'i> for PositionTryDeclarationParser<'a, 'o, 'i> {
+  type Prelude = ();
+  type QualifiedRule = ();
+  type Error = ParserError<'i>;
+}
+
+impl<'a, 'o, 'i> RuleBodyItemParser<'i, (), ParserError<'i>> for PositionTryDeclarationParser<'a, 'o, 'i> {
+  fn parse_qualified(&self) -> bool {
+    false
+  }
+
+  fn parse_declarations(&self) -> bool {
+    true
+  }
+}
@@ -8,16 +8,27 @@ use super::MinifyContext;
 use crate::context::DeclarationContext;
 use crate::declaration::DeclarationBlock;
 use crate::error::ParserError;
-use crate::error::{MinifyError, PrinterError, PrinterErrorKind};
+use crate::error::{ErrorWithLocation, MinifyError, PrinterError, PrinterErrorKind};
+use crate::macros::phase;
 use crate::parser::DefaultAtRule;
 use crate::printer::Printer;
 use crate::rules::CssRuleList;
-use crate::selector::{downlevel_selectors, get_prefix, is_compatible, is_unused, SelectorList};
+use crate::selector::{
+  canonical_hash, downlevel_dir_to_attribute, downlevel_selectors, expand_is_selectors, get_prefix,
+  is_canonically_equal, is_compatible, is_purged, is_unused, SelectorList,
+};
+use crate::stylesheet::DirStrategy;
 use crate::targets::{should_compile, Targets};
 use crate::traits::ToCss;
 use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
+#[cfg(feature = "into_owned")]
+use crate::{
+  rules::CssRule,
+  selector::{scope_selectors, AttrSelectorOperator, Component, Scope},
+  stylesheet::SelectorScope,
+};
 use cssparser::*;
 
 /// A CSS [style rule](https://drafts.csswg.org/css-syntax/#style-rules).
@@ -57,15 +68,42 @@ impl<'i, T: Clone> StyleRule<'i, T> {
     parent_is_unused: bool,
   ) -> Result<bool, MinifyError> {
     let mut unused = false;
-    if !context.unused_symbols.is_empty() {
-      if is_unused(&mut self.selectors.0.iter(), &context.unused_symbols, parent_is_unused) {
-        if self.rules.0.is_empty() {
-          return Ok(true);
-        }
+    let is_rule_unused = {
+      phase!("minify: remove unused rules");
+      is_unused(&mut self.selectors.0.iter(), context.unused_symbols, parent_is_unused)
+        || context
+          .purge
+          .map_or(false, |purge| is_purged(&self.selectors.0, purge, parent_is_unused))
+    };
+
+    if is_rule_unused {
+      if self.rules.0.is_empty() {
+        return Ok(true);
+      }
 
-        self.declarations.declarations.clear();
-        self.declarations.important_declarations.clear();
-        unused = true;
+      self.declarations.declarations.clear();
+      self.declarations.important_declarations.clear();
+      unused = true;
+    } else if let Some(purge) = context.purge {
+      // Remove the selectors in the list that can never match.
+      if self.selectors.0.len() > 1 {
+        self
+          .selectors
+          .0
+          .retain(|selector| !is_purged(std::slice::from_ref(selector), purge, parent_is_unused));
+      }
+    }
+
+    if let Some(limit) = context.expand_is_selectors {
+      if should_compile!(context.targets, IsSelector) {
+        for selector in expand_is_selectors(&mut self.selectors, limit) {
+          context.warnings.push(ErrorWithLocation {
+            kind: ParserError::UnexpandableSelector(
+              selector.to_css_string(Default::default()).unwrap_or_default().into(),
+            ),
+            loc: self.loc,
+          });
+        }
       }
     }
 
@@ -135,7 +173,7 @@ impl<'i, T> StyleRule<'i, T> {
   #[inline]
   pub(crate) fn hash_key(&self) -> u64 {
     let mut hasher = ahash::AHasher::default();
-    self.selectors.hash(&mut hasher);
+    canonical_hash(&self.selectors.0).hash(&mut hasher);
     for (property, _) in self.declarations.iter() {
       property.property_id().hash(&mut hasher);
     }
@@ -143,21 +181,22 @@ impl<'i, T> StyleRule<'i, T> {
   }
 
   /// Returns whether this rule is a duplicate of another rule.
-  /// This means it has the same selectors and properties.
+  /// This means it has the same selectors, in canonical order, and properties.
   #[inline]
   pub(crate) fn is_duplicate(&self, other_rule: &StyleRule<'i, T>) -> bool {
     self.declarations.len() == other_rule.declarations.len()
-      && self.selectors == other_rule.selectors
-      && self
-        .declarations
-        .iter()
-        .zip(other_rule.declarations.iter())
-        .all(|((a, _), (b, _))| a.property_id() == b.property_id())
+      && is_canonically_equal(&self.selectors.0, &other_rule.selectors.0)
+      && self.declarations.iter().zip(other_rule.declarations.iter()).all(
+        |((a, a_important), (b, b_important))| a_important == b_important && a.property_id() == b.property_id(),
+      )
   }
 
   pub(crate) fn update_prefix(&mut self, context: &mut MinifyContext<'_, 'i>) {
     self.vendor_prefix = get_prefix(&self.selectors);
     if self.vendor_prefix.contains(VendorPrefix::None) && context.targets.should_compile_selectors() {
+      if context.dir_strategy == DirStrategy::Attribute && should_compile!(context.targets, DirSelector) {
+        downlevel_dir_to_attribute(self.selectors.0.as_mut_slice());
+      }
       self.vendor_prefix = downlevel_selectors(self.selectors.0.as_mut_slice(), *context.targets);
     }
   }
@@ -310,3 +349,41 @@ impl<'a, 'i, T: ToCss> StyleRule<'i, T> {
     Ok(())
   }
 }
+
+/// Scopes the selectors of the style rules in the given list, including nested rules
+/// and rules within conditional group rules.
+#[cfg(feature = "into_owned")]
+pub(crate) fn scope_style_rules<R>(rules: &mut CssRuleList<'_, R>, scope: &SelectorScope) {
+  use static_self::IntoOwned;
+
+  let scope = match scope {
+    SelectorScope::Ancestor(selector) => Scope::Ancestor(selector.clone().into_owned()),
+    SelectorScope::Attribute { name, value } => Scope::Attribute(match value {
+      Some(value) => Component::attribute(name.clone().into(), AttrSelectorOperator::Equal, value.clone().into()),
+      None => Component::attribute_exists(name.clone().into()),
+    }),
+  };
+  scope_rules(rules, &scope, false);
+}
+
+#[cfg(feature = "into_owned")]
+fn scope_rules<'i, R>(rules: &mut CssRuleList<'i, R>, scope: &Scope<'i>, is_nested: bool) {
+  for rule in &mut rules.0 {
+    match rule {
+      CssRule::Style(style) => {
+        scope_selectors(&mut style.selectors.0, scope, is_nested);
+        scope_rules(&mut style.rules, scope, true);
+      }
+      CssRule::Nesting(nesting) => {
+        scope_selectors(&mut nesting.style.selectors.0, scope, true);
+        scope_rules(&mut nesting.style.rules, scope, true);
+      }
+      CssRule::Media(media) => scope_rules(&mut media.rules, scope, is_nested),
+      CssRule::Supports(supports) => scope_rules(&mut supports.rules, scope, is_nested),
+      CssRule::Container(container) => scope_rules(&mut container.rules, scope, is_nested),
+      CssRule::LayerBlock(layer) => scope_rules(&mut layer.rules, scope, is_nested),
+      CssRule::StartingStyle(starting_style) => scope_rules(&mut starting_style.rules, scope, is_nested),
+      _ => {}
+    }
+  }
+}
@@ -4,11 +4,15 @@ use std::collections::HashMap;
 
 use super::Location;
 use super::{CssRuleList, MinifyContext};
+use crate::compat::Feature;
 use crate::error::{MinifyError, ParserError, PrinterError};
 use crate::parser::DefaultAtRule;
 use crate::printer::Printer;
-use crate::properties::PropertyId;
-use crate::targets::Targets;
+use crate::properties::{Property, PropertyId};
+use crate::rules::font_face::{FontFormat, FontTechnology};
+use crate::selector::{ParseSelector, SelectorList};
+use crate::stylesheet::ParserOptions;
+use crate::targets::{Browsers, Targets};
 use crate::traits::{Parse, ToCss};
 use crate::values::string::CowArcStr;
 use crate::vendor_prefix::VendorPrefix;
@@ -103,12 +107,28 @@ pub enum SupportsCondition<'i> {
   /// A selector to evaluate.
   #[cfg_attr(feature = "serde", serde(with = "ValueWrapper::<CowArcStr>"))]
   Selector(CowArcStr<'i>),
-  // FontTechnology()
+  /// A `font-format()` function to evaluate.
+  #[cfg_attr(feature = "serde", serde(with = "ValueWrapper::<FontFormat>"))]
+  FontFormat(FontFormat<'i>),
+  /// A `font-tech()` function to evaluate.
+  #[cfg_attr(feature = "serde", serde(with = "ValueWrapper::<FontTechnology>"))]
+  FontTechnology(FontTechnology),
   /// An unknown condition.
   #[cfg_attr(feature = "serde", serde(with = "ValueWrapper::<CowArcStr>"))]
   Unknown(CowArcStr<'i>),
 }
 
+/// The result of evaluating a [SupportsCondition](SupportsCondition) against browser targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SupportsResult {
+  /// The condition is true in all targets.
+  Supported,
+  /// The condition is false in all targets.
+  Unsupported,
+  /// The condition differs between targets, or could not be determined.
+  Unknown,
+}
+
 impl<'i> SupportsCondition<'i> {
   /// Combines the given supports condition into this one with an `and` expression.
   pub fn and(&mut self, b: &SupportsCondition<'i>) {
@@ -132,7 +152,95 @@ impl<'i> SupportsCondition<'i> {
     }
   }
 
-  fn set_prefixes_for_targets(&mut self, targets: &Targets) {
+  /// Evaluates the supports condition against the given browser targets, using the
+  /// compatibility data included in Lightning CSS.
+  ///
+  /// Returns [Supported](SupportsResult::Supported) if the condition is true in all targets,
+  /// [Unsupported](SupportsResult::Unsupported) if it is false in all targets, and
+  /// [Unknown](SupportsResult::Unknown) if the result differs between targets or
+  /// there is no compatibility data to determine it.
+  pub fn evaluate(&self, targets: &Targets) -> SupportsResult {
+    let results: Vec<Option<bool>> = match targets.browsers {
+      Some(browsers) => browsers.split().into_iter().map(|b| self.evaluate_browser(Some(b))).collect(),
+      None => vec![self.evaluate_browser(None)],
+    };
+
+    if !results.is_empty() && results.iter().all(|r| *r == Some(true)) {
+      SupportsResult::Supported
+    } else if !results.is_empty() && results.iter().all(|r| *r == Some(false)) {
+      SupportsResult::Unsupported
+    } else {
+      SupportsResult::Unknown
+    }
+  }
+
+  fn evaluate_browser(&self, browsers: Option<Browsers>) -> Option<bool> {
+    match self {
+      SupportsCondition::Not(cond) => cond.evaluate_browser(browsers).map(|r| !r),
+      SupportsCondition::And(items) => {
+        let mut res = Some(true);
+        for item in items {
+          match item.evaluate_browser(browsers) {
+            Some(false) => return Some(false),
+            None => res = None,
+            Some(true) => {}
+          }
+        }
+        res
+      }
+      SupportsCondition::Or(items) => {
+        let mut res = Some(false);
+        for item in items {
+          match item.evaluate_browser(browsers) {
+            Some(true) => return Some(true),
+            None => res = None,
+            Some(false) => {}
+          }
+        }
+        res
+      }
+      SupportsCondition::Declaration { property_id, value } => {
+        let property =
+          Property::parse_string(property_id.clone(), value.as_ref(), ParserOptions::default()).ok()?;
+        match property {
+          Property::Unparsed(..) | Property::Custom(..) => None,
+          property => property.is_compatible(browsers?),
+        }
+      }
+      SupportsCondition::Selector(sel) => {
+        let browsers = browsers?;
+        if !Feature::SupportsSelector.is_compatible(browsers) {
+          return Some(false);
+        }
+        match SelectorList::parse_string(sel.as_ref()) {
+          Ok(selectors) => Some(crate::selector::is_compatible(&selectors.0, Targets::from(browsers))),
+          Err(..) => Some(false),
+        }
+      }
+      SupportsCondition::FontFormat(format) => {
+        let browsers = browsers?;
+        if !Feature::SupportsFontFormat.is_compatible(browsers) {
+          return Some(false);
+        }
+        match format.compat_feature() {
+          Some(feature) => Some(feature.is_compatible(browsers)),
+          // Every browser that supports font-format() also supports these.
+          None if matches!(format, FontFormat::TrueType | FontFormat::OpenType) => Some(true),
+          None => None,
+        }
+      }
+      SupportsCondition::FontTechnology(..) => {
+        // Support for individual font technologies varies too much to resolve statically.
+        if !Feature::SupportsFontTech.is_compatible(browsers?) {
+          return Some(false);
+        }
+        None
+      }
+      SupportsCondition::Unknown(..) => None,
+    }
+  }
+
+  pub(crate) fn set_prefixes_for_targets(&mut self, targets: &Targets) {
     match self {
       SupportsCondition::Not(cond) => cond.set_prefixes_for_targets(targets),
       SupportsCondition::And(items) | SupportsCondition::Or(items) => {
@@ -250,6 +358,28 @@ impl<'i> SupportsCondition<'i> {
               return res
             }
           },
+          "font-format" => {
+            let res = input.try_parse(|input| {
+              input.parse_nested_block(|input| {
+                let location = input.current_source_location();
+                match FontFormat::parse(input)? {
+                  FontFormat::String(..) => Err(location.new_custom_error(ParserError::InvalidValue)),
+                  format => Ok(SupportsCondition::FontFormat(format)),
+                }
+              })
+            });
+            if res.is_ok() {
+              return res
+            }
+          },
+          "font-tech" => {
+            let res = input.try_parse(|input| {
+              input.parse_nested_block(|input| Ok(SupportsCondition::FontTechnology(FontTechnology::parse(input)?)))
+            });
+            if res.is_ok() {
+              return res
+            }
+          },
           _ => {}
         }
       }
@@ -380,6 +510,16 @@ impl<'i> ToCss for SupportsCondition<'i> {
         dest.write_str(sel)?;
         dest.write_char(')')
       }
+      SupportsCondition::FontFormat(format) => {
+        dest.write_str("font-format(")?;
+        dest.write_str(format.as_str())?;
+        dest.write_char(')')
+      }
+      SupportsCondition::FontTechnology(tech) => {
+        dest.write_str("font-tech(")?;
+        tech.to_css(dest)?;
+        dest.write_char(')')
+      }
       SupportsCondition::Unknown(unknown) => dest.write_str(&unknown),
     }
   }
@@ -0,0 +1,401 @@
+//! The `@when` and `@else` rules.
+
+use super::supports::{SupportsCondition, SupportsResult};
+use super::Location;
+use super::{CssRuleList, MinifyContext};
+use crate::error::{MinifyError, ParserError, PrinterError};
+use crate::media_query::{MediaCondition, MediaFeature, MediaQuery, MediaType, QueryFeature};
+use crate::parser::DefaultAtRule;
+use crate::printer::Printer;
+use crate::targets::Targets;
+use crate::traits::{Parse, ToCss};
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+/// A [@when](https://drafts.csswg.org/css-conditional-5/#when-rule) rule,
+/// along with the chain of `@else` rules that follow it.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct WhenRule<'i, R = DefaultAtRule> {
+  /// The condition of the `@when` rule.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub condition: WhenCondition<'i>,
+  /// The rules within the `@when` rule.
+  pub rules: CssRuleList<'i, R>,
+  /// The `@else` rules chained to the `@when` rule.
+  pub else_rules: Vec<ElseRule<'i, R>>,
+  /// The location of the rule in the source file.
+  #[cfg_attr(feature = "visitor", skip_visit)]
+  pub loc: Location,
+}
+
+/// An [@else](https://drafts.csswg.org/css-conditional-5/#else-rule) rule.
+///
+/// See [WhenRule](WhenRule).
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct ElseRule<'i, R = DefaultAtRule> {
+  /// The condition of the `@else` rule, if any.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub condition: Option<WhenCondition<'i>>,
+  /// The rules within the `@else` rule.
+  pub rules: CssRuleList<'i, R>,
+  /// The location of the rule in the source file.
+  #[cfg_attr(feature = "visitor", skip_visit)]
+  pub loc: Location,
+}
+
+/// A [`<boolean-condition>`](https://drafts.csswg.org/css-conditional-5/#typedef-boolean-condition),
+/// as used in the `@when` and `@else` rules.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum WhenCondition<'i> {
+  /// A `media()` test.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Media(MediaQuery<'i>),
+  /// A `supports()` test.
+  Supports(SupportsCondition<'i>),
+  /// A `not` expression.
+  #[cfg_attr(feature = "visitor", skip_type)]
+  Not(Box<WhenCondition<'i>>),
+  /// An `and` expression.
+  #[cfg_attr(feature = "visitor", skip_type)]
+  And(Vec<WhenCondition<'i>>),
+  /// An `or` expression.
+  #[cfg_attr(feature = "visitor", skip_type)]
+  Or(Vec<WhenCondition<'i>>),
+}
+
+impl<'i> Parse<'i> for WhenCondition<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("not")).is_ok() {
+      let test = Self::parse_test(input)?;
+      return Ok(WhenCondition::Not(Box::new(test)));
+    }
+
+    let first = Self::parse_test(input)?;
+    let mut is_and = None;
+    let mut conditions = vec![first];
+    loop {
+      let location = input.current_source_location();
+      let Ok(ident) = input.try_parse(|input| input.expect_ident_cloned()) else {
+        break;
+      };
+
+      let found_and = match_ignore_ascii_case! { &ident,
+        "and" => true,
+        "or" => false,
+        _ => return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
+      };
+
+      // `and` and `or` cannot be mixed without parentheses.
+      if *is_and.get_or_insert(found_and) != found_and {
+        return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())));
+      }
+
+      conditions.push(Self::parse_test(input)?);
+    }
+
+    match is_and {
+      Some(true) => Ok(WhenCondition::And(conditions)),
+      Some(false) => Ok(WhenCondition::Or(conditions)),
+      None => Ok(conditions.pop().unwrap()),
+    }
+  }
+}
+
+impl<'i> WhenCondition<'i> {
+  fn parse_test<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    match input.next()?.clone() {
+      Token::ParenthesisBlock => input.parse_nested_block(WhenCondition::parse),
+      Token::Function(ref f) if f.eq_ignore_ascii_case("media") => {
+        input.parse_nested_block(|input| Ok(WhenCondition::Media(parse_media_test(input)?)))
+      }
+      Token::Function(ref f) if f.eq_ignore_ascii_case("supports") => input.parse_nested_block(|input| {
+        let condition = input
+          .try_parse(SupportsCondition::parse)
+          .or_else(|_| SupportsCondition::parse_declaration(input))?;
+        Ok(WhenCondition::Supports(condition))
+      }),
+      t => Err(location.new_unexpected_token_error(t)),
+    }
+  }
+
+  /// Evaluates the condition against the given browser targets. Returns `None` if
+  /// the result cannot be statically determined.
+  fn evaluate(&self, targets: &Targets) -> Option<bool> {
+    match self {
+      WhenCondition::Media(query) => {
+        if query.always_matches() {
+          Some(true)
+        } else if query.never_matches() {
+          Some(false)
+        } else {
+          None
+        }
+      }
+      WhenCondition::Supports(condition) => match condition.evaluate(targets) {
+        SupportsResult::Supported => Some(true),
+        SupportsResult::Unsupported => Some(false),
+        SupportsResult::Unknown => None,
+      },
+      WhenCondition::Not(condition) => condition.evaluate(targets).map(|r| !r),
+      WhenCondition::And(conditions) => {
+        let mut res = Some(true);
+        for condition in conditions {
+          match condition.evaluate(targets) {
+            Some(false) => return Some(false),
+            None => res = None,
+            Some(true) => {}
+          }
+        }
+        res
+      }
+      WhenCondition::Or(conditions) => {
+        let mut res = Some(false);
+        for condition in conditions {
+          match condition.evaluate(targets) {
+            Some(true) => return Some(true),
+            None => res = None,
+            Some(false) => {}
+          }
+        }
+        res
+      }
+    }
+  }
+
+  fn set_prefixes_for_targets(&mut self, targets: &Targets) {
+    match self {
+      WhenCondition::Media(..) => {}
+      WhenCondition::Supports(condition) => condition.set_prefixes_for_targets(targets),
+      WhenCondition::Not(condition) => condition.set_prefixes_for_targets(targets),
+      WhenCondition::And(conditions) | WhenCondition::Or(conditions) => {
+        for condition in conditions {
+          condition.set_prefixes_for_targets(targets);
+        }
+      }
+    }
+  }
+
+  fn needs_parens(&self, parent: &WhenCondition) -> bool {
+    match self {
+      WhenCondition::Not(_) => true,
+      WhenCondition::And(_) | WhenCondition::Or(_) => !matches!(
+        (self, parent),
+        (WhenCondition::And(_), WhenCondition::And(_)) | (WhenCondition::Or(_), WhenCondition::Or(_))
+      ),
+      _ => false,
+    }
+  }
+
+  fn to_css_with_parens_if_needed<W>(&self, dest: &mut Printer<W>, needs_parens: bool) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if needs_parens {
+      dest.write_char('(')?;
+    }
+    self.to_css(dest)?;
+    if needs_parens {
+      dest.write_char(')')?;
+    }
+    Ok(())
+  }
+}
+
+/// Parses the contents of a `media()` test, which is either a media query or a single media feature.
+fn parse_media_test<'i>(input: &mut Parser<'i, '_>) -> Result<MediaQuery<'i>, ParseError<'i, ParserError<'i>>> {
+  if let Ok(query) = input.try_parse(parse_media_query) {
+    return Ok(query);
+  }
+
+  let feature = MediaFeature::parse(input)?;
+  Ok(MediaQuery {
+    qualifier: None,
+    media_type: MediaType::All,
+    condition: Some(MediaCondition::Feature(feature)),
+  })
+}
+
+fn parse_media_query<'i>(input: &mut Parser<'i, '_>) -> Result<MediaQuery<'i>, ParseError<'i, ParserError<'i>>> {
+  let query = MediaQuery::parse(input)?;
+  input.expect_exhausted()?;
+  Ok(query)
+}
+
+impl<'i> ToCss for WhenCondition<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      WhenCondition::Media(query) => {
+        dest.write_str("media")?;
+        match query {
+          // A single media feature already includes its own parentheses.
+          MediaQuery {
+            qualifier: None,
+            media_type: MediaType::All,
+            condition: Some(MediaCondition::Feature(feature)),
+          } if !matches!(feature, QueryFeature::Interval { .. }) => feature.to_css(dest),
+          _ => {
+            dest.write_char('(')?;
+            query.to_css(dest)?;
+            dest.write_char(')')
+          }
+        }
+      }
+      WhenCondition::Supports(condition) => {
+        dest.write_str("supports")?;
+        if matches!(condition, SupportsCondition::Declaration { .. }) {
+          condition.to_css(dest)
+        } else {
+          dest.write_char('(')?;
+          condition.to_css(dest)?;
+          dest.write_char(')')
+        }
+      }
+      WhenCondition::Not(condition) => {
+        dest.write_str("not ")?;
+        condition.to_css_with_parens_if_needed(dest, condition.needs_parens(self))
+      }
+      WhenCondition::And(conditions) | WhenCondition::Or(conditions) => {
+        let operator = if matches!(self, WhenCondition::And(_)) {
+          " and "
+        } else {
+          " or "
+        };
+        let mut first = true;
+        for condition in conditions {
+          if first {
+            first = false;
+          } else {
+            dest.write_str(operator)?;
+          }
+          condition.to_css_with_parens_if_needed(dest, condition.needs_parens(self))?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+impl<'i, T> WhenRule<'i, T> {
+  /// Returns whether an `@else` rule can be chained to this rule.
+  pub(crate) fn accepts_else(&self) -> bool {
+    self.else_rules.iter().all(|rule| rule.condition.is_some())
+  }
+}
+
+impl<'i, T: Clone> WhenRule<'i, T> {
+  /// Minifies the rule. If the chain can be statically resolved, returns the
+  /// rules that should replace it, which may be empty.
+  pub(crate) fn minify(
+    &mut self,
+    context: &mut MinifyContext<'_, 'i>,
+    parent_is_unused: bool,
+  ) -> Result<Option<CssRuleList<'i, T>>, MinifyError> {
+    let first = ElseRule {
+      condition: Some(self.condition.clone()),
+      rules: std::mem::replace(&mut self.rules, CssRuleList(vec![])),
+      loc: self.loc,
+    };
+
+    // Remove branches that never match, and branches following one that always matches.
+    let mut branches = Vec::with_capacity(self.else_rules.len() + 1);
+    for mut branch in std::iter::once(first).chain(self.else_rules.drain(..)) {
+      branch.rules.minify(context, parent_is_unused)?;
+      if let Some(condition) = &mut branch.condition {
+        condition.set_prefixes_for_targets(context.targets);
+        match condition.evaluate(context.targets) {
+          Some(false) => continue,
+          Some(true) => branch.condition = None,
+          None => {}
+        }
+      }
+
+      let is_last = branch.condition.is_none();
+      branches.push(branch);
+      if is_last {
+        break;
+      }
+    }
+
+    // Trailing empty branches have no effect.
+    while matches!(branches.last(), Some(branch) if branch.rules.0.is_empty()) {
+      branches.pop();
+    }
+
+    let mut branches = branches.into_iter();
+    let Some(first) = branches.next() else {
+      return Ok(Some(CssRuleList(vec![])));
+    };
+
+    match first.condition {
+      Some(condition) => {
+        self.condition = condition;
+        self.rules = first.rules;
+        self.else_rules = branches.collect();
+        Ok(None)
+      }
+      None => Ok(Some(first.rules)),
+    }
+  }
+}
+
+impl<'i, T: ToCss> ToCss for WhenRule<'i, T> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    #[cfg(feature = "sourcemap")]
+    dest.add_mapping(self.loc);
+    dest.write_str("@when ")?;
+    self.condition.to_css(dest)?;
+    write_block(&self.rules, dest)?;
+
+    for rule in &self.else_rules {
+      dest.whitespace()?;
+      #[cfg(feature = "sourcemap")]
+      dest.add_mapping(rule.loc);
+      dest.write_str("@else")?;
+      if let Some(condition) = &rule.condition {
+        dest.write_char(' ')?;
+        condition.to_css(dest)?;
+      }
+      write_block(&rule.rules, dest)?;
+    }
+
+    Ok(())
+  }
+}
+
+fn write_block<T: ToCss, W>(rules: &CssRuleList<'_, T>, dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  dest.whitespace()?;
+  dest.write_char('{')?;
+  dest.indent();
+  dest.newline()?;
+  rules.to_css(dest)?;
+  dest.dedent();
+  dest.newline()?;
+  dest.write_char('}')
+}
@@ -6,7 +6,7 @@ use crate::parser::ParserFlags;
 use crate::printer::Printer;
 use crate::properties::custom::TokenList;
 use crate::rules::StyleContext;
-use crate::stylesheet::{ParserOptions, PrinterOptions};
+use crate::stylesheet::{ParserOptions, PrinterOptions, PurgeOptions};
 use crate::targets::{should_compile, Targets};
 use crate::traits::{Parse, ParseWithOptions, ToCss};
 use crate::values::ident::{CustomIdent, Ident};
@@ -18,11 +18,13 @@ use crate::{macros::enum_property, values::string::CowArcStr};
 use cssparser::*;
 use parcel_selectors::parser::{NthType, SelectorParseErrorKind};
 use parcel_selectors::{
-  attr::{AttrSelectorOperator, ParsedAttrSelectorOperation, ParsedCaseSensitivity},
+  attr::{ParsedAttrSelectorOperation, ParsedCaseSensitivity},
   parser::SelectorImpl,
 };
-use std::collections::HashSet;
+use smallvec::SmallVec;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
+use std::hash::{Hash, Hasher};
 
 #[cfg(feature = "serde")]
 use crate::serialization::*;
@@ -60,6 +62,8 @@ pub type SelectorList<'i> = parcel_selectors::SelectorList<'i, Selectors>;
 pub type Selector<'i> = parcel_selectors::parser::Selector<'i, Selectors>;
 /// An individual component within a selector.
 pub type Component<'i> = parcel_selectors::parser::Component<'i, Selectors>;
+/// An attribute selector operator.
+pub use parcel_selectors::attr::AttrSelectorOperator;
 /// A combinator.
 pub use parcel_selectors::parser::Combinator;
 
@@ -89,6 +93,56 @@ pub(crate) struct SelectorParser<'a, 'o, 'i> {
   pub options: &'a ParserOptions<'o, 'i>,
 }
 
+enum CustomPseudoArguments<'i> {
+  Selectors(SelectorList<'i>),
+  Tokens(TokenList<'i>),
+}
+
+fn lookup_custom_pseudo(registry: &HashMap<String, CustomPseudoSyntax>, name: &str) -> Option<CustomPseudoSyntax> {
+  if registry.is_empty() {
+    return None;
+  }
+
+  registry.get(name).or_else(|| registry.get(&name.to_ascii_lowercase())).copied()
+}
+
+impl<'a, 'o, 'i> SelectorParser<'a, 'o, 'i> {
+  /// Parses the arguments of a non-standard functional pseudo class or pseudo element
+  /// according to the syntax it was registered with. Unregistered pseudos are parsed
+  /// as raw tokens, and a warning is emitted.
+  fn parse_custom_pseudo_arguments<'t>(
+    &self,
+    syntax: Option<CustomPseudoSyntax>,
+    name: &CowRcStr<'i>,
+    parser: &mut cssparser::Parser<'i, 't>,
+  ) -> Result<CustomPseudoArguments<'i>, ParseError<'i, ParserError<'i>>> {
+    match syntax {
+      Some(CustomPseudoSyntax::None) => {
+        Err(parser.new_custom_error(SelectorParseErrorKind::UnsupportedPseudoClassOrElement(name.clone())))
+      }
+      Some(CustomPseudoSyntax::Selector) => {
+        Ok(CustomPseudoArguments::Selectors(Selector::parse(self, parser)?.into()))
+      }
+      Some(CustomPseudoSyntax::SelectorList) => Ok(CustomPseudoArguments::Selectors(SelectorList::parse(
+        self,
+        parser,
+        parcel_selectors::parser::ParseErrorRecovery::DiscardList,
+        parcel_selectors::parser::NestingRequirement::None,
+      )?)),
+      Some(CustomPseudoSyntax::Tokens) | None => {
+        if syntax.is_none() && !name.starts_with('-') {
+          self
+            .options
+            .warn(parser.new_custom_error(SelectorParseErrorKind::UnsupportedPseudoClassOrElement(name.clone())));
+        }
+        let mut args = Vec::new();
+        TokenList::parse_raw(parser, &mut args, self.options, 0)?;
+        Ok(CustomPseudoArguments::Tokens(TokenList(args)))
+      }
+    }
+  }
+}
+
 impl<'a, 'o, 'i> parcel_selectors::parser::Parser<'i> for SelectorParser<'a, 'o, 'i> {
   type Impl = Selectors;
   type Error = ParserError<'i>;
@@ -191,7 +245,7 @@ impl<'a, 'o, 'i> parcel_selectors::parser::Parser<'i> for SelectorParser<'a, 'o,
       "window-inactive" => WebKitScrollbar(WebKitScrollbarPseudoClass::WindowInactive),
 
       _ => {
-        if !name.starts_with('-') {
+        if !name.starts_with('-') && lookup_custom_pseudo(&self.options.custom_pseudo_classes, &name).is_none() {
           self.options.warn(loc.new_custom_error(SelectorParseErrorKind::UnsupportedPseudoClassOrElement(name.clone())));
         }
         Custom { name: name.into() }
@@ -220,14 +274,10 @@ impl<'a, 'o, 'i> parcel_selectors::parser::Parser<'i> for SelectorParser<'a, 'o,
       "local" if self.options.css_modules.is_some() => Local { selector: Box::new(Selector::parse(self, parser)?) },
       "global" if self.options.css_modules.is_some() => Global { selector: Box::new(Selector::parse(self, parser)?) },
       _ => {
-        if !name.starts_with('-') {
-          self.options.warn(parser.new_custom_error(SelectorParseErrorKind::UnsupportedPseudoClassOrElement(name.clone())));
-        }
-        let mut args = Vec::new();
-        TokenList::parse_raw(parser, &mut args, &self.options, 0)?;
-        CustomFunction {
-          name: name.into(),
-          arguments: TokenList(args)
+        let syntax = lookup_custom_pseudo(&self.options.custom_pseudo_classes, &name);
+        match self.parse_custom_pseudo_arguments(syntax, &name, parser)? {
+          CustomPseudoArguments::Selectors(selectors) => CustomSelectorFunction { name: name.into(), selectors },
+          CustomPseudoArguments::Tokens(arguments) => CustomFunction { name: name.into(), arguments },
         }
       },
     };
@@ -280,7 +330,7 @@ impl<'a, 'o, 'i> parcel_selectors::parser::Parser<'i> for SelectorParser<'a, 'o,
       "view-transition" => ViewTransition,
 
       _ => {
-        if !name.starts_with('-') {
+        if !name.starts_with('-') && lookup_custom_pseudo(&self.options.custom_pseudo_elements, &name).is_none() {
           self.options.warn(loc.new_custom_error(SelectorParseErrorKind::UnsupportedPseudoClassOrElement(name.clone())));
         }
         Custom { name: name.into() }
@@ -299,17 +349,16 @@ impl<'a, 'o, 'i> parcel_selectors::parser::Parser<'i> for SelectorParser<'a, 'o,
     let pseudo_element = match_ignore_ascii_case! { &name,
       "cue" => CueFunction { selector: Box::new(Selector::parse(self, arguments)?) },
       "cue-region" => CueRegionFunction { selector: Box::new(Selector::parse(self, arguments)?) },
-      "view-transition-group" => ViewTransitionGroup { part_name: ViewTransitionPartName::parse(arguments)? },
-      "view-transition-image-pair" => ViewTransitionImagePair { part_name: ViewTransitionPartName::parse(arguments)? },
-      "view-transition-old" => ViewTransitionOld { part_name: ViewTransitionPartName::parse(arguments)? },
-      "view-transition-new" => ViewTransitionNew { part_name: ViewTransitionPartName::parse(arguments)? },
+      "view-transition-group" => ViewTransitionGroup { part: ViewTransitionPartSelector::parse(arguments)? },
+      "view-transition-image-pair" => ViewTransitionImagePair { part: ViewTransitionPartSelector::parse(arguments)? },
+      "view-transition-old" => ViewTransitionOld { part: ViewTransitionPartSelector::parse(arguments)? },
+      "view-transition-new" => ViewTransitionNew { part: ViewTransitionPartSelector::parse(arguments)? },
       _ => {
-        if !name.starts_with('-') {
-          self.options.warn(arguments.new_custom_error(SelectorParseErrorKind::UnsupportedPseudoClassOrElement(name.clone())));
+        let syntax = lookup_custom_pseudo(&self.options.custom_pseudo_elements, &name);
+        match self.parse_custom_pseudo_arguments(syntax, &name, arguments)? {
+          CustomPseudoArguments::Selectors(selectors) => CustomSelectorFunction { name: name.into(), selectors },
+          CustomPseudoArguments::Tokens(arguments) => CustomFunction { name: name.into(), arguments },
         }
-        let mut args = Vec::new();
-        TokenList::parse_raw(arguments, &mut args, &self.options, 0)?;
-        CustomFunction { name: name.into(), arguments: TokenList(args) }
       }
     };
 
@@ -365,6 +414,23 @@ enum_property! {
   }
 }
 
+/// The syntax of the arguments accepted by a non-standard pseudo class or pseudo element
+/// registered via [ParserOptions](crate::stylesheet::ParserOptions).
+///
+/// Registered names are always accepted without arguments (e.g. `::v-deep`), and are not
+/// reported as unsupported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CustomPseudoSyntax {
+  /// The pseudo does not accept arguments.
+  None,
+  /// A single selector, e.g. `:deep(.foo)`.
+  Selector,
+  /// A comma separated list of selectors, e.g. `::v-deep(.foo, .bar)`.
+  SelectorList,
+  /// An arbitrary list of tokens, e.g. `:state(checked)`.
+  Tokens,
+}
+
 /// A pseudo class.
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(
@@ -534,6 +600,13 @@ pub enum PseudoClass<'i> {
     /// The arguments of the pseudo class function.
     arguments: TokenList<'i>,
   },
+  /// A registered functional pseudo class that accepts selectors.
+  CustomSelectorFunction {
+    /// The pseudo class name.
+    name: CowArcStr<'i>,
+    /// The selector arguments of the pseudo class function.
+    selectors: SelectorList<'i>,
+  },
 }
 
 /// A [webkit scrollbar](https://webkit.org/blog/363/styling-scrollbars/) pseudo class.
@@ -797,6 +870,13 @@ where
       args.to_css_raw(dest)?;
       dest.write_char(')')
     }
+    CustomSelectorFunction { name, selectors } => {
+      dest.write_char(':')?;
+      dest.write_str(name)?;
+      dest.write_char('(')?;
+      serialize_selector_list(selectors.0.iter(), dest, context, false)?;
+      dest.write_char(')')
+    }
   }
 }
 
@@ -898,25 +978,25 @@ pub enum PseudoElement<'i> {
   #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
   ViewTransitionGroup {
     /// A part name selector.
-    part_name: ViewTransitionPartName<'i>,
+    part: ViewTransitionPartSelector<'i>,
   },
   /// The [::view-transition-image-pair()](https://w3c.github.io/csswg-drafts/css-view-transitions-1/#view-transition-image-pair-pt-name-selector) functional pseudo element.
   #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
   ViewTransitionImagePair {
     /// A part name selector.
-    part_name: ViewTransitionPartName<'i>,
+    part: ViewTransitionPartSelector<'i>,
   },
   /// The [::view-transition-old()](https://w3c.github.io/csswg-drafts/css-view-transitions-1/#view-transition-old-pt-name-selector) functional pseudo element.
   #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
   ViewTransitionOld {
     /// A part name selector.
-    part_name: ViewTransitionPartName<'i>,
+    part: ViewTransitionPartSelector<'i>,
   },
   /// The [::view-transition-new()](https://w3c.github.io/csswg-drafts/css-view-transitions-1/#view-transition-new-pt-name-selector) functional pseudo element.
   #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
   ViewTransitionNew {
     /// A part name selector.
-    part_name: ViewTransitionPartName<'i>,
+    part: ViewTransitionPartSelector<'i>,
   },
   /// An unknown pseudo element.
   Custom {
@@ -931,6 +1011,13 @@ pub enum PseudoElement<'i> {
     /// The arguments of the pseudo element function.
     arguments: TokenList<'i>,
   },
+  /// A registered functional pseudo element that accepts selectors.
+  CustomSelectorFunction {
+    /// The name of the pseudo element.
+    name: CowArcStr<'i>,
+    /// The selector arguments of the pseudo element function.
+    selectors: SelectorList<'i>,
+  },
 }
 
 /// A [webkit scrollbar](https://webkit.org/blog/363/styling-scrollbars/) pseudo element.
@@ -1032,11 +1119,95 @@ impl<'i> ToCss for ViewTransitionPartName<'i> {
   {
     match self {
       ViewTransitionPartName::All => dest.write_char('*'),
-      ViewTransitionPartName::Name(name) => name.to_css(dest),
+      ViewTransitionPartName::Name(name) => write_view_transition_ident(name, dest),
+    }
+  }
+}
+
+/// A [view transition part selector](https://drafts.csswg.org/css-view-transitions-2/#typedef-pt-name-and-class-selector).
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct ViewTransitionPartSelector<'i> {
+  /// The view transition part name.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: Option<ViewTransitionPartName<'i>>,
+  /// A list of view transition classes.
+  pub classes: Vec<CustomIdent<'i>>,
+}
+
+impl<'i> Parse<'i> for ViewTransitionPartSelector<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    input.skip_whitespace();
+    let name = input.try_parse(ViewTransitionPartName::parse).ok();
+    let mut classes = Vec::new();
+
+    // Whitespace is not allowed between the name and classes.
+    while let Ok(class) = input.try_parse(parse_view_transition_class) {
+      classes.push(class);
+    }
+
+    if name.is_none() && classes.is_empty() {
+      return Err(input.new_error_for_next_token());
+    }
+
+    Ok(ViewTransitionPartSelector { name, classes })
+  }
+}
+
+fn parse_view_transition_class<'i>(
+  input: &mut Parser<'i, '_>,
+) -> Result<CustomIdent<'i>, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  match input.next_including_whitespace()? {
+    Token::Delim('.') => {}
+    t => return Err(location.new_unexpected_token_error(t.clone())),
+  }
+
+  let location = input.current_source_location();
+  match input.next_including_whitespace()? {
+    Token::Ident(ident) => Ok(CustomIdent(ident.into())),
+    t => Err(location.new_unexpected_token_error(t.clone())),
+  }
+}
+
+impl<'i> ToCss for ViewTransitionPartSelector<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if let Some(name) = &self.name {
+      name.to_css(dest)?;
+    }
+
+    for class in &self.classes {
+      dest.write_char('.')?;
+      write_view_transition_ident(class, dest)?;
     }
+
+    Ok(())
   }
 }
 
+/// Writes a view transition name or class referenced by a pseudo element,
+/// scoping it in CSS modules mode in the same way as the properties that define it.
+fn write_view_transition_ident<W>(ident: &CustomIdent, dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  let css_module_custom_idents_enabled = dest
+    .css_module
+    .as_ref()
+    .map_or(false, |css_module| css_module.config.custom_idents);
+  if css_module_custom_idents_enabled {
+    if let Some(css_module) = &mut dest.css_module {
+      css_module.reference(&ident.0, dest.loc.source_index)
+    }
+  }
+  ident.to_css_with_options(dest, css_module_custom_idents_enabled)
+}
+
 impl<'i> cssparser::ToCss for PseudoElement<'i> {
   fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
   where
@@ -1134,24 +1305,24 @@ where
       })
     }
     ViewTransition => dest.write_str("::view-transition"),
-    ViewTransitionGroup { part_name } => {
+    ViewTransitionGroup { part } => {
       dest.write_str("::view-transition-group(")?;
-      part_name.to_css(dest)?;
+      part.to_css(dest)?;
       dest.write_char(')')
     }
-    ViewTransitionImagePair { part_name } => {
+    ViewTransitionImagePair { part } => {
       dest.write_str("::view-transition-image-pair(")?;
-      part_name.to_css(dest)?;
+      part.to_css(dest)?;
       dest.write_char(')')
     }
-    ViewTransitionOld { part_name } => {
+    ViewTransitionOld { part } => {
       dest.write_str("::view-transition-old(")?;
-      part_name.to_css(dest)?;
+      part.to_css(dest)?;
       dest.write_char(')')
     }
-    ViewTransitionNew { part_name } => {
+    ViewTransitionNew { part } => {
       dest.write_str("::view-transition-new(")?;
-      part_name.to_css(dest)?;
+      part.to_css(dest)?;
       dest.write_char(')')
     }
     Custom { name: val } => {
@@ -1165,6 +1336,13 @@ where
       args.to_css_raw(dest)?;
       dest.write_char(')')
     }
+    CustomSelectorFunction { name, selectors } => {
+      dest.write_str("::")?;
+      dest.write_str(name)?;
+      dest.write_char('(')?;
+      serialize_selector_list(selectors.0.iter(), dest, context, false)?;
+      dest.write_char(')')
+    }
   }
 }
 
@@ -1207,7 +1385,9 @@ impl<'i> parcel_selectors::parser::PseudoElement<'i> for PseudoElement<'i> {
   fn is_unknown(&self) -> bool {
     matches!(
       *self,
-      PseudoElement::Custom { .. } | PseudoElement::CustomFunction { .. },
+      PseudoElement::Custom { .. }
+        | PseudoElement::CustomFunction { .. }
+        | PseudoElement::CustomSelectorFunction { .. },
     )
   }
 }
@@ -2027,6 +2207,174 @@ fn lang_list_to_selectors<'i>(langs: &Vec<CowArcStr<'i>>) -> Box<[Selector<'i>]>
     .into_boxed_slice()
 }
 
+/// Converts top-level `:dir()` pseudo classes into a descendant selector of an element with
+/// a `dir` attribute, e.g. `.foo:dir(rtl)` => `[dir="rtl"] .foo`. Selectors with conflicting
+/// directions are left as is.
+pub(crate) fn downlevel_dir_to_attribute(selectors: &mut [Selector]) {
+  for selector in selectors {
+    let mut direction = None;
+    for component in selector.iter_raw_match_order() {
+      if let Component::NonTSPseudoClass(PseudoClass::Dir { direction: dir }) = component {
+        if direction.map_or(false, |d| d != *dir) {
+          direction = None;
+          break;
+        }
+        direction = Some(*dir);
+      }
+    }
+
+    let direction = match direction {
+      Some(direction) => direction,
+      None => continue,
+    };
+
+    let value = match direction {
+      Direction::Ltr => "ltr",
+      Direction::Rtl => "rtl",
+    };
+    let attribute = Component::attribute("dir".into(), AttrSelectorOperator::Equal, value.into());
+    let mut components = Vec::new();
+    let mut has_attribute = false;
+    let mut is_empty_compound = true;
+    for component in parse_order_components(selector) {
+      match component {
+        Component::NonTSPseudoClass(PseudoClass::Dir { .. }) => continue,
+        Component::Combinator(_) => {
+          // Use the attribute selector in place of compound selectors that only contained :dir().
+          if is_empty_compound {
+            components.push(attribute.clone());
+            has_attribute = true;
+          }
+          is_empty_compound = true;
+        }
+        _ => is_empty_compound = false,
+      }
+      components.push(component);
+    }
+
+    if is_empty_compound {
+      components.push(attribute.clone());
+      has_attribute = true;
+    }
+
+    // Otherwise, match descendants of an element with the attribute.
+    if !has_attribute {
+      components.insert(0, Component::Combinator(Combinator::Descendant));
+      components.insert(0, attribute);
+    }
+
+    *selector = Selector::from(components);
+  }
+}
+
+/// A scope to apply to selectors, resolved from a [SelectorScope](crate::stylesheet::SelectorScope).
+#[cfg(feature = "into_owned")]
+pub(crate) enum Scope<'i> {
+  Ancestor(Selector<'i>),
+  Attribute(Component<'i>),
+}
+
+/// Scopes the given selectors. Nested selectors are only scoped by attribute, because
+/// their parent rule already has an ancestor scope.
+#[cfg(feature = "into_owned")]
+pub(crate) fn scope_selectors<'i>(selectors: &mut [Selector<'i>], scope: &Scope<'i>, is_nested: bool) {
+  for selector in selectors {
+    if is_global(selector) {
+      continue;
+    }
+
+    match scope {
+      Scope::Ancestor(ancestor) if !is_nested => scope_with_ancestor(selector, ancestor),
+      Scope::Attribute(attribute) => scope_with_attribute(selector, attribute),
+      _ => {}
+    }
+  }
+}
+
+#[cfg(feature = "into_owned")]
+fn is_global(selector: &Selector) -> bool {
+  selector.iter_raw_match_order().any(|component| match component {
+    Component::NonTSPseudoClass(PseudoClass::Global { .. }) => true,
+    Component::NonTSPseudoClass(PseudoClass::CustomFunction { name, .. }) => name.eq_ignore_ascii_case("global"),
+    _ => false,
+  })
+}
+
+#[cfg(feature = "into_owned")]
+fn is_root(component: &Component) -> bool {
+  match component {
+    Component::Root => true,
+    Component::LocalName(local) => local.lower_name.0 == "html",
+    _ => false,
+  }
+}
+
+#[cfg(feature = "into_owned")]
+fn scope_with_ancestor<'i>(selector: &mut Selector<'i>, ancestor: &Selector<'i>) {
+  // A top-level nesting selector refers to the scoping root, which is already scoped.
+  if selector.iter_raw_match_order().any(|c| matches!(c, Component::Nesting)) {
+    return;
+  }
+
+  let components = parse_order_components(selector);
+  let leftmost_len = components
+    .iter()
+    .position(|c| matches!(c, Component::Combinator(_)))
+    .unwrap_or(components.len());
+  let (leftmost, rest) = components.split_at(leftmost_len);
+
+  let mut scoped = parse_order_components(ancestor);
+  if leftmost.iter().any(is_root) {
+    // Replace :root and html with the scope selector, keeping the rest of the compound selector.
+    let remaining: Vec<_> = leftmost
+      .iter()
+      .filter(|c| !is_root(c) && !matches!(c, Component::ExplicitUniversalType))
+      .cloned()
+      .collect();
+    if !is_type_selector(remaining.first()) && !is_namespace(remaining.first()) {
+      scoped.extend(remaining);
+      scoped.extend(rest.iter().cloned());
+      *selector = Selector::from(scoped);
+      return;
+    }
+  }
+
+  scoped.push(Component::Combinator(Combinator::Descendant));
+  scoped.extend(components);
+  *selector = Selector::from(scoped);
+}
+
+#[cfg(feature = "into_owned")]
+fn scope_with_attribute<'i>(selector: &mut Selector<'i>, attribute: &Component<'i>) {
+  let mut components = parse_order_components(selector);
+
+  // Deep combinators pierce the scope, so the compound selector before the first one is scoped.
+  let end = components
+    .iter()
+    .position(|c| matches!(c, Component::Combinator(Combinator::Deep | Combinator::DeepDescendant)))
+    .unwrap_or(components.len());
+  let start = components[..end]
+    .iter()
+    .rposition(|c| matches!(c, Component::Combinator(c) if c.is_tree_combinator()))
+    .map_or(0, |i| i + 1);
+
+  // Compound selectors containing `&` are already scoped by the parent rule.
+  if components[start..end]
+    .iter()
+    .any(|c| is_root(c) || matches!(c, Component::Nesting))
+  {
+    return;
+  }
+
+  // Insert before pseudo elements, ::slotted(), and ::part().
+  let index = components[start..end]
+    .iter()
+    .position(|c| matches!(c, Component::Combinator(_)))
+    .map_or(end, |i| start + i);
+  components.insert(index, attribute.clone());
+  *selector = Selector::from(components);
+}
+
 fn downlevel_dir<'i>(dir: Direction, targets: Targets) -> Component<'i> {
   // Convert :dir to :lang. If supported, use a list of languages in a single :lang,
   // otherwise, use :is/:not, which may be further downleveled to e.g. :-webkit-any.
@@ -2047,6 +2395,243 @@ fn downlevel_dir<'i>(dir: Direction, targets: Targets) -> Component<'i> {
   }
 }
 
+/// Expands top-level `:is()` and `:where()` selectors into an equivalent list of selectors,
+/// e.g. `:is(.a, .b) .c` => `.a .c, .b .c`. Each selector may expand into at most `limit` selectors.
+/// Returns the selectors that could not be expanded, which are left unchanged.
+pub(crate) fn expand_is_selectors<'i>(selectors: &mut SelectorList<'i>, limit: usize) -> Vec<Selector<'i>> {
+  fn has_is(selector: &Selector) -> bool {
+    selector
+      .iter_raw_match_order()
+      .any(|c| matches!(c, Component::Is(_) | Component::Where(_)))
+  }
+
+  let mut unexpanded = Vec::new();
+  if !selectors.0.iter().any(has_is) {
+    return unexpanded;
+  }
+
+  let mut expanded = SmallVec::new();
+  for selector in selectors.0.drain(..) {
+    if !has_is(&selector) {
+      expanded.push(selector);
+      continue;
+    }
+
+    match expand_is_selector(&selector, limit) {
+      Some(selectors) => expanded.extend(selectors),
+      None => {
+        unexpanded.push(selector.clone());
+        expanded.push(selector);
+      }
+    }
+  }
+
+  selectors.0 = expanded;
+  unexpanded
+}
+
+fn expand_is_selector<'i>(selector: &Selector<'i>, limit: usize) -> Option<Vec<Selector<'i>>> {
+  let mut expanded: Vec<Vec<Component<'i>>> = vec![vec![]];
+  for component in parse_order_components(selector) {
+    let (selectors, is_where) = match &component {
+      Component::Is(selectors) => (selectors, false),
+      Component::Where(selectors) => (selectors, true),
+      _ => {
+        for components in &mut expanded {
+          components.push(component.clone());
+        }
+        continue;
+      }
+    };
+
+    // Only compound selectors can be expanded in place. In addition, :is() takes the highest
+    // specificity of its arguments, and :where() has no specificity, so the arguments must all
+    // have the same specificity for the expanded selectors to be equivalent.
+    let specificity = selectors.first()?.specificity();
+    if selectors.iter().any(|selector| {
+      selector.has_combinator()
+        || selector.has_pseudo_element()
+        || selector.specificity() != specificity
+        || (is_where && selector.specificity() != 0)
+    }) || expanded.len() * selectors.len() > limit
+    {
+      return None;
+    }
+
+    let mut next = Vec::with_capacity(expanded.len() * selectors.len());
+    for components in &expanded {
+      let compound_start = components.iter().rposition(|c| c.is_combinator()).map_or(0, |i| i + 1);
+      for selector in selectors.iter() {
+        // Type selectors and namespaces must come first in the compound selector.
+        let (prefix, rest): (Vec<_>, Vec<_>) = parse_order_components(selector)
+          .into_iter()
+          .partition(|c| is_type_selector(Some(c)) || is_namespace(Some(c)));
+        if !prefix.is_empty()
+          && components[compound_start..]
+            .iter()
+            .any(|c| is_type_selector(Some(c)) || is_namespace(Some(c)))
+        {
+          return None;
+        }
+
+        let mut components = components.clone();
+        components.splice(compound_start..compound_start, prefix);
+        components.extend(rest);
+        next.push(components);
+      }
+    }
+    expanded = next;
+  }
+
+  Some(expanded.into_iter().map(Selector::from).collect())
+}
+
+/// Returns whether the order of a component within a compound selector is insignificant,
+/// i.e. it is a class, ID, or attribute selector.
+#[inline]
+fn is_sortable(component: &Component) -> bool {
+  matches!(
+    component,
+    Component::Class(_)
+      | Component::ID(_)
+      | Component::AttributeInNoNamespaceExists { .. }
+      | Component::AttributeInNoNamespace { .. }
+      | Component::AttributeOther(_)
+  )
+}
+
+fn hash_component(component: &Component) -> u64 {
+  let mut hasher = ahash::AHasher::default();
+  component.hash(&mut hasher);
+  hasher.finish()
+}
+
+fn canonical_selector_hash(selector: &Selector) -> u64 {
+  let mut hasher = ahash::AHasher::default();
+  let mut sortable: SmallVec<[u64; 4]> = SmallVec::new();
+  for component in selector.iter_raw_match_order() {
+    if is_sortable(component) {
+      sortable.push(hash_component(component));
+      continue;
+    }
+
+    if component.is_combinator() {
+      sortable.sort_unstable();
+      sortable.hash(&mut hasher);
+      sortable.clear();
+    }
+    component.hash(&mut hasher);
+  }
+
+  sortable.sort_unstable();
+  sortable.hash(&mut hasher);
+  hasher.finish()
+}
+
+/// Computes a hash of a selector list in canonical order. The hash does not depend on the order
+/// of the selectors in the list, or the order of classes, IDs, and attribute selectors within
+/// each compound selector. See [is_canonically_equal](is_canonically_equal).
+pub(crate) fn canonical_hash(selectors: &[Selector]) -> u64 {
+  let mut hashes: SmallVec<[u64; 4]> = selectors.iter().map(canonical_selector_hash).collect();
+  hashes.sort_unstable();
+  let mut hasher = ahash::AHasher::default();
+  hashes.hash(&mut hasher);
+  hasher.finish()
+}
+
+/// Returns whether two lists of components are equal, ignoring their order.
+fn is_same_set<'a, 'i: 'a>(a: &[&'a Component<'i>], b: &[&'a Component<'i>]) -> bool {
+  if a.len() != b.len() {
+    return false;
+  }
+
+  let mut used: SmallVec<[bool; 4]> = smallvec::smallvec![false; b.len()];
+  a.iter().all(|a| {
+    if let Some(i) = (0..b.len()).find(|i| !used[*i] && *a == b[*i]) {
+      used[i] = true;
+      true
+    } else {
+      false
+    }
+  })
+}
+
+fn is_canonically_equal_selector<'i>(a: &Selector<'i>, b: &Selector<'i>) -> bool {
+  if a == b {
+    return true;
+  }
+
+  let a = a.iter_raw_match_order().as_slice();
+  let b = b.iter_raw_match_order().as_slice();
+  if a.len() != b.len() {
+    return false;
+  }
+
+  let mut a_compounds = a.split(|c| c.is_combinator());
+  let mut b_compounds = b.split(|c| c.is_combinator());
+  let a_combinators = a.iter().filter(|c| c.is_combinator());
+  let b_combinators = b.iter().filter(|c| c.is_combinator());
+  if !a_combinators.eq(b_combinators) {
+    return false;
+  }
+
+  loop {
+    match (a_compounds.next(), b_compounds.next()) {
+      (Some(a), Some(b)) => {
+        let (a_sortable, a_rest): (SmallVec<[&Component; 4]>, SmallVec<[&Component; 4]>) =
+          a.iter().partition(|c| is_sortable(c));
+        let (b_sortable, b_rest): (SmallVec<[&Component; 4]>, SmallVec<[&Component; 4]>) =
+          b.iter().partition(|c| is_sortable(c));
+        if a_rest != b_rest || !is_same_set(&a_sortable, &b_sortable) {
+          return false;
+        }
+      }
+      (None, None) => return true,
+      _ => return false,
+    }
+  }
+}
+
+/// Returns whether two selector lists are equal in canonical order, i.e. regardless of the order
+/// of the selectors in the list, and of classes, IDs, and attribute selectors within each compound selector.
+/// For example, `.a.b, .c` is canonically equal to `.c, .b.a`.
+pub(crate) fn is_canonically_equal<'i>(a: &[Selector<'i>], b: &[Selector<'i>]) -> bool {
+  if a.len() != b.len() {
+    return false;
+  }
+
+  if a == b {
+    return true;
+  }
+
+  let mut used: SmallVec<[bool; 4]> = smallvec::smallvec![false; b.len()];
+  a.iter().all(|a| {
+    if let Some(i) = (0..b.len()).find(|i| !used[*i] && is_canonically_equal_selector(a, &b[*i])) {
+      used[i] = true;
+      true
+    } else {
+      false
+    }
+  })
+}
+
+/// Returns whether all of the given selectors reference a class name, id, or tag name
+/// that is not used according to the given purge options.
+pub(crate) fn is_purged(selectors: &[Selector], purge: &PurgeOptions, parent_is_unused: bool) -> bool {
+  selectors.iter().all(|selector| {
+    selector.iter_raw_match_order().any(|component| match component {
+      Component::Class(name) => !purge.is_class_used(&name.0),
+      Component::ID(name) => !purge.is_id_used(&name.0),
+      Component::LocalName(local) => !purge.is_tag_used(&local.lower_name.0),
+      Component::Is(list) | Component::Where(list) | Component::Any(_, list) => {
+        is_purged(list, purge, parent_is_unused)
+      }
+      Component::Nesting => parent_is_unused,
+      _ => false,
+    })
+  })
+}
+
 /// Determines whether a selector list contains only unused selectors.
 /// A selector is considered unused if it contains a class or id component that exists in the set of unused symbols.
 pub(crate) fn is_unused(
@@ -2147,3 +2732,199 @@ impl<'i> ParseWithOptions<'i> for SelectorList<'i> {
     )
   }
 }
+
+/// Options for parsing a standalone selector via [ParseSelector](ParseSelector).
+#[derive(Default)]
+pub struct SelectorParseOptions<'o, 'i> {
+  /// Parser options, e.g. to enable CSS modules or the deep combinator.
+  pub parser_options: ParserOptions<'o, 'i>,
+  /// Whether the nesting selector `&` is allowed.
+  pub allow_nesting: bool,
+}
+
+/// Trait for parsing selectors from a string, outside of a style rule.
+///
+/// This is useful to validate, compare, or compute the specificity of selectors.
+/// Use [to_css_string](ToCss::to_css_string) to serialize them again.
+pub trait ParseSelector<'i>: Sized {
+  /// Parses a selector from a string with the given options.
+  fn parse_string_with(
+    input: &'i str,
+    options: SelectorParseOptions<'_, 'i>,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>>;
+
+  /// Parses a selector from a string with the default options.
+  fn parse_string(input: &'i str) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    Self::parse_string_with(input, SelectorParseOptions::default())
+  }
+}
+
+impl<'i> ParseSelector<'i> for Selector<'i> {
+  fn parse_string_with(
+    input: &'i str,
+    options: SelectorParseOptions<'_, 'i>,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut input = ParserInput::new(input);
+    let mut parser = Parser::new(&mut input);
+    let selector = Selector::parse(
+      &SelectorParser {
+        is_nesting_allowed: options.allow_nesting,
+        options: &options.parser_options,
+      },
+      &mut parser,
+    )?;
+    parser.expect_exhausted()?;
+    Ok(selector)
+  }
+}
+
+impl<'i> ParseSelector<'i> for SelectorList<'i> {
+  fn parse_string_with(
+    input: &'i str,
+    options: SelectorParseOptions<'_, 'i>,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut input = ParserInput::new(input);
+    let mut parser = Parser::new(&mut input);
+    let selectors = SelectorList::parse(
+      &SelectorParser {
+        is_nesting_allowed: options.allow_nesting,
+        options: &options.parser_options,
+      },
+      &mut parser,
+      parcel_selectors::parser::ParseErrorRecovery::DiscardList,
+      parcel_selectors::parser::NestingRequirement::None,
+    )?;
+    parser.expect_exhausted()?;
+    Ok(selectors)
+  }
+}
+
+/// Returns the components of a selector from left to right, in the same order they are written in CSS.
+///
+/// Selectors store their compound selectors from right to left, but the components within each
+/// compound selector from left to right, so [iter_raw_parse_order_from](Selector::iter_raw_parse_order_from)
+/// reverses the components within each compound selector. The result can be passed to `Selector::from`.
+pub(crate) fn parse_order_components<'i>(selector: &Selector<'i>) -> Vec<Component<'i>> {
+  let mut components: Vec<_> = selector.iter_raw_parse_order_from(0).cloned().collect();
+  let mut start = 0;
+  for i in 0..=components.len() {
+    if i == components.len() || components[i].is_combinator() {
+      components[start..i].reverse();
+      start = i + 1;
+    }
+  }
+  components
+}
+
+/// A builder for constructing a [Selector](Selector) without parsing a string.
+///
+/// Components are added from left to right, in the same order they are written in CSS.
+/// This is useful to construct or rewrite selectors in visitors, e.g.
+/// `SelectorBuilder::new().class("foo").child().pseudo_class(PseudoClass::Hover).build()`
+/// builds `.foo > :hover`. An existing selector can be extended via [from_selector](SelectorBuilder::from_selector).
+#[derive(Debug, Clone, Default)]
+pub struct SelectorBuilder<'i> {
+  components: Vec<Component<'i>>,
+}
+
+impl<'i> SelectorBuilder<'i> {
+  /// Creates an empty selector builder.
+  pub fn new() -> Self {
+    SelectorBuilder::default()
+  }
+
+  /// Creates a selector builder containing the components of an existing selector.
+  pub fn from_selector(selector: &Selector<'i>) -> Self {
+    SelectorBuilder {
+      components: parse_order_components(selector),
+    }
+  }
+
+  /// Adds a component, e.g. a combinator or a simple selector.
+  pub fn component(mut self, component: Component<'i>) -> Self {
+    self.components.push(component);
+    self
+  }
+
+  /// Adds a type selector, e.g. `div`.
+  pub fn local_name(self, name: impl Into<CowRcStr<'i>>) -> Self {
+    self.component(Component::local_name(name.into()))
+  }
+
+  /// Adds the universal selector `*`.
+  pub fn universal(self) -> Self {
+    self.component(Component::ExplicitUniversalType)
+  }
+
+  /// Adds an ID selector, e.g. `#foo`.
+  pub fn id(self, id: impl Into<CowRcStr<'i>>) -> Self {
+    self.component(Component::id(id.into()))
+  }
+
+  /// Adds a class selector, e.g. `.foo`.
+  pub fn class(self, class: impl Into<CowRcStr<'i>>) -> Self {
+    self.component(Component::class(class.into()))
+  }
+
+  /// Adds an attribute selector that matches elements with the attribute, e.g. `[foo]`.
+  pub fn attribute_exists(self, name: impl Into<CowRcStr<'i>>) -> Self {
+    self.component(Component::attribute_exists(name.into()))
+  }
+
+  /// Adds an attribute selector that compares the value of the attribute, e.g. `[foo^=bar]`.
+  pub fn attribute(
+    self,
+    name: impl Into<CowRcStr<'i>>,
+    operator: AttrSelectorOperator,
+    value: impl Into<CowRcStr<'i>>,
+  ) -> Self {
+    self.component(Component::attribute(name.into(), operator, value.into()))
+  }
+
+  /// Adds a pseudo class, e.g. `:hover`.
+  pub fn pseudo_class(self, pseudo_class: PseudoClass<'i>) -> Self {
+    self.component(Component::NonTSPseudoClass(pseudo_class))
+  }
+
+  /// Adds a pseudo element, e.g. `::before`.
+  pub fn pseudo_element(self, pseudo_element: PseudoElement<'i>) -> Self {
+    self
+      .combinator(Combinator::PseudoElement)
+      .component(Component::PseudoElement(pseudo_element))
+  }
+
+  /// Adds the nesting selector `&`.
+  pub fn nesting(self) -> Self {
+    self.component(Component::Nesting)
+  }
+
+  /// Adds a combinator, starting a new compound selector.
+  pub fn combinator(self, combinator: Combinator) -> Self {
+    self.component(Component::Combinator(combinator))
+  }
+
+  /// Adds a descendant combinator, i.e. whitespace.
+  pub fn descendant(self) -> Self {
+    self.combinator(Combinator::Descendant)
+  }
+
+  /// Adds a child combinator `>`.
+  pub fn child(self) -> Self {
+    self.combinator(Combinator::Child)
+  }
+
+  /// Adds a next sibling combinator `+`.
+  pub fn next_sibling(self) -> Self {
+    self.combinator(Combinator::NextSibling)
+  }
+
+  /// Adds a later sibling combinator `~`.
+  pub fn later_sibling(self) -> Self {
+    self.combinator(Combinator::LaterSibling)
+  }
+
+  /// Builds the selector, computing its specificity.
+  pub fn build(self) -> Selector<'i> {
+    Selector::from(self.components)
+  }
+}
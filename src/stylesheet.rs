@@ -8,11 +8,23 @@ use crate::css_modules::{CssModule, CssModuleExports, CssModuleReferences};
 use crate::declaration::{DeclarationBlock, DeclarationHandler};
 use crate::dependencies::Dependency;
 use crate::error::{Error, ErrorLocation, MinifyErrorKind, ParserError, PrinterError, PrinterErrorKind};
+use crate::macros::phase;
 use crate::parser::{DefaultAtRule, DefaultAtRuleParser, TopLevelRuleParser};
 use crate::printer::Printer;
+#[cfg(feature = "substitute_variables")]
+use crate::properties::custom::TokenList;
+use crate::properties::Property;
+use crate::rules::font_face::{deduplicate_font_faces, remove_unused_font_faces};
+use crate::rules::keyframes::{
+  deduplicate_keyframes, referenced_animation_names, remove_purged_keyframes, remove_unused_keyframes,
+};
+#[cfg(feature = "into_owned")]
+use crate::rules::style::scope_style_rules;
 use crate::rules::{CssRule, CssRuleList, MinifyContext};
+use crate::selector::Selector;
 use crate::targets::{should_compile, Targets};
 use crate::traits::{AtRuleParser, ToCss};
+use crate::values::color::GamutMapping;
 use crate::values::string::CowArcStr;
 #[cfg(feature = "visitor")]
 use crate::visitor::{Visit, VisitTypes, Visitor};
@@ -20,10 +32,12 @@ use cssparser::{Parser, ParserInput, StyleSheetParser};
 #[cfg(feature = "sourcemap")]
 use parcel_sourcemap::SourceMap;
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 pub use crate::parser::{ParserFlags, ParserOptions};
 pub use crate::printer::PrinterOptions;
 pub use crate::printer::PseudoClasses;
+pub use crate::printer::SourceMapUrl;
 
 /// A CSS style sheet, representing a `.css` file or inline `<style>` element.
 ///
@@ -95,6 +109,271 @@ pub struct MinifyOptions {
   /// A list of known unused symbols, including CSS class names,
   /// ids, and `@keyframe` names. The declarations of these will be removed.
   pub unused_symbols: HashSet<String>,
+  /// A hook that is called before compatibility fallbacks are generated,
+  /// which can suppress or replace them.
+  pub fallback_control: Option<Arc<dyn FallbackControl>>,
+  /// Whether to remove `@keyframes` rules that are not referenced by any `animation-name`
+  /// or `animation` declaration in the style sheet. Note that keyframes only referenced
+  /// from elsewhere, e.g. inline styles or JavaScript, are removed as well.
+  pub remove_unused_keyframes: bool,
+  /// Whether to merge `@keyframes` rules with identical bodies into a single rule, and update
+  /// `animation-name` and `animation` declarations to reference it. Note that keyframes
+  /// referenced from elsewhere, e.g. inline styles or JavaScript, may be renamed as well.
+  pub deduplicate_keyframes: bool,
+  /// Whether to remove `@font-face` rules that are identical to a later `@font-face` rule.
+  pub deduplicate_font_faces: bool,
+  /// Whether to remove `@font-face` rules whose `font-family` is not referenced by any `font-family`
+  /// or `font` declaration in the style sheet, or listed in `used_font_families`.
+  pub remove_unused_font_faces: bool,
+  /// Font families that are used elsewhere, e.g. in inline styles or JavaScript, and whose
+  /// `@font-face` rules should be kept by `remove_unused_font_faces`. Matched case-insensitively.
+  pub used_font_families: HashSet<String>,
+  /// Expands `:is()` and `:where()` selectors into equivalent selector lists for targets that do not
+  /// support them, e.g. `:is(.a, .b) .c` => `.a .c, .b .c`. The value is the maximum number of selectors
+  /// that each selector may expand into. Selectors that cannot be expanded without changing their
+  /// specificity or exceeding the limit are kept, and a warning is added to the `warnings` of the
+  /// [ParserOptions](ParserOptions) used to parse the style sheet.
+  pub expand_is_selectors: Option<usize>,
+  /// A map of environment variable names to values, which are substituted into `env()`
+  /// references in declarations, e.g. `--brand-color` or `safe-area-inset-top`. Author-defined
+  /// environment variables that are not provided are replaced with their fallback value, if any.
+  #[cfg(feature = "substitute_variables")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "substitute_variables")))]
+  pub environment_variables: HashMap<String, TokenList<'static>>,
+  /// How the `:dir()` pseudo class is compiled for targets that do not support it.
+  pub dir_strategy: DirStrategy,
+  /// How `light-dark()` colors are compiled for targets that do not support them.
+  #[cfg(feature = "visitor")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "visitor")))]
+  pub light_dark: LightDarkStrategy,
+  /// The algorithm used to map out of gamut colors when generating sRGB fallbacks.
+  pub gamut_mapping: GamutMapping,
+  /// Converts `px` lengths in declarations to `rem`.
+  #[cfg(feature = "visitor")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "visitor")))]
+  pub px_to_rem: Option<PxToRemOptions>,
+  /// Removes style rules whose selectors can never match, given the class names, ids,
+  /// and tag names that are used, e.g. in the HTML of a page.
+  pub purge: Option<PurgeOptions>,
+  /// How style rules with equivalent selectors or identical declarations are merged.
+  pub merge_rules: MergeRules,
+  /// Whether to remove declarations that are always overridden by a declaration of the same property
+  /// in a later style rule with the same selectors, within the same `@media`, `@supports`, and `@layer` context.
+  pub remove_overridden_declarations: bool,
+  /// Scopes the selectors of all style rules, e.g. for a component or an embedded widget.
+  #[cfg(feature = "into_owned")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "into_owned")))]
+  pub scope: Option<SelectorScope>,
+}
+
+/// Options for converting `px` lengths to `rem`. See [MinifyOptions](MinifyOptions).
+///
+/// Custom properties are left as is, since their values may be used in places other than lengths.
+#[cfg(feature = "visitor")]
+#[cfg_attr(docsrs, doc(cfg(feature = "visitor")))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PxToRemOptions {
+  /// The root font size, i.e. the number of `px` in `1rem`. Default is `16`.
+  pub root_font_size: f32,
+  /// Lengths smaller than this number of `px` are not converted. Default is `0`, which converts
+  /// all non-zero lengths. Set it to e.g. `2` to keep hairline borders in `px`.
+  pub min_px: f32,
+  /// Names of properties to leave in `px`. A name also matches the properties
+  /// that start with it followed by a `-`, e.g. `border` matches `border-top-width`.
+  pub exclude: Vec<String>,
+}
+
+#[cfg(feature = "visitor")]
+impl Default for PxToRemOptions {
+  fn default() -> Self {
+    PxToRemOptions {
+      root_font_size: 16.0,
+      min_px: 0.0,
+      exclude: Vec::new(),
+    }
+  }
+}
+
+#[cfg(feature = "visitor")]
+impl PxToRemOptions {
+  pub(crate) fn is_excluded(&self, name: &str) -> bool {
+    self.exclude.iter().any(|exclude| {
+      name.len() >= exclude.len()
+        && name.as_bytes()[..exclude.len()].eq_ignore_ascii_case(exclude.as_bytes())
+        && (name.len() == exclude.len() || name.as_bytes()[exclude.len()] == b'-')
+    })
+  }
+}
+
+/// Options for removing unused style rules. See [MinifyOptions](MinifyOptions).
+///
+/// A selector can never match when it references a class name, id, or tag name that is not used,
+/// e.g. `.foo .bar` unless both `foo` and `bar` are used classes. Such selectors are removed from
+/// selector lists, and style rules where none of the selectors can match are removed entirely.
+/// Selectors within `:not()` and `:has()` are not considered. Conditional group rules such as
+/// `@media` that become empty are removed as well, along with `@keyframes` rules that were only
+/// referenced by removed style rules.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PurgeOptions {
+  /// The class names that are used. If `None`, selectors are not purged based on class names.
+  pub classes: Option<HashSet<String>>,
+  /// The ids that are used. If `None`, selectors are not purged based on ids.
+  pub ids: Option<HashSet<String>>,
+  /// The lowercase tag names that are used. If `None`, selectors are not purged based on tag names.
+  pub tags: Option<HashSet<String>>,
+  /// Patterns of class names, ids, and tag names that are always considered used,
+  /// e.g. for names that are generated at runtime. `*` matches any sequence of characters,
+  /// e.g. `is-*` matches `is-active`.
+  pub safelist: Vec<String>,
+}
+
+impl PurgeOptions {
+  pub(crate) fn is_class_used(&self, name: &str) -> bool {
+    Self::is_used(&self.classes, name) || self.is_safelisted(name)
+  }
+
+  pub(crate) fn is_id_used(&self, name: &str) -> bool {
+    Self::is_used(&self.ids, name) || self.is_safelisted(name)
+  }
+
+  pub(crate) fn is_tag_used(&self, name: &str) -> bool {
+    Self::is_used(&self.tags, name) || self.is_safelisted(name)
+  }
+
+  fn is_used(names: &Option<HashSet<String>>, name: &str) -> bool {
+    names.as_ref().map_or(true, |names| names.contains(name))
+  }
+
+  fn is_safelisted(&self, name: &str) -> bool {
+    self
+      .safelist
+      .iter()
+      .any(|pattern| matches_pattern(pattern.as_bytes(), name.as_bytes()))
+  }
+}
+
+fn matches_pattern(pattern: &[u8], name: &[u8]) -> bool {
+  match pattern.split_first() {
+    None => name.is_empty(),
+    Some((b'*', rest)) => (0..=name.len()).any(|i| matches_pattern(rest, &name[i..])),
+    Some((c, rest)) => name.first() == Some(c) && matches_pattern(rest, &name[1..]),
+  }
+}
+
+/// A strategy for compiling `light-dark()` colors for targets that do not support them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LightDarkStrategy {
+  /// Select the color using the `--lightningcss-light` and `--lightningcss-dark` custom properties,
+  /// which are defined by the `color-scheme` property. This respects the color scheme of each element.
+  #[default]
+  CustomProperties,
+  /// Use the light color in the declaration, and override it with the dark color in a
+  /// `@media (prefers-color-scheme: dark)` rule. This follows the color scheme preferred by the
+  /// user rather than the `color-scheme` property, and does not apply in style attributes,
+  /// `@keyframes` rules, or `!important` declarations.
+  MediaQuery,
+}
+
+/// A strategy for compiling the `:dir()` pseudo class for targets that do not support it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DirStrategy {
+  /// Match the languages that are written right-to-left using the `:lang()` pseudo class.
+  /// This does not respect the `dir` attribute.
+  #[default]
+  Lang,
+  /// Match descendants of an element with a `dir` attribute, e.g. `.foo:dir(rtl)` => `[dir="rtl"] .foo`.
+  /// Compound selectors that only contain `:dir()` match the element with the attribute instead,
+  /// e.g. `:dir(rtl) > .foo` => `[dir="rtl"] > .foo`.
+  /// This is an approximation: the `dir` attribute of the element itself and of nested elements
+  /// with the opposite direction is not considered, and `:dir(ltr)` only matches within an element
+  /// with an explicit `dir="ltr"` attribute. `:dir()` within another pseudo class, e.g. `:not()`,
+  /// and selectors with conflicting directions are compiled using the `Lang` strategy.
+  Attribute,
+}
+
+/// How style rules are merged when minifying. See [MinifyOptions](MinifyOptions).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum MergeRules {
+  /// Merge adjacent style rules with equivalent selectors or identical declarations,
+  /// e.g. `.a { color: red } .b { color: red }` => `.a, .b { color: red }`.
+  #[default]
+  Adjacent,
+  /// In addition, merge a style rule into an earlier, non-adjacent style rule with equivalent selectors
+  /// or identical declarations, e.g. `.a { color: red } .b { margin: 0 } .a { padding: 0 }` =>
+  /// `.a { color: red; padding: 0 } .b { margin: 0 }`. This is only done when none of the rules in between
+  /// declare an overlapping property, or contain nested rules. Other rules, such as `@media`, are not
+  /// moved across. `@media` rules with identical queries are merged in the same way, e.g.
+  /// `@media print { .a { color: red } } .b { margin: 0 } @media print { .c { color: red } }` =>
+  /// `@media print { .a, .c { color: red } } .b { margin: 0 }`.
+  Restructure,
+  /// In addition, factor declarations that are shared by multiple style rules out into a new rule with
+  /// the combined selectors when this reduces the size of the output, e.g.
+  /// `.a { color: red; margin: 0 } .b { color: red; padding: 0 }` =>
+  /// `.a, .b { color: red } .a { margin: 0 } .b { padding: 0 }`. This is only done when none of the rules
+  /// in between declare an overlapping property.
+  Aggressive,
+}
+
+/// A strategy for scoping the selectors of a style sheet. See [MinifyOptions](MinifyOptions).
+///
+/// Selectors that contain `:global()` are not scoped. Nested style rules, as well as rules within
+/// `@media`, `@supports`, `@container`, `@layer`, and `@starting-style` are scoped, while other rules
+/// such as `@keyframes`, `@font-face`, `@page`, and `@scope` are left untouched.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SelectorScope {
+  /// Prefixes each selector with a scope selector as an ancestor, e.g. `.foo` => `.widget .foo`.
+  /// `:root` and `html` are replaced by the scope selector, e.g. `:root.dark .foo` => `.widget.dark .foo`.
+  /// Nested style rules are already scoped by their parent, and are left untouched.
+  Ancestor(Selector<'static>),
+  /// Adds an attribute selector to the rightmost compound selector of each selector, before any pseudo
+  /// element, e.g. `.foo .bar::before` => `.foo .bar[data-v-123]::before`. With the `>>>` and `/deep/`
+  /// combinators, the compound selector before the combinator is scoped instead. Selectors whose
+  /// rightmost compound selector matches `:root` or `html`, or contains `&`, are not scoped.
+  Attribute {
+    /// The name of the attribute, e.g. `data-v-123`.
+    name: String,
+    /// The value of the attribute. If `None`, only the presence of the attribute is matched.
+    value: Option<String>,
+  },
+}
+
+/// The kind of compatibility fallback passed to a [FallbackControl](FallbackControl) hook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FallbackKind {
+  /// Fallback values for the property, e.g. an `rgb()` fallback for a `lab()` color.
+  Value,
+  /// Vendor prefixed copies of the property, one per prefix.
+  Prefix,
+  /// Physical fallbacks for a logical property. The fallbacks are the left-to-right
+  /// declaration followed by the right-to-left declaration, which are emitted in rules
+  /// using the `:dir()` selector. The original logical property is not kept.
+  Logical,
+}
+
+/// A hook that controls the compatibility fallbacks generated during minification.
+///
+/// This is useful for frameworks that handle some fallbacks themselves.
+pub trait FallbackControl: Send + Sync {
+  /// Called whenever fallbacks are about to be generated for a property. Receives the
+  /// property, the kind of fallback, the targets, and the generated fallback declarations,
+  /// and returns the declarations to emit instead. Returning the fallbacks unchanged
+  /// keeps the default behavior, and returning an empty list suppresses them.
+  ///
+  /// For [Logical](FallbackKind::Logical) fallbacks, the property is the left-to-right
+  /// declaration, and both directions must be returned for the fallbacks to be kept.
+  fn fallbacks<'i>(
+    &self,
+    property: &Property<'i>,
+    kind: FallbackKind,
+    targets: &Targets,
+    fallbacks: Vec<Property<'i>>,
+  ) -> Vec<Property<'i>>;
+}
+
+impl std::fmt::Debug for dyn FallbackControl + '_ {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str("FallbackControl")
+  }
 }
 
 /// A result returned from `to_css`, including the serialize CSS
@@ -146,6 +425,7 @@ where
     mut options: ParserOptions<'o, 'i>,
     at_rule_parser: &mut P,
   ) -> Result<Self, Error<ParserError<'i>>> {
+    phase!("parse");
     let mut input = ParserInput::new(&code);
     let mut parser = Parser::new(&mut input);
     let mut license_comments = Vec::new();
@@ -205,10 +485,38 @@ where
 
   /// Minify and transform the style sheet for the provided browser targets.
   pub fn minify(&mut self, options: MinifyOptions) -> Result<(), Error<MinifyErrorKind>> {
-    let context = PropertyHandlerContext::new(options.targets, &options.unused_symbols);
+    phase!("minify");
+    let mut context = PropertyHandlerContext::new(options.targets, &options.unused_symbols);
+    context.fallback_control = options.fallback_control.as_deref();
+    context.gamut_mapping = options.gamut_mapping;
+    #[cfg(feature = "substitute_variables")]
+    if !options.environment_variables.is_empty() {
+      context.environment_variables = Some(&options.environment_variables);
+    }
+    #[cfg(feature = "visitor")]
+    {
+      context.light_dark = options.light_dark;
+      context.px_to_rem = options.px_to_rem.as_ref();
+    }
     let mut handler = DeclarationHandler::default();
     let mut important_handler = DeclarationHandler::default();
 
+    #[cfg(feature = "into_owned")]
+    if let Some(scope) = &options.scope {
+      scope_style_rules(&mut self.rules, scope);
+    }
+
+    if options.remove_unused_keyframes {
+      remove_unused_keyframes(&mut self.rules);
+    }
+
+    // Keyframes that are only referenced by purged rules are removed after minifying.
+    let purged_keyframes = if options.purge.is_some() {
+      referenced_animation_names(&self.rules)
+    } else {
+      None
+    };
+
     // @custom-media rules may be defined after they are referenced, but may only be defined at the top level
     // of a stylesheet. Do a pre-scan here and create a lookup table by name.
     let custom_media = if self.options.flags.contains(ParserFlags::CUSTOM_MEDIA)
@@ -233,6 +541,12 @@ where
       unused_symbols: &options.unused_symbols,
       custom_media,
       css_modules: self.options.css_modules.is_some(),
+      expand_is_selectors: options.expand_is_selectors,
+      dir_strategy: options.dir_strategy,
+      purge: options.purge.as_ref(),
+      merge_rules: options.merge_rules,
+      remove_overridden_declarations: options.remove_overridden_declarations,
+      warnings: Vec::new(),
     };
 
     self.rules.minify(&mut ctx, false).map_err(|e| Error {
@@ -243,14 +557,71 @@ where
       )),
     })?;
 
+    if let Some(warnings) = &self.options.warnings {
+      if let Ok(mut warnings) = warnings.write() {
+        for warning in ctx.warnings {
+          warnings.push(Error {
+            kind: warning.kind,
+            loc: Some(ErrorLocation::new(
+              warning.loc,
+              self.sources[warning.loc.source_index as usize].clone(),
+            )),
+          });
+        }
+      }
+    }
+
+    if options.deduplicate_font_faces {
+      deduplicate_font_faces(&mut self.rules);
+    }
+
+    if options.remove_unused_font_faces {
+      remove_unused_font_faces(&mut self.rules, &options.used_font_families);
+    }
+
+    if let Some(referenced) = purged_keyframes {
+      remove_purged_keyframes(&mut self.rules, &referenced);
+    }
+
+    // Keyframes names are exported from CSS modules, so they cannot be renamed.
+    if options.deduplicate_keyframes && self.options.css_modules.is_none() {
+      deduplicate_keyframes(&mut self.rules);
+    }
+
+    Ok(())
+  }
+
+  fn write_source_map_url<W: std::fmt::Write>(
+    &self,
+    source_map_url: Option<SourceMapUrl>,
+    dest: &mut Printer<W>,
+  ) -> Result<(), PrinterError> {
+    let url = match source_map_url {
+      Some(SourceMapUrl::Preserve) if self.sources.len() == 1 => self.source_map_url(0).map(|url| url.as_str()),
+      Some(SourceMapUrl::Url(url)) => Some(url),
+      _ => None,
+    };
+
+    if let Some(url) = url {
+      if dest.minify {
+        dest.write_char('\n')?;
+      }
+      dest.write_str("/*# sourceMappingURL=")?;
+      dest.write_str(url)?;
+      dest.write_str(" */")?;
+      dest.newline()?;
+    }
+
     Ok(())
   }
 
   /// Serialize the style sheet to a CSS string.
   pub fn to_css(&self, options: PrinterOptions) -> Result<ToCssResult, Error<PrinterErrorKind>> {
+    phase!("print");
     // Make sure we always have capacity > 0: https://github.com/napi-rs/napi-rs/issues/1124.
     let mut dest = String::with_capacity(1);
     let project_root = options.project_root.clone();
+    let source_map_url = options.source_map_url;
     let mut printer = Printer::new(&mut dest, options);
 
     #[cfg(feature = "sourcemap")]
@@ -260,6 +631,7 @@ where
 
     #[cfg(feature = "sourcemap")]
     if printer.source_map.is_some() {
+      phase!("source map");
       printer.source_maps = self.sources.iter().enumerate().map(|(i, _)| self.source_map(i)).collect();
     }
 
@@ -275,6 +647,7 @@ where
 
       self.rules.to_css(&mut printer)?;
       printer.newline()?;
+      self.write_source_map_url(source_map_url, &mut printer)?;
 
       Ok(ToCssResult {
         dependencies: printer.dependencies,
@@ -287,6 +660,7 @@ where
     } else {
       self.rules.to_css(&mut printer)?;
       printer.newline()?;
+      self.write_source_map_url(source_map_url, &mut printer)?;
 
       Ok(ToCssResult {
         dependencies: printer.dependencies,
@@ -370,6 +744,16 @@ impl<'i> StyleAttribute<'i> {
     let mut handler = DeclarationHandler::default();
     let mut important_handler = DeclarationHandler::default();
     context.context = DeclarationContext::StyleAttribute;
+    context.fallback_control = options.fallback_control.as_deref();
+    context.gamut_mapping = options.gamut_mapping;
+    #[cfg(feature = "substitute_variables")]
+    if !options.environment_variables.is_empty() {
+      context.environment_variables = Some(&options.environment_variables);
+    }
+    #[cfg(feature = "visitor")]
+    {
+      context.px_to_rem = options.px_to_rem.as_ref();
+    }
     self.declarations.minify(&mut handler, &mut important_handler, &mut context);
   }
 
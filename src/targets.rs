@@ -115,6 +115,28 @@ impl Browsers {
   }
 }
 
+impl Browsers {
+  /// Returns a separate set of targets for each browser included in these targets.
+  pub(crate) fn split(&self) -> Vec<Browsers> {
+    let mut res = Vec::new();
+    macro_rules! browser {
+      ($($name: ident),+) => {
+        $(
+          if self.$name.is_some() {
+            res.push(Browsers {
+              $name: self.$name,
+              ..Browsers::default()
+            });
+          }
+        )+
+      };
+    }
+
+    browser!(android, chrome, edge, firefox, ie, ios_saf, opera, safari, samsung);
+    res
+  }
+}
+
 #[cfg(feature = "browserslist")]
 fn parse_version(version: &str) -> Option<u32> {
   let version = version.split('-').next();
@@ -158,6 +180,8 @@ bitflags! {
     const DoublePositionGradients = 1 << 17;
     const VendorPrefixes = 1 << 18;
     const LogicalProperties = 1 << 19;
+    const LineClamp = 1 << 20;
+    const WebKitScrollbar = 1 << 21;
     const Selectors = Self::Nesting.bits() | Self::NotSelectorList.bits() | Self::DirSelector.bits() | Self::LangSelectorList.bits() | Self::IsSelector.bits();
     const MediaQueries = Self::MediaIntervalSyntax.bits() | Self::MediaRangeSyntax.bits() | Self::CustomMediaQueries.bits();
     const Colors = Self::ColorFunction.bits() | Self::OklabColors.bits() | Self::LabColors.bits() | Self::P3Colors.bits() | Self::HexAlphaColors.bits() | Self::SpaceSeparatedColorNotation.bits();
@@ -0,0 +1,238 @@
+//! Instrumentation for diagnosing slow builds.
+//!
+//! When the `timings` feature is enabled, Lightning CSS records the time spent in each
+//! phase of processing a style sheet (e.g. parsing, minifying, and printing), along with
+//! the number of allocations made during each phase. Recording is started with [start](start),
+//! and the results are collected with [finish](finish).
+//!
+//! Allocation counts are only available when [CountingAllocator](CountingAllocator) is
+//! installed as the global allocator. Otherwise, they are always zero. Allocations are
+//! counted per thread, so a phase only includes allocations made on the thread it ran on.
+//!
+//! # Example
+//!
+//! ```
+//! use lightningcss::stylesheet::{StyleSheet, ParserOptions, MinifyOptions, PrinterOptions};
+//! use lightningcss::timings;
+//!
+//! timings::start();
+//! let mut stylesheet = StyleSheet::parse(".foo { color: red }", ParserOptions::default()).unwrap();
+//! stylesheet.minify(MinifyOptions::default()).unwrap();
+//! stylesheet.to_css(PrinterOptions::default()).unwrap();
+//! let timings = timings::finish();
+//!
+//! assert!(timings.get("parse").is_some());
+//! eprintln!("{}", timings);
+//! ```
+
+use std::alloc::{GlobalAlloc, Layout};
+use std::cell::{Cell, RefCell};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static PHASES: Mutex<Vec<PhaseTiming>> = Mutex::new(Vec::new());
+
+thread_local! {
+  static ACTIVE: RefCell<Vec<&'static str>> = RefCell::new(Vec::new());
+  // These are accessed from the global allocator, so they must not allocate or have destructors.
+  static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+  static ALLOCATED_BYTES: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Returns the number of allocations and allocated bytes counted on the current thread.
+fn thread_allocations() -> (u64, u64) {
+  (
+    ALLOCATIONS.try_with(Cell::get).unwrap_or(0),
+    ALLOCATED_BYTES.try_with(Cell::get).unwrap_or(0),
+  )
+}
+
+fn count_allocation(size: usize) {
+  let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+  let _ = ALLOCATED_BYTES.try_with(|bytes| bytes.set(bytes.get() + size as u64));
+}
+
+/// The time and allocations recorded for a phase.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhaseTiming {
+  /// The name of the phase.
+  pub name: &'static str,
+  /// The number of times the phase was entered.
+  pub count: u64,
+  /// The total time spent in the phase.
+  pub duration: Duration,
+  /// The number of allocations made during the phase.
+  pub allocations: u64,
+  /// The number of bytes allocated during the phase.
+  pub allocated_bytes: u64,
+}
+
+/// The timings recorded between calls to [start](start) and [finish](finish).
+///
+/// Phases are listed in the order they first completed. Timings are inclusive,
+/// i.e. a phase includes the time spent in any phases nested within it. When phases
+/// run in parallel (e.g. when bundling), their durations are summed across threads.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Timings {
+  /// The recorded phases.
+  pub phases: Vec<PhaseTiming>,
+}
+
+impl Timings {
+  /// Returns the timing for the phase with the given name.
+  pub fn get(&self, name: &str) -> Option<&PhaseTiming> {
+    self.phases.iter().find(|phase| phase.name == name)
+  }
+}
+
+impl std::fmt::Display for Timings {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let width = self.phases.iter().map(|phase| phase.name.len()).max().unwrap_or(0).max(5);
+    writeln!(
+      f,
+      "{:<width$} {:>8} {:>12} {:>12} {:>14}",
+      "phase", "count", "time (ms)", "allocations", "bytes"
+    )?;
+    for phase in &self.phases {
+      writeln!(
+        f,
+        "{:<width$} {:>8} {:>12.3} {:>12} {:>14}",
+        phase.name,
+        phase.count,
+        phase.duration.as_secs_f64() * 1000.0,
+        phase.allocations,
+        phase.allocated_bytes
+      )?;
+    }
+    Ok(())
+  }
+}
+
+/// Starts recording timings, discarding any previously recorded phases.
+pub fn start() {
+  PHASES.lock().unwrap().clear();
+  ENABLED.store(true, Ordering::SeqCst);
+}
+
+/// Stops recording timings, and returns the phases recorded since [start](start) was called.
+pub fn finish() -> Timings {
+  ENABLED.store(false, Ordering::SeqCst);
+  Timings {
+    phases: std::mem::take(&mut *PHASES.lock().unwrap()),
+  }
+}
+
+/// A guard that records a phase when dropped.
+///
+/// Nested phases with the same name on the same thread are only recorded once,
+/// so recursive functions can be instrumented without counting time twice.
+pub struct Phase {
+  name: &'static str,
+  start: Option<(Instant, u64, u64)>,
+}
+
+impl Phase {
+  /// Enters a phase with the given name. The phase ends when the returned guard is dropped.
+  pub fn start(name: &'static str) -> Phase {
+    let active = ENABLED.load(Ordering::Relaxed)
+      && ACTIVE.with(|active| {
+        let mut active = active.borrow_mut();
+        if active.contains(&name) {
+          false
+        } else {
+          active.push(name);
+          true
+        }
+      });
+
+    Phase {
+      name,
+      start: if active {
+        let (allocations, allocated_bytes) = thread_allocations();
+        Some((Instant::now(), allocations, allocated_bytes))
+      } else {
+        None
+      },
+    }
+  }
+}
+
+impl Drop for Phase {
+  fn drop(&mut self) {
+    let Some((start, start_allocations, start_allocated_bytes)) = self.start else {
+      return;
+    };
+
+    let duration = start.elapsed();
+    let (allocations, allocated_bytes) = thread_allocations();
+    let allocations = allocations.saturating_sub(start_allocations);
+    let allocated_bytes = allocated_bytes.saturating_sub(start_allocated_bytes);
+
+    ACTIVE.with(|active| {
+      let mut active = active.borrow_mut();
+      if let Some(index) = active.iter().rposition(|name| *name == self.name) {
+        active.remove(index);
+      }
+    });
+
+    if !ENABLED.load(Ordering::Relaxed) {
+      return;
+    }
+
+    let mut phases = PHASES.lock().unwrap();
+    if let Some(phase) = phases.iter_mut().find(|phase| phase.name == self.name) {
+      phase.count += 1;
+      phase.duration += duration;
+      phase.allocations += allocations;
+      phase.allocated_bytes += allocated_bytes;
+    } else {
+      phases.push(PhaseTiming {
+        name: self.name,
+        count: 1,
+        duration,
+        allocations,
+        allocated_bytes,
+      });
+    }
+  }
+}
+
+/// A global allocator that counts allocations for [Timings](Timings), wrapping another allocator.
+///
+/// Allocations are counted separately for each thread, so phases running in parallel on
+/// other threads do not affect each other's counts.
+///
+/// # Example
+///
+/// ```
+/// use lightningcss::timings::CountingAllocator;
+///
+/// #[global_allocator]
+/// static GLOBAL: CountingAllocator<std::alloc::System> = CountingAllocator(std::alloc::System);
+///
+/// fn main() {}
+/// ```
+pub struct CountingAllocator<A>(pub A);
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for CountingAllocator<A> {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    count_allocation(layout.size());
+    self.0.alloc(layout)
+  }
+
+  unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+    count_allocation(layout.size());
+    self.0.alloc_zeroed(layout)
+  }
+
+  unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+    count_allocation(new_size);
+    self.0.realloc(ptr, layout, new_size)
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    self.0.dealloc(ptr, layout)
+  }
+}
@@ -7,6 +7,7 @@ use crate::printer::Printer;
 use crate::properties::{Property, PropertyId};
 use crate::stylesheet::{ParserOptions, PrinterOptions};
 use crate::targets::{Browsers, Targets};
+use crate::values::color::GamutMapping;
 use crate::vendor_prefix::VendorPrefix;
 use cssparser::*;
 
@@ -115,7 +116,7 @@ pub(crate) trait FromStandard<T>: Sized {
 }
 
 pub(crate) trait FallbackValues: Sized {
-  fn get_fallbacks(&mut self, targets: Targets) -> Vec<Self>;
+  fn get_fallbacks(&mut self, targets: Targets, gamut_mapping: GamutMapping) -> Vec<Self>;
 }
 
 /// Trait for shorthand properties.
@@ -12,6 +12,7 @@ use crate::visitor::Visit;
 use cssparser::*;
 
 use super::angle::Angle;
+use super::ident::{DashedIdent, DashedIdentReference};
 use super::length::Length;
 use super::number::CSSNumber;
 use super::percentage::Percentage;
@@ -51,6 +52,14 @@ pub enum MathFunction<V> {
   Sign(Calc<V>),
   /// The [`hypot()`](https://drafts.csswg.org/css-values-4/#funcdef-hypot) function.
   Hypot(Vec<Calc<V>>),
+  /// The [`anchor()`](https://drafts.csswg.org/css-anchor-position-1/#anchor-pos) function.
+  Anchor(AnchorFunction<V>),
+  /// The [`anchor-size()`](https://drafts.csswg.org/css-anchor-position-1/#anchor-size-fn) function.
+  AnchorSize(AnchorSizeFunction<V>),
+  /// The [`sibling-index()`](https://drafts.csswg.org/css-values-5/#funcdef-sibling-index) function.
+  SiblingIndex,
+  /// The [`sibling-count()`](https://drafts.csswg.org/css-values-5/#funcdef-sibling-count) function.
+  SiblingCount,
 }
 
 impl<V: IsCompatible> IsCompatible for MathFunction<V> {
@@ -83,6 +92,12 @@ impl<V: IsCompatible> IsCompatible for MathFunction<V> {
       MathFunction::Hypot(v) => {
         Feature::HypotFunction.is_compatible(browsers) && v.iter().all(|v| v.is_compatible(browsers))
       }
+      MathFunction::Anchor(AnchorFunction { fallback, .. })
+      | MathFunction::AnchorSize(AnchorSizeFunction { fallback, .. }) => {
+        fallback.as_ref().map_or(true, |v| v.is_compatible(browsers))
+      }
+      MathFunction::SiblingIndex => Feature::SiblingIndexFunction.is_compatible(browsers),
+      MathFunction::SiblingCount => Feature::SiblingCountFunction.is_compatible(browsers),
     }
   }
 }
@@ -108,6 +123,25 @@ impl Default for RoundingStrategy {
   }
 }
 
+/// The number of decimal places that the results of `sin()`, `cos()`, and `tan()` are rounded to.
+/// This drops floating point noise, e.g. `cos(90deg)` evaluates to exactly `0`.
+const TRIG_PRECISION: i32 = 6;
+
+fn round_trig_result(value: f64) -> f32 {
+  if !value.is_finite() {
+    return value as f32;
+  }
+
+  let factor = 10f64.powi(TRIG_PRECISION);
+  let rounded = (value * factor).round() / factor;
+  // Avoid emitting negative zero.
+  if rounded == 0.0 {
+    0.0
+  } else {
+    rounded as f32
+  }
+}
+
 fn round(value: f32, to: f32, strategy: RoundingStrategy) -> f32 {
   let v = value / to;
   match strategy {
@@ -229,10 +263,177 @@ impl<V: ToCss + std::ops::Mul<f32, Output = V> + TrySign + Clone + std::fmt::Deb
         }
         dest.write_char(')')
       }
+      MathFunction::Anchor(anchor) => {
+        dest.write_str("anchor(")?;
+        if let Some(name) = &anchor.name {
+          write_anchor_name(name, dest)?;
+          dest.write_char(' ')?;
+        }
+        anchor.side.to_css(dest)?;
+        if let Some(fallback) = &anchor.fallback {
+          dest.delim(',', false)?;
+          fallback.to_css(dest)?;
+        }
+        dest.write_char(')')
+      }
+      MathFunction::AnchorSize(anchor) => {
+        dest.write_str("anchor-size(")?;
+        if let Some(name) = &anchor.name {
+          write_anchor_name(name, dest)?;
+          if anchor.size.is_some() {
+            dest.write_char(' ')?;
+          }
+        }
+        if let Some(size) = &anchor.size {
+          size.to_css(dest)?;
+        }
+        if let Some(fallback) = &anchor.fallback {
+          if anchor.name.is_some() || anchor.size.is_some() {
+            dest.delim(',', false)?;
+          }
+          fallback.to_css(dest)?;
+        }
+        dest.write_char(')')
+      }
+      MathFunction::SiblingIndex => dest.write_str("sibling-index()"),
+      MathFunction::SiblingCount => dest.write_str("sibling-count()"),
     }
   }
 }
 
+fn write_anchor_name<W>(name: &str, dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  DashedIdentReference {
+    ident: DashedIdent(name.into()),
+    from: None,
+  }
+  .to_css(dest)
+}
+
+/// An [`anchor()`](https://drafts.csswg.org/css-anchor-position-1/#anchor-pos) function,
+/// which resolves to the position of an edge of an anchor element.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct AnchorFunction<V> {
+  /// The name of the anchor element. If omitted, the default anchor element is used.
+  pub name: Option<String>,
+  /// The side of the anchor element to position against.
+  pub side: AnchorSide,
+  /// A fallback value, used when the anchor element cannot be resolved.
+  pub fallback: Option<Calc<V>>,
+}
+
+/// An [`anchor-size()`](https://drafts.csswg.org/css-anchor-position-1/#anchor-size-fn) function,
+/// which resolves to a dimension of an anchor element.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct AnchorSizeFunction<V> {
+  /// The name of the anchor element. If omitted, the default anchor element is used.
+  pub name: Option<String>,
+  /// The dimension of the anchor element. If omitted, the axis of the property is used.
+  pub size: Option<AnchorSizeKeyword>,
+  /// A fallback value, used when the anchor element cannot be resolved.
+  pub fallback: Option<Calc<V>>,
+}
+
+enum_property! {
+  /// A keyword for the [`<anchor-side>`](https://drafts.csswg.org/css-anchor-position-1/#typedef-anchor-side)
+  /// argument of the `anchor()` function.
+  pub enum AnchorSideKeyword {
+    /// The side of the anchor element facing the positioned element.
+    "inside": Inside,
+    /// The side of the anchor element opposite the positioned element.
+    "outside": Outside,
+    /// The top side of the anchor element.
+    "top": Top,
+    /// The left side of the anchor element.
+    "left": Left,
+    /// The right side of the anchor element.
+    "right": Right,
+    /// The bottom side of the anchor element.
+    "bottom": Bottom,
+    /// The start side of the anchor element, according to the containing block's writing mode.
+    "start": Start,
+    /// The end side of the anchor element, according to the containing block's writing mode.
+    "end": End,
+    /// The start side of the anchor element, according to the element's own writing mode.
+    "self-start": SelfStart,
+    /// The end side of the anchor element, according to the element's own writing mode.
+    "self-end": SelfEnd,
+    /// The center of the anchor element.
+    "center": Center,
+  }
+}
+
+/// An [`<anchor-side>`](https://drafts.csswg.org/css-anchor-position-1/#typedef-anchor-side) value.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum AnchorSide {
+  /// A side keyword.
+  Keyword(AnchorSideKeyword),
+  /// A position between the start and end sides of the anchor element.
+  Percentage(Percentage),
+}
+
+impl<'i> Parse<'i> for AnchorSide {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(keyword) = input.try_parse(AnchorSideKeyword::parse) {
+      return Ok(AnchorSide::Keyword(keyword));
+    }
+
+    Ok(AnchorSide::Percentage(Percentage::parse(input)?))
+  }
+}
+
+impl ToCss for AnchorSide {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      AnchorSide::Keyword(keyword) => keyword.to_css(dest),
+      AnchorSide::Percentage(percentage) => percentage.to_css(dest),
+    }
+  }
+}
+
+enum_property! {
+  /// An [`<anchor-size>`](https://drafts.csswg.org/css-anchor-position-1/#typedef-anchor-size) keyword.
+  pub enum AnchorSizeKeyword {
+    /// The width of the anchor element.
+    "width": Width,
+    /// The height of the anchor element.
+    "height": Height,
+    /// The block size of the anchor element, according to the containing block's writing mode.
+    "block": Block,
+    /// The inline size of the anchor element, according to the containing block's writing mode.
+    "inline": Inline,
+    /// The block size of the anchor element, according to the element's own writing mode.
+    "self-block": SelfBlock,
+    /// The inline size of the anchor element, according to the element's own writing mode.
+    "self-inline": SelfInline,
+  }
+}
+
+fn parse_anchor_name<'i>(input: &mut Parser<'i, '_>) -> Result<String, ParseError<'i, ParserError<'i>>> {
+  Ok(DashedIdent::parse(input)?.0.to_string())
+}
+
 /// A mathematical expression used within the [`calc()`](https://www.w3.org/TR/css-values-4/#calc-func) function.
 ///
 /// This type supports generic value types. Values such as [Length](super::length::Length), [Percentage](super::percentage::Percentage),
@@ -260,6 +461,10 @@ pub enum Calc<V> {
   /// A math function, such as `calc()`, `min()`, or `max()`.
   #[cfg_attr(feature = "visitor", skip_type)]
   Function(Box<MathFunction<V>>),
+  /// A product of two calc expressions, where the first is a number that cannot
+  /// be computed statically, such as `sibling-index()`.
+  #[cfg_attr(feature = "visitor", skip_type)]
+  Multiply(Box<Calc<V>>, Box<Calc<V>>),
 }
 
 impl<V: IsCompatible> IsCompatible for Calc<V> {
@@ -267,6 +472,7 @@ impl<V: IsCompatible> IsCompatible for Calc<V> {
     match self {
       Calc::Sum(a, b) => a.is_compatible(browsers) && b.is_compatible(browsers),
       Calc::Product(_, v) => v.is_compatible(browsers),
+      Calc::Multiply(a, b) => a.is_compatible(browsers) && b.is_compatible(browsers),
       Calc::Function(f) => f.is_compatible(browsers),
       Calc::Value(v) => v.is_compatible(browsers),
       Calc::Number(..) => true,
@@ -382,6 +588,12 @@ impl<
         })?;
 
         // According to the spec, the minimum should "win" over the maximum if they are in the wrong order.
+        if let (Some(Calc::Value(min_val)), Some(Calc::Value(max_val))) = (&min, &max) {
+          if min_val.partial_cmp(max_val) == Some(std::cmp::Ordering::Greater) {
+            return Ok(min.unwrap());
+          }
+        }
+
         let cmp = if let (Some(Calc::Value(max_val)), Calc::Value(center_val)) = (&max, &center) {
           center_val.partial_cmp(&max_val)
         } else {
@@ -426,6 +638,14 @@ impl<
           (Some(min), Some(max)) => Ok(Calc::Function(Box::new(MathFunction::Clamp(min, center, max))))
         }
       },
+      "sibling-index" => {
+        input.parse_nested_block(|input| input.expect_exhausted().map_err(|e| e.into()))?;
+        Ok(Calc::Function(Box::new(MathFunction::SiblingIndex)))
+      },
+      "sibling-count" => {
+        input.parse_nested_block(|input| input.expect_exhausted().map_err(|e| e.into()))?;
+        Ok(Calc::Function(Box::new(MathFunction::SiblingCount)))
+      },
       "round" => {
         input.parse_nested_block(|input| {
           let strategy = if let Ok(s) = input.try_parse(RoundingStrategy::parse) {
@@ -435,12 +655,17 @@ impl<
             RoundingStrategy::default()
           };
 
-          Self::parse_math_fn(
-            input,
-            |a, b| round(a, b, strategy),
-            |a, b| MathFunction::Round(strategy, a, b),
-            parse_ident
-          )
+          // The interval may be omitted when the value is a plain number, in which case it defaults to 1.
+          let a: Calc<V> = Calc::parse_sum(input, parse_ident)?;
+          let b: Calc<V> = match a {
+            Calc::Number(_) if input.is_exhausted() => Calc::Number(1.0),
+            _ => {
+              input.expect_comma()?;
+              Calc::parse_sum(input, parse_ident)?
+            }
+          };
+
+          Ok(Self::apply_op(&a, &b, |a, b| round(a, b, strategy)).unwrap_or_else(|| Calc::Function(Box::new(MathFunction::Round(strategy, a, b)))))
         })
       },
       "rem" => {
@@ -453,12 +678,12 @@ impl<
           Self::parse_math_fn(input, modulo, MathFunction::Mod, parse_ident)
         })
       },
-      "sin" => Self::parse_trig(input, f32::sin, false, parse_ident),
-      "cos" => Self::parse_trig(input, f32::cos, false, parse_ident),
-      "tan" => Self::parse_trig(input, f32::tan, false, parse_ident),
-      "asin" => Self::parse_trig(input, f32::asin, true, parse_ident),
-      "acos" => Self::parse_trig(input, f32::acos, true, parse_ident),
-      "atan" => Self::parse_trig(input, f32::atan, true, parse_ident),
+      "sin" => Self::parse_trig(input, f64::sin, false, parse_ident),
+      "cos" => Self::parse_trig(input, f64::cos, false, parse_ident),
+      "tan" => Self::parse_trig(input, f64::tan, false, parse_ident),
+      "asin" => Self::parse_trig(input, f64::asin, true, parse_ident),
+      "acos" => Self::parse_trig(input, f64::acos, true, parse_ident),
+      "atan" => Self::parse_trig(input, f64::atan, true, parse_ident),
       "atan2" => {
         input.parse_nested_block(|input| {
           let res = Self::parse_atan2(input, parse_ident)?;
@@ -528,6 +753,36 @@ impl<
 
           Ok(Calc::Function(Box::new(MathFunction::Sign(v))))
         })
+      },
+      "anchor" => {
+        input.parse_nested_block(|input| {
+          let mut name = input.try_parse(parse_anchor_name).ok();
+          let side = AnchorSide::parse(input)?;
+          if name.is_none() {
+            name = input.try_parse(parse_anchor_name).ok();
+          }
+          let fallback = if input.try_parse(|input| input.expect_comma()).is_ok() {
+            Some(Self::parse_sum(input, parse_ident)?)
+          } else {
+            None
+          };
+          Ok(Calc::Function(Box::new(MathFunction::Anchor(AnchorFunction { name, side, fallback }))))
+        })
+      },
+      "anchor-size" => {
+        input.parse_nested_block(|input| {
+          let mut name = input.try_parse(parse_anchor_name).ok();
+          let size = input.try_parse(AnchorSizeKeyword::parse).ok();
+          if name.is_none() {
+            name = input.try_parse(parse_anchor_name).ok();
+          }
+          let fallback = if (name.is_none() && size.is_none()) || input.try_parse(|input| input.expect_comma()).is_ok() {
+            input.try_parse(|input| Self::parse_sum(input, parse_ident)).ok()
+          } else {
+            None
+          };
+          Ok(Calc::Function(Box::new(MathFunction::AnchorSize(AnchorSizeFunction { name, size, fallback }))))
+        })
       },
        _ => Err(location.new_unexpected_token_error(Token::Ident(f.clone()))),
     }
@@ -586,6 +841,10 @@ impl<
           } else if let Calc::Number(val) = node {
             node = rhs;
             node = node * val;
+          } else if node.is_dynamic_number() {
+            node = Calc::Multiply(Box::new(node), Box::new(rhs));
+          } else if rhs.is_dynamic_number() {
+            node = Calc::Multiply(Box::new(rhs), Box::new(node));
           } else {
             return Err(input.new_unexpected_token_error(Token::Delim('*')));
           }
@@ -609,6 +868,21 @@ impl<
     Ok(node)
   }
 
+  /// Returns whether this is a number that can only be computed at runtime,
+  /// such as an expression involving `sibling-index()` or `sibling-count()`.
+  fn is_dynamic_number(&self) -> bool {
+    match self {
+      Calc::Function(f) => matches!(**f, MathFunction::SiblingIndex | MathFunction::SiblingCount),
+      Calc::Sum(a, b) => {
+        (a.is_dynamic_number() || matches!(**a, Calc::Number(_)))
+          && (b.is_dynamic_number() || matches!(**b, Calc::Number(_)))
+      }
+      Calc::Product(_, v) => v.is_dynamic_number(),
+      Calc::Multiply(a, b) => a.is_dynamic_number() && b.is_dynamic_number(),
+      _ => false,
+    }
+  }
+
   fn parse_value<'t, Parse: Copy + Fn(&str) -> Option<Calc<V>>>(
     input: &mut Parser<'i, 't>,
     parse_ident: Parse,
@@ -654,8 +928,18 @@ impl<
   fn reduce_args(args: &mut Vec<Calc<V>>, cmp: std::cmp::Ordering) -> Vec<Calc<V>> {
     // Reduces the arguments of a min() or max() expression, combining compatible values.
     // e.g. min(1px, 1em, 2px, 3in) => min(1px, 1em)
+    // Nested functions of the same kind are flattened first, e.g. min(1px, min(2px, 1em)) => min(1px, 1em).
+    let args = args.drain(..).flat_map(|arg| match arg {
+      Calc::Function(f) => match (*f, cmp) {
+        (MathFunction::Min(args), std::cmp::Ordering::Less)
+        | (MathFunction::Max(args), std::cmp::Ordering::Greater) => args,
+        (f, _) => vec![Calc::Function(Box::new(f))],
+      },
+      arg => vec![arg],
+    });
+
     let mut reduced: Vec<Calc<V>> = vec![];
-    for arg in args.drain(..) {
+    for arg in args {
       let mut found = None;
       match &arg {
         Calc::Value(val) => {
@@ -734,7 +1018,7 @@ impl<
     None
   }
 
-  fn parse_trig<'t, F: FnOnce(f32) -> f32, Parse: Copy + Fn(&str) -> Option<Calc<V>>>(
+  fn parse_trig<'t, F: FnOnce(f64) -> f64, Parse: Copy + Fn(&str) -> Option<Calc<V>>>(
     input: &mut Parser<'i, 't>,
     f: F,
     to_angle: bool,
@@ -747,20 +1031,21 @@ impl<
           _ => None,
         })
       })?;
+      // Evaluate in double precision so that results like cos(90deg) are not polluted by f32 rounding errors.
       let rad = match v {
-        Calc::Value(angle) if !to_angle => f(angle.to_radians()),
-        Calc::Number(v) => f(v),
+        Calc::Value(angle) if !to_angle => f((angle.to_degrees() as f64).to_radians()),
+        Calc::Number(v) => f(v as f64),
         _ => return Err(input.new_custom_error(ParserError::InvalidValue)),
       };
 
       if to_angle && !rad.is_nan() {
-        if let Ok(v) = V::try_from(Angle::Rad(rad)) {
+        if let Ok(v) = V::try_from(Angle::Rad(rad as f32)) {
           return Ok(Calc::Value(Box::new(v)));
         } else {
           return Err(input.new_custom_error(ParserError::InvalidValue));
         }
       } else {
-        Ok(Calc::Number(rad))
+        Ok(Calc::Number(round_trig_result(rad)))
       }
     })
   }
@@ -897,6 +1182,7 @@ impl<V: std::ops::Mul<f32, Output = V>> std::ops::Mul<f32> for Calc<V> {
         MathFunction::Calc(c) => Calc::Function(Box::new(MathFunction::Calc(c * other))),
         _ => Calc::Product(other, Box::new(Calc::Function(f))),
       },
+      Calc::Multiply(a, b) => Calc::Multiply(a, Box::new(*b * other)),
     }
   }
 }
@@ -908,10 +1194,15 @@ impl<V: AddInternal + std::convert::Into<Calc<V>> + std::convert::From<Calc<V>>
     match (self, other) {
       (Calc::Value(a), Calc::Value(b)) => (a.add(*b)).into(),
       (Calc::Number(a), Calc::Number(b)) => Calc::Number(a + b),
+      // Numbers cannot be added to values of other types, so leave these unsimplified.
+      (a @ Calc::Value(_), b @ Calc::Number(_)) | (a @ Calc::Number(_), b @ Calc::Value(_)) => {
+        Calc::Sum(Box::new(a), Box::new(b))
+      }
       (Calc::Value(a), b) => (a.add(V::from(b))).into(),
       (a, Calc::Value(b)) => (V::from(a).add(*b)).into(),
       (Calc::Function(a), b) => Calc::Sum(Box::new(Calc::Function(a)), Box::new(b)),
       (a, Calc::Function(b)) => Calc::Sum(Box::new(a), Box::new(Calc::Function(b))),
+      (a @ Calc::Multiply(..), b) | (a, b @ Calc::Multiply(..)) => Calc::Sum(Box::new(a), Box::new(b)),
       (a, b) => V::from(a).add(V::from(b)).into(),
     }
   }
@@ -954,6 +1245,20 @@ impl<V: ToCss + std::ops::Mul<f32, Output = V> + TrySign + Clone + std::fmt::Deb
         }
       }
       Calc::Function(f) => f.to_css(dest),
+      Calc::Multiply(a, b) => {
+        let write_operand = |calc: &Calc<V>, dest: &mut Printer<W>| {
+          if matches!(calc, Calc::Sum(..)) {
+            dest.write_char('(')?;
+            calc.to_css(dest)?;
+            dest.write_char(')')
+          } else {
+            calc.to_css(dest)
+          }
+        };
+        write_operand(a, dest)?;
+        dest.delim('*', true)?;
+        write_operand(b, dest)
+      }
     };
 
     dest.in_calc = was_in_calc;
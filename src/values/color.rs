@@ -60,10 +60,19 @@ pub enum CssColor {
   Predefined(Box<PredefinedColor>),
   /// A floating point representation of an RGB, HSL, or HWB color when it contains `none` components.
   Float(Box<FloatColor>),
+  /// A value in a custom color profile defined by an `@color-profile` rule.
+  Profile(Box<ProfileColor>),
+  /// A [`device-cmyk()`](https://drafts.csswg.org/css-color-5/#device-cmyk) color.
+  #[cfg_attr(feature = "visitor", skip_type)]
+  DeviceCMYK(Box<DeviceCMYK>),
   /// The [`light-dark()`](https://drafts.csswg.org/css-color-5/#light-dark) function.
   #[cfg_attr(feature = "visitor", skip_type)]
   #[cfg_attr(feature = "serde", serde(with = "LightDark"))]
   LightDark(Box<CssColor>, Box<CssColor>),
+  /// The [`contrast-color()`](https://drafts.csswg.org/css-color-5/#contrast-color) function.
+  #[cfg_attr(feature = "visitor", skip_type)]
+  #[cfg_attr(feature = "serde", serde(with = "ContrastColor"))]
+  ContrastColor(Box<CssColor>),
   /// A [system color](https://drafts.csswg.org/css-color/#css-system-colors) keyword.
   System(SystemColor),
 }
@@ -157,6 +166,36 @@ impl<'de> LightDark {
   }
 }
 
+// For AST serialization.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+enum ContrastColor {
+  ContrastColor { color: CssColor },
+}
+
+#[cfg(feature = "serde")]
+impl<'de> ContrastColor {
+  pub fn serialize<S>(color: &CssColor, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    let wrapper = ContrastColor::ContrastColor { color: color.clone() };
+    serde::Serialize::serialize(&wrapper, serializer)
+  }
+
+  pub fn deserialize<D>(deserializer: D) -> Result<Box<CssColor>, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    let v: ContrastColor = serde::Deserialize::deserialize(deserializer)?;
+    match v {
+      ContrastColor::ContrastColor { color } => Ok(Box::new(color)),
+    }
+  }
+}
+
 /// A color in a LAB color space, including the `lab()`, `lch()`, `oklab()`, and `oklch()` functions.
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
@@ -209,6 +248,69 @@ pub enum PredefinedColor {
   XYZd65(XYZd65),
 }
 
+/// A color in a custom color profile, defined by an [`@color-profile`](https://drafts.csswg.org/css-color-5/#at-profile)
+/// rule and referenced by name within the `color()` function, e.g. `color(--swop5c 0% 70% 20% 0%)`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename = "profile")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct ProfileColor {
+  /// The name of the color profile, including the leading `--`.
+  pub profile: String,
+  /// The color components. Percentages are stored as numbers between 0 and 1,
+  /// and missing (i.e. `none`) components are stored as NaN.
+  pub components: Vec<f32>,
+  /// The alpha component.
+  pub alpha: f32,
+}
+
+/// A color in the uncalibrated [device-cmyk](https://drafts.csswg.org/css-color-5/#device-cmyk) color space.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename = "device-cmyk")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct DeviceCMYK {
+  /// The cyan component, between 0 and 1.
+  pub c: f32,
+  /// The magenta component, between 0 and 1.
+  pub m: f32,
+  /// The yellow component, between 0 and 1.
+  pub y: f32,
+  /// The black component, between 0 and 1.
+  pub k: f32,
+  /// The alpha component.
+  pub alpha: f32,
+  /// An optional fallback color.
+  pub fallback: Option<CssColor>,
+}
+
+impl DeviceCMYK {
+  /// Converts the color to sRGB using the
+  /// [naive conversion](https://drafts.csswg.org/css-color-5/#cmyk-rgb) defined by the spec.
+  pub fn to_srgb(&self) -> SRGB {
+    let c = if self.c.is_nan() { 0.0 } else { self.c };
+    let m = if self.m.is_nan() { 0.0 } else { self.m };
+    let y = if self.y.is_nan() { 0.0 } else { self.y };
+    let k = if self.k.is_nan() { 0.0 } else { self.k };
+    SRGB {
+      r: 1.0 - (c * (1.0 - k) + k).min(1.0),
+      g: 1.0 - (m * (1.0 - k) + k).min(1.0),
+      b: 1.0 - (y * (1.0 - k) + k).min(1.0),
+      alpha: if self.alpha.is_nan() { 0.0 } else { self.alpha },
+    }
+  }
+}
+
 /// A floating point representation of color types that
 /// are usually stored as RGBA. These are used when there
 /// are any `none` components, which are represented as NaN.
@@ -244,6 +346,23 @@ bitflags! {
   }
 }
 
+/// The algorithm used to map out of gamut colors into the sRGB gamut when generating fallbacks.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(
+  any(feature = "serde", feature = "nodejs"),
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "kebab-case")
+)]
+pub enum GamutMapping {
+  /// Reduces chroma in the OKLCH color space until the color is within gamut, as defined by
+  /// the [CSS gamut mapping algorithm](https://www.w3.org/TR/css-color-4/#css-gamut-mapping).
+  /// This preserves the lightness and hue of the original color.
+  #[default]
+  ChromaReduction,
+  /// Clamps each channel to the sRGB gamut. This is faster, but can visibly shift the hue.
+  Clip,
+}
+
 enum_property! {
   /// A [color space](https://www.w3.org/TR/css-color-4/#interpolation-space) keyword
   /// used in interpolation functions such as `color-mix()`.
@@ -338,6 +457,18 @@ impl CssColor {
     }
   }
 
+  /// Converts the color to RGBA, using the given algorithm to map out of gamut colors.
+  pub fn to_rgb_with_gamut_mapping(&self, gamut_mapping: GamutMapping) -> Result<CssColor, ()> {
+    match (self, gamut_mapping) {
+      (_, GamutMapping::ChromaReduction) => self.to_rgb(),
+      (CssColor::LightDark(light, dark), GamutMapping::Clip) => Ok(CssColor::LightDark(
+        Box::new(light.to_rgb_with_gamut_mapping(gamut_mapping)?),
+        Box::new(dark.to_rgb_with_gamut_mapping(gamut_mapping)?),
+      )),
+      (_, GamutMapping::Clip) => Ok(RGBA::from(SRGB::try_from(self)?.resolve_missing().clip()).into()),
+    }
+  }
+
   /// Converts the color to the LAB color space.
   pub fn to_lab(&self) -> Result<CssColor, ()> {
     match self {
@@ -358,14 +489,48 @@ impl CssColor {
     }
   }
 
+  /// Resolves a [`contrast-color()`](https://drafts.csswg.org/css-color-5/#contrast-color) function
+  /// to white or black, whichever has the higher WCAG 2.1 contrast ratio against the input color.
+  /// Returns `None` if this is not a `contrast-color()` function, or if the input color is not
+  /// known until computed value time.
+  pub fn resolve_contrast_color(&self) -> Option<CssColor> {
+    let color = match self {
+      CssColor::ContrastColor(color) => color,
+      _ => return None,
+    };
+
+    match &**color {
+      CssColor::LightDark(light, dark) => {
+        let light = CssColor::ContrastColor(light.clone()).resolve_contrast_color()?;
+        let dark = CssColor::ContrastColor(dark.clone()).resolve_contrast_color()?;
+        Some(CssColor::LightDark(Box::new(light), Box::new(dark)))
+      }
+      color => {
+        // The Y component of XYZ-D65 is the relative luminance. Alpha is ignored.
+        let y = XYZd65::try_from(color).ok()?.resolve_missing().y.max(0.0).min(1.0);
+        let white = 1.05 / (y + 0.05);
+        let black = (y + 0.05) / 0.05;
+        Some(if white >= black {
+          CssColor::RGBA(RGBA::new(255, 255, 255, 1.0))
+        } else {
+          CssColor::RGBA(RGBA::new(0, 0, 0, 1.0))
+        })
+      }
+    }
+  }
+
   pub(crate) fn get_possible_fallbacks(&self, targets: Targets) -> ColorFallbackKind {
     // Fallbacks occur in levels: Oklab -> Lab -> P3 -> RGB. We start with all levels
     // below and including the authored color space, and remove the ones that aren't
     // compatible with our browser targets.
     let mut fallbacks = match self {
-      CssColor::CurrentColor | CssColor::RGBA(_) | CssColor::Float(..) | CssColor::System(..) => {
-        return ColorFallbackKind::empty()
-      }
+      CssColor::CurrentColor
+      | CssColor::RGBA(_)
+      | CssColor::Float(..)
+      | CssColor::System(..)
+      | CssColor::Profile(..)
+      | CssColor::DeviceCMYK(..)
+      | CssColor::ContrastColor(..) => return ColorFallbackKind::empty(),
       CssColor::LAB(lab) => match &**lab {
         LABColor::LAB(..) | LABColor::LCH(..) if should_compile!(targets, LabColors) => {
           ColorFallbackKind::LAB.and_below()
@@ -433,12 +598,18 @@ impl CssColor {
 
   /// Returns a fallback color for the given fallback type.
   pub fn get_fallback(&self, kind: ColorFallbackKind) -> CssColor {
+    self.get_fallback_with_gamut_mapping(kind, GamutMapping::default())
+  }
+
+  /// Returns a fallback color for the given fallback type, using the given
+  /// algorithm to map out of gamut colors for sRGB fallbacks.
+  pub fn get_fallback_with_gamut_mapping(&self, kind: ColorFallbackKind, gamut_mapping: GamutMapping) -> CssColor {
     if matches!(self, CssColor::RGBA(_)) {
       return self.clone();
     }
 
     match kind {
-      ColorFallbackKind::RGB => self.to_rgb().unwrap(),
+      ColorFallbackKind::RGB => self.to_rgb_with_gamut_mapping(gamut_mapping).unwrap(),
       ColorFallbackKind::P3 => self.to_p3().unwrap(),
       ColorFallbackKind::LAB => self.to_lab().unwrap(),
       _ => unreachable!(),
@@ -449,7 +620,7 @@ impl CssColor {
 impl IsCompatible for CssColor {
   fn is_compatible(&self, browsers: Browsers) -> bool {
     match self {
-      CssColor::CurrentColor | CssColor::RGBA(_) | CssColor::Float(..) => true,
+      CssColor::CurrentColor | CssColor::RGBA(_) | CssColor::Float(..) | CssColor::Profile(..) => true,
       CssColor::LAB(lab) => match &**lab {
         LABColor::LAB(..) | LABColor::LCH(..) => Feature::LabColors.is_compatible(browsers),
         LABColor::OKLAB(..) | LABColor::OKLCH(..) => Feature::OklabColors.is_compatible(browsers),
@@ -461,18 +632,22 @@ impl IsCompatible for CssColor {
       CssColor::LightDark(light, dark) => {
         Feature::LightDark.is_compatible(browsers) && light.is_compatible(browsers) && dark.is_compatible(browsers)
       }
+      CssColor::ContrastColor(color) => {
+        Feature::ContrastColor.is_compatible(browsers) && color.is_compatible(browsers)
+      }
+      CssColor::DeviceCMYK(..) => Feature::DeviceCmyk.is_compatible(browsers),
       CssColor::System(system) => system.is_compatible(browsers),
     }
   }
 }
 
 impl FallbackValues for CssColor {
-  fn get_fallbacks(&mut self, targets: Targets) -> Vec<CssColor> {
+  fn get_fallbacks(&mut self, targets: Targets, gamut_mapping: GamutMapping) -> Vec<CssColor> {
     let fallbacks = self.get_necessary_fallbacks(targets);
 
     let mut res = Vec::new();
     if fallbacks.contains(ColorFallbackKind::RGB) {
-      res.push(self.to_rgb().unwrap());
+      res.push(self.to_rgb_with_gamut_mapping(gamut_mapping).unwrap());
     }
 
     if fallbacks.contains(ColorFallbackKind::P3) {
@@ -589,6 +764,50 @@ impl ToCss for CssColor {
         LABColor::OKLCH(lch) => write_components("oklch", lch.l, lch.c, lch.h, lch.alpha, dest),
       },
       CssColor::Predefined(predefined) => write_predefined(predefined, dest),
+      CssColor::Profile(color) => {
+        dest.write_str("color(")?;
+        dest.write_dashed_ident(&color.profile, false)?;
+        for component in &color.components {
+          dest.write_char(' ')?;
+          write_component(*component, dest)?;
+        }
+
+        if color.alpha.is_nan() || (color.alpha - 1.0).abs() > f32::EPSILON {
+          dest.delim('/', true)?;
+          write_component(color.alpha, dest)?;
+        }
+
+        dest.write_char(')')
+      }
+      CssColor::DeviceCMYK(cmyk) => {
+        if !dest.targets.is_compatible(Feature::DeviceCmyk) {
+          return match &cmyk.fallback {
+            Some(fallback) => fallback.to_css(dest),
+            None => CssColor::from(cmyk.to_srgb()).to_css(dest),
+          };
+        }
+
+        dest.write_str("device-cmyk(")?;
+        write_component(cmyk.c, dest)?;
+        dest.write_char(' ')?;
+        write_component(cmyk.m, dest)?;
+        dest.write_char(' ')?;
+        write_component(cmyk.y, dest)?;
+        dest.write_char(' ')?;
+        write_component(cmyk.k, dest)?;
+
+        if cmyk.alpha.is_nan() || (cmyk.alpha - 1.0).abs() > f32::EPSILON {
+          dest.delim('/', true)?;
+          write_component(cmyk.alpha, dest)?;
+        }
+
+        if let Some(fallback) = &cmyk.fallback {
+          dest.delim(',', false)?;
+          fallback.to_css(dest)?;
+        }
+
+        dest.write_char(')')
+      }
       CssColor::Float(float) => {
         // Serialize as hex.
         let srgb = SRGB::from(**float);
@@ -613,6 +832,17 @@ impl ToCss for CssColor {
         dark.to_css(dest)?;
         dest.write_char(')')
       }
+      CssColor::ContrastColor(color) => {
+        if !dest.targets.is_compatible(Feature::ContrastColor) {
+          if let Some(resolved) = self.resolve_contrast_color() {
+            return resolved.to_css(dest);
+          }
+        }
+
+        dest.write_str("contrast-color(")?;
+        color.to_css(dest)?;
+        dest.write_char(')')
+      }
       CssColor::System(system) => system.to_css(dest),
     }
   }
@@ -673,6 +903,8 @@ struct RelativeComponentParser {
   names: (&'static str, &'static str, &'static str),
   components: (f32, f32, f32, f32),
   types: (ChannelType, ChannelType, ChannelType),
+  /// The reference range that numbers in percentage channels are resolved against.
+  percentage_range: f32,
 }
 
 impl RelativeComponentParser {
@@ -681,6 +913,7 @@ impl RelativeComponentParser {
       names: color.channels(),
       components: color.components(),
       types: color.types(),
+      percentage_range: color.percentage_range(),
     }
   }
 
@@ -779,6 +1012,25 @@ impl<'i> ColorParser<'i> for RelativeComponentParser {
       return Ok(value);
     }
 
+    // Numbers are resolved against the reference range of the channel, e.g. 0-100 for the lightness in lab().
+    if let Ok(value) = input.try_parse(|input| input.expect_number()) {
+      return Ok(value / self.percentage_range);
+    }
+
+    if let Ok(value) = input.try_parse(|input| -> Result<f32, ParseError<'i, ParserError<'i>>> {
+      match Calc::parse_with(input, |ident| {
+        self
+          .get_ident(ident, ChannelType::Percentage)
+          .map(|v| Calc::Number(v * self.percentage_range))
+      }) {
+        Ok(Calc::Value(v)) => Ok(*v),
+        Ok(Calc::Number(n)) => Ok(n),
+        _ => Err(input.new_custom_error(ParserError::InvalidValue)),
+      }
+    }) {
+      return Ok(value / self.percentage_range);
+    }
+
     if let Ok(value) = input.try_parse(|input| -> Result<Percentage, ParseError<'i, ParserError<'i>>> {
       match Calc::parse_with(input, |ident| {
         self
@@ -852,7 +1104,7 @@ impl ComponentParser {
   pub fn parse_relative<
     'i,
     't,
-    T: TryFrom<CssColor> + ColorSpace,
+    T: TryFrom<CssColor> + ColorSpace + 'static,
     C: LightDarkColor,
     P: Fn(&mut Parser<'i, 't>, &mut Self) -> Result<C, ParseError<'i, ParserError<'i>>>,
   >(
@@ -871,7 +1123,7 @@ impl ComponentParser {
   fn parse_from<
     'i,
     't,
-    T: TryFrom<CssColor> + ColorSpace,
+    T: TryFrom<CssColor> + ColorSpace + 'static,
     C: LightDarkColor,
     P: Fn(&mut Parser<'i, 't>, &mut Self) -> Result<C, ParseError<'i, ParserError<'i>>>,
   >(
@@ -1041,6 +1293,9 @@ fn parse_color_function<'i, 't>(
     "color-mix" => {
       input.parse_nested_block(parse_color_mix)
     },
+    "device-cmyk" => {
+      input.parse_nested_block(|input| parse_device_cmyk(input, &mut parser))
+    },
     "light-dark" => {
       input.parse_nested_block(|input| {
         let light = match CssColor::parse(input)? {
@@ -1055,6 +1310,11 @@ fn parse_color_function<'i, 't>(
         Ok(CssColor::LightDark(light, dark))
       })
     },
+    "contrast-color" => {
+      input.parse_nested_block(|input| {
+        Ok(CssColor::ContrastColor(Box::new(CssColor::parse(input)?)))
+      })
+    },
     _ => Err(location.new_unexpected_token_error(
       cssparser::Token::Ident(function.clone())
     ))
@@ -1063,7 +1323,7 @@ fn parse_color_function<'i, 't>(
 
 /// Parses the lab() and oklab() functions.
 #[inline]
-fn parse_lab<'i, 't, T: TryFrom<CssColor> + ColorSpace, F: Fn(f32, f32, f32, f32) -> LABColor>(
+fn parse_lab<'i, 't, T: TryFrom<CssColor> + ColorSpace + 'static, F: Fn(f32, f32, f32, f32) -> LABColor>(
   input: &mut Parser<'i, 't>,
   parser: &mut ComponentParser,
   f: F,
@@ -1085,7 +1345,7 @@ fn parse_lab<'i, 't, T: TryFrom<CssColor> + ColorSpace, F: Fn(f32, f32, f32, f32
 
 /// Parses the lch() and oklch() functions.
 #[inline]
-fn parse_lch<'i, 't, T: TryFrom<CssColor> + ColorSpace, F: Fn(f32, f32, f32, f32) -> LABColor>(
+fn parse_lch<'i, 't, T: TryFrom<CssColor> + ColorSpace + 'static, F: Fn(f32, f32, f32, f32) -> LABColor>(
   input: &mut Parser<'i, 't>,
   parser: &mut ComponentParser,
   f: F,
@@ -1128,6 +1388,29 @@ fn parse_predefined<'i, 't>(
 
     let colorspace = input.expect_ident_cloned()?;
 
+    // https://drafts.csswg.org/css-color-5/#custom-color
+    if colorspace.starts_with("--") {
+      if from.is_some() {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
+
+      let mut components = Vec::new();
+      while let Ok(component) = input.try_parse(|input| parse_number_or_percentage(input, parser)) {
+        components.push(component);
+      }
+
+      if components.is_empty() {
+        return Err(input.new_error_for_next_token());
+      }
+
+      let alpha = parse_alpha(input, parser)?;
+      return Ok(CssColor::Profile(Box::new(ProfileColor {
+        profile: colorspace.as_ref().to_owned(),
+        components,
+        alpha,
+      })));
+    }
+
     if let Some(CssColor::LightDark(light, dark)) = from {
       let state = input.state();
       let light = parse_predefined_relative(input, parser, &colorspace, Some(&*light))?;
@@ -1191,10 +1474,52 @@ fn parse_predefined_relative<'i, 't>(
   Ok(CssColor::Predefined(Box::new(res)))
 }
 
+#[inline]
+fn parse_device_cmyk<'i>(
+  input: &mut Parser<'i, '_>,
+  parser: &mut ComponentParser,
+) -> Result<CssColor, ParseError<'i, ParserError<'i>>> {
+  // https://drafts.csswg.org/css-color-5/#device-cmyk
+  let c = parse_number_or_percentage(input, parser)?;
+  let (m, y, k, alpha) = if !c.is_nan() && input.try_parse(|input| input.expect_comma()).is_ok() {
+    // The legacy syntax only accepts comma separated numbers.
+    let m = parser.parse_number(input)?;
+    input.expect_comma()?;
+    let y = parser.parse_number(input)?;
+    input.expect_comma()?;
+    let k = parser.parse_number(input)?;
+    if m.is_nan() || y.is_nan() || k.is_nan() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+    (m, y, k, 1.0)
+  } else {
+    let m = parse_number_or_percentage(input, parser)?;
+    let y = parse_number_or_percentage(input, parser)?;
+    let k = parse_number_or_percentage(input, parser)?;
+    let alpha = parse_alpha(input, parser)?;
+    (m, y, k, alpha)
+  };
+
+  let fallback = if input.try_parse(|input| input.expect_comma()).is_ok() {
+    Some(CssColor::parse(input)?)
+  } else {
+    None
+  };
+
+  Ok(CssColor::DeviceCMYK(Box::new(DeviceCMYK {
+    c,
+    m,
+    y,
+    k,
+    alpha,
+    fallback,
+  })))
+}
+
 /// Parses the hsl() and hwb() functions.
 /// The results of this function are stored as floating point if there are any `none` components.
 #[inline]
-fn parse_hsl_hwb<'i, 't, T: TryFrom<CssColor> + ColorSpace, F: Fn(f32, f32, f32, f32) -> CssColor>(
+fn parse_hsl_hwb<'i, 't, T: TryFrom<CssColor> + ColorSpace + 'static, F: Fn(f32, f32, f32, f32) -> CssColor>(
   input: &mut Parser<'i, 't>,
   parser: &mut ComponentParser,
   allows_legacy: bool,
@@ -1459,6 +1784,11 @@ pub trait ColorSpace {
   fn channels(&self) -> (&'static str, &'static str, &'static str);
   /// Returns the channel types for this color space.
   fn types(&self) -> (ChannelType, ChannelType, ChannelType);
+  /// Returns the reference range that percentage channels are resolved against
+  /// when they are used as numbers, e.g. in relative colors.
+  fn percentage_range(&self) -> f32 {
+    1.0
+  }
   /// Resolves missing color components (e.g. `none` keywords) in the color.
   fn resolve_missing(&self) -> Self;
   /// Returns a resolved color by replacing missing (i.e. `none`) components with zero,
@@ -1477,6 +1807,7 @@ macro_rules! define_colorspace {
       $(#[$c_meta: meta])*
       $c: ident: $ct: ident
     }
+    $(percentage_range: $range: literal)?
   ) => {
     $(#[$outer])*
     #[derive(Debug, Clone, Copy, PartialEq)] #[cfg_attr(feature = "visitor", derive(Visit))]
@@ -1506,6 +1837,12 @@ macro_rules! define_colorspace {
         (ChannelType::$at, ChannelType::$bt, ChannelType::$ct)
       }
 
+      $(
+        fn percentage_range(&self) -> f32 {
+          $range
+        }
+      )?
+
       #[inline]
       fn resolve_missing(&self) -> Self {
         Self {
@@ -1724,6 +2061,7 @@ define_colorspace! {
     /// The b component.
     b: Number
   }
+  percentage_range: 100.0
 }
 
 define_colorspace! {
@@ -1736,6 +2074,7 @@ define_colorspace! {
     /// The hue component.
     h: Angle
   }
+  percentage_range: 100.0
 }
 
 define_colorspace! {
@@ -1796,6 +2135,7 @@ define_colorspace! {
     /// The lightness component.
     l: Percentage
   }
+  percentage_range: 100.0
 }
 
 define_colorspace! {
@@ -1808,6 +2148,7 @@ define_colorspace! {
     /// The blackness component.
     b: Percentage
   }
+  percentage_range: 100.0
 }
 
 macro_rules! via {
@@ -2880,6 +3221,9 @@ macro_rules! color_space {
           CssColor::Float(float) => (**float).into(),
           CssColor::CurrentColor => return Err(()),
           CssColor::LightDark(..) => return Err(()),
+          CssColor::ContrastColor(..) => return Err(()),
+          CssColor::Profile(..) => return Err(()),
+          CssColor::DeviceCMYK(..) => return Err(()),
           CssColor::System(..) => return Err(()),
         })
       }
@@ -2895,6 +3239,9 @@ macro_rules! color_space {
           CssColor::Float(float) => (*float).into(),
           CssColor::CurrentColor => return Err(()),
           CssColor::LightDark(..) => return Err(()),
+          CssColor::ContrastColor(..) => return Err(()),
+          CssColor::Profile(..) => return Err(()),
+          CssColor::DeviceCMYK(..) => return Err(()),
           CssColor::System(..) => return Err(()),
         })
       }
@@ -3253,7 +3600,22 @@ impl CssColor {
       + From<OKLCH>
       + Copy,
   {
-    if matches!(self, CssColor::CurrentColor) || matches!(other, CssColor::CurrentColor) {
+    // These colors are not known until computed value time.
+    if matches!(
+      self,
+      CssColor::CurrentColor
+        | CssColor::System(..)
+        | CssColor::ContrastColor(..)
+        | CssColor::Profile(..)
+        | CssColor::DeviceCMYK(..)
+    ) || matches!(
+      other,
+      CssColor::CurrentColor
+        | CssColor::System(..)
+        | CssColor::ContrastColor(..)
+        | CssColor::Profile(..)
+        | CssColor::DeviceCMYK(..)
+    ) {
       return Err(());
     }
 
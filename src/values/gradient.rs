@@ -1,7 +1,7 @@
 //! CSS gradient values.
 
 use super::angle::{Angle, AnglePercentage};
-use super::color::{ColorFallbackKind, CssColor};
+use super::color::{ColorFallbackKind, CssColor, GamutMapping};
 use super::length::{Length, LengthPercentage};
 use super::number::CSSNumber;
 use super::percentage::{DimensionPercentage, NumberOrPercentage, Percentage};
@@ -136,14 +136,20 @@ impl Gradient {
 
   /// Returns a fallback gradient for the given color fallback type.
   pub fn get_fallback(&self, kind: ColorFallbackKind) -> Gradient {
+    self.get_fallback_with_gamut_mapping(kind, GamutMapping::default())
+  }
+
+  /// Returns a fallback gradient for the given color fallback type, using the
+  /// given algorithm to map out of gamut colors for sRGB fallbacks.
+  pub fn get_fallback_with_gamut_mapping(&self, kind: ColorFallbackKind, gamut_mapping: GamutMapping) -> Gradient {
     match self {
-      Gradient::Linear(g) => Gradient::Linear(g.get_fallback(kind)),
-      Gradient::RepeatingLinear(g) => Gradient::RepeatingLinear(g.get_fallback(kind)),
-      Gradient::Radial(g) => Gradient::Radial(g.get_fallback(kind)),
-      Gradient::RepeatingRadial(g) => Gradient::RepeatingRadial(g.get_fallback(kind)),
-      Gradient::Conic(g) => Gradient::Conic(g.get_fallback(kind)),
-      Gradient::RepeatingConic(g) => Gradient::RepeatingConic(g.get_fallback(kind)),
-      Gradient::WebKitGradient(g) => Gradient::WebKitGradient(g.get_fallback(kind)),
+      Gradient::Linear(g) => Gradient::Linear(g.get_fallback(kind, gamut_mapping)),
+      Gradient::RepeatingLinear(g) => Gradient::RepeatingLinear(g.get_fallback(kind, gamut_mapping)),
+      Gradient::Radial(g) => Gradient::Radial(g.get_fallback(kind, gamut_mapping)),
+      Gradient::RepeatingRadial(g) => Gradient::RepeatingRadial(g.get_fallback(kind, gamut_mapping)),
+      Gradient::Conic(g) => Gradient::Conic(g.get_fallback(kind, gamut_mapping)),
+      Gradient::RepeatingConic(g) => Gradient::RepeatingConic(g.get_fallback(kind, gamut_mapping)),
+      Gradient::WebKitGradient(g) => Gradient::WebKitGradient(g.get_fallback(kind, gamut_mapping)),
     }
   }
 }
@@ -317,10 +323,14 @@ impl LinearGradient {
     }
   }
 
-  fn get_fallback(&self, kind: ColorFallbackKind) -> LinearGradient {
+  fn get_fallback(&self, kind: ColorFallbackKind, gamut_mapping: GamutMapping) -> LinearGradient {
     LinearGradient {
       direction: self.direction.clone(),
-      items: self.items.iter().map(|item| item.get_fallback(kind)).collect(),
+      items: self
+        .items
+        .iter()
+        .map(|item| item.get_fallback_with_gamut_mapping(kind, gamut_mapping))
+        .collect(),
       vendor_prefix: self.vendor_prefix,
     }
   }
@@ -405,11 +415,15 @@ impl ToCss for RadialGradient {
 }
 
 impl RadialGradient {
-  fn get_fallback(&self, kind: ColorFallbackKind) -> RadialGradient {
+  fn get_fallback(&self, kind: ColorFallbackKind, gamut_mapping: GamutMapping) -> RadialGradient {
     RadialGradient {
       shape: self.shape.clone(),
       position: self.position.clone(),
-      items: self.items.iter().map(|item| item.get_fallback(kind)).collect(),
+      items: self
+        .items
+        .iter()
+        .map(|item| item.get_fallback_with_gamut_mapping(kind, gamut_mapping))
+        .collect(),
       vendor_prefix: self.vendor_prefix,
     }
   }
@@ -813,11 +827,15 @@ impl ToCss for ConicGradient {
 }
 
 impl ConicGradient {
-  fn get_fallback(&self, kind: ColorFallbackKind) -> ConicGradient {
+  fn get_fallback(&self, kind: ColorFallbackKind, gamut_mapping: GamutMapping) -> ConicGradient {
     ConicGradient {
       angle: self.angle.clone(),
       position: self.position.clone(),
-      items: self.items.iter().map(|item| item.get_fallback(kind)).collect(),
+      items: self
+        .items
+        .iter()
+        .map(|item| item.get_fallback_with_gamut_mapping(kind, gamut_mapping))
+        .collect(),
     }
   }
 }
@@ -914,9 +932,19 @@ impl<D: Clone> GradientItem<D> {
 
   /// Returns a fallback gradient item for the given color fallback type.
   pub fn get_fallback(&self, kind: ColorFallbackKind) -> GradientItem<D> {
+    self.get_fallback_with_gamut_mapping(kind, GamutMapping::default())
+  }
+
+  /// Returns a fallback gradient item for the given color fallback type, using the
+  /// given algorithm to map out of gamut colors for sRGB fallbacks.
+  pub fn get_fallback_with_gamut_mapping(
+    &self,
+    kind: ColorFallbackKind,
+    gamut_mapping: GamutMapping,
+  ) -> GradientItem<D> {
     match self {
       GradientItem::ColorStop(stop) => GradientItem::ColorStop(ColorStop {
-        color: stop.color.get_fallback(kind),
+        color: stop.color.get_fallback_with_gamut_mapping(kind, gamut_mapping),
         position: stop.position.clone(),
       }),
       GradientItem::Hint(..) => self.clone(),
@@ -1152,13 +1180,13 @@ impl ToCss for WebKitGradient {
 }
 
 impl WebKitGradient {
-  fn get_fallback(&self, kind: ColorFallbackKind) -> WebKitGradient {
+  fn get_fallback(&self, kind: ColorFallbackKind, gamut_mapping: GamutMapping) -> WebKitGradient {
     let stops = match self {
       WebKitGradient::Linear { stops, .. } => stops,
       WebKitGradient::Radial { stops, .. } => stops,
     };
 
-    let stops = stops.iter().map(|stop| stop.get_fallback(kind)).collect();
+    let stops = stops.iter().map(|stop| stop.get_fallback(kind, gamut_mapping)).collect();
 
     match self {
       WebKitGradient::Linear { from, to, .. } => WebKitGradient::Linear {
@@ -1232,9 +1260,9 @@ impl ToCss for WebKitColorStop {
 }
 
 impl WebKitColorStop {
-  fn get_fallback(&self, kind: ColorFallbackKind) -> WebKitColorStop {
+  fn get_fallback(&self, kind: ColorFallbackKind, gamut_mapping: GamutMapping) -> WebKitColorStop {
     WebKitColorStop {
-      color: self.color.get_fallback(kind),
+      color: self.color.get_fallback_with_gamut_mapping(kind, gamut_mapping),
       position: self.position,
     }
   }
@@ -1,6 +1,6 @@
 //! CSS image values.
 
-use super::color::ColorFallbackKind;
+use super::color::{ColorFallbackKind, GamutMapping};
 use super::gradient::*;
 use super::resolution::Resolution;
 use crate::compat;
@@ -8,8 +8,11 @@ use crate::dependencies::{Dependency, UrlDependency};
 use crate::error::{ParserError, PrinterError};
 use crate::prefixes::{is_webkit_gradient, Feature};
 use crate::printer::Printer;
+use crate::properties::custom::TokenList;
+use crate::stylesheet::ParserOptions;
 use crate::targets::{Browsers, Targets};
 use crate::traits::{FallbackValues, IsCompatible, Parse, ToCss};
+use crate::values::ident::Ident;
 use crate::values::string::CowArcStr;
 use crate::values::url::Url;
 use crate::vendor_prefix::VendorPrefix;
@@ -39,6 +42,8 @@ pub enum Image<'i> {
   Gradient(Box<Gradient>),
   /// An `image-set()`.
   ImageSet(ImageSet<'i>),
+  /// A `paint()` function.
+  Paint(PaintFunction<'i>),
 }
 
 impl<'i> Default for Image<'i> {
@@ -101,8 +106,20 @@ impl<'i> Image<'i> {
 
   /// Returns a fallback version of the image for the given color fallback type.
   pub fn get_fallback(&self, kind: ColorFallbackKind) -> Image<'i> {
+    self.get_fallback_with_gamut_mapping(kind, GamutMapping::default())
+  }
+
+  /// Returns a fallback version of the image for the given color fallback type, using the
+  /// given algorithm to map out of gamut colors for sRGB fallbacks.
+  pub fn get_fallback_with_gamut_mapping(
+    &self,
+    kind: ColorFallbackKind,
+    gamut_mapping: GamutMapping,
+  ) -> Image<'i> {
     match self {
-      Image::Gradient(grad) => Image::Gradient(Box::new(grad.get_fallback(kind))),
+      Image::Gradient(grad) => {
+        Image::Gradient(Box::new(grad.get_fallback_with_gamut_mapping(kind, gamut_mapping)))
+      }
       _ => self.clone(),
     }
   }
@@ -131,6 +148,7 @@ impl<'i> IsCompatible for Image<'i> {
         Gradient::WebKitGradient(..) => is_webkit_gradient(browsers),
       },
       Image::ImageSet(i) => i.is_compatible(browsers),
+      Image::Paint(..) => compat::Feature::PaintFunction.is_compatible(browsers),
       Image::Url(..) | Image::None => true,
     }
   }
@@ -146,8 +164,8 @@ pub(crate) trait ImageFallback<'i>: Sized {
   }
 
   #[inline]
-  fn get_fallback(&self, kind: ColorFallbackKind) -> Self {
-    self.with_image(self.get_image().get_fallback(kind))
+  fn get_fallback(&self, kind: ColorFallbackKind, gamut_mapping: GamutMapping) -> Self {
+    self.with_image(self.get_image().get_fallback_with_gamut_mapping(kind, gamut_mapping))
   }
 }
 
@@ -164,7 +182,7 @@ impl<'i> ImageFallback<'i> for Image<'i> {
 }
 
 impl<'i> FallbackValues for Image<'i> {
-  fn get_fallbacks(&mut self, targets: Targets) -> Vec<Self> {
+  fn get_fallbacks(&mut self, targets: Targets, gamut_mapping: GamutMapping) -> Vec<Self> {
     // Determine which prefixes and color fallbacks are needed.
     let prefixes = self.get_necessary_prefixes(targets);
     let fallbacks = self.get_necessary_fallbacks(targets);
@@ -172,7 +190,7 @@ impl<'i> FallbackValues for Image<'i> {
 
     // Get RGB fallbacks if needed.
     let rgb = if fallbacks.contains(ColorFallbackKind::RGB) {
-      Some(self.get_fallback(ColorFallbackKind::RGB))
+      Some(self.get_fallback_with_gamut_mapping(ColorFallbackKind::RGB, gamut_mapping))
     } else {
       None
     };
@@ -211,12 +229,12 @@ impl<'i> FallbackValues for Image<'i> {
 
       // P3 fallback.
       if fallbacks.contains(ColorFallbackKind::P3) {
-        res.push(self.get_fallback(ColorFallbackKind::P3));
+        res.push(self.get_fallback_with_gamut_mapping(ColorFallbackKind::P3, gamut_mapping));
       }
 
       // Convert original to lab if needed (e.g. if oklab is not supported but lab is).
       if fallbacks.contains(ColorFallbackKind::LAB) {
-        *self = self.get_fallback(ColorFallbackKind::LAB);
+        *self = self.get_fallback_with_gamut_mapping(ColorFallbackKind::LAB, gamut_mapping);
       }
     } else if let Some(last) = res.pop() {
       // Prefixed property with no unprefixed version.
@@ -230,7 +248,7 @@ impl<'i> FallbackValues for Image<'i> {
 }
 
 impl<'i, T: ImageFallback<'i>> FallbackValues for SmallVec<[T; 1]> {
-  fn get_fallbacks(&mut self, targets: Targets) -> Vec<Self> {
+  fn get_fallbacks(&mut self, targets: Targets, gamut_mapping: GamutMapping) -> Vec<Self> {
     // Determine what vendor prefixes and color fallbacks are needed.
     let mut prefixes = VendorPrefix::empty();
     let mut fallbacks = ColorFallbackKind::empty();
@@ -242,7 +260,12 @@ impl<'i, T: ImageFallback<'i>> FallbackValues for SmallVec<[T; 1]> {
 
     // Get RGB fallbacks if needed.
     let rgb: Option<SmallVec<[T; 1]>> = if fallbacks.contains(ColorFallbackKind::RGB) {
-      Some(self.iter().map(|item| item.get_fallback(ColorFallbackKind::RGB)).collect())
+      Some(
+        self
+          .iter()
+          .map(|item| item.get_fallback(ColorFallbackKind::RGB, gamut_mapping))
+          .collect(),
+      )
     } else {
       None
     };
@@ -287,7 +310,10 @@ impl<'i, T: ImageFallback<'i>> FallbackValues for SmallVec<[T; 1]> {
       }
 
       if fallbacks.contains(ColorFallbackKind::P3) {
-        let p3_images = self.iter().map(|item| item.get_fallback(ColorFallbackKind::P3)).collect();
+        let p3_images = self
+          .iter()
+          .map(|item| item.get_fallback(ColorFallbackKind::P3, gamut_mapping))
+          .collect();
 
         res.push(p3_images)
       }
@@ -295,7 +321,7 @@ impl<'i, T: ImageFallback<'i>> FallbackValues for SmallVec<[T; 1]> {
       // Convert to lab if needed (e.g. if oklab is not supported but lab is).
       if fallbacks.contains(ColorFallbackKind::LAB) {
         for item in self.iter_mut() {
-          *item = item.get_fallback(ColorFallbackKind::LAB);
+          *item = item.get_fallback(ColorFallbackKind::LAB, gamut_mapping);
         }
       }
     } else if let Some(last) = res.pop() {
@@ -327,6 +353,10 @@ impl<'i> Parse<'i> for Image<'i> {
       return Ok(Image::ImageSet(image_set));
     }
 
+    if let Ok(paint) = input.try_parse(PaintFunction::parse) {
+      return Ok(Image::Paint(paint));
+    }
+
     Err(input.new_error_for_next_token())
   }
 }
@@ -341,10 +371,57 @@ impl<'i> ToCss for Image<'i> {
       Image::Url(url) => url.to_css(dest),
       Image::Gradient(grad) => grad.to_css(dest),
       Image::ImageSet(image_set) => image_set.to_css(dest),
+      Image::Paint(paint) => paint.to_css(dest),
     }
   }
 }
 
+/// A CSS [`paint()`](https://drafts.css-houdini.org/css-paint-api/#paint-notation) function,
+/// which renders an image using a registered paint worklet.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct PaintFunction<'i> {
+  /// The name of the paint worklet.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: Ident<'i>,
+  /// The arguments passed to the paint worklet.
+  pub arguments: TokenList<'i>,
+}
+
+impl<'i> Parse<'i> for PaintFunction<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    input.expect_function_matching("paint")?;
+    input.parse_nested_block(|input| {
+      let name = Ident::parse(input)?;
+      let arguments = if input.try_parse(|input| input.expect_comma()).is_ok() {
+        TokenList::parse(input, &ParserOptions::default(), 0)?
+      } else {
+        TokenList(vec![])
+      };
+      Ok(PaintFunction { name, arguments })
+    })
+  }
+}
+
+impl<'i> ToCss for PaintFunction<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let css_module_paint_enabled = dest.css_module.as_ref().map_or(false, |css_module| css_module.config.paint);
+    dest.write_str("paint(")?;
+    dest.write_ident(&self.name.0, css_module_paint_enabled)?;
+    if !self.arguments.0.is_empty() {
+      dest.delim(',', false)?;
+      self.arguments.to_css(dest, false)?;
+    }
+    dest.write_char(')')
+  }
+}
+
 /// A CSS [`image-set()`](https://drafts.csswg.org/css-images-4/#image-set-notation) value.
 ///
 /// `image-set()` allows the user agent to choose between multiple versions of an image to
@@ -379,11 +456,65 @@ impl<'i> ImageSet<'i> {
 
   /// Returns the `image-set()` value with the given vendor prefix.
   pub fn get_prefixed(&self, prefix: VendorPrefix) -> ImageSet<'i> {
+    // -webkit-image-set() does not support type(), so omit those options when
+    // there is an alternative. Otherwise, keep them so that browsers ignore the value.
+    let options =
+      if prefix.contains(VendorPrefix::WebKit) && self.options.iter().any(|option| option.file_type.is_none()) {
+        self
+          .options
+          .iter()
+          .filter(|option| option.file_type.is_none())
+          .cloned()
+          .collect()
+      } else {
+        self.options.clone()
+      };
+
     ImageSet {
-      options: self.options.clone(),
+      options,
       vendor_prefix: prefix,
     }
   }
+
+  fn optimize(&mut self) {
+    for option in &mut self.options {
+      if matches!(&option.file_type, Some(file_type) if is_universal_file_type(file_type)) {
+        option.file_type = None;
+      }
+    }
+
+    // The sort is stable, so options with the same resolution keep their relative order.
+    // This matters because the first one with a supported type is chosen.
+    self.options.sort_by(|a, b| {
+      a.resolution
+        .to_dppx()
+        .partial_cmp(&b.resolution.to_dppx())
+        .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    // An option can never be chosen if a previous option has the same resolution,
+    // and either has no type or the same type.
+    let mut options: Vec<ImageSetOption<'i>> = Vec::with_capacity(self.options.len());
+    for option in std::mem::take(&mut self.options) {
+      let dppx = option.resolution.to_dppx();
+      if !options
+        .iter()
+        .any(|o| o.resolution.to_dppx() == dppx && (o.file_type.is_none() || o.file_type == option.file_type))
+      {
+        options.push(option);
+      }
+    }
+    self.options = options;
+  }
+}
+
+/// Returns whether every browser that supports `image-set()` can decode the given mime type,
+/// making a `type()` annotation for it redundant.
+fn is_universal_file_type(file_type: &str) -> bool {
+  matches!(
+    file_type.to_ascii_lowercase().as_str(),
+    "image/png" | "image/jpeg" | "image/gif"
+  )
 }
 
 impl<'i> Parse<'i> for ImageSet<'i> {
@@ -399,7 +530,9 @@ impl<'i> Parse<'i> for ImageSet<'i> {
     };
 
     let options = input.parse_nested_block(|input| input.parse_comma_separated(ImageSetOption::parse))?;
-    Ok(ImageSet { options, vendor_prefix })
+    let mut image_set = ImageSet { options, vendor_prefix };
+    image_set.optimize();
+    Ok(image_set)
   }
 }
 
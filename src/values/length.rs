@@ -210,7 +210,7 @@ macro_rules! define_length_units {
           )+
           (a, b) => {
             if let (Some(a), Some(b)) = (a.to_px(), b.to_px()) {
-              Some(Px(a + b))
+              Some(LengthValue::shortest_absolute(a + b, self, other))
             } else {
               None
             }
@@ -259,7 +259,7 @@ macro_rules! define_length_units {
           )+
           (a, b) => {
             if let (Some(a), Some(b)) = (a.to_px(), b.to_px()) {
-              Some(Px(op(a, b)))
+              Some(LengthValue::shortest_absolute(op(a, b), self, rhs))
             } else {
               None
             }
@@ -490,7 +490,7 @@ impl ToCss for LengthValue {
 
     // The unit can be omitted if the value is zero, except inside calc()
     // expressions, where unitless numbers won't be parsed as dimensions.
-    if !dest.in_calc && value == 0.0 {
+    if !dest.in_calc && dest.round(value) == 0.0 {
       return dest.write_char('0');
     }
 
@@ -515,6 +515,7 @@ where
   W: std::fmt::Write,
 {
   use cssparser::ToCss;
+  let value = dest.round(value);
   let int_value = if value.fract() == 0.0 { Some(value as i32) } else { None };
   let token = Token::Dimension {
     has_sign: value < 0.0,
@@ -553,6 +554,42 @@ impl LengthValue {
       _ => None,
     }
   }
+
+  /// Converts a number of pixels to an absolute length in the same unit as this value.
+  fn px_to_same_unit(&self, px: CSSNumber) -> Option<LengthValue> {
+    use LengthValue::*;
+    match self {
+      Px(_) => Some(Px(px)),
+      In(_) => Some(In(px / PX_PER_IN)),
+      Cm(_) => Some(Cm(px / PX_PER_CM)),
+      Mm(_) => Some(Mm(px / PX_PER_MM)),
+      Q(_) => Some(Q(px / PX_PER_Q)),
+      Pt(_) => Some(Pt(px / PX_PER_PT)),
+      Pc(_) => Some(Pc(px / PX_PER_PC)),
+      _ => None,
+    }
+  }
+
+  /// Returns the absolute length equivalent to the given number of pixels, in either
+  /// pixels or the unit of one of the operands, whichever has the shortest serialization.
+  /// Pixels are preferred in case of a tie.
+  fn shortest_absolute(px: CSSNumber, a: &LengthValue, b: &LengthValue) -> LengthValue {
+    let mut shortest = LengthValue::Px(px);
+    let mut shortest_len = usize::MAX;
+    for candidate in [Some(LengthValue::Px(px)), a.px_to_same_unit(px), b.px_to_same_unit(px)]
+      .into_iter()
+      .flatten()
+    {
+      let (value, unit) = candidate.to_unit_value();
+      let len = cssparser::ToCss::to_css_string(&value).len() + unit.len();
+      if len < shortest_len {
+        shortest = candidate;
+        shortest_len = len;
+      }
+    }
+
+    shortest
+  }
 }
 
 /// A CSS [`<length>`](https://www.w3.org/TR/css-values-4/#lengths) value, with support for `calc()`.
@@ -34,10 +34,10 @@ impl ToCss for CSSNumber {
   where
     W: std::fmt::Write,
   {
-    let number = *self;
+    let number = dest.round(*self);
     if number != 0.0 && number.abs() < 1.0 {
       let mut s = String::new();
-      cssparser::ToCss::to_css(self, &mut s)?;
+      cssparser::ToCss::to_css(&number, &mut s)?;
       if number < 0.0 {
         dest.write_char('-')?;
         dest.write_str(s.trim_start_matches("-0"))
@@ -45,7 +45,7 @@ impl ToCss for CSSNumber {
         dest.write_str(s.trim_start_matches('0'))
       }
     } else {
-      cssparser::ToCss::to_css(self, dest)?;
+      cssparser::ToCss::to_css(&number, dest)?;
       Ok(())
     }
   }
@@ -115,7 +115,15 @@ pub type CSSInteger = i32;
 
 impl<'i> Parse<'i> for CSSInteger {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
-    // TODO: calc??
+    // Math functions in integer contexts are rounded to the nearest integer, with halves rounded up.
+    // https://drafts.csswg.org/css-values-4/#calc-type-checking
+    match input.try_parse(Calc::<CSSNumber>::parse) {
+      Ok(Calc::Value(v)) => return Ok((*v + 0.5).floor() as CSSInteger),
+      Ok(Calc::Number(n)) => return Ok((n + 0.5).floor() as CSSInteger),
+      Ok(_) => return Err(input.new_custom_error(ParserError::InvalidValue)),
+      _ => {}
+    }
+
     let integer = input.expect_integer()?;
     Ok(integer)
   }
@@ -26,8 +26,9 @@ impl<'i> Parse<'i> for Percentage {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     match input.try_parse(Calc::parse) {
       Ok(Calc::Value(v)) => return Ok(*v),
-      // Percentages are always compatible, so they will always compute to a value.
-      Ok(_) => unreachable!(),
+      // Percentages are always compatible, so they will always compute to a value
+      // unless mixed with other types such as numbers, which is invalid.
+      Ok(_) => return Err(input.new_custom_error(ParserError::InvalidValue)),
       _ => {}
     }
 
@@ -41,6 +42,14 @@ impl ToCss for Percentage {
   where
     W: std::fmt::Write,
   {
+    if dest.precision.is_some() {
+      // Round the percentage rather than the unit value, so that the
+      // configured number of decimal places applies to the output.
+      let percent = dest.round(self.0 * 100.0);
+      ToCss::to_css(&percent, dest)?;
+      return dest.write_char('%');
+    }
+
     use cssparser::ToCss;
     let int_value = if (self.0 * 100.0).fract() == 0.0 {
       Some(self.0 as i32)
@@ -63,7 +63,7 @@ use crate::{
   media_query::{MediaFeature, MediaFeatureValue, MediaList, MediaQuery},
   parser::DefaultAtRule,
   properties::{
-    custom::{EnvironmentVariable, Function, TokenList, TokenOrValue, Variable},
+    custom::{AttrFunction, EnvironmentVariable, Function, TokenList, TokenOrValue, Variable},
     Property,
   },
   rules::{supports::SupportsCondition, CssRule, CssRuleList},
@@ -131,6 +131,8 @@ bitflags! {
     const FUNCTIONS = 1 << 17;
     /// Visit a token.
     const TOKENS = 1 << 18;
+    /// Visit attribute references.
+    const ATTR_FUNCTIONS = 1 << 19;
   }
 }
 
@@ -252,6 +254,12 @@ pub trait Visitor<'i, T: Visit<'i, T, Self> = DefaultAtRule> {
     env.visit_children(self)
   }
 
+  /// Visits an attribute reference.
+  #[inline]
+  fn visit_attr_function(&mut self, attr: &mut AttrFunction<'i>) -> Result<(), Self::Error> {
+    attr.visit_children(self)
+  }
+
   /// Visits a media query list.
   #[inline]
   fn visit_media_list(&mut self, media: &mut MediaList<'i>) -> Result<(), Self::Error> {
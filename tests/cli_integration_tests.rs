@@ -283,6 +283,19 @@ fn minify_option() -> Result<(), Box<dyn std::error::Error>> {
   Ok(())
 }
 
+#[test]
+fn precision_option() -> Result<(), Box<dyn std::error::Error>> {
+  let infile = assert_fs::NamedTempFile::new("test.css")?;
+  infile.write_str(".foo { width: calc(100px / 3) }")?;
+  let mut cmd = Command::cargo_bin("lightningcss")?;
+  cmd.arg(infile.path());
+  cmd.arg("--minify");
+  cmd.arg("--precision=2");
+  cmd.assert().success().stdout(predicate::str::contains(".foo{width:33.33px}"));
+
+  Ok(())
+}
+
 #[test]
 fn nesting_option() -> Result<(), Box<dyn std::error::Error>> {
   let infile = assert_fs::NamedTempFile::new("test.css")?;